@@ -0,0 +1,3126 @@
+//! Command-line interface for the freelance payment escrow contract.
+
+use alloy::network::EthereumWallet;
+use alloy::primitives::utils::{format_ether, parse_ether};
+use alloy::primitives::{Address, Bytes, B256, U256};
+use alloy::providers::{DynProvider, Provider, ProviderBuilder};
+use alloy::rpc::types::{Log, TransactionReceipt};
+use alloy::signers::local::PrivateKeySigner;
+use alloy::signers::SignerSync;
+use clap::{Args, Parser, Subcommand};
+use escrow_client::{
+    decode_memo, encode_memo, AppealPolicy, ArbitrationPolicy, DisputeStakePolicy,
+    DisputeTimeoutPolicy, EscrowClient, FeeTier, Job, JobSummary, JuryPolicy, VolumeDiscount,
+};
+use escrow_types::{
+    job_commitment, EscrowEvent, JobOffer, JobStatus, Settlement, Verdict, JOB_FIELD_AMOUNT,
+    JOB_FIELD_CLIENT, JOB_FIELD_DEADLINE, JOB_FIELD_FREELANCER, JOB_FIELD_METADATA,
+    JOB_FIELD_PAYOUT_ADDRESS, MAX_JOBS_RANGE, TIMESHEET_APPROVED, TIMESHEET_REJECTED,
+};
+use eyre::{eyre, Result};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+#[derive(Parser)]
+#[command(
+    name = "escrow-cli",
+    about = "Manage the freelance payment escrow contract"
+)]
+struct Cli {
+    /// RPC endpoint
+    #[arg(long, env = "RPC_URL")]
+    rpc_url: String,
+
+    /// Escrow contract address
+    #[arg(long, env = "STYLUS_CONTRACT_ADDRESS")]
+    contract: Address,
+
+    #[command(flatten)]
+    signer: SignerArgs,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Args)]
+struct SignerArgs {
+    /// File containing a hex-encoded private key
+    #[arg(long, env = "PRIV_KEY_PATH", conflicts_with = "keystore")]
+    private_key_path: Option<PathBuf>,
+
+    /// Encrypted JSON keystore file
+    #[arg(long, env = "KEYSTORE_PATH")]
+    keystore: Option<PathBuf>,
+
+    /// Password for the keystore
+    #[arg(long, env = "KEYSTORE_PASSWORD", hide_env_values = true)]
+    keystore_password: Option<String>,
+}
+
+/// Terms of a signed job offer
+#[derive(Args)]
+struct OfferTerms {
+    /// Amount in ETH (e.g. 0.1)
+    #[arg(long)]
+    amount: String,
+    /// Job duration in seconds
+    #[arg(long)]
+    duration: u64,
+    /// Invoice or PO reference (up to 32 bytes of text, or 0x-prefixed bytes32)
+    #[arg(long, value_parser = parse_memo)]
+    memo: Option<B256>,
+    /// Client nonce; the offer opens at most one job
+    #[arg(long)]
+    nonce: U256,
+    /// Unix time from which the offer can no longer be accepted
+    #[arg(long)]
+    expiry: u64,
+}
+
+/// Terms of a signed release or refund
+#[derive(Args)]
+struct SettlementTerms {
+    /// Job ID
+    #[arg(long)]
+    job_id: U256,
+    /// Authorize a refund instead of a release; signed by the freelancer,
+    /// it returns the funds to the client
+    #[arg(long)]
+    refund: bool,
+    /// Memo for the settlement (up to 32 bytes of text, or 0x-prefixed
+    /// bytes32)
+    #[arg(long, value_parser = parse_memo)]
+    memo: Option<B256>,
+    /// Signer nonce; the authorization settles at most once
+    #[arg(long)]
+    nonce: U256,
+    /// Unix time from which the authorization can no longer be submitted
+    #[arg(long)]
+    expiry: u64,
+}
+
+/// Reference a settlement or dispute is logged with
+#[derive(Args)]
+struct MemoArg {
+    /// Invoice, PO or ticket reference (up to 32 bytes of text, or
+    /// 0x-prefixed bytes32)
+    #[arg(long, value_parser = parse_memo)]
+    memo: Option<B256>,
+}
+
+impl MemoArg {
+    /// The memo, or zero for none
+    fn value(&self) -> B256 {
+        self.memo.unwrap_or_default()
+    }
+}
+
+/// Terms of a signed dispute ruling
+#[derive(Args)]
+struct VerdictTerms {
+    /// Job ID
+    #[arg(long)]
+    job_id: U256,
+    /// Freelancer's share of the job in basis points; the client gets the
+    /// rest
+    #[arg(long)]
+    freelancer_bps: u16,
+    /// Arbitrator nonce, shared with their signed settlements
+    #[arg(long)]
+    nonce: U256,
+    /// Unix time from which the verdict can no longer be submitted
+    #[arg(long)]
+    expiry: u64,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Deposit ETH for a new job
+    Deposit {
+        /// Freelancer address
+        #[arg(long)]
+        freelancer: Address,
+        /// Job duration in seconds
+        #[arg(long)]
+        duration: u64,
+        /// Amount in ETH (e.g. 0.1)
+        #[arg(long)]
+        amount: String,
+        /// Invoice or PO reference (up to 32 bytes of text, or 0x-prefixed bytes32)
+        #[arg(long, value_parser = parse_memo)]
+        memo: Option<B256>,
+        /// Idempotency nonce; a retry with the same nonce cannot open a second job
+        #[arg(long, conflicts_with = "memo")]
+        nonce: Option<U256>,
+        /// UID of the freelancer's EAS attestation; the deposit fails unless it
+        /// verifies them
+        #[arg(long, conflicts_with_all = ["memo", "nonce"])]
+        attestation: Option<B256>,
+        /// Contract whose canRelease(job_id) must return true before the
+        /// freelancer can auto-release
+        #[arg(long, conflicts_with_all = ["memo", "nonce", "attestation"])]
+        condition: Option<Address>,
+        /// Seconds of notice the client must give before a refund; the
+        /// freelancer can deliver in that time to keep the job
+        #[arg(long, conflicts_with_all = ["memo", "nonce", "attestation", "condition"])]
+        notice: Option<u64>,
+        /// Release only once both the client and the freelancer approve it
+        #[arg(
+            long,
+            conflicts_with_all = ["memo", "nonce", "attestation", "condition", "notice"]
+        )]
+        dual_approval: bool,
+        /// Marketplace that takes `--platform-bps` of every payout to the
+        /// freelancer
+        #[arg(
+            long,
+            requires = "platform_bps",
+            conflicts_with_all = ["nonce", "attestation", "condition", "notice", "dual_approval"]
+        )]
+        platform: Option<Address>,
+        /// Platform fee in basis points
+        #[arg(long, requires = "platform")]
+        platform_bps: Option<u16>,
+        /// Basis points of the job held back for `--warranty` seconds after
+        /// release, during which the client can still dispute defects
+        #[arg(
+            long,
+            requires = "warranty",
+            conflicts_with_all = [
+                "memo",
+                "nonce",
+                "attestation",
+                "condition",
+                "notice",
+                "dual_approval",
+                "platform"
+            ]
+        )]
+        holdback_bps: Option<u16>,
+        /// Warranty window in seconds
+        #[arg(long, requires = "holdback_bps")]
+        warranty: Option<u64>,
+        /// Seconds a release waits before paying out, during which the
+        /// arbitrator can reverse it
+        #[arg(
+            long,
+            conflicts_with_all = [
+                "memo",
+                "nonce",
+                "attestation",
+                "condition",
+                "notice",
+                "dual_approval",
+                "platform",
+                "holdback_bps"
+            ]
+        )]
+        clawback_window: Option<u64>,
+        /// Agreed delivery date (unix seconds); releasing the job refunds
+        /// `--late-bps-per-day` of it for every full day it is delivered late
+        #[arg(
+            long,
+            requires = "late_bps_per_day",
+            conflicts_with_all = [
+                "memo",
+                "nonce",
+                "attestation",
+                "condition",
+                "notice",
+                "dual_approval",
+                "platform",
+                "holdback_bps",
+                "clawback_window"
+            ]
+        )]
+        delivery_by: Option<u64>,
+        /// Basis points of the job refunded per full day late
+        #[arg(long, requires = "delivery_by")]
+        late_bps_per_day: Option<u16>,
+        /// Category number to file the job under, e.g. the marketplace's id
+        /// for design or development work
+        #[arg(
+            long,
+            conflicts_with_all = [
+                "nonce",
+                "attestation",
+                "condition",
+                "notice",
+                "dual_approval",
+                "platform",
+                "holdback_bps",
+                "clawback_window",
+                "delivery_by"
+            ]
+        )]
+        category: Option<u16>,
+        /// Tag narrowing down the category (up to 32 bytes of text, or
+        /// 0x-prefixed bytes32)
+        #[arg(long, value_parser = parse_memo, requires = "category")]
+        tag: Option<B256>,
+        /// Arbitrator who rules on the job's disputes instead of the global
+        /// one, e.g. one shown by `listed-arbitrators`
+        #[arg(
+            long,
+            conflicts_with_all = [
+                "nonce",
+                "attestation",
+                "condition",
+                "notice",
+                "dual_approval",
+                "platform",
+                "holdback_bps",
+                "clawback_window",
+                "delivery_by",
+                "category"
+            ]
+        )]
+        arbitrator: Option<Address>,
+    },
+    /// Deposit from your WETH, unwrapping it to ETH first (two transactions;
+    /// if the deposit fails you keep the ETH)
+    DepositWeth {
+        /// Freelancer address
+        #[arg(long)]
+        freelancer: Address,
+        /// Job duration in seconds
+        #[arg(long)]
+        duration: u64,
+        /// Amount in WETH (e.g. 0.1)
+        #[arg(long)]
+        amount: String,
+        /// Invoice or PO reference (up to 32 bytes of text, or 0x-prefixed bytes32)
+        #[arg(long, value_parser = parse_memo)]
+        memo: Option<B256>,
+    },
+    /// Open a job that becomes active once installments reach its target
+    CreateJob {
+        /// Freelancer address
+        #[arg(long)]
+        freelancer: Address,
+        /// Job duration in seconds, counted from when the target is met
+        #[arg(long)]
+        duration: u64,
+        /// Amount in ETH the job needs
+        #[arg(long)]
+        target: String,
+        /// Unix time after which contributions can be reclaimed if the
+        /// target has not been met
+        #[arg(long)]
+        cutoff: u64,
+        /// Amount in ETH to pay now
+        #[arg(long, default_value = "0")]
+        amount: String,
+    },
+    /// Pay towards a job's funding target
+    Fund {
+        job_id: U256,
+        /// Amount in ETH
+        #[arg(long)]
+        amount: String,
+    },
+    /// Take back your contributions to a job that missed its funding cutoff
+    Reclaim { job_id: U256 },
+    /// As freelancer, bill a client for a job they can pay with pay-invoice
+    CreateInvoice {
+        /// Client address
+        #[arg(long)]
+        client: Address,
+        /// Amount in ETH (e.g. 0.1)
+        #[arg(long)]
+        amount: String,
+        /// Job duration in seconds
+        #[arg(long)]
+        duration: u64,
+        /// Invoice reference, echoed as the job's memo (up to 32 bytes of
+        /// text, or 0x-prefixed bytes32)
+        #[arg(long, value_parser = parse_memo)]
+        metadata: Option<B256>,
+        /// Unix time from which the invoice can no longer be paid
+        #[arg(long)]
+        expiry: u64,
+    },
+    /// As the invoiced client, pay an invoice in full, opening its job
+    PayInvoice { invoice_id: U256 },
+    /// Show an invoice
+    Invoice { invoice_id: U256 },
+    /// Clear expired, unpaid invoices from storage
+    PruneInvoices {
+        #[arg(required = true)]
+        invoice_ids: Vec<U256>,
+    },
+    /// Withdraw ETH credited to you, such as deposit overpayments
+    WithdrawCredit,
+    /// Show the ETH credited to an address
+    Credit { account: Address },
+    /// Add ETH to your credit, to fund the job offers you sign
+    DepositCredit {
+        /// Amount in ETH
+        #[arg(long)]
+        amount: String,
+    },
+    /// As client, sign a job offer for the freelancer to submit
+    SignOffer {
+        /// Freelancer address
+        #[arg(long)]
+        freelancer: Address,
+        #[command(flatten)]
+        terms: OfferTerms,
+    },
+    /// As freelancer, open the job a client offered; their credit pays for it
+    AcceptOffer {
+        /// Client who signed the offer
+        #[arg(long)]
+        client: Address,
+        #[command(flatten)]
+        terms: OfferTerms,
+        /// Client's signature from sign-offer
+        #[arg(long)]
+        signature: Bytes,
+    },
+    /// Withdraw a job offer you signed before it is accepted
+    CancelOffer {
+        /// Nonce of the offer
+        #[arg(long)]
+        nonce: U256,
+    },
+    /// As a party, sign a release or refund for anyone to submit
+    SignSettlement {
+        #[command(flatten)]
+        terms: SettlementTerms,
+    },
+    /// Submit a release or refund a party signed; smart-account signers are
+    /// checked under ERC-1271
+    SubmitSettlement {
+        /// Party who signed the settlement
+        #[arg(long)]
+        signer: Address,
+        #[command(flatten)]
+        terms: SettlementTerms,
+        /// Signer's signature from sign-settlement, or their smart account's
+        #[arg(long)]
+        signature: Bytes,
+    },
+    /// Withdraw a release, refund or verdict you signed before it is submitted
+    CancelAuthorization {
+        /// Nonce of the authorization
+        #[arg(long)]
+        nonce: U256,
+    },
+    /// Deposit ETH for a job paid in tranches that unlock over time
+    DepositTranched {
+        /// Freelancer address
+        #[arg(long)]
+        freelancer: Address,
+        /// A tranche as AMOUNT@UNIX_TIME (e.g. 0.3@1735689600), in unlock
+        /// order; repeat for each tranche
+        #[arg(long = "tranche", value_parser = parse_tranche, required = true)]
+        tranches: Vec<(U256, u64)>,
+    },
+    /// Fund a retainer paid out in equal parts at the end of each period
+    DepositRetainer {
+        /// Freelancer address
+        #[arg(long)]
+        freelancer: Address,
+        /// Number of periods to fund up front
+        #[arg(long)]
+        periods: u64,
+        /// Period length in seconds
+        #[arg(long)]
+        period: u64,
+        /// Seconds of notice needed to stop future periods
+        #[arg(long, default_value_t = 0)]
+        notice: u64,
+        /// Total amount in ETH, split evenly across the periods
+        #[arg(long)]
+        amount: String,
+    },
+    /// Fund a job with ETH and admin-approved ERC-20s; approve the escrow to
+    /// pull each token first
+    DepositBasket {
+        /// Freelancer address
+        #[arg(long)]
+        freelancer: Address,
+        /// Job duration in seconds
+        #[arg(long)]
+        duration: u64,
+        /// ETH part of the basket
+        #[arg(long)]
+        amount: String,
+        /// A token as TOKEN:AMOUNT in its base units (e.g.
+        /// 0xaf88...5831:500000000); repeat for each token
+        #[arg(long = "token", value_parser = parse_basket_token, required = true)]
+        tokens: Vec<(Address, U256)>,
+    },
+    /// Fund the budget of an hourly engagement billed through timesheets
+    DepositHourly {
+        /// Freelancer address
+        #[arg(long)]
+        freelancer: Address,
+        /// Engagement length in seconds; the unspent budget can be taken
+        /// back after it
+        #[arg(long)]
+        duration: u64,
+        /// Hourly rate in ETH
+        #[arg(long)]
+        rate: String,
+        /// Budget in ETH
+        #[arg(long)]
+        amount: String,
+    },
+    /// Deposit ETH for a private job, storing only a commitment to its details
+    DepositPrivate {
+        /// Freelancer address
+        #[arg(long)]
+        freelancer: Address,
+        /// Job duration in seconds
+        #[arg(long)]
+        duration: u64,
+        /// Amount in ETH the freelancer is owed
+        #[arg(long)]
+        amount: String,
+        /// Amount in ETH to escrow, at least --amount, to hide the rate;
+        /// the excess is refunded on reveal
+        #[arg(long)]
+        escrow: Option<String>,
+        /// Hash of the agreed terms
+        #[arg(long)]
+        terms: B256,
+        /// Secret salt; keep it, it is needed to reveal the job
+        #[arg(long)]
+        salt: B256,
+    },
+    /// Reveal a private job's details so it can be settled
+    Reveal {
+        job_id: U256,
+        /// Freelancer address
+        #[arg(long)]
+        freelancer: Address,
+        /// Committed amount in ETH
+        #[arg(long)]
+        amount: String,
+        /// Hash of the agreed terms
+        #[arg(long)]
+        terms: B256,
+        /// Salt the job was committed with
+        #[arg(long)]
+        salt: B256,
+    },
+    /// Have a contract called back when a job settles (client)
+    SettlementHook {
+        job_id: U256,
+        /// Contract implementing onReleased/onRefunded; the zero address detaches it
+        hook: Address,
+    },
+    /// Release a job's funds to the freelancer
+    Release {
+        job_id: U256,
+        #[command(flatten)]
+        memo: MemoArg,
+    },
+    /// Release part of a job's funds, leaving the rest in escrow
+    ReleasePartial {
+        job_id: U256,
+        /// Amount in ETH
+        #[arg(long)]
+        amount: String,
+    },
+    /// Refund a job before its deadline
+    Refund {
+        job_id: U256,
+        #[command(flatten)]
+        memo: MemoArg,
+    },
+    /// Ask to cancel a job opened with a notice period (client)
+    RequestCancellation { job_id: U256 },
+    /// Deliver during a cancellation's notice period to keep the job, or to stop
+    /// an SLA's late-refund clock (freelancer)
+    Deliver {
+        job_id: U256,
+        /// Hash or reference of the delivered work
+        delivery: B256,
+    },
+    /// Push a job's deadline back to a later unix time (freelancer)
+    ExtendDeadline { job_id: U256, deadline: u64 },
+    /// Offer control of a job to another address, which takes over once it
+    /// accepts; the zero address withdraws the offer (client)
+    TransferClient { job_id: U256, new_client: Address },
+    /// Take over a job whose client role was offered to you
+    AcceptClient { job_id: U256 },
+    /// Commit to a job, after which the client can no longer reassign it
+    /// (freelancer)
+    AcceptJob { job_id: U256 },
+    /// Hand a job nobody has accepted yet to another freelancer (client)
+    ReassignFreelancer {
+        job_id: U256,
+        new_freelancer: Address,
+    },
+    /// Open a dispute over a job (either party)
+    Dispute {
+        job_id: U256,
+        #[command(flatten)]
+        memo: MemoArg,
+    },
+    /// Add evidence to a job's open dispute (either party)
+    Evidence {
+        job_id: U256,
+        /// Hash of the evidence document
+        evidence_hash: B256,
+    },
+    /// Settle a disputed job, giving the freelancer this share in basis
+    /// points and the client the rest (arbitrator)
+    Rule { job_id: U256, freelancer_bps: u16 },
+    /// As the arbitrator, sign a ruling for anyone to submit
+    SignVerdict {
+        #[command(flatten)]
+        terms: VerdictTerms,
+    },
+    /// Submit a ruling the arbitrator signed; multisig arbitrators such as
+    /// a Safe are checked under ERC-1271
+    SubmitVerdict {
+        #[command(flatten)]
+        terms: VerdictTerms,
+        /// Arbitrator's signature from sign-verdict, or their multisig's
+        #[arg(long)]
+        signature: Bytes,
+    },
+    /// Apply a dispute's timeout rule once it has run out (anyone)
+    TimeOutDispute { job_id: U256 },
+    /// Put an open dispute to a panel of jurors instead of the arbitrator
+    /// (either party)
+    EscalateToJury { job_id: U256 },
+    /// Vote the freelancer's share of a disputed job in basis points
+    /// (juror on its panel)
+    JuryVote { job_id: U256, freelancer_bps: u16 },
+    /// Count a jury's votes once voting closes, settling the dispute (anyone)
+    TallyJury { job_id: U256 },
+    /// Join the juror registry, or add to your stake
+    JoinJury {
+        /// Stake in ETH
+        #[arg(long)]
+        amount: String,
+    },
+    /// Leave the juror registry, withdrawing your stake
+    LeaveJury,
+    /// Show a juror's stake and how many juries they sit on
+    Juror { juror: Address },
+    /// Settle a dispute at its default split after the ruling period (either party)
+    ResolveByDefault { job_id: U256 },
+    /// Appeal a held ruling to governance, proposing the freelancer's share
+    /// in basis points instead and posting the appeal bond (either party)
+    Appeal { job_id: U256, freelancer_bps: u16 },
+    /// Settle a job at its held ruling once it can no longer be appealed
+    /// (anyone)
+    FinalizeRuling { job_id: U256 },
+    /// Rate the other party to a settled job from 1 to 5 stars (either party)
+    Rate { job_id: U256, stars: u8 },
+    /// Claim a job's funds after its deadline (freelancer)
+    AutoRelease {
+        job_id: U256,
+        #[command(flatten)]
+        memo: MemoArg,
+    },
+    /// Pay a job's holdback to the freelancer once its warranty has ended
+    ReleaseHoldback { job_id: U256 },
+    /// Pay out a queued release once its clawback window is over
+    FinalizeRelease { job_id: U256 },
+    /// Reverse a queued release during its clawback window (arbitrator)
+    ReverseRelease { job_id: U256 },
+    /// Approve releasing a dual-approval job; the second approval pays the
+    /// freelancer (client or freelancer)
+    ApproveRelease { job_id: U256 },
+    /// Consent to holding a job months from its deadline in the staking
+    /// token; the other party's consent to the same split stakes it (client
+    /// or freelancer)
+    ConsentToStake {
+        job_id: U256,
+        /// Basis points of the yield going to the freelancer
+        freelancer_yield_bps: u16,
+        /// Basis points of the yield going to the treasury; the client gets
+        /// the rest
+        #[arg(long, default_value_t = 0)]
+        treasury_yield_bps: u16,
+    },
+    /// Buy a staked job's shares back so it settles in ETH again (anyone)
+    UnstakeJob {
+        job_id: U256,
+        /// ETH to pay, at least the shares' value less the slippage bound
+        #[arg(long)]
+        amount: String,
+    },
+    /// Give up a job, refunding the client (freelancer)
+    ReturnFunds {
+        job_id: U256,
+        #[command(flatten)]
+        memo: MemoArg,
+    },
+    /// Return a job's funds to its client once they have gone unclaimed for
+    /// the claim window after the deadline (anyone)
+    ReclaimUnclaimed {
+        job_id: U256,
+        #[command(flatten)]
+        memo: MemoArg,
+    },
+    /// Claim the emergency refund listed for a job in the published Merkle
+    /// root (client)
+    ClaimEmergencyRefund {
+        job_id: U256,
+        /// Amount in ETH listed for the job
+        #[arg(long)]
+        amount: String,
+        /// Sibling hashes from the job's leaf up to the root; repeat for
+        /// each level
+        #[arg(long = "proof")]
+        proof: Vec<B256>,
+    },
+    /// Claim an unlocked tranche of a job (freelancer)
+    ClaimTranche { job_id: U256, index: U256 },
+    /// Take back a tranche of a job before it unlocks (client)
+    RefundTranche { job_id: U256, index: U256 },
+    /// Claim every unlocked tranche or retainer period of a job (freelancer)
+    ClaimUnlocked { job_id: U256 },
+    /// Stop a retainer, refunding periods past the notice period (client)
+    StopRetainer { job_id: U256 },
+    /// Bill hours worked on an hourly engagement (freelancer)
+    SubmitTimesheet {
+        job_id: U256,
+        #[arg(long)]
+        hours: u32,
+        /// Hash or reference of the timesheet
+        #[arg(long, default_value_t = B256::ZERO)]
+        details: B256,
+    },
+    /// Approve a timesheet, paying it out of the budget (client)
+    ApproveTimesheet { job_id: U256, index: U256 },
+    /// Reject a timesheet (client)
+    RejectTimesheet { job_id: U256, index: U256 },
+    /// End an hourly engagement after its deadline, taking back the unspent
+    /// budget (client)
+    EndEngagement { job_id: U256 },
+    /// Limit how many active jobs you take on at once (freelancer); 0 removes
+    /// your own limit
+    SetJobCap { cap: u32 },
+    /// Show a freelancer's job cap and active jobs
+    JobCap { freelancer: Address },
+    /// Show the arbitrators clients can pick for their jobs
+    ListedArbitrators,
+    /// Choose the token your payouts are swapped into (freelancer)
+    PayoutToken {
+        /// ERC-20 to receive; the zero address switches back to ETH
+        token: Address,
+        /// Minimum token units per ETH, in the token's smallest unit
+        #[arg(long, default_value_t = U256::ZERO)]
+        min_rate: U256,
+    },
+    /// Take your payouts as WETH, wrapped 1:1 without a swap (freelancer)
+    WrappedPayout {
+        /// Switch back to ETH
+        #[arg(long)]
+        off: bool,
+    },
+    /// Have your payouts sent to another address, such as a cold wallet,
+    /// after a delay (freelancer); the zero address goes back to direct payment
+    SetPayoutAddress { payout_address: Address },
+    /// Make a freelancer's proposed payout address current once its delay
+    /// has passed (anyone)
+    ApplyPayoutAddress { freelancer: Address },
+    /// Show where a freelancer's payouts are sent
+    PayoutAddress { freelancer: Address },
+    /// Withhold a share of your payouts for tax (freelancer)
+    Withholding {
+        /// Withheld share in basis points (e.g. 2400 for 24%); 0 turns it off
+        rate_bps: u16,
+        /// Where withheld amounts are sent
+        #[arg(long, default_value_t = Address::ZERO)]
+        destination: Address,
+    },
+    /// Show a single job
+    Job { job_id: U256 },
+    /// Quote the fees on a hypothetical job
+    Quote {
+        /// Amount in ETH (e.g. 0.1)
+        #[arg(long)]
+        amount: String,
+        /// Client opening the job, for their volume discount or exemption
+        #[arg(long)]
+        client: Option<Address>,
+        /// Marketplace the job would be opened through
+        #[arg(long, requires = "platform_bps")]
+        platform: Option<Address>,
+        /// Platform fee in basis points
+        #[arg(long, requires = "platform")]
+        platform_bps: Option<u16>,
+    },
+    /// Show what settling a job now would pay each party
+    Preview {
+        job_id: U256,
+        /// Preview a refund instead of a release
+        #[arg(long)]
+        refund: bool,
+    },
+    /// List jobs (active only unless --all or --status)
+    Jobs {
+        #[arg(long, conflicts_with = "status")]
+        all: bool,
+        /// Only jobs with this status: active, released, refunded or swept
+        #[arg(long, value_parser = parse_job_status)]
+        status: Option<JobStatus>,
+        /// Only jobs this address is currently client or freelancer of
+        #[arg(long)]
+        party: Option<Address>,
+        /// Only jobs filed under this category
+        #[arg(long, conflicts_with = "party")]
+        category: Option<u16>,
+        /// Only jobs with this tag within `--category`
+        #[arg(long, value_parser = parse_memo, requires = "category")]
+        tag: Option<B256>,
+    },
+    /// List active jobs whose deadline passes before a timestamp
+    Expiring {
+        /// Unix timestamp
+        #[arg(long)]
+        before: u64,
+    },
+    /// Show the settlement the Gelato resolver would send next, if any
+    Resolver {
+        /// Position in the active-job set to check from
+        #[arg(long, default_value_t = U256::ZERO)]
+        cursor: U256,
+    },
+    /// Show protocol stats, an address's stats with --user, or one day's
+    /// activity with --epoch
+    Stats {
+        #[arg(long)]
+        user: Option<Address>,
+        /// Day number, counted from the Unix epoch
+        #[arg(long, conflicts_with = "user")]
+        epoch: Option<u64>,
+    },
+    /// Admin-only operations
+    #[command(subcommand)]
+    Admin(AdminCommand),
+}
+
+#[derive(Subcommand)]
+enum AdminCommand {
+    /// Pause the escrow (admin or pauser)
+    Pause,
+    /// Unpause the escrow
+    Unpause,
+    /// Let an account, e.g. an incident-response bot, pause but not unpause
+    /// the escrow; the zero address removes it
+    SetPauser { pauser: Address },
+    /// Cap how long a new pause may last, in seconds; 0 removes the cap
+    SetMaxPause { duration: u64 },
+    /// Clear a pause that has outlived its end (anyone)
+    UnpauseExpired,
+    /// Transfer admin rights
+    TransferOwnership { new_admin: Address },
+    /// Start the 7-day countdown to giving up the admin role; needs no
+    /// pause, pending treasury, fee manager or pauser
+    ProposeRenounce,
+    /// Permanently remove the admin once the countdown is over
+    RenounceOwnership,
+    /// Force refund a job to its client once it is 14 days old or 3 days
+    /// past its deadline
+    EmergencyRefund { job_id: U256 },
+    /// Force refund up to 50 jobs at once, skipping those that can't be
+    EmergencyRefundBatch {
+        #[arg(required = true)]
+        job_ids: Vec<U256>,
+    },
+    /// Publish the Merkle root of (job ID, amount) entries clients claim
+    /// emergency refunds against; the zero root stops claims
+    SetRefundRoot { root: B256 },
+    /// Close deposits for good from a unix timestamp on
+    BeginSunset { timestamp: u64 },
+    /// Retire the escrow once the sunset is reached and all jobs are settled
+    FinalizeSunset,
+    /// Sweep jobs unsettled this many seconds past their deadline to a recovery address
+    SetDormancyPolicy { period: u64, recovery: Address },
+    /// Announce a dormant job, starting its notice period
+    AnnounceDormant { job_id: U256 },
+    /// Sweep an announced dormant job once its notice period is over
+    SweepDormant { job_id: U256 },
+    /// Propose the address that receives fees and sweeps; it takes effect
+    /// after a 7-day delay
+    ProposeTreasury { treasury: Address },
+    /// Make the proposed treasury current once its delay is over
+    ApplyTreasury,
+    /// Send the balance beyond what the escrow owes to the treasury
+    SweepSurplus,
+    /// Require `threshold` of the guardians to confirm emergency actions; no
+    /// guardians and threshold 0 hand emergencies back to the admin
+    SetGuardians {
+        threshold: u8,
+        #[arg(long = "guardian")]
+        guardians: Vec<Address>,
+    },
+    /// Propose and confirm an emergency action: 0 refunds the job `target`,
+    /// 1 sweeps surplus, 2 keeps the pause until timestamp `target`
+    /// (guardian only)
+    ProposeEmergency { action: u8, target: U256 },
+    /// Confirm an emergency proposal (guardian only)
+    ConfirmEmergency { proposal_id: U256 },
+    /// Hand fees, caps, arbitration and grace periods to governance: a
+    /// council with one vote each, or a votes token (once)
+    SetGovernance {
+        /// Votes needed for a proposal to pass
+        quorum: U256,
+        /// Token whose delegated votes count, instead of a council
+        #[arg(long, default_value_t = Address::ZERO)]
+        token: Address,
+        #[arg(long = "member")]
+        council: Vec<Address>,
+    },
+    /// Propose a governance action: 0 protocol fee, 1 default job cap,
+    /// 2 arbitrator `--target`, 3 ruling period, 4 dispute timeout,
+    /// 5 dormancy period, 6/7 add/remove council member `--target`, 8 quorum,
+    /// 9 emergency refund root, 11 hand job `--value`'s dispute to arbitrator
+    /// `--target` once its arbitrator missed the ruling deadline,
+    /// 12 governed setting `--value` with its ABI-encoded `--data`,
+    /// 13 treasury `--target`
+    /// (council member or token holder)
+    ProposeGovernance {
+        action: u8,
+        #[arg(long, default_value_t = Address::ZERO)]
+        target: Address,
+        #[arg(long, default_value_t = U256::ZERO)]
+        value: U256,
+        #[arg(long, default_value = "0x")]
+        data: Bytes,
+    },
+    /// Vote on a governance proposal (council member or token holder)
+    VoteGovernance {
+        proposal_id: U256,
+        /// Vote against instead of for
+        #[arg(long)]
+        against: bool,
+    },
+    /// Carry out a passed governance proposal once its timelock is over
+    ExecuteGovernance { proposal_id: U256 },
+    /// Approve the router that swaps payouts; the zero address disables swaps
+    SetSwapRouter { router: Address, weth: Address },
+    /// Set the liquid staking token long jobs can be held in and the most
+    /// basis points below their value staked shares can be bought back at;
+    /// the zero address stops new stakes
+    SetStakingToken {
+        token: Address,
+        max_slippage_bps: u16,
+    },
+    /// Screen deposits and releases with a compliance contract; the zero
+    /// address turns screening off
+    SetComplianceHook { hook: Address },
+    /// Limit how many active jobs any freelancer may hold; 0 removes the limit
+    SetDefaultJobCap { cap: u32 },
+    /// Make each client wait COOLDOWN seconds between the jobs it opens and
+    /// open at most PER_BLOCK in one block; 0 turns either off
+    SetDepositLimits { cooldown: u64, per_block: u32 },
+    /// Exempt an address, such as a marketplace, from the deposit limits
+    SetDepositLimitExempt {
+        account: Address,
+        /// End the exemption instead
+        #[arg(long)]
+        revoke: bool,
+    },
+    /// Hand fee configuration to another account; the zero address gives it
+    /// back to the admin
+    SetFeeManager { manager: Address },
+    /// Send accrued protocol fees to an address (fee manager only)
+    WithdrawFees {
+        to: Address,
+        /// Amount in ETH
+        amount: String,
+    },
+    /// Charge a protocol fee on payouts, in basis points (at most 1000)
+    SetProtocolFee { fee_bps: u16 },
+    /// Rebate up to this much ETH of the fee to whoever sends auto-release,
+    /// release-holdback or finalize-release; 0 turns rebates off
+    SetSettlementRebate { amount: String },
+    /// Replace the fee's size tiers; no tiers leaves only the base fee
+    SetFeeTiers {
+        /// A tier as MIN_AMOUNT@BPS (e.g. 10@100), smallest first; repeat
+        /// for each tier
+        #[arg(long = "tier", value_parser = parse_fee_tier)]
+        tiers: Vec<FeeTier>,
+    },
+    /// Replace the fee discounts clients earn with released volume
+    SetVolumeDiscounts {
+        /// A discount as MIN_VOLUME@BPS (e.g. 100@2000), smallest first;
+        /// repeat for each discount
+        #[arg(long = "discount", value_parser = parse_volume_discount)]
+        discounts: Vec<VolumeDiscount>,
+    },
+    /// Let new jobs be funded with an ERC-20 in a basket
+    SetBasketToken {
+        token: Address,
+        /// Stop accepting it instead
+        #[arg(long)]
+        revoke: bool,
+    },
+    /// List an arbitrator clients can pick for their jobs
+    SetArbitratorListing {
+        arbitrator: Address,
+        /// Unlist it instead
+        #[arg(long)]
+        unlist: bool,
+    },
+    /// Exempt a partner's new jobs from the protocol fee
+    SetFeeExempt {
+        account: Address,
+        /// End the exemption instead
+        #[arg(long)]
+        revoke: bool,
+    },
+    /// Accept attestations from `attester` under `schema` on the EAS contract
+    /// `eas` as proof a freelancer is verified; a zero `eas` disables them
+    /// Appoint the arbitrator for new disputes, with the seconds they have to
+    /// rule and the freelancer's share in basis points if they don't; the
+    /// zero address leaves new disputes without one
+    SetArbitrationPolicy {
+        arbitrator: Address,
+        ruling_period: u64,
+        default_split_bps: u16,
+    },
+    /// Cap how long new disputes may stay unresolved (0 for no limit); past
+    /// it they are split giving the freelancer `--split` basis points, or
+    /// lifted so the job settles by its deadline if `--split` is omitted
+    SetDisputeTimeoutPolicy {
+        timeout: u64,
+        #[arg(long)]
+        split: Option<u16>,
+    },
+    /// Make whoever opens a dispute stake this share of the job in basis
+    /// points (at most 500), but at least `min_stake` ETH
+    SetDisputeStakePolicy {
+        stake_bps: u16,
+        /// Minimum stake in ETH
+        min_stake: String,
+    },
+    /// Seat `size` jurors (odd, at most 15; 0 turns juries off) on escalated
+    /// disputes, voting for `voting_period` seconds, each staked at least
+    /// `min_stake` ETH and losing `slash_bps` of it outside the majority
+    SetJuryPolicy {
+        size: u8,
+        voting_period: u64,
+        /// Minimum juror stake in ETH
+        min_stake: String,
+        slash_bps: u16,
+    },
+    /// Hold rulings `window` seconds for appeal to governance (0 settles
+    /// them at once), taking a bond of `bond_bps` of the job (at most
+    /// 2000), but at least `min_bond` ETH
+    SetAppealPolicy {
+        window: u64,
+        bond_bps: u16,
+        /// Minimum bond in ETH
+        min_bond: String,
+    },
+    SetAttestationPolicy {
+        eas: Address,
+        schema: B256,
+        attester: Address,
+    },
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    dotenv::dotenv().ok();
+    let cli = Cli::parse();
+    let (provider, sender) = connect(&cli.rpc_url, &cli.signer)?;
+    let client = EscrowClient::new(cli.contract, provider);
+
+    match cli.command {
+        Command::Deposit {
+            freelancer,
+            duration,
+            amount,
+            memo,
+            nonce,
+            attestation,
+            condition,
+            notice,
+            dual_approval,
+            platform,
+            platform_bps,
+            holdback_bps,
+            warranty,
+            clawback_window,
+            delivery_by,
+            late_bps_per_day,
+            category,
+            tag,
+            arbitrator,
+        } => {
+            let amount = parse_ether(&amount)?;
+            let job_id = match (nonce, attestation, condition, notice, dual_approval) {
+                (Some(nonce), _, _, _, _) => {
+                    client
+                        .deposit_with_nonce(freelancer, duration, amount, nonce)
+                        .await?
+                }
+                (None, Some(uid), _, _, _) => {
+                    client
+                        .deposit_attested(freelancer, duration, amount, uid)
+                        .await?
+                }
+                (None, None, Some(condition), _, _) => {
+                    client
+                        .deposit_with_condition(freelancer, duration, amount, condition)
+                        .await?
+                }
+                (None, None, None, Some(notice), _) => {
+                    client
+                        .deposit_with_notice(freelancer, duration, amount, notice)
+                        .await?
+                }
+                (None, None, None, None, true) => {
+                    client
+                        .deposit_with_dual_approval(freelancer, duration, amount)
+                        .await?
+                }
+                (None, None, None, None, false) => {
+                    let memo = memo.unwrap_or_default();
+                    match (
+                        platform,
+                        platform_bps,
+                        holdback_bps,
+                        warranty,
+                        clawback_window,
+                        (delivery_by, late_bps_per_day),
+                    ) {
+                        (Some(platform), Some(platform_bps), _, _, _, _) => {
+                            client
+                                .deposit_with_platform(
+                                    freelancer,
+                                    duration,
+                                    amount,
+                                    memo,
+                                    platform,
+                                    platform_bps,
+                                )
+                                .await?
+                        }
+                        (_, _, Some(holdback_bps), Some(warranty), _, _) => {
+                            client
+                                .deposit_with_holdback(
+                                    freelancer,
+                                    duration,
+                                    amount,
+                                    holdback_bps,
+                                    warranty,
+                                )
+                                .await?
+                        }
+                        (_, _, _, _, Some(window), _) => {
+                            client
+                                .deposit_with_clawback(freelancer, duration, amount, window)
+                                .await?
+                        }
+                        (_, _, _, _, _, (Some(delivery_by), Some(late_bps_per_day))) => {
+                            client
+                                .deposit_with_sla(
+                                    freelancer,
+                                    duration,
+                                    amount,
+                                    delivery_by,
+                                    late_bps_per_day,
+                                )
+                                .await?
+                        }
+                        _ => match (category, arbitrator) {
+                            (Some(category), _) => {
+                                client
+                                    .deposit_categorized(
+                                        freelancer,
+                                        duration,
+                                        amount,
+                                        memo,
+                                        category,
+                                        tag.unwrap_or_default(),
+                                    )
+                                    .await?
+                            }
+                            (None, Some(arbitrator)) => {
+                                client
+                                    .deposit_with_arbitrator(
+                                        freelancer, duration, amount, memo, arbitrator,
+                                    )
+                                    .await?
+                            }
+                            (None, None) => {
+                                client
+                                    .deposit_with_memo(freelancer, duration, amount, memo)
+                                    .await?
+                            }
+                        },
+                    }
+                }
+            };
+            println!("Created job {job_id}");
+            print_job(&client.get_job(job_id).await?);
+        }
+        Command::DepositWeth {
+            freelancer,
+            duration,
+            amount,
+            memo,
+        } => {
+            let amount = parse_ether(&amount)?;
+            let memo = memo.unwrap_or_default();
+            let job_id = client
+                .deposit_weth(freelancer, duration, amount, memo)
+                .await?;
+            println!("Created job {job_id}");
+            print_job(&client.get_job(job_id).await?);
+        }
+        Command::CreateJob {
+            freelancer,
+            duration,
+            target,
+            cutoff,
+            amount,
+        } => {
+            let target = parse_ether(&target)?;
+            let amount = parse_ether(&amount)?;
+            let job_id = client
+                .create_job(freelancer, duration, target, cutoff, amount)
+                .await?;
+            println!("Created job {job_id}, raising {} ETH", format_ether(target));
+        }
+        Command::Fund { job_id, amount } => {
+            let amount = parse_ether(&amount)?;
+            print_receipt(&client.fund(job_id, amount).await?)
+        }
+        Command::Reclaim { job_id } => print_receipt(&client.reclaim_contribution(job_id).await?),
+        Command::CreateInvoice {
+            client: invoiced,
+            amount,
+            duration,
+            metadata,
+            expiry,
+        } => {
+            let amount = parse_ether(&amount)?;
+            let metadata = metadata.unwrap_or_default();
+            let invoice_id = client
+                .create_invoice(invoiced, amount, duration, metadata, expiry)
+                .await?;
+            println!("Created invoice {invoice_id}");
+        }
+        Command::PayInvoice { invoice_id } => {
+            let invoice = client
+                .get_invoice(invoice_id)
+                .await?
+                .ok_or_else(|| eyre!("invoice {invoice_id} not found"))?;
+            let job_id = client.fund_invoice(invoice_id, invoice.amount).await?;
+            println!("Created job {job_id}");
+            print_job(&client.get_job(job_id).await?);
+        }
+        Command::Invoice { invoice_id } => match client.get_invoice(invoice_id).await? {
+            Some(invoice) => {
+                println!(
+                    "Invoice {invoice_id} freelancer={} client={} amount={} ETH duration={}{}",
+                    invoice.freelancer,
+                    invoice.client,
+                    format_ether(invoice.amount),
+                    invoice.duration,
+                    describe_memo(invoice.metadata)
+                );
+                match invoice.job_id {
+                    Some(job_id) => println!("  paid: job {job_id}"),
+                    None => println!("  unpaid, expires {}", invoice.expires_at),
+                }
+            }
+            None => println!("Invoice {invoice_id} not found"),
+        },
+        Command::PruneInvoices { invoice_ids } => {
+            print_receipt(&client.prune_invoices(invoice_ids).await?)
+        }
+        Command::WithdrawCredit => print_receipt(&client.withdraw_credit().await?),
+        Command::Credit { account } => {
+            println!("{} ETH", format_ether(client.get_credit(account).await?))
+        }
+        Command::DepositCredit { amount } => {
+            print_receipt(&client.deposit_credit(parse_ether(&amount)?).await?)
+        }
+        Command::SignOffer { freelancer, terms } => {
+            let signer =
+                load_signer(&cli.signer)?.ok_or_else(|| eyre!("sign-offer needs a signer"))?;
+            let offer = job_offer(signer.address(), freelancer, &terms)?;
+            let signature = signer.sign_hash_sync(&client.offer_hash(&offer).await?)?;
+            println!("Signature: {}", Bytes::from(signature.as_bytes()));
+        }
+        Command::AcceptOffer {
+            client: offer_client,
+            terms,
+            signature,
+        } => {
+            let sender = sender.ok_or_else(|| eyre!("accept-offer needs a signer"))?;
+            let offer = job_offer(offer_client, sender, &terms)?;
+            let job_id = client.accept_offer(&offer, signature).await?;
+            println!("Created job {job_id}");
+            print_job(&client.get_job(job_id).await?);
+        }
+        Command::CancelOffer { nonce } => print_receipt(&client.cancel_offer(nonce).await?),
+        Command::SignSettlement { terms } => {
+            let signer =
+                load_signer(&cli.signer)?.ok_or_else(|| eyre!("sign-settlement needs a signer"))?;
+            let settlement = settlement(signer.address(), &terms);
+            let signature = signer.sign_hash_sync(&client.settlement_hash(&settlement).await?)?;
+            println!("Signature: {}", Bytes::from(signature.as_bytes()));
+        }
+        Command::SubmitSettlement {
+            signer,
+            terms,
+            signature,
+        } => {
+            let settlement = settlement(signer, &terms);
+            print_receipt(&client.submit_settlement(&settlement, signature).await?)
+        }
+        Command::CancelAuthorization { nonce } => {
+            print_receipt(&client.cancel_authorization(nonce).await?)
+        }
+        Command::DepositTranched {
+            freelancer,
+            tranches,
+        } => {
+            let job_id = client.deposit_tranched(freelancer, &tranches).await?;
+            println!("Created tranched job {job_id}");
+            print_job(&client.get_job(job_id).await?);
+        }
+        Command::DepositRetainer {
+            freelancer,
+            periods,
+            period,
+            notice,
+            amount,
+        } => {
+            let amount = parse_ether(&amount)?;
+            let job_id = client
+                .deposit_retainer(freelancer, periods, period, notice, amount)
+                .await?;
+            println!("Created retainer job {job_id}");
+            print_job(&client.get_job(job_id).await?);
+        }
+        Command::DepositBasket {
+            freelancer,
+            duration,
+            amount,
+            tokens,
+        } => {
+            let amount = parse_ether(&amount)?;
+            let (tokens, amounts) = tokens.into_iter().unzip();
+            let job_id = client
+                .deposit_basket(freelancer, duration, amount, tokens, amounts)
+                .await?;
+            println!("Created basket job {job_id}");
+            print_job(&client.get_job(job_id).await?);
+        }
+        Command::DepositHourly {
+            freelancer,
+            duration,
+            rate,
+            amount,
+        } => {
+            let rate = parse_ether(&rate)?;
+            let amount = parse_ether(&amount)?;
+            let job_id = client
+                .deposit_hourly(freelancer, duration, amount, rate)
+                .await?;
+            println!("Created hourly job {job_id}");
+            print_job(&client.get_job(job_id).await?);
+        }
+        Command::DepositPrivate {
+            freelancer,
+            duration,
+            amount,
+            escrow,
+            terms,
+            salt,
+        } => {
+            let sender = sender.ok_or_else(|| eyre!("deposit-private needs a signer"))?;
+            let amount = parse_ether(&amount)?;
+            let escrow = escrow
+                .as_deref()
+                .map(parse_ether)
+                .transpose()?
+                .unwrap_or(amount);
+            if escrow < amount {
+                return Err(eyre!("--escrow must be at least --amount"));
+            }
+            let commitment = job_commitment(sender, freelancer, amount, terms, salt);
+            let job_id = client.deposit_private(commitment, duration, escrow).await?;
+            println!("Created private job {job_id} with commitment {commitment}");
+        }
+        Command::Reveal {
+            job_id,
+            freelancer,
+            amount,
+            terms,
+            salt,
+        } => {
+            let amount = parse_ether(&amount)?;
+            print_receipt(
+                &client
+                    .reveal_job(job_id, freelancer, amount, terms, salt)
+                    .await?,
+            )
+        }
+        Command::SettlementHook { job_id, hook } => {
+            print_receipt(&client.set_settlement_hook(job_id, hook).await?)
+        }
+        Command::Release { job_id, memo } => {
+            print_receipt(&client.release_with_memo(job_id, memo.value()).await?)
+        }
+        Command::ReleasePartial { job_id, amount } => {
+            let amount = parse_ether(&amount)?;
+            print_receipt(&client.release_partial(job_id, amount).await?)
+        }
+        Command::Refund { job_id, memo } => {
+            print_receipt(&client.refund_with_memo(job_id, memo.value()).await?)
+        }
+        Command::RequestCancellation { job_id } => {
+            print_receipt(&client.request_cancellation(job_id).await?)
+        }
+        Command::ExtendDeadline { job_id, deadline } => {
+            print_receipt(&client.extend_deadline(job_id, deadline).await?)
+        }
+        Command::TransferClient { job_id, new_client } => {
+            print_receipt(&client.transfer_client(job_id, new_client).await?)
+        }
+        Command::AcceptClient { job_id } => print_receipt(&client.accept_client(job_id).await?),
+        Command::AcceptJob { job_id } => print_receipt(&client.accept_job(job_id).await?),
+        Command::ReassignFreelancer {
+            job_id,
+            new_freelancer,
+        } => print_receipt(&client.reassign_freelancer(job_id, new_freelancer).await?),
+        Command::Deliver { job_id, delivery } => {
+            print_receipt(&client.submit_delivery(job_id, delivery).await?)
+        }
+        Command::Dispute { job_id, memo } => {
+            print_receipt(&client.open_dispute_with_memo(job_id, memo.value()).await?)
+        }
+        Command::Evidence {
+            job_id,
+            evidence_hash,
+        } => print_receipt(&client.submit_evidence(job_id, evidence_hash).await?),
+        Command::Rule {
+            job_id,
+            freelancer_bps,
+        } => print_receipt(&client.rule(job_id, freelancer_bps).await?),
+        Command::SignVerdict { terms } => {
+            let signer =
+                load_signer(&cli.signer)?.ok_or_else(|| eyre!("sign-verdict needs a signer"))?;
+            let verdict = verdict(&terms);
+            let signature = signer.sign_hash_sync(&client.verdict_hash(&verdict).await?)?;
+            println!("Signature: {}", Bytes::from(signature.as_bytes()));
+        }
+        Command::SubmitVerdict { terms, signature } => {
+            let verdict = verdict(&terms);
+            print_receipt(&client.submit_verdict(&verdict, signature).await?)
+        }
+        Command::TimeOutDispute { job_id } => {
+            print_receipt(&client.time_out_dispute(job_id).await?)
+        }
+        Command::EscalateToJury { job_id } => {
+            print_receipt(&client.escalate_to_jury(job_id).await?);
+            if let Some(jury) = client.get_jury(job_id).await? {
+                println!("Jury votes until {}:", jury.vote_by);
+                jury.panel.iter().for_each(|juror| println!("  {juror}"));
+            }
+        }
+        Command::JuryVote {
+            job_id,
+            freelancer_bps,
+        } => print_receipt(&client.cast_jury_vote(job_id, freelancer_bps).await?),
+        Command::TallyJury { job_id } => print_receipt(&client.tally_jury(job_id).await?),
+        Command::JoinJury { amount } => {
+            print_receipt(&client.join_jury(parse_ether(&amount)?).await?)
+        }
+        Command::LeaveJury => print_receipt(&client.leave_jury().await?),
+        Command::Juror { juror } => {
+            let record = client.get_juror(juror).await?;
+            println!("Stake:      {} ETH", format_ether(record.stake));
+            println!("Registered: {}", record.registered);
+            println!("Juries:     {}", record.panels);
+        }
+        Command::ResolveByDefault { job_id } => {
+            print_receipt(&client.resolve_by_default(job_id).await?)
+        }
+        Command::Appeal {
+            job_id,
+            freelancer_bps,
+        } => {
+            print_receipt(&client.appeal_ruling(job_id, freelancer_bps).await?);
+            if let Some(appeal) = client.get_appeal(job_id).await? {
+                println!("Governance proposal: {}", appeal.proposal_id);
+            }
+        }
+        Command::FinalizeRuling { job_id } => print_receipt(&client.finalize_ruling(job_id).await?),
+        Command::Rate { job_id, stars } => print_receipt(&client.rate_job(job_id, stars).await?),
+        Command::AutoRelease { job_id, memo } => {
+            print_receipt(&client.auto_release_with_memo(job_id, memo.value()).await?)
+        }
+        Command::ReleaseHoldback { job_id } => {
+            print_receipt(&client.release_holdback(job_id).await?)
+        }
+        Command::FinalizeRelease { job_id } => {
+            print_receipt(&client.finalize_release(job_id).await?)
+        }
+        Command::ReverseRelease { job_id } => print_receipt(&client.reverse_release(job_id).await?),
+        Command::ApproveRelease { job_id } => print_receipt(&client.approve_release(job_id).await?),
+        Command::ConsentToStake {
+            job_id,
+            freelancer_yield_bps,
+            treasury_yield_bps,
+        } => print_receipt(
+            &client
+                .consent_to_stake(job_id, freelancer_yield_bps, treasury_yield_bps)
+                .await?,
+        ),
+        Command::UnstakeJob { job_id, amount } => {
+            print_receipt(&client.unstake_job(job_id, parse_ether(&amount)?).await?)
+        }
+        Command::ReturnFunds { job_id, memo } => {
+            print_receipt(&client.return_funds_with_memo(job_id, memo.value()).await?)
+        }
+        Command::ReclaimUnclaimed { job_id, memo } => {
+            print_receipt(&client.reclaim_with_memo(job_id, memo.value()).await?)
+        }
+        Command::ClaimEmergencyRefund {
+            job_id,
+            amount,
+            proof,
+        } => {
+            let amount = parse_ether(&amount)?;
+            print_receipt(&client.claim_emergency_refund(job_id, amount, proof).await?)
+        }
+        Command::ClaimTranche { job_id, index } => {
+            print_receipt(&client.claim_tranche(job_id, index).await?)
+        }
+        Command::RefundTranche { job_id, index } => {
+            print_receipt(&client.refund_tranche(job_id, index).await?)
+        }
+        Command::ClaimUnlocked { job_id } => print_receipt(&client.claim_unlocked(job_id).await?),
+        Command::StopRetainer { job_id } => print_receipt(&client.stop_retainer(job_id).await?),
+        Command::SubmitTimesheet {
+            job_id,
+            hours,
+            details,
+        } => print_receipt(&client.submit_timesheet(job_id, hours, details).await?),
+        Command::ApproveTimesheet { job_id, index } => {
+            print_receipt(&client.approve_timesheet(job_id, index).await?)
+        }
+        Command::RejectTimesheet { job_id, index } => {
+            print_receipt(&client.reject_timesheet(job_id, index).await?)
+        }
+        Command::EndEngagement { job_id } => print_receipt(&client.end_engagement(job_id).await?),
+        Command::SetJobCap { cap } => print_receipt(&client.set_job_cap(cap).await?),
+        Command::JobCap { freelancer } => {
+            let cap = client.get_job_cap(freelancer).await?;
+            match cap.cap {
+                Some(limit) => println!("{}/{limit} active jobs", cap.active),
+                None => println!("{} active jobs (no cap)", cap.active),
+            }
+        }
+        Command::ListedArbitrators => {
+            for arbitrator in client.get_listed_arbitrators().await? {
+                println!("{arbitrator}");
+            }
+        }
+        Command::PayoutToken { token, min_rate } => {
+            print_receipt(&client.set_payout_token(token, min_rate).await?)
+        }
+        Command::WrappedPayout { off } => print_receipt(&client.set_wrapped_payout(!off).await?),
+        Command::SetPayoutAddress { payout_address } => {
+            print_receipt(&client.propose_payout_address(payout_address).await?)
+        }
+        Command::ApplyPayoutAddress { freelancer } => {
+            print_receipt(&client.apply_payout_address(freelancer).await?)
+        }
+        Command::PayoutAddress { freelancer } => {
+            let payout = client.get_payout_address(freelancer).await?;
+            if payout.current == Address::ZERO {
+                println!("Paid to:  {freelancer}");
+            } else {
+                println!("Paid to:  {}", payout.current);
+            }
+            if payout.effective_at != 0 {
+                println!("Pending:  {} from {}", payout.pending, payout.effective_at);
+            }
+        }
+        Command::Withholding {
+            rate_bps,
+            destination,
+        } => print_receipt(&client.set_withholding(rate_bps, destination).await?),
+        Command::Preview { job_id, refund } => {
+            let preview = if refund {
+                client.preview_refund(job_id).await?
+            } else {
+                client.preview_release(job_id).await?
+            };
+            println!("Freelancer: {} ETH", format_ether(preview.freelancer));
+            println!("Withheld:   {} ETH", format_ether(preview.withheld));
+            println!("Client:     {} ETH", format_ether(preview.client));
+            println!("Fee:        {} ETH", format_ether(preview.fee));
+            println!("Platform:   {} ETH", format_ether(preview.platform_fee));
+            println!("Retained:   {} ETH", format_ether(preview.retained));
+        }
+        Command::Quote {
+            amount,
+            client: account,
+            platform,
+            platform_bps,
+        } => {
+            let account = account.unwrap_or(Address::ZERO);
+            let quote = client
+                .quote_fee(
+                    account,
+                    parse_ether(&amount)?,
+                    Address::ZERO,
+                    platform.zip(platform_bps),
+                )
+                .await?;
+            println!("Protocol fee: {} ETH", format_ether(quote.protocol_fee));
+            println!("Platform fee: {} ETH", format_ether(quote.platform_fee));
+            println!("Net payout:   {} ETH", format_ether(quote.net));
+        }
+        Command::Job { job_id } => {
+            print_job(&client.get_job(job_id).await?);
+            if let Some(funding) = client.get_funding(job_id).await? {
+                println!(
+                    "  funding: {}/{} ETH cutoff={}{}",
+                    format_ether(funding.raised),
+                    format_ether(funding.target),
+                    funding.cutoff,
+                    if funding.is_complete() {
+                        " (complete)"
+                    } else {
+                        ""
+                    }
+                );
+            }
+            if let Some(fee) = client.get_platform_fee(job_id).await? {
+                println!("  platform: {} ({} bps)", fee.platform, fee.fee_bps);
+            }
+            if let Some(approval) = client.get_dual_approval(job_id).await? {
+                println!(
+                    "  dual approval: client={} freelancer={}",
+                    approval.client_approved, approval.freelancer_approved
+                );
+            }
+            let stake = client.get_job_stake(job_id).await?;
+            if stake.principal != U256::ZERO {
+                println!(
+                    "  staked: {} ETH as {} shares of {} freelancer_yield={} bps treasury_yield={} bps slippage={} bps",
+                    format_ether(stake.principal),
+                    stake.shares,
+                    stake.token,
+                    stake.freelancer_yield_bps,
+                    stake.treasury_yield_bps,
+                    stake.max_slippage_bps
+                );
+            } else if stake.consented_by != Address::ZERO {
+                println!(
+                    "  stake consent: {} freelancer_yield={} bps treasury_yield={} bps",
+                    stake.consented_by, stake.freelancer_yield_bps, stake.treasury_yield_bps
+                );
+            }
+            if let Some(sla) = client.get_sla(job_id).await? {
+                println!(
+                    "  sla: delivery_by={} late={} bps/day delivered_at={}",
+                    sla.delivery_by, sla.late_bps_per_day, sla.delivered_at
+                );
+            }
+            if let Some(clawback) = client.get_clawback(job_id).await? {
+                println!(
+                    "  clawback: window={}s payable_at={}",
+                    clawback.window, clawback.payable_at
+                );
+            }
+            if let Some(holdback) = client.get_holdback(job_id).await? {
+                println!(
+                    "  holdback: {} bps warranty={}s release_at={}",
+                    holdback.holdback_bps, holdback.warranty, holdback.release_at
+                );
+            }
+            if let Some(cancellation) = client.get_cancellation(job_id).await? {
+                println!(
+                    "  cancellation: notice={}s effective_at={}",
+                    cancellation.notice, cancellation.effective_at
+                );
+            }
+            let pending_client = client.get_pending_client(job_id).await?;
+            if pending_client != Address::ZERO {
+                println!("  client transfer: offered to {pending_client}");
+            }
+            if client.is_job_accepted(job_id).await? {
+                println!("  accepted by freelancer");
+            }
+            if let Some(retainer) = client.get_retainer(job_id).await? {
+                println!(
+                    "  retainer: period={}s notice={}s",
+                    retainer.period, retainer.notice
+                );
+            }
+            for item in client.get_basket(job_id).await?.iter().skip(1) {
+                println!("  basket: {} of {}", item.amount, item.token);
+            }
+            if let Some(engagement) = client.get_engagement(job_id).await? {
+                println!(
+                    "  hourly: rate={} ETH pending={} ETH",
+                    format_ether(engagement.rate),
+                    format_ether(engagement.pending)
+                );
+            }
+            for (index, sheet) in client.get_timesheets(job_id).await?.iter().enumerate() {
+                let status = match sheet.status {
+                    TIMESHEET_APPROVED => "approved",
+                    TIMESHEET_REJECTED => "rejected",
+                    _ => "submitted",
+                };
+                println!(
+                    "  timesheet {index}: {}h details={} ({status})",
+                    sheet.hours, sheet.details
+                );
+            }
+            for (index, tranche) in client.get_tranches(job_id).await?.iter().enumerate() {
+                println!(
+                    "  tranche {index}: {} ETH unlocks={}{}",
+                    format_ether(tranche.amount),
+                    tranche.unlock_at,
+                    if tranche.settled { " (settled)" } else { "" }
+                );
+            }
+            if let Some(dispute) = client.get_dispute(job_id).await? {
+                println!(
+                    "  dispute: opened_by={} opened_at={}{}",
+                    dispute.opened_by,
+                    dispute.opened_at,
+                    if dispute.open { " (open)" } else { "" }
+                );
+                if dispute.timeout_at != 0 {
+                    if dispute.timeout_split {
+                        println!(
+                            "  timeout: at={} split={} bps",
+                            dispute.timeout_at, dispute.timeout_split_bps
+                        );
+                    } else {
+                        println!("  timeout: at={} lifts dispute", dispute.timeout_at);
+                    }
+                }
+                if !dispute.stake.is_zero() {
+                    println!("  stake: {} ETH", format_ether(dispute.stake));
+                }
+                if dispute.arbitrator != Address::ZERO {
+                    println!(
+                        "  arbitrator: {} rule_by={} default_split={} bps",
+                        dispute.arbitrator, dispute.rule_by, dispute.default_split_bps
+                    );
+                }
+                if let Some(jury) = client.get_jury(job_id).await? {
+                    println!(
+                        "  jury: vote_by={} votes={}/{}{}",
+                        jury.vote_by,
+                        jury.votes_cast,
+                        jury.panel.len(),
+                        if jury.tallied { " (tallied)" } else { "" }
+                    );
+                    for juror in &jury.panel {
+                        match client.get_jury_vote(job_id, *juror).await? {
+                            Some(bps) => println!("    {juror} voted {bps} bps"),
+                            None => println!("    {juror}"),
+                        }
+                    }
+                }
+                if let Some(ruling) = client.get_held_ruling(job_id).await? {
+                    println!(
+                        "  ruling: {} bps by {} appealable_until={}",
+                        ruling.freelancer_bps, ruling.resolver, ruling.appealable_until
+                    );
+                }
+                if let Some(appeal) = client.get_appeal(job_id).await? {
+                    println!(
+                        "  appeal: {} bps by {} bond={} ETH proposal={}",
+                        appeal.freelancer_bps,
+                        appeal.appellant,
+                        format_ether(appeal.bond),
+                        appeal.proposal_id
+                    );
+                }
+                for (index, evidence) in client.get_evidence(job_id).await?.iter().enumerate() {
+                    println!(
+                        "  evidence {index}: {} by {} at {}",
+                        evidence.evidence_hash, evidence.submitter, evidence.submitted_at
+                    );
+                }
+            }
+        }
+        Command::Jobs {
+            all: true,
+            party: None,
+            category: None,
+            ..
+        } => {
+            let mut start = U256::from(1);
+            loop {
+                let jobs = client.get_jobs_range(start, MAX_JOBS_RANGE).await?;
+                if jobs.is_empty() {
+                    break;
+                }
+                start += U256::from(jobs.len());
+                jobs.iter().for_each(print_job);
+            }
+        }
+        Command::Expiring { before } => {
+            let mut cursor = U256::ZERO;
+            loop {
+                let (ids, next) = client
+                    .get_jobs_expiring_before(before, cursor, MAX_JOBS_RANGE)
+                    .await?;
+                for job_id in ids {
+                    print_job(&client.get_job(job_id).await?);
+                }
+                match next {
+                    Some(next) => cursor = next,
+                    None => break,
+                }
+            }
+        }
+        Command::Resolver { cursor } => match client.resolver(cursor).await? {
+            (true, payload) => println!("Due: {payload}"),
+            (false, reason) => println!("Nothing due: {}", String::from_utf8_lossy(&reason)),
+        },
+        Command::Jobs {
+            all: false,
+            status: None,
+            party: None,
+            category: None,
+            ..
+        } => {
+            for job_id in client.get_active_jobs().await? {
+                print_job(&client.get_job(job_id).await?);
+            }
+        }
+        Command::Jobs {
+            all,
+            status,
+            party,
+            category,
+            tag,
+        } => {
+            let status = status.or((!all).then_some(JobStatus::Active));
+            let mut cursor = U256::ZERO;
+            loop {
+                let (jobs, next) = match category {
+                    Some(category) => {
+                        client
+                            .query_jobs_by_category(
+                                category,
+                                tag.unwrap_or_default(),
+                                status,
+                                cursor,
+                                MAX_JOBS_RANGE,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .query_jobs(status, party, cursor, MAX_JOBS_RANGE)
+                            .await?
+                    }
+                };
+                jobs.iter().for_each(print_summary);
+                match next {
+                    Some(next) => cursor = next,
+                    None => break,
+                }
+            }
+        }
+        Command::Stats {
+            epoch: Some(epoch), ..
+        } => {
+            let stats = client.get_period_stats(epoch).await?;
+            println!("Epoch:     {epoch}");
+            println!("Jobs:      {}", stats.jobs_created);
+            println!("Deposited: {} ETH", format_ether(stats.deposited));
+            println!("Released:  {} ETH", format_ether(stats.released));
+            println!("Refunded:  {} ETH", format_ether(stats.refunded));
+        }
+        Command::Stats {
+            user: None,
+            epoch: None,
+        } => {
+            let stats = client.get_protocol_stats().await?;
+            println!("Jobs:      {}", client.get_total_jobs().await?);
+            println!("Deposited: {} ETH", format_ether(stats.deposited));
+            println!("Released:  {} ETH", format_ether(stats.released));
+            println!("Refunded:  {} ETH", format_ether(stats.refunded));
+            println!("Swept:     {} ETH", format_ether(stats.swept));
+            println!("Escrowed:  {} ETH", format_ether(stats.escrowed()));
+            println!("Disputed:  {} ETH", format_ether(stats.disputed));
+            println!("Fee:       {} bps", client.get_protocol_fee().await?);
+            let rebate = client.get_settlement_rebate().await?;
+            if !rebate.is_zero() {
+                let rebate = format_ether(rebate);
+                println!("           {rebate} ETH rebated per settlement");
+            }
+            let accrued = client.get_accrued_fees().await?;
+            println!("Accrued:   {} ETH in fees", format_ether(accrued));
+            let collected = format_ether(stats.fees_collected);
+            println!("           {collected} ETH charged in all");
+            let treasury = client.get_treasury().await?;
+            if treasury.treasury != Address::ZERO {
+                println!("Treasury:  {}", treasury.treasury);
+            }
+            if treasury.pending != Address::ZERO {
+                let (pending, at) = (treasury.pending, treasury.effective_at);
+                println!("           {pending} pending from {at}");
+            }
+            for tier in client.get_fee_tiers().await? {
+                let min_amount = format_ether(tier.min_amount);
+                println!("           {} bps from {min_amount} ETH", tier.fee_bps);
+            }
+            let limits = client.get_deposit_limits().await?;
+            if let Some(cooldown) = limits.cooldown {
+                println!("Deposits:  {cooldown}s apart per client");
+            }
+            if let Some(per_block) = limits.per_block {
+                println!("           at most {per_block} per client per block");
+            }
+            let stake = client.get_dispute_stake_policy().await?;
+            if stake.stake_bps != 0 || !stake.min_stake.is_zero() {
+                let (stake_bps, min_stake) = (stake.stake_bps, format_ether(stake.min_stake));
+                println!("Disputes:  {stake_bps} bps staked, at least {min_stake} ETH");
+            }
+            let jury = client.get_jury_policy().await?;
+            if jury.size != 0 {
+                let (size, period) = (jury.size, jury.voting_period);
+                println!("Juries:    {size} jurors voting for {period}s");
+                let (min_stake, slash_bps) = (format_ether(jury.min_stake), jury.slash_bps);
+                println!("           {min_stake} ETH staked, {slash_bps} bps slashed");
+                let jurors = client.get_juror_count().await?;
+                println!("           {jurors} jurors registered");
+            }
+            let appeals = client.get_appeal_policy().await?;
+            if appeals.window != 0 {
+                println!("Appeals:   {}s to appeal", appeals.window);
+                let (bond_bps, min_bond) = (appeals.bond_bps, format_ether(appeals.min_bond));
+                println!("           {bond_bps} bps bonded, at least {min_bond} ETH");
+            }
+            let paused = client.is_paused().await?;
+            println!("Paused:    {paused}");
+            let pause_until = client.get_pause_until().await?;
+            if paused && pause_until != 0 {
+                println!("           until {pause_until}");
+            }
+            let max_pause = client.get_max_pause().await?;
+            if max_pause != 0 {
+                println!("           at most {max_pause}s");
+            }
+            let pauser = client.get_pauser().await?;
+            if pauser != Address::ZERO {
+                println!("Pauser:    {pauser}");
+            }
+            let renounce_at = client.get_renounce_at().await?;
+            if renounce_at != 0 {
+                println!("Renounce:  from {renounce_at}");
+            }
+            let guardians = client.get_guardians().await?;
+            if !guardians.guardians.is_empty() {
+                let count = guardians.guardians.len();
+                println!("Guardians: {} of {count}", guardians.threshold);
+            }
+            let governance = client.get_governance().await?;
+            if !governance.quorum.is_zero() {
+                if governance.token == Address::ZERO {
+                    let count = governance.council.len();
+                    println!("Council:   {} of {count}", governance.quorum);
+                } else {
+                    println!(
+                        "Governance: {} votes of {}",
+                        governance.quorum, governance.token
+                    );
+                }
+            }
+            let refund_root = client.get_refund_root().await?;
+            if refund_root != B256::ZERO {
+                println!("Refunds:   claimable under {refund_root}");
+            }
+            let sunset = client.get_sunset().await?;
+            if sunset.sunset_at != 0 {
+                println!("Sunset:    {}", sunset.sunset_at);
+                println!("Retired:   {}", sunset.retired);
+            }
+        }
+        Command::Stats {
+            user: Some(user), ..
+        } => {
+            let stats = client.get_user_stats(user).await?;
+            println!("Jobs as client:     {}", stats.jobs_as_client);
+            println!("Jobs as freelancer: {}", stats.jobs_as_freelancer);
+            println!("Deposited:          {} ETH", format_ether(stats.deposited));
+            println!("Earned:             {} ETH", format_ether(stats.earned));
+            println!("Refunded:           {} ETH", format_ether(stats.refunded));
+            println!("Locked in escrow:   {} ETH", format_ether(stats.locked));
+            println!("Pending earnings:   {} ETH", format_ether(stats.pending));
+            let profile = client.get_freelancer_profile(user).await?;
+            println!("Jobs completed:     {}", profile.jobs_completed);
+            if profile.jobs_completed != 0 {
+                let average = profile.average_settlement_time;
+                println!("Avg settlement:     {average}s");
+            }
+            println!("Disputes:           {}", profile.disputes);
+            let standing = client.get_client_profile(user).await?;
+            println!("Refund rate:        {} bps", standing.refund_bps);
+            println!("Client disputes:    {}", standing.disputes);
+            println!("Dispute loss rate:  {} bps", standing.dispute_loss_bps);
+            let volume = client.get_client_volume(user).await?;
+            println!("Released volume:    {} ETH", format_ether(volume));
+            println!("Fee exempt:         {}", client.is_fee_exempt(user).await?);
+            let exempt = client.is_deposit_limit_exempt(user).await?;
+            println!("Deposit exempt:     {exempt}");
+            let reputation = client.get_reputation(user).await?;
+            if let Some(average) = reputation.average_centistars() {
+                let (whole, hundredths) = (average / 100, average % 100);
+                println!(
+                    "Rating:             {whole}.{hundredths:02} stars from {}",
+                    reputation.ratings
+                );
+            }
+            println!("Disputes lost:      {}", reputation.disputes_lost);
+            println!("Score:              {} bps", client.get_score(user).await?);
+        }
+        Command::Admin(command) => {
+            let receipt = match command {
+                AdminCommand::Pause => client.set_paused(true).await?,
+                AdminCommand::Unpause => client.set_paused(false).await?,
+                AdminCommand::SetPauser { pauser } => client.set_pauser(pauser).await?,
+                AdminCommand::SetMaxPause { duration } => client.set_max_pause(duration).await?,
+                AdminCommand::UnpauseExpired => client.unpause_expired().await?,
+                AdminCommand::TransferOwnership { new_admin } => {
+                    client.transfer_ownership(new_admin).await?
+                }
+                AdminCommand::ProposeRenounce => client.propose_renounce().await?,
+                AdminCommand::RenounceOwnership => client.renounce_ownership().await?,
+                AdminCommand::EmergencyRefund { job_id } => client.emergency_refund(job_id).await?,
+                AdminCommand::EmergencyRefundBatch { job_ids } => {
+                    client.emergency_refund_batch(job_ids).await?
+                }
+                AdminCommand::SetRefundRoot { root } => client.set_refund_root(root).await?,
+                AdminCommand::BeginSunset { timestamp } => client.begin_sunset(timestamp).await?,
+                AdminCommand::FinalizeSunset => client.finalize_sunset().await?,
+                AdminCommand::SetDormancyPolicy { period, recovery } => {
+                    client.set_dormancy_policy(period, recovery).await?
+                }
+                AdminCommand::AnnounceDormant { job_id } => client.announce_dormant(job_id).await?,
+                AdminCommand::SweepDormant { job_id } => client.sweep_dormant(job_id).await?,
+                AdminCommand::ProposeTreasury { treasury } => {
+                    client.propose_treasury(treasury).await?
+                }
+                AdminCommand::ApplyTreasury => client.apply_treasury().await?,
+                AdminCommand::SweepSurplus => client.sweep_surplus().await?,
+                AdminCommand::SetGuardians {
+                    threshold,
+                    guardians,
+                } => client.set_guardians(guardians, threshold).await?,
+                AdminCommand::ProposeEmergency { action, target } => {
+                    client.propose_emergency(action, target).await?
+                }
+                AdminCommand::ConfirmEmergency { proposal_id } => {
+                    client.confirm_emergency(proposal_id).await?
+                }
+                AdminCommand::SetGovernance {
+                    quorum,
+                    token,
+                    council,
+                } => client.set_governance(token, council, quorum).await?,
+                AdminCommand::ProposeGovernance {
+                    action,
+                    target,
+                    value,
+                    data,
+                } => {
+                    client
+                        .propose_governance(action, target, value, data)
+                        .await?
+                }
+                AdminCommand::VoteGovernance {
+                    proposal_id,
+                    against,
+                } => client.vote_governance(proposal_id, !against).await?,
+                AdminCommand::ExecuteGovernance { proposal_id } => {
+                    client.execute_governance(proposal_id).await?
+                }
+                AdminCommand::SetSwapRouter { router, weth } => {
+                    client.set_swap_router(router, weth).await?
+                }
+                AdminCommand::SetStakingToken {
+                    token,
+                    max_slippage_bps,
+                } => client.set_staking_token(token, max_slippage_bps).await?,
+                AdminCommand::SetComplianceHook { hook } => {
+                    client.set_compliance_hook(hook).await?
+                }
+                AdminCommand::SetDefaultJobCap { cap } => client.set_default_job_cap(cap).await?,
+                AdminCommand::SetDepositLimits {
+                    cooldown,
+                    per_block,
+                } => client.set_deposit_limits(cooldown, per_block).await?,
+                AdminCommand::SetDepositLimitExempt { account, revoke } => {
+                    client.set_deposit_limit_exempt(account, !revoke).await?
+                }
+                AdminCommand::SetFeeManager { manager } => client.set_fee_manager(manager).await?,
+                AdminCommand::WithdrawFees { to, amount } => {
+                    client.withdraw_fees(to, parse_ether(&amount)?).await?
+                }
+                AdminCommand::SetProtocolFee { fee_bps } => {
+                    client.set_protocol_fee(fee_bps).await?
+                }
+                AdminCommand::SetSettlementRebate { amount } => {
+                    client.set_settlement_rebate(parse_ether(&amount)?).await?
+                }
+                AdminCommand::SetFeeTiers { tiers } => client.set_fee_tiers(&tiers).await?,
+                AdminCommand::SetVolumeDiscounts { discounts } => {
+                    client.set_volume_discounts(&discounts).await?
+                }
+                AdminCommand::SetBasketToken { token, revoke } => {
+                    client.set_basket_token(token, !revoke).await?
+                }
+                AdminCommand::SetFeeExempt { account, revoke } => {
+                    client.set_fee_exempt(account, !revoke).await?
+                }
+                AdminCommand::SetArbitratorListing { arbitrator, unlist } => {
+                    client.set_arbitrator_listing(arbitrator, !unlist).await?
+                }
+                AdminCommand::SetArbitrationPolicy {
+                    arbitrator,
+                    ruling_period,
+                    default_split_bps,
+                } => {
+                    client
+                        .set_arbitration_policy(ArbitrationPolicy {
+                            arbitrator,
+                            ruling_period,
+                            default_split_bps,
+                        })
+                        .await?
+                }
+                AdminCommand::SetDisputeTimeoutPolicy { timeout, split } => {
+                    client
+                        .set_dispute_timeout_policy(DisputeTimeoutPolicy {
+                            timeout,
+                            split: split.is_some(),
+                            split_bps: split.unwrap_or_default(),
+                        })
+                        .await?
+                }
+                AdminCommand::SetDisputeStakePolicy {
+                    stake_bps,
+                    min_stake,
+                } => {
+                    client
+                        .set_dispute_stake_policy(DisputeStakePolicy {
+                            stake_bps,
+                            min_stake: parse_ether(&min_stake)?,
+                        })
+                        .await?
+                }
+                AdminCommand::SetJuryPolicy {
+                    size,
+                    voting_period,
+                    min_stake,
+                    slash_bps,
+                } => {
+                    client
+                        .set_jury_policy(JuryPolicy {
+                            size,
+                            voting_period,
+                            min_stake: parse_ether(&min_stake)?,
+                            slash_bps,
+                        })
+                        .await?
+                }
+                AdminCommand::SetAppealPolicy {
+                    window,
+                    bond_bps,
+                    min_bond,
+                } => {
+                    client
+                        .set_appeal_policy(AppealPolicy {
+                            window,
+                            bond_bps,
+                            min_bond: parse_ether(&min_bond)?,
+                        })
+                        .await?
+                }
+                AdminCommand::SetAttestationPolicy {
+                    eas,
+                    schema,
+                    attester,
+                } => client.set_attestation_policy(eas, schema, attester).await?,
+            };
+            print_receipt(&receipt);
+        }
+    }
+    Ok(())
+}
+
+/// Build a provider, attaching a wallet if a private key or keystore is
+/// configured, and return it with the signing address
+fn connect(rpc_url: &str, args: &SignerArgs) -> Result<(DynProvider, Option<Address>)> {
+    let url = rpc_url.parse()?;
+    Ok(match load_signer(args)? {
+        Some(signer) => {
+            let address = signer.address();
+            let provider = ProviderBuilder::new()
+                .wallet(EthereumWallet::from(signer))
+                .on_http(url)
+                .erased();
+            (provider, Some(address))
+        }
+        None => (ProviderBuilder::new().on_http(url).erased(), None),
+    })
+}
+
+/// Load the configured private key or keystore, if any
+fn load_signer(args: &SignerArgs) -> Result<Option<PrivateKeySigner>> {
+    Ok(if let Some(path) = &args.private_key_path {
+        let key = std::fs::read_to_string(path)?;
+        Some(PrivateKeySigner::from_str(key.trim())?)
+    } else if let Some(path) = &args.keystore {
+        let password = match &args.keystore_password {
+            Some(password) => password.clone(),
+            None => return Err(eyre!("--keystore requires KEYSTORE_PASSWORD")),
+        };
+        Some(PrivateKeySigner::decrypt_keystore(path, password)?)
+    } else {
+        None
+    })
+}
+
+/// The job offer `client` makes `freelancer` on `terms`
+fn job_offer(client: Address, freelancer: Address, terms: &OfferTerms) -> Result<JobOffer> {
+    Ok(JobOffer {
+        client,
+        freelancer,
+        amount: parse_ether(&terms.amount)?,
+        duration: terms.duration,
+        memo: terms.memo.unwrap_or_default(),
+        nonce: terms.nonce,
+        expiry: terms.expiry,
+    })
+}
+
+/// Settlement `signer` authorizes on `terms`
+fn settlement(signer: Address, terms: &SettlementTerms) -> Settlement {
+    Settlement {
+        job_id: terms.job_id,
+        signer,
+        release: !terms.refund,
+        memo: terms.memo.unwrap_or_default(),
+        nonce: terms.nonce,
+        expiry: terms.expiry,
+    }
+}
+
+/// Verdict an arbitrator signs on `terms`
+fn verdict(terms: &VerdictTerms) -> Verdict {
+    Verdict {
+        job_id: terms.job_id,
+        freelancer_bps: terms.freelancer_bps,
+        nonce: terms.nonce,
+        expiry: terms.expiry,
+    }
+}
+
+/// Parse a `--memo` argument
+fn parse_memo(reference: &str) -> Result<B256, String> {
+    encode_memo(reference).ok_or_else(|| "memo must be at most 32 bytes".to_string())
+}
+
+/// Parse a `--status` argument
+fn parse_job_status(status: &str) -> Result<JobStatus, String> {
+    match status {
+        "active" => Ok(JobStatus::Active),
+        "released" => Ok(JobStatus::Released),
+        "refunded" => Ok(JobStatus::Refunded),
+        "swept" => Ok(JobStatus::Swept),
+        _ => Err("status must be active, released, refunded or swept".to_string()),
+    }
+}
+
+/// Parse a `--tranche AMOUNT@UNIX_TIME` argument
+fn parse_tranche(tranche: &str) -> Result<(U256, u64), String> {
+    let (amount, unlock_at) = tranche
+        .split_once('@')
+        .ok_or_else(|| "tranche must be AMOUNT@UNIX_TIME".to_string())?;
+    let amount = parse_ether(amount).map_err(|e| e.to_string())?;
+    let unlock_at = unlock_at
+        .parse()
+        .map_err(|_| "invalid unlock time".to_string())?;
+    Ok((amount, unlock_at))
+}
+
+/// Parse a `--token TOKEN:AMOUNT` argument, the amount in the token's base
+/// units
+fn parse_basket_token(token: &str) -> Result<(Address, U256), String> {
+    let (token, amount) = token
+        .split_once(':')
+        .ok_or_else(|| "token must be TOKEN:AMOUNT".to_string())?;
+    let token = token
+        .parse()
+        .map_err(|_| "invalid token address".to_string())?;
+    let amount = amount
+        .parse()
+        .map_err(|_| "invalid token amount".to_string())?;
+    Ok((token, amount))
+}
+
+/// Parse a `--tier MIN_AMOUNT@BPS` argument
+fn parse_fee_tier(tier: &str) -> Result<FeeTier, String> {
+    let (min_amount, fee_bps) = tier
+        .split_once('@')
+        .ok_or_else(|| "tier must be MIN_AMOUNT@BPS".to_string())?;
+    let min_amount = parse_ether(min_amount).map_err(|e| e.to_string())?;
+    let fee_bps = fee_bps.parse().map_err(|_| "invalid fee".to_string())?;
+    Ok(FeeTier {
+        min_amount,
+        fee_bps,
+    })
+}
+
+/// Parse a `--discount MIN_VOLUME@BPS` argument
+fn parse_volume_discount(discount: &str) -> Result<VolumeDiscount, String> {
+    let (min_volume, discount_bps) = discount
+        .split_once('@')
+        .ok_or_else(|| "discount must be MIN_VOLUME@BPS".to_string())?;
+    let min_volume = parse_ether(min_volume).map_err(|e| e.to_string())?;
+    let discount_bps = discount_bps
+        .parse()
+        .map_err(|_| "invalid discount".to_string())?;
+    Ok(VolumeDiscount {
+        min_volume,
+        discount_bps,
+    })
+}
+
+/// ` memo=...` for events that carry one, or nothing if it is empty
+fn describe_memo(memo: B256) -> String {
+    if memo.is_zero() {
+        String::new()
+    } else {
+        format!(" memo={}", decode_memo(memo))
+    }
+}
+
+/// ` category=... tag=...` for a job filed under one, or nothing if it is not
+fn describe_category(category: u16, tag: B256) -> String {
+    match (category, tag.is_zero()) {
+        (0, true) => String::new(),
+        (category, true) => format!(" category={category}"),
+        (category, false) => format!(" category={category} tag={}", decode_memo(tag)),
+    }
+}
+
+/// Name of a `JobAmended` field ID
+fn describe_job_field(field: u8) -> String {
+    match field {
+        JOB_FIELD_DEADLINE => "deadline".to_string(),
+        JOB_FIELD_AMOUNT => "amount".to_string(),
+        JOB_FIELD_METADATA => "metadata".to_string(),
+        JOB_FIELD_PAYOUT_ADDRESS => "payout_address".to_string(),
+        JOB_FIELD_CLIENT => "client".to_string(),
+        JOB_FIELD_FREELANCER => "freelancer".to_string(),
+        other => other.to_string(),
+    }
+}
+
+fn print_job(job: &Job) {
+    print_summary(&JobSummary {
+        id: job.id,
+        client: job.client,
+        freelancer: job.freelancer,
+        amount: job.amount,
+        deadline: job.deadline,
+        status: job.status(),
+    });
+}
+
+fn print_summary(job: &JobSummary) {
+    println!(
+        "Job {} [{:?}] client={} freelancer={} amount={} ETH deadline={}",
+        job.id,
+        job.status,
+        job.client,
+        job.freelancer,
+        format_ether(job.amount),
+        job.deadline,
+    );
+}
+
+fn print_receipt(receipt: &TransactionReceipt) {
+    println!(
+        "Confirmed tx {} (gas used {})",
+        receipt.transaction_hash, receipt.gas_used
+    );
+    for log in receipt.inner.logs() {
+        if let Some(line) = describe_log(log) {
+            println!("  {line}");
+        }
+    }
+}
+
+/// Render an escrow event log as a single line
+fn describe_log(log: &Log) -> Option<String> {
+    Some(match EscrowEvent::decode(log.topics(), &log.data().data)? {
+        EscrowEvent::Deposited(e) => format!(
+            "Deposited job={} client={} freelancer={} amount={} ETH{}{}",
+            e.job_id,
+            e.client,
+            e.freelancer,
+            format_ether(e.amount),
+            describe_memo(e.memo),
+            describe_category(e.category, e.tag)
+        ),
+        EscrowEvent::Released(e) => format!(
+            "Released job={} amount={} ETH{}",
+            e.job_id,
+            format_ether(e.amount),
+            describe_memo(e.memo)
+        ),
+        EscrowEvent::Refunded(e) => format!(
+            "Refunded job={} amount={} ETH{}",
+            e.job_id,
+            format_ether(e.amount),
+            describe_memo(e.memo)
+        ),
+        EscrowEvent::AutoReleased(e) => format!(
+            "AutoReleased job={} amount={} ETH{}",
+            e.job_id,
+            format_ether(e.amount),
+            describe_memo(e.memo)
+        ),
+        EscrowEvent::EmergencyRefunded(e) => {
+            format!("EmergencyRefunded job={} admin={}", e.job_id, e.admin)
+        }
+        EscrowEvent::PauseToggled(e) => format!("PauseToggled paused={}", e.paused),
+        EscrowEvent::OwnershipTransferred(e) => format!(
+            "OwnershipTransferred old={} new={}",
+            e.old_admin, e.new_admin
+        ),
+        EscrowEvent::SunsetBegun(e) => format!("SunsetBegun timestamp={}", e.timestamp),
+        EscrowEvent::SunsetFinalized(e) => format!(
+            "SunsetFinalized admin={} swept={} ETH",
+            e.admin,
+            format_ether(e.swept)
+        ),
+        EscrowEvent::DormancyPolicySet(e) => format!(
+            "DormancyPolicySet period={} recovery={}",
+            e.period, e.recovery
+        ),
+        EscrowEvent::DormancyAnnounced(e) => format!(
+            "DormancyAnnounced job={} sweep_after={}",
+            e.job_id, e.sweep_after
+        ),
+        EscrowEvent::DormantSwept(e) => format!(
+            "DormantSwept job={} recovery={} amount={} ETH",
+            e.job_id,
+            e.recovery,
+            format_ether(e.amount)
+        ),
+        EscrowEvent::PrivateDeposited(e) => format!(
+            "PrivateDeposited job={} client={} amount={} ETH commitment={}",
+            e.job_id,
+            e.client,
+            format_ether(e.amount),
+            e.commitment
+        ),
+        EscrowEvent::JobRevealed(e) => format!(
+            "JobRevealed job={} freelancer={} amount={} ETH terms={}",
+            e.job_id,
+            e.freelancer,
+            format_ether(e.amount),
+            e.terms
+        ),
+        EscrowEvent::SwapRouterSet(e) => {
+            format!("SwapRouterSet router={} weth={}", e.router, e.weth)
+        }
+        EscrowEvent::PayoutTokenSet(e) => format!(
+            "PayoutTokenSet freelancer={} token={} min_rate={}",
+            e.freelancer, e.token, e.min_rate
+        ),
+        EscrowEvent::PayoutSwapped(e) => format!(
+            "PayoutSwapped job={} token={} in={} ETH out={}",
+            e.job_id,
+            e.token,
+            format_ether(e.amount_in),
+            e.amount_out
+        ),
+        EscrowEvent::PayoutSwapFailed(e) => {
+            format!("PayoutSwapFailed job={} token={}", e.job_id, e.token)
+        }
+        EscrowEvent::PayoutPushed(e) => format!(
+            "PayoutPushed job={} recipient={} amount={} ETH pushed={}",
+            e.job_id,
+            e.recipient,
+            format_ether(e.amount),
+            e.pushed
+        ),
+        EscrowEvent::WithholdingSet(e) => format!(
+            "WithholdingSet freelancer={} destination={} rate_bps={}",
+            e.freelancer, e.destination, e.rate_bps
+        ),
+        EscrowEvent::ClawbackWindowSet(e) => format!(
+            "ClawbackWindowSet job={} window={}s",
+            e.job_id, e.window
+        ),
+        EscrowEvent::ReleaseQueued(e) => format!(
+            "ReleaseQueued job={} amount={} ETH payable_at={}",
+            e.job_id,
+            format_ether(e.amount),
+            e.payable_at
+        ),
+        EscrowEvent::ReleaseReversed(e) => format!(
+            "ReleaseReversed job={} arbitrator={} amount={} ETH",
+            e.job_id,
+            e.arbitrator,
+            format_ether(e.amount)
+        ),
+        EscrowEvent::SlaSet(e) => format!(
+            "SlaSet job={} delivery_by={} late={} bps/day",
+            e.job_id, e.delivery_by, e.late_bps_per_day
+        ),
+        EscrowEvent::LateRefunded(e) => format!(
+            "LateRefunded job={} days_late={} amount={} ETH",
+            e.job_id,
+            e.days_late,
+            format_ether(e.amount)
+        ),
+        EscrowEvent::HoldbackSet(e) => format!(
+            "HoldbackSet job={} holdback={} bps warranty={}s",
+            e.job_id, e.holdback_bps, e.warranty
+        ),
+        EscrowEvent::HoldbackRetained(e) => format!(
+            "HoldbackRetained job={} amount={} ETH release_at={}",
+            e.job_id,
+            format_ether(e.amount),
+            e.release_at
+        ),
+        EscrowEvent::PartiallyReleased(e) => format!(
+            "PartiallyReleased job={} amount={} ETH remaining={} ETH",
+            e.job_id,
+            format_ether(e.amount),
+            format_ether(e.remaining)
+        ),
+        EscrowEvent::TrancheClaimed(e) => format!(
+            "TrancheClaimed job={} index={} amount={} ETH remaining={} ETH",
+            e.job_id,
+            e.index,
+            format_ether(e.amount),
+            format_ether(e.remaining)
+        ),
+        EscrowEvent::TrancheRefunded(e) => format!(
+            "TrancheRefunded job={} index={} amount={} ETH remaining={} ETH",
+            e.job_id,
+            e.index,
+            format_ether(e.amount),
+            format_ether(e.remaining)
+        ),
+        EscrowEvent::EmergencyRefundBatch(e) => format!(
+            "EmergencyRefundBatch admin={} jobs={:?} refunded={:?}",
+            e.admin, e.job_ids, e.refunded
+        ),
+        EscrowEvent::RefundRootSet(e) => format!("RefundRootSet root={}", e.root),
+        EscrowEvent::JobRated(e) => format!(
+            "JobRated job={} rater={} ratee={} stars={}",
+            e.job_id, e.rater, e.ratee, e.stars
+        ),
+        EscrowEvent::EmergencyRefundClaimed(e) => format!(
+            "EmergencyRefundClaimed job={} client={} amount={} ETH remaining={} ETH",
+            e.job_id,
+            e.client,
+            format_ether(e.amount),
+            format_ether(e.remaining)
+        ),
+        EscrowEvent::BasketTokenSet(e) => {
+            format!("BasketTokenSet token={} approved={}", e.token, e.approved)
+        }
+        EscrowEvent::BasketFunded(e) => format!(
+            "BasketFunded job={} token={} amount={}",
+            e.job_id, e.token, e.amount
+        ),
+        EscrowEvent::BasketPaid(e) => format!(
+            "BasketPaid job={} token={} recipient={} amount={}",
+            e.job_id, e.token, e.recipient, e.amount
+        ),
+        EscrowEvent::HourlyRateSet(e) => format!(
+            "HourlyRateSet job={} rate={} ETH",
+            e.job_id,
+            format_ether(e.rate)
+        ),
+        EscrowEvent::TimesheetSubmitted(e) => format!(
+            "TimesheetSubmitted job={} index={} hours={} amount={} ETH details={}",
+            e.job_id,
+            e.index,
+            e.hours,
+            format_ether(e.amount),
+            e.details
+        ),
+        EscrowEvent::TimesheetApproved(e) => format!(
+            "TimesheetApproved job={} index={} amount={} ETH remaining={} ETH",
+            e.job_id,
+            e.index,
+            format_ether(e.amount),
+            format_ether(e.remaining)
+        ),
+        EscrowEvent::TimesheetRejected(e) => {
+            format!("TimesheetRejected job={} index={}", e.job_id, e.index)
+        }
+        EscrowEvent::RetainerStarted(e) => format!(
+            "RetainerStarted job={} periods={} period={}s notice={}s",
+            e.job_id, e.periods, e.period, e.notice
+        ),
+        EscrowEvent::RetainerStopped(e) => format!(
+            "RetainerStopped job={} refunded={} ETH",
+            e.job_id,
+            format_ether(e.refunded)
+        ),
+        EscrowEvent::CancellationNoticeSet(e) => format!(
+            "CancellationNoticeSet job={} notice={}s",
+            e.job_id, e.notice
+        ),
+        EscrowEvent::CancellationRequested(e) => format!(
+            "CancellationRequested job={} effective_at={}",
+            e.job_id, e.effective_at
+        ),
+        EscrowEvent::DeliverySubmitted(e) => {
+            format!("DeliverySubmitted job={} delivery={}", e.job_id, e.delivery)
+        }
+        EscrowEvent::FundingOpened(e) => format!(
+            "FundingOpened job={} client={} freelancer={} target={} ETH cutoff={}",
+            e.job_id,
+            e.client,
+            e.freelancer,
+            format_ether(e.target),
+            e.cutoff
+        ),
+        EscrowEvent::JobFunded(e) => format!(
+            "JobFunded job={} contributor={} amount={} ETH raised={} ETH",
+            e.job_id,
+            e.contributor,
+            format_ether(e.amount),
+            format_ether(e.raised)
+        ),
+        EscrowEvent::ContributionReclaimed(e) => format!(
+            "ContributionReclaimed job={} contributor={} amount={} ETH",
+            e.job_id,
+            e.contributor,
+            format_ether(e.amount)
+        ),
+        EscrowEvent::ExcessCredited(e) => format!(
+            "ExcessCredited job={} client={} excess={} ETH",
+            e.job_id,
+            e.client,
+            format_ether(e.excess)
+        ),
+        EscrowEvent::CreditWithdrawn(e) => format!(
+            "CreditWithdrawn account={} amount={} ETH",
+            e.account,
+            format_ether(e.amount)
+        ),
+        EscrowEvent::CreditDeposited(e) => format!(
+            "CreditDeposited account={} amount={} ETH",
+            e.account,
+            format_ether(e.amount)
+        ),
+        EscrowEvent::DefaultJobCapSet(e) => format!("DefaultJobCapSet cap={}", e.cap),
+        EscrowEvent::DepositLimitsSet(e) => format!(
+            "DepositLimitsSet cooldown={} per_block={}",
+            e.cooldown, e.per_block
+        ),
+        EscrowEvent::DepositLimitExemptionSet(e) => format!(
+            "DepositLimitExemptionSet account={} exempt={}",
+            e.account, e.exempt
+        ),
+        EscrowEvent::JobCapSet(e) => format!("JobCapSet freelancer={} cap={}", e.freelancer, e.cap),
+        EscrowEvent::ReleaseConditionSet(e) => format!(
+            "ReleaseConditionSet job={} condition={}",
+            e.job_id, e.condition
+        ),
+        EscrowEvent::SettlementHookSet(e) => {
+            format!("SettlementHookSet job={} hook={}", e.job_id, e.hook)
+        }
+        EscrowEvent::SettlementHookFailed(e) => {
+            format!("SettlementHookFailed job={} hook={}", e.job_id, e.hook)
+        }
+        EscrowEvent::JobAttested(e) => format!("JobAttested job={} uid={}", e.job_id, e.uid),
+        EscrowEvent::JobArbitratorSet(e) => {
+            format!("JobArbitratorSet job={} arbitrator={}", e.job_id, e.arbitrator)
+        }
+        EscrowEvent::ArbitratorReplaced(e) => format!(
+            "ArbitratorReplaced job={} old={} new={} rule_by={}",
+            e.job_id, e.old_arbitrator, e.new_arbitrator, e.rule_by
+        ),
+        EscrowEvent::AttestationPolicySet(e) => format!(
+            "AttestationPolicySet eas={} schema={} attester={}",
+            e.eas, e.schema, e.attester
+        ),
+        EscrowEvent::ComplianceHookSet(e) => format!("ComplianceHookSet hook={}", e.hook),
+        EscrowEvent::Withheld(e) => format!(
+            "Withheld job={} destination={} net={} ETH withheld={} ETH",
+            e.job_id,
+            e.destination,
+            format_ether(e.net),
+            format_ether(e.withheld)
+        ),
+        EscrowEvent::ProtocolFeeSet(e) => format!("ProtocolFeeSet fee_bps={}", e.fee_bps),
+        EscrowEvent::SettlementRebateSet(e) => {
+            format!("SettlementRebateSet amount={} ETH", format_ether(e.amount))
+        }
+        EscrowEvent::SettlementRebated(e) => format!(
+            "SettlementRebated job={} caller={} amount={} ETH",
+            e.job_id,
+            e.caller,
+            format_ether(e.amount)
+        ),
+        EscrowEvent::FeeCharged(e) => format!(
+            "FeeCharged job={} recipient={} fee={} ETH",
+            e.job_id,
+            e.recipient,
+            format_ether(e.fee)
+        ),
+        EscrowEvent::FeeManagerSet(e) => format!("FeeManagerSet manager={}", e.manager),
+        EscrowEvent::FeeTiersSet(e) => format!(
+            "FeeTiersSet min_amounts={:?} fee_bps={:?}",
+            e.min_amounts, e.fee_bps
+        ),
+        EscrowEvent::VolumeDiscountsSet(e) => format!(
+            "VolumeDiscountsSet min_volumes={:?} discount_bps={:?}",
+            e.min_volumes, e.discount_bps
+        ),
+        EscrowEvent::FeeExemptionSet(e) => {
+            format!("FeeExemptionSet account={} exempt={}", e.account, e.exempt)
+        }
+        EscrowEvent::DisputeOpened(e) => format!(
+            "DisputeOpened job={} opened_by={}{}",
+            e.job_id,
+            e.opened_by,
+            describe_memo(e.memo)
+        ),
+        EscrowEvent::EvidenceSubmitted(e) => format!(
+            "EvidenceSubmitted job={} submitter={} index={} evidence_hash={}",
+            e.job_id, e.submitter, e.index, e.evidence_hash
+        ),
+        EscrowEvent::DisputeResolved(e) => format!("DisputeResolved job={} resolver={} freelancer_amount={} ETH client_amount={} ETH by_default={}", e.job_id, e.resolver, format_ether(e.freelancer_amount), format_ether(e.client_amount), e.by_default),
+        EscrowEvent::ArbitratorListed(e) => {
+            format!("ArbitratorListed arbitrator={} listed={}", e.arbitrator, e.listed)
+        }
+        EscrowEvent::ArbitrationPolicySet(e) => format!("ArbitrationPolicySet arbitrator={} ruling_period={} default_split_bps={}", e.arbitrator, e.ruling_period, e.default_split_bps),
+        EscrowEvent::Reclaimed(e) => format!("Reclaimed job={} caller={} amount={} ETH{}", e.job_id, e.caller, format_ether(e.amount), describe_memo(e.memo)),
+        EscrowEvent::FundsReturned(e) => format!("FundsReturned job={} amount={} ETH{}", e.job_id, format_ether(e.amount), describe_memo(e.memo)),
+        EscrowEvent::DualApprovalRequired(e) => format!("DualApprovalRequired job={}", e.job_id),
+        EscrowEvent::ReleaseApproved(e) => format!("ReleaseApproved job={} approver={}", e.job_id, e.approver),
+        EscrowEvent::StakingTokenSet(e) => format!(
+            "StakingTokenSet token={} max_slippage_bps={}",
+            e.token, e.max_slippage_bps
+        ),
+        EscrowEvent::StakeConsented(e) => format!(
+            "StakeConsented job={} party={} freelancer_yield_bps={} treasury_yield_bps={}",
+            e.job_id, e.party, e.freelancer_yield_bps, e.treasury_yield_bps
+        ),
+        EscrowEvent::JobStaked(e) => format!(
+            "JobStaked job={} token={} amount={} ETH shares={}",
+            e.job_id,
+            e.token,
+            format_ether(e.amount),
+            e.shares
+        ),
+        EscrowEvent::JobUnstaked(e) => format!(
+            "JobUnstaked job={} buyer={} principal={} ETH paid={} ETH client_yield={} ETH freelancer_yield={} ETH treasury_yield={} ETH",
+            e.job_id,
+            e.buyer,
+            format_ether(e.principal),
+            format_ether(e.paid),
+            format_ether(e.client_yield),
+            format_ether(e.freelancer_yield),
+            format_ether(e.treasury_yield)
+        ),
+        EscrowEvent::YieldSplitSet(e) => format!(
+            "YieldSplitSet job={} client={} bps freelancer={} bps treasury={} bps",
+            e.job_id, e.client_yield_bps, e.freelancer_yield_bps, e.treasury_yield_bps
+        ),
+        EscrowEvent::DisputeTimedOut(e) => format!("DisputeTimedOut job={} split={}", e.job_id, e.split),
+        EscrowEvent::DisputeStakePolicySet(e) => format!(
+            "DisputeStakePolicySet stake_bps={} min_stake={} ETH",
+            e.stake_bps,
+            format_ether(e.min_stake)
+        ),
+        EscrowEvent::DisputeStakeSettled(e) => format!(
+            "DisputeStakeSettled job={} recipient={} amount={} ETH forfeited={}",
+            e.job_id,
+            e.recipient,
+            format_ether(e.amount),
+            e.forfeited
+        ),
+        EscrowEvent::JuryPolicySet(e) => format!(
+            "JuryPolicySet size={} voting_period={} min_stake={} ETH slash_bps={}",
+            e.size,
+            e.voting_period,
+            format_ether(e.min_stake),
+            e.slash_bps
+        ),
+        EscrowEvent::JurorStaked(e) => format!(
+            "JurorStaked juror={} amount={} ETH stake={} ETH",
+            e.juror,
+            format_ether(e.amount),
+            format_ether(e.stake)
+        ),
+        EscrowEvent::JurorLeft(e) => format!(
+            "JurorLeft juror={} amount={} ETH",
+            e.juror,
+            format_ether(e.amount)
+        ),
+        EscrowEvent::JuryDrawn(e) => format!(
+            "JuryDrawn job={} panel={} vote_by={}",
+            e.job_id,
+            e.panel.len(),
+            e.vote_by
+        ),
+        EscrowEvent::JuryVoted(e) => format!(
+            "JuryVoted job={} juror={} freelancer_bps={}",
+            e.job_id, e.juror, e.freelancer_bps
+        ),
+        EscrowEvent::JuryTallied(e) => format!(
+            "JuryTallied job={} decided={} freelancer_bps={} slashed={} ETH",
+            e.job_id,
+            e.decided,
+            e.freelancer_bps,
+            format_ether(e.slashed)
+        ),
+        EscrowEvent::AppealPolicySet(e) => format!(
+            "AppealPolicySet window={} bond_bps={} min_bond={} ETH",
+            e.window,
+            e.bond_bps,
+            format_ether(e.min_bond)
+        ),
+        EscrowEvent::RulingHeld(e) => format!(
+            "RulingHeld job={} resolver={} freelancer_bps={} appealable_until={}",
+            e.job_id, e.resolver, e.freelancer_bps, e.appealable_until
+        ),
+        EscrowEvent::RulingAppealed(e) => format!(
+            "RulingAppealed job={} appellant={} freelancer_bps={} bond={} ETH proposal_id={}",
+            e.job_id,
+            e.appellant,
+            e.freelancer_bps,
+            format_ether(e.bond),
+            e.proposal_id
+        ),
+        EscrowEvent::AppealBondSettled(e) => format!(
+            "AppealBondSettled job={} recipient={} amount={} ETH forfeited={}",
+            e.job_id,
+            e.recipient,
+            format_ether(e.amount),
+            e.forfeited
+        ),
+        EscrowEvent::DisputeTimeoutPolicySet(e) => format!("DisputeTimeoutPolicySet timeout={} split={} split_bps={}", e.timeout, e.split, e.split_bps),
+        EscrowEvent::JobAmended(e) => format!(
+            "JobAmended job={} field={} old={} new={} actor={}",
+            e.job_id,
+            describe_job_field(e.field),
+            U256::from_be_bytes(e.old_value.0),
+            U256::from_be_bytes(e.new_value.0),
+            e.actor
+        ),
+        EscrowEvent::FeesWithdrawn(e) => format!("FeesWithdrawn to={} amount={} ETH remaining={} ETH", e.to, format_ether(e.amount), format_ether(e.remaining)),
+        EscrowEvent::TreasuryProposed(e) => format!("TreasuryProposed treasury={} effective_at={}", e.treasury, e.effective_at),
+        EscrowEvent::TreasurySet(e) => format!("TreasurySet treasury={}", e.treasury),
+        EscrowEvent::GuardiansSet(e) => format!("GuardiansSet guardians={:?} threshold={}", e.guardians, e.threshold),
+        EscrowEvent::EmergencyProposed(e) => format!("EmergencyProposed proposal_id={} action={} target={} proposer={} expires_at={}", e.proposal_id, e.action, e.target, e.proposer, e.expires_at),
+        EscrowEvent::EmergencyConfirmed(e) => format!("EmergencyConfirmed proposal_id={} guardian={} confirmations={}", e.proposal_id, e.guardian, e.confirmations),
+        EscrowEvent::SurplusSwept(e) => format!("SurplusSwept to={} amount={} ETH", e.to, format_ether(e.amount)),
+        EscrowEvent::PauseExtended(e) => format!("PauseExtended until={}", e.until),
+        EscrowEvent::ClientTransferProposed(e) => format!("ClientTransferProposed job={} client={} new_client={}", e.job_id, e.client, e.new_client),
+        EscrowEvent::JobAccepted(e) => format!("JobAccepted job={} freelancer={}", e.job_id, e.freelancer),
+        EscrowEvent::PayoutAddressProposed(e) => format!("PayoutAddressProposed freelancer={} payout_address={} effective_at={}", e.freelancer, e.payout_address, e.effective_at),
+        EscrowEvent::PayoutAddressSet(e) => format!("PayoutAddressSet freelancer={} payout_address={}", e.freelancer, e.payout_address),
+        EscrowEvent::GovernanceSet(e) => format!("GovernanceSet token={} council={:?} quorum={}", e.token, e.council, e.quorum),
+        EscrowEvent::GovernanceProposed(e) => format!("GovernanceProposed proposal_id={} action={} target={} value={} proposer={} voting_ends={}", e.proposal_id, e.action, e.target, e.value, e.proposer, e.voting_ends),
+        EscrowEvent::GovernanceVoted(e) => format!("GovernanceVoted proposal_id={} voter={} support={} weight={}", e.proposal_id, e.voter, e.support, e.weight),
+        EscrowEvent::GovernanceExecuted(e) => format!("GovernanceExecuted proposal_id={}", e.proposal_id),
+        EscrowEvent::PauserSet(e) => format!("PauserSet pauser={}", e.pauser),
+        EscrowEvent::MaxPauseSet(e) => format!("MaxPauseSet duration={}", e.duration),
+        EscrowEvent::RenounceProposed(e) => format!("RenounceProposed effective_at={}", e.effective_at),
+        EscrowEvent::InvoiceCreated(e) => format!(
+            "InvoiceCreated invoice={} freelancer={} client={} amount={} ETH duration={} expiry={}{}",
+            e.invoice_id,
+            e.freelancer,
+            e.client,
+            format_ether(e.amount),
+            e.duration,
+            e.expiry,
+            describe_memo(e.metadata)
+        ),
+        EscrowEvent::InvoicePruned(e) => format!("InvoicePruned invoice={}", e.invoice_id),
+        EscrowEvent::InvoiceFunded(e) => {
+            format!("InvoiceFunded invoice={} job={}", e.invoice_id, e.job_id)
+        }
+        EscrowEvent::AuthorizationCancelled(e) => {
+            format!("AuthorizationCancelled signer={} nonce={}", e.signer, e.nonce)
+        }
+        EscrowEvent::OfferCancelled(e) => {
+            format!("OfferCancelled client={} nonce={}", e.client, e.nonce)
+        }
+        EscrowEvent::PlatformFeeSet(e) => format!("PlatformFeeSet job={} platform={} fee_bps={}", e.job_id, e.platform, e.fee_bps),
+        EscrowEvent::FreelancerChanged(e) => format!("FreelancerChanged job={} old_freelancer={} new_freelancer={}", e.job_id, e.old_freelancer, e.new_freelancer),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::{LogData, B256};
+    use alloy_sol_types::SolEvent;
+    use escrow_client::Released;
+
+    #[test]
+    fn test_cli_parses_commands() {
+        let cli = Cli::try_parse_from([
+            "escrow-cli",
+            "--rpc-url",
+            "http://localhost:8547",
+            "--contract",
+            "0x0000000000000000000000000000000000000001",
+            "deposit",
+            "--freelancer",
+            "0x0101010101010101010101010101010101010101",
+            "--duration",
+            "86400",
+            "--amount",
+            "0.5",
+            "--memo",
+            "PO-2024-0117",
+        ])
+        .unwrap();
+        match cli.command {
+            Command::Deposit {
+                freelancer,
+                duration,
+                amount,
+                memo,
+                nonce,
+                attestation,
+                condition,
+                notice,
+                dual_approval,
+                platform,
+                platform_bps,
+                holdback_bps,
+                warranty,
+                clawback_window,
+                delivery_by,
+                late_bps_per_day,
+                category,
+                tag,
+                arbitrator,
+            } => {
+                assert_eq!((nonce, attestation, condition), (None, None, None));
+                assert_eq!((category, tag, arbitrator), (None, None, None));
+                assert_eq!((notice, platform, platform_bps), (None, None, None));
+                assert_eq!(
+                    (holdback_bps, warranty, clawback_window),
+                    (None, None, None)
+                );
+                assert_eq!((delivery_by, late_bps_per_day), (None, None));
+                assert!(!dual_approval);
+                assert_eq!(freelancer, Address::from([0x01; 20]));
+                assert_eq!(memo.map(decode_memo).as_deref(), Some("PO-2024-0117"));
+                assert_eq!(duration, 86_400);
+                assert_eq!(
+                    parse_ether(&amount).unwrap(),
+                    U256::from(500_000_000_000_000_000_u64)
+                );
+            }
+            _ => panic!("expected deposit"),
+        }
+
+        let cli = Cli::try_parse_from([
+            "escrow-cli",
+            "--rpc-url",
+            "http://localhost:8547",
+            "--contract",
+            "0x0000000000000000000000000000000000000001",
+            "consent-to-stake",
+            "1",
+            "3000",
+            "--treasury-yield-bps",
+            "1000",
+        ])
+        .unwrap();
+        match cli.command {
+            Command::ConsentToStake {
+                job_id,
+                freelancer_yield_bps,
+                treasury_yield_bps,
+            } => assert_eq!(
+                (job_id, freelancer_yield_bps, treasury_yield_bps),
+                (U256::from(1), 3_000, 1_000)
+            ),
+            _ => panic!("expected consent-to-stake"),
+        }
+
+        let cli = Cli::try_parse_from([
+            "escrow-cli",
+            "--rpc-url",
+            "http://localhost:8547",
+            "--contract",
+            "0x0000000000000000000000000000000000000001",
+            "deposit-tranched",
+            "--freelancer",
+            "0x0101010101010101010101010101010101010101",
+            "--tranche",
+            "0.3@1735689600",
+            "--tranche",
+            "0.7@1738368000",
+        ])
+        .unwrap();
+        match cli.command {
+            Command::DepositTranched { tranches, .. } => assert_eq!(
+                tranches,
+                vec![
+                    (U256::from(300_000_000_000_000_000_u64), 1_735_689_600),
+                    (U256::from(700_000_000_000_000_000_u64), 1_738_368_000),
+                ]
+            ),
+            _ => panic!("expected deposit-tranched"),
+        }
+
+        let cli = Cli::try_parse_from([
+            "escrow-cli",
+            "--rpc-url",
+            "http://localhost:8547",
+            "--contract",
+            "0x0000000000000000000000000000000000000001",
+            "admin",
+            "emergency-refund",
+            "7",
+        ])
+        .unwrap();
+        assert!(matches!(
+            cli.command,
+            Command::Admin(AdminCommand::EmergencyRefund { job_id }) if job_id == U256::from(7)
+        ));
+        let cli = Cli::try_parse_from([
+            "escrow-cli",
+            "--rpc-url",
+            "http://localhost:8547",
+            "--contract",
+            "0x0000000000000000000000000000000000000001",
+            "jobs",
+            "--status",
+            "refunded",
+            "--party",
+            "0x0101010101010101010101010101010101010101",
+        ])
+        .unwrap();
+        match cli.command {
+            Command::Jobs {
+                all,
+                status,
+                party,
+                category,
+                tag,
+            } => {
+                assert!(!all);
+                assert_eq!(status, Some(JobStatus::Refunded));
+                assert_eq!(party, Some(Address::from([0x01; 20])));
+                assert_eq!((category, tag), (None, None));
+            }
+            _ => panic!("expected jobs"),
+        }
+        let cli = Cli::try_parse_from([
+            "escrow-cli",
+            "--rpc-url",
+            "http://localhost:8547",
+            "--contract",
+            "0x0000000000000000000000000000000000000001",
+            "jobs",
+            "--category",
+            "3",
+            "--tag",
+            "logo",
+        ])
+        .unwrap();
+        match cli.command {
+            Command::Jobs { category, tag, .. } => {
+                assert_eq!(category, Some(3));
+                assert_eq!(tag.map(decode_memo).as_deref(), Some("logo"));
+            }
+            _ => panic!("expected jobs"),
+        }
+        let cli = Cli::try_parse_from([
+            "escrow-cli",
+            "--rpc-url",
+            "http://localhost:8547",
+            "--contract",
+            "0x0000000000000000000000000000000000000001",
+            "rate",
+            "7",
+            "5",
+        ])
+        .unwrap();
+        assert!(matches!(
+            cli.command,
+            Command::Rate { job_id, stars } if job_id == U256::from(7) && stars == 5
+        ));
+        let cli = Cli::try_parse_from([
+            "escrow-cli",
+            "--rpc-url",
+            "http://localhost:8547",
+            "--contract",
+            "0x0000000000000000000000000000000000000001",
+            "dispute",
+            "7",
+            "--memo",
+            "TICKET-7",
+        ])
+        .unwrap();
+        match cli.command {
+            Command::Dispute { job_id, memo } => {
+                assert_eq!(job_id, U256::from(7));
+                assert_eq!(decode_memo(memo.value()), "TICKET-7");
+            }
+            _ => panic!("expected dispute"),
+        }
+    }
+
+    #[test]
+    fn test_describe_log() {
+        let event = Released {
+            job_id: U256::from(3),
+            amount: U256::from(1_000_000_000_000_000_000_u64),
+            memo: encode_memo("INV-0042").unwrap(),
+        };
+        let log = Log {
+            inner: alloy::primitives::Log {
+                address: Address::ZERO,
+                data: event.encode_log_data(),
+            },
+            ..Default::default()
+        };
+        assert_eq!(
+            describe_log(&log).unwrap(),
+            "Released job=3 amount=1.000000000000000000 ETH memo=INV-0042"
+        );
+
+        // Unrelated logs are skipped
+        let log = Log {
+            inner: alloy::primitives::Log {
+                address: Address::ZERO,
+                data: LogData::new_unchecked(vec![B256::ZERO], Default::default()),
+            },
+            ..Default::default()
+        };
+        assert_eq!(describe_log(&log), None);
+    }
+}
@@ -0,0 +1,89 @@
+use alloy::contract;
+use alloy::primitives::TxHash;
+use alloy::providers::PendingTransactionError;
+use alloy::transports::TransportError;
+use alloy_sol_types::{Revert, SolError};
+use escrow_types::{ErrorCode, EscrowError};
+use std::fmt;
+
+/// Errors returned by [`EscrowClient`](crate::EscrowClient)
+#[derive(Debug)]
+pub enum ClientError {
+    /// The contract reverted with the given message (e.g. "Only client can release")
+    Revert(String),
+    /// The transaction was mined but failed
+    Failed(TxHash),
+    /// An expected event was not found in the receipt
+    MissingEvent(&'static str),
+    /// RPC or ABI decoding failure
+    Rpc(contract::Error),
+    /// Failure while waiting for the transaction to be mined
+    Pending(PendingTransactionError),
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Revert(msg) => write!(f, "escrow reverted: {msg}"),
+            Self::Failed(hash) => write!(f, "transaction {hash} failed"),
+            Self::MissingEvent(name) => write!(f, "receipt has no {name} event"),
+            Self::Rpc(err) => write!(f, "rpc error: {err}"),
+            Self::Pending(err) => write!(f, "pending transaction error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<contract::Error> for ClientError {
+    fn from(err: contract::Error) -> Self {
+        if let contract::Error::TransportError(err) = &err {
+            if let Some(msg) = revert_message(err) {
+                return Self::Revert(msg);
+            }
+        }
+        Self::Rpc(err)
+    }
+}
+
+impl From<PendingTransactionError> for ClientError {
+    fn from(err: PendingTransactionError) -> Self {
+        if let PendingTransactionError::TransportError(err) = &err {
+            if let Some(msg) = revert_message(err) {
+                return Self::Revert(msg);
+            }
+        }
+        Self::Pending(err)
+    }
+}
+
+fn revert_message(err: &TransportError) -> Option<String> {
+    let data = err.as_error_resp()?.as_revert_data()?;
+    Some(decode_revert(&data))
+}
+
+/// Decode contract revert data into a readable message.
+///
+/// The escrow reverts with `EscrowError(code)`, which is turned back into the
+/// error's message. Standard `Error(string)` payloads and plain UTF-8 bytes,
+/// as raised by the Solidity reference or the contracts the escrow calls,
+/// are also accepted.
+pub fn decode_revert(data: &[u8]) -> String {
+    if data.starts_with(&EscrowError::SELECTOR) {
+        if let Ok(error) = EscrowError::abi_decode(data, true) {
+            return match ErrorCode::from_code(error.code) {
+                Some(error) => error.message().to_string(),
+                None => format!("escrow error {}", error.code),
+            };
+        }
+    }
+    if data.starts_with(&Revert::SELECTOR) {
+        if let Ok(revert) = Revert::abi_decode(data, true) {
+            return revert.reason;
+        }
+    }
+    match std::str::from_utf8(data) {
+        Ok(msg) => msg.to_string(),
+        Err(_) => format!("0x{}", alloy::hex::encode(data)),
+    }
+}
@@ -0,0 +1,3189 @@
+//! Typed Rust client for the freelance payment escrow contract.
+//!
+//! Wraps an alloy provider with async methods for every escrow entrypoint,
+//! returning plain Rust structs instead of raw ABI tuples and decoding the
+//! contract's revert messages into [`ClientError`].
+
+mod error;
+mod memo;
+mod types;
+
+use alloy::contract;
+use alloy::contract::{CallBuilder, CallDecoder};
+use alloy::network::Ethereum;
+use alloy::primitives::{Address, Bytes, B256, U256};
+use alloy::providers::Provider;
+use alloy::rpc::types::{Filter, TransactionReceipt};
+use alloy::sol;
+use alloy_sol_types::abi::TokenSeq;
+use alloy_sol_types::{sol_data, SolEvent, SolType, SolValue};
+
+pub use error::{decode_revert, ClientError};
+pub use escrow_types::events::{
+    AppealBondSettled, AppealPolicySet, ArbitrationPolicySet, ArbitratorListed, ArbitratorReplaced,
+    AttestationPolicySet, AuthorizationCancelled, AutoReleased, BasketFunded, BasketPaid,
+    BasketTokenSet, CancellationNoticeSet, CancellationRequested, ClawbackWindowSet,
+    ClientTransferProposed, ComplianceHookSet, ContributionReclaimed, CreditDeposited,
+    CreditWithdrawn, DefaultJobCapSet, DeliverySubmitted, DepositLimitExemptionSet,
+    DepositLimitsSet, Deposited, DisputeOpened, DisputeResolved, DisputeStakePolicySet,
+    DisputeStakeSettled, DisputeTimedOut, DisputeTimeoutPolicySet, DormancyAnnounced,
+    DormancyPolicySet, DormantSwept, DualApprovalRequired, EmergencyConfirmed, EmergencyProposed,
+    EmergencyRefundBatch, EmergencyRefundClaimed, EmergencyRefunded, EscrowEvent,
+    EvidenceSubmitted, ExcessCredited, FeeCharged, FeeExemptionSet, FeeManagerSet, FeeTiersSet,
+    FeesWithdrawn, FreelancerChanged, FundingOpened, FundsReturned, GovernanceExecuted,
+    GovernanceProposed, GovernanceSet, GovernanceVoted, GuardiansSet, HoldbackRetained,
+    HoldbackSet, HourlyRateSet, InvoiceCreated, InvoiceFunded, InvoicePruned, JobAccepted,
+    JobAmended, JobArbitratorSet, JobAttested, JobCapSet, JobFunded, JobRated, JobRevealed,
+    JobStaked, JobUnstaked, JurorLeft, JurorStaked, JuryDrawn, JuryPolicySet, JuryTallied,
+    JuryVoted, LateRefunded, MaxPauseSet, OfferCancelled, OwnershipTransferred, PartiallyReleased,
+    PauseExtended, PauseToggled, PauserSet, PayoutAddressProposed, PayoutAddressSet, PayoutPushed,
+    PayoutSwapFailed, PayoutSwapped, PayoutTokenSet, PlatformFeeSet, PrivateDeposited,
+    ProtocolFeeSet, Reclaimed, RefundRootSet, Refunded, ReleaseApproved, ReleaseConditionSet,
+    ReleaseQueued, ReleaseReversed, Released, RenounceProposed, RetainerStarted, RetainerStopped,
+    RulingAppealed, RulingHeld, SettlementHookFailed, SettlementHookSet, SettlementRebateSet,
+    SettlementRebated, SlaSet, StakeConsented, StakingTokenSet, SunsetBegun, SunsetFinalized,
+    SurplusSwept, SwapRouterSet, TimesheetApproved, TimesheetRejected, TimesheetSubmitted,
+    TrancheClaimed, TrancheRefunded, TreasuryProposed, TreasurySet, VolumeDiscountsSet, Withheld,
+    WithholdingSet, YieldSplitSet, APPEAL_BOND_SETTLED_TOPIC, APPEAL_POLICY_SET_TOPIC,
+    ARBITRATION_POLICY_SET_TOPIC, ARBITRATOR_LISTED_TOPIC, ARBITRATOR_REPLACED_TOPIC,
+    ATTESTATION_POLICY_SET_TOPIC, AUTHORIZATION_CANCELLED_TOPIC, AUTO_RELEASED_TOPIC,
+    BASKET_FUNDED_TOPIC, BASKET_PAID_TOPIC, BASKET_TOKEN_SET_TOPIC, CANCELLATION_NOTICE_SET_TOPIC,
+    CANCELLATION_REQUESTED_TOPIC, CLAWBACK_WINDOW_SET_TOPIC, CLIENT_TRANSFER_PROPOSED_TOPIC,
+    COMPLIANCE_HOOK_SET_TOPIC, CONTRIBUTION_RECLAIMED_TOPIC, CREDIT_DEPOSITED_TOPIC,
+    CREDIT_WITHDRAWN_TOPIC, DEFAULT_JOB_CAP_SET_TOPIC, DELIVERY_SUBMITTED_TOPIC, DEPOSITED_TOPIC,
+    DEPOSIT_LIMITS_SET_TOPIC, DEPOSIT_LIMIT_EXEMPTION_SET_TOPIC, DISPUTE_OPENED_TOPIC,
+    DISPUTE_RESOLVED_TOPIC, DISPUTE_STAKE_POLICY_SET_TOPIC, DISPUTE_STAKE_SETTLED_TOPIC,
+    DISPUTE_TIMED_OUT_TOPIC, DISPUTE_TIMEOUT_POLICY_SET_TOPIC, DORMANCY_ANNOUNCED_TOPIC,
+    DORMANCY_POLICY_SET_TOPIC, DORMANT_SWEPT_TOPIC, DUAL_APPROVAL_REQUIRED_TOPIC,
+    EMERGENCY_CONFIRMED_TOPIC, EMERGENCY_PROPOSED_TOPIC, EMERGENCY_REFUNDED_TOPIC,
+    EMERGENCY_REFUND_BATCH_TOPIC, EMERGENCY_REFUND_CLAIMED_TOPIC, EVENT_TOPICS,
+    EVIDENCE_SUBMITTED_TOPIC, EXCESS_CREDITED_TOPIC, FEES_WITHDRAWN_TOPIC, FEE_CHARGED_TOPIC,
+    FEE_EXEMPTION_SET_TOPIC, FEE_MANAGER_SET_TOPIC, FEE_TIERS_SET_TOPIC, FREELANCER_CHANGED_TOPIC,
+    FUNDING_OPENED_TOPIC, FUNDS_RETURNED_TOPIC, GOVERNANCE_EXECUTED_TOPIC,
+    GOVERNANCE_PROPOSED_TOPIC, GOVERNANCE_SET_TOPIC, GOVERNANCE_VOTED_TOPIC, GUARDIANS_SET_TOPIC,
+    HOLDBACK_RETAINED_TOPIC, HOLDBACK_SET_TOPIC, HOURLY_RATE_SET_TOPIC, INVOICE_CREATED_TOPIC,
+    INVOICE_FUNDED_TOPIC, INVOICE_PRUNED_TOPIC, JOB_ACCEPTED_TOPIC, JOB_AMENDED_TOPIC,
+    JOB_ARBITRATOR_SET_TOPIC, JOB_ATTESTED_TOPIC, JOB_CAP_SET_TOPIC, JOB_FUNDED_TOPIC,
+    JOB_RATED_TOPIC, JOB_REVEALED_TOPIC, JOB_STAKED_TOPIC, JOB_UNSTAKED_TOPIC, JUROR_LEFT_TOPIC,
+    JUROR_STAKED_TOPIC, JURY_DRAWN_TOPIC, JURY_POLICY_SET_TOPIC, JURY_TALLIED_TOPIC,
+    JURY_VOTED_TOPIC, LATE_REFUNDED_TOPIC, MAX_PAUSE_SET_TOPIC, OFFER_CANCELLED_TOPIC,
+    OWNERSHIP_TRANSFERRED_TOPIC, PARTIALLY_RELEASED_TOPIC, PAUSER_SET_TOPIC, PAUSE_EXTENDED_TOPIC,
+    PAUSE_TOGGLED_TOPIC, PAYOUT_ADDRESS_PROPOSED_TOPIC, PAYOUT_ADDRESS_SET_TOPIC,
+    PAYOUT_PUSHED_TOPIC, PAYOUT_SWAPPED_TOPIC, PAYOUT_SWAP_FAILED_TOPIC, PAYOUT_TOKEN_SET_TOPIC,
+    PLATFORM_FEE_SET_TOPIC, PRIVATE_DEPOSITED_TOPIC, PROTOCOL_FEE_SET_TOPIC, RECLAIMED_TOPIC,
+    REFUNDED_TOPIC, REFUND_ROOT_SET_TOPIC, RELEASED_TOPIC, RELEASE_APPROVED_TOPIC,
+    RELEASE_CONDITION_SET_TOPIC, RELEASE_QUEUED_TOPIC, RELEASE_REVERSED_TOPIC,
+    RENOUNCE_PROPOSED_TOPIC, RETAINER_STARTED_TOPIC, RETAINER_STOPPED_TOPIC, RULING_APPEALED_TOPIC,
+    RULING_HELD_TOPIC, SETTLEMENT_HOOK_FAILED_TOPIC, SETTLEMENT_HOOK_SET_TOPIC,
+    SETTLEMENT_REBATED_TOPIC, SETTLEMENT_REBATE_SET_TOPIC, SLA_SET_TOPIC, STAKE_CONSENTED_TOPIC,
+    STAKING_TOKEN_SET_TOPIC, SUNSET_BEGUN_TOPIC, SUNSET_FINALIZED_TOPIC, SURPLUS_SWEPT_TOPIC,
+    SWAP_ROUTER_SET_TOPIC, TIMESHEET_APPROVED_TOPIC, TIMESHEET_REJECTED_TOPIC,
+    TIMESHEET_SUBMITTED_TOPIC, TRANCHE_CLAIMED_TOPIC, TRANCHE_REFUNDED_TOPIC,
+    TREASURY_PROPOSED_TOPIC, TREASURY_SET_TOPIC, VOLUME_DISCOUNTS_SET_TOPIC, WITHHELD_TOPIC,
+    WITHHOLDING_SET_TOPIC, YIELD_SPLIT_SET_TOPIC,
+};
+pub use escrow_types::{
+    job_commitment, offer_domain, offer_hash, refund_leaf, settlement_hash, verdict_hash,
+    ErrorCode, EscrowError, JobOffer, JobStatus, Settlement, Verdict, BASIS_POINTS, CLAIM_WINDOW,
+    DISPUTE_LOSS_WEIGHT, DORMANCY_NOTICE_PERIOD, EMERGENCY_ACTION_EXTEND_PAUSE,
+    EMERGENCY_ACTION_REFUND, EMERGENCY_ACTION_SWEEP_SURPLUS, EMERGENCY_PROPOSAL_TTL,
+    EMERGENCY_REFUND_GRACE, EMERGENCY_REFUND_MIN_AGE, EPOCH_LENGTH,
+    GOVERNANCE_ACTION_ADD_COUNCIL_MEMBER, GOVERNANCE_ACTION_APPEAL, GOVERNANCE_ACTION_ARBITRATOR,
+    GOVERNANCE_ACTION_DEFAULT_JOB_CAP, GOVERNANCE_ACTION_DISPUTE_TIMEOUT,
+    GOVERNANCE_ACTION_DORMANCY_PERIOD, GOVERNANCE_ACTION_PROTOCOL_FEE, GOVERNANCE_ACTION_QUORUM,
+    GOVERNANCE_ACTION_REFUND_ROOT, GOVERNANCE_ACTION_REMOVE_COUNCIL_MEMBER,
+    GOVERNANCE_ACTION_REPLACE_ARBITRATOR, GOVERNANCE_ACTION_RULING_PERIOD,
+    GOVERNANCE_ACTION_SETTING, GOVERNANCE_ACTION_TREASURY, GOVERNANCE_EXECUTION_WINDOW,
+    GOVERNANCE_TIMELOCK, GOVERNANCE_VOTING_PERIOD, JOB_FIELD_AMOUNT, JOB_FIELD_CLIENT,
+    JOB_FIELD_DEADLINE, JOB_FIELD_FREELANCER, JOB_FIELD_METADATA, JOB_FIELD_PAYOUT_ADDRESS,
+    JOB_STATUS_ACTIVE, JOB_STATUS_ANY, JOB_STATUS_REFUNDED, JOB_STATUS_RELEASED, JOB_STATUS_SWEPT,
+    MAX_ADMIN_PAUSE, MAX_APPEAL_BOND_BPS, MAX_BASKET_TOKENS, MAX_CLAWBACK_WINDOW, MAX_COUNCIL,
+    MAX_DISPUTE_STAKE_BPS, MAX_EVIDENCE, MAX_FEE_TIERS, MAX_GUARDIANS, MAX_JOBS_RANGE,
+    MAX_JURY_SIZE, MAX_LISTED_ARBITRATORS, MAX_PLATFORM_FEE_BPS, MAX_PROTOCOL_FEE_BPS,
+    MAX_REFUND_BATCH, MAX_RETAINER_PERIODS, MAX_STARS, MAX_TRANCHES, MAX_UNSTAKE_SLIPPAGE_BPS,
+    MIN_DORMANCY_PERIOD, MIN_STAKE_DURATION, PAYOUT_ADDRESS_DELAY, PAYOUT_CALL_GAS, RENOUNCE_DELAY,
+    REPUTATION_HALF_LIFE, REPUTATION_PRIOR, SETTING_APPEAL_POLICY, SETTING_ARBITRATION_POLICY,
+    SETTING_ARBITRATOR_LISTING, SETTING_ATTESTATION_POLICY, SETTING_BASKET_TOKEN,
+    SETTING_COMPLIANCE_HOOK, SETTING_DEFAULT_JOB_CAP, SETTING_DEPOSIT_LIMITS,
+    SETTING_DEPOSIT_LIMIT_EXEMPT, SETTING_DISPUTE_STAKE_POLICY, SETTING_DISPUTE_TIMEOUT_POLICY,
+    SETTING_DORMANCY_POLICY, SETTING_FEE_EXEMPT, SETTING_FEE_MANAGER, SETTING_FEE_TIERS,
+    SETTING_JURY_POLICY, SETTING_MAX_PAUSE, SETTING_PAUSER, SETTING_PROTOCOL_FEE,
+    SETTING_SETTLEMENT_REBATE, SETTING_STAKING_TOKEN, SETTING_SWAP_ROUTER,
+    SETTING_VOLUME_DISCOUNTS, SETTLEMENT_HOOK_GAS, TIMESHEET_APPROVED, TIMESHEET_REJECTED,
+    TIMESHEET_SUBMITTED, TREASURY_DELAY, UNSTAKE_WINDOW,
+};
+pub use memo::{decode_memo, encode_memo};
+pub use types::{
+    Appeal, AppealPolicy, ArbitrationPolicy, AttestationPolicy, BasketItem, Cancellation,
+    CategoryStats, Clawback, ClientProfile, DepositLimits, Dispute, DisputeStakePolicy,
+    DisputeTimeoutPolicy, Dormancy, DormancyPolicy, DualApproval, EmergencyProposal, Engagement,
+    Evidence, FeeQuote, FeeTier, FreelancerProfile, Funding, Governance, GovernanceProposal,
+    Guardians, HeldRuling, Holdback, Invoice, Job, JobCap, JobStake, JobSummary, Juror, Jury,
+    JuryPolicy, PayoutAddress, PayoutToken, PeriodStats, PlatformFee, ProtocolStats, Reputation,
+    Retainer, SettlementPreview, Sla, Sunset, SwapRouter, Timesheet, Tranche, Treasury, UserStats,
+    VolumeDiscount, Withholding,
+};
+
+sol! {
+    /// Solidity view of the escrow's public entrypoints.
+    #[sol(rpc)]
+    #[allow(clippy::too_many_arguments)]
+    interface IEscrow {
+        function deposit(address freelancer, uint64 duration, bytes32 memo) external payable returns (uint256);
+        function depositCategorized(address freelancer, uint64 duration, bytes32 memo, uint16 category, bytes32 tag) external payable returns (uint256);
+        function depositWithArbitrator(address freelancer, uint64 duration, bytes32 memo, address arbitrator) external payable returns (uint256);
+        function depositWithNonce(address freelancer, uint64 duration, uint256 nonce) external payable returns (uint256);
+        function getJobByNonce(address client, uint256 nonce) external view returns (uint256);
+        function acceptOffer(address client, uint256 amount, uint64 duration, bytes32 memo, uint256 nonce, uint64 expiry, bytes signature) external returns (uint256);
+        function cancelOffer(uint256 nonce) external;
+        function isOfferCancelled(address client, uint256 nonce) external view returns (bool);
+        function releaseWithSignature(uint256 job_id, bytes32 memo, uint256 nonce, uint64 expiry, bytes signature) external;
+        function refundWithSignature(uint256 job_id, address signer, bytes32 memo, uint256 nonce, uint64 expiry, bytes signature) external;
+        function getSettlementDigest(uint256 job_id, address signer, bool release, bytes32 memo, uint256 nonce, uint64 expiry) external view returns (bytes32);
+        function verifySignature(address signer, bytes32 digest, bytes signature) external view returns (bool);
+        function cancelAuthorization(uint256 nonce) external;
+        function isAuthorizationUsed(address signer, uint256 nonce) external view returns (bool);
+        function depositPrivate(bytes32 commitment, uint64 duration) external payable returns (uint256);
+        function revealJob(uint256 job_id, address freelancer, uint256 amount, bytes32 terms, bytes32 salt) external;
+        function getCommitment(uint256 job_id) external view returns (bytes32);
+        function setSettlementHook(uint256 job_id, address hook) external;
+        function getSettlementHook(uint256 job_id) external view returns (address);
+        function release(uint256 job_id, bytes32 memo) external;
+        function releasePartial(uint256 job_id, uint256 amount) external;
+        function refund(uint256 job_id, bytes32 memo) external;
+        function autoRelease(uint256 job_id, bytes32 memo) external;
+        function depositWithDualApproval(address freelancer, uint64 duration) external payable returns (uint256);
+        function approveRelease(uint256 job_id) external;
+        function consentToStake(uint256 job_id, uint16 freelancer_yield_bps, uint16 treasury_yield_bps) external;
+        function unstakeJob(uint256 job_id) external payable;
+        function getJobStake(uint256 job_id) external view returns (address token, uint256 principal, uint256 shares, uint16 freelancer_yield_bps, uint16 treasury_yield_bps, address consented_by, uint16 max_slippage_bps);
+        function getDualApproval(uint256 job_id) external view returns (bool required, bool client_approved, bool freelancer_approved);
+        function reclaim(uint256 job_id, bytes32 memo) external;
+        function returnFunds(uint256 job_id, bytes32 memo) external;
+        function configure(uint8 setting, bytes data) external;
+        function getSetting(uint8 setting, address key) external view returns (bytes);
+        function setPaused(bool state) external;
+        function unpauseExpired() external;
+        function transferOwnership(address new_admin) external;
+        function proposeRenounce() external;
+        function renounceOwnership() external;
+        function getRenounceAt() external view returns (uint64);
+        function emergencyRefund(uint256 job_id) external;
+        function emergencyRefundBatch(uint256[] job_ids) external returns (bool[] memory);
+        function setRefundRoot(bytes32 root) external;
+        function getRefundRoot() external view returns (bytes32);
+        function isRefundClaimed(uint256 job_id) external view returns (bool);
+        function claimEmergencyRefund(uint256 job_id, uint256 amount, bytes32[] proof) external;
+        function beginSunset(uint64 timestamp) external;
+        function finalizeSunset() external;
+        function announceDormant(uint256 job_id) external;
+        function sweepDormant(uint256 job_id) external;
+        function getJob(uint256 job_id) external view returns (uint256 id, address client, address freelancer, uint256 amount, uint64 deadline, bool released, bool refunded);
+        function getActiveJobs() external view returns (uint256[] memory);
+        function resolver(uint256 cursor) external view returns (bool can_exec, bytes exec_payload);
+        function getJobsExpiringBefore(uint64 timestamp, uint256 cursor, uint64 limit) external view returns (uint256[] ids, uint256 next);
+        function queryJobs(uint8 status, address party, uint256 cursor, uint64 limit) external view returns (uint256[] ids, address[] clients, address[] freelancers, uint256[] amounts, uint64[] deadlines, uint8[] statuses, uint256 next);
+        function queryJobsByCategory(uint16 category, bytes32 tag, uint8 status, uint256 cursor, uint64 limit) external view returns (uint256[] ids, address[] clients, address[] freelancers, uint256[] amounts, uint64[] deadlines, uint8[] statuses, uint256 next);
+        function getJobCategory(uint256 job_id) external view returns (uint16 category, bytes32 tag);
+        function getCategoryStats(uint16 category) external view returns (uint256 jobs, uint256 deposited);
+        function getJobsRange(uint256 start_id, uint64 count) external view returns (address[] clients, address[] freelancers, uint256[] amounts, uint64[] deadlines, bool[] released, bool[] refunded);
+        function getTotalJobs() external view returns (uint256);
+        function isPaused() external view returns (bool);
+        function getProtocolStats() external view returns (uint256 deposited, uint256 released, uint256 refunded, uint256 swept, uint256 fees_collected, uint256 disputed);
+        function verifySolvency() external view returns (bool);
+        function getUserStats(address user) external view returns (uint256 jobs_as_client, uint256 jobs_as_freelancer, uint256 deposited, uint256 earned, uint256 refunded, uint256 locked, uint256 pending);
+        function rateJob(uint256 job_id, uint8 stars) external;
+        function getJobRatings(uint256 job_id) external view returns (uint8 by_client, uint8 by_freelancer);
+        function getReputation(address account) external view returns (uint32 ratings, uint32 stars_total, uint32 disputes_lost, uint256 weighted_stars, uint256 weight, uint32 jobs_completed, uint64 average_settlement_time, uint32 disputes, uint16 refund_bps, uint32 client_disputes, uint16 dispute_loss_bps);
+        function getScore(address account) external view returns (uint16);
+        function getPeriodStats(uint64 epoch) external view returns (uint256 jobs_created, uint256 deposited, uint256 released, uint256 refunded);
+        function getSunset() external view returns (uint64 sunset_at, bool retired);
+        function proposeTreasury(address treasury) external;
+        function applyTreasury() external;
+        function getTreasury() external view returns (address treasury, address pending, uint64 effective_at);
+        function sweepSurplus() external;
+        function setGuardians(address[] guardians, uint8 threshold) external;
+        function getGuardians() external view returns (address[] guardians, uint8 threshold);
+        function proposeEmergency(uint8 action, uint256 target) external returns (uint256);
+        function confirmEmergency(uint256 proposal_id) external;
+        function getEmergencyProposal(uint256 proposal_id) external view returns (uint8 action, uint256 target, address proposer, uint64 expires_at, uint8 confirmations, bool executed);
+        function setGovernance(address token, address[] council, uint256 quorum) external;
+        function getGovernance() external view returns (address token, address[] council, uint256 quorum);
+        function proposeGovernance(uint8 action, address target, uint256 value, bytes data) external returns (uint256);
+        function voteGovernance(uint256 proposal_id, bool support) external;
+        function executeGovernance(uint256 proposal_id) external;
+        function getGovernanceProposal(uint256 proposal_id) external view returns (uint8 action, address target, uint256 value, address proposer, uint64 voting_ends, uint256 votes_for, uint256 votes_against, bool executed, bytes data);
+        function getPauseUntil() external view returns (uint64);
+        function getDormancy(uint256 job_id) external view returns (uint64 sweep_after, bool swept);
+        function setPayoutToken(address token, uint256 min_rate) external;
+        function getPayoutToken(address freelancer) external view returns (address token, uint256 min_rate);
+        function proposePayoutAddress(address payout_address) external;
+        function applyPayoutAddress(address freelancer) external;
+        function getPayoutAddress(address freelancer) external view returns (address current, address pending, uint64 effective_at);
+        function setWithholding(uint16 rate_bps, address destination) external;
+        function getWithholding(address freelancer) external view returns (uint16 rate_bps, address destination);
+        function previewRelease(uint256 job_id) external view returns (uint256 freelancer, uint256 withheld, uint256 client, uint256 fee, uint256 platform_fee, uint256 retained);
+        function previewRefund(uint256 job_id) external view returns (uint256 freelancer, uint256 withheld, uint256 client, uint256 fee, uint256 platform_fee, uint256 retained);
+        function quoteFee(address client, uint256 amount, address token, address platform, uint16 platform_bps) external view returns (uint256 protocol_fee, uint256 platform_fee, uint256 net);
+        function withdrawFees(address to, uint256 amount) external;
+        function getAccruedFees() external view returns (uint256);
+        function getJobFee(uint256 job_id) external view returns (uint16);
+        function getClientVolume(address client) external view returns (uint256);
+        function depositAttested(address freelancer, uint64 duration, bytes32 uid) external payable returns (uint256);
+        function getAttestation(uint256 job_id) external view returns (bytes32);
+        function depositWithCondition(address freelancer, uint64 duration, address condition) external payable returns (uint256);
+        function getReleaseCondition(uint256 job_id) external view returns (address);
+        function depositWithPlatform(address freelancer, uint64 duration, bytes32 memo, address platform, uint16 platform_bps) external payable returns (uint256);
+        function getPlatformFee(uint256 job_id) external view returns (address platform, uint16 fee_bps);
+        function depositWithNotice(address freelancer, uint64 duration, uint64 notice) external payable returns (uint256);
+        function getCancellation(uint256 job_id) external view returns (uint64 notice, uint64 effective_at);
+        function requestCancellation(uint256 job_id) external;
+        function depositWithHoldback(address freelancer, uint64 duration, uint16 holdback_bps, uint64 warranty) external payable returns (uint256);
+        function getHoldback(uint256 job_id) external view returns (uint16 holdback_bps, uint64 warranty, uint64 release_at);
+        function releaseHoldback(uint256 job_id) external;
+        function depositWithClawback(address freelancer, uint64 duration, uint64 window) external payable returns (uint256);
+        function getClawback(uint256 job_id) external view returns (uint64 window, uint64 payable_at);
+        function depositWithSla(address freelancer, uint64 duration, uint64 delivery_by, uint16 late_bps_per_day) external payable returns (uint256);
+        function getSla(uint256 job_id) external view returns (uint64 delivery_by, uint16 late_bps_per_day, uint64 delivered_at);
+        function finalizeRelease(uint256 job_id) external;
+        function reverseRelease(uint256 job_id) external;
+        function extendDeadline(uint256 job_id, uint64 new_deadline) external;
+        function transferClient(uint256 job_id, address new_client) external;
+        function acceptClient(uint256 job_id) external;
+        function getPendingClient(uint256 job_id) external view returns (address);
+        function acceptJob(uint256 job_id) external;
+        function isJobAccepted(uint256 job_id) external view returns (bool);
+        function reassignFreelancer(uint256 job_id, address new_freelancer) external;
+        function submitDelivery(uint256 job_id, bytes32 delivery) external;
+        function openDispute(uint256 job_id, bytes32 memo) external payable;
+        function submitEvidence(uint256 job_id, bytes32 evidence_hash) external;
+        function getDispute(uint256 job_id) external view returns (address opened_by, uint64 opened_at, address arbitrator, uint64 rule_by, uint16 default_split_bps, bool open);
+        function rule(uint256 job_id, uint16 freelancer_bps) external;
+        function ruleWithSignature(uint256 job_id, uint16 freelancer_bps, uint256 nonce, uint64 expiry, bytes signature) external;
+        function getVerdictDigest(uint256 job_id, uint16 freelancer_bps, uint256 nonce, uint64 expiry) external view returns (bytes32);
+        function resolveByDefault(uint256 job_id) external;
+        function timeOutDispute(uint256 job_id) external;
+        function getDisputeTimeout(uint256 job_id) external view returns (uint64 timeout_at, bool split, uint16 split_bps);
+        function quoteDisputeStake(uint256 job_id) external view returns (uint256);
+        function getDisputeStake(uint256 job_id) external view returns (uint256);
+        function joinJury() external payable;
+        function leaveJury() external;
+        function getJuror(address juror) external view returns (uint256 stake, bool registered, uint32 panels);
+        function getJurorCount() external view returns (uint256);
+        function escalateToJury(uint256 job_id) external;
+        function castJuryVote(uint256 job_id, uint16 freelancer_bps) external;
+        function tallyJury(uint256 job_id) external;
+        function getJury(uint256 job_id) external view returns (address[] panel, uint64 vote_by, uint8 votes_cast, bool tallied);
+        function getJuryVote(uint256 job_id, address juror) external view returns (bool voted, uint16 freelancer_bps);
+        function quoteAppealBond(uint256 job_id) external view returns (uint256);
+        function appealRuling(uint256 job_id, uint16 freelancer_bps) external payable returns (uint256);
+        function finalizeRuling(uint256 job_id) external;
+        function getHeldRuling(uint256 job_id) external view returns (address resolver, uint16 freelancer_bps, uint64 appealable_until);
+        function getAppeal(uint256 job_id) external view returns (address appellant, uint16 freelancer_bps, uint256 bond, uint256 proposal_id);
+        function getEvidence(uint256 job_id) external view returns (bytes32[] hashes, address[] submitters, uint64[] submitted_at);
+        function depositTranched(address freelancer, uint256[] amounts, uint64[] unlocks) external payable returns (uint256);
+        function claimTranche(uint256 job_id, uint256 index) external;
+        function refundTranche(uint256 job_id, uint256 index) external;
+        function getTranches(uint256 job_id) external view returns (uint256[] amounts, uint64[] unlocks, bool[] settled);
+        function depositBasket(address freelancer, uint64 duration, address[] tokens, uint256[] amounts) external payable returns (uint256);
+        function getBasket(uint256 job_id) external view returns (address[] tokens, uint256[] amounts);
+        function depositHourly(address freelancer, uint64 duration, uint256 rate) external payable returns (uint256);
+        function submitTimesheet(uint256 job_id, uint32 hours, bytes32 details) external returns (uint256);
+        function approveTimesheet(uint256 job_id, uint256 index) external;
+        function rejectTimesheet(uint256 job_id, uint256 index) external;
+        function endEngagement(uint256 job_id) external;
+        function getEngagement(uint256 job_id) external view returns (uint256 rate, uint256 pending);
+        function getTimesheets(uint256 job_id) external view returns (uint32[] hours, bytes32[] details, uint8[] statuses);
+        function depositRetainer(address freelancer, uint64 periods, uint64 period, uint64 notice) external payable returns (uint256);
+        function claimUnlocked(uint256 job_id) external;
+        function stopRetainer(uint256 job_id) external;
+        function getRetainer(uint256 job_id) external view returns (uint64 period, uint64 notice);
+        function depositExact(address freelancer, uint64 duration, uint256 amount) external payable returns (uint256);
+        function withdrawCredit() external;
+        function depositCredit() external payable;
+        function setJobCap(uint32 cap) external;
+        function getJobCap(address freelancer) external view returns (uint32 cap, uint32 active);
+        function getCredit(address account) external view returns (uint256);
+        function createJob(address freelancer, uint64 duration, uint256 target, uint64 cutoff) external payable returns (uint256);
+        function fund(uint256 job_id) external payable;
+        function reclaimContribution(uint256 job_id) external;
+        function getFunding(uint256 job_id) external view returns (uint256 target, uint256 raised, uint64 cutoff);
+        function getContribution(uint256 job_id, address contributor) external view returns (uint256);
+        function createInvoice(address client, uint256 amount, uint64 duration, bytes32 metadata, uint64 expiry) external returns (uint256);
+        function fundInvoice(uint256 invoice_id) external payable returns (uint256);
+        function pruneInvoices(uint256[] invoice_ids) external returns (uint32);
+        function getInvoice(uint256 invoice_id) external view returns (address freelancer, address client, uint256 amount, uint64 duration, bytes32 metadata, uint64 expires_at, uint256 job_id);
+    }
+
+    /// The WETH the escrow wraps payouts into
+    #[sol(rpc)]
+    interface IWETH {
+        function withdraw(uint256 amount) external;
+    }
+}
+
+/// Client for a deployed escrow contract.
+#[derive(Clone)]
+pub struct EscrowClient<P> {
+    contract: IEscrow::IEscrowInstance<(), P>,
+}
+
+impl<P: Provider<Ethereum>> EscrowClient<P> {
+    /// Create a client for the escrow at `address`.
+    ///
+    /// Transactions are signed by whatever wallet the provider was built with.
+    pub fn new(address: Address, provider: P) -> Self {
+        Self {
+            contract: IEscrow::new(address, provider),
+        }
+    }
+
+    /// Address of the escrow contract
+    pub fn address(&self) -> Address {
+        *self.contract.address()
+    }
+
+    /// Underlying provider
+    pub fn provider(&self) -> &P {
+        self.contract.provider()
+    }
+
+    /// Deposit `amount` wei for `freelancer`, returning the new job ID
+    pub async fn deposit(
+        &self,
+        freelancer: Address,
+        duration: u64,
+        amount: U256,
+    ) -> Result<U256, ClientError> {
+        self.deposit_with_memo(freelancer, duration, amount, B256::ZERO)
+            .await
+    }
+
+    /// [`deposit`](Self::deposit) with a memo, e.g. from [`encode_memo`]
+    pub async fn deposit_with_memo(
+        &self,
+        freelancer: Address,
+        duration: u64,
+        amount: U256,
+        memo: B256,
+    ) -> Result<U256, ClientError> {
+        let receipt = send(
+            self.contract
+                .deposit(freelancer, duration, memo)
+                .value(amount),
+        )
+        .await?;
+        deposited_job(&receipt)
+    }
+
+    /// [`deposit_with_memo`](Self::deposit_with_memo) funded from the
+    /// caller's WETH, unwrapping `amount` first; the escrow only holds ETH
+    /// and can't unwrap WETH itself, as that would call back into it.
+    ///
+    /// This is two transactions and not atomic: if the deposit fails, the
+    /// unwrapped ETH stays in the caller's wallet.
+    pub async fn deposit_weth(
+        &self,
+        freelancer: Address,
+        duration: u64,
+        amount: U256,
+        memo: B256,
+    ) -> Result<U256, ClientError> {
+        let weth = self.get_swap_router().await?.weth;
+        if weth == Address::ZERO {
+            return Err(ClientError::Revert(
+                ErrorCode::InvalidWETHAddress.message().to_string(),
+            ));
+        }
+        let token = IWETH::new(weth, self.provider());
+        send(token.withdraw(amount)).await?;
+        self.deposit_with_memo(freelancer, duration, amount, memo)
+            .await
+    }
+
+    /// [`deposit_with_memo`](Self::deposit_with_memo) filing the job under
+    /// `category` and `tag`, so marketplaces can segment jobs by kind of
+    /// work; zero means none
+    pub async fn deposit_categorized(
+        &self,
+        freelancer: Address,
+        duration: u64,
+        amount: U256,
+        memo: B256,
+        category: u16,
+        tag: B256,
+    ) -> Result<U256, ClientError> {
+        let receipt = send(
+            self.contract
+                .depositCategorized(freelancer, duration, memo, category, tag)
+                .value(amount),
+        )
+        .await?;
+        deposited_job(&receipt)
+    }
+
+    /// [`deposit_with_memo`](Self::deposit_with_memo) naming the `arbitrator`
+    /// who rules on the job's disputes instead of the global one, e.g. one
+    /// from [`get_listed_arbitrators`](Self::get_listed_arbitrators)
+    pub async fn deposit_with_arbitrator(
+        &self,
+        freelancer: Address,
+        duration: u64,
+        amount: U256,
+        memo: B256,
+        arbitrator: Address,
+    ) -> Result<U256, ClientError> {
+        let receipt = send(
+            self.contract
+                .depositWithArbitrator(freelancer, duration, memo, arbitrator)
+                .value(amount),
+        )
+        .await?;
+        deposited_job(&receipt)
+    }
+
+    /// [`deposit`](Self::deposit) for a verified freelancer: succeeds only if
+    /// `uid` is a live EAS attestation of `freelancer` under the admin's
+    /// [`AttestationPolicy`], and records it on the job
+    pub async fn deposit_attested(
+        &self,
+        freelancer: Address,
+        duration: u64,
+        amount: U256,
+        uid: B256,
+    ) -> Result<U256, ClientError> {
+        let receipt = send(
+            self.contract
+                .depositAttested(freelancer, duration, uid)
+                .value(amount),
+        )
+        .await?;
+        deposited_job(&receipt)
+    }
+
+    /// [`deposit`](Self::deposit) whose auto-release also waits for
+    /// `condition`'s `canRelease(job_id)` to return true
+    pub async fn deposit_with_condition(
+        &self,
+        freelancer: Address,
+        duration: u64,
+        amount: U256,
+        condition: Address,
+    ) -> Result<U256, ClientError> {
+        let receipt = send(
+            self.contract
+                .depositWithCondition(freelancer, duration, condition)
+                .value(amount),
+        )
+        .await?;
+        deposited_job(&receipt)
+    }
+
+    /// [`deposit_with_memo`](Self::deposit_with_memo) through a marketplace
+    /// `platform`, which takes `platform_bps` of every payout to the
+    /// freelancer on top of the protocol fee
+    pub async fn deposit_with_platform(
+        &self,
+        freelancer: Address,
+        duration: u64,
+        amount: U256,
+        memo: B256,
+        platform: Address,
+        platform_bps: u16,
+    ) -> Result<U256, ClientError> {
+        let receipt = send(
+            self.contract
+                .depositWithPlatform(freelancer, duration, memo, platform, platform_bps)
+                .value(amount),
+        )
+        .await?;
+        deposited_job(&receipt)
+    }
+
+    /// Open a job escrowing exactly `amount`, paying `paid`; anything above
+    /// `amount` is credited back, see [`withdraw_credit`](Self::withdraw_credit)
+    pub async fn deposit_exact(
+        &self,
+        freelancer: Address,
+        duration: u64,
+        amount: U256,
+        paid: U256,
+    ) -> Result<U256, ClientError> {
+        let receipt = send(
+            self.contract
+                .depositExact(freelancer, duration, amount)
+                .value(paid),
+        )
+        .await?;
+        deposited_job(&receipt)
+    }
+
+    /// Withdraw everything credited to the signer
+    pub async fn withdraw_credit(&self) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.withdrawCredit()).await
+    }
+
+    /// Add `amount` wei to the signer's withdrawable balance, e.g. to fund
+    /// the job offers they sign
+    pub async fn deposit_credit(&self, amount: U256) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.depositCredit().value(amount)).await
+    }
+
+    /// Digest the client signs to make `offer` through this escrow
+    pub async fn offer_hash(&self, offer: &JobOffer) -> Result<B256, ClientError> {
+        let chain_id = self
+            .provider()
+            .get_chain_id()
+            .await
+            .map_err(contract::Error::TransportError)?;
+        Ok(offer_hash(offer, chain_id, self.address()))
+    }
+
+    /// As `offer.freelancer`, open the job a client offered with
+    /// `signature`, returning its ID; the client's credit pays for it
+    pub async fn accept_offer(
+        &self,
+        offer: &JobOffer,
+        signature: Bytes,
+    ) -> Result<U256, ClientError> {
+        let receipt = send(self.contract.acceptOffer(
+            offer.client,
+            offer.amount,
+            offer.duration,
+            offer.memo,
+            offer.nonce,
+            offer.expiry,
+            signature,
+        ))
+        .await?;
+        deposited_job(&receipt)
+    }
+
+    /// Withdraw the offer the signer made with `nonce` before it is accepted
+    pub async fn cancel_offer(&self, nonce: U256) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.cancelOffer(nonce)).await
+    }
+
+    /// Digest `settlement.signer` signs to authorize `settlement` through
+    /// this escrow
+    pub async fn settlement_hash(&self, settlement: &Settlement) -> Result<B256, ClientError> {
+        let chain_id = self
+            .provider()
+            .get_chain_id()
+            .await
+            .map_err(contract::Error::TransportError)?;
+        Ok(settlement_hash(settlement, chain_id, self.address()))
+    }
+
+    /// Send the release or refund a party authorized with `signature`; any
+    /// account can submit it, such as an ERC-4337 bundler
+    pub async fn submit_settlement(
+        &self,
+        settlement: &Settlement,
+        signature: Bytes,
+    ) -> Result<TransactionReceipt, ClientError> {
+        if settlement.release {
+            send(self.contract.releaseWithSignature(
+                settlement.job_id,
+                settlement.memo,
+                settlement.nonce,
+                settlement.expiry,
+                signature,
+            ))
+            .await
+        } else {
+            send(self.contract.refundWithSignature(
+                settlement.job_id,
+                settlement.signer,
+                settlement.memo,
+                settlement.nonce,
+                settlement.expiry,
+                signature,
+            ))
+            .await
+        }
+    }
+
+    /// Withdraw the settlement or verdict the signer authorized with `nonce`
+    /// before it is submitted
+    pub async fn cancel_authorization(
+        &self,
+        nonce: U256,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.cancelAuthorization(nonce)).await
+    }
+
+    /// Whether the escrow accepts `signature` as `signer`'s over `digest`,
+    /// asking contract accounts under ERC-1271
+    pub async fn verify_signature(
+        &self,
+        signer: Address,
+        digest: B256,
+        signature: Bytes,
+    ) -> Result<bool, ClientError> {
+        Ok(self
+            .contract
+            .verifySignature(signer, digest, signature)
+            .call()
+            .await?
+            ._0)
+    }
+
+    /// [`deposit`](Self::deposit) that can only be refunded `notice` seconds
+    /// after the client requests cancellation
+    pub async fn deposit_with_notice(
+        &self,
+        freelancer: Address,
+        duration: u64,
+        amount: U256,
+        notice: u64,
+    ) -> Result<U256, ClientError> {
+        let receipt = send(
+            self.contract
+                .depositWithNotice(freelancer, duration, notice)
+                .value(amount),
+        )
+        .await?;
+        deposited_job(&receipt)
+    }
+
+    /// [`deposit`](Self::deposit) that holds `holdback_bps` of the job back
+    /// for `warranty` seconds after it is released
+    pub async fn deposit_with_holdback(
+        &self,
+        freelancer: Address,
+        duration: u64,
+        amount: U256,
+        holdback_bps: u16,
+        warranty: u64,
+    ) -> Result<U256, ClientError> {
+        let receipt = send(
+            self.contract
+                .depositWithHoldback(freelancer, duration, holdback_bps, warranty)
+                .value(amount),
+        )
+        .await?;
+        deposited_job(&receipt)
+    }
+
+    /// [`deposit`](Self::deposit) whose release only pays out after a
+    /// `window`-second clawback window, during which the arbitrator can
+    /// reverse it
+    pub async fn deposit_with_clawback(
+        &self,
+        freelancer: Address,
+        duration: u64,
+        amount: U256,
+        window: u64,
+    ) -> Result<U256, ClientError> {
+        let receipt = send(
+            self.contract
+                .depositWithClawback(freelancer, duration, window)
+                .value(amount),
+        )
+        .await?;
+        deposited_job(&receipt)
+    }
+
+    /// [`deposit`](Self::deposit) of `amount` wei together with `amounts[i]`
+    /// of each admin-approved ERC-20 `tokens[i]`, which the client must have
+    /// approved the escrow to pull
+    pub async fn deposit_basket(
+        &self,
+        freelancer: Address,
+        duration: u64,
+        amount: U256,
+        tokens: Vec<Address>,
+        amounts: Vec<U256>,
+    ) -> Result<U256, ClientError> {
+        let receipt = send(
+            self.contract
+                .depositBasket(freelancer, duration, tokens, amounts)
+                .value(amount),
+        )
+        .await?;
+        deposited_job(&receipt)
+    }
+
+    /// [`deposit`](Self::deposit) of a budget for an hourly engagement,
+    /// billed at `rate` wei per hour through timesheets
+    pub async fn deposit_hourly(
+        &self,
+        freelancer: Address,
+        duration: u64,
+        amount: U256,
+        rate: U256,
+    ) -> Result<U256, ClientError> {
+        let receipt = send(
+            self.contract
+                .depositHourly(freelancer, duration, rate)
+                .value(amount),
+        )
+        .await?;
+        deposited_job(&receipt)
+    }
+
+    /// [`deposit`](Self::deposit) for a job due by `delivery_by` that
+    /// refunds the client `late_bps_per_day` of it for every full day it is
+    /// delivered late
+    pub async fn deposit_with_sla(
+        &self,
+        freelancer: Address,
+        duration: u64,
+        amount: U256,
+        delivery_by: u64,
+        late_bps_per_day: u16,
+    ) -> Result<U256, ClientError> {
+        let receipt = send(
+            self.contract
+                .depositWithSla(freelancer, duration, delivery_by, late_bps_per_day)
+                .value(amount),
+        )
+        .await?;
+        deposited_job(&receipt)
+    }
+
+    /// [`deposit`](Self::deposit) that is only released once both parties
+    /// have called [`approve_release`](Self::approve_release)
+    pub async fn deposit_with_dual_approval(
+        &self,
+        freelancer: Address,
+        duration: u64,
+        amount: U256,
+    ) -> Result<U256, ClientError> {
+        let receipt = send(
+            self.contract
+                .depositWithDualApproval(freelancer, duration)
+                .value(amount),
+        )
+        .await?;
+        deposited_job(&receipt)
+    }
+
+    /// Open a job for `target` wei, paying `amount` of it now; it becomes
+    /// active once [`fund`](Self::fund) payments make up the rest, and can be
+    /// reclaimed by its contributors if that has not happened by `cutoff`
+    pub async fn create_job(
+        &self,
+        freelancer: Address,
+        duration: u64,
+        target: U256,
+        cutoff: u64,
+        amount: U256,
+    ) -> Result<U256, ClientError> {
+        let receipt = send(
+            self.contract
+                .createJob(freelancer, duration, target, cutoff)
+                .value(amount),
+        )
+        .await?;
+        receipt
+            .inner
+            .logs()
+            .iter()
+            .filter(|log| log.topic0() == Some(&FUNDING_OPENED_TOPIC))
+            .find_map(|log| FundingOpened::decode_log_data(log.data(), true).ok())
+            .map(|event| event.job_id)
+            .ok_or(ClientError::MissingEvent("FundingOpened"))
+    }
+
+    /// Pay `amount` towards a job's funding target
+    pub async fn fund(
+        &self,
+        job_id: U256,
+        amount: U256,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.fund(job_id).value(amount)).await
+    }
+
+    /// As freelancer, bill `client` `amount` wei for a job lasting
+    /// `duration` seconds, payable until `expiry`, returning the invoice ID;
+    /// `metadata` becomes the job's memo once it is paid
+    pub async fn create_invoice(
+        &self,
+        client: Address,
+        amount: U256,
+        duration: u64,
+        metadata: B256,
+        expiry: u64,
+    ) -> Result<U256, ClientError> {
+        let receipt = send(
+            self.contract
+                .createInvoice(client, amount, duration, metadata, expiry),
+        )
+        .await?;
+        receipt
+            .inner
+            .logs()
+            .iter()
+            .filter(|log| log.topic0() == Some(&INVOICE_CREATED_TOPIC))
+            .find_map(|log| InvoiceCreated::decode_log_data(log.data(), true).ok())
+            .map(|event| event.invoice_id)
+            .ok_or(ClientError::MissingEvent("InvoiceCreated"))
+    }
+
+    /// As the invoiced client, pay `amount` wei, the invoice's full amount,
+    /// opening its job and returning the job ID
+    pub async fn fund_invoice(&self, invoice_id: U256, amount: U256) -> Result<U256, ClientError> {
+        let receipt = send(self.contract.fundInvoice(invoice_id).value(amount)).await?;
+        deposited_job(&receipt)
+    }
+
+    /// Clear expired, unpaid invoices from storage; other IDs are skipped
+    pub async fn prune_invoices(
+        &self,
+        invoice_ids: Vec<U256>,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.pruneInvoices(invoice_ids)).await
+    }
+
+    /// Take back your contributions to a job that missed its funding cutoff
+    pub async fn reclaim_contribution(
+        &self,
+        job_id: U256,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.reclaimContribution(job_id)).await
+    }
+
+    /// Open a job paid in tranches, given as `(amount, unlock time)` pairs
+    /// in unlock order; the deposit is their total
+    pub async fn deposit_tranched(
+        &self,
+        freelancer: Address,
+        tranches: &[(U256, u64)],
+    ) -> Result<U256, ClientError> {
+        let (amounts, unlocks): (Vec<U256>, Vec<u64>) = tranches.iter().copied().unzip();
+        let total = amounts.iter().sum::<U256>();
+        let receipt = send(
+            self.contract
+                .depositTranched(freelancer, amounts, unlocks)
+                .value(total),
+        )
+        .await?;
+        deposited_job(&receipt)
+    }
+
+    /// Fund a retainer of `periods` equal payments of `amount / periods`,
+    /// one claimable at the end of each `period` seconds; the client can
+    /// stop it with `notice` seconds' notice
+    pub async fn deposit_retainer(
+        &self,
+        freelancer: Address,
+        periods: u64,
+        period: u64,
+        notice: u64,
+        amount: U256,
+    ) -> Result<U256, ClientError> {
+        let receipt = send(
+            self.contract
+                .depositRetainer(freelancer, periods, period, notice)
+                .value(amount),
+        )
+        .await?;
+        deposited_job(&receipt)
+    }
+
+    /// [`deposit`](Self::deposit) that succeeds at most once per `nonce`, so
+    /// it is safe to retry after an ambiguous failure; see
+    /// [`get_job_by_nonce`](Self::get_job_by_nonce)
+    pub async fn deposit_with_nonce(
+        &self,
+        freelancer: Address,
+        duration: u64,
+        amount: U256,
+        nonce: U256,
+    ) -> Result<U256, ClientError> {
+        let receipt = send(
+            self.contract
+                .depositWithNonce(freelancer, duration, nonce)
+                .value(amount),
+        )
+        .await?;
+        deposited_job(&receipt)
+    }
+
+    /// Open a private job storing only `commitment` (see [`job_commitment`]),
+    /// escrowing `amount` wei, and return its ID
+    pub async fn deposit_private(
+        &self,
+        commitment: B256,
+        duration: u64,
+        amount: U256,
+    ) -> Result<U256, ClientError> {
+        let receipt = send(
+            self.contract
+                .depositPrivate(commitment, duration)
+                .value(amount),
+        )
+        .await?;
+        receipt
+            .inner
+            .logs()
+            .iter()
+            .filter(|log| log.topic0() == Some(&PRIVATE_DEPOSITED_TOPIC))
+            .find_map(|log| PrivateDeposited::decode_log_data(log.data(), true).ok())
+            .map(|event| event.job_id)
+            .ok_or(ClientError::MissingEvent("PrivateDeposited"))
+    }
+
+    /// Reveal a private job's preimage (client or freelancer)
+    pub async fn reveal_job(
+        &self,
+        job_id: U256,
+        freelancer: Address,
+        amount: U256,
+        terms: B256,
+        salt: B256,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(
+            self.contract
+                .revealJob(job_id, freelancer, amount, terms, salt),
+        )
+        .await
+    }
+
+    /// Attach a contract whose `onReleased`/`onRefunded` is called when the
+    /// job settles, with [`SETTLEMENT_HOOK_GAS`] gas; zero detaches it
+    /// (client only)
+    pub async fn set_settlement_hook(
+        &self,
+        job_id: U256,
+        hook: Address,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.setSettlementHook(job_id, hook)).await
+    }
+
+    /// Release a job's funds to the freelancer (client only)
+    pub async fn release(&self, job_id: U256) -> Result<TransactionReceipt, ClientError> {
+        self.release_with_memo(job_id, B256::ZERO).await
+    }
+
+    /// [`release`](Self::release) with a memo
+    pub async fn release_with_memo(
+        &self,
+        job_id: U256,
+        memo: B256,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.release(job_id, memo)).await
+    }
+
+    /// Release part of a job's escrow to the freelancer, leaving the rest
+    /// escrowed (client only)
+    pub async fn release_partial(
+        &self,
+        job_id: U256,
+        amount: U256,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.releasePartial(job_id, amount)).await
+    }
+
+    /// Refund a job to the client before its deadline (client only)
+    pub async fn refund(&self, job_id: U256) -> Result<TransactionReceipt, ClientError> {
+        self.refund_with_memo(job_id, B256::ZERO).await
+    }
+
+    /// [`refund`](Self::refund) with a memo
+    pub async fn refund_with_memo(
+        &self,
+        job_id: U256,
+        memo: B256,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.refund(job_id, memo)).await
+    }
+
+    /// Start the notice period after which a job with one can be refunded
+    /// (client only)
+    pub async fn request_cancellation(
+        &self,
+        job_id: U256,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.requestCancellation(job_id)).await
+    }
+
+    /// Submit a delivery during a cancellation's notice period, withdrawing
+    /// the cancellation, or on a job with an SLA, stopping the clock on late
+    /// refunds (freelancer only)
+    pub async fn submit_delivery(
+        &self,
+        job_id: U256,
+        delivery: B256,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.submitDelivery(job_id, delivery)).await
+    }
+
+    /// Push a job's deadline back to `deadline` (freelancer only)
+    pub async fn extend_deadline(
+        &self,
+        job_id: U256,
+        deadline: u64,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.extendDeadline(job_id, deadline)).await
+    }
+
+    /// Offer control of a job to `new_client` (client); the zero address
+    /// withdraws the offer
+    pub async fn transfer_client(
+        &self,
+        job_id: U256,
+        new_client: Address,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.transferClient(job_id, new_client)).await
+    }
+
+    /// Take over a job whose client role was offered to the signer
+    pub async fn accept_client(&self, job_id: U256) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.acceptClient(job_id)).await
+    }
+
+    /// Address a job's client role was offered to; zero if none
+    pub async fn get_pending_client(&self, job_id: U256) -> Result<Address, ClientError> {
+        Ok(self.contract.getPendingClient(job_id).call().await?._0)
+    }
+
+    /// Commit to a funded job (freelancer), ending the client's option to
+    /// reassign it
+    pub async fn accept_job(&self, job_id: U256) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.acceptJob(job_id)).await
+    }
+
+    /// Whether the freelancer has accepted a job
+    pub async fn is_job_accepted(&self, job_id: U256) -> Result<bool, ClientError> {
+        Ok(self.contract.isJobAccepted(job_id).call().await?._0)
+    }
+
+    /// Hand a job nobody has accepted yet to `new_freelancer` (client)
+    pub async fn reassign_freelancer(
+        &self,
+        job_id: U256,
+        new_freelancer: Address,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.reassignFreelancer(job_id, new_freelancer)).await
+    }
+
+    /// Open a dispute over a funded, unsettled job (either party, once),
+    /// sending the stake it takes
+    pub async fn open_dispute(&self, job_id: U256) -> Result<TransactionReceipt, ClientError> {
+        self.open_dispute_with_memo(job_id, B256::ZERO).await
+    }
+
+    /// [`open_dispute`](Self::open_dispute) with a memo
+    pub async fn open_dispute_with_memo(
+        &self,
+        job_id: U256,
+        memo: B256,
+    ) -> Result<TransactionReceipt, ClientError> {
+        let stake = self.quote_dispute_stake(job_id).await?;
+        send(self.contract.openDispute(job_id, memo).value(stake)).await
+    }
+
+    /// Add the hash of a piece of evidence to a job's open dispute (either
+    /// party)
+    pub async fn submit_evidence(
+        &self,
+        job_id: U256,
+        evidence_hash: B256,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.submitEvidence(job_id, evidence_hash)).await
+    }
+
+    /// Settle a disputed job, paying `freelancer_bps` of it to the
+    /// freelancer and the rest to the client (assigned arbitrator only,
+    /// within the ruling period)
+    pub async fn rule(
+        &self,
+        job_id: U256,
+        freelancer_bps: u16,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.rule(job_id, freelancer_bps)).await
+    }
+
+    /// Digest a job's arbitrator signs to rule `verdict` through this escrow
+    pub async fn verdict_hash(&self, verdict: &Verdict) -> Result<B256, ClientError> {
+        let chain_id = self
+            .provider()
+            .get_chain_id()
+            .await
+            .map_err(contract::Error::TransportError)?;
+        Ok(verdict_hash(verdict, chain_id, self.address()))
+    }
+
+    /// Send the ruling an arbitrator signed, such as a panel's multisig; any
+    /// account can submit it, and `cancel_authorization` withdraws it first
+    pub async fn submit_verdict(
+        &self,
+        verdict: &Verdict,
+        signature: Bytes,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.ruleWithSignature(
+            verdict.job_id,
+            verdict.freelancer_bps,
+            verdict.nonce,
+            verdict.expiry,
+            signature,
+        ))
+        .await
+    }
+
+    /// Apply a dispute's timeout rule once it has run out, splitting the job
+    /// or lifting the dispute (anyone)
+    pub async fn time_out_dispute(&self, job_id: U256) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.timeOutDispute(job_id)).await
+    }
+
+    /// Put an open dispute to a jury drawn from the registry, taking it out
+    /// of the arbitrator's hands (either party, once)
+    pub async fn escalate_to_jury(&self, job_id: U256) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.escalateToJury(job_id)).await
+    }
+
+    /// Vote, as a juror on its panel, the freelancer's share of a disputed
+    /// job in basis points
+    pub async fn cast_jury_vote(
+        &self,
+        job_id: U256,
+        freelancer_bps: u16,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.castJuryVote(job_id, freelancer_bps)).await
+    }
+
+    /// Count a jury's votes once voting closes, settling the dispute and
+    /// slashing jurors outside the majority (anyone)
+    pub async fn tally_jury(&self, job_id: U256) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.tallyJury(job_id)).await
+    }
+
+    /// Appeal a job's held ruling to governance (either party, within the
+    /// window), proposing the freelancer get `freelancer_bps` instead and
+    /// sending the bond it takes
+    pub async fn appeal_ruling(
+        &self,
+        job_id: U256,
+        freelancer_bps: u16,
+    ) -> Result<TransactionReceipt, ClientError> {
+        let bond = self.quote_appeal_bond(job_id).await?;
+        send(
+            self.contract
+                .appealRuling(job_id, freelancer_bps)
+                .value(bond),
+        )
+        .await
+    }
+
+    /// Settle a job at its held ruling once it can no longer be appealed,
+    /// or its appeal lapsed (anyone)
+    pub async fn finalize_ruling(&self, job_id: U256) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.finalizeRuling(job_id)).await
+    }
+
+    /// Join the juror registry, or add to your stake, staking `amount` wei
+    pub async fn join_jury(&self, amount: U256) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.joinJury().value(amount)).await
+    }
+
+    /// Leave the juror registry with your whole stake
+    pub async fn leave_jury(&self) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.leaveJury()).await
+    }
+
+    /// Settle a dispute at its default split once the arbitrator has let
+    /// the ruling period pass (either party)
+    pub async fn resolve_by_default(
+        &self,
+        job_id: U256,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.resolveByDefault(job_id)).await
+    }
+
+    /// Claim tranche `index` of a job once it has unlocked (freelancer only)
+    pub async fn claim_tranche(
+        &self,
+        job_id: U256,
+        index: U256,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.claimTranche(job_id, index)).await
+    }
+
+    /// Refund tranche `index` of a job before it unlocks (client only)
+    pub async fn refund_tranche(
+        &self,
+        job_id: U256,
+        index: U256,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.refundTranche(job_id, index)).await
+    }
+
+    /// Bill `hours` of work on an hourly engagement (freelancer only)
+    pub async fn submit_timesheet(
+        &self,
+        job_id: U256,
+        hours: u32,
+        details: B256,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.submitTimesheet(job_id, hours, details)).await
+    }
+
+    /// Approve timesheet `index`, paying it out of the budget (client only)
+    pub async fn approve_timesheet(
+        &self,
+        job_id: U256,
+        index: U256,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.approveTimesheet(job_id, index)).await
+    }
+
+    /// Reject timesheet `index` (client only)
+    pub async fn reject_timesheet(
+        &self,
+        job_id: U256,
+        index: U256,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.rejectTimesheet(job_id, index)).await
+    }
+
+    /// End an hourly engagement after its deadline, taking back the unspent
+    /// budget (client only)
+    pub async fn end_engagement(&self, job_id: U256) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.endEngagement(job_id)).await
+    }
+
+    /// Claim every unlocked tranche of a job at once (freelancer only)
+    pub async fn claim_unlocked(&self, job_id: U256) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.claimUnlocked(job_id)).await
+    }
+
+    /// Stop a retainer, refunding the periods that unlock after its notice
+    /// period (client only)
+    pub async fn stop_retainer(&self, job_id: U256) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.stopRetainer(job_id)).await
+    }
+
+    /// Claim a job's funds after its deadline (freelancer only)
+    pub async fn auto_release(&self, job_id: U256) -> Result<TransactionReceipt, ClientError> {
+        self.auto_release_with_memo(job_id, B256::ZERO).await
+    }
+
+    /// [`auto_release`](Self::auto_release) with a memo
+    pub async fn auto_release_with_memo(
+        &self,
+        job_id: U256,
+        memo: B256,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.autoRelease(job_id, memo)).await
+    }
+
+    /// Pay out a job's queued release once its clawback window is over
+    /// (anyone)
+    pub async fn finalize_release(&self, job_id: U256) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.finalizeRelease(job_id)).await
+    }
+
+    /// Reverse a queued release during its clawback window, refunding the
+    /// client (arbitrator only)
+    pub async fn reverse_release(&self, job_id: U256) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.reverseRelease(job_id)).await
+    }
+
+    /// Pay a job's holdback to the freelancer once its warranty window has
+    /// ended without a dispute (anyone)
+    pub async fn release_holdback(&self, job_id: U256) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.releaseHoldback(job_id)).await
+    }
+
+    /// Approve releasing a dual-approval job; the second party's approval
+    /// pays the freelancer (client or freelancer)
+    pub async fn approve_release(&self, job_id: U256) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.approveRelease(job_id)).await
+    }
+
+    /// Consent to holding a job months from its deadline in the staking
+    /// token, with `freelancer_yield_bps` of the yield to the freelancer and
+    /// `treasury_yield_bps` to the treasury; the job is staked once the other
+    /// party consents to the same split (either party)
+    pub async fn consent_to_stake(
+        &self,
+        job_id: U256,
+        freelancer_yield_bps: u16,
+        treasury_yield_bps: u16,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(
+            self.contract
+                .consentToStake(job_id, freelancer_yield_bps, treasury_yield_bps),
+        )
+        .await
+    }
+
+    /// Buy a staked job's shares for `amount` wei, at least their redemption
+    /// value less the job's slippage bound, so the job settles in ETH again
+    /// (a party, or anyone within `UNSTAKE_WINDOW` of the deadline at a
+    /// discount the yield covers)
+    pub async fn unstake_job(
+        &self,
+        job_id: U256,
+        amount: U256,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.unstakeJob(job_id).value(amount)).await
+    }
+
+    /// A job's stake, and whose consent to staking it awaits the other's
+    pub async fn get_job_stake(&self, job_id: U256) -> Result<JobStake, ClientError> {
+        let stake = self.contract.getJobStake(job_id).call().await?;
+        Ok(JobStake {
+            token: stake.token,
+            principal: stake.principal,
+            shares: stake.shares,
+            freelancer_yield_bps: stake.freelancer_yield_bps,
+            treasury_yield_bps: stake.treasury_yield_bps,
+            consented_by: stake.consented_by,
+            max_slippage_bps: stake.max_slippage_bps,
+        })
+    }
+
+    /// Give up a job, refunding everything still escrowed to the client
+    /// (freelancer only)
+    pub async fn return_funds(&self, job_id: U256) -> Result<TransactionReceipt, ClientError> {
+        self.return_funds_with_memo(job_id, B256::ZERO).await
+    }
+
+    /// [`return_funds`](Self::return_funds) with a memo
+    pub async fn return_funds_with_memo(
+        &self,
+        job_id: U256,
+        memo: B256,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.returnFunds(job_id, memo)).await
+    }
+
+    /// Return a job's funds to its client once the freelancer has left them
+    /// unclaimed for `CLAIM_WINDOW` past the deadline (anyone)
+    pub async fn reclaim(&self, job_id: U256) -> Result<TransactionReceipt, ClientError> {
+        self.reclaim_with_memo(job_id, B256::ZERO).await
+    }
+
+    /// [`reclaim`](Self::reclaim) with a memo
+    pub async fn reclaim_with_memo(
+        &self,
+        job_id: U256,
+        memo: B256,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.reclaim(job_id, memo)).await
+    }
+
+    /// Be paid in `token` (zero for ETH), receiving at least `min_rate` token
+    /// units per ETH (freelancer)
+    pub async fn set_payout_token(
+        &self,
+        token: Address,
+        min_rate: U256,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.setPayoutToken(token, min_rate)).await
+    }
+
+    /// Be paid in WETH, wrapped 1:1, or back in ETH (freelancer)
+    pub async fn set_wrapped_payout(
+        &self,
+        wrapped: bool,
+    ) -> Result<TransactionReceipt, ClientError> {
+        if !wrapped {
+            return self.set_payout_token(Address::ZERO, U256::ZERO).await;
+        }
+        let weth = self.get_swap_router().await?.weth;
+        if weth == Address::ZERO {
+            return Err(ClientError::Revert(
+                ErrorCode::InvalidWETHAddress.message().to_string(),
+            ));
+        }
+        // WETH pays 1:1 whatever the rate, so state exactly that
+        let one_to_one = U256::from(1_000_000_000_000_000_000_u64);
+        self.set_payout_token(weth, one_to_one).await
+    }
+
+    /// Have payouts sent to `payout_address` (freelancer), applicable after
+    /// `PAYOUT_ADDRESS_DELAY`; the zero address goes back to direct payment
+    pub async fn propose_payout_address(
+        &self,
+        payout_address: Address,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.proposePayoutAddress(payout_address)).await
+    }
+
+    /// Make `freelancer`'s proposed payout address current once its delay
+    /// has passed (anyone)
+    pub async fn apply_payout_address(
+        &self,
+        freelancer: Address,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.applyPayoutAddress(freelancer)).await
+    }
+
+    /// Withhold `rate_bps` of every payout for tax and send it to
+    /// `destination`; a zero rate turns withholding off (freelancer)
+    pub async fn set_withholding(
+        &self,
+        rate_bps: u16,
+        destination: Address,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.setWithholding(rate_bps, destination)).await
+    }
+
+    /// ADMIN: approve the router that swaps payouts; zero disables swaps
+    pub async fn set_swap_router(
+        &self,
+        router: Address,
+        weth: Address,
+    ) -> Result<TransactionReceipt, ClientError> {
+        self.configure(
+            SETTING_SWAP_ROUTER,
+            (router, weth).abi_encode_params().into(),
+        )
+        .await
+    }
+
+    /// ADMIN: allow or stop new jobs being funded with `token` in a basket
+    pub async fn set_basket_token(
+        &self,
+        token: Address,
+        approved: bool,
+    ) -> Result<TransactionReceipt, ClientError> {
+        self.configure(
+            SETTING_BASKET_TOKEN,
+            (token, approved).abi_encode_params().into(),
+        )
+        .await
+    }
+
+    /// ADMIN: limit how many active jobs a freelancer may hold; 0 removes
+    /// the limit
+    pub async fn set_default_job_cap(&self, cap: u32) -> Result<TransactionReceipt, ClientError> {
+        self.configure(SETTING_DEFAULT_JOB_CAP, (cap,).abi_encode_params().into())
+            .await
+    }
+
+    /// ADMIN: make each client wait `cooldown` seconds between the jobs it
+    /// opens and open at most `per_block` in one block; 0 turns either off
+    pub async fn set_deposit_limits(
+        &self,
+        cooldown: u64,
+        per_block: u32,
+    ) -> Result<TransactionReceipt, ClientError> {
+        self.configure(
+            SETTING_DEPOSIT_LIMITS,
+            (cooldown, per_block).abi_encode_params().into(),
+        )
+        .await
+    }
+
+    /// ADMIN: exempt an address, such as a marketplace, from the deposit
+    /// limits, or end the exemption
+    pub async fn set_deposit_limit_exempt(
+        &self,
+        account: Address,
+        exempt: bool,
+    ) -> Result<TransactionReceipt, ClientError> {
+        self.configure(
+            SETTING_DEPOSIT_LIMIT_EXEMPT,
+            (account, exempt).abi_encode_params().into(),
+        )
+        .await
+    }
+
+    /// ADMIN: hand fee configuration to `manager`; zero gives it back to
+    /// the admin
+    pub async fn set_fee_manager(
+        &self,
+        manager: Address,
+    ) -> Result<TransactionReceipt, ClientError> {
+        self.configure(SETTING_FEE_MANAGER, (manager,).abi_encode_params().into())
+            .await
+    }
+
+    /// FEE MANAGER: send `amount` wei of the accrued protocol fees to `to`
+    pub async fn withdraw_fees(
+        &self,
+        to: Address,
+        amount: U256,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.withdrawFees(to, amount)).await
+    }
+
+    /// FEE MANAGER: set the protocol fee charged on payouts, in basis points
+    pub async fn set_protocol_fee(&self, fee_bps: u16) -> Result<TransactionReceipt, ClientError> {
+        self.configure(SETTING_PROTOCOL_FEE, (fee_bps,).abi_encode_params().into())
+            .await
+    }
+
+    /// FEE MANAGER: rebate up to `amount` wei of each keeper-style
+    /// settlement's protocol fee to its sender; zero turns rebates off
+    pub async fn set_settlement_rebate(
+        &self,
+        amount: U256,
+    ) -> Result<TransactionReceipt, ClientError> {
+        self.configure(
+            SETTING_SETTLEMENT_REBATE,
+            (amount,).abi_encode_params().into(),
+        )
+        .await
+    }
+
+    /// FEE MANAGER: replace the size tiers; minimums must be strictly
+    /// increasing
+    pub async fn set_fee_tiers(
+        &self,
+        tiers: &[FeeTier],
+    ) -> Result<TransactionReceipt, ClientError> {
+        let min_amounts: Vec<U256> = tiers.iter().map(|tier| tier.min_amount).collect();
+        let fee_bps: Vec<u16> = tiers.iter().map(|tier| tier.fee_bps).collect();
+        self.configure(
+            SETTING_FEE_TIERS,
+            (min_amounts, fee_bps).abi_encode_params().into(),
+        )
+        .await
+    }
+
+    /// FEE MANAGER: replace the volume discounts; minimums must be strictly
+    /// increasing
+    pub async fn set_volume_discounts(
+        &self,
+        discounts: &[VolumeDiscount],
+    ) -> Result<TransactionReceipt, ClientError> {
+        let min_volumes: Vec<U256> = discounts.iter().map(|d| d.min_volume).collect();
+        let discount_bps: Vec<u16> = discounts.iter().map(|d| d.discount_bps).collect();
+        self.configure(
+            SETTING_VOLUME_DISCOUNTS,
+            (min_volumes, discount_bps).abi_encode_params().into(),
+        )
+        .await
+    }
+
+    /// ADMIN: exempt a partner's new jobs from the protocol fee, or end the
+    /// exemption
+    pub async fn set_fee_exempt(
+        &self,
+        account: Address,
+        exempt: bool,
+    ) -> Result<TransactionReceipt, ClientError> {
+        self.configure(
+            SETTING_FEE_EXEMPT,
+            (account, exempt).abi_encode_params().into(),
+        )
+        .await
+    }
+
+    /// ADMIN: list an arbitrator clients can pick for their jobs, or unlist
+    /// it
+    pub async fn set_arbitrator_listing(
+        &self,
+        arbitrator: Address,
+        listed: bool,
+    ) -> Result<TransactionReceipt, ClientError> {
+        self.configure(
+            SETTING_ARBITRATOR_LISTING,
+            (arbitrator, listed).abi_encode_params().into(),
+        )
+        .await
+    }
+
+    /// ADMIN: set the liquid staking token long jobs can be held in and the
+    /// discount staked shares can be bought back at; zero stops new stakes
+    pub async fn set_staking_token(
+        &self,
+        token: Address,
+        max_slippage_bps: u16,
+    ) -> Result<TransactionReceipt, ClientError> {
+        self.configure(
+            SETTING_STAKING_TOKEN,
+            (token, max_slippage_bps).abi_encode_params().into(),
+        )
+        .await
+    }
+
+    /// Limit how many active jobs the signer takes on as a freelancer, below
+    /// the admin default if one is set; 0 removes their own limit
+    pub async fn set_job_cap(&self, cap: u32) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.setJobCap(cap)).await
+    }
+
+    /// ADMIN: screen deposits and releases with a compliance contract;
+    /// zero turns screening off
+    pub async fn set_compliance_hook(
+        &self,
+        hook: Address,
+    ) -> Result<TransactionReceipt, ClientError> {
+        self.configure(SETTING_COMPLIANCE_HOOK, (hook,).abi_encode_params().into())
+            .await
+    }
+
+    /// ADMIN: set which EAS attestations verify a freelancer; a zero `eas`
+    /// disables attested deposits
+    pub async fn set_attestation_policy(
+        &self,
+        eas: Address,
+        schema: B256,
+        attester: Address,
+    ) -> Result<TransactionReceipt, ClientError> {
+        self.configure(
+            SETTING_ATTESTATION_POLICY,
+            (eas, schema, attester).abi_encode_params().into(),
+        )
+        .await
+    }
+
+    /// ADMIN: appoint the arbitrator assigned to new disputes, with their
+    /// ruling period and the default freelancer split; a zero arbitrator
+    /// leaves new disputes without one
+    pub async fn set_arbitration_policy(
+        &self,
+        policy: ArbitrationPolicy,
+    ) -> Result<TransactionReceipt, ClientError> {
+        self.configure(
+            SETTING_ARBITRATION_POLICY,
+            (
+                policy.arbitrator,
+                policy.ruling_period,
+                policy.default_split_bps,
+            )
+                .abi_encode_params()
+                .into(),
+        )
+        .await
+    }
+
+    /// ADMIN: cap how long new disputes may stay unresolved, and whether
+    /// they are split or lifted once the cap passes
+    pub async fn set_dispute_timeout_policy(
+        &self,
+        policy: DisputeTimeoutPolicy,
+    ) -> Result<TransactionReceipt, ClientError> {
+        self.configure(
+            SETTING_DISPUTE_TIMEOUT_POLICY,
+            (policy.timeout, policy.split, policy.split_bps)
+                .abi_encode_params()
+                .into(),
+        )
+        .await
+    }
+
+    /// ADMIN: make whoever opens a dispute stake a share of the job, but at
+    /// least a minimum
+    pub async fn set_dispute_stake_policy(
+        &self,
+        policy: DisputeStakePolicy,
+    ) -> Result<TransactionReceipt, ClientError> {
+        self.configure(
+            SETTING_DISPUTE_STAKE_POLICY,
+            (policy.stake_bps, policy.min_stake)
+                .abi_encode_params()
+                .into(),
+        )
+        .await
+    }
+
+    /// ADMIN: set the jury escalated disputes seat; a size of 0 turns
+    /// juries off
+    pub async fn set_jury_policy(
+        &self,
+        policy: JuryPolicy,
+    ) -> Result<TransactionReceipt, ClientError> {
+        // alloy encodes `u8` as bytes; a wider integer fills the same word
+        self.configure(
+            SETTING_JURY_POLICY,
+            (
+                u16::from(policy.size),
+                policy.voting_period,
+                policy.min_stake,
+                policy.slash_bps,
+            )
+                .abi_encode_params()
+                .into(),
+        )
+        .await
+    }
+
+    /// ADMIN: hold rulings for appeal to governance for a window, taking a
+    /// bond of a share of the job, but at least a minimum; a window of 0
+    /// settles rulings at once
+    pub async fn set_appeal_policy(
+        &self,
+        policy: AppealPolicy,
+    ) -> Result<TransactionReceipt, ClientError> {
+        self.configure(
+            SETTING_APPEAL_POLICY,
+            (policy.window, policy.bond_bps, policy.min_bond)
+                .abi_encode_params()
+                .into(),
+        )
+        .await
+    }
+
+    /// ADMIN or FEE MANAGER: change the `SETTING_*` `setting`, with its
+    /// arguments ABI-encoded as a tuple in `data`; the `set_*` methods for
+    /// each setting wrap it
+    pub async fn configure(
+        &self,
+        setting: u8,
+        data: Bytes,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.configure(setting, data)).await
+    }
+
+    /// Current value of the `SETTING_*` `setting`, decoded as the tuple `T`
+    /// `configure` takes for it; `key` picks the account for per-address
+    /// settings and is ignored by the rest
+    pub async fn get_setting<T>(
+        &self,
+        setting: u8,
+        key: Address,
+    ) -> Result<T::RustType, ClientError>
+    where
+        T: SolType,
+        for<'de> T::Token<'de>: TokenSeq<'de>,
+    {
+        let data = self.contract.getSetting(setting, key).call().await?._0;
+        T::abi_decode_params(&data, true).map_err(|err| contract::Error::from(err).into())
+    }
+
+    /// ADMIN: pause/unpause the escrow; the pauser may only pause
+    pub async fn set_paused(&self, state: bool) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.setPaused(state)).await
+    }
+
+    /// ADMIN: let `pauser` pause, but not unpause, the escrow; zero removes
+    /// it
+    pub async fn set_pauser(&self, pauser: Address) -> Result<TransactionReceipt, ClientError> {
+        self.configure(SETTING_PAUSER, (pauser,).abi_encode_params().into())
+            .await
+    }
+
+    /// ADMIN: cap how long a new pause may last, in seconds; 0 removes the
+    /// cap
+    pub async fn set_max_pause(&self, duration: u64) -> Result<TransactionReceipt, ClientError> {
+        self.configure(SETTING_MAX_PAUSE, (duration,).abi_encode_params().into())
+            .await
+    }
+
+    /// Clear a pause that has outlived its end (anyone)
+    pub async fn unpause_expired(&self) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.unpauseExpired()).await
+    }
+
+    /// ADMIN: transfer ownership
+    pub async fn transfer_ownership(
+        &self,
+        new_admin: Address,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.transferOwnership(new_admin)).await
+    }
+
+    /// ADMIN: start the `RENOUNCE_DELAY` countdown to giving up the admin
+    /// role
+    pub async fn propose_renounce(&self) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.proposeRenounce()).await
+    }
+
+    /// ADMIN: permanently remove the admin once the renounce delay is over
+    pub async fn renounce_ownership(&self) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.renounceOwnership()).await
+    }
+
+    /// ADMIN: force refund a job to its client
+    pub async fn emergency_refund(&self, job_id: U256) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.emergencyRefund(job_id)).await
+    }
+
+    /// ADMIN: force refund up to `MAX_REFUND_BATCH` jobs, skipping those
+    /// that can't be; the `EmergencyRefundBatch` log reports which were
+    pub async fn emergency_refund_batch(
+        &self,
+        job_ids: Vec<U256>,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.emergencyRefundBatch(job_ids)).await
+    }
+
+    /// ADMIN: publish the Merkle root of `refund_leaf` entries clients claim
+    /// emergency refunds against; zero stops claims
+    pub async fn set_refund_root(&self, root: B256) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.setRefundRoot(root)).await
+    }
+
+    /// Claim the emergency refund of `amount` listed for a job in the
+    /// published Merkle root, proven by `proof` (client only)
+    pub async fn claim_emergency_refund(
+        &self,
+        job_id: U256,
+        amount: U256,
+        proof: Vec<B256>,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.claimEmergencyRefund(job_id, amount, proof)).await
+    }
+
+    /// ADMIN: close deposits for good from `timestamp` on
+    pub async fn begin_sunset(&self, timestamp: u64) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.beginSunset(timestamp)).await
+    }
+
+    /// ADMIN: retire the escrow once the sunset is reached and all jobs are settled
+    pub async fn finalize_sunset(&self) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.finalizeSunset()).await
+    }
+
+    /// ADMIN: sweep jobs unsettled for `period` seconds past their deadline to `recovery`
+    pub async fn set_dormancy_policy(
+        &self,
+        period: u64,
+        recovery: Address,
+    ) -> Result<TransactionReceipt, ClientError> {
+        self.configure(
+            SETTING_DORMANCY_POLICY,
+            (period, recovery).abi_encode_params().into(),
+        )
+        .await
+    }
+
+    /// ADMIN: announce a dormant job, starting its notice period
+    pub async fn announce_dormant(&self, job_id: U256) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.announceDormant(job_id)).await
+    }
+
+    /// ADMIN: sweep an announced dormant job to the recovery address
+    pub async fn sweep_dormant(&self, job_id: U256) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.sweepDormant(job_id)).await
+    }
+
+    /// ADMIN: propose a new treasury, applicable after `TREASURY_DELAY`
+    pub async fn propose_treasury(
+        &self,
+        treasury: Address,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.proposeTreasury(treasury)).await
+    }
+
+    /// Make the proposed treasury current once its delay has passed (anyone)
+    pub async fn apply_treasury(&self) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.applyTreasury()).await
+    }
+
+    /// ADMIN: send the balance beyond what the escrow owes to the treasury;
+    /// only while no guardians are appointed
+    pub async fn sweep_surplus(&self) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.sweepSurplus()).await
+    }
+
+    /// ADMIN: appoint `guardians`, `threshold` of whom must confirm emergency
+    /// actions; an empty set with threshold 0 removes them
+    pub async fn set_guardians(
+        &self,
+        guardians: Vec<Address>,
+        threshold: u8,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.setGuardians(guardians, threshold)).await
+    }
+
+    /// GUARDIAN: propose and confirm an `EMERGENCY_ACTION_*` on `target`
+    pub async fn propose_emergency(
+        &self,
+        action: u8,
+        target: U256,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.proposeEmergency(action, target)).await
+    }
+
+    /// GUARDIAN: confirm an emergency proposal
+    pub async fn confirm_emergency(
+        &self,
+        proposal_id: U256,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.confirmEmergency(proposal_id)).await
+    }
+
+    /// ADMIN: hand the governed parameters to a `council` or to holders of
+    /// the votes `token`, passing proposals with `quorum` votes (once)
+    pub async fn set_governance(
+        &self,
+        token: Address,
+        council: Vec<Address>,
+        quorum: U256,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.setGovernance(token, council, quorum)).await
+    }
+
+    /// Propose a `GOVERNANCE_ACTION_*` with its `target` address or `value`,
+    /// and for a governed setting its ABI-encoded arguments in `data`
+    pub async fn propose_governance(
+        &self,
+        action: u8,
+        target: Address,
+        value: U256,
+        data: Bytes,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.proposeGovernance(action, target, value, data)).await
+    }
+
+    /// Back or oppose a governance proposal with all of the signer's votes
+    pub async fn vote_governance(
+        &self,
+        proposal_id: U256,
+        support: bool,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.voteGovernance(proposal_id, support)).await
+    }
+
+    /// Carry out a passed governance proposal once its timelock is over
+    /// (anyone)
+    pub async fn execute_governance(
+        &self,
+        proposal_id: U256,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.executeGovernance(proposal_id)).await
+    }
+
+    /// Fetch a job by ID
+    pub async fn get_job(&self, job_id: U256) -> Result<Job, ClientError> {
+        let job = self.contract.getJob(job_id).call().await?;
+        Ok(Job {
+            id: job.id,
+            client: job.client,
+            freelancer: job.freelancer,
+            amount: job.amount,
+            deadline: job.deadline,
+            released: job.released,
+            refunded: job.refunded,
+        })
+    }
+
+    /// Up to `count` consecutive jobs from `start_id` on, at most
+    /// `MAX_JOBS_RANGE`; fewer (or none) once the newest job is reached
+    pub async fn get_jobs_range(
+        &self,
+        start_id: U256,
+        count: u64,
+    ) -> Result<Vec<Job>, ClientError> {
+        let range = self.contract.getJobsRange(start_id, count).call().await?;
+        Ok(range
+            .clients
+            .into_iter()
+            .zip(range.freelancers)
+            .zip(range.amounts)
+            .zip(range.deadlines)
+            .zip(range.released.into_iter().zip(range.refunded))
+            .enumerate()
+            .map(
+                |(i, ((((client, freelancer), amount), deadline), (released, refunded)))| Job {
+                    id: start_id + U256::from(i),
+                    client,
+                    freelancer,
+                    amount,
+                    deadline,
+                    released,
+                    refunded,
+                },
+            )
+            .collect())
+    }
+
+    /// Up to `limit` jobs with `status` that `party` is currently client or
+    /// freelancer of, either filter left out with `None`, reading from
+    /// `cursor` (0 to start); also returns the cursor to resume from, or
+    /// `None` once every job was scanned. Without a party each call reads at
+    /// most `MAX_JOBS_RANGE` job IDs, so a short page can still have more
+    pub async fn query_jobs(
+        &self,
+        status: Option<JobStatus>,
+        party: Option<Address>,
+        cursor: U256,
+        limit: u64,
+    ) -> Result<(Vec<JobSummary>, Option<U256>), ClientError> {
+        let status = status.map_or(JOB_STATUS_ANY, JobStatus::code);
+        let party = party.unwrap_or(Address::ZERO);
+        let page = self
+            .contract
+            .queryJobs(status, party, cursor, limit)
+            .call()
+            .await?;
+        let jobs = job_summaries(
+            page.ids,
+            page.clients,
+            page.freelancers,
+            page.amounts,
+            page.deadlines,
+            page.statuses,
+        );
+        Ok((jobs, (!page.next.is_zero()).then_some(page.next)))
+    }
+
+    /// Up to `limit` jobs filed under `category` and, unless `tag` is zero,
+    /// `tag`, with `status` if given; paged like
+    /// [`query_jobs`](Self::query_jobs)
+    pub async fn query_jobs_by_category(
+        &self,
+        category: u16,
+        tag: B256,
+        status: Option<JobStatus>,
+        cursor: U256,
+        limit: u64,
+    ) -> Result<(Vec<JobSummary>, Option<U256>), ClientError> {
+        let status = status.map_or(JOB_STATUS_ANY, JobStatus::code);
+        let page = self
+            .contract
+            .queryJobsByCategory(category, tag, status, cursor, limit)
+            .call()
+            .await?;
+        let jobs = job_summaries(
+            page.ids,
+            page.clients,
+            page.freelancers,
+            page.amounts,
+            page.deadlines,
+            page.statuses,
+        );
+        Ok((jobs, (!page.next.is_zero()).then_some(page.next)))
+    }
+
+    /// A job's category and tag; zero if it was filed under none
+    pub async fn get_job_category(&self, job_id: U256) -> Result<(u16, B256), ClientError> {
+        let category = self.contract.getJobCategory(job_id).call().await?;
+        Ok((category.category, category.tag))
+    }
+
+    /// Jobs filed under `category` and the ETH deposited in them
+    pub async fn get_category_stats(&self, category: u16) -> Result<CategoryStats, ClientError> {
+        let stats = self.contract.getCategoryStats(category).call().await?;
+        Ok(CategoryStats {
+            jobs: stats.jobs,
+            deposited: stats.deposited,
+        })
+    }
+
+    /// Up to `limit` active jobs whose deadline falls before `timestamp`,
+    /// scanning the active-job set from `cursor` (0 to start); also returns
+    /// the cursor to resume from, or `None` once the set was scanned
+    pub async fn get_jobs_expiring_before(
+        &self,
+        timestamp: u64,
+        cursor: U256,
+        limit: u64,
+    ) -> Result<(Vec<U256>, Option<U256>), ClientError> {
+        let page = self
+            .contract
+            .getJobsExpiringBefore(timestamp, cursor, limit)
+            .call()
+            .await?;
+        Ok((page.ids, (!page.next.is_zero()).then_some(page.next)))
+    }
+
+    /// Who would receive what if `job_id` were released now
+    pub async fn preview_release(&self, job_id: U256) -> Result<SettlementPreview, ClientError> {
+        let preview = self.contract.previewRelease(job_id).call().await?;
+        Ok(SettlementPreview {
+            freelancer: preview.freelancer,
+            withheld: preview.withheld,
+            client: preview.client,
+            fee: preview.fee,
+            platform_fee: preview.platform_fee,
+            retained: preview.retained,
+        })
+    }
+
+    /// Who would receive what if `job_id` were refunded now
+    pub async fn preview_refund(&self, job_id: U256) -> Result<SettlementPreview, ClientError> {
+        let preview = self.contract.previewRefund(job_id).call().await?;
+        Ok(SettlementPreview {
+            freelancer: preview.freelancer,
+            withheld: preview.withheld,
+            client: preview.client,
+            fee: preview.fee,
+            platform_fee: preview.platform_fee,
+            retained: preview.retained,
+        })
+    }
+
+    /// Fees on a hypothetical job `client` would open paying `amount` of
+    /// `token` (zero for ETH), after their volume discount or exemption,
+    /// with `platform`'s address and rate if it would be opened through one
+    pub async fn quote_fee(
+        &self,
+        client: Address,
+        amount: U256,
+        token: Address,
+        platform: Option<(Address, u16)>,
+    ) -> Result<FeeQuote, ClientError> {
+        let (platform, platform_bps) = platform.unwrap_or_default();
+        let quote = self
+            .contract
+            .quoteFee(client, amount, token, platform, platform_bps)
+            .call()
+            .await?;
+        Ok(FeeQuote {
+            protocol_fee: quote.protocol_fee,
+            platform_fee: quote.platform_fee,
+            net: quote.net,
+        })
+    }
+
+    /// IDs of all unsettled jobs
+    pub async fn get_active_jobs(&self) -> Result<Vec<U256>, ClientError> {
+        Ok(self.contract.getActiveJobs().call().await?._0)
+    }
+
+    /// Gelato Automate checker: whether a settlement anyone can send is due
+    /// among the `MAX_JOBS_RANGE` active jobs from position `cursor`, and the
+    /// calldata to send to the escrow for it, or the reason there is none
+    pub async fn resolver(&self, cursor: U256) -> Result<(bool, Bytes), ClientError> {
+        let check = self.contract.resolver(cursor).call().await?;
+        Ok((check.can_exec, check.exec_payload))
+    }
+
+    /// Total number of jobs created
+    pub async fn get_total_jobs(&self) -> Result<U256, ClientError> {
+        Ok(self.contract.getTotalJobs().call().await?._0)
+    }
+
+    /// Whether the escrow is paused
+    pub async fn is_paused(&self) -> Result<bool, ClientError> {
+        Ok(self.contract.isPaused().call().await?._0)
+    }
+
+    /// Cumulative protocol volume
+    pub async fn get_protocol_stats(&self) -> Result<ProtocolStats, ClientError> {
+        let stats = self.contract.getProtocolStats().call().await?;
+        Ok(ProtocolStats {
+            deposited: stats.deposited,
+            released: stats.released,
+            refunded: stats.refunded,
+            swept: stats.swept,
+            fees_collected: stats.fees_collected,
+            disputed: stats.disputed,
+        })
+    }
+
+    /// Whether the contract's balance covers every unsettled job
+    pub async fn verify_solvency(&self) -> Result<bool, ClientError> {
+        Ok(self.contract.verifySolvency().call().await?._0)
+    }
+
+    /// Sunset schedule and whether the escrow is retired
+    pub async fn get_sunset(&self) -> Result<Sunset, ClientError> {
+        let sunset = self.contract.getSunset().call().await?;
+        Ok(Sunset {
+            sunset_at: sunset.sunset_at,
+            retired: sunset.retired,
+        })
+    }
+
+    /// Current dormancy policy
+    pub async fn get_dormancy_policy(&self) -> Result<DormancyPolicy, ClientError> {
+        let (period, recovery) = self
+            .get_setting::<(sol_data::Uint<64>, sol_data::Address)>(
+                SETTING_DORMANCY_POLICY,
+                Address::ZERO,
+            )
+            .await?;
+        Ok(DormancyPolicy { period, recovery })
+    }
+
+    /// Current and pending treasury
+    pub async fn get_treasury(&self) -> Result<Treasury, ClientError> {
+        let treasury = self.contract.getTreasury().call().await?;
+        Ok(Treasury {
+            treasury: treasury.treasury,
+            pending: treasury.pending,
+            effective_at: treasury.effective_at,
+        })
+    }
+
+    /// Guardian set and the confirmations an emergency action needs
+    pub async fn get_guardians(&self) -> Result<Guardians, ClientError> {
+        let guardians = self.contract.getGuardians().call().await?;
+        Ok(Guardians {
+            guardians: guardians.guardians,
+            threshold: guardians.threshold,
+        })
+    }
+
+    /// Fetch an emergency proposal by ID
+    pub async fn get_emergency_proposal(
+        &self,
+        proposal_id: U256,
+    ) -> Result<EmergencyProposal, ClientError> {
+        let proposal = self
+            .contract
+            .getEmergencyProposal(proposal_id)
+            .call()
+            .await?;
+        Ok(EmergencyProposal {
+            action: proposal.action,
+            target: proposal.target,
+            proposer: proposal.proposer,
+            expires_at: proposal.expires_at,
+            confirmations: proposal.confirmations,
+            executed: proposal.executed,
+        })
+    }
+
+    /// Who governs the protocol parameters and the votes a proposal needs
+    pub async fn get_governance(&self) -> Result<Governance, ClientError> {
+        let governance = self.contract.getGovernance().call().await?;
+        Ok(Governance {
+            token: governance.token,
+            council: governance.council,
+            quorum: governance.quorum,
+        })
+    }
+
+    /// Fetch a governance proposal by ID
+    pub async fn get_governance_proposal(
+        &self,
+        proposal_id: U256,
+    ) -> Result<GovernanceProposal, ClientError> {
+        let proposal = self
+            .contract
+            .getGovernanceProposal(proposal_id)
+            .call()
+            .await?;
+        Ok(GovernanceProposal {
+            action: proposal.action,
+            target: proposal.target,
+            value: proposal.value,
+            proposer: proposal.proposer,
+            voting_ends: proposal.voting_ends,
+            votes_for: proposal.votes_for,
+            votes_against: proposal.votes_against,
+            executed: proposal.executed,
+            data: proposal.data,
+        })
+    }
+
+    /// When the running pause ends by itself; 0 if it doesn't
+    pub async fn get_pause_until(&self) -> Result<u64, ClientError> {
+        Ok(self.contract.getPauseUntil().call().await?._0)
+    }
+
+    /// Account allowed to pause the escrow besides the admin (zero if none)
+    pub async fn get_pauser(&self) -> Result<Address, ClientError> {
+        let (value,) = self
+            .get_setting::<(sol_data::Address,)>(SETTING_PAUSER, Address::ZERO)
+            .await?;
+        Ok(value)
+    }
+
+    /// When the admin may renounce; 0 if no renounce is pending
+    pub async fn get_renounce_at(&self) -> Result<u64, ClientError> {
+        Ok(self.contract.getRenounceAt().call().await?._0)
+    }
+
+    /// Longest a new pause may last, in seconds (0 for no limit)
+    pub async fn get_max_pause(&self) -> Result<u64, ClientError> {
+        let (value,) = self
+            .get_setting::<(sol_data::Uint<64>,)>(SETTING_MAX_PAUSE, Address::ZERO)
+            .await?;
+        Ok(value)
+    }
+
+    /// Approved swap router
+    pub async fn get_swap_router(&self) -> Result<SwapRouter, ClientError> {
+        let (router, weth) = self
+            .get_setting::<(sol_data::Address, sol_data::Address)>(
+                SETTING_SWAP_ROUTER,
+                Address::ZERO,
+            )
+            .await?;
+        Ok(SwapRouter { router, weth })
+    }
+
+    /// Merkle root emergency refunds are claimed against, zero if none
+    pub async fn get_refund_root(&self) -> Result<B256, ClientError> {
+        Ok(self.contract.getRefundRoot().call().await?._0)
+    }
+
+    /// Whether a job already claimed its emergency refund under the current
+    /// root
+    pub async fn is_refund_claimed(&self, job_id: U256) -> Result<bool, ClientError> {
+        Ok(self.contract.isRefundClaimed(job_id).call().await?._0)
+    }
+
+    /// Whether jobs can be funded with `token` in a basket
+    pub async fn is_basket_token(&self, token: Address) -> Result<bool, ClientError> {
+        let (_, value) = self
+            .get_setting::<(sol_data::Address, sol_data::Bool)>(SETTING_BASKET_TOKEN, token)
+            .await?;
+        Ok(value)
+    }
+
+    /// Protocol fee charged on payouts, in basis points
+    pub async fn get_protocol_fee(&self) -> Result<u16, ClientError> {
+        let (value,) = self
+            .get_setting::<(sol_data::Uint<16>,)>(SETTING_PROTOCOL_FEE, Address::ZERO)
+            .await?;
+        Ok(value)
+    }
+
+    /// Most of a settlement's protocol fee rebated to its sender, in wei
+    pub async fn get_settlement_rebate(&self) -> Result<U256, ClientError> {
+        let (value,) = self
+            .get_setting::<(sol_data::Uint<256>,)>(SETTING_SETTLEMENT_REBATE, Address::ZERO)
+            .await?;
+        Ok(value)
+    }
+
+    /// Protocol fees charged and not yet withdrawn, in wei
+    pub async fn get_accrued_fees(&self) -> Result<U256, ClientError> {
+        Ok(self.contract.getAccruedFees().call().await?._0)
+    }
+
+    /// Account that configures fees: the fee manager, or the admin if none
+    /// is set
+    pub async fn get_fee_manager(&self) -> Result<Address, ClientError> {
+        let (value,) = self
+            .get_setting::<(sol_data::Address,)>(SETTING_FEE_MANAGER, Address::ZERO)
+            .await?;
+        Ok(value)
+    }
+
+    /// Size tiers of the protocol fee, smallest first
+    pub async fn get_fee_tiers(&self) -> Result<Vec<FeeTier>, ClientError> {
+        let (min_amounts, fee_bps) = self
+            .get_setting::<(
+                sol_data::Array<sol_data::Uint<256>>,
+                sol_data::Array<sol_data::Uint<16>>,
+            )>(SETTING_FEE_TIERS, Address::ZERO)
+            .await?;
+        Ok(min_amounts
+            .into_iter()
+            .zip(fee_bps)
+            .map(|(min_amount, fee_bps)| FeeTier {
+                min_amount,
+                fee_bps,
+            })
+            .collect())
+    }
+
+    /// Fee rate a job was opened with, in basis points
+    pub async fn get_job_fee(&self, job_id: U256) -> Result<u16, ClientError> {
+        Ok(self.contract.getJobFee(job_id).call().await?._0)
+    }
+
+    /// Volume discounts off the protocol fee, smallest first
+    pub async fn get_volume_discounts(&self) -> Result<Vec<VolumeDiscount>, ClientError> {
+        let (min_volumes, discount_bps) = self
+            .get_setting::<(
+                sol_data::Array<sol_data::Uint<256>>,
+                sol_data::Array<sol_data::Uint<16>>,
+            )>(SETTING_VOLUME_DISCOUNTS, Address::ZERO)
+            .await?;
+        Ok(min_volumes
+            .into_iter()
+            .zip(discount_bps)
+            .map(|(min_volume, discount_bps)| VolumeDiscount {
+                min_volume,
+                discount_bps,
+            })
+            .collect())
+    }
+
+    /// Total a client's jobs have released to freelancers
+    pub async fn get_client_volume(&self, client: Address) -> Result<U256, ClientError> {
+        Ok(self.contract.getClientVolume(client).call().await?._0)
+    }
+
+    /// Whether jobs `account` opens are exempt from the protocol fee
+    pub async fn is_fee_exempt(&self, account: Address) -> Result<bool, ClientError> {
+        let (_, value) = self
+            .get_setting::<(sol_data::Address, sol_data::Bool)>(SETTING_FEE_EXEMPT, account)
+            .await?;
+        Ok(value)
+    }
+
+    /// How often a client may open jobs
+    pub async fn get_deposit_limits(&self) -> Result<DepositLimits, ClientError> {
+        let (cooldown, per_block) = self
+            .get_setting::<(sol_data::Uint<64>, sol_data::Uint<32>)>(
+                SETTING_DEPOSIT_LIMITS,
+                Address::ZERO,
+            )
+            .await?;
+        Ok(DepositLimits {
+            cooldown: (cooldown != 0).then_some(cooldown),
+            per_block: (per_block != 0).then_some(per_block),
+        })
+    }
+
+    /// Whether jobs `account` opens bypass the deposit limits
+    pub async fn is_deposit_limit_exempt(&self, account: Address) -> Result<bool, ClientError> {
+        let (_, value) = self
+            .get_setting::<(sol_data::Address, sol_data::Bool)>(
+                SETTING_DEPOSIT_LIMIT_EXEMPT,
+                account,
+            )
+            .await?;
+        Ok(value)
+    }
+
+    /// Default cap on a freelancer's active jobs, if any
+    pub async fn get_default_job_cap(&self) -> Result<Option<u32>, ClientError> {
+        let (cap,) = self
+            .get_setting::<(sol_data::Uint<32>,)>(SETTING_DEFAULT_JOB_CAP, Address::ZERO)
+            .await?;
+        Ok((cap != 0).then_some(cap))
+    }
+
+    /// A freelancer's effective job cap and how many active jobs they hold
+    pub async fn get_job_cap(&self, freelancer: Address) -> Result<JobCap, ClientError> {
+        let cap = self.contract.getJobCap(freelancer).call().await?;
+        Ok(JobCap {
+            cap: (cap.cap != 0).then_some(cap.cap),
+            active: cap.active,
+        })
+    }
+
+    /// Compliance contract consulted on deposit and release, if any
+    pub async fn get_compliance_hook(&self) -> Result<Option<Address>, ClientError> {
+        let (hook,) = self
+            .get_setting::<(sol_data::Address,)>(SETTING_COMPLIANCE_HOOK, Address::ZERO)
+            .await?;
+        Ok((hook != Address::ZERO).then_some(hook))
+    }
+
+    /// Attestations accepted as proof a freelancer is verified
+    pub async fn get_attestation_policy(&self) -> Result<AttestationPolicy, ClientError> {
+        let (eas, schema, attester) = self
+            .get_setting::<(
+                sol_data::Address,
+                sol_data::FixedBytes<32>,
+                sol_data::Address,
+            )>(SETTING_ATTESTATION_POLICY, Address::ZERO)
+            .await?;
+        Ok(AttestationPolicy {
+            eas,
+            schema,
+            attester,
+        })
+    }
+
+    /// Timeout rule assigned to new disputes
+    pub async fn get_dispute_timeout_policy(&self) -> Result<DisputeTimeoutPolicy, ClientError> {
+        let (timeout, split, split_bps) = self
+            .get_setting::<(sol_data::Uint<64>, sol_data::Bool, sol_data::Uint<16>)>(
+                SETTING_DISPUTE_TIMEOUT_POLICY,
+                Address::ZERO,
+            )
+            .await?;
+        Ok(DisputeTimeoutPolicy {
+            timeout,
+            split,
+            split_bps,
+        })
+    }
+
+    /// Stake new disputes take
+    pub async fn get_dispute_stake_policy(&self) -> Result<DisputeStakePolicy, ClientError> {
+        let (stake_bps, min_stake) = self
+            .get_setting::<(sol_data::Uint<16>, sol_data::Uint<256>)>(
+                SETTING_DISPUTE_STAKE_POLICY,
+                Address::ZERO,
+            )
+            .await?;
+        Ok(DisputeStakePolicy {
+            stake_bps,
+            min_stake,
+        })
+    }
+
+    /// Stake opening a dispute over a job would take now
+    pub async fn quote_dispute_stake(&self, job_id: U256) -> Result<U256, ClientError> {
+        Ok(self.contract.quoteDisputeStake(job_id).call().await?._0)
+    }
+
+    /// Jury escalated disputes seat
+    pub async fn get_jury_policy(&self) -> Result<JuryPolicy, ClientError> {
+        let (size, voting_period, min_stake, slash_bps) = self
+            .get_setting::<(
+                sol_data::Uint<8>,
+                sol_data::Uint<64>,
+                sol_data::Uint<256>,
+                sol_data::Uint<16>,
+            )>(SETTING_JURY_POLICY, Address::ZERO)
+            .await?;
+        Ok(JuryPolicy {
+            size,
+            voting_period,
+            min_stake,
+            slash_bps,
+        })
+    }
+
+    /// `juror`'s stake and standing in the registry
+    pub async fn get_juror(&self, juror: Address) -> Result<Juror, ClientError> {
+        let record = self.contract.getJuror(juror).call().await?;
+        Ok(Juror {
+            stake: record.stake,
+            registered: record.registered,
+            panels: record.panels,
+        })
+    }
+
+    /// How many jurors are in the registry
+    pub async fn get_juror_count(&self) -> Result<U256, ClientError> {
+        Ok(self.contract.getJurorCount().call().await?._0)
+    }
+
+    /// The jury a job's dispute was escalated to, if any
+    pub async fn get_jury(&self, job_id: U256) -> Result<Option<Jury>, ClientError> {
+        let jury = self.contract.getJury(job_id).call().await?;
+        if jury.panel.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(Jury {
+            panel: jury.panel,
+            vote_by: jury.vote_by,
+            votes_cast: jury.votes_cast,
+            tallied: jury.tallied,
+        }))
+    }
+
+    /// Freelancer's share `juror` voted on a job, in basis points; `None`
+    /// if they haven't voted
+    pub async fn get_jury_vote(
+        &self,
+        job_id: U256,
+        juror: Address,
+    ) -> Result<Option<u16>, ClientError> {
+        let vote = self.contract.getJuryVote(job_id, juror).call().await?;
+        Ok(vote.voted.then_some(vote.freelancer_bps))
+    }
+
+    /// How rulings are held for appeal
+    pub async fn get_appeal_policy(&self) -> Result<AppealPolicy, ClientError> {
+        let (window, bond_bps, min_bond) = self
+            .get_setting::<(sol_data::Uint<64>, sol_data::Uint<16>, sol_data::Uint<256>)>(
+                SETTING_APPEAL_POLICY,
+                Address::ZERO,
+            )
+            .await?;
+        Ok(AppealPolicy {
+            window,
+            bond_bps,
+            min_bond,
+        })
+    }
+
+    /// Bond appealing a ruling on a job would take now
+    pub async fn quote_appeal_bond(&self, job_id: U256) -> Result<U256, ClientError> {
+        Ok(self.contract.quoteAppealBond(job_id).call().await?._0)
+    }
+
+    /// The ruling held for appeal on a job, if one ever was
+    pub async fn get_held_ruling(&self, job_id: U256) -> Result<Option<HeldRuling>, ClientError> {
+        let ruling = self.contract.getHeldRuling(job_id).call().await?;
+        if ruling.appealable_until == 0 {
+            return Ok(None);
+        }
+        Ok(Some(HeldRuling {
+            resolver: ruling.resolver,
+            freelancer_bps: ruling.freelancer_bps,
+            appealable_until: ruling.appealable_until,
+        }))
+    }
+
+    /// The appeal against a job's ruling, if any
+    pub async fn get_appeal(&self, job_id: U256) -> Result<Option<Appeal>, ClientError> {
+        let appeal = self.contract.getAppeal(job_id).call().await?;
+        if appeal.appellant == Address::ZERO {
+            return Ok(None);
+        }
+        Ok(Some(Appeal {
+            appellant: appeal.appellant,
+            freelancer_bps: appeal.freelancer_bps,
+            bond: appeal.bond,
+            proposal_id: appeal.proposal_id,
+        }))
+    }
+
+    /// Arbitration terms assigned to new disputes
+    pub async fn get_arbitration_policy(&self) -> Result<ArbitrationPolicy, ClientError> {
+        let (arbitrator, ruling_period, default_split_bps) = self
+            .get_setting::<(sol_data::Address, sol_data::Uint<64>, sol_data::Uint<16>)>(
+                SETTING_ARBITRATION_POLICY,
+                Address::ZERO,
+            )
+            .await?;
+        Ok(ArbitrationPolicy {
+            arbitrator,
+            ruling_period,
+            default_split_bps,
+        })
+    }
+
+    /// Arbitrator a job's disputes go to: the one its dispute was assigned,
+    /// or before one is opened the one its client named; `None` if it falls
+    /// back to the [`ArbitrationPolicy`]'s and was never disputed
+    pub async fn get_job_arbitrator(&self, job_id: U256) -> Result<Option<Address>, ClientError> {
+        let arbitrator = self.contract.getDispute(job_id).call().await?.arbitrator;
+        Ok((arbitrator != Address::ZERO).then_some(arbitrator))
+    }
+
+    /// Arbitrators the admin lists for clients to pick from, replayed from
+    /// the `ArbitratorListed` events the escrow emitted
+    pub async fn get_listed_arbitrators(&self) -> Result<Vec<Address>, ClientError> {
+        let filter = Filter::new()
+            .address(*self.contract.address())
+            .event_signature(ARBITRATOR_LISTED_TOPIC)
+            .from_block(0);
+        let logs = self
+            .contract
+            .provider()
+            .get_logs(&filter)
+            .await
+            .map_err(contract::Error::from)?;
+        let mut listed = Vec::new();
+        for log in logs {
+            let Ok(event) = ArbitratorListed::decode_log_data(log.data(), true) else {
+                continue;
+            };
+            listed.retain(|&arbitrator| arbitrator != event.arbitrator);
+            if event.listed {
+                listed.push(event.arbitrator);
+            }
+        }
+        Ok(listed)
+    }
+
+    /// Payout token chosen by `freelancer`
+    pub async fn get_payout_token(&self, freelancer: Address) -> Result<PayoutToken, ClientError> {
+        let preference = self.contract.getPayoutToken(freelancer).call().await?;
+        Ok(PayoutToken {
+            token: preference.token,
+            min_rate: preference.min_rate,
+        })
+    }
+
+    /// Current and pending payout address of `freelancer`
+    pub async fn get_payout_address(
+        &self,
+        freelancer: Address,
+    ) -> Result<PayoutAddress, ClientError> {
+        let payout = self.contract.getPayoutAddress(freelancer).call().await?;
+        Ok(PayoutAddress {
+            current: payout.current,
+            pending: payout.pending,
+            effective_at: payout.effective_at,
+        })
+    }
+
+    /// Tax withholding configured by `freelancer`
+    pub async fn get_withholding(&self, freelancer: Address) -> Result<Withholding, ClientError> {
+        let withholding = self.contract.getWithholding(freelancer).call().await?;
+        Ok(Withholding {
+            rate_bps: withholding.rate_bps,
+            destination: withholding.destination,
+        })
+    }
+
+    /// Dormancy state of `job_id`
+    pub async fn get_dormancy(&self, job_id: U256) -> Result<Dormancy, ClientError> {
+        let dormancy = self.contract.getDormancy(job_id).call().await?;
+        Ok(Dormancy {
+            sweep_after: dormancy.sweep_after,
+            swept: dormancy.swept,
+        })
+    }
+
+    /// Job `client` opened with `nonce`, or `None` if the nonce is unused
+    pub async fn get_job_by_nonce(
+        &self,
+        client: Address,
+        nonce: U256,
+    ) -> Result<Option<U256>, ClientError> {
+        let job_id = self.contract.getJobByNonce(client, nonce).call().await?._0;
+        Ok((!job_id.is_zero()).then_some(job_id))
+    }
+
+    /// Whether `client` cancelled the offer made with `nonce`
+    pub async fn is_offer_cancelled(
+        &self,
+        client: Address,
+        nonce: U256,
+    ) -> Result<bool, ClientError> {
+        Ok(self
+            .contract
+            .isOfferCancelled(client, nonce)
+            .call()
+            .await?
+            ._0)
+    }
+
+    /// Whether `signer` spent or cancelled the authorization made with
+    /// `nonce`
+    pub async fn is_authorization_used(
+        &self,
+        signer: Address,
+        nonce: U256,
+    ) -> Result<bool, ClientError> {
+        Ok(self
+            .contract
+            .isAuthorizationUsed(signer, nonce)
+            .call()
+            .await?
+            ._0)
+    }
+
+    /// A job's release schedule; empty for untranched jobs
+    pub async fn get_tranches(&self, job_id: U256) -> Result<Vec<Tranche>, ClientError> {
+        let schedule = self.contract.getTranches(job_id).call().await?;
+        Ok(schedule
+            .amounts
+            .into_iter()
+            .zip(schedule.unlocks)
+            .zip(schedule.settled)
+            .map(|((amount, unlock_at), settled)| Tranche {
+                amount,
+                unlock_at,
+                settled,
+            })
+            .collect())
+    }
+
+    /// Everything a job is funded with, its ETH first
+    pub async fn get_basket(&self, job_id: U256) -> Result<Vec<BasketItem>, ClientError> {
+        let basket = self.contract.getBasket(job_id).call().await?;
+        Ok(basket
+            .tokens
+            .into_iter()
+            .zip(basket.amounts)
+            .map(|(token, amount)| BasketItem { token, amount })
+            .collect())
+    }
+
+    /// A job's hourly terms, or `None` if it is not an hourly engagement
+    pub async fn get_engagement(&self, job_id: U256) -> Result<Option<Engagement>, ClientError> {
+        let terms = self.contract.getEngagement(job_id).call().await?;
+        Ok((!terms.rate.is_zero()).then_some(Engagement {
+            rate: terms.rate,
+            pending: terms.pending,
+        }))
+    }
+
+    /// A job's timesheets; empty unless it is an hourly engagement
+    pub async fn get_timesheets(&self, job_id: U256) -> Result<Vec<Timesheet>, ClientError> {
+        let sheets = self.contract.getTimesheets(job_id).call().await?;
+        Ok(sheets
+            .hours
+            .into_iter()
+            .zip(sheets.details)
+            .zip(sheets.statuses)
+            .map(|((hours, details), status)| Timesheet {
+                hours,
+                details,
+                status,
+            })
+            .collect())
+    }
+
+    /// A job's installment funding, or `None` if it was funded in one deposit
+    pub async fn get_funding(&self, job_id: U256) -> Result<Option<Funding>, ClientError> {
+        let funding = self.contract.getFunding(job_id).call().await?;
+        Ok((!funding.target.is_zero()).then_some(Funding {
+            target: funding.target,
+            raised: funding.raised,
+            cutoff: funding.cutoff,
+        }))
+    }
+
+    /// An invoice's terms, or `None` if no such invoice exists
+    pub async fn get_invoice(&self, invoice_id: U256) -> Result<Option<Invoice>, ClientError> {
+        let invoice = self.contract.getInvoice(invoice_id).call().await?;
+        Ok((invoice.freelancer != Address::ZERO).then_some(Invoice {
+            freelancer: invoice.freelancer,
+            client: invoice.client,
+            amount: invoice.amount,
+            duration: invoice.duration,
+            metadata: invoice.metadata,
+            expires_at: invoice.expires_at,
+            job_id: (!invoice.job_id.is_zero()).then_some(invoice.job_id),
+        }))
+    }
+
+    /// What `contributor` has paid towards a job's funding and not reclaimed
+    pub async fn get_contribution(
+        &self,
+        job_id: U256,
+        contributor: Address,
+    ) -> Result<U256, ClientError> {
+        Ok(self
+            .contract
+            .getContribution(job_id, contributor)
+            .call()
+            .await?
+            ._0)
+    }
+
+    /// ETH credited to `account` and not yet withdrawn
+    pub async fn get_credit(&self, account: Address) -> Result<U256, ClientError> {
+        Ok(self.contract.getCredit(account).call().await?._0)
+    }
+
+    /// A retainer's terms, or `None` if the job is not a retainer
+    pub async fn get_retainer(&self, job_id: U256) -> Result<Option<Retainer>, ClientError> {
+        let terms = self.contract.getRetainer(job_id).call().await?;
+        Ok((terms.period != 0).then_some(Retainer {
+            period: terms.period,
+            notice: terms.notice,
+        }))
+    }
+
+    /// A job's release approvals, or `None` if it doesn't need both parties'
+    pub async fn get_dual_approval(
+        &self,
+        job_id: U256,
+    ) -> Result<Option<DualApproval>, ClientError> {
+        let approval = self.contract.getDualApproval(job_id).call().await?;
+        Ok(approval.required.then_some(DualApproval {
+            client_approved: approval.client_approved,
+            freelancer_approved: approval.freelancer_approved,
+        }))
+    }
+
+    /// A job's cancellation terms, or `None` if it has no notice period
+    pub async fn get_cancellation(
+        &self,
+        job_id: U256,
+    ) -> Result<Option<Cancellation>, ClientError> {
+        let terms = self.contract.getCancellation(job_id).call().await?;
+        Ok((terms.notice != 0).then_some(Cancellation {
+            notice: terms.notice,
+            effective_at: terms.effective_at,
+        }))
+    }
+
+    /// A job's clawback terms, or `None` if it was opened without a window
+    pub async fn get_clawback(&self, job_id: U256) -> Result<Option<Clawback>, ClientError> {
+        let terms = self.contract.getClawback(job_id).call().await?;
+        Ok((terms.window != 0).then_some(Clawback {
+            window: terms.window,
+            payable_at: terms.payable_at,
+        }))
+    }
+
+    /// A job's holdback terms, or `None` if it was opened without one
+    pub async fn get_holdback(&self, job_id: U256) -> Result<Option<Holdback>, ClientError> {
+        let terms = self.contract.getHoldback(job_id).call().await?;
+        Ok((terms.holdback_bps != 0).then_some(Holdback {
+            holdback_bps: terms.holdback_bps,
+            warranty: terms.warranty,
+            release_at: terms.release_at,
+        }))
+    }
+
+    /// A job's SLA terms, or `None` if it was opened without one
+    pub async fn get_sla(&self, job_id: U256) -> Result<Option<Sla>, ClientError> {
+        let terms = self.contract.getSla(job_id).call().await?;
+        Ok((terms.delivery_by != 0).then_some(Sla {
+            delivery_by: terms.delivery_by,
+            late_bps_per_day: terms.late_bps_per_day,
+            delivered_at: terms.delivered_at,
+        }))
+    }
+
+    /// Release condition a job was opened with, if any
+    pub async fn get_release_condition(
+        &self,
+        job_id: U256,
+    ) -> Result<Option<Address>, ClientError> {
+        let condition = self.contract.getReleaseCondition(job_id).call().await?._0;
+        Ok((condition != Address::ZERO).then_some(condition))
+    }
+
+    /// Marketplace platform a job was opened through, if any
+    pub async fn get_platform_fee(&self, job_id: U256) -> Result<Option<PlatformFee>, ClientError> {
+        let fee = self.contract.getPlatformFee(job_id).call().await?;
+        Ok((fee.platform != Address::ZERO).then_some(PlatformFee {
+            platform: fee.platform,
+            fee_bps: fee.fee_bps,
+        }))
+    }
+
+    /// Settlement hook attached to a job, if any
+    pub async fn get_settlement_hook(&self, job_id: U256) -> Result<Option<Address>, ClientError> {
+        let hook = self.contract.getSettlementHook(job_id).call().await?._0;
+        Ok((hook != Address::ZERO).then_some(hook))
+    }
+
+    /// A job's dispute, if it was ever disputed
+    pub async fn get_dispute(&self, job_id: U256) -> Result<Option<Dispute>, ClientError> {
+        let dispute = self.contract.getDispute(job_id).call().await?;
+        if dispute.opened_at == 0 {
+            return Ok(None);
+        }
+        let timeout = self.contract.getDisputeTimeout(job_id).call().await?;
+        let stake = self.contract.getDisputeStake(job_id).call().await?._0;
+        Ok(Some(Dispute {
+            opened_by: dispute.opened_by,
+            opened_at: dispute.opened_at,
+            arbitrator: dispute.arbitrator,
+            rule_by: dispute.rule_by,
+            default_split_bps: dispute.default_split_bps,
+            timeout_at: timeout.timeout_at,
+            timeout_split: timeout.split,
+            timeout_split_bps: timeout.split_bps,
+            open: dispute.open,
+            stake,
+        }))
+    }
+
+    /// A job's evidence trail, oldest first
+    pub async fn get_evidence(&self, job_id: U256) -> Result<Vec<Evidence>, ClientError> {
+        let trail = self.contract.getEvidence(job_id).call().await?;
+        Ok(trail
+            .hashes
+            .into_iter()
+            .zip(trail.submitters)
+            .zip(trail.submitted_at)
+            .map(|((evidence_hash, submitter), submitted_at)| Evidence {
+                evidence_hash,
+                submitter,
+                submitted_at,
+            })
+            .collect())
+    }
+
+    /// Commitment of a private job; zero for public jobs
+    pub async fn get_commitment(&self, job_id: U256) -> Result<B256, ClientError> {
+        Ok(self.contract.getCommitment(job_id).call().await?._0)
+    }
+
+    /// Attestation UID a job was opened with, if any
+    pub async fn get_attestation(&self, job_id: U256) -> Result<Option<B256>, ClientError> {
+        let uid = self.contract.getAttestation(job_id).call().await?._0;
+        Ok((!uid.is_zero()).then_some(uid))
+    }
+
+    /// Lifetime stats for `user`, and what it has in escrow now
+    pub async fn get_user_stats(&self, user: Address) -> Result<UserStats, ClientError> {
+        let stats = self.contract.getUserStats(user).call().await?;
+        Ok(UserStats {
+            jobs_as_client: stats.jobs_as_client,
+            jobs_as_freelancer: stats.jobs_as_freelancer,
+            deposited: stats.deposited,
+            earned: stats.earned,
+            refunded: stats.refunded,
+            locked: stats.locked,
+            pending: stats.pending,
+        })
+    }
+
+    /// `freelancer`'s earnings, completed jobs, their average settlement
+    /// time and disputes, read from its stats and reputation
+    pub async fn get_freelancer_profile(
+        &self,
+        freelancer: Address,
+    ) -> Result<FreelancerProfile, ClientError> {
+        let stats = self.contract.getUserStats(freelancer).call().await?;
+        let reputation = self.contract.getReputation(freelancer).call().await?;
+        Ok(FreelancerProfile {
+            earned: stats.earned,
+            jobs_completed: reputation.jobs_completed,
+            average_settlement_time: reputation.average_settlement_time,
+            disputes: reputation.disputes,
+        })
+    }
+
+    /// `client`'s funded volume, refund rate and dispute-loss rate, read
+    /// from its stats and reputation
+    pub async fn get_client_profile(&self, client: Address) -> Result<ClientProfile, ClientError> {
+        let stats = self.contract.getUserStats(client).call().await?;
+        let reputation = self.contract.getReputation(client).call().await?;
+        Ok(ClientProfile {
+            funded: stats.deposited,
+            refund_bps: reputation.refund_bps,
+            disputes: reputation.client_disputes,
+            dispute_loss_bps: reputation.dispute_loss_bps,
+        })
+    }
+
+    /// Rate the other party to a settled job from 1 to `MAX_STARS` stars
+    pub async fn rate_job(
+        &self,
+        job_id: U256,
+        stars: u8,
+    ) -> Result<TransactionReceipt, ClientError> {
+        send(self.contract.rateJob(job_id, stars)).await
+    }
+
+    /// Stars each party gave for a job as (client's rating of the
+    /// freelancer, freelancer's rating of the client), `None` where not rated
+    pub async fn get_job_ratings(
+        &self,
+        job_id: U256,
+    ) -> Result<(Option<u8>, Option<u8>), ClientError> {
+        let ratings = self.contract.getJobRatings(job_id).call().await?;
+        Ok((
+            (ratings.by_client != 0).then_some(ratings.by_client),
+            (ratings.by_freelancer != 0).then_some(ratings.by_freelancer),
+        ))
+    }
+
+    /// Ratings `account` has received, weighted figures decayed to now
+    pub async fn get_reputation(&self, account: Address) -> Result<Reputation, ClientError> {
+        let reputation = self.contract.getReputation(account).call().await?;
+        Ok(Reputation {
+            ratings: reputation.ratings,
+            stars_total: reputation.stars_total,
+            disputes_lost: reputation.disputes_lost,
+            weighted_stars: reputation.weighted_stars,
+            weight: reputation.weight,
+        })
+    }
+
+    /// `account`'s reputation score in basis points
+    pub async fn get_score(&self, account: Address) -> Result<u16, ClientError> {
+        Ok(self.contract.getScore(account).call().await?._0)
+    }
+
+    /// Activity within `epoch`, the `EPOCH_LENGTH`-second bucket numbered
+    /// from the Unix epoch
+    pub async fn get_period_stats(&self, epoch: u64) -> Result<PeriodStats, ClientError> {
+        let stats = self.contract.getPeriodStats(epoch).call().await?;
+        Ok(PeriodStats {
+            jobs_created: stats.jobs_created,
+            deposited: stats.deposited,
+            released: stats.released,
+            refunded: stats.refunded,
+        })
+    }
+}
+
+/// Zip the columns of a job page into summaries, skipping unknown statuses
+fn job_summaries(
+    ids: Vec<U256>,
+    clients: Vec<Address>,
+    freelancers: Vec<Address>,
+    amounts: Vec<U256>,
+    deadlines: Vec<u64>,
+    statuses: Vec<u8>,
+) -> Vec<JobSummary> {
+    ids.into_iter()
+        .zip(clients)
+        .zip(freelancers)
+        .zip(amounts)
+        .zip(deadlines)
+        .zip(statuses)
+        .filter_map(
+            |(((((id, client), freelancer), amount), deadline), status)| {
+                Some(JobSummary {
+                    id,
+                    client,
+                    freelancer,
+                    amount,
+                    deadline,
+                    status: JobStatus::from_code(status)?,
+                })
+            },
+        )
+        .collect()
+}
+
+/// ID of the job opened in a deposit receipt
+fn deposited_job(receipt: &TransactionReceipt) -> Result<U256, ClientError> {
+    receipt
+        .inner
+        .logs()
+        .iter()
+        .filter(|log| log.topic0() == Some(&DEPOSITED_TOPIC))
+        .find_map(|log| Deposited::decode_log_data(log.data(), true).ok())
+        .map(|event| event.job_id)
+        .ok_or(ClientError::MissingEvent("Deposited"))
+}
+
+/// Send a transaction and wait for a successful receipt.
+async fn send<P, D>(call: CallBuilder<(), &P, D>) -> Result<TransactionReceipt, ClientError>
+where
+    P: Provider<Ethereum>,
+    D: CallDecoder,
+{
+    let receipt = call.send().await?.get_receipt().await?;
+    if !receipt.status() {
+        return Err(ClientError::Failed(receipt.transaction_hash));
+    }
+    Ok(receipt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_sol_types::SolError;
+
+    #[test]
+    fn test_decode_revert() {
+        // Escrow errors come back as their message
+        let encoded = Vec::from(ErrorCode::OnlyClientCanRelease);
+        assert_eq!(decode_revert(&encoded), "Only client can release");
+        let unknown = EscrowError { code: u16::MAX }.abi_encode();
+        assert_eq!(decode_revert(&unknown), "escrow error 65535");
+
+        // Raw message bytes are passed through
+        assert_eq!(
+            decode_revert(b"Only client can release"),
+            "Only client can release"
+        );
+
+        // Solidity-style Error(string) payloads are decoded too
+        let encoded = alloy_sol_types::Revert::from("Escrow is paused").abi_encode();
+        assert_eq!(decode_revert(&encoded), "Escrow is paused");
+
+        // Non-UTF-8 data falls back to hex
+        assert_eq!(decode_revert(&[0xff, 0x00]), "0xff00");
+    }
+
+    #[test]
+    fn test_memo() {
+        let memo = encode_memo("INV-0042").unwrap();
+        assert_eq!(&memo[..8], b"INV-0042");
+        assert_eq!(decode_memo(memo), "INV-0042");
+
+        // Full 32-byte hex passes through and renders as hex
+        let hex = B256::repeat_byte(0xab);
+        assert_eq!(encode_memo(&hex.to_string()), Some(hex));
+        assert_eq!(decode_memo(hex), hex.to_string());
+
+        assert_eq!(encode_memo(&"x".repeat(33)), None);
+        assert_eq!(decode_memo(B256::ZERO), "");
+    }
+
+    #[test]
+    fn test_job_commitment() {
+        // Matches Solidity's keccak256(abi.encode(client, freelancer, amount, terms, salt))
+        let client = Address::from([0x01; 20]);
+        let freelancer = Address::from([0x02; 20]);
+        let amount = U256::from(1_000);
+        let terms = B256::repeat_byte(0x03);
+        let salt = B256::repeat_byte(0x04);
+        let mut preimage = Vec::new();
+        preimage.extend(client.into_word());
+        preimage.extend(freelancer.into_word());
+        preimage.extend(B256::from(amount));
+        preimage.extend(terms);
+        preimage.extend(salt);
+        assert_eq!(
+            job_commitment(client, freelancer, amount, terms, salt),
+            alloy::primitives::keccak256(preimage)
+        );
+    }
+
+    #[test]
+    fn test_job_status() {
+        let mut job = Job {
+            id: U256::from(1),
+            client: Address::from([0x01; 20]),
+            freelancer: Address::from([0x02; 20]),
+            amount: U256::from(1_000),
+            deadline: 86_400,
+            released: false,
+            refunded: false,
+        };
+        assert_eq!(job.status(), JobStatus::Active);
+
+        job.released = true;
+        assert_eq!(job.status(), JobStatus::Released);
+
+        job.released = false;
+        job.refunded = true;
+        assert_eq!(job.status(), JobStatus::Refunded);
+    }
+}
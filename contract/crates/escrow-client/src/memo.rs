@@ -0,0 +1,26 @@
+use alloy::primitives::B256;
+
+/// Encode a reference such as an invoice or PO number as a `bytes32` memo.
+///
+/// Accepts a `0x`-prefixed 32-byte hex string as is, or up to 32 bytes of
+/// text, right-padded with zeros. Returns `None` if the text is too long.
+pub fn encode_memo(reference: &str) -> Option<B256> {
+    if let Some(hex) = reference.strip_prefix("0x") {
+        if hex.len() == 64 {
+            return reference.parse().ok();
+        }
+    }
+    (reference.len() <= 32).then(|| B256::right_padding_from(reference.as_bytes()))
+}
+
+/// Render a memo for display: the text if it is zero-padded printable ASCII,
+/// hex otherwise. Empty memos render as an empty string.
+pub fn decode_memo(memo: B256) -> String {
+    let len = memo.iter().rposition(|&b| b != 0).map_or(0, |i| i + 1);
+    let text = &memo[..len];
+    if text.iter().all(|&b| b.is_ascii_graphic() || b == b' ') {
+        String::from_utf8_lossy(text).into_owned()
+    } else {
+        memo.to_string()
+    }
+}
@@ -0,0 +1,628 @@
+use alloy::primitives::{Address, Bytes, B256, U256};
+use escrow_types::JobStatus;
+
+/// A job as returned by `get_job`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Job {
+    pub id: U256,
+    pub client: Address,
+    pub freelancer: Address,
+    pub amount: U256,
+    pub deadline: u64,
+    pub released: bool,
+    pub refunded: bool,
+}
+
+/// A job as returned by `query_jobs`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JobSummary {
+    pub id: U256,
+    pub client: Address,
+    pub freelancer: Address,
+    pub amount: U256,
+    pub deadline: u64,
+    pub status: JobStatus,
+}
+
+impl Job {
+    /// Current settlement state
+    pub fn status(&self) -> JobStatus {
+        if self.released {
+            JobStatus::Released
+        } else if self.refunded {
+            JobStatus::Refunded
+        } else {
+            JobStatus::Active
+        }
+    }
+}
+
+/// Cumulative protocol volume, in wei
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProtocolStats {
+    pub deposited: U256,
+    pub released: U256,
+    pub refunded: U256,
+    /// Dormant funds sent to the recovery address
+    pub swept: U256,
+    /// Protocol fees charged on payouts
+    pub fees_collected: U256,
+    /// Escrowed in jobs when a dispute was opened over them
+    pub disputed: U256,
+}
+
+impl ProtocolStats {
+    /// Volume still held in escrow
+    pub fn escrowed(&self) -> U256 {
+        self.deposited - self.released - self.refunded - self.swept
+    }
+}
+
+/// Lifetime stats for a single address, and the ETH it has in escrow
+/// across its unsettled jobs as client (`locked`) and held for it across
+/// those as freelancer (`pending`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UserStats {
+    pub jobs_as_client: U256,
+    pub jobs_as_freelancer: U256,
+    pub deposited: U256,
+    pub earned: U256,
+    pub refunded: U256,
+    pub locked: U256,
+    pub pending: U256,
+}
+
+/// A freelancer's work history; only jobs settled as released count as
+/// completed, and the settlement time is seconds from deposit to settlement
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FreelancerProfile {
+    pub earned: U256,
+    pub jobs_completed: u32,
+    pub average_settlement_time: u64,
+    pub disputes: u32,
+}
+
+/// A client's standing; the refund rate is the share of what it deposited
+/// that was refunded, the dispute-loss rate the share of disputes over its
+/// jobs an arbitrator ruled against it, both in basis points
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClientProfile {
+    pub funded: U256,
+    pub refund_bps: u16,
+    pub disputes: u32,
+    pub dispute_loss_bps: u16,
+}
+
+/// A job held in the staking token; the token, principal, shares and
+/// slippage bound are zero unless it is staked, the yield shares are the
+/// ones last consented to, the client taking the rest, and `consented_by`
+/// is the party whose consent to staking it awaits the other's
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JobStake {
+    pub token: Address,
+    pub principal: U256,
+    pub shares: U256,
+    pub freelancer_yield_bps: u16,
+    pub treasury_yield_bps: u16,
+    pub consented_by: Address,
+    pub max_slippage_bps: u16,
+}
+
+/// Ratings an address has received; the weighted figures decay over
+/// `REPUTATION_HALF_LIFE`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Reputation {
+    pub ratings: u32,
+    pub stars_total: u32,
+    pub disputes_lost: u32,
+    pub weighted_stars: U256,
+    pub weight: U256,
+}
+
+impl Reputation {
+    /// Plain average of the stars received, in hundredths of a star; `None`
+    /// before the first rating
+    pub fn average_centistars(&self) -> Option<u32> {
+        (self.ratings != 0).then(|| self.stars_total * 100 / self.ratings)
+    }
+}
+
+/// Activity within a single epoch
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PeriodStats {
+    pub jobs_created: U256,
+    pub deposited: U256,
+    pub released: U256,
+    pub refunded: U256,
+}
+
+/// Jobs filed under a category and the ETH deposited in them
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CategoryStats {
+    pub jobs: U256,
+    pub deposited: U256,
+}
+
+/// Sunset schedule of the escrow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sunset {
+    /// When deposits close for good; 0 if no sunset is scheduled
+    pub sunset_at: u64,
+    /// Whether the sunset was finalized and the escrow retired
+    pub retired: bool,
+}
+
+/// Admin-configured policy for sweeping abandoned jobs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DormancyPolicy {
+    /// Seconds past the deadline before a job counts as dormant; 0 if unset
+    pub period: u64,
+    /// Where swept funds go
+    pub recovery: Address,
+}
+
+/// Address that receives fees, dormant sweeps and retirement leftovers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Treasury {
+    /// Current treasury; zero if none is set
+    pub treasury: Address,
+    /// Proposed treasury waiting out its delay; zero if none
+    pub pending: Address,
+    /// When the pending treasury can be applied
+    pub effective_at: u64,
+}
+
+/// Guardians who approve emergency actions
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Guardians {
+    /// Current guardian set; empty if the admin acts alone
+    pub guardians: Vec<Address>,
+    /// Confirmations an emergency action needs
+    pub threshold: u8,
+}
+
+/// Emergency action proposed by a guardian
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EmergencyProposal {
+    /// One of the `EMERGENCY_ACTION_*` constants
+    pub action: u8,
+    /// Job ID to refund, or timestamp to keep the pause until
+    pub target: U256,
+    /// Guardian who proposed it
+    pub proposer: Address,
+    /// When it can no longer be confirmed
+    pub expires_at: u64,
+    /// Guardians who confirmed it so far
+    pub confirmations: u8,
+    /// Whether it has run
+    pub executed: bool,
+}
+
+/// Who governs the protocol parameters
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Governance {
+    /// Token whose delegated votes count; zero if a council votes
+    pub token: Address,
+    /// Council members, one vote each; empty if a token votes
+    pub council: Vec<Address>,
+    /// Votes a proposal needs to pass; 0 if the admin still sets parameters
+    pub quorum: U256,
+}
+
+/// Parameter change put to a governance vote
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GovernanceProposal {
+    /// One of the `GOVERNANCE_ACTION_*` constants
+    pub action: u8,
+    /// Address the action applies to, if any
+    pub target: Address,
+    /// New value the action sets, if any
+    pub value: U256,
+    /// Who proposed it
+    pub proposer: Address,
+    /// When voting closes
+    pub voting_ends: u64,
+    /// Votes in favour
+    pub votes_for: U256,
+    /// Votes against
+    pub votes_against: U256,
+    /// Whether it has run
+    pub executed: bool,
+    /// ABI-encoded arguments of a governed setting; empty otherwise
+    pub data: Bytes,
+}
+
+/// Dormancy state of a single job
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dormancy {
+    /// When the announced sweep may happen; 0 if not announced
+    pub sweep_after: u64,
+    /// Whether the job's funds were swept
+    pub swept: bool,
+}
+
+/// One part of a tranched job's release schedule
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tranche {
+    pub amount: U256,
+    /// When the freelancer can claim it; until then the client can refund it
+    pub unlock_at: u64,
+    /// Whether it has been claimed or refunded
+    pub settled: bool,
+}
+
+/// One part of what a job is funded with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BasketItem {
+    /// ERC-20 escrowed, or the zero address for ETH
+    pub token: Address,
+    pub amount: U256,
+}
+
+/// Terms of an hourly engagement
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Engagement {
+    /// Wei billed per hour
+    pub rate: U256,
+    /// Amount billed in timesheets awaiting approval
+    pub pending: U256,
+}
+
+/// Hours billed on an hourly engagement
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timesheet {
+    pub hours: u32,
+    /// Description of the work, e.g. a hash of the timesheet
+    pub details: B256,
+    /// `TIMESHEET_SUBMITTED`, `TIMESHEET_APPROVED` or `TIMESHEET_REJECTED`
+    pub status: u8,
+}
+
+/// Terms of a retainer job
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Retainer {
+    /// Seconds per period; one tranche unlocks at the end of each
+    pub period: u64,
+    /// Seconds of notice the client must give to stop future periods
+    pub notice: u64,
+}
+
+/// Cancellation terms of a job opened with a notice period
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cancellation {
+    /// Seconds between a cancellation request and the refund it allows
+    pub notice: u64,
+    /// When the pending cancellation takes effect; 0 if none is pending
+    pub effective_at: u64,
+}
+
+/// Retainage terms of a job opened with a holdback
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Holdback {
+    /// Share of the job held back after release, in basis points
+    pub holdback_bps: u16,
+    /// Seconds the holdback stays escrowed after release
+    pub warranty: u64,
+    /// When the holdback can be released to the freelancer; 0 until the job
+    /// is released
+    pub release_at: u64,
+}
+
+/// Clawback terms of a job opened with a clawback window
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Clawback {
+    /// Seconds a release waits before it can be paid out
+    pub window: u64,
+    /// When the queued release can be paid out; 0 if none is queued
+    pub payable_at: u64,
+}
+
+/// Delivery terms of a job opened with an SLA
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sla {
+    /// Agreed delivery date
+    pub delivery_by: u64,
+    /// Share of the job refunded to the client per full day late, in basis
+    /// points
+    pub late_bps_per_day: u16,
+    /// When the freelancer submitted the delivery; 0 until then
+    pub delivered_at: u64,
+}
+
+/// Marketplace platform a job was opened through
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlatformFee {
+    /// Platform credited its fee on every payout
+    pub platform: Address,
+    /// Its share of each payout, in basis points
+    pub fee_bps: u16,
+}
+
+/// Approvals of a job that needs both parties to approve its release
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DualApproval {
+    pub client_approved: bool,
+    pub freelancer_approved: bool,
+}
+
+/// A dispute opened over a job
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dispute {
+    pub opened_by: Address,
+    pub opened_at: u64,
+    /// Arbitrator assigned when it was opened; zero if none was appointed
+    pub arbitrator: Address,
+    /// When the ruling period ends and either party can settle at the default
+    pub rule_by: u64,
+    /// Freelancer's share at the default split, in basis points
+    pub default_split_bps: u16,
+    /// When the timeout rule can be applied; 0 if the dispute has no timeout
+    pub timeout_at: u64,
+    /// Whether the timeout splits the job rather than lifting the dispute
+    pub timeout_split: bool,
+    /// Freelancer's share when the timeout splits the job, in basis points
+    pub timeout_split_bps: u16,
+    /// Whether it is still open; a dispute ends when its job settles or its
+    /// timeout lifts it
+    pub open: bool,
+    /// Stake the opener locked with it; zero once the dispute is settled
+    pub stake: U256,
+}
+
+/// One entry of a dispute's evidence trail
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Evidence {
+    /// Hash of the evidence, kept off chain
+    pub evidence_hash: B256,
+    pub submitter: Address,
+    pub submitted_at: u64,
+}
+
+/// Installment funding of a job opened with [`create_job`](crate::EscrowClient::create_job)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Funding {
+    /// Amount the job needs before it becomes active
+    pub target: U256,
+    /// Amount contributed so far
+    pub raised: U256,
+    /// When contributions can be reclaimed if the target has not been met
+    pub cutoff: u64,
+}
+
+impl Funding {
+    /// Whether the target has been met and the job is active
+    pub fn is_complete(&self) -> bool {
+        self.raised >= self.target
+    }
+}
+
+/// A freelancer's bill to a client, from
+/// [`create_invoice`](crate::EscrowClient::create_invoice)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Invoice {
+    /// Freelancer who issued it and will be paid through the job
+    pub freelancer: Address,
+    /// Client who can pay it
+    pub client: Address,
+    /// Amount the client pays into escrow
+    pub amount: U256,
+    /// Duration of the job it opens, in seconds
+    pub duration: u64,
+    /// Reference echoed as the job's memo
+    pub metadata: B256,
+    /// When it can no longer be paid
+    pub expires_at: u64,
+    /// Job it opened; `None` until paid
+    pub job_id: Option<U256>,
+}
+
+/// How many jobs a freelancer holds against their cap
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JobCap {
+    /// Most active jobs they may hold; `None` if unlimited
+    pub cap: Option<u32>,
+    /// Active jobs they hold now
+    pub active: u32,
+}
+
+/// How often a client may open jobs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DepositLimits {
+    /// Seconds a client waits between the jobs it opens; `None` if off
+    pub cooldown: Option<u64>,
+    /// Most jobs a client may open in one block; `None` if unlimited
+    pub per_block: Option<u32>,
+}
+
+/// Which EAS attestations mark a freelancer as verified
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AttestationPolicy {
+    /// Ethereum Attestation Service contract; zero if attested deposits are disabled
+    pub eas: Address,
+    /// Schema a verifying attestation must use
+    pub schema: B256,
+    /// Attester trusted to issue it
+    pub attester: Address,
+}
+
+/// Arbitration terms assigned to new disputes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArbitrationPolicy {
+    /// Arbitrator who rules on disputes; zero if none is appointed
+    pub arbitrator: Address,
+    /// Seconds the arbitrator has to rule
+    pub ruling_period: u64,
+    /// Freelancer's share, in basis points, once the ruling period passes
+    pub default_split_bps: u16,
+}
+
+/// Stake new disputes take: a share of the job, but at least a minimum
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DisputeStakePolicy {
+    /// Share of the job's amount, in basis points
+    pub stake_bps: u16,
+    /// Least any dispute stakes, in wei
+    pub min_stake: U256,
+}
+
+/// How rulings are held for appeal to governance
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AppealPolicy {
+    /// Seconds a ruling can be appealed; 0 if rulings settle at once
+    pub window: u64,
+    /// Bond as a share of the job's amount, in basis points
+    pub bond_bps: u16,
+    /// Least any appeal bonds, in wei
+    pub min_bond: U256,
+}
+
+/// Ruling held for appeal before it settles its job
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeldRuling {
+    /// Arbitrator, or whoever tallied the jury
+    pub resolver: Address,
+    /// Freelancer's share, in basis points
+    pub freelancer_bps: u16,
+    /// When the appeal window closes
+    pub appealable_until: u64,
+}
+
+/// Appeal of a held ruling, decided by a governance proposal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Appeal {
+    pub appellant: Address,
+    /// Freelancer's share the appellant asked for, in basis points
+    pub freelancer_bps: u16,
+    /// Bond still held, in wei; 0 once the appeal is decided
+    pub bond: U256,
+    pub proposal_id: U256,
+}
+
+/// Jury an escalated dispute seats
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JuryPolicy {
+    /// Jurors on each panel; 0 if juries are off
+    pub size: u8,
+    /// Seconds the panel has to vote
+    pub voting_period: u64,
+    /// Least a juror must stake to be drawn, in wei
+    pub min_stake: U256,
+    /// Share of their stake a juror outside the majority loses, in basis
+    /// points
+    pub slash_bps: u16,
+}
+
+/// A member of the juror registry
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Juror {
+    /// Stake they can withdraw on leaving, in wei
+    pub stake: U256,
+    /// Whether they are in the registry
+    pub registered: bool,
+    /// Juries they sit on that haven't been tallied
+    pub panels: u32,
+}
+
+/// Panel of jurors a dispute was escalated to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Jury {
+    pub panel: Vec<Address>,
+    /// When voting closes
+    pub vote_by: u64,
+    pub votes_cast: u8,
+    /// Whether the votes were counted and the jurors dismissed
+    pub tallied: bool,
+}
+
+/// Timeout rule assigned to new disputes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DisputeTimeoutPolicy {
+    /// Seconds a dispute may stay unresolved; 0 for no limit
+    pub timeout: u64,
+    /// Split the job once the timeout passes; otherwise lift the dispute so
+    /// the job settles by its deadline
+    pub split: bool,
+    /// Freelancer's share when splitting, in basis points
+    pub split_bps: u16,
+}
+
+/// Admin-approved router that swaps payouts into freelancers' tokens
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SwapRouter {
+    /// Uniswap V2-style router; zero if swaps are disabled
+    pub router: Address,
+    /// WETH the router swaps through
+    pub weth: Address,
+}
+
+/// Token a freelancer wants to be paid in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PayoutToken {
+    /// ERC-20 to receive; zero for ETH
+    pub token: Address,
+    /// Minimum token units received per ETH, bounding slippage
+    pub min_rate: U256,
+}
+
+/// Where a freelancer's payouts are sent
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PayoutAddress {
+    /// Current payout address; zero if the freelancer is paid directly
+    pub current: Address,
+    /// Proposed payout address waiting out its delay
+    pub pending: Address,
+    /// When the pending address can be applied; 0 if none is pending
+    pub effective_at: u64,
+}
+
+/// Where a job's funds would go if it were settled now, in wei
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SettlementPreview {
+    /// Paid to the freelancer, after withholding
+    pub freelancer: U256,
+    /// Sent to the freelancer's withholding destination
+    pub withheld: U256,
+    /// Returned to the client
+    pub client: U256,
+    /// Protocol fee
+    pub fee: U256,
+    /// Fee to the marketplace the job was opened through, if any
+    pub platform_fee: U256,
+    /// Holdback left in escrow through the job's warranty
+    pub retained: U256,
+}
+
+/// All-in pricing of a hypothetical job, in wei
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeeQuote {
+    pub protocol_fee: U256,
+    pub platform_fee: U256,
+    /// What the freelancer receives, before their own withholding
+    pub net: U256,
+}
+
+/// A size tier of the protocol fee schedule
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeeTier {
+    /// Smallest job amount, in wei, the tier applies to
+    pub min_amount: U256,
+    pub fee_bps: u16,
+}
+
+/// A discount off the protocol fee for clients with enough released volume
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VolumeDiscount {
+    /// Lifetime volume, in wei, a client's jobs must have released
+    pub min_volume: U256,
+    /// Share of the fee waived, in basis points
+    pub discount_bps: u16,
+}
+
+/// Share of a freelancer's payouts withheld for tax
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Withholding {
+    /// Withheld share in basis points; 0 if off
+    pub rate_bps: u16,
+    /// Where withheld amounts are sent
+    pub destination: Address,
+}
@@ -0,0 +1,157 @@
+//! Front-run-safe deployment of the escrow contract.
+//!
+//! The escrow sets its admin in a Stylus constructor, which only runs when the
+//! chain's `StylusDeployer` creates, activates and calls it in one transaction,
+//! so the contract never exists on chain without an admin. The resulting state
+//! is checked before the address is handed back.
+
+use alloy::network::{Ethereum, TransactionBuilder};
+use alloy::primitives::{address, Address, Bytes, B256, U256};
+use alloy::providers::Provider;
+use alloy::rpc::types::TransactionRequest;
+use alloy::sol;
+use alloy::sol_types::SolValue;
+use escrow_client::{ClientError, EscrowClient};
+use eyre::{ensure, eyre, Result};
+use std::path::Path;
+use tokio::process::Command;
+
+/// `StylusDeployer` address on Arbitrum One, Arbitrum Sepolia and nitro-devnode
+pub const STYLUS_DEPLOYER: Address = address!("cEcba2F1DC234f70Dd89F2041029807F8D03A990");
+
+/// Selector the Stylus router dispatches to `#[constructor]`: the first four
+/// bytes of `keccak256("stylus_constructor()")`
+pub const CONSTRUCTOR_SELECTOR: [u8; 4] = [0x55, 0x85, 0x25, 0x8d];
+
+sol! {
+    /// Factory that deploys, activates and constructs a Stylus contract atomically
+    #[sol(rpc)]
+    interface IStylusDeployer {
+        event ContractDeployed(address deployedContract);
+
+        function deploy(bytes calldata bytecode, bytes calldata initData, uint256 initValue, bytes32 salt) external payable returns (address);
+    }
+}
+
+/// Compile the contract in `contract_dir` and return its deployment init code
+pub async fn initcode(contract_dir: &Path) -> Result<Bytes> {
+    let output = Command::new("cargo")
+        .args(["stylus", "get-initcode"])
+        .current_dir(contract_dir)
+        .output()
+        .await?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(eyre!("cargo stylus get-initcode failed:\n{stdout}{stderr}"));
+    }
+    parse_initcode(&stdout).ok_or_else(|| eyre!("no init code in output:\n{stdout}"))
+}
+
+/// Constructor calldata for `admin`, run by the deployer right after activation
+pub fn init_data(admin: Address) -> Bytes {
+    let mut data = CONSTRUCTOR_SELECTOR.to_vec();
+    data.extend(admin.abi_encode());
+    data.into()
+}
+
+/// Deploy `initcode` through `deployer`, constructing it with `admin` in the
+/// same transaction.
+///
+/// `activation_budget` is sent along to pay the activation fee; the deployer
+/// refunds whatever is left. Returns the new contract's address.
+pub async fn deploy<P: Provider<Ethereum>>(
+    provider: &P,
+    deployer: Address,
+    initcode: Bytes,
+    admin: Address,
+    salt: B256,
+    activation_budget: U256,
+) -> Result<Address> {
+    let factory = IStylusDeployer::new(deployer, provider);
+    let receipt = factory
+        .deploy(initcode, init_data(admin), U256::ZERO, salt)
+        .value(activation_budget)
+        .send()
+        .await
+        .map_err(ClientError::from)?
+        .get_receipt()
+        .await?;
+    ensure!(
+        receipt.status(),
+        "deployment {} reverted",
+        receipt.transaction_hash
+    );
+    receipt
+        .inner
+        .logs()
+        .iter()
+        .find_map(|log| log.log_decode::<IStylusDeployer::ContractDeployed>().ok())
+        .map(|log| log.inner.data.deployedContract)
+        .ok_or_else(|| eyre!("receipt has no ContractDeployed event"))
+}
+
+/// Check that the escrow at `address` is freshly constructed with `admin` and
+/// its constructor cannot run again
+pub async fn verify<P: Provider<Ethereum>>(
+    provider: &P,
+    address: Address,
+    admin: Address,
+) -> Result<()> {
+    // `admin` is the first storage field, packed in the low bytes of slot 0
+    let slot = provider.get_storage_at(address, U256::ZERO).await?;
+    let stored = Address::from_word(B256::from(slot));
+    ensure!(stored == admin, "admin is {stored}, expected {admin}");
+
+    let client = EscrowClient::new(address, provider);
+    ensure!(!client.is_paused().await?, "escrow is paused");
+    ensure!(
+        client.get_total_jobs().await?.is_zero(),
+        "escrow already has jobs"
+    );
+    ensure!(client.verify_solvency().await?, "escrow is not solvent");
+
+    let rerun = TransactionRequest::default()
+        .with_to(address)
+        .with_input(init_data(admin));
+    ensure!(
+        provider.call(&rerun).await.is_err(),
+        "escrow constructor can run again"
+    );
+    Ok(())
+}
+
+/// Extract the hex init code from `cargo stylus get-initcode` output
+fn parse_initcode(output: &str) -> Option<Bytes> {
+    output
+        .lines()
+        .rev()
+        .find(|line| !line.trim().is_empty())?
+        .trim()
+        .parse()
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::keccak256;
+
+    #[test]
+    fn test_parse_initcode() {
+        assert_eq!(
+            parse_initcode("0x7f000000eff00000\n\n"),
+            Some(Bytes::from_static(&[0x7f, 0, 0, 0, 0xef, 0xf0, 0, 0]))
+        );
+        assert_eq!(parse_initcode(""), None);
+        assert_eq!(parse_initcode("error: could not compile"), None);
+    }
+
+    #[test]
+    fn test_init_data() {
+        let admin = Address::from([0x01; 20]);
+        let data = init_data(admin);
+        assert_eq!(data[..4], keccak256("stylus_constructor()")[..4]);
+        assert_eq!(data[16..], admin[..]);
+    }
+}
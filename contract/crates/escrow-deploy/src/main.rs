@@ -0,0 +1,100 @@
+//! Deploy, activate and construct the escrow in one transaction.
+
+use alloy::network::EthereumWallet;
+use alloy::primitives::utils::parse_ether;
+use alloy::primitives::{Address, B256};
+use alloy::providers::ProviderBuilder;
+use alloy::signers::local::PrivateKeySigner;
+use clap::Parser;
+use escrow_deploy::{deploy, initcode, verify, STYLUS_DEPLOYER};
+use eyre::{eyre, Result};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+#[derive(Parser)]
+#[command(
+    name = "escrow-deploy",
+    about = "Deploy and construct the escrow atomically"
+)]
+struct Cli {
+    /// RPC endpoint
+    #[arg(long, env = "RPC_URL")]
+    rpc_url: String,
+
+    /// File containing a hex-encoded private key
+    #[arg(long, env = "PRIV_KEY_PATH", conflicts_with = "keystore")]
+    private_key_path: Option<PathBuf>,
+
+    /// Encrypted JSON keystore file
+    #[arg(long, env = "KEYSTORE_PATH")]
+    keystore: Option<PathBuf>,
+
+    /// Password for the keystore
+    #[arg(long, env = "KEYSTORE_PASSWORD", hide_env_values = true)]
+    keystore_password: Option<String>,
+
+    /// Admin of the new escrow; defaults to the deploying account
+    #[arg(long)]
+    admin: Option<Address>,
+
+    /// CREATE2 salt
+    #[arg(long, default_value_t = B256::ZERO)]
+    salt: B256,
+
+    /// StylusDeployer contract
+    #[arg(long, default_value_t = STYLUS_DEPLOYER)]
+    deployer: Address,
+
+    /// Most ETH to spend on activation; the rest is refunded
+    #[arg(long, default_value = "0.01")]
+    activation_budget: String,
+
+    /// Contract crate to compile
+    #[arg(long, default_value = concat!(env!("CARGO_MANIFEST_DIR"), "/../.."))]
+    contract_dir: PathBuf,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    dotenv::dotenv().ok();
+    let cli = Cli::parse();
+    let signer = load_signer(&cli)?;
+    let admin = cli.admin.unwrap_or(signer.address());
+    let provider = ProviderBuilder::new()
+        .wallet(EthereumWallet::from(signer))
+        .on_http(cli.rpc_url.parse()?);
+
+    println!("Compiling {}", cli.contract_dir.display());
+    let initcode = initcode(&cli.contract_dir).await?;
+
+    println!("Deploying via {} with admin {admin}", cli.deployer);
+    let address = deploy(
+        &provider,
+        cli.deployer,
+        initcode,
+        admin,
+        cli.salt,
+        parse_ether(&cli.activation_budget)?,
+    )
+    .await?;
+
+    verify(&provider, address, admin).await?;
+    println!("Escrow deployed at {address}");
+    Ok(())
+}
+
+/// Load the deploying key from a private key file or keystore
+fn load_signer(cli: &Cli) -> Result<PrivateKeySigner> {
+    if let Some(path) = &cli.private_key_path {
+        let key = std::fs::read_to_string(path)?;
+        return Ok(PrivateKeySigner::from_str(key.trim())?);
+    }
+    if let Some(path) = &cli.keystore {
+        let password = cli
+            .keystore_password
+            .as_ref()
+            .ok_or_else(|| eyre!("--keystore requires KEYSTORE_PASSWORD"))?;
+        return Ok(PrivateKeySigner::decrypt_keystore(path, password)?);
+    }
+    Err(eyre!("set PRIV_KEY_PATH or --keystore"))
+}
@@ -0,0 +1,11 @@
+//! Off-chain indexer for the freelance payment escrow contract.
+//!
+//! Follows `Deposited`/`Released`/`Refunded`/`AutoReleased`/`EmergencyRefunded`
+//! logs from an RPC endpoint, keeps job state in SQLite, and rolls back
+//! state when a previously indexed block is reorged out.
+
+mod store;
+mod sync;
+
+pub use store::{EventKind, IndexedEvent, JobRecord, JobState, Store};
+pub use sync::{decode_log, Indexer};
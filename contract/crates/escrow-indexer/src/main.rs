@@ -0,0 +1,34 @@
+use alloy::primitives::Address;
+use alloy::providers::ProviderBuilder;
+use escrow_indexer::{Indexer, Store};
+use eyre::eyre;
+use std::str::FromStr;
+use std::time::Duration;
+
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    dotenv::dotenv().ok();
+    let rpc_url = std::env::var("RPC_URL").map_err(|_| eyre!("No RPC_URL env var set"))?;
+    let address = std::env::var("STYLUS_CONTRACT_ADDRESS")
+        .map_err(|_| eyre!("No STYLUS_CONTRACT_ADDRESS env var set"))?;
+    let db_path =
+        std::env::var("INDEXER_DB_PATH").unwrap_or_else(|_| "escrow-indexer.db".to_string());
+    let start_block = env_u64("INDEXER_START_BLOCK", 0)?;
+    let confirmations = env_u64("INDEXER_CONFIRMATIONS", 0)?;
+    let poll_secs = env_u64("INDEXER_POLL_SECS", 5)?;
+
+    let provider = ProviderBuilder::new().on_http(rpc_url.parse()?);
+    let store = Store::open(&db_path)?;
+    let mut indexer = Indexer::new(provider, Address::from_str(&address)?, store, start_block)
+        .with_confirmations(confirmations);
+
+    println!("Indexing escrow {address} into {db_path}");
+    indexer.run(Duration::from_secs(poll_secs)).await
+}
+
+fn env_u64(key: &str, default: u64) -> eyre::Result<u64> {
+    match std::env::var(key) {
+        Ok(value) => Ok(value.parse()?),
+        Err(_) => Ok(default),
+    }
+}
@@ -0,0 +1,1771 @@
+use alloy::primitives::{Address, B256, U256};
+use eyre::{eyre, Result};
+use escrow_types::{BASIS_POINTS, JOB_FIELD_AMOUNT, JOB_FIELD_CLIENT, JOB_FIELD_FREELANCER};
+use rusqlite::{params, Connection, OptionalExtension, Row};
+use std::path::Path;
+use std::str::FromStr;
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS blocks (
+    number INTEGER PRIMARY KEY,
+    hash TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS events (
+    block_number INTEGER NOT NULL,
+    log_index INTEGER NOT NULL,
+    block_hash TEXT NOT NULL,
+    tx_hash TEXT NOT NULL,
+    job_id INTEGER NOT NULL,
+    kind TEXT NOT NULL,
+    client TEXT,
+    freelancer TEXT,
+    amount TEXT,
+    memo TEXT,
+    PRIMARY KEY (block_number, log_index)
+);
+CREATE INDEX IF NOT EXISTS events_job_id ON events (job_id);
+CREATE TABLE IF NOT EXISTS jobs (
+    job_id INTEGER PRIMARY KEY,
+    client TEXT NOT NULL,
+    freelancer TEXT NOT NULL,
+    amount TEXT NOT NULL,
+    status TEXT NOT NULL,
+    created_block INTEGER NOT NULL,
+    settled_block INTEGER
+);
+CREATE INDEX IF NOT EXISTS jobs_client ON jobs (client);
+CREATE INDEX IF NOT EXISTS jobs_freelancer ON jobs (freelancer);
+";
+
+/// Decoded escrow event payload
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EventKind {
+    Deposited {
+        client: Address,
+        freelancer: Address,
+        amount: U256,
+        memo: B256,
+    },
+    Released {
+        amount: U256,
+        memo: B256,
+    },
+    Refunded {
+        amount: U256,
+        memo: B256,
+    },
+    AutoReleased {
+        amount: U256,
+        memo: B256,
+    },
+    EmergencyRefunded {
+        admin: Address,
+    },
+    /// The freelancer gave the job up, refunding the client
+    FundsReturned {
+        amount: U256,
+        memo: B256,
+    },
+    /// The job's unclaimed funds went back to the client after the claim window
+    Reclaimed {
+        caller: Address,
+        amount: U256,
+        memo: B256,
+    },
+    DormancyAnnounced {
+        sweep_after: u64,
+    },
+    DormantSwept {
+        recovery: Address,
+        amount: U256,
+    },
+    /// A private job; its freelancer is unknown until revealed
+    PrivateDeposited {
+        client: Address,
+        amount: U256,
+        commitment: B256,
+    },
+    JobRevealed {
+        freelancer: Address,
+        amount: U256,
+        terms: B256,
+    },
+    /// The payout was swapped into `amount_out` of the freelancer's `token`
+    PayoutSwapped {
+        token: Address,
+        amount_out: U256,
+    },
+    /// The swap failed and the freelancer was paid in ETH
+    PayoutSwapFailed {
+        token: Address,
+    },
+    /// `amount` was paid to the contract at `recipient`, or credited to it if
+    /// the contract refused the push
+    PayoutPushed {
+        recipient: Address,
+        amount: U256,
+        pushed: bool,
+    },
+    /// Part of a payout was withheld for tax
+    Withheld {
+        destination: Address,
+        withheld: U256,
+    },
+    /// The job was opened for a freelancer verified by attestation `uid`
+    JobAttested {
+        uid: B256,
+    },
+    /// The client named the arbitrator of the job's disputes
+    JobArbitratorSet {
+        arbitrator: Address,
+    },
+    /// Governance handed the job's dispute to a new arbitrator, who has
+    /// until `rule_by` to rule
+    ArbitratorReplaced {
+        arbitrator: Address,
+        rule_by: u64,
+    },
+    /// A party consented to staking the job with `freelancer_yield_bps` of
+    /// the yield to the freelancer and `treasury_yield_bps` to the treasury
+    StakeConsented {
+        party: Address,
+        freelancer_yield_bps: u16,
+        treasury_yield_bps: u16,
+    },
+    /// Both parties consented and the job's `amount` was staked into
+    /// `shares` of `token`
+    JobStaked {
+        token: Address,
+        amount: U256,
+        shares: U256,
+    },
+    /// `buyer` bought the job's shares for `paid`, replacing the staked
+    /// `principal`
+    JobUnstaked {
+        buyer: Address,
+        paid: U256,
+        principal: U256,
+    },
+    /// The job was staked at a split of any yield it earns between client,
+    /// freelancer and treasury
+    YieldSplitSet {
+        client_yield_bps: u16,
+        freelancer_yield_bps: u16,
+        treasury_yield_bps: u16,
+    },
+    /// A settlement hook was attached, or detached with the zero address
+    SettlementHookSet {
+        hook: Address,
+    },
+    /// The settlement hook reverted; the settlement itself went through
+    SettlementHookFailed {
+        hook: Address,
+    },
+    /// The job's auto-release waits on `condition`
+    ReleaseConditionSet {
+        condition: Address,
+    },
+    /// The client released part of the job, leaving `remaining` in escrow
+    PartiallyReleased {
+        amount: U256,
+        remaining: U256,
+    },
+    /// The freelancer claimed a tranche, leaving `remaining` in escrow
+    TrancheClaimed {
+        amount: U256,
+        remaining: U256,
+    },
+    /// The client took back a tranche, leaving `remaining` in escrow
+    TrancheRefunded {
+        amount: U256,
+        remaining: U256,
+    },
+    /// The job is a retainer paying out every `period` seconds
+    RetainerStarted {
+        period: u64,
+    },
+    /// The client stopped the retainer, taking back `refunded`
+    RetainerStopped {
+        refunded: U256,
+    },
+    /// Refunds need `notice` seconds' warning
+    CancellationNoticeSet {
+        notice: u64,
+    },
+    /// The client asked to cancel; a refund is allowed from `effective_at`
+    CancellationRequested {
+        effective_at: u64,
+    },
+    /// The freelancer delivered, withdrawing the pending cancellation
+    DeliverySubmitted {
+        delivery: B256,
+    },
+    /// The job was opened to raise `target` in installments; it is recorded
+    /// as a job once `Deposited` marks it funded
+    FundingOpened {
+        client: Address,
+        freelancer: Address,
+        target: U256,
+    },
+    /// `contributor` paid `amount` towards the job's target
+    JobFunded {
+        contributor: Address,
+        amount: U256,
+    },
+    /// `contributor` took back `amount` after the job missed its cutoff
+    ContributionReclaimed {
+        contributor: Address,
+        amount: U256,
+    },
+    /// The deposit's surplus over the job amount was credited to `client`
+    ExcessCredited {
+        client: Address,
+        excess: U256,
+    },
+    /// A payout paid `fee` to the protocol, credited to `recipient`
+    FeeCharged {
+        recipient: Address,
+        fee: U256,
+    },
+    /// `amount` of the payout's fee was rebated to `caller` for settling it
+    SettlementRebated {
+        caller: Address,
+        amount: U256,
+    },
+    DisputeOpened {
+        opened_by: Address,
+        memo: B256,
+    },
+    /// One of the job's terms was amended; the old value is not indexed, as
+    /// it is the field's previous value
+    JobAmended {
+        field: u8,
+        new_value: B256,
+        actor: Address,
+    },
+    /// The client offered the job to `new_client`, who takes over once a
+    /// `JobAmended` of the client field records their acceptance
+    ClientTransferProposed {
+        client: Address,
+        new_client: Address,
+    },
+    /// The freelancer committed to the job
+    JobAccepted {
+        freelancer: Address,
+    },
+    /// The client handed the job to another freelancer before it was
+    /// accepted; the paired `JobAmended` updates the job itself
+    FreelancerChanged {
+        old_freelancer: Address,
+        new_freelancer: Address,
+    },
+    /// The job was opened through a marketplace taking `fee_bps` of every
+    /// payout to the freelancer
+    PlatformFeeSet {
+        platform: Address,
+        fee_bps: u16,
+    },
+    /// The job was opened by the client paying invoice `invoice_id`
+    InvoiceFunded {
+        invoice_id: U256,
+    },
+    /// `holdback_bps` of the job is held back for `warranty` seconds after
+    /// release
+    HoldbackSet {
+        holdback_bps: u16,
+        warranty: u64,
+    },
+    /// The job was released down to its holdback, which can be released
+    /// from `release_at`
+    HoldbackRetained {
+        amount: U256,
+        release_at: u64,
+    },
+    /// Releases of the job wait `window` seconds before paying out
+    ClawbackWindowSet {
+        window: u64,
+    },
+    /// The job was released, paying out `amount` from `payable_at` unless
+    /// the arbitrator reverses it first
+    ReleaseQueued {
+        amount: U256,
+        payable_at: u64,
+    },
+    /// The arbitrator reversed the queued release, refunding the client
+    ReleaseReversed {
+        arbitrator: Address,
+        amount: U256,
+    },
+    /// The job is due by `delivery_by`, refunding `late_bps_per_day` of it
+    /// for every full day it is delivered late
+    SlaSet {
+        delivery_by: u64,
+        late_bps_per_day: u16,
+    },
+    /// The job is an hourly engagement billed at `rate` per hour
+    HourlyRateSet {
+        rate: U256,
+    },
+    /// The freelancer billed `amount` in a timesheet described by `details`
+    TimesheetSubmitted {
+        amount: U256,
+        details: B256,
+    },
+    /// The client approved a timesheet, leaving `remaining` of the budget
+    TimesheetApproved {
+        amount: U256,
+        remaining: U256,
+    },
+    /// The client rejected timesheet `index`
+    TimesheetRejected {
+        index: U256,
+    },
+    /// The client escrowed `amount` of `token` in the job's basket
+    BasketFunded {
+        token: Address,
+        amount: U256,
+    },
+    /// `amount` of basket `token` was paid out to `recipient`
+    BasketPaid {
+        token: Address,
+        recipient: Address,
+        amount: U256,
+    },
+    /// `client` claimed an emergency refund of `amount` from the published
+    /// Merkle root, leaving `remaining` escrowed
+    EmergencyRefundClaimed {
+        client: Address,
+        amount: U256,
+        remaining: U256,
+    },
+    /// `rater` gave the other party, `ratee`, `stars` stars for the settled
+    /// job
+    JobRated {
+        rater: Address,
+        ratee: Address,
+        stars: u8,
+    },
+    /// `amount` was refunded to the client for delivering `days_late` days
+    /// late (followed by the release of the rest)
+    LateRefunded {
+        days_late: u64,
+        amount: U256,
+    },
+    /// The job's dispute ran past its timeout and was split (followed by
+    /// `DisputeResolved`) or lifted
+    DisputeTimedOut {
+        split: bool,
+    },
+    /// The dispute's stake of `amount` went to `recipient`: back to whoever
+    /// opened it, or forfeited to the other party
+    DisputeStakeSettled {
+        recipient: Address,
+        amount: U256,
+        forfeited: bool,
+    },
+    /// The dispute was escalated to a jury voting until `vote_by`
+    JuryDrawn {
+        vote_by: u64,
+    },
+    /// A juror voted to give the freelancer `freelancer_bps` of the job
+    JuryVoted {
+        juror: Address,
+        freelancer_bps: u16,
+    },
+    /// The jury's votes were counted, settling at `freelancer_bps` and
+    /// moving `slashed` from the minority's stakes to the majority's
+    JuryTallied {
+        freelancer_bps: u16,
+        slashed: U256,
+    },
+    /// `resolver` ruled `freelancer_bps`, held for appeal until
+    /// `appealable_until`
+    RulingHeld {
+        resolver: Address,
+        freelancer_bps: u16,
+        appealable_until: u64,
+    },
+    /// `appellant` appealed the held ruling to governance, asking for
+    /// `freelancer_bps` and bonding `bond`
+    RulingAppealed {
+        appellant: Address,
+        freelancer_bps: u16,
+        bond: U256,
+    },
+    /// The appeal's bond of `amount` went to `recipient`: back to the
+    /// appellant, or forfeited to the other party
+    AppealBondSettled {
+        recipient: Address,
+        amount: U256,
+        forfeited: bool,
+    },
+    /// The job was opened needing both parties to approve its release
+    DualApprovalRequired,
+    /// One of the parties approved releasing the job
+    ReleaseApproved {
+        approver: Address,
+    },
+    /// The job's dispute was settled, splitting it between the parties;
+    /// whether the split was the default is not indexed
+    DisputeResolved {
+        resolver: Address,
+        freelancer_amount: U256,
+        client_amount: U256,
+    },
+    /// Evidence number `index` was added to the job's dispute
+    EvidenceSubmitted {
+        submitter: Address,
+        index: U256,
+        evidence_hash: B256,
+    },
+}
+
+impl EventKind {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Deposited { .. } => "Deposited",
+            Self::Released { .. } => "Released",
+            Self::Refunded { .. } => "Refunded",
+            Self::AutoReleased { .. } => "AutoReleased",
+            Self::EmergencyRefunded { .. } => "EmergencyRefunded",
+            Self::Reclaimed { .. } => "Reclaimed",
+            Self::FundsReturned { .. } => "FundsReturned",
+            Self::DormancyAnnounced { .. } => "DormancyAnnounced",
+            Self::DormantSwept { .. } => "DormantSwept",
+            Self::PrivateDeposited { .. } => "PrivateDeposited",
+            Self::JobRevealed { .. } => "JobRevealed",
+            Self::PayoutSwapped { .. } => "PayoutSwapped",
+            Self::PayoutSwapFailed { .. } => "PayoutSwapFailed",
+            Self::PayoutPushed { .. } => "PayoutPushed",
+            Self::Withheld { .. } => "Withheld",
+            Self::JobAttested { .. } => "JobAttested",
+            Self::JobArbitratorSet { .. } => "JobArbitratorSet",
+            Self::ArbitratorReplaced { .. } => "ArbitratorReplaced",
+            Self::StakeConsented { .. } => "StakeConsented",
+            Self::JobStaked { .. } => "JobStaked",
+            Self::JobUnstaked { .. } => "JobUnstaked",
+            Self::YieldSplitSet { .. } => "YieldSplitSet",
+            Self::SettlementHookSet { .. } => "SettlementHookSet",
+            Self::SettlementHookFailed { .. } => "SettlementHookFailed",
+            Self::ReleaseConditionSet { .. } => "ReleaseConditionSet",
+            Self::PartiallyReleased { .. } => "PartiallyReleased",
+            Self::TrancheClaimed { .. } => "TrancheClaimed",
+            Self::TrancheRefunded { .. } => "TrancheRefunded",
+            Self::RetainerStarted { .. } => "RetainerStarted",
+            Self::RetainerStopped { .. } => "RetainerStopped",
+            Self::CancellationNoticeSet { .. } => "CancellationNoticeSet",
+            Self::CancellationRequested { .. } => "CancellationRequested",
+            Self::DeliverySubmitted { .. } => "DeliverySubmitted",
+            Self::FundingOpened { .. } => "FundingOpened",
+            Self::JobFunded { .. } => "JobFunded",
+            Self::ContributionReclaimed { .. } => "ContributionReclaimed",
+            Self::ExcessCredited { .. } => "ExcessCredited",
+            Self::FeeCharged { .. } => "FeeCharged",
+            Self::SettlementRebated { .. } => "SettlementRebated",
+            Self::DisputeOpened { .. } => "DisputeOpened",
+            Self::EvidenceSubmitted { .. } => "EvidenceSubmitted",
+            Self::DisputeResolved { .. } => "DisputeResolved",
+            Self::DisputeTimedOut { .. } => "DisputeTimedOut",
+            Self::DisputeStakeSettled { .. } => "DisputeStakeSettled",
+            Self::JuryDrawn { .. } => "JuryDrawn",
+            Self::JuryVoted { .. } => "JuryVoted",
+            Self::JuryTallied { .. } => "JuryTallied",
+            Self::RulingHeld { .. } => "RulingHeld",
+            Self::RulingAppealed { .. } => "RulingAppealed",
+            Self::AppealBondSettled { .. } => "AppealBondSettled",
+            Self::JobAmended { .. } => "JobAmended",
+            Self::ClientTransferProposed { .. } => "ClientTransferProposed",
+            Self::JobAccepted { .. } => "JobAccepted",
+            Self::FreelancerChanged { .. } => "FreelancerChanged",
+            Self::PlatformFeeSet { .. } => "PlatformFeeSet",
+            Self::InvoiceFunded { .. } => "InvoiceFunded",
+            Self::HoldbackSet { .. } => "HoldbackSet",
+            Self::HoldbackRetained { .. } => "HoldbackRetained",
+            Self::ClawbackWindowSet { .. } => "ClawbackWindowSet",
+            Self::ReleaseQueued { .. } => "ReleaseQueued",
+            Self::ReleaseReversed { .. } => "ReleaseReversed",
+            Self::SlaSet { .. } => "SlaSet",
+            Self::HourlyRateSet { .. } => "HourlyRateSet",
+            Self::TimesheetSubmitted { .. } => "TimesheetSubmitted",
+            Self::TimesheetApproved { .. } => "TimesheetApproved",
+            Self::TimesheetRejected { .. } => "TimesheetRejected",
+            Self::BasketFunded { .. } => "BasketFunded",
+            Self::BasketPaid { .. } => "BasketPaid",
+            Self::EmergencyRefundClaimed { .. } => "EmergencyRefundClaimed",
+            Self::JobRated { .. } => "JobRated",
+            Self::LateRefunded { .. } => "LateRefunded",
+            Self::DualApprovalRequired => "DualApprovalRequired",
+            Self::ReleaseApproved { .. } => "ReleaseApproved",
+        }
+    }
+}
+
+/// An escrow event together with its position in the chain
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexedEvent {
+    pub block_number: u64,
+    pub block_hash: B256,
+    pub log_index: u64,
+    pub tx_hash: B256,
+    pub job_id: u64,
+    pub kind: EventKind,
+}
+
+/// Job settlement state as seen by the indexer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JobState {
+    Active,
+    Released,
+    Refunded,
+    /// Abandoned past the dormancy period and sent to the recovery address
+    Swept,
+}
+
+impl JobState {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Active => "active",
+            Self::Released => "released",
+            Self::Refunded => "refunded",
+            Self::Swept => "swept",
+        }
+    }
+
+    fn parse(s: &str) -> Result<Self> {
+        match s {
+            "active" => Ok(Self::Active),
+            "released" => Ok(Self::Released),
+            "refunded" => Ok(Self::Refunded),
+            "swept" => Ok(Self::Swept),
+            other => Err(eyre!("unknown job status {other}")),
+        }
+    }
+}
+
+/// Current state of an indexed job
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JobRecord {
+    pub job_id: u64,
+    pub client: Address,
+    pub freelancer: Address,
+    pub amount: U256,
+    pub state: JobState,
+    pub created_block: u64,
+    pub settled_block: Option<u64>,
+}
+
+/// SQLite-backed job and event store
+pub struct Store {
+    conn: Connection,
+}
+
+impl Store {
+    /// Open (or create) the database at `path`
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Self::init(Connection::open(path)?)
+    }
+
+    /// Open a throwaway in-memory database
+    pub fn in_memory() -> Result<Self> {
+        Self::init(Connection::open_in_memory()?)
+    }
+
+    fn init(conn: Connection) -> Result<Self> {
+        conn.execute_batch(SCHEMA)?;
+        Ok(Self { conn })
+    }
+
+    /// Highest block that has been fully indexed
+    pub fn last_block(&self) -> Result<Option<(u64, B256)>> {
+        self.conn
+            .query_row(
+                "SELECT number, hash FROM blocks ORDER BY number DESC LIMIT 1",
+                [],
+                |row| Ok((row.get::<_, u64>(0)?, row.get::<_, String>(1)?)),
+            )
+            .optional()?
+            .map(|(number, hash)| Ok((number, B256::from_str(&hash)?)))
+            .transpose()
+    }
+
+    /// Indexed blocks at or below `number`, newest first
+    pub fn blocks_before(&self, number: u64) -> Result<Vec<(u64, B256)>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT number, hash FROM blocks WHERE number <= ?1 ORDER BY number DESC")?;
+        let rows = stmt.query_map([number], |row| {
+            Ok((row.get::<_, u64>(0)?, row.get::<_, String>(1)?))
+        })?;
+        rows.map(|row| {
+            let (number, hash) = row?;
+            Ok((number, B256::from_str(&hash)?))
+        })
+        .collect()
+    }
+
+    /// Persist a batch of events and mark `(block_number, block_hash)` as indexed.
+    ///
+    /// Events must be ordered by block number and log index.
+    pub fn commit_batch(
+        &mut self,
+        events: &[IndexedEvent],
+        block_number: u64,
+        block_hash: B256,
+    ) -> Result<()> {
+        let tx = self.conn.transaction()?;
+        for event in events {
+            insert_event(&tx, event)?;
+            apply_event(&tx, event)?;
+            tx.execute(
+                "INSERT OR REPLACE INTO blocks (number, hash) VALUES (?1, ?2)",
+                params![event.block_number, event.block_hash.to_string()],
+            )?;
+        }
+        tx.execute(
+            "INSERT OR REPLACE INTO blocks (number, hash) VALUES (?1, ?2)",
+            params![block_number, block_hash.to_string()],
+        )?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Drop everything above `block_number` and rebuild the affected jobs
+    /// from the events that remain.
+    pub fn rollback_to(&mut self, block_number: u64) -> Result<()> {
+        let tx = self.conn.transaction()?;
+        let affected: Vec<u64> = {
+            let mut stmt =
+                tx.prepare("SELECT DISTINCT job_id FROM events WHERE block_number > ?1")?;
+            let rows = stmt.query_map([block_number], |row| row.get(0))?;
+            rows.collect::<rusqlite::Result<_>>()?
+        };
+        tx.execute("DELETE FROM events WHERE block_number > ?1", [block_number])?;
+        tx.execute("DELETE FROM blocks WHERE number > ?1", [block_number])?;
+        for job_id in affected {
+            tx.execute("DELETE FROM jobs WHERE job_id = ?1", [job_id])?;
+            for event in query_events(&tx, job_id)? {
+                apply_event(&tx, &event)?;
+            }
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Look up a single job
+    pub fn job(&self, job_id: u64) -> Result<Option<JobRecord>> {
+        self.conn
+            .query_row(
+                "SELECT job_id, client, freelancer, amount, status, created_block, settled_block
+                 FROM jobs WHERE job_id = ?1",
+                [job_id],
+                raw_job,
+            )
+            .optional()?
+            .map(parse_job)
+            .transpose()
+    }
+
+    /// Jobs funded by `client`
+    pub fn jobs_by_client(&self, client: Address) -> Result<Vec<JobRecord>> {
+        self.query_jobs("WHERE client = ?1", [client.to_string()])
+    }
+
+    /// Jobs assigned to `freelancer`
+    pub fn jobs_by_freelancer(&self, freelancer: Address) -> Result<Vec<JobRecord>> {
+        self.query_jobs("WHERE freelancer = ?1", [freelancer.to_string()])
+    }
+
+    /// Jobs currently in `state`
+    pub fn jobs_with_state(&self, state: JobState) -> Result<Vec<JobRecord>> {
+        self.query_jobs("WHERE status = ?1", [state.as_str()])
+    }
+
+    /// Full event history of a job, oldest first
+    pub fn job_events(&self, job_id: u64) -> Result<Vec<IndexedEvent>> {
+        query_events(&self.conn, job_id)
+    }
+
+    fn query_jobs<P: rusqlite::Params>(&self, filter: &str, params: P) -> Result<Vec<JobRecord>> {
+        let sql = format!(
+            "SELECT job_id, client, freelancer, amount, status, created_block, settled_block
+             FROM jobs {filter} ORDER BY job_id"
+        );
+        let mut stmt = self.conn.prepare(&sql)?;
+        let rows = stmt.query_map(params, raw_job)?;
+        rows.map(|row| parse_job(row?)).collect()
+    }
+}
+
+type RawJob = (u64, String, String, String, String, u64, Option<u64>);
+
+fn raw_job(row: &Row<'_>) -> rusqlite::Result<RawJob> {
+    Ok((
+        row.get(0)?,
+        row.get(1)?,
+        row.get(2)?,
+        row.get(3)?,
+        row.get(4)?,
+        row.get(5)?,
+        row.get(6)?,
+    ))
+}
+
+fn parse_job(raw: RawJob) -> Result<JobRecord> {
+    let (job_id, client, freelancer, amount, status, created_block, settled_block) = raw;
+    Ok(JobRecord {
+        job_id,
+        client: Address::from_str(&client)?,
+        freelancer: Address::from_str(&freelancer)?,
+        amount: U256::from_str(&amount)?,
+        state: JobState::parse(&status)?,
+        created_block,
+        settled_block,
+    })
+}
+
+fn insert_event(conn: &Connection, event: &IndexedEvent) -> Result<()> {
+    // A private job's commitment and revealed terms hash, an attestation UID,
+    // evidence hashes, what a tranche or partial release leaves in escrow, a
+    // holdback's warranty window or release time, when a queued release pays
+    // out, a dispute's refund to the client, whether a payout was pushed,
+    // whether a dispute stake was forfeited, a stake's shares or principal
+    // and the treasury's share of a yield split share the memo column
+    let memo = match &event.kind {
+        EventKind::Deposited { memo, .. }
+        | EventKind::Released { memo, .. }
+        | EventKind::Refunded { memo, .. }
+        | EventKind::AutoReleased { memo, .. }
+        | EventKind::FundsReturned { memo, .. }
+        | EventKind::Reclaimed { memo, .. }
+        | EventKind::DisputeOpened { memo, .. } => Some(*memo),
+        EventKind::PrivateDeposited { commitment, .. } => Some(*commitment),
+        EventKind::JobRevealed { terms, .. } => Some(*terms),
+        EventKind::JobAttested { uid } => Some(*uid),
+        EventKind::DeliverySubmitted { delivery } => Some(*delivery),
+        EventKind::EvidenceSubmitted { evidence_hash, .. } => Some(*evidence_hash),
+        EventKind::DisputeResolved { client_amount, .. } => Some(B256::from(*client_amount)),
+        EventKind::JobAmended { new_value, .. } => Some(*new_value),
+        EventKind::PartiallyReleased { remaining, .. }
+        | EventKind::TrancheClaimed { remaining, .. }
+        | EventKind::TrancheRefunded { remaining, .. }
+        | EventKind::TimesheetApproved { remaining, .. }
+        | EventKind::EmergencyRefundClaimed { remaining, .. } => Some(B256::from(*remaining)),
+        EventKind::TimesheetSubmitted { details, .. } => Some(*details),
+        EventKind::HoldbackSet { warranty, .. } => Some(B256::from(U256::from(*warranty))),
+        EventKind::HoldbackRetained { release_at, .. } => Some(B256::from(U256::from(*release_at))),
+        EventKind::ReleaseQueued { payable_at, .. } => Some(B256::from(U256::from(*payable_at))),
+        EventKind::SlaSet { delivery_by, .. } => Some(B256::from(U256::from(*delivery_by))),
+        EventKind::LateRefunded { days_late, .. } => Some(B256::from(U256::from(*days_late))),
+        EventKind::PayoutPushed { pushed, .. } => Some(B256::from(U256::from(*pushed))),
+        EventKind::DisputeStakeSettled { forfeited, .. } => {
+            Some(B256::from(U256::from(*forfeited)))
+        }
+        EventKind::JuryTallied { freelancer_bps, .. }
+        | EventKind::RulingHeld { freelancer_bps, .. }
+        | EventKind::RulingAppealed { freelancer_bps, .. } => {
+            Some(B256::from(U256::from(*freelancer_bps)))
+        }
+        EventKind::AppealBondSettled { forfeited, .. } => {
+            Some(B256::from(U256::from(*forfeited)))
+        }
+        EventKind::JobStaked { shares, .. } => Some(B256::from(*shares)),
+        EventKind::JobUnstaked { principal, .. } => Some(B256::from(*principal)),
+        EventKind::StakeConsented {
+            treasury_yield_bps, ..
+        }
+        | EventKind::YieldSplitSet {
+            treasury_yield_bps, ..
+        } => Some(B256::from(U256::from(*treasury_yield_bps))),
+        _ => None,
+    };
+    let (client, freelancer, amount) = match &event.kind {
+        EventKind::Deposited {
+            client,
+            freelancer,
+            amount,
+            ..
+        } => (Some(*client), Some(*freelancer), Some(*amount)),
+        EventKind::Released { amount, .. }
+        | EventKind::Refunded { amount, .. }
+        | EventKind::AutoReleased { amount, .. }
+        | EventKind::FundsReturned { amount, .. } => (None, None, Some(*amount)),
+        EventKind::EmergencyRefunded { admin } => (Some(*admin), None, None),
+        EventKind::Reclaimed { caller, amount, .. } => (Some(*caller), None, Some(*amount)),
+        // The notice's end is stored in the amount column
+        EventKind::DormancyAnnounced { sweep_after } => {
+            (None, None, Some(U256::from(*sweep_after)))
+        }
+        EventKind::DormantSwept { recovery, amount } => (Some(*recovery), None, Some(*amount)),
+        EventKind::PrivateDeposited { client, amount, .. } => (Some(*client), None, Some(*amount)),
+        EventKind::JobRevealed {
+            freelancer, amount, ..
+        } => (None, Some(*freelancer), Some(*amount)),
+        EventKind::PayoutSwapped { token, amount_out } => (Some(*token), None, Some(*amount_out)),
+        EventKind::PayoutSwapFailed { token } => (Some(*token), None, None),
+        EventKind::PayoutPushed {
+            recipient, amount, ..
+        } => (Some(*recipient), None, Some(*amount)),
+        EventKind::Withheld {
+            destination,
+            withheld,
+        } => (Some(*destination), None, Some(*withheld)),
+        EventKind::JobAttested { .. } => (None, None, None),
+        EventKind::JobArbitratorSet { arbitrator } => (Some(*arbitrator), None, None),
+        // The new ruling deadline is stored in the amount column
+        EventKind::ArbitratorReplaced {
+            arbitrator,
+            rule_by,
+        } => (Some(*arbitrator), None, Some(U256::from(*rule_by))),
+        // The freelancer's yield share is stored in the amount column and the
+        // treasury's in the memo column
+        EventKind::StakeConsented {
+            party,
+            freelancer_yield_bps,
+            ..
+        } => (Some(*party), None, Some(U256::from(*freelancer_yield_bps))),
+        EventKind::JobStaked { token, amount, .. } => (Some(*token), None, Some(*amount)),
+        EventKind::JobUnstaked { buyer, paid, .. } => (Some(*buyer), None, Some(*paid)),
+        // The freelancer's share likewise; the client's is what is left
+        EventKind::YieldSplitSet {
+            freelancer_yield_bps,
+            ..
+        } => (None, None, Some(U256::from(*freelancer_yield_bps))),
+        EventKind::SettlementHookSet { hook } | EventKind::SettlementHookFailed { hook } => {
+            (Some(*hook), None, None)
+        }
+        EventKind::ReleaseConditionSet { condition } => (Some(*condition), None, None),
+        EventKind::PartiallyReleased { amount, .. }
+        | EventKind::TrancheClaimed { amount, .. }
+        | EventKind::TrancheRefunded { amount, .. }
+        | EventKind::TimesheetSubmitted { amount, .. }
+        | EventKind::TimesheetApproved { amount, .. } => (None, None, Some(*amount)),
+        // The hourly rate and rejected timesheet index are stored in the
+        // amount column
+        EventKind::HourlyRateSet { rate } => (None, None, Some(*rate)),
+        EventKind::TimesheetRejected { index } => (None, None, Some(*index)),
+        // Basket tokens are stored in the client column and who was paid
+        // them in the freelancer column
+        EventKind::BasketFunded { token, amount } => (Some(*token), None, Some(*amount)),
+        EventKind::BasketPaid {
+            token,
+            recipient,
+            amount,
+        } => (Some(*token), Some(*recipient), Some(*amount)),
+        EventKind::EmergencyRefundClaimed { client, amount, .. } => {
+            (Some(*client), None, Some(*amount))
+        }
+        // The rater and ratee take the party columns and the stars the
+        // amount column
+        EventKind::JobRated {
+            rater,
+            ratee,
+            stars,
+        } => (Some(*rater), Some(*ratee), Some(U256::from(*stars))),
+        // The period length is stored in the amount column
+        EventKind::RetainerStarted { period } => (None, None, Some(U256::from(*period))),
+        EventKind::RetainerStopped { refunded } => (None, None, Some(*refunded)),
+        // Notice periods and when a cancellation takes effect likewise
+        EventKind::CancellationNoticeSet { notice } => (None, None, Some(U256::from(*notice))),
+        EventKind::CancellationRequested { effective_at } => {
+            (None, None, Some(U256::from(*effective_at)))
+        }
+        EventKind::DeliverySubmitted { .. } => (None, None, None),
+        EventKind::FundingOpened {
+            client,
+            freelancer,
+            target,
+        } => (Some(*client), Some(*freelancer), Some(*target)),
+        EventKind::JobFunded {
+            contributor,
+            amount,
+        }
+        | EventKind::ContributionReclaimed {
+            contributor,
+            amount,
+        } => (Some(*contributor), None, Some(*amount)),
+        EventKind::ExcessCredited { client, excess } => (Some(*client), None, Some(*excess)),
+        EventKind::FeeCharged { recipient, fee } => (Some(*recipient), None, Some(*fee)),
+        EventKind::SettlementRebated { caller, amount } => (Some(*caller), None, Some(*amount)),
+        EventKind::DisputeOpened { opened_by, .. } => (Some(*opened_by), None, None),
+        // Whether the dispute was split is stored in the amount column as 0 or 1
+        EventKind::DisputeTimedOut { split } => (None, None, Some(U256::from(*split))),
+        EventKind::DisputeStakeSettled {
+            recipient, amount, ..
+        } => (Some(*recipient), None, Some(*amount)),
+        // When voting closes is stored in the amount column
+        EventKind::JuryDrawn { vote_by } => (None, None, Some(U256::from(*vote_by))),
+        EventKind::JuryVoted {
+            juror,
+            freelancer_bps,
+        } => (Some(*juror), None, Some(U256::from(*freelancer_bps))),
+        EventKind::JuryTallied { slashed, .. } => (None, None, Some(*slashed)),
+        // When the appeal window closes is stored in the amount column
+        EventKind::RulingHeld {
+            resolver,
+            appealable_until,
+            ..
+        } => (Some(*resolver), None, Some(U256::from(*appealable_until))),
+        EventKind::RulingAppealed {
+            appellant, bond, ..
+        } => (Some(*appellant), None, Some(*bond)),
+        EventKind::AppealBondSettled {
+            recipient, amount, ..
+        } => (Some(*recipient), None, Some(*amount)),
+        // The amended field's ID is stored in the amount column
+        EventKind::JobAmended { field, actor, .. } => {
+            (Some(*actor), None, Some(U256::from(*field)))
+        }
+        // The offered client is stored in the freelancer column
+        EventKind::ClientTransferProposed { client, new_client } => {
+            (Some(*client), Some(*new_client), None)
+        }
+        EventKind::JobAccepted { freelancer } => (None, Some(*freelancer), None),
+        // The outgoing freelancer is stored in the client column
+        EventKind::FreelancerChanged {
+            old_freelancer,
+            new_freelancer,
+        } => (Some(*old_freelancer), Some(*new_freelancer), None),
+        // The fee rate is stored in the amount column
+        EventKind::PlatformFeeSet { platform, fee_bps } => {
+            (Some(*platform), None, Some(U256::from(*fee_bps)))
+        }
+        // The invoice ID is stored in the amount column
+        EventKind::InvoiceFunded { invoice_id } => (None, None, Some(*invoice_id)),
+        // The holdback rate likewise
+        EventKind::HoldbackSet { holdback_bps, .. } => {
+            (None, None, Some(U256::from(*holdback_bps)))
+        }
+        EventKind::HoldbackRetained { amount, .. } | EventKind::ReleaseQueued { amount, .. } => {
+            (None, None, Some(*amount))
+        }
+        // The clawback window likewise
+        EventKind::ClawbackWindowSet { window } => (None, None, Some(U256::from(*window))),
+        EventKind::ReleaseReversed { arbitrator, amount } => {
+            (Some(*arbitrator), None, Some(*amount))
+        }
+        // The late refund rate likewise
+        EventKind::SlaSet {
+            late_bps_per_day, ..
+        } => (None, None, Some(U256::from(*late_bps_per_day))),
+        EventKind::LateRefunded { amount, .. } => (None, None, Some(*amount)),
+        EventKind::DualApprovalRequired => (None, None, None),
+        EventKind::ReleaseApproved { approver } => (Some(*approver), None, None),
+        EventKind::DisputeResolved {
+            resolver,
+            freelancer_amount,
+            ..
+        } => (Some(*resolver), None, Some(*freelancer_amount)),
+        // The evidence's position in the trail is stored in the amount column
+        EventKind::EvidenceSubmitted {
+            submitter, index, ..
+        } => (Some(*submitter), None, Some(*index)),
+    };
+    conn.execute(
+        "INSERT OR REPLACE INTO events
+         (block_number, log_index, block_hash, tx_hash, job_id, kind, client, freelancer, amount, memo)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+        params![
+            event.block_number,
+            event.log_index,
+            event.block_hash.to_string(),
+            event.tx_hash.to_string(),
+            event.job_id,
+            event.kind.name(),
+            client.map(|a| a.to_string()),
+            freelancer.map(|a| a.to_string()),
+            amount.map(|a| a.to_string()),
+            memo.map(|m| m.to_string()),
+        ],
+    )?;
+    Ok(())
+}
+
+fn apply_event(conn: &Connection, event: &IndexedEvent) -> Result<()> {
+    let settled = |state: JobState| -> Result<()> {
+        conn.execute(
+            "UPDATE jobs SET status = ?1, settled_block = ?2 WHERE job_id = ?3",
+            params![state.as_str(), event.block_number, event.job_id],
+        )?;
+        Ok(())
+    };
+    match &event.kind {
+        EventKind::Deposited {
+            client,
+            freelancer,
+            amount,
+            ..
+        } => {
+            conn.execute(
+                "INSERT OR REPLACE INTO jobs
+                 (job_id, client, freelancer, amount, status, created_block, settled_block)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, NULL)",
+                params![
+                    event.job_id,
+                    client.to_string(),
+                    freelancer.to_string(),
+                    amount.to_string(),
+                    JobState::Active.as_str(),
+                    event.block_number,
+                ],
+            )?;
+        }
+        EventKind::Released { .. } | EventKind::AutoReleased { .. } => settled(JobState::Released)?,
+        EventKind::Refunded { .. }
+        | EventKind::EmergencyRefunded { .. }
+        | EventKind::Reclaimed { .. }
+        | EventKind::FundsReturned { .. }
+        | EventKind::ReleaseReversed { .. } => settled(JobState::Refunded)?,
+        EventKind::DormancyAnnounced { .. }
+        | EventKind::PayoutSwapped { .. }
+        | EventKind::PayoutSwapFailed { .. }
+        | EventKind::PayoutPushed { .. }
+        | EventKind::Withheld { .. }
+        | EventKind::JobAttested { .. }
+        | EventKind::JobArbitratorSet { .. }
+        | EventKind::ArbitratorReplaced { .. }
+        | EventKind::StakeConsented { .. }
+        | EventKind::JobStaked { .. }
+        | EventKind::JobUnstaked { .. }
+        | EventKind::YieldSplitSet { .. }
+        | EventKind::SettlementHookSet { .. }
+        | EventKind::SettlementHookFailed { .. }
+        | EventKind::ReleaseConditionSet { .. }
+        | EventKind::RetainerStarted { .. }
+        | EventKind::RetainerStopped { .. }
+        | EventKind::CancellationNoticeSet { .. }
+        | EventKind::CancellationRequested { .. }
+        | EventKind::DeliverySubmitted { .. }
+        | EventKind::FundingOpened { .. }
+        | EventKind::JobFunded { .. }
+        | EventKind::ContributionReclaimed { .. }
+        | EventKind::ExcessCredited { .. }
+        | EventKind::FeeCharged { .. }
+        | EventKind::SettlementRebated { .. }
+        | EventKind::DisputeOpened { .. }
+        | EventKind::EvidenceSubmitted { .. }
+        | EventKind::DisputeTimedOut { .. }
+        | EventKind::DisputeStakeSettled { .. }
+        | EventKind::JuryDrawn { .. }
+        | EventKind::JuryVoted { .. }
+        | EventKind::JuryTallied { .. }
+        | EventKind::RulingHeld { .. }
+        | EventKind::RulingAppealed { .. }
+        | EventKind::AppealBondSettled { .. }
+        | EventKind::DualApprovalRequired
+        | EventKind::ReleaseApproved { .. }
+        | EventKind::ClientTransferProposed { .. }
+        | EventKind::JobAccepted { .. }
+        | EventKind::FreelancerChanged { .. }
+        | EventKind::PlatformFeeSet { .. }
+        | EventKind::InvoiceFunded { .. }
+        | EventKind::HoldbackSet { .. }
+        | EventKind::HoldbackRetained { .. }
+        | EventKind::ClawbackWindowSet { .. }
+        | EventKind::ReleaseQueued { .. }
+        | EventKind::SlaSet { .. }
+        | EventKind::LateRefunded { .. }
+        | EventKind::HourlyRateSet { .. }
+        | EventKind::TimesheetSubmitted { .. }
+        | EventKind::TimesheetRejected { .. }
+        | EventKind::BasketFunded { .. }
+        | EventKind::BasketPaid { .. }
+        | EventKind::JobRated { .. } => {}
+        // The job settles with its last tranche, partial release or timesheet
+        EventKind::PartiallyReleased { remaining, .. }
+        | EventKind::TrancheClaimed { remaining, .. }
+        | EventKind::TimesheetApproved { remaining, .. }
+            if remaining.is_zero() =>
+        {
+            settled(JobState::Released)?
+        }
+        EventKind::TrancheRefunded { remaining, .. }
+        | EventKind::EmergencyRefundClaimed { remaining, .. }
+            if remaining.is_zero() =>
+        {
+            settled(JobState::Refunded)?
+        }
+        EventKind::PartiallyReleased { .. }
+        | EventKind::TrancheClaimed { .. }
+        | EventKind::TrancheRefunded { .. }
+        | EventKind::EmergencyRefundClaimed { .. }
+        | EventKind::TimesheetApproved { .. } => {}
+        EventKind::DormantSwept { .. } => settled(JobState::Swept)?,
+        EventKind::DisputeResolved {
+            freelancer_amount, ..
+        } => {
+            if freelancer_amount.is_zero() {
+                settled(JobState::Refunded)?
+            } else {
+                settled(JobState::Released)?
+            }
+        }
+        EventKind::PrivateDeposited { client, amount, .. } => {
+            conn.execute(
+                "INSERT OR REPLACE INTO jobs
+                 (job_id, client, freelancer, amount, status, created_block, settled_block)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, NULL)",
+                params![
+                    event.job_id,
+                    client.to_string(),
+                    Address::ZERO.to_string(),
+                    amount.to_string(),
+                    JobState::Active.as_str(),
+                    event.block_number,
+                ],
+            )?;
+        }
+        EventKind::JobAmended {
+            field, new_value, ..
+        } => {
+            if *field == JOB_FIELD_AMOUNT {
+                conn.execute(
+                    "UPDATE jobs SET amount = ?1 WHERE job_id = ?2",
+                    params![U256::from_be_bytes(new_value.0).to_string(), event.job_id],
+                )?;
+            } else if *field == JOB_FIELD_CLIENT {
+                conn.execute(
+                    "UPDATE jobs SET client = ?1 WHERE job_id = ?2",
+                    params![Address::from_word(*new_value).to_string(), event.job_id],
+                )?;
+            } else if *field == JOB_FIELD_FREELANCER {
+                conn.execute(
+                    "UPDATE jobs SET freelancer = ?1 WHERE job_id = ?2",
+                    params![Address::from_word(*new_value).to_string(), event.job_id],
+                )?;
+            }
+        }
+        EventKind::JobRevealed {
+            freelancer, amount, ..
+        } => {
+            conn.execute(
+                "UPDATE jobs SET freelancer = ?1, amount = ?2 WHERE job_id = ?3",
+                params![freelancer.to_string(), amount.to_string(), event.job_id],
+            )?;
+        }
+    }
+    Ok(())
+}
+
+fn query_events(conn: &Connection, job_id: u64) -> Result<Vec<IndexedEvent>> {
+    let mut stmt = conn.prepare(
+        "SELECT block_number, log_index, block_hash, tx_hash, kind, client, freelancer, amount, memo
+         FROM events WHERE job_id = ?1 ORDER BY block_number, log_index",
+    )?;
+    let rows = stmt.query_map([job_id], |row| {
+        Ok((
+            row.get::<_, u64>(0)?,
+            row.get::<_, u64>(1)?,
+            row.get::<_, String>(2)?,
+            row.get::<_, String>(3)?,
+            row.get::<_, String>(4)?,
+            row.get::<_, Option<String>>(5)?,
+            row.get::<_, Option<String>>(6)?,
+            row.get::<_, Option<String>>(7)?,
+            row.get::<_, Option<String>>(8)?,
+        ))
+    })?;
+    rows.map(|row| {
+        let (block_number, log_index, block_hash, tx_hash, kind, client, freelancer, amount, memo) =
+            row?;
+        let address = |value: Option<String>| -> Result<Address> {
+            Ok(Address::from_str(
+                &value.ok_or_else(|| eyre!("missing address"))?,
+            )?)
+        };
+        let amount = || -> Result<U256> {
+            Ok(U256::from_str(
+                amount.as_deref().ok_or_else(|| eyre!("missing amount"))?,
+            )?)
+        };
+        let memo = || -> Result<B256> {
+            Ok(B256::from_str(
+                memo.as_deref().ok_or_else(|| eyre!("missing memo"))?,
+            )?)
+        };
+        let kind = match kind.as_str() {
+            "Deposited" => EventKind::Deposited {
+                client: address(client)?,
+                freelancer: address(freelancer)?,
+                amount: amount()?,
+                memo: memo()?,
+            },
+            "Released" => EventKind::Released {
+                amount: amount()?,
+                memo: memo()?,
+            },
+            "Refunded" => EventKind::Refunded {
+                amount: amount()?,
+                memo: memo()?,
+            },
+            "AutoReleased" => EventKind::AutoReleased {
+                amount: amount()?,
+                memo: memo()?,
+            },
+            "EmergencyRefunded" => EventKind::EmergencyRefunded {
+                admin: address(client)?,
+            },
+            "FundsReturned" => EventKind::FundsReturned {
+                amount: amount()?,
+                memo: memo()?,
+            },
+            "Reclaimed" => EventKind::Reclaimed {
+                caller: address(client)?,
+                amount: amount()?,
+                memo: memo()?,
+            },
+            "DormancyAnnounced" => EventKind::DormancyAnnounced {
+                sweep_after: amount()?.try_into()?,
+            },
+            "DormantSwept" => EventKind::DormantSwept {
+                recovery: address(client)?,
+                amount: amount()?,
+            },
+            "PrivateDeposited" => EventKind::PrivateDeposited {
+                client: address(client)?,
+                amount: amount()?,
+                commitment: memo()?,
+            },
+            "PayoutSwapped" => EventKind::PayoutSwapped {
+                token: address(client)?,
+                amount_out: amount()?,
+            },
+            "PayoutSwapFailed" => EventKind::PayoutSwapFailed {
+                token: address(client)?,
+            },
+            "PayoutPushed" => EventKind::PayoutPushed {
+                recipient: address(client)?,
+                amount: amount()?,
+                pushed: memo()? != B256::ZERO,
+            },
+            "Withheld" => EventKind::Withheld {
+                destination: address(client)?,
+                withheld: amount()?,
+            },
+            "JobAttested" => EventKind::JobAttested { uid: memo()? },
+            "JobArbitratorSet" => EventKind::JobArbitratorSet {
+                arbitrator: address(client)?,
+            },
+            "ArbitratorReplaced" => EventKind::ArbitratorReplaced {
+                arbitrator: address(client)?,
+                rule_by: amount()?.try_into()?,
+            },
+            "StakeConsented" => EventKind::StakeConsented {
+                party: address(client)?,
+                freelancer_yield_bps: amount()?.try_into()?,
+                treasury_yield_bps: U256::from_be_bytes(memo()?.0).try_into()?,
+            },
+            "JobStaked" => EventKind::JobStaked {
+                token: address(client)?,
+                amount: amount()?,
+                shares: memo()?.into(),
+            },
+            "JobUnstaked" => EventKind::JobUnstaked {
+                buyer: address(client)?,
+                paid: amount()?,
+                principal: memo()?.into(),
+            },
+            "YieldSplitSet" => {
+                let freelancer_yield_bps: u16 = amount()?.try_into()?;
+                let treasury_yield_bps: u16 = U256::from_be_bytes(memo()?.0).try_into()?;
+                EventKind::YieldSplitSet {
+                    client_yield_bps: BASIS_POINTS - freelancer_yield_bps - treasury_yield_bps,
+                    freelancer_yield_bps,
+                    treasury_yield_bps,
+                }
+            }
+            "SettlementHookSet" => EventKind::SettlementHookSet {
+                hook: address(client)?,
+            },
+            "SettlementHookFailed" => EventKind::SettlementHookFailed {
+                hook: address(client)?,
+            },
+            "ReleaseConditionSet" => EventKind::ReleaseConditionSet {
+                condition: address(client)?,
+            },
+            "PartiallyReleased" => EventKind::PartiallyReleased {
+                amount: amount()?,
+                remaining: memo()?.into(),
+            },
+            "TrancheClaimed" => EventKind::TrancheClaimed {
+                amount: amount()?,
+                remaining: memo()?.into(),
+            },
+            "HourlyRateSet" => EventKind::HourlyRateSet { rate: amount()? },
+            "TimesheetSubmitted" => EventKind::TimesheetSubmitted {
+                amount: amount()?,
+                details: memo()?,
+            },
+            "TimesheetApproved" => EventKind::TimesheetApproved {
+                amount: amount()?,
+                remaining: memo()?.into(),
+            },
+            "TimesheetRejected" => EventKind::TimesheetRejected { index: amount()? },
+            "BasketFunded" => EventKind::BasketFunded {
+                token: address(client)?,
+                amount: amount()?,
+            },
+            "BasketPaid" => EventKind::BasketPaid {
+                token: address(client)?,
+                recipient: address(freelancer)?,
+                amount: amount()?,
+            },
+            "EmergencyRefundClaimed" => EventKind::EmergencyRefundClaimed {
+                client: address(client)?,
+                amount: amount()?,
+                remaining: memo()?.into(),
+            },
+            "JobRated" => EventKind::JobRated {
+                rater: address(client)?,
+                ratee: address(freelancer)?,
+                stars: amount()?.try_into()?,
+            },
+            "TrancheRefunded" => EventKind::TrancheRefunded {
+                amount: amount()?,
+                remaining: memo()?.into(),
+            },
+            "RetainerStarted" => EventKind::RetainerStarted {
+                period: amount()?.try_into()?,
+            },
+            "RetainerStopped" => EventKind::RetainerStopped {
+                refunded: amount()?,
+            },
+            "CancellationNoticeSet" => EventKind::CancellationNoticeSet {
+                notice: amount()?.try_into()?,
+            },
+            "CancellationRequested" => EventKind::CancellationRequested {
+                effective_at: amount()?.try_into()?,
+            },
+            "DeliverySubmitted" => EventKind::DeliverySubmitted { delivery: memo()? },
+            "FundingOpened" => EventKind::FundingOpened {
+                client: address(client)?,
+                freelancer: address(freelancer)?,
+                target: amount()?,
+            },
+            "JobFunded" => EventKind::JobFunded {
+                contributor: address(client)?,
+                amount: amount()?,
+            },
+            "ContributionReclaimed" => EventKind::ContributionReclaimed {
+                contributor: address(client)?,
+                amount: amount()?,
+            },
+            "ExcessCredited" => EventKind::ExcessCredited {
+                client: address(client)?,
+                excess: amount()?,
+            },
+            "FeeCharged" => EventKind::FeeCharged {
+                recipient: address(client)?,
+                fee: amount()?,
+            },
+            "SettlementRebated" => EventKind::SettlementRebated {
+                caller: address(client)?,
+                amount: amount()?,
+            },
+            "DisputeOpened" => EventKind::DisputeOpened {
+                opened_by: address(client)?,
+                memo: memo()?,
+            },
+            "DisputeStakeSettled" => EventKind::DisputeStakeSettled {
+                recipient: address(client)?,
+                amount: amount()?,
+                forfeited: memo()? != B256::ZERO,
+            },
+            "JuryDrawn" => EventKind::JuryDrawn {
+                vote_by: amount()?.try_into()?,
+            },
+            "JuryVoted" => EventKind::JuryVoted {
+                juror: address(client)?,
+                freelancer_bps: amount()?.try_into()?,
+            },
+            "JuryTallied" => EventKind::JuryTallied {
+                freelancer_bps: U256::from_be_bytes(memo()?.0).try_into()?,
+                slashed: amount()?,
+            },
+            "RulingHeld" => EventKind::RulingHeld {
+                resolver: address(client)?,
+                freelancer_bps: U256::from_be_bytes(memo()?.0).try_into()?,
+                appealable_until: amount()?.try_into()?,
+            },
+            "RulingAppealed" => EventKind::RulingAppealed {
+                appellant: address(client)?,
+                freelancer_bps: U256::from_be_bytes(memo()?.0).try_into()?,
+                bond: amount()?,
+            },
+            "AppealBondSettled" => EventKind::AppealBondSettled {
+                recipient: address(client)?,
+                amount: amount()?,
+                forfeited: memo()? != B256::ZERO,
+            },
+            "DisputeTimedOut" => EventKind::DisputeTimedOut {
+                split: !amount()?.is_zero(),
+            },
+            "JobAmended" => EventKind::JobAmended {
+                field: amount()?.try_into()?,
+                new_value: memo()?,
+                actor: address(client)?,
+            },
+            "ClientTransferProposed" => EventKind::ClientTransferProposed {
+                client: address(client)?,
+                new_client: address(freelancer)?,
+            },
+            "JobAccepted" => EventKind::JobAccepted {
+                freelancer: address(freelancer)?,
+            },
+            "FreelancerChanged" => EventKind::FreelancerChanged {
+                old_freelancer: address(client)?,
+                new_freelancer: address(freelancer)?,
+            },
+            "PlatformFeeSet" => EventKind::PlatformFeeSet {
+                platform: address(client)?,
+                fee_bps: amount()?.try_into()?,
+            },
+            "InvoiceFunded" => EventKind::InvoiceFunded {
+                invoice_id: amount()?,
+            },
+            "HoldbackSet" => EventKind::HoldbackSet {
+                holdback_bps: amount()?.try_into()?,
+                warranty: U256::from_be_bytes(memo()?.0).try_into()?,
+            },
+            "ClawbackWindowSet" => EventKind::ClawbackWindowSet {
+                window: amount()?.try_into()?,
+            },
+            "ReleaseQueued" => EventKind::ReleaseQueued {
+                amount: amount()?,
+                payable_at: U256::from_be_bytes(memo()?.0).try_into()?,
+            },
+            "ReleaseReversed" => EventKind::ReleaseReversed {
+                arbitrator: address(client)?,
+                amount: amount()?,
+            },
+            "SlaSet" => EventKind::SlaSet {
+                delivery_by: U256::from_be_bytes(memo()?.0).try_into()?,
+                late_bps_per_day: amount()?.try_into()?,
+            },
+            "LateRefunded" => EventKind::LateRefunded {
+                days_late: U256::from_be_bytes(memo()?.0).try_into()?,
+                amount: amount()?,
+            },
+            "HoldbackRetained" => EventKind::HoldbackRetained {
+                amount: amount()?,
+                release_at: U256::from_be_bytes(memo()?.0).try_into()?,
+            },
+            "DualApprovalRequired" => EventKind::DualApprovalRequired,
+            "ReleaseApproved" => EventKind::ReleaseApproved {
+                approver: address(client)?,
+            },
+            "DisputeResolved" => EventKind::DisputeResolved {
+                resolver: address(client)?,
+                freelancer_amount: amount()?,
+                client_amount: memo()?.into(),
+            },
+            "EvidenceSubmitted" => EventKind::EvidenceSubmitted {
+                submitter: address(client)?,
+                index: amount()?,
+                evidence_hash: memo()?,
+            },
+            "JobRevealed" => EventKind::JobRevealed {
+                freelancer: address(freelancer)?,
+                amount: amount()?,
+                terms: memo()?,
+            },
+            other => return Err(eyre!("unknown event kind {other}")),
+        };
+        Ok(IndexedEvent {
+            block_number,
+            block_hash: B256::from_str(&block_hash)?,
+            log_index,
+            tx_hash: B256::from_str(&tx_hash)?,
+            job_id,
+            kind,
+        })
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(block_number: u64, job_id: u64, kind: EventKind) -> IndexedEvent {
+        IndexedEvent {
+            block_number,
+            block_hash: B256::with_last_byte(block_number as u8),
+            log_index: 0,
+            tx_hash: B256::with_last_byte(0xaa),
+            job_id,
+            kind,
+        }
+    }
+
+    #[test]
+    fn test_apply_and_query() {
+        let mut store = Store::in_memory().unwrap();
+        let client = Address::from([0x01; 20]);
+        let freelancer = Address::from([0x02; 20]);
+        let amount = U256::from(1_000_000_000_000_000_000_u64); // 1 ETH
+
+        let deposit = event(
+            10,
+            1,
+            EventKind::Deposited {
+                client,
+                freelancer,
+                amount,
+                memo: B256::ZERO,
+            },
+        );
+        store
+            .commit_batch(&[deposit.clone()], 10, deposit.block_hash)
+            .unwrap();
+        let job = store.job(1).unwrap().unwrap();
+        assert_eq!(job.client, client);
+        assert_eq!(job.freelancer, freelancer);
+        assert_eq!(job.amount, amount);
+        assert_eq!(job.state, JobState::Active);
+        assert_eq!(store.last_block().unwrap(), Some((10, deposit.block_hash)));
+
+        let release = event(
+            11,
+            1,
+            EventKind::Released {
+                amount,
+                memo: B256::right_padding_from(b"INV-0042"),
+            },
+        );
+        store
+            .commit_batch(&[release.clone()], 12, B256::with_last_byte(12))
+            .unwrap();
+        let job = store.job(1).unwrap().unwrap();
+        assert_eq!(job.state, JobState::Released);
+        assert_eq!(job.settled_block, Some(11));
+
+        assert_eq!(store.jobs_by_client(client).unwrap().len(), 1);
+        assert_eq!(store.jobs_by_freelancer(freelancer).unwrap().len(), 1);
+        assert!(store.jobs_with_state(JobState::Active).unwrap().is_empty());
+        assert_eq!(store.job_events(1).unwrap(), vec![deposit, release]);
+        assert_eq!(store.job(2).unwrap(), None);
+    }
+
+    #[test]
+    fn test_rollback_replays_remaining_events() {
+        let mut store = Store::in_memory().unwrap();
+        let client = Address::from([0x01; 20]);
+        let freelancer = Address::from([0x02; 20]);
+        let amount = U256::from(1_000);
+
+        let deposit = event(
+            10,
+            1,
+            EventKind::Deposited {
+                client,
+                freelancer,
+                amount,
+                memo: B256::ZERO,
+            },
+        );
+        let deposit2 = event(
+            11,
+            2,
+            EventKind::Deposited {
+                client,
+                freelancer,
+                amount,
+                memo: B256::ZERO,
+            },
+        );
+        let refund = event(
+            12,
+            1,
+            EventKind::Refunded {
+                amount,
+                memo: B256::ZERO,
+            },
+        );
+        store
+            .commit_batch(&[deposit, deposit2, refund], 12, B256::with_last_byte(12))
+            .unwrap();
+        assert_eq!(store.job(1).unwrap().unwrap().state, JobState::Refunded);
+
+        // Block 11 and above are reorged out
+        store.rollback_to(10).unwrap();
+        let job = store.job(1).unwrap().unwrap();
+        assert_eq!(job.state, JobState::Active);
+        assert_eq!(job.settled_block, None);
+        assert_eq!(store.job(2).unwrap(), None);
+        assert_eq!(store.last_block().unwrap().unwrap().0, 10);
+        assert_eq!(store.job_events(1).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_dormant_sweep() {
+        let mut store = Store::in_memory().unwrap();
+        let recovery = Address::from([0x03; 20]);
+        let amount = U256::from(1_000);
+
+        let deposit = event(
+            10,
+            1,
+            EventKind::Deposited {
+                client: Address::from([0x01; 20]),
+                freelancer: Address::from([0x02; 20]),
+                amount,
+                memo: B256::ZERO,
+            },
+        );
+        let announce = event(
+            11,
+            1,
+            EventKind::DormancyAnnounced {
+                sweep_after: 65_750_400,
+            },
+        );
+        store
+            .commit_batch(
+                &[deposit.clone(), announce.clone()],
+                11,
+                announce.block_hash,
+            )
+            .unwrap();
+
+        // The announcement alone leaves the job active
+        assert_eq!(store.job(1).unwrap().unwrap().state, JobState::Active);
+
+        let sweep = event(12, 1, EventKind::DormantSwept { recovery, amount });
+        store
+            .commit_batch(&[sweep.clone()], 12, sweep.block_hash)
+            .unwrap();
+        let job = store.job(1).unwrap().unwrap();
+        assert_eq!(job.state, JobState::Swept);
+        assert_eq!(job.settled_block, Some(12));
+        assert_eq!(store.job_events(1).unwrap(), vec![deposit, announce, sweep]);
+    }
+
+    #[test]
+    fn test_client_transfer() {
+        let mut store = Store::in_memory().unwrap();
+        let client = Address::from([0x01; 20]);
+        let new_client = Address::from([0x03; 20]);
+
+        let deposit = event(
+            10,
+            1,
+            EventKind::Deposited {
+                client,
+                freelancer: Address::from([0x02; 20]),
+                amount: U256::from(1_000),
+                memo: B256::ZERO,
+            },
+        );
+        let offer = event(11, 1, EventKind::ClientTransferProposed { client, new_client });
+        store
+            .commit_batch(&[deposit.clone(), offer.clone()], 11, offer.block_hash)
+            .unwrap();
+        assert_eq!(store.job(1).unwrap().unwrap().client, client);
+
+        // The job changes hands once the new client accepts
+        let accept = event(
+            12,
+            1,
+            EventKind::JobAmended {
+                field: JOB_FIELD_CLIENT,
+                new_value: new_client.into_word(),
+                actor: new_client,
+            },
+        );
+        store
+            .commit_batch(&[accept.clone()], 12, accept.block_hash)
+            .unwrap();
+        assert_eq!(store.job(1).unwrap().unwrap().client, new_client);
+        assert_eq!(store.job_events(1).unwrap(), vec![deposit, offer, accept]);
+    }
+
+    #[test]
+    fn test_tranches() {
+        let mut store = Store::in_memory().unwrap();
+
+        let deposit = event(
+            10,
+            1,
+            EventKind::Deposited {
+                client: Address::from([0x01; 20]),
+                freelancer: Address::from([0x02; 20]),
+                amount: U256::from(1_000),
+                memo: B256::ZERO,
+            },
+        );
+        let claim = event(
+            11,
+            1,
+            EventKind::TrancheClaimed {
+                amount: U256::from(300),
+                remaining: U256::from(700),
+            },
+        );
+        store
+            .commit_batch(&[deposit.clone(), claim.clone()], 11, claim.block_hash)
+            .unwrap();
+
+        // The job stays active until its last tranche settles
+        assert_eq!(store.job(1).unwrap().unwrap().state, JobState::Active);
+
+        let refund = event(
+            12,
+            1,
+            EventKind::TrancheRefunded {
+                amount: U256::from(700),
+                remaining: U256::ZERO,
+            },
+        );
+        store
+            .commit_batch(&[refund.clone()], 12, refund.block_hash)
+            .unwrap();
+        let job = store.job(1).unwrap().unwrap();
+        assert_eq!(job.state, JobState::Refunded);
+        assert_eq!(job.settled_block, Some(12));
+        assert_eq!(store.job_events(1).unwrap(), vec![deposit, claim, refund]);
+    }
+
+    #[test]
+    fn test_private_job() {
+        let mut store = Store::in_memory().unwrap();
+        let client = Address::from([0x01; 20]);
+        let freelancer = Address::from([0x02; 20]);
+
+        let deposit = event(
+            10,
+            1,
+            EventKind::PrivateDeposited {
+                client,
+                amount: U256::from(1_000),
+                commitment: B256::repeat_byte(0xcc),
+            },
+        );
+        store
+            .commit_batch(&[deposit.clone()], 10, deposit.block_hash)
+            .unwrap();
+
+        // The freelancer stays unknown until the reveal
+        let job = store.job(1).unwrap().unwrap();
+        assert_eq!(job.freelancer, Address::ZERO);
+        assert_eq!(job.amount, U256::from(1_000));
+
+        let reveal = event(
+            11,
+            1,
+            EventKind::JobRevealed {
+                freelancer,
+                amount: U256::from(700),
+                terms: B256::repeat_byte(0x7e),
+            },
+        );
+        store
+            .commit_batch(&[reveal.clone()], 11, reveal.block_hash)
+            .unwrap();
+        let job = store.job(1).unwrap().unwrap();
+        assert_eq!(job.freelancer, freelancer);
+        assert_eq!(job.amount, U256::from(700));
+        assert_eq!(job.state, JobState::Active);
+        assert_eq!(store.jobs_by_freelancer(freelancer).unwrap().len(), 1);
+        assert_eq!(store.job_events(1).unwrap(), vec![deposit, reveal]);
+    }
+}
@@ -0,0 +1,665 @@
+use crate::store::{EventKind, IndexedEvent, Store};
+use alloy::eips::BlockNumberOrTag;
+use alloy::network::Ethereum;
+use alloy::primitives::{Address, B256};
+use alloy::providers::Provider;
+use alloy::rpc::types::{BlockTransactionsKind, Filter, Log};
+use escrow_types::events::{EscrowEvent, EVENT_TOPICS};
+use eyre::{eyre, Result};
+use std::time::Duration;
+
+/// Decode an escrow log, returning `None` for unrelated or malformed logs
+pub fn decode_log(log: &Log) -> Option<IndexedEvent> {
+    let (job_id, kind) = match EscrowEvent::decode(log.topics(), &log.data().data)? {
+        EscrowEvent::Deposited(e) => {
+            let kind = EventKind::Deposited {
+                client: e.client,
+                freelancer: e.freelancer,
+                amount: e.amount,
+                memo: e.memo,
+            };
+            (e.job_id, kind)
+        }
+        EscrowEvent::Released(e) => (
+            e.job_id,
+            EventKind::Released {
+                amount: e.amount,
+                memo: e.memo,
+            },
+        ),
+        EscrowEvent::Refunded(e) => (
+            e.job_id,
+            EventKind::Refunded {
+                amount: e.amount,
+                memo: e.memo,
+            },
+        ),
+        EscrowEvent::AutoReleased(e) => (
+            e.job_id,
+            EventKind::AutoReleased {
+                amount: e.amount,
+                memo: e.memo,
+            },
+        ),
+        EscrowEvent::EmergencyRefunded(e) => {
+            (e.job_id, EventKind::EmergencyRefunded { admin: e.admin })
+        }
+        EscrowEvent::FundsReturned(e) => (
+            e.job_id,
+            EventKind::FundsReturned {
+                amount: e.amount,
+                memo: e.memo,
+            },
+        ),
+        EscrowEvent::Reclaimed(e) => (
+            e.job_id,
+            EventKind::Reclaimed {
+                caller: e.caller,
+                amount: e.amount,
+                memo: e.memo,
+            },
+        ),
+        EscrowEvent::DormancyAnnounced(e) => (
+            e.job_id,
+            EventKind::DormancyAnnounced {
+                sweep_after: e.sweep_after,
+            },
+        ),
+        EscrowEvent::DormantSwept(e) => (
+            e.job_id,
+            EventKind::DormantSwept {
+                recovery: e.recovery,
+                amount: e.amount,
+            },
+        ),
+        EscrowEvent::PrivateDeposited(e) => (
+            e.job_id,
+            EventKind::PrivateDeposited {
+                client: e.client,
+                amount: e.amount,
+                commitment: e.commitment,
+            },
+        ),
+        EscrowEvent::JobRevealed(e) => (
+            e.job_id,
+            EventKind::JobRevealed {
+                freelancer: e.freelancer,
+                amount: e.amount,
+                terms: e.terms,
+            },
+        ),
+        EscrowEvent::PayoutSwapped(e) => (
+            e.job_id,
+            EventKind::PayoutSwapped {
+                token: e.token,
+                amount_out: e.amount_out,
+            },
+        ),
+        EscrowEvent::PayoutSwapFailed(e) => {
+            (e.job_id, EventKind::PayoutSwapFailed { token: e.token })
+        }
+        EscrowEvent::PayoutPushed(e) => (
+            e.job_id,
+            EventKind::PayoutPushed {
+                recipient: e.recipient,
+                amount: e.amount,
+                pushed: e.pushed,
+            },
+        ),
+        EscrowEvent::Withheld(e) => (
+            e.job_id,
+            EventKind::Withheld {
+                destination: e.destination,
+                withheld: e.withheld,
+            },
+        ),
+        EscrowEvent::JobAttested(e) => (e.job_id, EventKind::JobAttested { uid: e.uid }),
+        EscrowEvent::JobArbitratorSet(e) => (
+            e.job_id,
+            EventKind::JobArbitratorSet {
+                arbitrator: e.arbitrator,
+            },
+        ),
+        EscrowEvent::ArbitratorReplaced(e) => (
+            e.job_id,
+            EventKind::ArbitratorReplaced {
+                arbitrator: e.new_arbitrator,
+                rule_by: e.rule_by,
+            },
+        ),
+        EscrowEvent::StakeConsented(e) => (
+            e.job_id,
+            EventKind::StakeConsented {
+                party: e.party,
+                freelancer_yield_bps: e.freelancer_yield_bps,
+                treasury_yield_bps: e.treasury_yield_bps,
+            },
+        ),
+        EscrowEvent::JobStaked(e) => (
+            e.job_id,
+            EventKind::JobStaked {
+                token: e.token,
+                amount: e.amount,
+                shares: e.shares,
+            },
+        ),
+        EscrowEvent::JobUnstaked(e) => (
+            e.job_id,
+            EventKind::JobUnstaked {
+                buyer: e.buyer,
+                paid: e.paid,
+                principal: e.principal,
+            },
+        ),
+        EscrowEvent::YieldSplitSet(e) => (
+            e.job_id,
+            EventKind::YieldSplitSet {
+                client_yield_bps: e.client_yield_bps,
+                freelancer_yield_bps: e.freelancer_yield_bps,
+                treasury_yield_bps: e.treasury_yield_bps,
+            },
+        ),
+        EscrowEvent::SettlementHookSet(e) => {
+            (e.job_id, EventKind::SettlementHookSet { hook: e.hook })
+        }
+        EscrowEvent::SettlementHookFailed(e) => {
+            (e.job_id, EventKind::SettlementHookFailed { hook: e.hook })
+        }
+        EscrowEvent::ReleaseConditionSet(e) => (
+            e.job_id,
+            EventKind::ReleaseConditionSet {
+                condition: e.condition,
+            },
+        ),
+        EscrowEvent::PartiallyReleased(e) => (
+            e.job_id,
+            EventKind::PartiallyReleased {
+                amount: e.amount,
+                remaining: e.remaining,
+            },
+        ),
+        EscrowEvent::TrancheClaimed(e) => (
+            e.job_id,
+            EventKind::TrancheClaimed {
+                amount: e.amount,
+                remaining: e.remaining,
+            },
+        ),
+        EscrowEvent::HourlyRateSet(e) => (e.job_id, EventKind::HourlyRateSet { rate: e.rate }),
+        EscrowEvent::TimesheetSubmitted(e) => (
+            e.job_id,
+            EventKind::TimesheetSubmitted {
+                amount: e.amount,
+                details: e.details,
+            },
+        ),
+        EscrowEvent::TimesheetApproved(e) => (
+            e.job_id,
+            EventKind::TimesheetApproved {
+                amount: e.amount,
+                remaining: e.remaining,
+            },
+        ),
+        EscrowEvent::TimesheetRejected(e) => {
+            (e.job_id, EventKind::TimesheetRejected { index: e.index })
+        }
+        EscrowEvent::BasketFunded(e) => (
+            e.job_id,
+            EventKind::BasketFunded {
+                token: e.token,
+                amount: e.amount,
+            },
+        ),
+        EscrowEvent::BasketPaid(e) => (
+            e.job_id,
+            EventKind::BasketPaid {
+                token: e.token,
+                recipient: e.recipient,
+                amount: e.amount,
+            },
+        ),
+        EscrowEvent::EmergencyRefundClaimed(e) => (
+            e.job_id,
+            EventKind::EmergencyRefundClaimed {
+                client: e.client,
+                amount: e.amount,
+                remaining: e.remaining,
+            },
+        ),
+        EscrowEvent::JobRated(e) => (
+            e.job_id,
+            EventKind::JobRated {
+                rater: e.rater,
+                ratee: e.ratee,
+                stars: e.stars,
+            },
+        ),
+        EscrowEvent::TrancheRefunded(e) => (
+            e.job_id,
+            EventKind::TrancheRefunded {
+                amount: e.amount,
+                remaining: e.remaining,
+            },
+        ),
+        EscrowEvent::RetainerStarted(e) => {
+            (e.job_id, EventKind::RetainerStarted { period: e.period })
+        }
+        EscrowEvent::RetainerStopped(e) => (
+            e.job_id,
+            EventKind::RetainerStopped {
+                refunded: e.refunded,
+            },
+        ),
+        EscrowEvent::CancellationNoticeSet(e) => (
+            e.job_id,
+            EventKind::CancellationNoticeSet { notice: e.notice },
+        ),
+        EscrowEvent::CancellationRequested(e) => (
+            e.job_id,
+            EventKind::CancellationRequested {
+                effective_at: e.effective_at,
+            },
+        ),
+        EscrowEvent::DeliverySubmitted(e) => (
+            e.job_id,
+            EventKind::DeliverySubmitted {
+                delivery: e.delivery,
+            },
+        ),
+        EscrowEvent::FundingOpened(e) => (
+            e.job_id,
+            EventKind::FundingOpened {
+                client: e.client,
+                freelancer: e.freelancer,
+                target: e.target,
+            },
+        ),
+        EscrowEvent::JobFunded(e) => (
+            e.job_id,
+            EventKind::JobFunded {
+                contributor: e.contributor,
+                amount: e.amount,
+            },
+        ),
+        EscrowEvent::ContributionReclaimed(e) => (
+            e.job_id,
+            EventKind::ContributionReclaimed {
+                contributor: e.contributor,
+                amount: e.amount,
+            },
+        ),
+        EscrowEvent::ExcessCredited(e) => (
+            e.job_id,
+            EventKind::ExcessCredited {
+                client: e.client,
+                excess: e.excess,
+            },
+        ),
+        EscrowEvent::SettlementRebated(e) => (
+            e.job_id,
+            EventKind::SettlementRebated {
+                caller: e.caller,
+                amount: e.amount,
+            },
+        ),
+        EscrowEvent::FeeCharged(e) => (
+            e.job_id,
+            EventKind::FeeCharged {
+                recipient: e.recipient,
+                fee: e.fee,
+            },
+        ),
+        EscrowEvent::DisputeOpened(e) => (
+            e.job_id,
+            EventKind::DisputeOpened {
+                opened_by: e.opened_by,
+                memo: e.memo,
+            },
+        ),
+        EscrowEvent::JobAmended(e) => (
+            e.job_id,
+            EventKind::JobAmended {
+                field: e.field,
+                new_value: e.new_value,
+                actor: e.actor,
+            },
+        ),
+        EscrowEvent::ClientTransferProposed(e) => (
+            e.job_id,
+            EventKind::ClientTransferProposed {
+                client: e.client,
+                new_client: e.new_client,
+            },
+        ),
+        EscrowEvent::JobAccepted(e) => (
+            e.job_id,
+            EventKind::JobAccepted {
+                freelancer: e.freelancer,
+            },
+        ),
+        EscrowEvent::FreelancerChanged(e) => (
+            e.job_id,
+            EventKind::FreelancerChanged {
+                old_freelancer: e.old_freelancer,
+                new_freelancer: e.new_freelancer,
+            },
+        ),
+        EscrowEvent::PlatformFeeSet(e) => (
+            e.job_id,
+            EventKind::PlatformFeeSet {
+                platform: e.platform,
+                fee_bps: e.fee_bps,
+            },
+        ),
+        EscrowEvent::InvoiceFunded(e) => (
+            e.job_id,
+            EventKind::InvoiceFunded {
+                invoice_id: e.invoice_id,
+            },
+        ),
+        EscrowEvent::HoldbackSet(e) => (
+            e.job_id,
+            EventKind::HoldbackSet {
+                holdback_bps: e.holdback_bps,
+                warranty: e.warranty,
+            },
+        ),
+        EscrowEvent::ClawbackWindowSet(e) => {
+            (e.job_id, EventKind::ClawbackWindowSet { window: e.window })
+        }
+        EscrowEvent::ReleaseQueued(e) => (
+            e.job_id,
+            EventKind::ReleaseQueued {
+                amount: e.amount,
+                payable_at: e.payable_at,
+            },
+        ),
+        EscrowEvent::ReleaseReversed(e) => (
+            e.job_id,
+            EventKind::ReleaseReversed {
+                arbitrator: e.arbitrator,
+                amount: e.amount,
+            },
+        ),
+        EscrowEvent::SlaSet(e) => (
+            e.job_id,
+            EventKind::SlaSet {
+                delivery_by: e.delivery_by,
+                late_bps_per_day: e.late_bps_per_day,
+            },
+        ),
+        EscrowEvent::LateRefunded(e) => (
+            e.job_id,
+            EventKind::LateRefunded {
+                days_late: e.days_late,
+                amount: e.amount,
+            },
+        ),
+        EscrowEvent::HoldbackRetained(e) => (
+            e.job_id,
+            EventKind::HoldbackRetained {
+                amount: e.amount,
+                release_at: e.release_at,
+            },
+        ),
+        EscrowEvent::DisputeTimedOut(e) => {
+            (e.job_id, EventKind::DisputeTimedOut { split: e.split })
+        }
+        EscrowEvent::DisputeStakeSettled(e) => (
+            e.job_id,
+            EventKind::DisputeStakeSettled {
+                recipient: e.recipient,
+                amount: e.amount,
+                forfeited: e.forfeited,
+            },
+        ),
+        EscrowEvent::JuryDrawn(e) => (e.job_id, EventKind::JuryDrawn { vote_by: e.vote_by }),
+        EscrowEvent::JuryVoted(e) => (
+            e.job_id,
+            EventKind::JuryVoted {
+                juror: e.juror,
+                freelancer_bps: e.freelancer_bps,
+            },
+        ),
+        EscrowEvent::JuryTallied(e) => (
+            e.job_id,
+            EventKind::JuryTallied {
+                freelancer_bps: e.freelancer_bps,
+                slashed: e.slashed,
+            },
+        ),
+        EscrowEvent::RulingHeld(e) => (
+            e.job_id,
+            EventKind::RulingHeld {
+                resolver: e.resolver,
+                freelancer_bps: e.freelancer_bps,
+                appealable_until: e.appealable_until,
+            },
+        ),
+        EscrowEvent::RulingAppealed(e) => (
+            e.job_id,
+            EventKind::RulingAppealed {
+                appellant: e.appellant,
+                freelancer_bps: e.freelancer_bps,
+                bond: e.bond,
+            },
+        ),
+        EscrowEvent::AppealBondSettled(e) => (
+            e.job_id,
+            EventKind::AppealBondSettled {
+                recipient: e.recipient,
+                amount: e.amount,
+                forfeited: e.forfeited,
+            },
+        ),
+        EscrowEvent::DualApprovalRequired(e) => (e.job_id, EventKind::DualApprovalRequired),
+        EscrowEvent::ReleaseApproved(e) => (
+            e.job_id,
+            EventKind::ReleaseApproved {
+                approver: e.approver,
+            },
+        ),
+        EscrowEvent::DisputeResolved(e) => (
+            e.job_id,
+            EventKind::DisputeResolved {
+                resolver: e.resolver,
+                freelancer_amount: e.freelancer_amount,
+                client_amount: e.client_amount,
+            },
+        ),
+        EscrowEvent::EvidenceSubmitted(e) => (
+            e.job_id,
+            EventKind::EvidenceSubmitted {
+                submitter: e.submitter,
+                index: e.index,
+                evidence_hash: e.evidence_hash,
+            },
+        ),
+        EscrowEvent::PauseToggled(_)
+        | EscrowEvent::OwnershipTransferred(_)
+        | EscrowEvent::SunsetBegun(_)
+        | EscrowEvent::SunsetFinalized(_)
+        | EscrowEvent::DormancyPolicySet(_)
+        | EscrowEvent::SwapRouterSet(_)
+        | EscrowEvent::PayoutTokenSet(_)
+        | EscrowEvent::WithholdingSet(_)
+        | EscrowEvent::ComplianceHookSet(_)
+        | EscrowEvent::AttestationPolicySet(_)
+        | EscrowEvent::CreditWithdrawn(_)
+        | EscrowEvent::DefaultJobCapSet(_)
+        | EscrowEvent::JobCapSet(_)
+        | EscrowEvent::ProtocolFeeSet(_)
+        | EscrowEvent::SettlementRebateSet(_)
+        | EscrowEvent::FeeManagerSet(_)
+        | EscrowEvent::FeeTiersSet(_)
+        | EscrowEvent::VolumeDiscountsSet(_)
+        | EscrowEvent::FeeExemptionSet(_)
+        | EscrowEvent::DepositLimitsSet(_)
+        | EscrowEvent::DepositLimitExemptionSet(_)
+        | EscrowEvent::ArbitrationPolicySet(_)
+        | EscrowEvent::ArbitratorListed(_)
+        | EscrowEvent::StakingTokenSet(_)
+        | EscrowEvent::DisputeTimeoutPolicySet(_)
+        | EscrowEvent::DisputeStakePolicySet(_)
+        | EscrowEvent::JuryPolicySet(_)
+        | EscrowEvent::AppealPolicySet(_)
+        | EscrowEvent::JurorStaked(_)
+        | EscrowEvent::JurorLeft(_)
+        | EscrowEvent::FeesWithdrawn(_)
+        | EscrowEvent::TreasuryProposed(_)
+        | EscrowEvent::TreasurySet(_)
+        | EscrowEvent::GuardiansSet(_)
+        | EscrowEvent::EmergencyProposed(_)
+        | EscrowEvent::EmergencyConfirmed(_)
+        | EscrowEvent::SurplusSwept(_)
+        | EscrowEvent::PauseExtended(_)
+        | EscrowEvent::PayoutAddressProposed(_)
+        | EscrowEvent::PayoutAddressSet(_)
+        | EscrowEvent::GovernanceSet(_)
+        | EscrowEvent::GovernanceProposed(_)
+        | EscrowEvent::GovernanceVoted(_)
+        | EscrowEvent::GovernanceExecuted(_)
+        | EscrowEvent::PauserSet(_)
+        | EscrowEvent::MaxPauseSet(_)
+        | EscrowEvent::RenounceProposed(_)
+        | EscrowEvent::CreditDeposited(_)
+        | EscrowEvent::OfferCancelled(_)
+        | EscrowEvent::AuthorizationCancelled(_)
+        | EscrowEvent::InvoiceCreated(_)
+        | EscrowEvent::InvoicePruned(_)
+        | EscrowEvent::BasketTokenSet(_)
+        | EscrowEvent::RefundRootSet(_)
+        | EscrowEvent::EmergencyRefundBatch(_) => return None,
+    };
+    Some(IndexedEvent {
+        block_number: log.block_number?,
+        block_hash: log.block_hash?,
+        log_index: log.log_index?,
+        tx_hash: log.transaction_hash?,
+        job_id: job_id.try_into().ok()?,
+        kind,
+    })
+}
+
+/// Polls an RPC endpoint for escrow logs and writes them to a [`Store`]
+pub struct Indexer<P> {
+    provider: P,
+    address: Address,
+    store: Store,
+    start_block: u64,
+    confirmations: u64,
+    batch_size: u64,
+}
+
+impl<P: Provider<Ethereum>> Indexer<P> {
+    /// Index the escrow at `address`, starting from `start_block` on an empty store
+    pub fn new(provider: P, address: Address, store: Store, start_block: u64) -> Self {
+        Self {
+            provider,
+            address,
+            store,
+            start_block,
+            confirmations: 0,
+            batch_size: 1_000,
+        }
+    }
+
+    /// Only index blocks at least `confirmations` deep
+    pub fn with_confirmations(mut self, confirmations: u64) -> Self {
+        self.confirmations = confirmations;
+        self
+    }
+
+    /// Maximum number of blocks fetched per `eth_getLogs` call
+    pub fn with_batch_size(mut self, batch_size: u64) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Indexed state, for queries
+    pub fn store(&self) -> &Store {
+        &self.store
+    }
+
+    /// Poll forever, sleeping `interval` once caught up
+    pub async fn run(&mut self, interval: Duration) -> Result<()> {
+        loop {
+            let head = self.sync_once().await?;
+            if head.is_none_or(|(indexed, target)| indexed >= target) {
+                tokio::time::sleep(interval).await;
+            }
+        }
+    }
+
+    /// Handle any reorg and index one batch of blocks.
+    ///
+    /// Returns `(last indexed block, target head)`, or `None` if the chain
+    /// has not yet reached the start block.
+    pub async fn sync_once(&mut self) -> Result<Option<(u64, u64)>> {
+        let head = self
+            .provider
+            .get_block_number()
+            .await?
+            .saturating_sub(self.confirmations);
+
+        let from = match self.check_reorg().await? {
+            Some(last) => last + 1,
+            None => self.start_block,
+        };
+        if from > head {
+            return Ok(from.checked_sub(1).map(|last| (last, head)));
+        }
+        let to = head.min(from + self.batch_size - 1);
+
+        let filter = Filter::new()
+            .address(self.address)
+            .event_signature(EVENT_TOPICS.to_vec())
+            .from_block(from)
+            .to_block(to);
+        let mut events: Vec<IndexedEvent> = self
+            .provider
+            .get_logs(&filter)
+            .await?
+            .iter()
+            .filter(|log| !log.removed)
+            .filter_map(decode_log)
+            .collect();
+        events.sort_by_key(|e| (e.block_number, e.log_index));
+
+        let to_hash = self.block_hash(to).await?;
+        self.store.commit_batch(&events, to, to_hash)?;
+        Ok(Some((to, head)))
+    }
+
+    /// Roll back any indexed blocks that are no longer canonical, returning
+    /// the last block that is still valid.
+    async fn check_reorg(&mut self) -> Result<Option<u64>> {
+        let Some((last, hash)) = self.store.last_block()? else {
+            return Ok(None);
+        };
+        if self.block_hash(last).await? == hash {
+            return Ok(Some(last));
+        }
+        for (number, hash) in self.store.blocks_before(last)? {
+            if self.block_hash(number).await? == hash {
+                self.store.rollback_to(number)?;
+                return Ok(Some(number));
+            }
+        }
+        // No common ancestor among indexed blocks; start over
+        self.store.rollback_to(self.start_block.saturating_sub(1))?;
+        Ok(None)
+    }
+
+    async fn block_hash(&self, number: u64) -> Result<B256> {
+        let block = self
+            .provider
+            .get_block_by_number(
+                BlockNumberOrTag::Number(number),
+                BlockTransactionsKind::Hashes,
+            )
+            .await?
+            .ok_or_else(|| eyre!("block {number} not found"))?;
+        Ok(block.header.hash)
+    }
+}
@@ -0,0 +1,218 @@
+//! Keeper that settles escrow jobs once their deadlines pass.
+//!
+//! Follows the contract with an [`Indexer`], looks up the deadline of every
+//! active job assigned to its freelancer, and submits `auto_release` as soon
+//! as the latest block is past it. The contract only lets a job's freelancer
+//! claim, so the keeper signs as (and only settles jobs for) that freelancer.
+
+use alloy::eips::BlockNumberOrTag;
+use alloy::network::Ethereum;
+use alloy::primitives::{Address, TxHash, B256, U256};
+use alloy::providers::Provider;
+use alloy::rpc::types::BlockTransactionsKind;
+use escrow_client::{ClientError, EscrowClient, IEscrow};
+use escrow_indexer::{Indexer, JobRecord, JobState, Store};
+use eyre::{eyre, Result};
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Transaction settings for claims
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GasSettings {
+    /// Fixed gas limit instead of estimating
+    pub gas_limit: Option<u64>,
+    /// Cap on the fee per gas, in wei
+    pub max_fee_per_gas: Option<u128>,
+}
+
+/// What the keeper did with a job that was due
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+    /// The claim was mined
+    Claimed { job_id: u64, tx_hash: TxHash },
+    /// Dry run: the claim would have been sent
+    WouldClaim { job_id: u64 },
+    /// The claim reverted or could not be sent
+    Failed { job_id: u64, reason: String },
+}
+
+/// Claims jobs for `freelancer` once their deadlines pass
+pub struct Keeper<P> {
+    client: EscrowClient<P>,
+    indexer: Indexer<P>,
+    freelancer: Address,
+    gas: GasSettings,
+    dry_run: bool,
+    /// Deadlines fetched so far; they never change once a job exists
+    deadlines: HashMap<u64, u64>,
+}
+
+impl<P: Provider<Ethereum> + Clone> Keeper<P> {
+    /// Watch the escrow at `address` for jobs assigned to `freelancer`,
+    /// indexing from `start_block` into `store`.
+    ///
+    /// `provider` must sign as `freelancer` unless running dry.
+    pub fn new(
+        provider: P,
+        address: Address,
+        store: Store,
+        start_block: u64,
+        freelancer: Address,
+    ) -> Self {
+        Self {
+            client: EscrowClient::new(address, provider.clone()),
+            indexer: Indexer::new(provider, address, store, start_block),
+            freelancer,
+            gas: GasSettings::default(),
+            dry_run: false,
+            deadlines: HashMap::new(),
+        }
+    }
+
+    /// Gas limit and fee cap for claims
+    pub fn with_gas(mut self, gas: GasSettings) -> Self {
+        self.gas = gas;
+        self
+    }
+
+    /// Report due jobs without sending transactions
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Poll forever, printing each action
+    pub async fn run(&mut self, interval: Duration) -> Result<()> {
+        loop {
+            for action in self.tick().await? {
+                match action {
+                    Action::Claimed { job_id, tx_hash } => {
+                        println!("Claimed job {job_id} in tx {tx_hash}")
+                    }
+                    Action::WouldClaim { job_id } => println!("Would claim job {job_id}"),
+                    Action::Failed { job_id, reason } => {
+                        eprintln!("Claim for job {job_id} failed: {reason}")
+                    }
+                }
+            }
+            tokio::time::sleep(interval).await;
+        }
+    }
+
+    /// Catch up with the chain and claim every job that is due
+    pub async fn tick(&mut self) -> Result<Vec<Action>> {
+        while let Some((indexed, head)) = self.indexer.sync_once().await? {
+            if indexed >= head {
+                break;
+            }
+        }
+
+        let jobs = self.indexer.store().jobs_by_freelancer(self.freelancer)?;
+        for job in &jobs {
+            if job.state == JobState::Active && !self.deadlines.contains_key(&job.job_id) {
+                let deadline = self.client.get_job(U256::from(job.job_id)).await?.deadline;
+                self.deadlines.insert(job.job_id, deadline);
+            }
+        }
+
+        let now = self.latest_timestamp().await?;
+        let mut actions = Vec::new();
+        for job_id in due_jobs(&jobs, &self.deadlines, now) {
+            actions.push(if self.dry_run {
+                Action::WouldClaim { job_id }
+            } else {
+                self.claim(job_id).await?
+            });
+        }
+        Ok(actions)
+    }
+
+    async fn claim(&self, job_id: u64) -> Result<Action> {
+        let contract = IEscrow::new(self.client.address(), self.client.provider());
+        let mut call = contract.autoRelease(U256::from(job_id), B256::ZERO);
+        if let Some(gas_limit) = self.gas.gas_limit {
+            call = call.gas(gas_limit);
+        }
+        if let Some(max_fee) = self.gas.max_fee_per_gas {
+            call = call.max_fee_per_gas(max_fee);
+        }
+
+        let pending = match call.send().await {
+            Ok(pending) => pending,
+            Err(err) => {
+                return match ClientError::from(err) {
+                    ClientError::Revert(reason) => Ok(Action::Failed { job_id, reason }),
+                    err => Err(err.into()),
+                }
+            }
+        };
+        let receipt = pending.get_receipt().await?;
+        Ok(if receipt.status() {
+            Action::Claimed {
+                job_id,
+                tx_hash: receipt.transaction_hash,
+            }
+        } else {
+            Action::Failed {
+                job_id,
+                reason: format!("transaction {} reverted", receipt.transaction_hash),
+            }
+        })
+    }
+
+    async fn latest_timestamp(&self) -> Result<u64> {
+        let block = self
+            .client
+            .provider()
+            .get_block_by_number(BlockNumberOrTag::Latest, BlockTransactionsKind::Hashes)
+            .await?
+            .ok_or_else(|| eyre!("latest block not found"))?;
+        Ok(block.header.timestamp)
+    }
+}
+
+/// Active jobs whose deadline is at or before `now`, mirroring `auto_release`'s check
+pub fn due_jobs(jobs: &[JobRecord], deadlines: &HashMap<u64, u64>, now: u64) -> Vec<u64> {
+    jobs.iter()
+        .filter(|job| job.state == JobState::Active)
+        .filter(|job| {
+            deadlines
+                .get(&job.job_id)
+                .is_some_and(|&deadline| now >= deadline)
+        })
+        .map(|job| job.job_id)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn job(job_id: u64, state: JobState) -> JobRecord {
+        JobRecord {
+            job_id,
+            client: Address::from([0x01; 20]),
+            freelancer: Address::from([0x02; 20]),
+            amount: U256::from(1_000),
+            state,
+            created_block: 1,
+            settled_block: None,
+        }
+    }
+
+    #[test]
+    fn test_due_jobs() {
+        let jobs = [
+            job(1, JobState::Active),
+            job(2, JobState::Active),
+            job(3, JobState::Released),
+            job(4, JobState::Active),
+        ];
+        let deadlines = HashMap::from([(1, 100), (2, 200), (3, 50)]);
+
+        // Only active jobs whose deadline has been reached; job 4's is unknown
+        assert_eq!(due_jobs(&jobs, &deadlines, 99), Vec::<u64>::new());
+        assert_eq!(due_jobs(&jobs, &deadlines, 100), vec![1]);
+        assert_eq!(due_jobs(&jobs, &deadlines, 250), vec![1, 2]);
+    }
+}
@@ -0,0 +1,125 @@
+//! Keeper that claims a freelancer's escrow jobs once their deadlines pass.
+
+use alloy::network::EthereumWallet;
+use alloy::primitives::Address;
+use alloy::providers::{Provider, ProviderBuilder};
+use alloy::signers::local::PrivateKeySigner;
+use clap::Parser;
+use escrow_indexer::Store;
+use escrow_keeper::{GasSettings, Keeper};
+use eyre::{eyre, Result};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+#[derive(Parser)]
+#[command(
+    name = "escrow-keeper",
+    about = "Claim escrow jobs automatically once their deadlines pass"
+)]
+struct Cli {
+    /// RPC endpoint
+    #[arg(long, env = "RPC_URL")]
+    rpc_url: String,
+
+    /// Escrow contract address
+    #[arg(long, env = "STYLUS_CONTRACT_ADDRESS")]
+    contract: Address,
+
+    /// File containing the freelancer's hex-encoded private key
+    #[arg(long, env = "PRIV_KEY_PATH", conflicts_with = "keystore")]
+    private_key_path: Option<PathBuf>,
+
+    /// Encrypted JSON keystore file
+    #[arg(long, env = "KEYSTORE_PATH")]
+    keystore: Option<PathBuf>,
+
+    /// Password for the keystore
+    #[arg(long, env = "KEYSTORE_PASSWORD", hide_env_values = true)]
+    keystore_password: Option<String>,
+
+    /// Freelancer to watch; defaults to the signer's address
+    #[arg(long)]
+    freelancer: Option<Address>,
+
+    /// Report due jobs without sending transactions
+    #[arg(long)]
+    dry_run: bool,
+
+    /// Fixed gas limit for claims instead of estimating
+    #[arg(long, env = "KEEPER_GAS_LIMIT")]
+    gas_limit: Option<u64>,
+
+    /// Cap on the fee per gas for claims, in wei
+    #[arg(long, env = "KEEPER_MAX_FEE_PER_GAS")]
+    max_fee_per_gas: Option<u128>,
+
+    /// SQLite database for the keeper's indexer
+    #[arg(long, env = "KEEPER_DB_PATH", default_value = "escrow-keeper.db")]
+    db_path: PathBuf,
+
+    /// First block to index
+    #[arg(long, env = "KEEPER_START_BLOCK", default_value_t = 0)]
+    start_block: u64,
+
+    /// Seconds between polls
+    #[arg(long, env = "KEEPER_POLL_SECS", default_value_t = 15)]
+    poll_secs: u64,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    dotenv::dotenv().ok();
+    let cli = Cli::parse();
+
+    let url = cli.rpc_url.parse()?;
+    let signer = load_signer(&cli)?;
+    let freelancer = match (cli.freelancer, &signer) {
+        (Some(freelancer), _) => freelancer,
+        (None, Some(signer)) => signer.address(),
+        (None, None) => return Err(eyre!("set --freelancer or configure a signer")),
+    };
+    let provider = match signer {
+        Some(signer) => ProviderBuilder::new()
+            .wallet(EthereumWallet::from(signer))
+            .on_http(url)
+            .erased(),
+        None if cli.dry_run => ProviderBuilder::new().on_http(url).erased(),
+        None => {
+            return Err(eyre!(
+                "claiming requires a signer; use --dry-run to only report"
+            ))
+        }
+    };
+
+    let store = Store::open(&cli.db_path)?;
+    let mut keeper = Keeper::new(provider, cli.contract, store, cli.start_block, freelancer)
+        .with_gas(GasSettings {
+            gas_limit: cli.gas_limit,
+            max_fee_per_gas: cli.max_fee_per_gas,
+        })
+        .with_dry_run(cli.dry_run);
+
+    println!(
+        "Watching escrow {} for jobs assigned to {freelancer}{}",
+        cli.contract,
+        if cli.dry_run { " (dry run)" } else { "" }
+    );
+    keeper.run(Duration::from_secs(cli.poll_secs)).await
+}
+
+/// Load the freelancer's key from a private key file or keystore, if configured
+fn load_signer(cli: &Cli) -> Result<Option<PrivateKeySigner>> {
+    if let Some(path) = &cli.private_key_path {
+        let key = std::fs::read_to_string(path)?;
+        return Ok(Some(PrivateKeySigner::from_str(key.trim())?));
+    }
+    if let Some(path) = &cli.keystore {
+        let password = cli
+            .keystore_password
+            .as_ref()
+            .ok_or_else(|| eyre!("--keystore requires KEYSTORE_PASSWORD"))?;
+        return Ok(Some(PrivateKeySigner::decrypt_keystore(path, password)?));
+    }
+    Ok(None)
+}
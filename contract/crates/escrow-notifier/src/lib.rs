@@ -0,0 +1,1213 @@
+//! Webhook notifier for the freelance payment escrow contract.
+//!
+//! Subscribes to the contract's logs over a websocket, turns each escrow event
+//! into a [`Notification`] and POSTs it as JSON to every configured webhook.
+//! Active jobs are tracked so a `deadline_approaching` notification can be sent
+//! once per job shortly before its deadline.
+
+mod webhook;
+mod ws;
+
+pub use webhook::Webhooks;
+pub use ws::LogSubscription;
+
+use alloy::network::Ethereum;
+use alloy::primitives::{Address, TxHash, B256, U256};
+use alloy::providers::Provider;
+use alloy::rpc::types::Log;
+use escrow_client::{
+    EscrowClient, EscrowEvent, JOB_FIELD_CLIENT, JOB_FIELD_DEADLINE, JOB_FIELD_FREELANCER,
+};
+use eyre::Result;
+use serde::{Serialize, Serializer};
+use std::collections::BTreeMap;
+
+/// JSON body posted to webhooks, tagged by `event`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum Notification {
+    JobCreated {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        client: Address,
+        freelancer: Address,
+        #[serde(serialize_with = "decimal")]
+        amount: U256,
+        deadline: u64,
+        memo: B256,
+        category: u16,
+        tag: B256,
+        tx_hash: Option<TxHash>,
+    },
+    /// A private job; its freelancer and amount are hidden until revealed
+    PrivateJobCreated {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        client: Address,
+        deadline: u64,
+        commitment: B256,
+        tx_hash: Option<TxHash>,
+    },
+    JobRevealed {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        freelancer: Address,
+        #[serde(serialize_with = "decimal")]
+        amount: U256,
+        terms: B256,
+        tx_hash: Option<TxHash>,
+    },
+    /// A payout reached the freelancer in their preferred token
+    PayoutSwapped {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        token: Address,
+        #[serde(serialize_with = "decimal")]
+        amount_in: U256,
+        #[serde(serialize_with = "decimal")]
+        amount_out: U256,
+        tx_hash: Option<TxHash>,
+    },
+    /// The swap failed and the freelancer was paid in ETH instead
+    PayoutSwapFailed {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        token: Address,
+        tx_hash: Option<TxHash>,
+    },
+    /// A payout was pushed to a contract, or credited to it for withdrawal
+    /// if `pushed` is false
+    PayoutPushed {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        recipient: Address,
+        #[serde(serialize_with = "decimal")]
+        amount: U256,
+        pushed: bool,
+        tx_hash: Option<TxHash>,
+    },
+    /// Part of a payout was withheld for tax
+    Withheld {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        destination: Address,
+        #[serde(serialize_with = "decimal")]
+        net: U256,
+        #[serde(serialize_with = "decimal")]
+        withheld: U256,
+        tx_hash: Option<TxHash>,
+    },
+    /// A freelancer proposed a new payout address, which can be applied from
+    /// `effective_at`; unexpected proposals point to a compromised key
+    PayoutAddressProposed {
+        freelancer: Address,
+        payout_address: Address,
+        effective_at: u64,
+        tx_hash: Option<TxHash>,
+    },
+    /// The client released part of a job, leaving `remaining` in escrow
+    PartiallyReleased {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        #[serde(serialize_with = "decimal")]
+        amount: U256,
+        #[serde(serialize_with = "decimal")]
+        remaining: U256,
+        tx_hash: Option<TxHash>,
+    },
+    /// The freelancer billed hours on an hourly engagement
+    TimesheetSubmitted {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        #[serde(serialize_with = "decimal")]
+        index: U256,
+        hours: u32,
+        #[serde(serialize_with = "decimal")]
+        amount: U256,
+        details: B256,
+        tx_hash: Option<TxHash>,
+    },
+    /// The client approved a timesheet, leaving `remaining` of the budget
+    TimesheetApproved {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        #[serde(serialize_with = "decimal")]
+        index: U256,
+        #[serde(serialize_with = "decimal")]
+        amount: U256,
+        #[serde(serialize_with = "decimal")]
+        remaining: U256,
+        tx_hash: Option<TxHash>,
+    },
+    /// The client rejected a timesheet
+    TimesheetRejected {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        #[serde(serialize_with = "decimal")]
+        index: U256,
+        tx_hash: Option<TxHash>,
+    },
+    /// An ERC-20 in the job's basket was paid out with the job's ETH
+    BasketPaid {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        token: Address,
+        recipient: Address,
+        #[serde(serialize_with = "decimal")]
+        amount: U256,
+        tx_hash: Option<TxHash>,
+    },
+    /// The freelancer claimed an unlocked tranche
+    TrancheClaimed {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        #[serde(serialize_with = "decimal")]
+        index: U256,
+        #[serde(serialize_with = "decimal")]
+        amount: U256,
+        #[serde(serialize_with = "decimal")]
+        remaining: U256,
+        tx_hash: Option<TxHash>,
+    },
+    /// The client took back a tranche before it unlocked
+    TrancheRefunded {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        #[serde(serialize_with = "decimal")]
+        index: U256,
+        #[serde(serialize_with = "decimal")]
+        amount: U256,
+        #[serde(serialize_with = "decimal")]
+        remaining: U256,
+        tx_hash: Option<TxHash>,
+    },
+    /// The client stopped a retainer; periods past the notice were refunded
+    RetainerStopped {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        #[serde(serialize_with = "decimal")]
+        refunded: U256,
+        tx_hash: Option<TxHash>,
+    },
+    /// The client asked to cancel; the job can be refunded from `effective_at`
+    /// unless the freelancer delivers first
+    CancellationRequested {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        effective_at: u64,
+        tx_hash: Option<TxHash>,
+    },
+    /// The freelancer delivered, withdrawing the pending cancellation
+    DeliverySubmitted {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        delivery: B256,
+        tx_hash: Option<TxHash>,
+    },
+    /// One of a job's terms changed; `field` is one of the `JOB_FIELD_*` IDs
+    JobAmended {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        field: u8,
+        old_value: B256,
+        new_value: B256,
+        actor: Address,
+        tx_hash: Option<TxHash>,
+    },
+    /// A dispute ran past its timeout; a split is followed by
+    /// `dispute_resolved`, otherwise the job is back on its deadline
+    DisputeTimedOut {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        split: bool,
+        tx_hash: Option<TxHash>,
+    },
+    /// A dispute's stake went back to whoever opened it, or to the other
+    /// party if `forfeited`
+    DisputeStakeSettled {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        recipient: Address,
+        #[serde(serialize_with = "decimal")]
+        amount: U256,
+        forfeited: bool,
+        tx_hash: Option<TxHash>,
+    },
+    /// A dispute was escalated to `panel`, which votes until `vote_by`
+    JuryDrawn {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        panel: Vec<Address>,
+        vote_by: u64,
+        tx_hash: Option<TxHash>,
+    },
+    /// A jury's votes were counted; `decided` if a majority agreed on
+    /// `freelancer_bps`, otherwise the default split applies. Followed by
+    /// `dispute_resolved` while the dispute is open
+    JuryTallied {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        decided: bool,
+        freelancer_bps: u16,
+        tx_hash: Option<TxHash>,
+    },
+    /// A dispute was ruled `freelancer_bps`, and either party can appeal the
+    /// ruling to governance until `appealable_until`
+    RulingHeld {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        resolver: Address,
+        freelancer_bps: u16,
+        appealable_until: u64,
+        tx_hash: Option<TxHash>,
+    },
+    /// A party appealed a held ruling, asking for `freelancer_bps`; the
+    /// governance proposal `proposal_id` decides it
+    RulingAppealed {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        appellant: Address,
+        freelancer_bps: u16,
+        #[serde(serialize_with = "decimal")]
+        proposal_id: U256,
+        tx_hash: Option<TxHash>,
+    },
+    /// An appeal bond went back to the appellant, or to the other party if
+    /// `forfeited`
+    AppealBondSettled {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        recipient: Address,
+        #[serde(serialize_with = "decimal")]
+        amount: U256,
+        forfeited: bool,
+        tx_hash: Option<TxHash>,
+    },
+    /// A party approved releasing a job that needs both parties' approval
+    ReleaseApproved {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        approver: Address,
+        tx_hash: Option<TxHash>,
+    },
+    /// A party consented to staking the job; the other party's consent to
+    /// the same split stakes it
+    StakeConsented {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        party: Address,
+        freelancer_yield_bps: u16,
+        treasury_yield_bps: u16,
+        tx_hash: Option<TxHash>,
+    },
+    /// The job's staked shares were bought back, crediting any yield to the
+    /// parties and the treasury
+    JobUnstaked {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        buyer: Address,
+        #[serde(serialize_with = "decimal")]
+        paid: U256,
+        #[serde(serialize_with = "decimal")]
+        client_yield: U256,
+        #[serde(serialize_with = "decimal")]
+        freelancer_yield: U256,
+        #[serde(serialize_with = "decimal")]
+        treasury_yield: U256,
+        tx_hash: Option<TxHash>,
+    },
+    /// A party opened a dispute over the job
+    Disputed {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        opened_by: Address,
+        memo: B256,
+        tx_hash: Option<TxHash>,
+    },
+    /// A party added evidence to the job's dispute
+    EvidenceSubmitted {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        submitter: Address,
+        #[serde(serialize_with = "decimal")]
+        index: U256,
+        evidence_hash: B256,
+        tx_hash: Option<TxHash>,
+    },
+    /// The dispute was settled by the arbitrator, or at its default split
+    /// after the ruling period
+    DisputeResolved {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        resolver: Address,
+        #[serde(serialize_with = "decimal")]
+        freelancer_amount: U256,
+        #[serde(serialize_with = "decimal")]
+        client_amount: U256,
+        by_default: bool,
+        tx_hash: Option<TxHash>,
+    },
+    /// A party rated the other after the job settled
+    JobRated {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        rater: Address,
+        ratee: Address,
+        stars: u8,
+        tx_hash: Option<TxHash>,
+    },
+    /// Someone paid towards a job's funding target
+    JobFunded {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        contributor: Address,
+        #[serde(serialize_with = "decimal")]
+        amount: U256,
+        #[serde(serialize_with = "decimal")]
+        raised: U256,
+        tx_hash: Option<TxHash>,
+    },
+    /// A contributor took back their payment after the job missed its cutoff
+    ContributionReclaimed {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        contributor: Address,
+        #[serde(serialize_with = "decimal")]
+        amount: U256,
+        tx_hash: Option<TxHash>,
+    },
+    /// A job's settlement hook reverted; the settlement itself went through
+    SettlementHookFailed {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        hook: Address,
+        tx_hash: Option<TxHash>,
+    },
+    DeadlineApproaching {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        client: Address,
+        freelancer: Address,
+        #[serde(serialize_with = "decimal")]
+        amount: U256,
+        deadline: u64,
+        seconds_left: u64,
+    },
+    Released {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        #[serde(serialize_with = "decimal")]
+        amount: U256,
+        memo: B256,
+        tx_hash: Option<TxHash>,
+    },
+    AutoReleased {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        #[serde(serialize_with = "decimal")]
+        amount: U256,
+        memo: B256,
+        tx_hash: Option<TxHash>,
+    },
+    Refunded {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        #[serde(serialize_with = "decimal")]
+        amount: U256,
+        memo: B256,
+        tx_hash: Option<TxHash>,
+    },
+    EmergencyRefunded {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        admin: Address,
+        tx_hash: Option<TxHash>,
+    },
+    /// The client claimed an emergency refund from the published Merkle
+    /// root, leaving `remaining` escrowed
+    EmergencyRefundClaimed {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        client: Address,
+        #[serde(serialize_with = "decimal")]
+        amount: U256,
+        #[serde(serialize_with = "decimal")]
+        remaining: U256,
+        tx_hash: Option<TxHash>,
+    },
+    FundsReturned {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        #[serde(serialize_with = "decimal")]
+        amount: U256,
+        memo: B256,
+        tx_hash: Option<TxHash>,
+    },
+    Reclaimed {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        caller: Address,
+        #[serde(serialize_with = "decimal")]
+        amount: U256,
+        memo: B256,
+        tx_hash: Option<TxHash>,
+    },
+    /// A job was released into its clawback window and pays out from
+    /// `payable_at` unless the arbitrator reverses it
+    ReleaseQueued {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        #[serde(serialize_with = "decimal")]
+        amount: U256,
+        payable_at: u64,
+        tx_hash: Option<TxHash>,
+    },
+    /// The client was refunded part of a late job ahead of its release
+    LateRefunded {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        days_late: u64,
+        #[serde(serialize_with = "decimal")]
+        amount: U256,
+        tx_hash: Option<TxHash>,
+    },
+    /// The arbitrator reversed a queued release, refunding the client
+    ReleaseReversed {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        arbitrator: Address,
+        #[serde(serialize_with = "decimal")]
+        amount: U256,
+        tx_hash: Option<TxHash>,
+    },
+    DormancyAnnounced {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        sweep_after: u64,
+        tx_hash: Option<TxHash>,
+    },
+    DormantSwept {
+        #[serde(serialize_with = "decimal")]
+        job_id: U256,
+        recovery: Address,
+        #[serde(serialize_with = "decimal")]
+        amount: U256,
+        tx_hash: Option<TxHash>,
+    },
+}
+
+/// Amounts and IDs as decimal strings, which JSON numbers cannot hold
+fn decimal<S: Serializer>(value: &U256, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&value.to_string())
+}
+
+/// An unsettled job being watched for its deadline
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Tracked {
+    client: Address,
+    freelancer: Address,
+    amount: U256,
+    deadline: u64,
+    warned: bool,
+}
+
+/// Active jobs and the warnings already sent for them
+#[derive(Debug, Default)]
+pub struct Deadlines {
+    jobs: BTreeMap<U256, Tracked>,
+}
+
+impl Deadlines {
+    /// Start watching a job
+    pub fn track(
+        &mut self,
+        job_id: U256,
+        client: Address,
+        freelancer: Address,
+        amount: U256,
+        deadline: u64,
+    ) {
+        self.jobs.insert(
+            job_id,
+            Tracked {
+                client,
+                freelancer,
+                amount,
+                deadline,
+                warned: false,
+            },
+        );
+    }
+
+    /// Move a watched job's deadline, warning again before the new one
+    pub fn extend(&mut self, job_id: U256, deadline: u64) {
+        if let Some(job) = self.jobs.get_mut(&job_id) {
+            job.deadline = deadline;
+            job.warned = false;
+        }
+    }
+
+    /// Address warnings for a job to its new client
+    pub fn set_client(&mut self, job_id: U256, client: Address) {
+        if let Some(job) = self.jobs.get_mut(&job_id) {
+            job.client = client;
+        }
+    }
+
+    /// Address warnings for a job to the freelancer it was reassigned to
+    pub fn set_freelancer(&mut self, job_id: U256, freelancer: Address) {
+        if let Some(job) = self.jobs.get_mut(&job_id) {
+            job.freelancer = freelancer;
+        }
+    }
+
+    /// Stop watching a settled job
+    pub fn settle(&mut self, job_id: U256) {
+        self.jobs.remove(&job_id);
+    }
+
+    /// Warnings for jobs whose deadline is less than `warn_before` seconds
+    /// after `now` but not yet passed; each job is warned once
+    pub fn due_warnings(&mut self, now: u64, warn_before: u64) -> Vec<Notification> {
+        let mut warnings = Vec::new();
+        for (job_id, job) in &mut self.jobs {
+            if job.warned || now >= job.deadline || job.deadline - now > warn_before {
+                continue;
+            }
+            job.warned = true;
+            warnings.push(Notification::DeadlineApproaching {
+                job_id: *job_id,
+                client: job.client,
+                freelancer: job.freelancer,
+                amount: job.amount,
+                deadline: job.deadline,
+                seconds_left: job.deadline - now,
+            });
+        }
+        warnings
+    }
+}
+
+/// Turns escrow logs into webhook notifications
+pub struct Notifier<P> {
+    client: EscrowClient<P>,
+    webhooks: Webhooks,
+    deadlines: Deadlines,
+    warn_before: u64,
+}
+
+impl<P: Provider<Ethereum>> Notifier<P> {
+    /// Notify `webhooks` about the escrow behind `client`, warning `warn_before`
+    /// seconds ahead of each deadline
+    pub fn new(client: EscrowClient<P>, webhooks: Webhooks, warn_before: u64) -> Self {
+        Self {
+            client,
+            webhooks,
+            deadlines: Deadlines::default(),
+            warn_before,
+        }
+    }
+
+    /// Address of the watched escrow
+    pub fn address(&self) -> Address {
+        self.client.address()
+    }
+
+    /// Track every job that is active on chain right now
+    pub async fn load_active_jobs(&mut self) -> Result<usize> {
+        let ids = self.client.get_active_jobs().await?;
+        for &job_id in &ids {
+            let job = self.client.get_job(job_id).await?;
+            self.deadlines
+                .track(job_id, job.client, job.freelancer, job.amount, job.deadline);
+        }
+        Ok(ids.len())
+    }
+
+    /// Notify about one contract log, ignoring unrelated logs and admin events
+    pub async fn handle_log(&mut self, log: &Log) -> Result<()> {
+        let Some(event) = EscrowEvent::decode(log.topics(), &log.data().data) else {
+            return Ok(());
+        };
+        if !matches!(
+            event,
+            EscrowEvent::Deposited(_)
+                | EscrowEvent::DormancyAnnounced(_)
+                | EscrowEvent::PrivateDeposited(_)
+                | EscrowEvent::JobRevealed(_)
+                | EscrowEvent::JobAttested(_)
+                | EscrowEvent::JobArbitratorSet(_)
+                | EscrowEvent::ArbitratorReplaced(_)
+                | EscrowEvent::JobStaked(_)
+                | EscrowEvent::YieldSplitSet(_)
+                | EscrowEvent::SettlementHookSet(_)
+                | EscrowEvent::ReleaseConditionSet(_)
+                | EscrowEvent::PartiallyReleased(_)
+                | EscrowEvent::HoldbackSet(_)
+                | EscrowEvent::HoldbackRetained(_)
+                | EscrowEvent::ClawbackWindowSet(_)
+                | EscrowEvent::ReleaseQueued(_)
+                | EscrowEvent::SlaSet(_)
+                | EscrowEvent::LateRefunded(_)
+                | EscrowEvent::TrancheClaimed(_)
+                | EscrowEvent::TrancheRefunded(_)
+                | EscrowEvent::HourlyRateSet(_)
+                | EscrowEvent::TimesheetSubmitted(_)
+                | EscrowEvent::TimesheetApproved(_)
+                | EscrowEvent::TimesheetRejected(_)
+                | EscrowEvent::BasketFunded(_)
+                | EscrowEvent::BasketPaid(_)
+                | EscrowEvent::EmergencyRefundClaimed(_)
+                | EscrowEvent::RetainerStarted(_)
+                | EscrowEvent::RetainerStopped(_)
+                | EscrowEvent::CancellationNoticeSet(_)
+                | EscrowEvent::CancellationRequested(_)
+                | EscrowEvent::DeliverySubmitted(_)
+                | EscrowEvent::FundingOpened(_)
+                | EscrowEvent::JobFunded(_)
+                | EscrowEvent::ContributionReclaimed(_)
+                | EscrowEvent::ExcessCredited(_)
+                | EscrowEvent::FeeCharged(_)
+                | EscrowEvent::DisputeOpened(_)
+                | EscrowEvent::EvidenceSubmitted(_)
+                | EscrowEvent::DualApprovalRequired(_)
+                | EscrowEvent::ReleaseApproved(_)
+                | EscrowEvent::StakeConsented(_)
+                | EscrowEvent::JobUnstaked(_)
+                | EscrowEvent::DisputeTimedOut(_)
+                | EscrowEvent::JobAmended(_)
+                | EscrowEvent::ClientTransferProposed(_)
+                | EscrowEvent::JobAccepted(_)
+                | EscrowEvent::FreelancerChanged(_)
+                | EscrowEvent::PlatformFeeSet(_)
+                | EscrowEvent::InvoiceFunded(_)
+                | EscrowEvent::JobRated(_)
+                | EscrowEvent::SettlementRebated(_)
+                | EscrowEvent::PayoutPushed(_)
+                | EscrowEvent::DisputeStakeSettled(_)
+                | EscrowEvent::JuryDrawn(_)
+                | EscrowEvent::JuryVoted(_)
+                | EscrowEvent::JuryTallied(_)
+                | EscrowEvent::RulingHeld(_)
+                | EscrowEvent::RulingAppealed(_)
+                | EscrowEvent::AppealBondSettled(_)
+        ) {
+            if let Some(job_id) = event.job_id() {
+                self.deadlines.settle(job_id);
+            }
+        }
+
+        let tx_hash = log.transaction_hash;
+        let notification = match event {
+            EscrowEvent::Deposited(e) => {
+                let deadline = self.client.get_job(e.job_id).await?.deadline;
+                self.deadlines
+                    .track(e.job_id, e.client, e.freelancer, e.amount, deadline);
+                Notification::JobCreated {
+                    job_id: e.job_id,
+                    client: e.client,
+                    freelancer: e.freelancer,
+                    amount: e.amount,
+                    deadline,
+                    memo: e.memo,
+                    category: e.category,
+                    tag: e.tag,
+                    tx_hash,
+                }
+            }
+            EscrowEvent::Released(e) => Notification::Released {
+                job_id: e.job_id,
+                amount: e.amount,
+                memo: e.memo,
+                tx_hash,
+            },
+            EscrowEvent::AutoReleased(e) => Notification::AutoReleased {
+                job_id: e.job_id,
+                amount: e.amount,
+                memo: e.memo,
+                tx_hash,
+            },
+            EscrowEvent::Refunded(e) => Notification::Refunded {
+                job_id: e.job_id,
+                amount: e.amount,
+                memo: e.memo,
+                tx_hash,
+            },
+            EscrowEvent::EmergencyRefunded(e) => Notification::EmergencyRefunded {
+                job_id: e.job_id,
+                admin: e.admin,
+                tx_hash,
+            },
+            // A claim of the whole amount settles the job
+            EscrowEvent::EmergencyRefundClaimed(e) => {
+                if e.remaining.is_zero() {
+                    self.deadlines.settle(e.job_id);
+                }
+                Notification::EmergencyRefundClaimed {
+                    job_id: e.job_id,
+                    client: e.client,
+                    amount: e.amount,
+                    remaining: e.remaining,
+                    tx_hash,
+                }
+            }
+            EscrowEvent::FundsReturned(e) => Notification::FundsReturned {
+                job_id: e.job_id,
+                amount: e.amount,
+                memo: e.memo,
+                tx_hash,
+            },
+            EscrowEvent::Reclaimed(e) => Notification::Reclaimed {
+                job_id: e.job_id,
+                caller: e.caller,
+                amount: e.amount,
+                memo: e.memo,
+                tx_hash,
+            },
+            EscrowEvent::ReleaseQueued(e) => Notification::ReleaseQueued {
+                job_id: e.job_id,
+                amount: e.amount,
+                payable_at: e.payable_at,
+                tx_hash,
+            },
+            EscrowEvent::LateRefunded(e) => Notification::LateRefunded {
+                job_id: e.job_id,
+                days_late: e.days_late,
+                amount: e.amount,
+                tx_hash,
+            },
+            EscrowEvent::ReleaseReversed(e) => Notification::ReleaseReversed {
+                job_id: e.job_id,
+                arbitrator: e.arbitrator,
+                amount: e.amount,
+                tx_hash,
+            },
+            EscrowEvent::DormancyAnnounced(e) => Notification::DormancyAnnounced {
+                job_id: e.job_id,
+                sweep_after: e.sweep_after,
+                tx_hash,
+            },
+            EscrowEvent::DormantSwept(e) => Notification::DormantSwept {
+                job_id: e.job_id,
+                recovery: e.recovery,
+                amount: e.amount,
+                tx_hash,
+            },
+            // Private jobs are only watched once their freelancer is known
+            EscrowEvent::PrivateDeposited(e) => Notification::PrivateJobCreated {
+                job_id: e.job_id,
+                client: e.client,
+                deadline: self.client.get_job(e.job_id).await?.deadline,
+                commitment: e.commitment,
+                tx_hash,
+            },
+            EscrowEvent::JobRevealed(e) => {
+                let job = self.client.get_job(e.job_id).await?;
+                self.deadlines
+                    .track(e.job_id, job.client, e.freelancer, e.amount, job.deadline);
+                Notification::JobRevealed {
+                    job_id: e.job_id,
+                    freelancer: e.freelancer,
+                    amount: e.amount,
+                    terms: e.terms,
+                    tx_hash,
+                }
+            }
+            EscrowEvent::PayoutSwapped(e) => Notification::PayoutSwapped {
+                job_id: e.job_id,
+                token: e.token,
+                amount_in: e.amount_in,
+                amount_out: e.amount_out,
+                tx_hash,
+            },
+            EscrowEvent::PayoutSwapFailed(e) => Notification::PayoutSwapFailed {
+                job_id: e.job_id,
+                token: e.token,
+                tx_hash,
+            },
+            EscrowEvent::PayoutPushed(e) => Notification::PayoutPushed {
+                job_id: e.job_id,
+                recipient: e.recipient,
+                amount: e.amount,
+                pushed: e.pushed,
+                tx_hash,
+            },
+            EscrowEvent::Withheld(e) => Notification::Withheld {
+                job_id: e.job_id,
+                destination: e.destination,
+                net: e.net,
+                withheld: e.withheld,
+                tx_hash,
+            },
+            EscrowEvent::PayoutAddressProposed(e) => Notification::PayoutAddressProposed {
+                freelancer: e.freelancer,
+                payout_address: e.payout_address,
+                effective_at: e.effective_at,
+                tx_hash,
+            },
+            // A job released in parts is settled once nothing is left
+            EscrowEvent::PartiallyReleased(e) => {
+                if e.remaining.is_zero() {
+                    self.deadlines.settle(e.job_id);
+                }
+                Notification::PartiallyReleased {
+                    job_id: e.job_id,
+                    amount: e.amount,
+                    remaining: e.remaining,
+                    tx_hash,
+                }
+            }
+            EscrowEvent::TimesheetSubmitted(e) => Notification::TimesheetSubmitted {
+                job_id: e.job_id,
+                index: e.index,
+                hours: e.hours,
+                amount: e.amount,
+                details: e.details,
+                tx_hash,
+            },
+            // An hourly engagement is settled once its budget is spent
+            EscrowEvent::TimesheetApproved(e) => {
+                if e.remaining.is_zero() {
+                    self.deadlines.settle(e.job_id);
+                }
+                Notification::TimesheetApproved {
+                    job_id: e.job_id,
+                    index: e.index,
+                    amount: e.amount,
+                    remaining: e.remaining,
+                    tx_hash,
+                }
+            }
+            EscrowEvent::TimesheetRejected(e) => Notification::TimesheetRejected {
+                job_id: e.job_id,
+                index: e.index,
+                tx_hash,
+            },
+            EscrowEvent::BasketPaid(e) => Notification::BasketPaid {
+                job_id: e.job_id,
+                token: e.token,
+                recipient: e.recipient,
+                amount: e.amount,
+                tx_hash,
+            },
+            // A tranched job is settled once its last tranche is
+            EscrowEvent::TrancheClaimed(e) => {
+                if e.remaining.is_zero() {
+                    self.deadlines.settle(e.job_id);
+                }
+                Notification::TrancheClaimed {
+                    job_id: e.job_id,
+                    index: e.index,
+                    amount: e.amount,
+                    remaining: e.remaining,
+                    tx_hash,
+                }
+            }
+            EscrowEvent::TrancheRefunded(e) => {
+                if e.remaining.is_zero() {
+                    self.deadlines.settle(e.job_id);
+                }
+                Notification::TrancheRefunded {
+                    job_id: e.job_id,
+                    index: e.index,
+                    amount: e.amount,
+                    remaining: e.remaining,
+                    tx_hash,
+                }
+            }
+            EscrowEvent::RetainerStopped(e) => Notification::RetainerStopped {
+                job_id: e.job_id,
+                refunded: e.refunded,
+                tx_hash,
+            },
+            EscrowEvent::CancellationRequested(e) => Notification::CancellationRequested {
+                job_id: e.job_id,
+                effective_at: e.effective_at,
+                tx_hash,
+            },
+            EscrowEvent::DeliverySubmitted(e) => Notification::DeliverySubmitted {
+                job_id: e.job_id,
+                delivery: e.delivery,
+                tx_hash,
+            },
+            EscrowEvent::JobAmended(e) => {
+                if e.field == JOB_FIELD_DEADLINE {
+                    self.deadlines
+                        .extend(e.job_id, U256::from_be_bytes(e.new_value.0).to());
+                } else if e.field == JOB_FIELD_CLIENT {
+                    self.deadlines
+                        .set_client(e.job_id, Address::from_word(e.new_value));
+                } else if e.field == JOB_FIELD_FREELANCER {
+                    self.deadlines
+                        .set_freelancer(e.job_id, Address::from_word(e.new_value));
+                }
+                Notification::JobAmended {
+                    job_id: e.job_id,
+                    field: e.field,
+                    old_value: e.old_value,
+                    new_value: e.new_value,
+                    actor: e.actor,
+                    tx_hash,
+                }
+            }
+            EscrowEvent::DisputeTimedOut(e) => Notification::DisputeTimedOut {
+                job_id: e.job_id,
+                split: e.split,
+                tx_hash,
+            },
+            EscrowEvent::DisputeStakeSettled(e) => Notification::DisputeStakeSettled {
+                job_id: e.job_id,
+                recipient: e.recipient,
+                amount: e.amount,
+                forfeited: e.forfeited,
+                tx_hash,
+            },
+            EscrowEvent::JuryDrawn(e) => Notification::JuryDrawn {
+                job_id: e.job_id,
+                panel: e.panel,
+                vote_by: e.vote_by,
+                tx_hash,
+            },
+            EscrowEvent::JuryTallied(e) => Notification::JuryTallied {
+                job_id: e.job_id,
+                decided: e.decided,
+                freelancer_bps: e.freelancer_bps,
+                tx_hash,
+            },
+            EscrowEvent::RulingHeld(e) => Notification::RulingHeld {
+                job_id: e.job_id,
+                resolver: e.resolver,
+                freelancer_bps: e.freelancer_bps,
+                appealable_until: e.appealable_until,
+                tx_hash,
+            },
+            EscrowEvent::RulingAppealed(e) => Notification::RulingAppealed {
+                job_id: e.job_id,
+                appellant: e.appellant,
+                freelancer_bps: e.freelancer_bps,
+                proposal_id: e.proposal_id,
+                tx_hash,
+            },
+            EscrowEvent::AppealBondSettled(e) => Notification::AppealBondSettled {
+                job_id: e.job_id,
+                recipient: e.recipient,
+                amount: e.amount,
+                forfeited: e.forfeited,
+                tx_hash,
+            },
+            EscrowEvent::ReleaseApproved(e) => Notification::ReleaseApproved {
+                job_id: e.job_id,
+                approver: e.approver,
+                tx_hash,
+            },
+            EscrowEvent::StakeConsented(e) => Notification::StakeConsented {
+                job_id: e.job_id,
+                party: e.party,
+                freelancer_yield_bps: e.freelancer_yield_bps,
+                treasury_yield_bps: e.treasury_yield_bps,
+                tx_hash,
+            },
+            EscrowEvent::JobUnstaked(e) => Notification::JobUnstaked {
+                job_id: e.job_id,
+                buyer: e.buyer,
+                paid: e.paid,
+                client_yield: e.client_yield,
+                freelancer_yield: e.freelancer_yield,
+                treasury_yield: e.treasury_yield,
+                tx_hash,
+            },
+            EscrowEvent::DisputeOpened(e) => Notification::Disputed {
+                job_id: e.job_id,
+                opened_by: e.opened_by,
+                memo: e.memo,
+                tx_hash,
+            },
+            EscrowEvent::EvidenceSubmitted(e) => Notification::EvidenceSubmitted {
+                job_id: e.job_id,
+                submitter: e.submitter,
+                index: e.index,
+                evidence_hash: e.evidence_hash,
+                tx_hash,
+            },
+            EscrowEvent::DisputeResolved(e) => Notification::DisputeResolved {
+                job_id: e.job_id,
+                resolver: e.resolver,
+                freelancer_amount: e.freelancer_amount,
+                client_amount: e.client_amount,
+                by_default: e.by_default,
+                tx_hash,
+            },
+            EscrowEvent::JobRated(e) => Notification::JobRated {
+                job_id: e.job_id,
+                rater: e.rater,
+                ratee: e.ratee,
+                stars: e.stars,
+                tx_hash,
+            },
+            EscrowEvent::JobFunded(e) => Notification::JobFunded {
+                job_id: e.job_id,
+                contributor: e.contributor,
+                amount: e.amount,
+                raised: e.raised,
+                tx_hash,
+            },
+            EscrowEvent::ContributionReclaimed(e) => Notification::ContributionReclaimed {
+                job_id: e.job_id,
+                contributor: e.contributor,
+                amount: e.amount,
+                tx_hash,
+            },
+            EscrowEvent::SettlementHookFailed(e) => Notification::SettlementHookFailed {
+                job_id: e.job_id,
+                hook: e.hook,
+                tx_hash,
+            },
+            EscrowEvent::PauseToggled(_)
+            | EscrowEvent::OwnershipTransferred(_)
+            | EscrowEvent::SunsetBegun(_)
+            | EscrowEvent::SunsetFinalized(_)
+            | EscrowEvent::DormancyPolicySet(_)
+            | EscrowEvent::SwapRouterSet(_)
+            | EscrowEvent::PayoutTokenSet(_)
+            | EscrowEvent::WithholdingSet(_)
+            | EscrowEvent::ComplianceHookSet(_)
+            | EscrowEvent::AttestationPolicySet(_)
+            | EscrowEvent::JobAttested(_)
+            | EscrowEvent::JobArbitratorSet(_)
+            | EscrowEvent::ArbitratorReplaced(_)
+            | EscrowEvent::JobStaked(_)
+            | EscrowEvent::YieldSplitSet(_)
+            | EscrowEvent::SettlementHookSet(_)
+            | EscrowEvent::ReleaseConditionSet(_)
+            | EscrowEvent::RetainerStarted(_)
+            | EscrowEvent::CancellationNoticeSet(_)
+            | EscrowEvent::DualApprovalRequired(_)
+            | EscrowEvent::FundingOpened(_)
+            | EscrowEvent::ExcessCredited(_)
+            | EscrowEvent::CreditWithdrawn(_)
+            | EscrowEvent::DefaultJobCapSet(_)
+            | EscrowEvent::JobCapSet(_)
+            | EscrowEvent::ProtocolFeeSet(_)
+            | EscrowEvent::FeeCharged(_)
+            | EscrowEvent::SettlementRebateSet(_)
+            | EscrowEvent::SettlementRebated(_)
+            | EscrowEvent::FeeManagerSet(_)
+            | EscrowEvent::FeeTiersSet(_)
+            | EscrowEvent::VolumeDiscountsSet(_)
+            | EscrowEvent::FeeExemptionSet(_)
+            | EscrowEvent::DepositLimitsSet(_)
+            | EscrowEvent::DepositLimitExemptionSet(_)
+            | EscrowEvent::ArbitrationPolicySet(_)
+            | EscrowEvent::ArbitratorListed(_)
+            | EscrowEvent::StakingTokenSet(_)
+            | EscrowEvent::DisputeTimeoutPolicySet(_)
+            | EscrowEvent::DisputeStakePolicySet(_)
+            | EscrowEvent::JuryPolicySet(_)
+            | EscrowEvent::AppealPolicySet(_)
+            | EscrowEvent::JurorStaked(_)
+            | EscrowEvent::JurorLeft(_)
+            | EscrowEvent::JuryVoted(_)
+            | EscrowEvent::FeesWithdrawn(_)
+            | EscrowEvent::TreasuryProposed(_)
+            | EscrowEvent::TreasurySet(_)
+            | EscrowEvent::GuardiansSet(_)
+            | EscrowEvent::EmergencyProposed(_)
+            | EscrowEvent::EmergencyConfirmed(_)
+            | EscrowEvent::SurplusSwept(_)
+            | EscrowEvent::PauseExtended(_)
+            | EscrowEvent::ClientTransferProposed(_)
+            | EscrowEvent::JobAccepted(_)
+            | EscrowEvent::FreelancerChanged(_)
+            | EscrowEvent::PlatformFeeSet(_)
+            | EscrowEvent::PayoutAddressSet(_)
+            | EscrowEvent::GovernanceSet(_)
+            | EscrowEvent::GovernanceProposed(_)
+            | EscrowEvent::GovernanceVoted(_)
+            | EscrowEvent::GovernanceExecuted(_)
+            | EscrowEvent::PauserSet(_)
+            | EscrowEvent::MaxPauseSet(_)
+            | EscrowEvent::RenounceProposed(_)
+            | EscrowEvent::CreditDeposited(_)
+            | EscrowEvent::OfferCancelled(_)
+            | EscrowEvent::AuthorizationCancelled(_)
+            | EscrowEvent::InvoiceCreated(_)
+            | EscrowEvent::InvoiceFunded(_)
+            | EscrowEvent::HoldbackSet(_)
+            | EscrowEvent::HoldbackRetained(_)
+            | EscrowEvent::ClawbackWindowSet(_)
+            | EscrowEvent::SlaSet(_)
+            | EscrowEvent::HourlyRateSet(_)
+            | EscrowEvent::BasketFunded(_)
+            | EscrowEvent::BasketTokenSet(_)
+            | EscrowEvent::RefundRootSet(_)
+            | EscrowEvent::EmergencyRefundBatch(_)
+            | EscrowEvent::InvoicePruned(_) => return Ok(()),
+        };
+        self.webhooks.post(&notification).await;
+        Ok(())
+    }
+
+    /// Send any deadline warnings that are due at `now`
+    pub async fn check_deadlines(&mut self, now: u64) {
+        for warning in self.deadlines.due_warnings(now, self.warn_before) {
+            self.webhooks.post(&warning).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_notification_json() {
+        let notification = Notification::Released {
+            job_id: U256::from(3),
+            amount: U256::from(1_000_000_000_000_000_000_u64),
+            memo: B256::ZERO,
+            tx_hash: None,
+        };
+        assert_eq!(
+            serde_json::to_value(&notification).unwrap(),
+            serde_json::json!({
+                "event": "released",
+                "job_id": "3",
+                "amount": "1000000000000000000",
+                "memo": B256::ZERO,
+                "tx_hash": null,
+            })
+        );
+    }
+
+    #[test]
+    fn test_deadline_warnings() {
+        let mut deadlines = Deadlines::default();
+        let client = Address::from([0x01; 20]);
+        let freelancer = Address::from([0x02; 20]);
+        deadlines.track(U256::from(1), client, freelancer, U256::from(10), 1_000);
+        deadlines.track(U256::from(2), client, freelancer, U256::from(20), 5_000);
+        deadlines.track(U256::from(3), client, freelancer, U256::from(30), 1_200);
+        deadlines.settle(U256::from(3));
+
+        // Nothing is within 15 minutes of its deadline yet
+        assert!(deadlines.due_warnings(0, 900).is_empty());
+
+        // Job 1 is warned once; settled job 3 never is
+        let warnings = deadlines.due_warnings(400, 900);
+        assert_eq!(
+            warnings,
+            vec![Notification::DeadlineApproaching {
+                job_id: U256::from(1),
+                client,
+                freelancer,
+                amount: U256::from(10),
+                deadline: 1_000,
+                seconds_left: 600,
+            }]
+        );
+        assert!(deadlines.due_warnings(500, 900).is_empty());
+
+        // Jobs already past their deadline are not warned
+        assert!(deadlines.due_warnings(6_000, 900).is_empty());
+    }
+}
@@ -0,0 +1,69 @@
+use alloy::primitives::Address;
+use alloy::providers::ProviderBuilder;
+use escrow_client::EscrowClient;
+use escrow_notifier::{LogSubscription, Notifier, Webhooks};
+use eyre::eyre;
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    dotenv::dotenv().ok();
+    let rpc_url = std::env::var("RPC_URL").map_err(|_| eyre!("No RPC_URL env var set"))?;
+    let ws_url = std::env::var("WS_URL").map_err(|_| eyre!("No WS_URL env var set"))?;
+    let address = std::env::var("STYLUS_CONTRACT_ADDRESS")
+        .map_err(|_| eyre!("No STYLUS_CONTRACT_ADDRESS env var set"))?;
+    let urls: Vec<String> = std::env::var("WEBHOOK_URLS")
+        .map_err(|_| eyre!("No WEBHOOK_URLS env var set"))?
+        .split(',')
+        .map(|url| url.trim().to_string())
+        .filter(|url| !url.is_empty())
+        .collect();
+    let warn_secs = env_u64("NOTIFIER_WARN_SECS", 86_400)?;
+    let check_secs = env_u64("NOTIFIER_CHECK_SECS", 60)?;
+
+    let provider = ProviderBuilder::new().on_http(rpc_url.parse()?);
+    let client = EscrowClient::new(Address::from_str(&address)?, provider);
+    let mut notifier = Notifier::new(client, Webhooks::new(urls), warn_secs);
+    let active = notifier.load_active_jobs().await?;
+    println!("Notifying for escrow {address}, tracking {active} active jobs");
+
+    let mut checks = tokio::time::interval(Duration::from_secs(check_secs));
+    loop {
+        let mut subscription = match LogSubscription::connect(&ws_url, notifier.address()).await {
+            Ok(subscription) => subscription,
+            Err(err) => {
+                eprintln!("Subscription failed: {err}; retrying");
+                tokio::time::sleep(Duration::from_secs(5)).await;
+                continue;
+            }
+        };
+        loop {
+            tokio::select! {
+                log = subscription.next() => match log {
+                    Ok(Some(log)) => notifier.handle_log(&log).await?,
+                    Ok(None) => break,
+                    Err(err) => {
+                        eprintln!("Subscription error: {err}");
+                        break;
+                    }
+                },
+                _ = checks.tick() => notifier.check_deadlines(unix_now()).await,
+            }
+        }
+        eprintln!("Websocket closed; reconnecting");
+    }
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+fn env_u64(key: &str, default: u64) -> eyre::Result<u64> {
+    match std::env::var(key) {
+        Ok(value) => Ok(value.parse()?),
+        Err(_) => Ok(default),
+    }
+}
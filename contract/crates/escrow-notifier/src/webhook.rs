@@ -0,0 +1,47 @@
+use crate::Notification;
+use std::time::Duration;
+
+/// Attempts per webhook before a notification is dropped
+const ATTEMPTS: u32 = 3;
+
+/// Webhook endpoints that every notification is POSTed to
+pub struct Webhooks {
+    http: reqwest::Client,
+    urls: Vec<String>,
+}
+
+impl Webhooks {
+    /// POST to each of `urls`
+    pub fn new(urls: Vec<String>) -> Self {
+        Self {
+            http: reqwest::Client::builder()
+                .timeout(Duration::from_secs(10))
+                .build()
+                .expect("default TLS backend is available"),
+            urls,
+        }
+    }
+
+    /// Deliver `notification` to every webhook, retrying with backoff.
+    ///
+    /// Failures are logged rather than returned so one unreachable endpoint
+    /// does not stop the others or the log subscription.
+    pub async fn post(&self, notification: &Notification) {
+        for url in &self.urls {
+            let mut delay = Duration::from_secs(1);
+            for attempt in 1..=ATTEMPTS {
+                let result = self.http.post(url).json(notification).send().await;
+                match result.and_then(|response| response.error_for_status()) {
+                    Ok(_) => break,
+                    Err(err) if attempt == ATTEMPTS => {
+                        eprintln!("Dropping notification for {url}: {err}")
+                    }
+                    Err(_) => {
+                        tokio::time::sleep(delay).await;
+                        delay *= 2;
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use alloy::primitives::Address;
+use alloy::rpc::types::Log;
+use escrow_client::EVENT_TOPICS;
+use eyre::{eyre, Result};
+use futures_util::{SinkExt, StreamExt};
+use serde_json::{json, Value};
+use tokio::net::TcpStream;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::{connect_async, MaybeTlsStream, WebSocketStream};
+
+/// `eth_subscribe("logs")` stream of escrow events over a websocket
+pub struct LogSubscription {
+    socket: WebSocketStream<MaybeTlsStream<TcpStream>>,
+    id: String,
+}
+
+impl LogSubscription {
+    /// Connect to `url` and subscribe to the escrow events emitted by `address`
+    pub async fn connect(url: &str, address: Address) -> Result<Self> {
+        let (mut socket, _) = connect_async(url).await?;
+        let request = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "eth_subscribe",
+            "params": ["logs", { "address": address, "topics": [EVENT_TOPICS.as_slice()] }],
+        });
+        socket.send(Message::Text(request.to_string())).await?;
+
+        while let Some(message) = socket.next().await {
+            let Message::Text(text) = message? else {
+                continue;
+            };
+            let response: Value = serde_json::from_str(&text)?;
+            if response["id"] != 1 {
+                continue;
+            }
+            if let Some(error) = response.get("error") {
+                return Err(eyre!("eth_subscribe failed: {error}"));
+            }
+            let id = response["result"]
+                .as_str()
+                .ok_or_else(|| eyre!("eth_subscribe returned no subscription ID"))?
+                .to_string();
+            return Ok(Self { socket, id });
+        }
+        Err(eyre!("websocket closed before subscribing"))
+    }
+
+    /// Next log, or `None` once the connection closes
+    pub async fn next(&mut self) -> Result<Option<Log>> {
+        while let Some(message) = self.socket.next().await {
+            let text = match message? {
+                Message::Text(text) => text,
+                Message::Close(_) => return Ok(None),
+                _ => continue,
+            };
+            if let Some(log) = parse_notification(&text, &self.id)? {
+                return Ok(Some(log));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// The log carried by an `eth_subscription` message for subscription `id`
+fn parse_notification(text: &str, id: &str) -> Result<Option<Log>> {
+    let message: Value = serde_json::from_str(text)?;
+    if message["method"] != "eth_subscription" || message["params"]["subscription"] != id {
+        return Ok(None);
+    }
+    Ok(Some(serde_json::from_value(
+        message["params"]["result"].clone(),
+    )?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::U256;
+    use alloy_sol_types::SolEvent;
+    use escrow_client::{EscrowEvent, Released};
+
+    #[test]
+    fn test_parse_notification() {
+        let event = Released {
+            job_id: U256::from(3),
+            amount: U256::from(1_000),
+            memo: Default::default(),
+        };
+        let data = event.encode_log_data();
+        let text = json!({
+            "jsonrpc": "2.0",
+            "method": "eth_subscription",
+            "params": {
+                "subscription": "0xabc",
+                "result": {
+                    "address": Address::ZERO,
+                    "topics": data.topics(),
+                    "data": data.data,
+                    "blockHash": null,
+                    "blockNumber": "0x10",
+                    "blockTimestamp": null,
+                    "transactionHash": null,
+                    "transactionIndex": "0x0",
+                    "logIndex": "0x0",
+                    "removed": false,
+                },
+            },
+        })
+        .to_string();
+
+        let log = parse_notification(&text, "0xabc").unwrap().unwrap();
+        assert_eq!(log.block_number, Some(16));
+        assert_eq!(
+            EscrowEvent::decode(log.topics(), &log.data().data),
+            Some(EscrowEvent::Released(event))
+        );
+
+        // Other subscriptions and responses are skipped
+        assert!(parse_notification(&text, "0xdef").unwrap().is_none());
+        assert!(parse_notification(r#"{"id":1,"result":"0xabc"}"#, "0xabc")
+            .unwrap()
+            .is_none());
+    }
+}
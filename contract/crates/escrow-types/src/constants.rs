@@ -0,0 +1,211 @@
+//! Limits, delays and the numbering of enum-like arguments and settings.
+
+use alloy_primitives::U256;
+
+/// Shortest dormancy period the admin can configure: two years past the deadline
+pub const MIN_DORMANCY_PERIOD: u64 = 2 * 365 * 86_400;
+/// Time between announcing a dormant job and being able to sweep it
+pub const DORMANCY_NOTICE_PERIOD: u64 = 30 * 86_400;
+/// Time between proposing a new treasury and it taking effect
+pub const TREASURY_DELAY: u64 = 7 * 86_400;
+/// Time between a freelancer proposing a new payout address and it taking
+/// effect
+pub const PAYOUT_ADDRESS_DELAY: u64 = 3 * 86_400;
+/// Time between the admin proposing to renounce the role and giving it up
+pub const RENOUNCE_DELAY: u64 = 7 * 86_400;
+/// Time a freelancer has to claim after the deadline before the funds can be
+/// returned to the client
+pub const CLAIM_WINDOW: u64 = 30 * 86_400;
+/// `JobAmended` field: the job's deadline
+pub const JOB_FIELD_DEADLINE: u8 = 0;
+/// `JobAmended` field: the amount held for the job
+pub const JOB_FIELD_AMOUNT: u8 = 1;
+/// `JobAmended` field: the job's metadata (memo or terms hash)
+pub const JOB_FIELD_METADATA: u8 = 2;
+/// `JobAmended` field: the address the job pays out to
+pub const JOB_FIELD_PAYOUT_ADDRESS: u8 = 3;
+/// `JobAmended` field: the job's client
+pub const JOB_FIELD_CLIENT: u8 = 4;
+/// `JobAmended` field: the job's freelancer
+pub const JOB_FIELD_FREELANCER: u8 = 5;
+/// Emergency action: refund the job given as target to its client
+pub const EMERGENCY_ACTION_REFUND: u8 = 0;
+/// Emergency action: sweep the balance beyond what the escrow owes
+pub const EMERGENCY_ACTION_SWEEP_SURPLUS: u8 = 1;
+/// Emergency action: keep the running pause until the timestamp given as target
+pub const EMERGENCY_ACTION_EXTEND_PAUSE: u8 = 2;
+/// Timesheet status: awaiting the client's approval
+pub const TIMESHEET_SUBMITTED: u8 = 0;
+/// Timesheet status: approved and paid out of the budget
+pub const TIMESHEET_APPROVED: u8 = 1;
+/// Timesheet status: rejected by the client
+pub const TIMESHEET_REJECTED: u8 = 2;
+/// Job status filter of `query_jobs`: any job
+pub const JOB_STATUS_ANY: u8 = 0;
+/// Job status: still holding funds
+pub const JOB_STATUS_ACTIVE: u8 = 1;
+/// Job status: released to the freelancer
+pub const JOB_STATUS_RELEASED: u8 = 2;
+/// Job status: refunded to the client
+pub const JOB_STATUS_REFUNDED: u8 = 3;
+/// Job status: swept as dormant
+pub const JOB_STATUS_SWEPT: u8 = 4;
+/// Most guardians the emergency quorum can have
+pub const MAX_GUARDIANS: usize = 16;
+/// Time guardians have to reach quorum on an emergency proposal
+pub const EMERGENCY_PROPOSAL_TTL: u64 = 3 * 86_400;
+/// Longest the admin can pause alone once guardians are appointed, whatever
+/// the configured maximum pause
+pub const MAX_ADMIN_PAUSE: u64 = 7 * 86_400;
+/// Age from which a job can be emergency refunded whatever its deadline
+pub const EMERGENCY_REFUND_MIN_AGE: u64 = 14 * 86_400;
+/// Time past its deadline from which a job can be emergency refunded
+pub const EMERGENCY_REFUND_GRACE: u64 = 3 * 86_400;
+/// Governance action: set the protocol fee to `value` basis points
+pub const GOVERNANCE_ACTION_PROTOCOL_FEE: u8 = 0;
+/// Governance action: set the default job cap to `value`
+pub const GOVERNANCE_ACTION_DEFAULT_JOB_CAP: u8 = 1;
+/// Governance action: appoint `target` as arbitrator of new disputes
+pub const GOVERNANCE_ACTION_ARBITRATOR: u8 = 2;
+/// Governance action: give arbitrators `value` seconds to rule
+pub const GOVERNANCE_ACTION_RULING_PERIOD: u8 = 3;
+/// Governance action: time new disputes out after `value` seconds, 0 for never
+pub const GOVERNANCE_ACTION_DISPUTE_TIMEOUT: u8 = 4;
+/// Governance action: sweep jobs `value` seconds past their deadline
+pub const GOVERNANCE_ACTION_DORMANCY_PERIOD: u8 = 5;
+/// Governance action: add `target` to the council
+pub const GOVERNANCE_ACTION_ADD_COUNCIL_MEMBER: u8 = 6;
+/// Governance action: remove `target` from the council
+pub const GOVERNANCE_ACTION_REMOVE_COUNCIL_MEMBER: u8 = 7;
+/// Governance action: require `value` votes for a proposal to pass
+pub const GOVERNANCE_ACTION_QUORUM: u8 = 8;
+/// Governance action: publish `value` as the emergency refund Merkle root
+pub const GOVERNANCE_ACTION_REFUND_ROOT: u8 = 9;
+/// Governance action: settle the appealed dispute over job `value` at the
+/// appellant's split; only `appeal_ruling` proposes it
+pub const GOVERNANCE_ACTION_APPEAL: u8 = 10;
+/// Governance action: hand the dispute over job `value` to `target`, once its
+/// arbitrator has let the ruling period pass without ruling
+pub const GOVERNANCE_ACTION_REPLACE_ARBITRATOR: u8 = 11;
+/// Governance action: apply the governed `SETTING_*` `value` with its
+/// arguments ABI-encoded in the proposal's data, as for `configure`
+pub const GOVERNANCE_ACTION_SETTING: u8 = 12;
+/// Governance action: propose `target` as the treasury, applied after
+/// `TREASURY_DELAY` like the admin's proposals
+pub const GOVERNANCE_ACTION_TREASURY: u8 = 13;
+/// Time a governance proposal is open for votes
+pub const GOVERNANCE_VOTING_PERIOD: u64 = 3 * 86_400;
+/// Time between voting ending and a passed proposal becoming executable
+pub const GOVERNANCE_TIMELOCK: u64 = 2 * 86_400;
+/// Time a passed proposal stays executable once its timelock is over
+pub const GOVERNANCE_EXECUTION_WINDOW: u64 = 14 * 86_400;
+/// Setting: swap router and WETH, as `(address router, address weth)`
+pub const SETTING_SWAP_ROUTER: u8 = 0;
+/// Setting: approve or drop a basket token, as `(address token, bool approved)`
+pub const SETTING_BASKET_TOKEN: u8 = 1;
+/// Setting: compliance hook, as `(address hook)`
+pub const SETTING_COMPLIANCE_HOOK: u8 = 2;
+/// Setting: default job cap, as `(uint32 cap)`
+pub const SETTING_DEFAULT_JOB_CAP: u8 = 3;
+/// Setting: fee manager, as `(address manager)`
+pub const SETTING_FEE_MANAGER: u8 = 4;
+/// Setting: protocol fee, as `(uint16 fee_bps)`
+pub const SETTING_PROTOCOL_FEE: u8 = 5;
+/// Setting: settlement rebate, as `(uint256 amount)`
+pub const SETTING_SETTLEMENT_REBATE: u8 = 6;
+/// Setting: fee tiers, as `(uint256[] min_amounts, uint16[] fee_bps)`
+pub const SETTING_FEE_TIERS: u8 = 7;
+/// Setting: volume discounts, as
+/// `(uint256[] min_volumes, uint16[] discount_bps)`
+pub const SETTING_VOLUME_DISCOUNTS: u8 = 8;
+/// Setting: fee exemption, as `(address account, bool exempt)`
+pub const SETTING_FEE_EXEMPT: u8 = 9;
+/// Setting: deposit limits, as `(uint64 cooldown, uint32 per_block)`
+pub const SETTING_DEPOSIT_LIMITS: u8 = 10;
+/// Setting: deposit limit exemption, as `(address account, bool exempt)`
+pub const SETTING_DEPOSIT_LIMIT_EXEMPT: u8 = 11;
+/// Setting: attestation policy, as
+/// `(address eas, bytes32 schema, address attester)`
+pub const SETTING_ATTESTATION_POLICY: u8 = 12;
+/// Setting: arbitration policy, as
+/// `(address arbitrator, uint64 ruling_period, uint16 default_split_bps)`
+pub const SETTING_ARBITRATION_POLICY: u8 = 13;
+/// Setting: dispute timeout policy, as
+/// `(uint64 timeout, bool split, uint16 split_bps)`
+pub const SETTING_DISPUTE_TIMEOUT_POLICY: u8 = 14;
+/// Setting: dispute stake policy, as `(uint16 stake_bps, uint256 min_stake)`
+pub const SETTING_DISPUTE_STAKE_POLICY: u8 = 15;
+/// Setting: jury policy, as
+/// `(uint8 size, uint64 voting_period, uint256 min_stake, uint16 slash_bps)`
+pub const SETTING_JURY_POLICY: u8 = 16;
+/// Setting: appeal policy, as
+/// `(uint64 window, uint16 bond_bps, uint256 min_bond)`
+pub const SETTING_APPEAL_POLICY: u8 = 17;
+/// Setting: maximum pause, as `(uint64 duration)`
+pub const SETTING_MAX_PAUSE: u8 = 18;
+/// Setting: pauser, as `(address pauser)`
+pub const SETTING_PAUSER: u8 = 19;
+/// Setting: dormancy policy, as `(uint64 period, address recovery)`
+pub const SETTING_DORMANCY_POLICY: u8 = 20;
+/// Setting: list or unlist an arbitrator clients can pick for their jobs, as
+/// `(address arbitrator, bool listed)`
+pub const SETTING_ARBITRATOR_LISTING: u8 = 21;
+/// Setting: liquid staking token long jobs can be held in, as
+/// `(address token, uint16 max_slippage_bps)`
+pub const SETTING_STAKING_TOKEN: u8 = 22;
+/// Highest discount to a staked job's redemption value its shares can be
+/// bought back at, in basis points
+pub const MAX_UNSTAKE_SLIPPAGE_BPS: u16 = 500;
+/// Shortest time to its deadline a job can be staked with
+pub const MIN_STAKE_DURATION: u64 = 90 * 86_400;
+/// How long before a staked job's deadline anyone, not only its parties,
+/// can buy back its shares
+pub const UNSTAKE_WINDOW: u64 = 7 * 86_400;
+/// Most arbitrators the registry can list
+pub const MAX_LISTED_ARBITRATORS: usize = 32;
+/// Most members the governance council can have
+pub const MAX_COUNCIL: usize = 16;
+/// Denominator of rates given in basis points
+pub const BASIS_POINTS: u16 = 10_000;
+/// Gas forwarded to a job's settlement hook
+pub const SETTLEMENT_HOOK_GAS: u64 = 100_000;
+/// Gas forwarded when paying a contract payout address; a payout that needs
+/// more is credited to it instead
+pub const PAYOUT_CALL_GAS: u64 = 50_000;
+/// Most tranches a job's release schedule can have
+pub const MAX_TRANCHES: usize = 12;
+/// Most ERC-20s a job's basket can hold besides its ETH
+pub const MAX_BASKET_TOKENS: usize = 4;
+/// Most periods a retainer can be funded for up front
+pub const MAX_RETAINER_PERIODS: u64 = 36;
+/// Highest protocol fee the admin can set: 10%
+pub const MAX_PROTOCOL_FEE_BPS: u16 = 1_000;
+/// Highest platform fee a marketplace can take on a job: 10%
+pub const MAX_PLATFORM_FEE_BPS: u16 = 1_000;
+/// Highest share of a job the admin can make a dispute stake: 5%
+pub const MAX_DISPUTE_STAKE_BPS: u16 = 500;
+/// Most jurors a dispute's panel can seat
+pub const MAX_JURY_SIZE: u8 = 15;
+/// Highest share of a job the admin can make an appeal bond: 20%
+pub const MAX_APPEAL_BOND_BPS: u16 = 2_000;
+/// Longest a job's released funds can wait out a clawback window
+pub const MAX_CLAWBACK_WINDOW: u64 = 7 * 86_400;
+/// Most pieces of evidence a dispute can hold
+pub const MAX_EVIDENCE: usize = 32;
+/// Most size tiers or volume discounts the fee schedule can have
+pub const MAX_FEE_TIERS: usize = 8;
+/// Most jobs `emergency_refund_batch` refunds in one call
+pub const MAX_REFUND_BATCH: usize = 50;
+/// Most jobs `get_jobs_range` returns in one call
+pub const MAX_JOBS_RANGE: u64 = 100;
+/// Width of the buckets `get_period_stats` reports activity in: one day
+pub const EPOCH_LENGTH: u64 = 86_400;
+/// Most stars a rating can give; ratings run from 1 to `MAX_STARS`
+pub const MAX_STARS: u8 = 5;
+/// Time over which a rating's weight in `get_score` halves
+pub const REPUTATION_HALF_LIFE: u64 = 180 * 86_400;
+/// How many times the disputed amount a lost ruling weighs as a zero-star
+/// rating
+pub const DISPUTE_LOSS_WEIGHT: u64 = 2;
+/// Unrated value, in wei, every score is measured against: 1 ETH
+pub const REPUTATION_PRIOR: U256 = U256::from_limbs([1_000_000_000_000_000_000, 0, 0, 0]);
@@ -0,0 +1,341 @@
+//! Error codes the escrow reverts with.
+//!
+//! Every failure is reported as `EscrowError(code)` instead of a message
+//! string, which keeps several kilobytes of text out of the WASM and makes
+//! reverts cheaper to return. The messages live in [`ErrorCode::message`],
+//! which the contract never calls, so off-chain tools can still print what
+//! went wrong. Codes are part of the ABI: new errors take the next code and
+//! existing ones are never renumbered or reused.
+
+use alloy_sol_types::{sol, SolError};
+
+sol! {
+    #![sol(abi, all_derives)]
+    error EscrowError(uint16 code);
+}
+
+macro_rules! error_codes {
+    ($($name:ident = $code:literal => $message:literal,)*) => {
+        /// Why an escrow call reverted
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        #[repr(u16)]
+        pub enum ErrorCode {
+            $($name = $code,)*
+        }
+
+        impl ErrorCode {
+            /// Every error, in code order
+            pub const ALL: &'static [ErrorCode] = &[$(ErrorCode::$name,)*];
+
+            /// The error with `code`, if there is one
+            pub fn from_code(code: u16) -> Option<Self> {
+                match code {
+                    $($code => Some(ErrorCode::$name),)*
+                    _ => None,
+                }
+            }
+
+            /// Human-readable description of the error
+            pub fn message(self) -> &'static str {
+                match self {
+                    $(ErrorCode::$name => $message,)*
+                }
+            }
+        }
+    };
+}
+
+error_codes! {
+    InvalidAdminAddress = 1 => "Invalid admin address",
+    DirectTransfersNotAccepted = 2 => "Direct transfers not accepted",
+    UnknownFunction = 3 => "Unknown function",
+    InsufficientDeposit = 4 => "Insufficient deposit",
+    EscrowIsPaused = 5 => "Escrow is paused",
+    NothingToWithdraw = 6 => "Nothing to withdraw",
+    AmountMustBePositive = 7 => "Amount must be > 0",
+    InvalidConditionAddress = 8 => "Invalid condition address",
+    InvalidPlatformAddress = 9 => "Invalid platform address",
+    InvalidPlatformFee = 10 => "Invalid platform fee",
+    DualApprovalNotRequired = 11 => "Dual approval not required",
+    OnlyJobPartiesCanApprove = 12 => "Only job parties can approve",
+    ReleaseAlreadyApproved = 13 => "Release already approved",
+    InvalidNotice = 14 => "Invalid notice",
+    InvalidHoldback = 15 => "Invalid holdback",
+    InvalidWarranty = 16 => "Invalid warranty",
+    InvalidClawbackWindow = 17 => "Invalid clawback window",
+    NoArbitrator = 18 => "No arbitrator",
+    InvalidDeliveryDate = 19 => "Invalid delivery date",
+    InvalidLateRefund = 20 => "Invalid late refund",
+    EscrowIsSunset = 21 => "Escrow is sunset",
+    InvalidFreelancerAddress = 22 => "Invalid freelancer address",
+    DurationMustBePositive = 23 => "Duration must be > 0",
+    InvalidTarget = 24 => "Invalid target",
+    InvalidCutoff = 25 => "Invalid cutoff",
+    FundingExceedsTarget = 26 => "Funding exceeds target",
+    JobNotFunding = 27 => "Job not funding",
+    FundingClosed = 28 => "Funding closed",
+    FundingStillOpen = 29 => "Funding still open",
+    NothingToReclaim = 30 => "Nothing to reclaim",
+    InvalidClientAddress = 31 => "Invalid client address",
+    InvalidExpiry = 32 => "Invalid expiry",
+    InvoiceNotFound = 33 => "Invoice not found",
+    InvoiceAlreadyFunded = 34 => "Invoice already funded",
+    OnlyInvoicedClient = 35 => "Only invoiced client",
+    InvoiceExpired = 36 => "Invoice expired",
+    IncorrectPayment = 37 => "Incorrect payment",
+    InvalidTranches = 38 => "Invalid tranches",
+    UnlocksMustBeIncreasing = 39 => "Unlocks must be increasing",
+    TranchesMustSumToDeposit = 40 => "Tranches must sum to deposit",
+    InvalidUnlockTime = 41 => "Invalid unlock time",
+    OnlyFreelancerCanClaim = 42 => "Only freelancer can claim",
+    JobAlreadySettled = 43 => "Job already settled",
+    JobAlreadyFinalized = 44 => "Job already finalized",
+    InvalidTranche = 45 => "Invalid tranche",
+    TrancheAlreadySettled = 46 => "Tranche already settled",
+    TrancheLocked = 47 => "Tranche locked",
+    OnlyClientCanRefund = 48 => "Only client can refund",
+    JobIsARetainer = 49 => "Job is a retainer",
+    TrancheUnlocked = 50 => "Tranche unlocked",
+    InvalidPeriods = 51 => "Invalid periods",
+    InvalidPeriod = 52 => "Invalid period",
+    DepositMustSplitEvenly = 53 => "Deposit must split evenly",
+    NothingToClaim = 54 => "Nothing to claim",
+    OnlyClientCanStop = 55 => "Only client can stop",
+    JobIsNotARetainer = 56 => "Job is not a retainer",
+    NothingToStop = 57 => "Nothing to stop",
+    InvalidRate = 58 => "Invalid rate",
+    OnlyFreelancerCanBill = 59 => "Only freelancer can bill",
+    JobIsNotHourly = 60 => "Job is not hourly",
+    InvalidHours = 61 => "Invalid hours",
+    ExceedsBudget = 62 => "Exceeds budget",
+    OnlyClientCanApprove = 63 => "Only client can approve",
+    OnlyClientCanReject = 64 => "Only client can reject",
+    OnlyClientCanEnd = 65 => "Only client can end",
+    EngagementRunning = 66 => "Engagement running",
+    InvalidBasket = 67 => "Invalid basket",
+    TokenNotApproved = 68 => "Token not approved",
+    DuplicateToken = 69 => "Duplicate token",
+    OfferExpired = 70 => "Offer expired",
+    InvalidSignature = 71 => "Invalid signature",
+    InsufficientCredit = 72 => "Insufficient credit",
+    NotAParty = 73 => "Not a party",
+    InvalidCommitment = 74 => "Invalid commitment",
+    JobIsNotPrivate = 75 => "Job is not private",
+    OnlyJobPartiesCanReveal = 76 => "Only job parties can reveal",
+    JobAlreadyRevealed = 77 => "Job already revealed",
+    CommitmentMismatch = 78 => "Commitment mismatch",
+    AmountExceedsDeposit = 79 => "Amount exceeds deposit",
+    OnlyClientCanSetHook = 80 => "Only client can set hook",
+    OnlyClientCanRelease = 81 => "Only client can release",
+    JobNotRevealed = 82 => "Job not revealed",
+    JobIsTranched = 83 => "Job is tranched",
+    JobHasClawbackWindow = 84 => "Job has clawback window",
+    JobHasSLA = 85 => "Job has SLA",
+    AmountExceedsEscrow = 86 => "Amount exceeds escrow",
+    UnsupportedToken = 87 => "Unsupported token",
+    OnlyClientCanCancel = 88 => "Only client can cancel",
+    JobHasNoNoticePeriod = 89 => "Job has no notice period",
+    CancellationAlreadyRequested = 90 => "Cancellation already requested",
+    DeadlinePassed = 91 => "Deadline passed",
+    OnlyFreelancerCanDeliver = 92 => "Only freelancer can deliver",
+    NoCancellationPending = 93 => "No cancellation pending",
+    NoticePeriodOver = 94 => "Notice period over",
+    OnlyFreelancerCanExtend = 95 => "Only freelancer can extend",
+    DeadlineMustBeLater = 96 => "Deadline must be later",
+    OnlyClientCanTransfer = 97 => "Only client can transfer",
+    OnlyPendingClientCanAccept = 98 => "Only pending client can accept",
+    OnlyFreelancerCanAccept = 99 => "Only freelancer can accept",
+    JobAlreadyAccepted = 100 => "Job already accepted",
+    OnlyClientCanReassign = 101 => "Only client can reassign",
+    JobIsAttested = 102 => "Job is attested",
+    OnlyJobPartiesCanDispute = 103 => "Only job parties can dispute",
+    DisputeAlreadyOpened = 104 => "Dispute already opened",
+    IncorrectStake = 105 => "Incorrect stake",
+    NoOpenDispute = 106 => "No open dispute",
+    OnlyJobPartiesCanSubmitEvidence = 107 => "Only job parties can submit evidence",
+    InvalidEvidence = 108 => "Invalid evidence",
+    TooMuchEvidence = 109 => "Too much evidence",
+    OnlyJobPartiesCanResolve = 110 => "Only job parties can resolve",
+    NoArbitratorAssigned = 111 => "No arbitrator assigned",
+    RulingPeriodNotOver = 112 => "Ruling period not over",
+    NoDisputeTimeout = 113 => "No dispute timeout",
+    DisputeTimeoutNotReached = 114 => "Dispute timeout not reached",
+    OnlyJobPartiesCanEscalate = 115 => "Only job parties can escalate",
+    JuryAlreadyDrawn = 116 => "Jury already drawn",
+    JuriesDisabled = 117 => "Juries disabled",
+    RulingPeriodOver = 118 => "Ruling period over",
+    NotOnJury = 119 => "Not on jury",
+    VotingClosed = 120 => "Voting closed",
+    AlreadyVoted = 121 => "Already voted",
+    InvalidSplit = 122 => "Invalid split",
+    NoJury = 123 => "No jury",
+    JuryAlreadyTallied = 124 => "Jury already tallied",
+    VotingStillOpen = 125 => "Voting still open",
+    NoPendingRuling = 126 => "No pending ruling",
+    OnlyJobPartiesCanAppeal = 127 => "Only job parties can appeal",
+    RulingAlreadyAppealed = 128 => "Ruling already appealed",
+    AppealWindowOver = 129 => "Appeal window over",
+    IncorrectBond = 130 => "Incorrect bond",
+    RulingStillAppealable = 131 => "Ruling still appealable",
+    DeadlineNotReached = 132 => "Deadline not reached",
+    NoReleaseQueued = 133 => "No release queued",
+    ClawbackWindowOpen = 134 => "Clawback window open",
+    OnlyArbitrator = 135 => "Only arbitrator",
+    ClawbackWindowClosed = 136 => "Clawback window closed",
+    NoHoldbackRetained = 137 => "No holdback retained",
+    WarrantyRunning = 138 => "Warranty running",
+    ClaimWindowOpen = 139 => "Claim window open",
+    InvalidMinRate = 140 => "Invalid min rate",
+    InvalidPayoutAddress = 141 => "Invalid payout address",
+    NoPendingPayoutAddress = 142 => "No pending payout address",
+    PayoutAddressDelayNotOver = 143 => "Payout address delay not over",
+    InvalidWithholdingRate = 144 => "Invalid withholding rate",
+    InvalidWithholdingAddress = 145 => "Invalid withholding address",
+    OnlyAdmin = 146 => "Only admin",
+    InvalidWETHAddress = 147 => "Invalid WETH address",
+    InvalidToken = 148 => "Invalid token",
+    InvalidRecipient = 149 => "Invalid recipient",
+    FeesGoToTreasury = 150 => "Fees go to treasury",
+    InvalidAmount = 151 => "Invalid amount",
+    ExceedsSurplus = 152 => "Exceeds surplus",
+    InvalidTiers = 153 => "Invalid tiers",
+    InvalidFee = 154 => "Invalid fee",
+    InvalidDiscount = 155 => "Invalid discount",
+    InvalidAttesterAddress = 156 => "Invalid attester address",
+    InvalidStake = 157 => "Invalid stake",
+    InvalidJurySize = 158 => "Invalid jury size",
+    InvalidVotingPeriod = 159 => "Invalid voting period",
+    InvalidSlash = 160 => "Invalid slash",
+    NoStake = 161 => "No stake",
+    InvalidBond = 162 => "Invalid bond",
+    PauseNotExpired = 163 => "Pause not expired",
+    NoPendingRenounce = 164 => "No pending renounce",
+    RenounceDelayNotOver = 165 => "Renounce delay not over",
+    InvalidBatch = 166 => "Invalid batch",
+    OnlyClientCanClaim = 167 => "Only client can claim",
+    NoRefundRoot = 168 => "No refund root",
+    RefundAlreadyClaimed = 169 => "Refund already claimed",
+    InvalidRefundAmount = 170 => "Invalid refund amount",
+    PartialRefundNotSupported = 171 => "Partial refund not supported",
+    InvalidProof = 172 => "Invalid proof",
+    TooManyGuardians = 173 => "Too many guardians",
+    InvalidThreshold = 174 => "Invalid threshold",
+    InvalidGuardian = 175 => "Invalid guardian",
+    InvalidAction = 176 => "Invalid action",
+    ProposalDoesNotExist = 177 => "Proposal does not exist",
+    ProposalAlreadyExecuted = 178 => "Proposal already executed",
+    ProposalStale = 179 => "Proposal stale",
+    ProposalExpired = 180 => "Proposal expired",
+    AlreadyConfirmed = 181 => "Already confirmed",
+    GovernanceAlreadySet = 182 => "Governance already set",
+    InvalidGovernance = 183 => "Invalid governance",
+    CouncilTooLarge = 184 => "Council too large",
+    InvalidCouncilMember = 185 => "Invalid council member",
+    InvalidQuorum = 186 => "Invalid quorum",
+    GovernanceNotSet = 187 => "Governance not set",
+    NoVotingPower = 188 => "No voting power",
+    ProposalNotPassed = 189 => "Proposal not passed",
+    TimelockNotOver = 190 => "Timelock not over",
+    SunsetAlreadyStarted = 191 => "Sunset already started",
+    InvalidSunsetTime = 192 => "Invalid sunset time",
+    EscrowIsRetired = 193 => "Escrow is retired",
+    SunsetNotReached = 194 => "Sunset not reached",
+    JobsStillOpen = 195 => "Jobs still open",
+    DormancyPolicyNotSet = 196 => "Dormancy policy not set",
+    DormancyAlreadyAnnounced = 197 => "Dormancy already announced",
+    JobNotDormant = 198 => "Job not dormant",
+    DormancyNotAnnounced = 199 => "Dormancy not announced",
+    NoticePeriodNotOver = 200 => "Notice period not over",
+    InvalidTreasuryAddress = 201 => "Invalid treasury address",
+    NoPendingTreasury = 202 => "No pending treasury",
+    TreasuryDelayNotOver = 203 => "Treasury delay not over",
+    InvalidStart = 204 => "Invalid start",
+    RangeTooLarge = 205 => "Range too large",
+    InvalidLimit = 206 => "Invalid limit",
+    InvalidStatus = 207 => "Invalid status",
+    JobNotSettled = 208 => "Job not settled",
+    OnlyJobPartiesCanRate = 209 => "Only job parties can rate",
+    InvalidRating = 210 => "Invalid rating",
+    AlreadyRated = 211 => "Already rated",
+    CancellationNotRequested = 212 => "Cancellation not requested",
+    NoticePeriodRunning = 213 => "Notice period running",
+    OnlyFreelancerCanReturnFunds = 214 => "Only freelancer can return funds",
+    RulingPending = 215 => "Ruling pending",
+    NoPendingAppeal = 216 => "No pending appeal",
+    DualApprovalRequired = 217 => "Dual approval required",
+    AttestationsNotConfigured = 218 => "Attestations not configured",
+    InvalidAttestation = 219 => "Invalid attestation",
+    AttestationRevoked = 220 => "Attestation revoked",
+    AttestationExpired = 221 => "Attestation expired",
+    AuthorizationExpired = 222 => "Authorization expired",
+    VerdictExpired = 223 => "Verdict expired",
+    NonceAlreadyUsed = 224 => "Nonce already used",
+    JobHasBasket = 225 => "Job has basket",
+    TokenTransferFailed = 226 => "Token transfer failed",
+    JobCapReached = 227 => "Job cap reached",
+    ReleaseQueued = 228 => "Release queued",
+    ComplianceCheckFailed = 229 => "Compliance check failed",
+    ReleaseConditionNotMet = 230 => "Release condition not met",
+    DepositCooldownActive = 231 => "Deposit cooldown active",
+    DepositLimitReached = 232 => "Deposit limit reached",
+    JobIsDisputed = 233 => "Job is disputed",
+    OnlyFeeManager = 234 => "Only fee manager",
+    JobNotFunded = 235 => "Job not funded",
+    GovernedParameter = 236 => "Governed parameter",
+    VotesUnavailable = 237 => "Votes unavailable",
+    InvalidCap = 238 => "Invalid cap",
+    InvalidRulingPeriod = 239 => "Invalid ruling period",
+    InvalidTimeout = 240 => "Invalid timeout",
+    DormancyPeriodTooShort = 241 => "Dormancy period too short",
+    InvalidRecoveryAddress = 242 => "Invalid recovery address",
+    GuardianQuorumRequired = 243 => "Guardian quorum required",
+    OnlyGuardian = 244 => "Only guardian",
+    InvalidPauseEnd = 245 => "Invalid pause end",
+    JobTooRecent = 246 => "Job too recent",
+    NoSurplus = 247 => "No surplus",
+    EscrowIsNotPaused = 248 => "Escrow is not paused",
+    HoldbackRetained = 249 => "Holdback retained",
+    StakeBelowMinimum = 250 => "Stake below minimum",
+    NotAJuror = 251 => "Not a juror",
+    JurorServing = 252 => "Juror serving",
+    NotEnoughJurors = 253 => "Not enough jurors",
+    DisputeBeforeJury = 254 => "Dispute before jury",
+    ParametersNotFinal = 255 => "Parameters not final",
+    JobDoesNotExist = 256 => "Job does not exist",
+    AlreadyDelivered = 257 => "Already delivered",
+    Insolvent = 258 => "Insolvent",
+    InvalidSwapOutput = 259 => "Invalid swap output",
+    JobIsHourly = 260 => "Job is hourly",
+    TimesheetsPending = 261 => "Timesheets pending",
+    InvalidTimesheet = 262 => "Invalid timesheet",
+    TimesheetAlreadySettled = 263 => "Timesheet already settled",
+    InvalidSetting = 264 => "Invalid setting",
+    InvalidArbitrator = 265 => "Invalid arbitrator",
+    ArbitratorIsParty = 266 => "Arbitrator is a party",
+    TooManyArbitrators = 267 => "Too many arbitrators",
+    StakingNotEnabled = 268 => "Staking not enabled",
+    JobIsStaked = 269 => "Job is staked",
+    JobNotStaked = 270 => "Job not staked",
+    StakeDurationTooShort = 271 => "Stake duration too short",
+    StakeFailed = 272 => "Stake failed",
+    UnstakePriceTooLow = 273 => "Unstake price too low",
+    InvalidSlippage = 274 => "Invalid slippage",
+    InvalidYieldSplit = 275 => "Invalid yield split",
+    UnstakeNotOpen = 276 => "Unstake not open",
+    CreditOutstanding = 277 => "Credit outstanding",
+    FeesOutstanding = 278 => "Fees outstanding",
+    JurorStakesOutstanding = 279 => "Juror stakes outstanding",
+}
+
+impl ErrorCode {
+    /// Code the error is reported with
+    pub fn code(self) -> u16 {
+        self as u16
+    }
+}
+
+impl From<ErrorCode> for Vec<u8> {
+    fn from(error: ErrorCode) -> Self {
+        EscrowError { code: error.code() }.abi_encode()
+    }
+}
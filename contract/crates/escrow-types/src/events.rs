@@ -0,0 +1,1029 @@
+//! Escrow events and decoding of raw `(topics, data)` logs.
+//!
+//! Shared by the contract's tests and the off-chain tools so everything matches on the
+//! same topic constants, which `sol!` hashes from the event signatures at compile time.
+
+use alloy_primitives::{B256, U256};
+use alloy_sol_types::{sol, SolEvent};
+
+sol! {
+    #![sol(abi, all_derives)]
+    event Deposited(uint256 indexed job_id, address indexed client, address indexed freelancer, uint256 amount, bytes32 memo, uint16 category, bytes32 tag);
+    event Released(uint256 indexed job_id, uint256 amount, bytes32 memo);
+    event Refunded(uint256 indexed job_id, uint256 amount, bytes32 memo);
+    event AutoReleased(uint256 indexed job_id, uint256 amount, bytes32 memo);
+    event EmergencyRefunded(uint256 indexed job_id, address indexed admin);
+    event PauseToggled(bool paused);
+    event OwnershipTransferred(address indexed old_admin, address indexed new_admin);
+    event SunsetBegun(uint64 timestamp);
+    event SunsetFinalized(address indexed admin, uint256 swept);
+    event DormancyPolicySet(uint64 period, address indexed recovery);
+    event DormancyAnnounced(uint256 indexed job_id, uint64 sweep_after);
+    event DormantSwept(uint256 indexed job_id, address indexed recovery, uint256 amount);
+    event PrivateDeposited(uint256 indexed job_id, address indexed client, uint256 amount, bytes32 commitment);
+    event JobRevealed(uint256 indexed job_id, address indexed freelancer, uint256 amount, bytes32 terms);
+    event SwapRouterSet(address indexed router, address weth);
+    event PayoutTokenSet(address indexed freelancer, address indexed token, uint256 min_rate);
+    event PayoutSwapped(uint256 indexed job_id, address indexed token, uint256 amount_in, uint256 amount_out);
+    event PayoutSwapFailed(uint256 indexed job_id, address indexed token);
+    event WithholdingSet(address indexed freelancer, address indexed destination, uint16 rate_bps);
+    event Withheld(uint256 indexed job_id, address indexed destination, uint256 net, uint256 withheld);
+    event ComplianceHookSet(address indexed hook);
+    event JobAttested(uint256 indexed job_id, bytes32 indexed uid);
+    event AttestationPolicySet(address indexed eas, bytes32 indexed schema, address indexed attester);
+    event SettlementHookSet(uint256 indexed job_id, address indexed hook);
+    event SettlementHookFailed(uint256 indexed job_id, address indexed hook);
+    event ReleaseConditionSet(uint256 indexed job_id, address indexed condition);
+    event TrancheClaimed(uint256 indexed job_id, uint256 index, uint256 amount, uint256 remaining);
+    event TrancheRefunded(uint256 indexed job_id, uint256 index, uint256 amount, uint256 remaining);
+    event RetainerStarted(uint256 indexed job_id, uint64 periods, uint64 period, uint64 notice);
+    event RetainerStopped(uint256 indexed job_id, uint256 refunded);
+    event CancellationNoticeSet(uint256 indexed job_id, uint64 notice);
+    event CancellationRequested(uint256 indexed job_id, uint64 effective_at);
+    event DeliverySubmitted(uint256 indexed job_id, bytes32 delivery);
+    event FundingOpened(uint256 indexed job_id, address indexed client, address indexed freelancer, uint256 target, uint64 cutoff);
+    event JobFunded(uint256 indexed job_id, address indexed contributor, uint256 amount, uint256 raised);
+    event ContributionReclaimed(uint256 indexed job_id, address indexed contributor, uint256 amount);
+    event ExcessCredited(uint256 indexed job_id, address indexed client, uint256 excess);
+    event CreditWithdrawn(address indexed account, uint256 amount);
+    event DefaultJobCapSet(uint32 cap);
+    event JobCapSet(address indexed freelancer, uint32 cap);
+    event ProtocolFeeSet(uint16 fee_bps);
+    event FeeCharged(uint256 indexed job_id, address indexed recipient, uint256 fee);
+    event FeeManagerSet(address indexed manager);
+    event FeeTiersSet(uint256[] min_amounts, uint16[] fee_bps);
+    event VolumeDiscountsSet(uint256[] min_volumes, uint16[] discount_bps);
+    event FeeExemptionSet(address indexed account, bool exempt);
+    event DisputeOpened(uint256 indexed job_id, address indexed opened_by, bytes32 memo);
+    event EvidenceSubmitted(uint256 indexed job_id, address indexed submitter, uint256 index, bytes32 evidence_hash);
+    event DisputeResolved(uint256 indexed job_id, address indexed resolver, uint256 freelancer_amount, uint256 client_amount, bool by_default);
+    event ArbitrationPolicySet(address indexed arbitrator, uint64 ruling_period, uint16 default_split_bps);
+    event Reclaimed(uint256 indexed job_id, address indexed caller, uint256 amount, bytes32 memo);
+    event FundsReturned(uint256 indexed job_id, uint256 amount, bytes32 memo);
+    event DualApprovalRequired(uint256 indexed job_id);
+    event ReleaseApproved(uint256 indexed job_id, address indexed approver);
+    event DisputeTimedOut(uint256 indexed job_id, bool split);
+    event DisputeTimeoutPolicySet(uint64 timeout, bool split, uint16 split_bps);
+    event JobAmended(uint256 indexed job_id, uint8 indexed field, bytes32 old_value, bytes32 new_value, address indexed actor);
+    event FeesWithdrawn(address indexed to, uint256 amount, uint256 remaining);
+    event TreasuryProposed(address indexed treasury, uint64 effective_at);
+    event TreasurySet(address indexed treasury);
+    event GuardiansSet(address[] guardians, uint8 threshold);
+    event EmergencyProposed(uint256 indexed proposal_id, uint8 action, uint256 target, address indexed proposer, uint64 expires_at);
+    event EmergencyConfirmed(uint256 indexed proposal_id, address indexed guardian, uint8 confirmations);
+    event SurplusSwept(address indexed to, uint256 amount);
+    event PauseExtended(uint64 until);
+    event ClientTransferProposed(uint256 indexed job_id, address indexed client, address indexed new_client);
+    event JobAccepted(uint256 indexed job_id, address indexed freelancer);
+    event FreelancerChanged(uint256 indexed job_id, address indexed old_freelancer, address indexed new_freelancer);
+    event PayoutAddressProposed(address indexed freelancer, address indexed payout_address, uint64 effective_at);
+    event PayoutAddressSet(address indexed freelancer, address indexed payout_address);
+    event GovernanceSet(address token, address[] council, uint256 quorum);
+    event GovernanceProposed(uint256 indexed proposal_id, uint8 action, address target, uint256 value, address indexed proposer, uint64 voting_ends);
+    event GovernanceVoted(uint256 indexed proposal_id, address indexed voter, bool support, uint256 weight);
+    event GovernanceExecuted(uint256 indexed proposal_id);
+    event PauserSet(address indexed pauser);
+    event MaxPauseSet(uint64 duration);
+    event RenounceProposed(uint64 effective_at);
+    event PlatformFeeSet(uint256 indexed job_id, address indexed platform, uint16 fee_bps);
+    event CreditDeposited(address indexed account, uint256 amount);
+    event OfferCancelled(address indexed client, uint256 nonce);
+    event InvoiceCreated(uint256 indexed invoice_id, address indexed freelancer, address indexed client, uint256 amount, uint64 duration, bytes32 metadata, uint64 expiry);
+    event InvoiceFunded(uint256 indexed invoice_id, uint256 indexed job_id);
+    event InvoicePruned(uint256 indexed invoice_id);
+    event PartiallyReleased(uint256 indexed job_id, uint256 amount, uint256 remaining);
+    event HoldbackSet(uint256 indexed job_id, uint16 holdback_bps, uint64 warranty);
+    event HoldbackRetained(uint256 indexed job_id, uint256 amount, uint64 release_at);
+    event ClawbackWindowSet(uint256 indexed job_id, uint64 window);
+    event ReleaseQueued(uint256 indexed job_id, uint256 amount, uint64 payable_at);
+    event ReleaseReversed(uint256 indexed job_id, address indexed arbitrator, uint256 amount);
+    event SlaSet(uint256 indexed job_id, uint64 delivery_by, uint16 late_bps_per_day);
+    event LateRefunded(uint256 indexed job_id, uint64 days_late, uint256 amount);
+    event HourlyRateSet(uint256 indexed job_id, uint256 rate);
+    event TimesheetSubmitted(uint256 indexed job_id, uint256 index, uint32 hours, uint256 amount, bytes32 details);
+    event TimesheetApproved(uint256 indexed job_id, uint256 index, uint256 amount, uint256 remaining);
+    event TimesheetRejected(uint256 indexed job_id, uint256 index);
+    event BasketTokenSet(address indexed token, bool approved);
+    event BasketFunded(uint256 indexed job_id, address indexed token, uint256 amount);
+    event BasketPaid(uint256 indexed job_id, address indexed token, address indexed recipient, uint256 amount);
+    event RefundRootSet(bytes32 indexed root);
+    event EmergencyRefundClaimed(uint256 indexed job_id, address indexed client, uint256 amount, uint256 remaining);
+    event EmergencyRefundBatch(address indexed admin, uint256[] job_ids, bool[] refunded);
+    event JobRated(uint256 indexed job_id, address indexed rater, address indexed ratee, uint8 stars);
+    event SettlementRebateSet(uint256 amount);
+    event SettlementRebated(uint256 indexed job_id, address indexed caller, uint256 amount);
+    event PayoutPushed(uint256 indexed job_id, address indexed recipient, uint256 amount, bool pushed);
+    event DepositLimitsSet(uint64 cooldown, uint32 per_block);
+    event DepositLimitExemptionSet(address indexed account, bool exempt);
+    event DisputeStakePolicySet(uint16 stake_bps, uint256 min_stake);
+    event DisputeStakeSettled(uint256 indexed job_id, address indexed recipient, uint256 amount, bool forfeited);
+    event AuthorizationCancelled(address indexed signer, uint256 nonce);
+    event JuryPolicySet(uint8 size, uint64 voting_period, uint256 min_stake, uint16 slash_bps);
+    event JurorStaked(address indexed juror, uint256 amount, uint256 stake);
+    event JurorLeft(address indexed juror, uint256 amount);
+    event JuryDrawn(uint256 indexed job_id, address[] panel, uint64 vote_by);
+    event JuryVoted(uint256 indexed job_id, address indexed juror, uint16 freelancer_bps);
+    event JuryTallied(uint256 indexed job_id, bool decided, uint16 freelancer_bps, uint256 slashed);
+    event AppealPolicySet(uint64 window, uint16 bond_bps, uint256 min_bond);
+    event RulingHeld(uint256 indexed job_id, address indexed resolver, uint16 freelancer_bps, uint64 appealable_until);
+    event RulingAppealed(uint256 indexed job_id, address indexed appellant, uint16 freelancer_bps, uint256 bond, uint256 indexed proposal_id);
+    event AppealBondSettled(uint256 indexed job_id, address indexed recipient, uint256 amount, bool forfeited);
+    event JobArbitratorSet(uint256 indexed job_id, address indexed arbitrator);
+    event ArbitratorListed(address indexed arbitrator, bool listed);
+    event ArbitratorReplaced(uint256 indexed job_id, address indexed old_arbitrator, address indexed new_arbitrator, uint64 rule_by);
+    event StakingTokenSet(address indexed token, uint16 max_slippage_bps);
+    event StakeConsented(uint256 indexed job_id, address indexed party, uint16 freelancer_yield_bps, uint16 treasury_yield_bps);
+    event JobStaked(uint256 indexed job_id, address indexed token, uint256 amount, uint256 shares);
+    event JobUnstaked(uint256 indexed job_id, address indexed buyer, uint256 principal, uint256 paid, uint256 client_yield, uint256 freelancer_yield, uint256 treasury_yield);
+    event YieldSplitSet(uint256 indexed job_id, uint16 client_yield_bps, uint16 freelancer_yield_bps, uint16 treasury_yield_bps);
+}
+
+/// `Deposited(uint256,address,address,uint256,bytes32,uint16,bytes32)`
+pub const DEPOSITED_TOPIC: B256 = Deposited::SIGNATURE_HASH;
+/// `Released(uint256,uint256,bytes32)`
+pub const RELEASED_TOPIC: B256 = Released::SIGNATURE_HASH;
+/// `Refunded(uint256,uint256,bytes32)`
+pub const REFUNDED_TOPIC: B256 = Refunded::SIGNATURE_HASH;
+/// `AutoReleased(uint256,uint256,bytes32)`
+pub const AUTO_RELEASED_TOPIC: B256 = AutoReleased::SIGNATURE_HASH;
+/// `EmergencyRefunded(uint256,address)`
+pub const EMERGENCY_REFUNDED_TOPIC: B256 = EmergencyRefunded::SIGNATURE_HASH;
+/// `PauseToggled(bool)`
+pub const PAUSE_TOGGLED_TOPIC: B256 = PauseToggled::SIGNATURE_HASH;
+/// `OwnershipTransferred(address,address)`
+pub const OWNERSHIP_TRANSFERRED_TOPIC: B256 = OwnershipTransferred::SIGNATURE_HASH;
+/// `SunsetBegun(uint64)`
+pub const SUNSET_BEGUN_TOPIC: B256 = SunsetBegun::SIGNATURE_HASH;
+/// `SunsetFinalized(address,uint256)`
+pub const SUNSET_FINALIZED_TOPIC: B256 = SunsetFinalized::SIGNATURE_HASH;
+/// `DormancyPolicySet(uint64,address)`
+pub const DORMANCY_POLICY_SET_TOPIC: B256 = DormancyPolicySet::SIGNATURE_HASH;
+/// `DormancyAnnounced(uint256,uint64)`
+pub const DORMANCY_ANNOUNCED_TOPIC: B256 = DormancyAnnounced::SIGNATURE_HASH;
+/// `DormantSwept(uint256,address,uint256)`
+pub const DORMANT_SWEPT_TOPIC: B256 = DormantSwept::SIGNATURE_HASH;
+/// `PrivateDeposited(uint256,address,uint256,bytes32)`
+pub const PRIVATE_DEPOSITED_TOPIC: B256 = PrivateDeposited::SIGNATURE_HASH;
+/// `JobRevealed(uint256,address,uint256,bytes32)`
+pub const JOB_REVEALED_TOPIC: B256 = JobRevealed::SIGNATURE_HASH;
+/// `SwapRouterSet(address,address)`
+pub const SWAP_ROUTER_SET_TOPIC: B256 = SwapRouterSet::SIGNATURE_HASH;
+/// `PayoutTokenSet(address,address,uint256)`
+pub const PAYOUT_TOKEN_SET_TOPIC: B256 = PayoutTokenSet::SIGNATURE_HASH;
+/// `PayoutSwapped(uint256,address,uint256,uint256)`
+pub const PAYOUT_SWAPPED_TOPIC: B256 = PayoutSwapped::SIGNATURE_HASH;
+/// `PayoutSwapFailed(uint256,address)`
+pub const PAYOUT_SWAP_FAILED_TOPIC: B256 = PayoutSwapFailed::SIGNATURE_HASH;
+/// `WithholdingSet(address,address,uint16)`
+pub const WITHHOLDING_SET_TOPIC: B256 = WithholdingSet::SIGNATURE_HASH;
+/// `Withheld(uint256,address,uint256,uint256)`
+pub const WITHHELD_TOPIC: B256 = Withheld::SIGNATURE_HASH;
+/// `ComplianceHookSet(address)`
+pub const COMPLIANCE_HOOK_SET_TOPIC: B256 = ComplianceHookSet::SIGNATURE_HASH;
+/// `JobAttested(uint256,bytes32)`
+pub const JOB_ATTESTED_TOPIC: B256 = JobAttested::SIGNATURE_HASH;
+/// `AttestationPolicySet(address,bytes32,address)`
+pub const ATTESTATION_POLICY_SET_TOPIC: B256 = AttestationPolicySet::SIGNATURE_HASH;
+/// `SettlementHookSet(uint256,address)`
+pub const SETTLEMENT_HOOK_SET_TOPIC: B256 = SettlementHookSet::SIGNATURE_HASH;
+/// `SettlementHookFailed(uint256,address)`
+pub const SETTLEMENT_HOOK_FAILED_TOPIC: B256 = SettlementHookFailed::SIGNATURE_HASH;
+/// `ReleaseConditionSet(uint256,address)`
+pub const RELEASE_CONDITION_SET_TOPIC: B256 = ReleaseConditionSet::SIGNATURE_HASH;
+/// `TrancheClaimed(uint256,uint256,uint256,uint256)`
+pub const TRANCHE_CLAIMED_TOPIC: B256 = TrancheClaimed::SIGNATURE_HASH;
+/// `TrancheRefunded(uint256,uint256,uint256,uint256)`
+pub const TRANCHE_REFUNDED_TOPIC: B256 = TrancheRefunded::SIGNATURE_HASH;
+/// `RetainerStarted(uint256,uint64,uint64,uint64)`
+pub const RETAINER_STARTED_TOPIC: B256 = RetainerStarted::SIGNATURE_HASH;
+/// `RetainerStopped(uint256,uint256)`
+pub const RETAINER_STOPPED_TOPIC: B256 = RetainerStopped::SIGNATURE_HASH;
+/// `CancellationNoticeSet(uint256,uint64)`
+pub const CANCELLATION_NOTICE_SET_TOPIC: B256 = CancellationNoticeSet::SIGNATURE_HASH;
+/// `CancellationRequested(uint256,uint64)`
+pub const CANCELLATION_REQUESTED_TOPIC: B256 = CancellationRequested::SIGNATURE_HASH;
+/// `DeliverySubmitted(uint256,bytes32)`
+pub const DELIVERY_SUBMITTED_TOPIC: B256 = DeliverySubmitted::SIGNATURE_HASH;
+/// `FundingOpened(uint256,address,address,uint256,uint64)`
+pub const FUNDING_OPENED_TOPIC: B256 = FundingOpened::SIGNATURE_HASH;
+/// `JobFunded(uint256,address,uint256,uint256)`
+pub const JOB_FUNDED_TOPIC: B256 = JobFunded::SIGNATURE_HASH;
+/// `ContributionReclaimed(uint256,address,uint256)`
+pub const CONTRIBUTION_RECLAIMED_TOPIC: B256 = ContributionReclaimed::SIGNATURE_HASH;
+/// `ExcessCredited(uint256,address,uint256)`
+pub const EXCESS_CREDITED_TOPIC: B256 = ExcessCredited::SIGNATURE_HASH;
+/// `CreditWithdrawn(address,uint256)`
+pub const CREDIT_WITHDRAWN_TOPIC: B256 = CreditWithdrawn::SIGNATURE_HASH;
+/// `DefaultJobCapSet(uint32)`
+pub const DEFAULT_JOB_CAP_SET_TOPIC: B256 = DefaultJobCapSet::SIGNATURE_HASH;
+/// `JobCapSet(address,uint32)`
+pub const JOB_CAP_SET_TOPIC: B256 = JobCapSet::SIGNATURE_HASH;
+/// `ProtocolFeeSet(uint16)`
+pub const PROTOCOL_FEE_SET_TOPIC: B256 = ProtocolFeeSet::SIGNATURE_HASH;
+/// `FeeCharged(uint256,address,uint256)`
+pub const FEE_CHARGED_TOPIC: B256 = FeeCharged::SIGNATURE_HASH;
+/// `FeeManagerSet(address)`
+pub const FEE_MANAGER_SET_TOPIC: B256 = FeeManagerSet::SIGNATURE_HASH;
+/// `FeeTiersSet(uint256[],uint16[])`
+pub const FEE_TIERS_SET_TOPIC: B256 = FeeTiersSet::SIGNATURE_HASH;
+/// `VolumeDiscountsSet(uint256[],uint16[])`
+pub const VOLUME_DISCOUNTS_SET_TOPIC: B256 = VolumeDiscountsSet::SIGNATURE_HASH;
+/// `FeeExemptionSet(address,bool)`
+pub const FEE_EXEMPTION_SET_TOPIC: B256 = FeeExemptionSet::SIGNATURE_HASH;
+/// `DisputeOpened(uint256,address,bytes32)`
+pub const DISPUTE_OPENED_TOPIC: B256 = DisputeOpened::SIGNATURE_HASH;
+/// `EvidenceSubmitted(uint256,address,uint256,bytes32)`
+pub const EVIDENCE_SUBMITTED_TOPIC: B256 = EvidenceSubmitted::SIGNATURE_HASH;
+/// `DisputeResolved(uint256,address,uint256,uint256,bool)`
+pub const DISPUTE_RESOLVED_TOPIC: B256 = DisputeResolved::SIGNATURE_HASH;
+/// `ArbitrationPolicySet(address,uint64,uint16)`
+pub const ARBITRATION_POLICY_SET_TOPIC: B256 = ArbitrationPolicySet::SIGNATURE_HASH;
+/// `Reclaimed(uint256,address,uint256,bytes32)`
+pub const RECLAIMED_TOPIC: B256 = Reclaimed::SIGNATURE_HASH;
+/// `FundsReturned(uint256,uint256,bytes32)`
+pub const FUNDS_RETURNED_TOPIC: B256 = FundsReturned::SIGNATURE_HASH;
+/// `DualApprovalRequired(uint256)`
+pub const DUAL_APPROVAL_REQUIRED_TOPIC: B256 = DualApprovalRequired::SIGNATURE_HASH;
+/// `ReleaseApproved(uint256,address)`
+pub const RELEASE_APPROVED_TOPIC: B256 = ReleaseApproved::SIGNATURE_HASH;
+/// `DisputeTimedOut(uint256,bool)`
+pub const DISPUTE_TIMED_OUT_TOPIC: B256 = DisputeTimedOut::SIGNATURE_HASH;
+/// `DisputeTimeoutPolicySet(uint64,bool,uint16)`
+pub const DISPUTE_TIMEOUT_POLICY_SET_TOPIC: B256 = DisputeTimeoutPolicySet::SIGNATURE_HASH;
+/// `JobAmended(uint256,uint8,bytes32,bytes32,address)`
+pub const JOB_AMENDED_TOPIC: B256 = JobAmended::SIGNATURE_HASH;
+/// `FeesWithdrawn(address,uint256,uint256)`
+pub const FEES_WITHDRAWN_TOPIC: B256 = FeesWithdrawn::SIGNATURE_HASH;
+/// `TreasuryProposed(address,uint64)`
+pub const TREASURY_PROPOSED_TOPIC: B256 = TreasuryProposed::SIGNATURE_HASH;
+/// `TreasurySet(address)`
+pub const TREASURY_SET_TOPIC: B256 = TreasurySet::SIGNATURE_HASH;
+/// `GuardiansSet(address[],uint8)`
+pub const GUARDIANS_SET_TOPIC: B256 = GuardiansSet::SIGNATURE_HASH;
+/// `EmergencyProposed(uint256,uint8,uint256,address,uint64)`
+pub const EMERGENCY_PROPOSED_TOPIC: B256 = EmergencyProposed::SIGNATURE_HASH;
+/// `EmergencyConfirmed(uint256,address,uint8)`
+pub const EMERGENCY_CONFIRMED_TOPIC: B256 = EmergencyConfirmed::SIGNATURE_HASH;
+/// `SurplusSwept(address,uint256)`
+pub const SURPLUS_SWEPT_TOPIC: B256 = SurplusSwept::SIGNATURE_HASH;
+/// `PauseExtended(uint64)`
+pub const PAUSE_EXTENDED_TOPIC: B256 = PauseExtended::SIGNATURE_HASH;
+/// `ClientTransferProposed(uint256,address,address)`
+pub const CLIENT_TRANSFER_PROPOSED_TOPIC: B256 = ClientTransferProposed::SIGNATURE_HASH;
+/// `JobAccepted(uint256,address)`
+pub const JOB_ACCEPTED_TOPIC: B256 = JobAccepted::SIGNATURE_HASH;
+/// `FreelancerChanged(uint256,address,address)`
+pub const FREELANCER_CHANGED_TOPIC: B256 = FreelancerChanged::SIGNATURE_HASH;
+/// `PayoutAddressProposed(address,address,uint64)`
+pub const PAYOUT_ADDRESS_PROPOSED_TOPIC: B256 = PayoutAddressProposed::SIGNATURE_HASH;
+/// `PayoutAddressSet(address,address)`
+pub const PAYOUT_ADDRESS_SET_TOPIC: B256 = PayoutAddressSet::SIGNATURE_HASH;
+/// `GovernanceSet(address,address[],uint256)`
+pub const GOVERNANCE_SET_TOPIC: B256 = GovernanceSet::SIGNATURE_HASH;
+/// `GovernanceProposed(uint256,uint8,address,uint256,address,uint64)`
+pub const GOVERNANCE_PROPOSED_TOPIC: B256 = GovernanceProposed::SIGNATURE_HASH;
+/// `GovernanceVoted(uint256,address,bool,uint256)`
+pub const GOVERNANCE_VOTED_TOPIC: B256 = GovernanceVoted::SIGNATURE_HASH;
+/// `GovernanceExecuted(uint256)`
+pub const GOVERNANCE_EXECUTED_TOPIC: B256 = GovernanceExecuted::SIGNATURE_HASH;
+/// `PauserSet(address)`
+pub const PAUSER_SET_TOPIC: B256 = PauserSet::SIGNATURE_HASH;
+/// `MaxPauseSet(uint64)`
+pub const MAX_PAUSE_SET_TOPIC: B256 = MaxPauseSet::SIGNATURE_HASH;
+/// `RenounceProposed(uint64)`
+pub const RENOUNCE_PROPOSED_TOPIC: B256 = RenounceProposed::SIGNATURE_HASH;
+/// `PlatformFeeSet(uint256,address,uint16)`
+pub const PLATFORM_FEE_SET_TOPIC: B256 = PlatformFeeSet::SIGNATURE_HASH;
+/// `CreditDeposited(address,uint256)`
+pub const CREDIT_DEPOSITED_TOPIC: B256 = CreditDeposited::SIGNATURE_HASH;
+/// `OfferCancelled(address,uint256)`
+pub const OFFER_CANCELLED_TOPIC: B256 = OfferCancelled::SIGNATURE_HASH;
+/// `InvoiceCreated(uint256,address,address,uint256,uint64,bytes32,uint64)`
+pub const INVOICE_CREATED_TOPIC: B256 = InvoiceCreated::SIGNATURE_HASH;
+/// `InvoiceFunded(uint256,uint256)`
+pub const INVOICE_FUNDED_TOPIC: B256 = InvoiceFunded::SIGNATURE_HASH;
+/// `InvoicePruned(uint256)`
+pub const INVOICE_PRUNED_TOPIC: B256 = InvoicePruned::SIGNATURE_HASH;
+/// `PartiallyReleased(uint256,uint256,uint256)`
+pub const PARTIALLY_RELEASED_TOPIC: B256 = PartiallyReleased::SIGNATURE_HASH;
+/// `HoldbackSet(uint256,uint16,uint64)`
+pub const HOLDBACK_SET_TOPIC: B256 = HoldbackSet::SIGNATURE_HASH;
+/// `HoldbackRetained(uint256,uint256,uint64)`
+pub const HOLDBACK_RETAINED_TOPIC: B256 = HoldbackRetained::SIGNATURE_HASH;
+/// `ClawbackWindowSet(uint256,uint64)`
+pub const CLAWBACK_WINDOW_SET_TOPIC: B256 = ClawbackWindowSet::SIGNATURE_HASH;
+/// `ReleaseQueued(uint256,uint256,uint64)`
+pub const RELEASE_QUEUED_TOPIC: B256 = ReleaseQueued::SIGNATURE_HASH;
+/// `ReleaseReversed(uint256,address,uint256)`
+pub const RELEASE_REVERSED_TOPIC: B256 = ReleaseReversed::SIGNATURE_HASH;
+/// `SlaSet(uint256,uint64,uint16)`
+pub const SLA_SET_TOPIC: B256 = SlaSet::SIGNATURE_HASH;
+/// `LateRefunded(uint256,uint64,uint256)`
+pub const LATE_REFUNDED_TOPIC: B256 = LateRefunded::SIGNATURE_HASH;
+/// `HourlyRateSet(uint256,uint256)`
+pub const HOURLY_RATE_SET_TOPIC: B256 = HourlyRateSet::SIGNATURE_HASH;
+/// `TimesheetSubmitted(uint256,uint256,uint32,uint256,bytes32)`
+pub const TIMESHEET_SUBMITTED_TOPIC: B256 = TimesheetSubmitted::SIGNATURE_HASH;
+/// `TimesheetApproved(uint256,uint256,uint256,uint256)`
+pub const TIMESHEET_APPROVED_TOPIC: B256 = TimesheetApproved::SIGNATURE_HASH;
+/// `TimesheetRejected(uint256,uint256)`
+pub const TIMESHEET_REJECTED_TOPIC: B256 = TimesheetRejected::SIGNATURE_HASH;
+/// `BasketTokenSet(address,bool)`
+pub const BASKET_TOKEN_SET_TOPIC: B256 = BasketTokenSet::SIGNATURE_HASH;
+/// `BasketFunded(uint256,address,uint256)`
+pub const BASKET_FUNDED_TOPIC: B256 = BasketFunded::SIGNATURE_HASH;
+/// `BasketPaid(uint256,address,address,uint256)`
+pub const BASKET_PAID_TOPIC: B256 = BasketPaid::SIGNATURE_HASH;
+/// `RefundRootSet(bytes32)`
+pub const REFUND_ROOT_SET_TOPIC: B256 = RefundRootSet::SIGNATURE_HASH;
+/// `EmergencyRefundClaimed(uint256,address,uint256,uint256)`
+pub const EMERGENCY_REFUND_CLAIMED_TOPIC: B256 = EmergencyRefundClaimed::SIGNATURE_HASH;
+/// `EmergencyRefundBatch(address,uint256[],bool[])`
+pub const EMERGENCY_REFUND_BATCH_TOPIC: B256 = EmergencyRefundBatch::SIGNATURE_HASH;
+/// `JobRated(uint256,address,address,uint8)`
+pub const JOB_RATED_TOPIC: B256 = JobRated::SIGNATURE_HASH;
+/// `SettlementRebateSet(uint256)`
+pub const SETTLEMENT_REBATE_SET_TOPIC: B256 = SettlementRebateSet::SIGNATURE_HASH;
+/// `SettlementRebated(uint256,address,uint256)`
+pub const SETTLEMENT_REBATED_TOPIC: B256 = SettlementRebated::SIGNATURE_HASH;
+/// `PayoutPushed(uint256,address,uint256,bool)`
+pub const PAYOUT_PUSHED_TOPIC: B256 = PayoutPushed::SIGNATURE_HASH;
+/// `DepositLimitsSet(uint64,uint32)`
+pub const DEPOSIT_LIMITS_SET_TOPIC: B256 = DepositLimitsSet::SIGNATURE_HASH;
+/// `DepositLimitExemptionSet(address,bool)`
+pub const DEPOSIT_LIMIT_EXEMPTION_SET_TOPIC: B256 = DepositLimitExemptionSet::SIGNATURE_HASH;
+/// `DisputeStakePolicySet(uint16,uint256)`
+pub const DISPUTE_STAKE_POLICY_SET_TOPIC: B256 = DisputeStakePolicySet::SIGNATURE_HASH;
+/// `DisputeStakeSettled(uint256,address,uint256,bool)`
+pub const DISPUTE_STAKE_SETTLED_TOPIC: B256 = DisputeStakeSettled::SIGNATURE_HASH;
+/// `AuthorizationCancelled(address,uint256)`
+pub const AUTHORIZATION_CANCELLED_TOPIC: B256 = AuthorizationCancelled::SIGNATURE_HASH;
+/// `JuryPolicySet(uint8,uint64,uint256,uint16)`
+pub const JURY_POLICY_SET_TOPIC: B256 = JuryPolicySet::SIGNATURE_HASH;
+/// `JurorStaked(address,uint256,uint256)`
+pub const JUROR_STAKED_TOPIC: B256 = JurorStaked::SIGNATURE_HASH;
+/// `JurorLeft(address,uint256)`
+pub const JUROR_LEFT_TOPIC: B256 = JurorLeft::SIGNATURE_HASH;
+/// `JuryDrawn(uint256,address[],uint64)`
+pub const JURY_DRAWN_TOPIC: B256 = JuryDrawn::SIGNATURE_HASH;
+/// `JuryVoted(uint256,address,uint16)`
+pub const JURY_VOTED_TOPIC: B256 = JuryVoted::SIGNATURE_HASH;
+/// `JuryTallied(uint256,bool,uint16,uint256)`
+pub const JURY_TALLIED_TOPIC: B256 = JuryTallied::SIGNATURE_HASH;
+/// `AppealPolicySet(uint64,uint16,uint256)`
+pub const APPEAL_POLICY_SET_TOPIC: B256 = AppealPolicySet::SIGNATURE_HASH;
+/// `RulingHeld(uint256,address,uint16,uint64)`
+pub const RULING_HELD_TOPIC: B256 = RulingHeld::SIGNATURE_HASH;
+/// `RulingAppealed(uint256,address,uint16,uint256,uint256)`
+pub const RULING_APPEALED_TOPIC: B256 = RulingAppealed::SIGNATURE_HASH;
+/// `AppealBondSettled(uint256,address,uint256,bool)`
+pub const APPEAL_BOND_SETTLED_TOPIC: B256 = AppealBondSettled::SIGNATURE_HASH;
+/// `JobArbitratorSet(uint256,address)`
+pub const JOB_ARBITRATOR_SET_TOPIC: B256 = JobArbitratorSet::SIGNATURE_HASH;
+/// `ArbitratorListed(address,bool)`
+pub const ARBITRATOR_LISTED_TOPIC: B256 = ArbitratorListed::SIGNATURE_HASH;
+/// `ArbitratorReplaced(uint256,address,address,uint64)`
+pub const ARBITRATOR_REPLACED_TOPIC: B256 = ArbitratorReplaced::SIGNATURE_HASH;
+/// `StakingTokenSet(address,uint16)`
+pub const STAKING_TOKEN_SET_TOPIC: B256 = StakingTokenSet::SIGNATURE_HASH;
+/// `StakeConsented(uint256,address,uint16,uint16)`
+pub const STAKE_CONSENTED_TOPIC: B256 = StakeConsented::SIGNATURE_HASH;
+/// `JobStaked(uint256,address,uint256,uint256)`
+pub const JOB_STAKED_TOPIC: B256 = JobStaked::SIGNATURE_HASH;
+/// `JobUnstaked(uint256,address,uint256,uint256,uint256,uint256,uint256)`
+pub const JOB_UNSTAKED_TOPIC: B256 = JobUnstaked::SIGNATURE_HASH;
+/// `YieldSplitSet(uint256,uint16,uint16,uint16)`
+pub const YIELD_SPLIT_SET_TOPIC: B256 = YieldSplitSet::SIGNATURE_HASH;
+
+/// Topic 0 of every escrow event, e.g. for log filters
+pub const EVENT_TOPICS: [B256; 128] = [
+    DEPOSITED_TOPIC,
+    RELEASED_TOPIC,
+    REFUNDED_TOPIC,
+    AUTO_RELEASED_TOPIC,
+    EMERGENCY_REFUNDED_TOPIC,
+    PAUSE_TOGGLED_TOPIC,
+    OWNERSHIP_TRANSFERRED_TOPIC,
+    SUNSET_BEGUN_TOPIC,
+    SUNSET_FINALIZED_TOPIC,
+    DORMANCY_POLICY_SET_TOPIC,
+    DORMANCY_ANNOUNCED_TOPIC,
+    DORMANT_SWEPT_TOPIC,
+    PRIVATE_DEPOSITED_TOPIC,
+    JOB_REVEALED_TOPIC,
+    SWAP_ROUTER_SET_TOPIC,
+    PAYOUT_TOKEN_SET_TOPIC,
+    PAYOUT_SWAPPED_TOPIC,
+    PAYOUT_SWAP_FAILED_TOPIC,
+    WITHHOLDING_SET_TOPIC,
+    WITHHELD_TOPIC,
+    COMPLIANCE_HOOK_SET_TOPIC,
+    JOB_ATTESTED_TOPIC,
+    ATTESTATION_POLICY_SET_TOPIC,
+    SETTLEMENT_HOOK_SET_TOPIC,
+    SETTLEMENT_HOOK_FAILED_TOPIC,
+    RELEASE_CONDITION_SET_TOPIC,
+    TRANCHE_CLAIMED_TOPIC,
+    TRANCHE_REFUNDED_TOPIC,
+    RETAINER_STARTED_TOPIC,
+    RETAINER_STOPPED_TOPIC,
+    CANCELLATION_NOTICE_SET_TOPIC,
+    CANCELLATION_REQUESTED_TOPIC,
+    DELIVERY_SUBMITTED_TOPIC,
+    FUNDING_OPENED_TOPIC,
+    JOB_FUNDED_TOPIC,
+    CONTRIBUTION_RECLAIMED_TOPIC,
+    EXCESS_CREDITED_TOPIC,
+    CREDIT_WITHDRAWN_TOPIC,
+    DEFAULT_JOB_CAP_SET_TOPIC,
+    JOB_CAP_SET_TOPIC,
+    PROTOCOL_FEE_SET_TOPIC,
+    FEE_CHARGED_TOPIC,
+    FEE_MANAGER_SET_TOPIC,
+    FEE_TIERS_SET_TOPIC,
+    VOLUME_DISCOUNTS_SET_TOPIC,
+    FEE_EXEMPTION_SET_TOPIC,
+    DISPUTE_OPENED_TOPIC,
+    EVIDENCE_SUBMITTED_TOPIC,
+    DISPUTE_RESOLVED_TOPIC,
+    ARBITRATION_POLICY_SET_TOPIC,
+    RECLAIMED_TOPIC,
+    FUNDS_RETURNED_TOPIC,
+    DUAL_APPROVAL_REQUIRED_TOPIC,
+    RELEASE_APPROVED_TOPIC,
+    DISPUTE_TIMED_OUT_TOPIC,
+    DISPUTE_TIMEOUT_POLICY_SET_TOPIC,
+    JOB_AMENDED_TOPIC,
+    FEES_WITHDRAWN_TOPIC,
+    TREASURY_PROPOSED_TOPIC,
+    TREASURY_SET_TOPIC,
+    GUARDIANS_SET_TOPIC,
+    EMERGENCY_PROPOSED_TOPIC,
+    EMERGENCY_CONFIRMED_TOPIC,
+    SURPLUS_SWEPT_TOPIC,
+    PAUSE_EXTENDED_TOPIC,
+    CLIENT_TRANSFER_PROPOSED_TOPIC,
+    JOB_ACCEPTED_TOPIC,
+    FREELANCER_CHANGED_TOPIC,
+    PAYOUT_ADDRESS_PROPOSED_TOPIC,
+    PAYOUT_ADDRESS_SET_TOPIC,
+    GOVERNANCE_SET_TOPIC,
+    GOVERNANCE_PROPOSED_TOPIC,
+    GOVERNANCE_VOTED_TOPIC,
+    GOVERNANCE_EXECUTED_TOPIC,
+    PAUSER_SET_TOPIC,
+    MAX_PAUSE_SET_TOPIC,
+    RENOUNCE_PROPOSED_TOPIC,
+    PLATFORM_FEE_SET_TOPIC,
+    CREDIT_DEPOSITED_TOPIC,
+    OFFER_CANCELLED_TOPIC,
+    INVOICE_CREATED_TOPIC,
+    INVOICE_FUNDED_TOPIC,
+    INVOICE_PRUNED_TOPIC,
+    PARTIALLY_RELEASED_TOPIC,
+    HOLDBACK_SET_TOPIC,
+    HOLDBACK_RETAINED_TOPIC,
+    CLAWBACK_WINDOW_SET_TOPIC,
+    RELEASE_QUEUED_TOPIC,
+    RELEASE_REVERSED_TOPIC,
+    SLA_SET_TOPIC,
+    LATE_REFUNDED_TOPIC,
+    HOURLY_RATE_SET_TOPIC,
+    TIMESHEET_SUBMITTED_TOPIC,
+    TIMESHEET_APPROVED_TOPIC,
+    TIMESHEET_REJECTED_TOPIC,
+    BASKET_TOKEN_SET_TOPIC,
+    BASKET_FUNDED_TOPIC,
+    BASKET_PAID_TOPIC,
+    REFUND_ROOT_SET_TOPIC,
+    EMERGENCY_REFUND_CLAIMED_TOPIC,
+    EMERGENCY_REFUND_BATCH_TOPIC,
+    JOB_RATED_TOPIC,
+    SETTLEMENT_REBATE_SET_TOPIC,
+    SETTLEMENT_REBATED_TOPIC,
+    PAYOUT_PUSHED_TOPIC,
+    DEPOSIT_LIMITS_SET_TOPIC,
+    DEPOSIT_LIMIT_EXEMPTION_SET_TOPIC,
+    DISPUTE_STAKE_POLICY_SET_TOPIC,
+    DISPUTE_STAKE_SETTLED_TOPIC,
+    AUTHORIZATION_CANCELLED_TOPIC,
+    JURY_POLICY_SET_TOPIC,
+    JUROR_STAKED_TOPIC,
+    JUROR_LEFT_TOPIC,
+    JURY_DRAWN_TOPIC,
+    JURY_VOTED_TOPIC,
+    JURY_TALLIED_TOPIC,
+    APPEAL_POLICY_SET_TOPIC,
+    RULING_HELD_TOPIC,
+    RULING_APPEALED_TOPIC,
+    APPEAL_BOND_SETTLED_TOPIC,
+    JOB_ARBITRATOR_SET_TOPIC,
+    ARBITRATOR_LISTED_TOPIC,
+    ARBITRATOR_REPLACED_TOPIC,
+    STAKING_TOKEN_SET_TOPIC,
+    STAKE_CONSENTED_TOPIC,
+    JOB_STAKED_TOPIC,
+    JOB_UNSTAKED_TOPIC,
+    YIELD_SPLIT_SET_TOPIC,
+];
+
+/// Any event emitted by the escrow
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EscrowEvent {
+    Deposited(Deposited),
+    Released(Released),
+    Refunded(Refunded),
+    AutoReleased(AutoReleased),
+    EmergencyRefunded(EmergencyRefunded),
+    PauseToggled(PauseToggled),
+    OwnershipTransferred(OwnershipTransferred),
+    SunsetBegun(SunsetBegun),
+    SunsetFinalized(SunsetFinalized),
+    DormancyPolicySet(DormancyPolicySet),
+    DormancyAnnounced(DormancyAnnounced),
+    DormantSwept(DormantSwept),
+    PrivateDeposited(PrivateDeposited),
+    JobRevealed(JobRevealed),
+    SwapRouterSet(SwapRouterSet),
+    PayoutTokenSet(PayoutTokenSet),
+    PayoutSwapped(PayoutSwapped),
+    PayoutSwapFailed(PayoutSwapFailed),
+    WithholdingSet(WithholdingSet),
+    Withheld(Withheld),
+    ComplianceHookSet(ComplianceHookSet),
+    JobAttested(JobAttested),
+    AttestationPolicySet(AttestationPolicySet),
+    SettlementHookSet(SettlementHookSet),
+    SettlementHookFailed(SettlementHookFailed),
+    ReleaseConditionSet(ReleaseConditionSet),
+    TrancheClaimed(TrancheClaimed),
+    TrancheRefunded(TrancheRefunded),
+    RetainerStarted(RetainerStarted),
+    RetainerStopped(RetainerStopped),
+    CancellationNoticeSet(CancellationNoticeSet),
+    CancellationRequested(CancellationRequested),
+    DeliverySubmitted(DeliverySubmitted),
+    FundingOpened(FundingOpened),
+    JobFunded(JobFunded),
+    ContributionReclaimed(ContributionReclaimed),
+    ExcessCredited(ExcessCredited),
+    CreditWithdrawn(CreditWithdrawn),
+    DefaultJobCapSet(DefaultJobCapSet),
+    JobCapSet(JobCapSet),
+    ProtocolFeeSet(ProtocolFeeSet),
+    FeeCharged(FeeCharged),
+    FeeManagerSet(FeeManagerSet),
+    FeeTiersSet(FeeTiersSet),
+    VolumeDiscountsSet(VolumeDiscountsSet),
+    FeeExemptionSet(FeeExemptionSet),
+    DisputeOpened(DisputeOpened),
+    EvidenceSubmitted(EvidenceSubmitted),
+    DisputeResolved(DisputeResolved),
+    ArbitrationPolicySet(ArbitrationPolicySet),
+    Reclaimed(Reclaimed),
+    FundsReturned(FundsReturned),
+    DualApprovalRequired(DualApprovalRequired),
+    ReleaseApproved(ReleaseApproved),
+    DisputeTimedOut(DisputeTimedOut),
+    DisputeTimeoutPolicySet(DisputeTimeoutPolicySet),
+    JobAmended(JobAmended),
+    FeesWithdrawn(FeesWithdrawn),
+    TreasuryProposed(TreasuryProposed),
+    TreasurySet(TreasurySet),
+    GuardiansSet(GuardiansSet),
+    EmergencyProposed(EmergencyProposed),
+    EmergencyConfirmed(EmergencyConfirmed),
+    SurplusSwept(SurplusSwept),
+    PauseExtended(PauseExtended),
+    ClientTransferProposed(ClientTransferProposed),
+    JobAccepted(JobAccepted),
+    FreelancerChanged(FreelancerChanged),
+    PayoutAddressProposed(PayoutAddressProposed),
+    PayoutAddressSet(PayoutAddressSet),
+    GovernanceSet(GovernanceSet),
+    GovernanceProposed(GovernanceProposed),
+    GovernanceVoted(GovernanceVoted),
+    GovernanceExecuted(GovernanceExecuted),
+    PauserSet(PauserSet),
+    MaxPauseSet(MaxPauseSet),
+    RenounceProposed(RenounceProposed),
+    PlatformFeeSet(PlatformFeeSet),
+    CreditDeposited(CreditDeposited),
+    OfferCancelled(OfferCancelled),
+    InvoiceCreated(InvoiceCreated),
+    InvoiceFunded(InvoiceFunded),
+    InvoicePruned(InvoicePruned),
+    PartiallyReleased(PartiallyReleased),
+    HoldbackSet(HoldbackSet),
+    HoldbackRetained(HoldbackRetained),
+    ClawbackWindowSet(ClawbackWindowSet),
+    ReleaseQueued(ReleaseQueued),
+    ReleaseReversed(ReleaseReversed),
+    SlaSet(SlaSet),
+    LateRefunded(LateRefunded),
+    HourlyRateSet(HourlyRateSet),
+    TimesheetSubmitted(TimesheetSubmitted),
+    TimesheetApproved(TimesheetApproved),
+    TimesheetRejected(TimesheetRejected),
+    BasketTokenSet(BasketTokenSet),
+    BasketFunded(BasketFunded),
+    BasketPaid(BasketPaid),
+    RefundRootSet(RefundRootSet),
+    EmergencyRefundClaimed(EmergencyRefundClaimed),
+    EmergencyRefundBatch(EmergencyRefundBatch),
+    JobRated(JobRated),
+    SettlementRebateSet(SettlementRebateSet),
+    SettlementRebated(SettlementRebated),
+    PayoutPushed(PayoutPushed),
+    DepositLimitsSet(DepositLimitsSet),
+    DepositLimitExemptionSet(DepositLimitExemptionSet),
+    DisputeStakePolicySet(DisputeStakePolicySet),
+    DisputeStakeSettled(DisputeStakeSettled),
+    AuthorizationCancelled(AuthorizationCancelled),
+    JuryPolicySet(JuryPolicySet),
+    JurorStaked(JurorStaked),
+    JurorLeft(JurorLeft),
+    JuryDrawn(JuryDrawn),
+    JuryVoted(JuryVoted),
+    JuryTallied(JuryTallied),
+    AppealPolicySet(AppealPolicySet),
+    RulingHeld(RulingHeld),
+    RulingAppealed(RulingAppealed),
+    AppealBondSettled(AppealBondSettled),
+    JobArbitratorSet(JobArbitratorSet),
+    ArbitratorListed(ArbitratorListed),
+    ArbitratorReplaced(ArbitratorReplaced),
+    StakingTokenSet(StakingTokenSet),
+    StakeConsented(StakeConsented),
+    JobStaked(JobStaked),
+    JobUnstaked(JobUnstaked),
+    YieldSplitSet(YieldSplitSet),
+}
+
+impl EscrowEvent {
+    /// Decode a raw log, returning `None` for unrelated or malformed logs
+    pub fn decode(topics: &[B256], data: &[u8]) -> Option<Self> {
+        let t = topics.iter().copied();
+        Some(match *topics.first()? {
+            DEPOSITED_TOPIC => Self::Deposited(Deposited::decode_raw_log(t, data, true).ok()?),
+            RELEASED_TOPIC => Self::Released(Released::decode_raw_log(t, data, true).ok()?),
+            REFUNDED_TOPIC => Self::Refunded(Refunded::decode_raw_log(t, data, true).ok()?),
+            AUTO_RELEASED_TOPIC => {
+                Self::AutoReleased(AutoReleased::decode_raw_log(t, data, true).ok()?)
+            }
+            EMERGENCY_REFUNDED_TOPIC => {
+                Self::EmergencyRefunded(EmergencyRefunded::decode_raw_log(t, data, true).ok()?)
+            }
+            PAUSE_TOGGLED_TOPIC => {
+                Self::PauseToggled(PauseToggled::decode_raw_log(t, data, true).ok()?)
+            }
+            OWNERSHIP_TRANSFERRED_TOPIC => Self::OwnershipTransferred(
+                OwnershipTransferred::decode_raw_log(t, data, true).ok()?,
+            ),
+            SUNSET_BEGUN_TOPIC => {
+                Self::SunsetBegun(SunsetBegun::decode_raw_log(t, data, true).ok()?)
+            }
+            SUNSET_FINALIZED_TOPIC => {
+                Self::SunsetFinalized(SunsetFinalized::decode_raw_log(t, data, true).ok()?)
+            }
+            DORMANCY_POLICY_SET_TOPIC => {
+                Self::DormancyPolicySet(DormancyPolicySet::decode_raw_log(t, data, true).ok()?)
+            }
+            DORMANCY_ANNOUNCED_TOPIC => {
+                Self::DormancyAnnounced(DormancyAnnounced::decode_raw_log(t, data, true).ok()?)
+            }
+            DORMANT_SWEPT_TOPIC => {
+                Self::DormantSwept(DormantSwept::decode_raw_log(t, data, true).ok()?)
+            }
+            PRIVATE_DEPOSITED_TOPIC => {
+                Self::PrivateDeposited(PrivateDeposited::decode_raw_log(t, data, true).ok()?)
+            }
+            JOB_REVEALED_TOPIC => {
+                Self::JobRevealed(JobRevealed::decode_raw_log(t, data, true).ok()?)
+            }
+            SWAP_ROUTER_SET_TOPIC => {
+                Self::SwapRouterSet(SwapRouterSet::decode_raw_log(t, data, true).ok()?)
+            }
+            PAYOUT_TOKEN_SET_TOPIC => {
+                Self::PayoutTokenSet(PayoutTokenSet::decode_raw_log(t, data, true).ok()?)
+            }
+            PAYOUT_SWAPPED_TOPIC => {
+                Self::PayoutSwapped(PayoutSwapped::decode_raw_log(t, data, true).ok()?)
+            }
+            PAYOUT_SWAP_FAILED_TOPIC => {
+                Self::PayoutSwapFailed(PayoutSwapFailed::decode_raw_log(t, data, true).ok()?)
+            }
+            WITHHOLDING_SET_TOPIC => {
+                Self::WithholdingSet(WithholdingSet::decode_raw_log(t, data, true).ok()?)
+            }
+            WITHHELD_TOPIC => Self::Withheld(Withheld::decode_raw_log(t, data, true).ok()?),
+            COMPLIANCE_HOOK_SET_TOPIC => {
+                Self::ComplianceHookSet(ComplianceHookSet::decode_raw_log(t, data, true).ok()?)
+            }
+            JOB_ATTESTED_TOPIC => {
+                Self::JobAttested(JobAttested::decode_raw_log(t, data, true).ok()?)
+            }
+            ATTESTATION_POLICY_SET_TOPIC => Self::AttestationPolicySet(
+                AttestationPolicySet::decode_raw_log(t, data, true).ok()?,
+            ),
+            SETTLEMENT_HOOK_SET_TOPIC => {
+                Self::SettlementHookSet(SettlementHookSet::decode_raw_log(t, data, true).ok()?)
+            }
+            SETTLEMENT_HOOK_FAILED_TOPIC => Self::SettlementHookFailed(
+                SettlementHookFailed::decode_raw_log(t, data, true).ok()?,
+            ),
+            RELEASE_CONDITION_SET_TOPIC => {
+                Self::ReleaseConditionSet(ReleaseConditionSet::decode_raw_log(t, data, true).ok()?)
+            }
+            TRANCHE_CLAIMED_TOPIC => {
+                Self::TrancheClaimed(TrancheClaimed::decode_raw_log(t, data, true).ok()?)
+            }
+            TRANCHE_REFUNDED_TOPIC => {
+                Self::TrancheRefunded(TrancheRefunded::decode_raw_log(t, data, true).ok()?)
+            }
+            RETAINER_STARTED_TOPIC => {
+                Self::RetainerStarted(RetainerStarted::decode_raw_log(t, data, true).ok()?)
+            }
+            RETAINER_STOPPED_TOPIC => {
+                Self::RetainerStopped(RetainerStopped::decode_raw_log(t, data, true).ok()?)
+            }
+            CANCELLATION_NOTICE_SET_TOPIC => Self::CancellationNoticeSet(
+                CancellationNoticeSet::decode_raw_log(t, data, true).ok()?,
+            ),
+            CANCELLATION_REQUESTED_TOPIC => Self::CancellationRequested(
+                CancellationRequested::decode_raw_log(t, data, true).ok()?,
+            ),
+            DELIVERY_SUBMITTED_TOPIC => {
+                Self::DeliverySubmitted(DeliverySubmitted::decode_raw_log(t, data, true).ok()?)
+            }
+            FUNDING_OPENED_TOPIC => {
+                Self::FundingOpened(FundingOpened::decode_raw_log(t, data, true).ok()?)
+            }
+            JOB_FUNDED_TOPIC => Self::JobFunded(JobFunded::decode_raw_log(t, data, true).ok()?),
+            CONTRIBUTION_RECLAIMED_TOPIC => Self::ContributionReclaimed(
+                ContributionReclaimed::decode_raw_log(t, data, true).ok()?,
+            ),
+            EXCESS_CREDITED_TOPIC => {
+                Self::ExcessCredited(ExcessCredited::decode_raw_log(t, data, true).ok()?)
+            }
+            CREDIT_WITHDRAWN_TOPIC => {
+                Self::CreditWithdrawn(CreditWithdrawn::decode_raw_log(t, data, true).ok()?)
+            }
+            DEFAULT_JOB_CAP_SET_TOPIC => {
+                Self::DefaultJobCapSet(DefaultJobCapSet::decode_raw_log(t, data, true).ok()?)
+            }
+            JOB_CAP_SET_TOPIC => Self::JobCapSet(JobCapSet::decode_raw_log(t, data, true).ok()?),
+            PROTOCOL_FEE_SET_TOPIC => {
+                Self::ProtocolFeeSet(ProtocolFeeSet::decode_raw_log(t, data, true).ok()?)
+            }
+            FEE_CHARGED_TOPIC => Self::FeeCharged(FeeCharged::decode_raw_log(t, data, true).ok()?),
+            FEE_MANAGER_SET_TOPIC => {
+                Self::FeeManagerSet(FeeManagerSet::decode_raw_log(t, data, true).ok()?)
+            }
+            FEE_TIERS_SET_TOPIC => {
+                Self::FeeTiersSet(FeeTiersSet::decode_raw_log(t, data, true).ok()?)
+            }
+            VOLUME_DISCOUNTS_SET_TOPIC => {
+                Self::VolumeDiscountsSet(VolumeDiscountsSet::decode_raw_log(t, data, true).ok()?)
+            }
+            FEE_EXEMPTION_SET_TOPIC => {
+                Self::FeeExemptionSet(FeeExemptionSet::decode_raw_log(t, data, true).ok()?)
+            }
+            DISPUTE_OPENED_TOPIC => {
+                Self::DisputeOpened(DisputeOpened::decode_raw_log(t, data, true).ok()?)
+            }
+            EVIDENCE_SUBMITTED_TOPIC => {
+                Self::EvidenceSubmitted(EvidenceSubmitted::decode_raw_log(t, data, true).ok()?)
+            }
+            DISPUTE_RESOLVED_TOPIC => {
+                Self::DisputeResolved(DisputeResolved::decode_raw_log(t, data, true).ok()?)
+            }
+            ARBITRATION_POLICY_SET_TOPIC => Self::ArbitrationPolicySet(
+                ArbitrationPolicySet::decode_raw_log(t, data, true).ok()?,
+            ),
+            RECLAIMED_TOPIC => Self::Reclaimed(Reclaimed::decode_raw_log(t, data, true).ok()?),
+            FUNDS_RETURNED_TOPIC => {
+                Self::FundsReturned(FundsReturned::decode_raw_log(t, data, true).ok()?)
+            }
+            DUAL_APPROVAL_REQUIRED_TOPIC => Self::DualApprovalRequired(
+                DualApprovalRequired::decode_raw_log(t, data, true).ok()?,
+            ),
+            RELEASE_APPROVED_TOPIC => {
+                Self::ReleaseApproved(ReleaseApproved::decode_raw_log(t, data, true).ok()?)
+            }
+            DISPUTE_TIMED_OUT_TOPIC => {
+                Self::DisputeTimedOut(DisputeTimedOut::decode_raw_log(t, data, true).ok()?)
+            }
+            DISPUTE_TIMEOUT_POLICY_SET_TOPIC => Self::DisputeTimeoutPolicySet(
+                DisputeTimeoutPolicySet::decode_raw_log(t, data, true).ok()?,
+            ),
+            JOB_AMENDED_TOPIC => Self::JobAmended(JobAmended::decode_raw_log(t, data, true).ok()?),
+            FEES_WITHDRAWN_TOPIC => Self::FeesWithdrawn(FeesWithdrawn::decode_raw_log(t, data, true).ok()?),
+            TREASURY_PROPOSED_TOPIC => Self::TreasuryProposed(TreasuryProposed::decode_raw_log(t, data, true).ok()?),
+            TREASURY_SET_TOPIC => Self::TreasurySet(TreasurySet::decode_raw_log(t, data, true).ok()?),
+            GUARDIANS_SET_TOPIC => Self::GuardiansSet(GuardiansSet::decode_raw_log(t, data, true).ok()?),
+            EMERGENCY_PROPOSED_TOPIC => Self::EmergencyProposed(EmergencyProposed::decode_raw_log(t, data, true).ok()?),
+            EMERGENCY_CONFIRMED_TOPIC => Self::EmergencyConfirmed(EmergencyConfirmed::decode_raw_log(t, data, true).ok()?),
+            SURPLUS_SWEPT_TOPIC => Self::SurplusSwept(SurplusSwept::decode_raw_log(t, data, true).ok()?),
+            PAUSE_EXTENDED_TOPIC => Self::PauseExtended(PauseExtended::decode_raw_log(t, data, true).ok()?),
+            CLIENT_TRANSFER_PROPOSED_TOPIC => Self::ClientTransferProposed(ClientTransferProposed::decode_raw_log(t, data, true).ok()?),
+            JOB_ACCEPTED_TOPIC => Self::JobAccepted(JobAccepted::decode_raw_log(t, data, true).ok()?),
+            FREELANCER_CHANGED_TOPIC => Self::FreelancerChanged(FreelancerChanged::decode_raw_log(t, data, true).ok()?),
+            PAYOUT_ADDRESS_PROPOSED_TOPIC => Self::PayoutAddressProposed(PayoutAddressProposed::decode_raw_log(t, data, true).ok()?),
+            PAYOUT_ADDRESS_SET_TOPIC => Self::PayoutAddressSet(PayoutAddressSet::decode_raw_log(t, data, true).ok()?),
+            GOVERNANCE_SET_TOPIC => Self::GovernanceSet(GovernanceSet::decode_raw_log(t, data, true).ok()?),
+            GOVERNANCE_PROPOSED_TOPIC => Self::GovernanceProposed(GovernanceProposed::decode_raw_log(t, data, true).ok()?),
+            GOVERNANCE_VOTED_TOPIC => Self::GovernanceVoted(GovernanceVoted::decode_raw_log(t, data, true).ok()?),
+            GOVERNANCE_EXECUTED_TOPIC => Self::GovernanceExecuted(GovernanceExecuted::decode_raw_log(t, data, true).ok()?),
+            PAUSER_SET_TOPIC => Self::PauserSet(PauserSet::decode_raw_log(t, data, true).ok()?),
+            MAX_PAUSE_SET_TOPIC => Self::MaxPauseSet(MaxPauseSet::decode_raw_log(t, data, true).ok()?),
+            RENOUNCE_PROPOSED_TOPIC => Self::RenounceProposed(RenounceProposed::decode_raw_log(t, data, true).ok()?),
+            PLATFORM_FEE_SET_TOPIC => Self::PlatformFeeSet(PlatformFeeSet::decode_raw_log(t, data, true).ok()?),
+            CREDIT_DEPOSITED_TOPIC => Self::CreditDeposited(CreditDeposited::decode_raw_log(t, data, true).ok()?),
+            OFFER_CANCELLED_TOPIC => Self::OfferCancelled(OfferCancelled::decode_raw_log(t, data, true).ok()?),
+            INVOICE_CREATED_TOPIC => Self::InvoiceCreated(InvoiceCreated::decode_raw_log(t, data, true).ok()?),
+            INVOICE_FUNDED_TOPIC => Self::InvoiceFunded(InvoiceFunded::decode_raw_log(t, data, true).ok()?),
+            INVOICE_PRUNED_TOPIC => Self::InvoicePruned(InvoicePruned::decode_raw_log(t, data, true).ok()?),
+            PARTIALLY_RELEASED_TOPIC => Self::PartiallyReleased(PartiallyReleased::decode_raw_log(t, data, true).ok()?),
+            HOLDBACK_SET_TOPIC => Self::HoldbackSet(HoldbackSet::decode_raw_log(t, data, true).ok()?),
+            HOLDBACK_RETAINED_TOPIC => Self::HoldbackRetained(HoldbackRetained::decode_raw_log(t, data, true).ok()?),
+            CLAWBACK_WINDOW_SET_TOPIC => Self::ClawbackWindowSet(ClawbackWindowSet::decode_raw_log(t, data, true).ok()?),
+            RELEASE_QUEUED_TOPIC => Self::ReleaseQueued(ReleaseQueued::decode_raw_log(t, data, true).ok()?),
+            RELEASE_REVERSED_TOPIC => Self::ReleaseReversed(ReleaseReversed::decode_raw_log(t, data, true).ok()?),
+            SLA_SET_TOPIC => Self::SlaSet(SlaSet::decode_raw_log(t, data, true).ok()?),
+            LATE_REFUNDED_TOPIC => Self::LateRefunded(LateRefunded::decode_raw_log(t, data, true).ok()?),
+            HOURLY_RATE_SET_TOPIC => Self::HourlyRateSet(HourlyRateSet::decode_raw_log(t, data, true).ok()?),
+            TIMESHEET_SUBMITTED_TOPIC => Self::TimesheetSubmitted(TimesheetSubmitted::decode_raw_log(t, data, true).ok()?),
+            TIMESHEET_APPROVED_TOPIC => Self::TimesheetApproved(TimesheetApproved::decode_raw_log(t, data, true).ok()?),
+            TIMESHEET_REJECTED_TOPIC => Self::TimesheetRejected(TimesheetRejected::decode_raw_log(t, data, true).ok()?),
+            BASKET_TOKEN_SET_TOPIC => Self::BasketTokenSet(BasketTokenSet::decode_raw_log(t, data, true).ok()?),
+            BASKET_FUNDED_TOPIC => Self::BasketFunded(BasketFunded::decode_raw_log(t, data, true).ok()?),
+            BASKET_PAID_TOPIC => Self::BasketPaid(BasketPaid::decode_raw_log(t, data, true).ok()?),
+            REFUND_ROOT_SET_TOPIC => Self::RefundRootSet(RefundRootSet::decode_raw_log(t, data, true).ok()?),
+            EMERGENCY_REFUND_CLAIMED_TOPIC => Self::EmergencyRefundClaimed(EmergencyRefundClaimed::decode_raw_log(t, data, true).ok()?),
+            EMERGENCY_REFUND_BATCH_TOPIC => Self::EmergencyRefundBatch(EmergencyRefundBatch::decode_raw_log(t, data, true).ok()?),
+            JOB_RATED_TOPIC => Self::JobRated(JobRated::decode_raw_log(t, data, true).ok()?),
+            SETTLEMENT_REBATE_SET_TOPIC => Self::SettlementRebateSet(SettlementRebateSet::decode_raw_log(t, data, true).ok()?),
+            SETTLEMENT_REBATED_TOPIC => Self::SettlementRebated(SettlementRebated::decode_raw_log(t, data, true).ok()?),
+            PAYOUT_PUSHED_TOPIC => Self::PayoutPushed(PayoutPushed::decode_raw_log(t, data, true).ok()?),
+            DEPOSIT_LIMITS_SET_TOPIC => Self::DepositLimitsSet(DepositLimitsSet::decode_raw_log(t, data, true).ok()?),
+            DEPOSIT_LIMIT_EXEMPTION_SET_TOPIC => Self::DepositLimitExemptionSet(DepositLimitExemptionSet::decode_raw_log(t, data, true).ok()?),
+            DISPUTE_STAKE_POLICY_SET_TOPIC => Self::DisputeStakePolicySet(DisputeStakePolicySet::decode_raw_log(t, data, true).ok()?),
+            DISPUTE_STAKE_SETTLED_TOPIC => Self::DisputeStakeSettled(DisputeStakeSettled::decode_raw_log(t, data, true).ok()?),
+            AUTHORIZATION_CANCELLED_TOPIC => Self::AuthorizationCancelled(AuthorizationCancelled::decode_raw_log(t, data, true).ok()?),
+            JURY_POLICY_SET_TOPIC => Self::JuryPolicySet(JuryPolicySet::decode_raw_log(t, data, true).ok()?),
+            JUROR_STAKED_TOPIC => Self::JurorStaked(JurorStaked::decode_raw_log(t, data, true).ok()?),
+            JUROR_LEFT_TOPIC => Self::JurorLeft(JurorLeft::decode_raw_log(t, data, true).ok()?),
+            JURY_DRAWN_TOPIC => Self::JuryDrawn(JuryDrawn::decode_raw_log(t, data, true).ok()?),
+            JURY_VOTED_TOPIC => Self::JuryVoted(JuryVoted::decode_raw_log(t, data, true).ok()?),
+            JURY_TALLIED_TOPIC => Self::JuryTallied(JuryTallied::decode_raw_log(t, data, true).ok()?),
+            APPEAL_POLICY_SET_TOPIC => Self::AppealPolicySet(AppealPolicySet::decode_raw_log(t, data, true).ok()?),
+            RULING_HELD_TOPIC => Self::RulingHeld(RulingHeld::decode_raw_log(t, data, true).ok()?),
+            RULING_APPEALED_TOPIC => Self::RulingAppealed(RulingAppealed::decode_raw_log(t, data, true).ok()?),
+            APPEAL_BOND_SETTLED_TOPIC => Self::AppealBondSettled(AppealBondSettled::decode_raw_log(t, data, true).ok()?),
+            JOB_ARBITRATOR_SET_TOPIC => Self::JobArbitratorSet(JobArbitratorSet::decode_raw_log(t, data, true).ok()?),
+            ARBITRATOR_LISTED_TOPIC => Self::ArbitratorListed(ArbitratorListed::decode_raw_log(t, data, true).ok()?),
+            ARBITRATOR_REPLACED_TOPIC => Self::ArbitratorReplaced(ArbitratorReplaced::decode_raw_log(t, data, true).ok()?),
+            STAKING_TOKEN_SET_TOPIC => Self::StakingTokenSet(StakingTokenSet::decode_raw_log(t, data, true).ok()?),
+            STAKE_CONSENTED_TOPIC => Self::StakeConsented(StakeConsented::decode_raw_log(t, data, true).ok()?),
+            JOB_STAKED_TOPIC => Self::JobStaked(JobStaked::decode_raw_log(t, data, true).ok()?),
+            JOB_UNSTAKED_TOPIC => Self::JobUnstaked(JobUnstaked::decode_raw_log(t, data, true).ok()?),
+            YIELD_SPLIT_SET_TOPIC => Self::YieldSplitSet(YieldSplitSet::decode_raw_log(t, data, true).ok()?),
+            _ => return None,
+        })
+    }
+
+    /// The job this event refers to, if any
+    pub fn job_id(&self) -> Option<U256> {
+        match self {
+            Self::Deposited(e) => Some(e.job_id),
+            Self::Released(e) => Some(e.job_id),
+            Self::Refunded(e) => Some(e.job_id),
+            Self::AutoReleased(e) => Some(e.job_id),
+            Self::EmergencyRefunded(e) => Some(e.job_id),
+            Self::DormancyAnnounced(e) => Some(e.job_id),
+            Self::DormantSwept(e) => Some(e.job_id),
+            Self::PrivateDeposited(e) => Some(e.job_id),
+            Self::JobRevealed(e) => Some(e.job_id),
+            Self::PayoutSwapped(e) => Some(e.job_id),
+            Self::PayoutSwapFailed(e) => Some(e.job_id),
+            Self::Withheld(e) => Some(e.job_id),
+            Self::JobAttested(e) => Some(e.job_id),
+            Self::SettlementHookSet(e) => Some(e.job_id),
+            Self::SettlementHookFailed(e) => Some(e.job_id),
+            Self::ReleaseConditionSet(e) => Some(e.job_id),
+            Self::TrancheClaimed(e) => Some(e.job_id),
+            Self::TrancheRefunded(e) => Some(e.job_id),
+            Self::RetainerStarted(e) => Some(e.job_id),
+            Self::RetainerStopped(e) => Some(e.job_id),
+            Self::CancellationNoticeSet(e) => Some(e.job_id),
+            Self::CancellationRequested(e) => Some(e.job_id),
+            Self::DeliverySubmitted(e) => Some(e.job_id),
+            Self::FundingOpened(e) => Some(e.job_id),
+            Self::JobFunded(e) => Some(e.job_id),
+            Self::ContributionReclaimed(e) => Some(e.job_id),
+            Self::ExcessCredited(e) => Some(e.job_id),
+            Self::FeeCharged(e) => Some(e.job_id),
+            Self::DisputeOpened(e) => Some(e.job_id),
+            Self::EvidenceSubmitted(e) => Some(e.job_id),
+            Self::DisputeResolved(e) => Some(e.job_id),
+            Self::Reclaimed(e) => Some(e.job_id),
+            Self::FundsReturned(e) => Some(e.job_id),
+            Self::DualApprovalRequired(e) => Some(e.job_id),
+            Self::ReleaseApproved(e) => Some(e.job_id),
+            Self::DisputeTimedOut(e) => Some(e.job_id),
+            Self::JobAmended(e) => Some(e.job_id),
+            Self::ClientTransferProposed(e) => Some(e.job_id),
+            Self::JobAccepted(e) => Some(e.job_id),
+            Self::FreelancerChanged(e) => Some(e.job_id),
+            Self::PlatformFeeSet(e) => Some(e.job_id),
+            Self::InvoiceFunded(e) => Some(e.job_id),
+            Self::PartiallyReleased(e) => Some(e.job_id),
+            Self::HoldbackSet(e) => Some(e.job_id),
+            Self::HoldbackRetained(e) => Some(e.job_id),
+            Self::ClawbackWindowSet(e) => Some(e.job_id),
+            Self::ReleaseQueued(e) => Some(e.job_id),
+            Self::ReleaseReversed(e) => Some(e.job_id),
+            Self::SlaSet(e) => Some(e.job_id),
+            Self::LateRefunded(e) => Some(e.job_id),
+            Self::HourlyRateSet(e) => Some(e.job_id),
+            Self::TimesheetSubmitted(e) => Some(e.job_id),
+            Self::TimesheetApproved(e) => Some(e.job_id),
+            Self::TimesheetRejected(e) => Some(e.job_id),
+            Self::BasketFunded(e) => Some(e.job_id),
+            Self::BasketPaid(e) => Some(e.job_id),
+            Self::EmergencyRefundClaimed(e) => Some(e.job_id),
+            Self::JobRated(e) => Some(e.job_id),
+            Self::SettlementRebated(e) => Some(e.job_id),
+            Self::PayoutPushed(e) => Some(e.job_id),
+            Self::DisputeStakeSettled(e) => Some(e.job_id),
+            Self::JuryDrawn(e) => Some(e.job_id),
+            Self::JuryVoted(e) => Some(e.job_id),
+            Self::JuryTallied(e) => Some(e.job_id),
+            Self::RulingHeld(e) => Some(e.job_id),
+            Self::RulingAppealed(e) => Some(e.job_id),
+            Self::AppealBondSettled(e) => Some(e.job_id),
+            Self::JobArbitratorSet(e) => Some(e.job_id),
+            Self::ArbitratorReplaced(e) => Some(e.job_id),
+            Self::StakeConsented(e) => Some(e.job_id),
+            Self::JobStaked(e) => Some(e.job_id),
+            Self::JobUnstaked(e) => Some(e.job_id),
+            Self::YieldSplitSet(e) => Some(e.job_id),
+            Self::PauseToggled(_)
+            | Self::OwnershipTransferred(_)
+            | Self::SunsetBegun(_)
+            | Self::SunsetFinalized(_)
+            | Self::DormancyPolicySet(_)
+            | Self::SwapRouterSet(_)
+            | Self::PayoutTokenSet(_)
+            | Self::WithholdingSet(_)
+            | Self::ComplianceHookSet(_)
+            | Self::AttestationPolicySet(_)
+            | Self::CreditWithdrawn(_)
+            | Self::DefaultJobCapSet(_)
+            | Self::JobCapSet(_)
+            | Self::ProtocolFeeSet(_)
+            | Self::FeeManagerSet(_)
+            | Self::FeeTiersSet(_)
+            | Self::VolumeDiscountsSet(_)
+            | Self::FeeExemptionSet(_)
+            | Self::ArbitrationPolicySet(_)
+            | Self::DisputeTimeoutPolicySet(_)
+            | Self::FeesWithdrawn(_)
+            | Self::TreasuryProposed(_)
+            | Self::TreasurySet(_)
+            | Self::GuardiansSet(_)
+            | Self::EmergencyProposed(_)
+            | Self::EmergencyConfirmed(_)
+            | Self::SurplusSwept(_)
+            | Self::PauseExtended(_)
+            | Self::PayoutAddressProposed(_)
+            | Self::PayoutAddressSet(_)
+            | Self::GovernanceSet(_)
+            | Self::GovernanceProposed(_)
+            | Self::GovernanceVoted(_)
+            | Self::GovernanceExecuted(_)
+            | Self::PauserSet(_)
+            | Self::MaxPauseSet(_)
+            | Self::RenounceProposed(_)
+            | Self::CreditDeposited(_)
+            | Self::OfferCancelled(_)
+            | Self::InvoiceCreated(_)
+            | Self::InvoicePruned(_)
+            | Self::BasketTokenSet(_)
+            | Self::RefundRootSet(_)
+            | Self::EmergencyRefundBatch(_)
+            | Self::SettlementRebateSet(_)
+            | Self::DepositLimitsSet(_)
+            | Self::DepositLimitExemptionSet(_)
+            | Self::DisputeStakePolicySet(_)
+            | Self::AuthorizationCancelled(_)
+            | Self::JuryPolicySet(_)
+            | Self::JurorStaked(_)
+            | Self::JurorLeft(_)
+            | Self::AppealPolicySet(_)
+            | Self::ArbitratorListed(_)
+            | Self::StakingTokenSet(_) => None,
+        }
+    }
+}
@@ -0,0 +1,22 @@
+//! Definitions shared by the escrow contract and the tools around it.
+//!
+//! The contract, the client SDK, the indexer and the CLI all depend on this
+//! crate rather than keeping their own copies, so event layouts, error
+//! codes, constants and signed-message hashing can't drift apart between
+//! them. It only needs alloy, so off-chain tools don't pull in the Stylus
+//! SDK with it.
+
+pub mod constants;
+pub mod errors;
+pub mod events;
+mod messages;
+mod status;
+
+pub use constants::*;
+pub use errors::{ErrorCode, EscrowError};
+pub use events::*;
+pub use messages::{
+    job_commitment, offer_domain, offer_hash, refund_leaf, settlement_hash, verdict_hash, JobOffer,
+    Settlement, Verdict,
+};
+pub use status::JobStatus;
@@ -0,0 +1,88 @@
+//! Messages parties sign or commit to off-chain, and their digests.
+//!
+//! The contract recomputes these to check signatures, commitments and
+//! refund proofs, so tools that build them must hash them the same way.
+
+use alloy_primitives::{keccak256, Address, B256, U256};
+use alloy_sol_types::{eip712_domain, sol, Eip712Domain, SolStruct, SolValue};
+
+sol! {
+    /// Terms a client offers a freelancer, signed under [`offer_domain`]
+    #[derive(Debug, PartialEq, Eq)]
+    struct JobOffer {
+        address client;
+        address freelancer;
+        uint256 amount;
+        uint64 duration;
+        bytes32 memo;
+        uint256 nonce;
+        uint64 expiry;
+    }
+
+    /// A release or refund of a job its signer authorizes, signed under
+    /// [`offer_domain`]
+    #[derive(Debug, PartialEq, Eq)]
+    struct Settlement {
+        uint256 job_id;
+        address signer;
+        bool release;
+        bytes32 memo;
+        uint256 nonce;
+        uint64 expiry;
+    }
+
+    /// An arbitrator's ruling on a job's dispute, signed under
+    /// [`offer_domain`]
+    #[derive(Debug, PartialEq, Eq)]
+    struct Verdict {
+        uint256 job_id;
+        uint16 freelancer_bps;
+        uint256 nonce;
+        uint64 expiry;
+    }
+}
+
+/// EIP-712 domain of the escrow at `contract` on `chain_id`
+pub fn offer_domain(chain_id: u64, contract: Address) -> Eip712Domain {
+    eip712_domain! {
+        name: "FreelancePaymentEscrow",
+        version: "1",
+        chain_id: chain_id,
+        verifying_contract: contract,
+    }
+}
+
+/// Digest a client signs to make `offer` through the escrow at `contract`
+pub fn offer_hash(offer: &JobOffer, chain_id: u64, contract: Address) -> B256 {
+    offer.eip712_signing_hash(&offer_domain(chain_id, contract))
+}
+
+/// Digest a party signs to authorize `settlement` through the escrow at
+/// `contract`
+pub fn settlement_hash(settlement: &Settlement, chain_id: u64, contract: Address) -> B256 {
+    settlement.eip712_signing_hash(&offer_domain(chain_id, contract))
+}
+
+/// Digest an arbitrator signs to rule `verdict` through the escrow at
+/// `contract`
+pub fn verdict_hash(verdict: &Verdict, chain_id: u64, contract: Address) -> B256 {
+    verdict.eip712_signing_hash(&offer_domain(chain_id, contract))
+}
+
+/// Commitment a private job is opened with:
+/// `keccak256(abi.encode(client, freelancer, amount, terms, salt))`
+pub fn job_commitment(
+    client: Address,
+    freelancer: Address,
+    amount: U256,
+    terms: B256,
+    salt: B256,
+) -> B256 {
+    keccak256((client, freelancer, amount, terms, salt).abi_encode())
+}
+
+/// Leaf committing to refunding `amount` of `job_id`:
+/// `keccak256(keccak256(abi.encode(job_id, amount)))`
+pub fn refund_leaf(job_id: U256, amount: U256) -> B256 {
+    keccak256(keccak256((job_id, amount).abi_encode()))
+}
@@ -0,0 +1,53 @@
+//! Settlement state of a job.
+
+use crate::{JOB_STATUS_ACTIVE, JOB_STATUS_REFUNDED, JOB_STATUS_RELEASED, JOB_STATUS_SWEPT};
+
+/// Settlement state of a job
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JobStatus {
+    Active,
+    Released,
+    Refunded,
+    /// Swept as dormant; only `query_jobs` reports it, a job from `get_job`
+    /// shows such a job as active
+    Swept,
+}
+
+impl JobStatus {
+    /// The matching `JOB_STATUS_*` constant
+    pub fn code(self) -> u8 {
+        match self {
+            Self::Active => JOB_STATUS_ACTIVE,
+            Self::Released => JOB_STATUS_RELEASED,
+            Self::Refunded => JOB_STATUS_REFUNDED,
+            Self::Swept => JOB_STATUS_SWEPT,
+        }
+    }
+
+    /// The status a `JOB_STATUS_*` constant stands for
+    pub fn from_code(code: u8) -> Option<Self> {
+        [Self::Active, Self::Released, Self::Refunded, Self::Swept]
+            .into_iter()
+            .find(|status| status.code() == code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::JOB_STATUS_ANY;
+
+    #[test]
+    fn test_job_status_codes() {
+        // Statuses round-trip through the contract's codes, which skip "any"
+        for status in [
+            JobStatus::Active,
+            JobStatus::Released,
+            JobStatus::Refunded,
+            JobStatus::Swept,
+        ] {
+            assert_eq!(JobStatus::from_code(status.code()), Some(status));
+        }
+        assert_eq!(JobStatus::from_code(JOB_STATUS_ANY), None);
+    }
+}
@@ -0,0 +1,210 @@
+//! Mintable ERC-20 for exercising token escrow in tests.
+//!
+//! Anyone can mint. `set_fail_transfers` makes `transfer`/`transferFrom`
+//! return `false` instead of reverting, like some non-standard tokens do.
+
+extern crate alloc;
+
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::sol;
+use stylus_sdk::prelude::*;
+
+sol_storage! {
+    #[entrypoint]
+    pub struct MockErc20 {
+        uint256 total_supply;
+        mapping(address => uint256) balances;
+        mapping(address => mapping(address => uint256)) allowances;
+        bool fail_transfers;
+    }
+}
+
+sol! {
+    event Transfer(address indexed from, address indexed to, uint256 value);
+    event Approval(address indexed owner, address indexed spender, uint256 value);
+}
+
+#[public]
+impl MockErc20 {
+    pub fn name(&self) -> String {
+        "Mock Token".into()
+    }
+
+    pub fn symbol(&self) -> String {
+        "MOCK".into()
+    }
+
+    pub fn decimals(&self) -> u8 {
+        18
+    }
+
+    pub fn total_supply(&self) -> U256 {
+        self.total_supply.get()
+    }
+
+    pub fn balance_of(&self, owner: Address) -> U256 {
+        self.balances.get(owner)
+    }
+
+    pub fn allowance(&self, owner: Address, spender: Address) -> U256 {
+        self.allowances.getter(owner).get(spender)
+    }
+
+    pub fn transfer(&mut self, to: Address, value: U256) -> Result<bool, Vec<u8>> {
+        if self.fail_transfers.get() {
+            return Ok(false);
+        }
+        let from = self.vm().msg_sender();
+        self.move_tokens(from, to, value)?;
+        Ok(true)
+    }
+
+    pub fn approve(&mut self, spender: Address, value: U256) -> bool {
+        let owner = self.vm().msg_sender();
+        self.allowances.setter(owner).setter(spender).set(value);
+        log(
+            self.vm(),
+            Approval {
+                owner,
+                spender,
+                value,
+            },
+        );
+        true
+    }
+
+    pub fn transfer_from(
+        &mut self,
+        from: Address,
+        to: Address,
+        value: U256,
+    ) -> Result<bool, Vec<u8>> {
+        if self.fail_transfers.get() {
+            return Ok(false);
+        }
+        let spender = self.vm().msg_sender();
+        let allowed = self.allowances.getter(from).get(spender);
+        if allowed < value {
+            return Err("Insufficient allowance".as_bytes().to_vec());
+        }
+        self.allowances
+            .setter(from)
+            .setter(spender)
+            .set(allowed - value);
+        self.move_tokens(from, to, value)?;
+        Ok(true)
+    }
+
+    /// TEST: create `value` tokens for `to`
+    pub fn mint(&mut self, to: Address, value: U256) {
+        let supply = self.total_supply.get();
+        self.total_supply.set(supply + value);
+        let balance = self.balances.get(to);
+        self.balances.setter(to).set(balance + value);
+        log(
+            self.vm(),
+            Transfer {
+                from: Address::ZERO,
+                to,
+                value,
+            },
+        );
+    }
+
+    /// TEST: make transfers return `false` without moving tokens
+    pub fn set_fail_transfers(&mut self, fail: bool) {
+        self.fail_transfers.set(fail);
+    }
+}
+
+impl MockErc20 {
+    fn move_tokens(&mut self, from: Address, to: Address, value: U256) -> Result<(), Vec<u8>> {
+        let balance = self.balances.get(from);
+        if balance < value {
+            return Err("Insufficient balance".as_bytes().to_vec());
+        }
+        self.balances.setter(from).set(balance - value);
+        let to_balance = self.balances.get(to);
+        self.balances.setter(to).set(to_balance + value);
+        log(self.vm(), Transfer { from, to, value });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use stylus_sdk::testing::*;
+
+    #[test]
+    fn test_mint_and_transfer() {
+        let vm = TestVM::default();
+        let mut token = MockErc20::from(&vm);
+        let owner = vm.msg_sender();
+        let recipient = Address::from([0x01; 20]);
+
+        // Mint to the sender
+        token.mint(owner, U256::from(100));
+        assert_eq!(token.total_supply(), U256::from(100));
+        assert_eq!(token.balance_of(owner), U256::from(100));
+
+        // Transfer part of it
+        assert_eq!(token.transfer(recipient, U256::from(40)), Ok(true));
+        assert_eq!(token.balance_of(owner), U256::from(60));
+        assert_eq!(token.balance_of(recipient), U256::from(40));
+
+        // Cannot overspend
+        assert_eq!(
+            token.transfer(recipient, U256::from(61)).unwrap_err(),
+            b"Insufficient balance".to_vec()
+        );
+        assert_eq!(vm.get_emitted_logs().len(), 2);
+    }
+
+    #[test]
+    fn test_approve_and_transfer_from() {
+        let vm = TestVM::default();
+        let mut token = MockErc20::from(&vm);
+        let owner = Address::from([0x01; 20]);
+        let spender = Address::from([0x02; 20]);
+        let recipient = Address::from([0x03; 20]);
+        token.mint(owner, U256::from(100));
+
+        // Owner approves the spender
+        vm.set_sender(owner);
+        assert!(token.approve(spender, U256::from(50)));
+        assert_eq!(token.allowance(owner, spender), U256::from(50));
+
+        // Spender pulls within the allowance
+        vm.set_sender(spender);
+        assert_eq!(
+            token.transfer_from(owner, recipient, U256::from(30)),
+            Ok(true)
+        );
+        assert_eq!(token.allowance(owner, spender), U256::from(20));
+        assert_eq!(token.balance_of(recipient), U256::from(30));
+
+        // But not beyond it
+        assert_eq!(
+            token
+                .transfer_from(owner, recipient, U256::from(21))
+                .unwrap_err(),
+            b"Insufficient allowance".to_vec()
+        );
+    }
+
+    #[test]
+    fn test_fail_transfers() {
+        let vm = TestVM::default();
+        let mut token = MockErc20::from(&vm);
+        let owner = vm.msg_sender();
+        let recipient = Address::from([0x01; 20]);
+        token.mint(owner, U256::from(100));
+
+        // Transfers report failure without moving tokens
+        token.set_fail_transfers(true);
+        assert_eq!(token.transfer(recipient, U256::from(10)), Ok(false));
+        assert_eq!(token.balance_of(owner), U256::from(100));
+        assert_eq!(token.balance_of(recipient), U256::ZERO);
+    }
+}
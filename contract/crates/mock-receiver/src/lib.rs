@@ -0,0 +1,159 @@
+//! Test contract for payout recipients that misbehave.
+//!
+//! Deploy it as a client or freelancer to check how the escrow copes when an
+//! ETH transfer reverts or calls back into the escrow. `call_target` lets it
+//! act on the escrow itself (e.g. deposit or refund as a client).
+
+extern crate alloc;
+
+use alloy_primitives::{Address, U256};
+use stylus_sdk::abi::Bytes;
+use stylus_sdk::prelude::*;
+use stylus_sdk::stylus_core::calls::context::Call;
+
+/// Accept incoming ETH
+pub const MODE_ACCEPT: u8 = 0;
+/// Revert on incoming ETH
+pub const MODE_REVERT: u8 = 1;
+/// Call `target` with the stored calldata once per incoming transfer
+pub const MODE_REENTER: u8 = 2;
+
+sol_storage! {
+    #[entrypoint]
+    pub struct MockReceiver {
+        uint8 mode;
+        address target;
+        bytes reenter_data;
+        uint256 received;
+        uint256 reentry_attempts;
+        bool last_reentry_succeeded;
+        bool reentering;
+    }
+}
+
+#[public]
+impl MockReceiver {
+    /// Choose how incoming ETH is handled
+    pub fn set_mode(&mut self, mode: u8) -> Result<(), Vec<u8>> {
+        if mode > MODE_REENTER {
+            return Err("Unknown mode".as_bytes().to_vec());
+        }
+        self.mode.set(alloy_primitives::Uint::<8, 1>::from(mode));
+        Ok(())
+    }
+
+    /// Set the call made when reentering
+    pub fn set_reentry(&mut self, target: Address, data: Bytes) {
+        self.target.set(target);
+        self.reenter_data.set_bytes(data);
+    }
+
+    /// Call `target` with `data`, forwarding any ETH sent along
+    #[payable]
+    pub fn call_target(&mut self, target: Address, data: Bytes) -> Result<Bytes, Vec<u8>> {
+        let value = self.vm().msg_value();
+        let result = self.vm().call(&Call::new().value(value), target, &data);
+        match result {
+            Ok(output) => Ok(output.into()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    #[receive]
+    #[payable]
+    pub fn receive(&mut self) -> Result<(), Vec<u8>> {
+        let mode = self.mode.get().to::<u8>();
+        if mode == MODE_REVERT {
+            return Err("Receiver rejects ETH".as_bytes().to_vec());
+        }
+
+        let received = self.received.get();
+        self.received.set(received + self.vm().msg_value());
+
+        if mode == MODE_REENTER && !self.reentering.get() {
+            let attempts = self.reentry_attempts.get();
+            self.reentry_attempts.set(attempts + U256::from(1));
+
+            self.reentering.set(true);
+            let target = self.target.get();
+            let data = self.reenter_data.get_bytes();
+            let succeeded = self.vm().call(&Call::new(), target, &data).is_ok();
+            self.reentering.set(false);
+            self.last_reentry_succeeded.set(succeeded);
+        }
+        Ok(())
+    }
+
+    /// Current mode
+    pub fn mode(&self) -> u8 {
+        self.mode.get().to()
+    }
+
+    /// Total ETH accepted
+    pub fn received(&self) -> U256 {
+        self.received.get()
+    }
+
+    /// Number of reentry calls made
+    pub fn reentry_attempts(&self) -> U256 {
+        self.reentry_attempts.get()
+    }
+
+    /// Whether the most recent reentry call succeeded
+    pub fn last_reentry_succeeded(&self) -> bool {
+        self.last_reentry_succeeded.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use stylus_sdk::testing::*;
+
+    #[test]
+    fn test_accept_and_revert() {
+        let vm = TestVM::default();
+        let mut receiver = MockReceiver::from(&vm);
+        let amount = U256::from(1_000);
+
+        // Accepts by default
+        vm.set_value(amount);
+        assert!(receiver.receive().is_ok());
+        assert_eq!(receiver.received(), amount);
+
+        // Rejects once switched
+        assert!(receiver.set_mode(MODE_REVERT).is_ok());
+        assert_eq!(
+            receiver.receive().unwrap_err(),
+            b"Receiver rejects ETH".to_vec()
+        );
+        assert_eq!(receiver.received(), amount);
+
+        // Unknown modes are refused
+        assert!(receiver.set_mode(3).is_err());
+    }
+
+    #[test]
+    fn test_reenter() {
+        let vm = TestVM::default();
+        let mut receiver = MockReceiver::from(&vm);
+        let target = Address::from([0x0E; 20]);
+        let data = vec![0x37, 0xbd, 0xc9, 0x9b];
+
+        // Reentry that the target rejects
+        assert!(receiver.set_mode(MODE_REENTER).is_ok());
+        receiver.set_reentry(target, data.clone().into());
+        vm.mock_call(target, data.clone(), Err(b"Job already settled".to_vec()));
+        vm.set_value(U256::from(1));
+        assert!(receiver.receive().is_ok());
+        assert_eq!(receiver.reentry_attempts(), U256::from(1));
+        assert!(!receiver.last_reentry_succeeded());
+
+        // Reentry that the target accepts
+        vm.mock_call(target, data, Ok(Vec::new()));
+        assert!(receiver.receive().is_ok());
+        assert_eq!(receiver.reentry_attempts(), U256::from(2));
+        assert!(receiver.last_reentry_succeeded());
+        assert_eq!(receiver.received(), U256::from(2));
+    }
+}
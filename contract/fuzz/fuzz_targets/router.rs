@@ -0,0 +1,174 @@
+//! Feeds arbitrary call sequences through the contract's ABI router.
+//!
+//! Each call picks a selector from `abi.json` (or a raw one, to cover the
+//! unknown-selector path), a sender, an attached value and a run of argument
+//! words biased towards small numbers and known accounts, so job IDs, offsets
+//! and addresses line up often enough to reach past decoding. A call must
+//! never panic, and after every call the contract must still be solvent.
+//!
+//! TestVM neither reverts storage on an error nor credits `msg.value`, so the
+//! harness does both itself to keep the model close to a real chain.
+
+#![no_main]
+
+use alloy_json_abi::JsonAbi;
+use alloy_primitives::{Address, B256, U256};
+use arbitrary::Arbitrary;
+use freelance_payment_escrow::Escrow;
+use libfuzzer_sys::fuzz_target;
+use std::collections::HashMap;
+use std::sync::LazyLock;
+use stylus_sdk::abi::Router;
+use stylus_sdk::testing::*;
+
+/// Accounts that take turns as sender and appear as address arguments;
+/// index 0 is the admin
+const ACTORS: [Address; 4] = [
+    Address::repeat_byte(0xA0),
+    Address::repeat_byte(0xA1),
+    Address::repeat_byte(0xA2),
+    Address::repeat_byte(0xA3),
+];
+
+/// Selectors of every public function, read from the checked-in ABI
+static SELECTORS: LazyLock<Vec<u32>> = LazyLock::new(|| {
+    let abi =
+        serde_json::from_str::<JsonAbi>(include_str!("../../abi.json")).expect("valid abi.json");
+    abi.functions()
+        .map(|function| u32::from_be_bytes(function.selector().0))
+        .collect()
+});
+
+#[derive(Arbitrary, Debug)]
+enum Selector {
+    Known(u16),
+    Raw(u32),
+}
+
+#[derive(Arbitrary, Debug)]
+enum Word {
+    Small(u16),
+    Actor(u8),
+    Raw([u8; 32]),
+}
+
+#[derive(Arbitrary, Debug)]
+struct Call {
+    selector: Selector,
+    sender: u8,
+    value: u64,
+    warp: u16,
+    args: Vec<Word>,
+}
+
+impl Call {
+    fn selector(&self) -> u32 {
+        match self.selector {
+            Selector::Known(index) => SELECTORS[index as usize % SELECTORS.len()],
+            Selector::Raw(selector) => selector,
+        }
+    }
+
+    fn input(&self) -> Vec<u8> {
+        let mut input = Vec::with_capacity(self.args.len() * 32);
+        for word in &self.args {
+            let word = match *word {
+                Word::Small(n) => U256::from(n).into(),
+                Word::Actor(i) => ACTORS[i as usize % ACTORS.len()].into_word(),
+                Word::Raw(bytes) => B256::from(bytes),
+            };
+            input.extend_from_slice(word.as_slice());
+        }
+        input
+    }
+}
+
+/// Put storage and balances back as they were, as a revert would
+fn revert_to(vm: &TestVM, storage: &HashMap<U256, B256>, balances: &HashMap<Address, U256>) {
+    vm.clear_storage();
+    for (&slot, &value) in storage {
+        vm.set_storage(slot, value);
+    }
+    for address in vm.snapshot().balances.into_keys() {
+        vm.set_balance(address, balances.get(&address).copied().unwrap_or_default());
+    }
+}
+
+fuzz_target!(|calls: Vec<Call>| {
+    let vm = TestVM::default();
+    let mut contract = Escrow::from(&vm);
+    vm.set_block_timestamp(1_000);
+    contract.constructor(ACTORS[0]).unwrap();
+
+    for call in calls {
+        let before = vm.snapshot();
+        let jobs = contract.get_total_jobs();
+        let value = U256::from(call.value);
+        vm.set_sender(ACTORS[call.sender as usize % ACTORS.len()]);
+        vm.set_value(value);
+        vm.set_block_timestamp(vm.block_timestamp() + u64::from(call.warp));
+        let escrow = vm.contract_address();
+        vm.set_balance(escrow, vm.balance(escrow) + value);
+
+        let result =
+            <Escrow as Router<Escrow>>::route(&mut contract, call.selector(), &call.input());
+        if !matches!(result, Some(Ok(_))) {
+            revert_to(&vm, &before.storage, &before.balances);
+        }
+
+        assert!(contract.verify_solvency(), "insolvent after {call:?}");
+        assert!(
+            contract.get_total_jobs() >= jobs,
+            "job count went backwards"
+        );
+    }
+});
+
+/// Coverage instrumentation keeps every `stylus-sdk` function alive, including
+/// the wasm-only paths that import the Stylus host functions, so those need a
+/// definition to link; on TestVM none of them is ever called
+macro_rules! unreachable_hostios {
+    ($($name:ident),* $(,)?) => {$(
+        #[no_mangle]
+        extern "C" fn $name() {
+            unreachable!(concat!("hostio `", stringify!($name), "` called outside TestVM"));
+        }
+    )*};
+}
+
+unreachable_hostios!(
+    account_balance,
+    account_code,
+    account_code_size,
+    account_codehash,
+    block_basefee,
+    block_coinbase,
+    block_gas_limit,
+    block_number,
+    block_timestamp,
+    call_contract,
+    chainid,
+    contract_address,
+    create1,
+    create2,
+    delegate_call_contract,
+    emit_log,
+    evm_gas_left,
+    evm_ink_left,
+    msg_reentrant,
+    msg_sender,
+    msg_value,
+    native_keccak256,
+    pay_for_memory_grow,
+    read_args,
+    read_return_data,
+    return_data_size,
+    static_call_contract,
+    storage_cache_bytes32,
+    storage_flush_cache,
+    storage_load_bytes32,
+    tx_gas_price,
+    tx_ink_price,
+    tx_origin,
+    write_result,
+);
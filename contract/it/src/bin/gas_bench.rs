@@ -0,0 +1,94 @@
+//! Gas benchmarks for the escrow's hot paths on a local Nitro dev node.
+//!
+//! Deploys a fresh escrow, grows it to each requested job count and estimates
+//! gas for `deposit`, `release`, `auto_release` and `get_active_jobs`:
+//!
+//! ```bash
+//! cargo run -p escrow-it --release --bin gas-bench -- 10 1000 100000
+//! ```
+
+use alloy::primitives::utils::parse_ether;
+use alloy::primitives::{B256, U256};
+use escrow_client::IEscrow;
+use escrow_it::Node;
+use eyre::Result;
+
+/// Fixed gas for seeding transactions, so they skip estimation
+const SEED_GAS: u64 = 500_000;
+
+/// Duration of seeded jobs (1 day)
+const DURATION: u64 = 86_400;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let mut sizes: Vec<u64> = std::env::args()
+        .skip(1)
+        .map(|arg| arg.parse())
+        .collect::<Result<_, _>>()?;
+    if sizes.is_empty() {
+        sizes = vec![10, 1_000, 100_000];
+    }
+    sizes.sort_unstable();
+
+    let node = Node::from_env()?;
+    let escrow = node.deploy().await?;
+    let freelancer = node.funded_account(parse_ether("1")?).await?;
+    let as_client = IEscrow::new(escrow.address(), escrow.provider().clone());
+    let as_freelancer = IEscrow::new(escrow.address(), node.provider_for(freelancer.clone())?);
+
+    println!("| jobs | deposit | release | auto_release | get_active_jobs |");
+    println!("|-----:|--------:|--------:|-------------:|----------------:|");
+
+    let mut jobs = 0;
+    for size in sizes {
+        // Grow the escrow, submitting without waiting and confirming only the last
+        let mut last = None;
+        while jobs < size {
+            let pending = as_client
+                .deposit(freelancer.address(), DURATION, B256::ZERO)
+                .value(U256::from(1))
+                .gas(SEED_GAS)
+                .send()
+                .await?;
+            last = Some(pending);
+            jobs += 1;
+        }
+        if let Some(pending) = last {
+            pending.get_receipt().await?;
+        }
+
+        // A deposit on top of `size` existing jobs
+        let deposit = as_client
+            .deposit(freelancer.address(), DURATION, B256::ZERO)
+            .value(U256::from(1))
+            .estimate_gas()
+            .await?;
+
+        // Settlement of the newest job
+        let release = as_client
+            .release(U256::from(jobs), B256::ZERO)
+            .estimate_gas()
+            .await?;
+
+        // Claim of a job whose deadline has passed
+        let expiring = escrow
+            .deposit(freelancer.address(), 1, U256::from(1))
+            .await?;
+        jobs += 1;
+        node.wait_past(escrow.get_job(expiring).await?.deadline)
+            .await?;
+        let auto_release = as_freelancer
+            .autoRelease(expiring, B256::ZERO)
+            .estimate_gas()
+            .await?;
+
+        // Full scan of every job; large escrows may exceed the block gas limit
+        let active = match as_client.getActiveJobs().estimate_gas().await {
+            Ok(gas) => gas.to_string(),
+            Err(_) => "out of gas".into(),
+        };
+
+        println!("| {size} | {deposit} | {release} | {auto_release} | {active} |");
+    }
+    Ok(())
+}
@@ -0,0 +1,265 @@
+//! Integration harness for running the escrow against a local Nitro dev node.
+//!
+//! Unlike the in-process `TestVM`, these tests deploy the compiled WASM with
+//! `cargo stylus deploy` and drive it through [`EscrowClient`] with real
+//! transactions, so ETH transfers, gas and revert data behave as on chain.
+//!
+//! The tests are `#[ignore]`d by default. Start a node with
+//! [nitro-devnode](https://github.com/OffchainLabs/nitro-devnode), then run:
+//!
+//! ```bash
+//! cargo test -p escrow-it -- --ignored --test-threads 1
+//! ```
+//!
+//! The differential tests also compile `reference/Escrow.sol`, so they need
+//! `solc` on the `PATH` (or its location in `SOLC`).
+
+use alloy::network::{EthereumWallet, TransactionBuilder};
+use alloy::primitives::{Address, Bytes, U256};
+use alloy::providers::{DynProvider, Provider, ProviderBuilder};
+use alloy::rpc::types::{TransactionReceipt, TransactionRequest};
+use alloy::signers::local::PrivateKeySigner;
+use alloy::sol_types::SolValue;
+use escrow_client::EscrowClient;
+use eyre::{eyre, Result};
+use std::path::Path;
+use std::time::Duration;
+use tokio::process::Command;
+
+/// RPC endpoint of a default nitro-devnode
+pub const DEFAULT_RPC_URL: &str = "http://localhost:8547";
+
+/// Pre-funded development account of nitro-devnode
+pub const DEV_PRIVATE_KEY: &str =
+    "0xb6b15c8cb491557369f3c7d2c287b053eb229daa9c22138887752191c9520659";
+
+/// A running dev node plus its funded account
+pub struct Node {
+    rpc_url: String,
+    dev: PrivateKeySigner,
+}
+
+impl Node {
+    /// Connect using `NITRO_RPC_URL` and `NITRO_PRIVATE_KEY`, falling back to nitro-devnode defaults
+    pub fn from_env() -> Result<Self> {
+        let rpc_url = std::env::var("NITRO_RPC_URL").unwrap_or_else(|_| DEFAULT_RPC_URL.into());
+        let key = std::env::var("NITRO_PRIVATE_KEY").unwrap_or_else(|_| DEV_PRIVATE_KEY.into());
+        Ok(Self {
+            rpc_url,
+            dev: key.parse()?,
+        })
+    }
+
+    /// Address of the funded development account
+    pub fn dev_address(&self) -> Address {
+        self.dev.address()
+    }
+
+    /// Provider that signs with the development account
+    pub fn dev_provider(&self) -> Result<DynProvider> {
+        self.provider_for(self.dev.clone())
+    }
+
+    /// Provider that signs with `signer`
+    pub fn provider_for(&self, signer: PrivateKeySigner) -> Result<DynProvider> {
+        Ok(ProviderBuilder::new()
+            .wallet(EthereumWallet::from(signer))
+            .on_http(self.rpc_url.parse()?)
+            .erased())
+    }
+
+    /// Deploy a fresh escrow, with the development account as admin
+    pub async fn deploy(&self) -> Result<EscrowClient<DynProvider>> {
+        let contract_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        let key = format!("{:#x}", self.dev.to_bytes());
+        let output = Command::new("cargo")
+            .args(["stylus", "deploy", "--no-verify", "--endpoint"])
+            .arg(&self.rpc_url)
+            .args(["--private-key", &key])
+            .arg("--constructor-args")
+            .arg(self.dev_address().to_string())
+            .current_dir(contract_dir)
+            .output()
+            .await?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(eyre!("cargo stylus deploy failed:\n{stdout}{stderr}"));
+        }
+        let address = parse_deployed_address(&stdout)
+            .ok_or_else(|| eyre!("no deployed address in output:\n{stdout}"))?;
+
+        Ok(EscrowClient::new(address, self.dev_provider()?))
+    }
+
+    /// Compressed size of the compiled contract in KB, as reported by `cargo stylus check`
+    pub async fn contract_size(&self) -> Result<f64> {
+        let contract_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        let output = Command::new("cargo")
+            .args(["stylus", "check", "--endpoint"])
+            .arg(&self.rpc_url)
+            .current_dir(contract_dir)
+            .output()
+            .await?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(eyre!("cargo stylus check failed:\n{stdout}{stderr}"));
+        }
+        parse_contract_size(&stdout).ok_or_else(|| eyre!("no contract size in output:\n{stdout}"))
+    }
+
+    /// Deploy the Solidity reference escrow from `reference/Escrow.sol`
+    pub async fn deploy_reference(&self) -> Result<EscrowClient<DynProvider>> {
+        let source = Path::new(env!("CARGO_MANIFEST_DIR")).join("../reference/Escrow.sol");
+        let solc = std::env::var("SOLC").unwrap_or_else(|_| "solc".into());
+        let output = Command::new(&solc)
+            .args(["--bin", "--optimize"])
+            .arg(source)
+            .output()
+            .await?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(eyre!("{solc} failed:\n{stdout}{stderr}"));
+        }
+        let bytecode = parse_solc_bin(&stdout)
+            .ok_or_else(|| eyre!("no bytecode in solc output:\n{stdout}"))?;
+
+        let mut code = bytecode.to_vec();
+        code.extend(self.dev_address().abi_encode());
+        let tx = TransactionRequest::default().with_deploy_code(code);
+        let receipt = self
+            .dev_provider()?
+            .send_transaction(tx)
+            .await?
+            .get_receipt()
+            .await?;
+        let address = receipt
+            .contract_address
+            .ok_or_else(|| eyre!("reference deployment created no contract"))?;
+
+        Ok(EscrowClient::new(address, self.dev_provider()?))
+    }
+
+    /// Create a new account holding `amount` wei from the development account
+    pub async fn funded_account(&self, amount: U256) -> Result<PrivateKeySigner> {
+        let signer = PrivateKeySigner::random();
+        let tx = TransactionRequest::default()
+            .with_to(signer.address())
+            .with_value(amount);
+        self.dev_provider()?
+            .send_transaction(tx)
+            .await?
+            .get_receipt()
+            .await?;
+        Ok(signer)
+    }
+
+    /// Current balance of `address`
+    pub async fn balance(&self, address: Address) -> Result<U256> {
+        let provider = ProviderBuilder::new().on_http(self.rpc_url.parse()?);
+        Ok(provider.get_balance(address).await?)
+    }
+
+    /// Wait until the latest block's timestamp is past `timestamp`
+    pub async fn wait_past(&self, timestamp: u64) -> Result<()> {
+        let provider = ProviderBuilder::new().on_http(self.rpc_url.parse()?);
+        loop {
+            let block = provider
+                .get_block_by_number(Default::default(), Default::default())
+                .await?;
+            if block.is_some_and(|block| block.header.timestamp > timestamp) {
+                return Ok(());
+            }
+            tokio::time::sleep(Duration::from_millis(500)).await;
+        }
+    }
+}
+
+/// Wei paid for gas by a mined transaction
+pub fn gas_cost(receipt: &TransactionReceipt) -> U256 {
+    U256::from(receipt.gas_used) * U256::from(receipt.effective_gas_price)
+}
+
+/// Extract the contract address from `cargo stylus deploy` output
+fn parse_deployed_address(output: &str) -> Option<Address> {
+    let line = output
+        .lines()
+        .find(|line| line.contains("deployed code at address"))?;
+    let start = line.find("0x")?;
+    line.get(start..start + 42)?.parse().ok()
+}
+
+/// Extract the contract size in KB from `cargo stylus` output, which
+/// colors the size and prints sizes up to 1 KB in bytes
+fn parse_contract_size(output: &str) -> Option<f64> {
+    let line = output
+        .lines()
+        .find(|line| line.contains("contract size:"))?;
+    let mut plain = String::new();
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            chars.by_ref().find(|c| c.is_ascii_alphabetic());
+        } else {
+            plain.push(c);
+        }
+    }
+    let mut words = plain.split("contract size:").nth(1)?.split_whitespace();
+    let size: f64 = words.next()?.parse().ok()?;
+    match words.next()? {
+        "KB" => Some(size),
+        "B" => Some(size / 1024.0),
+        _ => None,
+    }
+}
+
+/// Extract the creation bytecode from `solc --bin` output
+fn parse_solc_bin(output: &str) -> Option<Bytes> {
+    let mut lines = output.lines();
+    lines.find(|line| line.trim() == "Binary:")?;
+    lines.next()?.trim().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_deployed_address() {
+        let output = "stripped custom section from user wasm to remove any sensitive data\n\
+            contract size: 9.2 KB\n\
+            deployed code at address: \x1b[38;5;183m0x33f54de59419570a9442e788f5dd5cf635b3c7ac\x1b[0m\n\
+            deployment tx hash: 0x1d8ae97e245e1db21dd188e5b64ad9025c1fb4e5f82a8d38bc8ae2b7a387600b\n";
+        assert_eq!(
+            parse_deployed_address(output),
+            Some(
+                "0x33f54de59419570a9442e788f5dd5cf635b3c7ac"
+                    .parse()
+                    .unwrap()
+            )
+        );
+        assert_eq!(parse_deployed_address("contract size: 9.2 KB"), None);
+    }
+
+    #[test]
+    fn test_parse_contract_size() {
+        let output = "\x1b[32mstylus checks passed\x1b[0m\n\
+            contract size: \x1b[32m9.2 KB\x1b[0m\n\
+            wasm data fee: 0.000109 ETH\n";
+        assert_eq!(parse_contract_size(output), Some(9.2));
+        assert_eq!(parse_contract_size("contract size: 512 B"), Some(0.5));
+        assert_eq!(parse_contract_size("deployed code at address: 0x00"), None);
+    }
+
+    #[test]
+    fn test_parse_solc_bin() {
+        let output = "\n======= reference/Escrow.sol:Escrow =======\nBinary:\n6080604052\n";
+        assert_eq!(
+            parse_solc_bin(output),
+            Some(Bytes::from_static(&[0x60, 0x80, 0x60, 0x40, 0x52]))
+        );
+        assert_eq!(parse_solc_bin("Error: Source file not found."), None);
+    }
+}
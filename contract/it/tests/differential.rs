@@ -0,0 +1,325 @@
+//! Differential tests against the Solidity reference in `reference/Escrow.sol`.
+//!
+//! The same pseudo-random operation sequence is sent to the Stylus escrow and
+//! the reference from the same accounts. Every step must succeed or revert with
+//! the same message and emit the same events, and both contracts must end each
+//! step in the same state. Set `DIFF_SEED` and `DIFF_OPS` to vary the run.
+
+use alloy::primitives::utils::parse_ether;
+use alloy::primitives::{Address, B256, U256};
+use alloy::providers::{DynProvider, Provider};
+use alloy::rpc::types::{TransactionReceipt, TransactionRequest};
+use escrow_client::{
+    ClientError, ClientProfile, EscrowClient, EscrowEvent, FreelancerProfile, IEscrow, Job,
+    ProtocolStats, Sunset, UserStats,
+};
+use escrow_it::Node;
+use eyre::{bail, eyre, Result};
+
+/// Duration of jobs that stay refundable for the whole run (1 day)
+const LONG: u64 = 86_400;
+
+/// Duration of jobs the harness waits out so they can be claimed
+const SHORT: u64 = 1;
+
+/// Memo derived from an op's arguments, so the events carry something to compare
+fn memo(value: u64) -> B256 {
+    B256::from(U256::from(value))
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Op {
+    Deposit {
+        sender: usize,
+        freelancer: usize,
+        amount: u64,
+        duration: u64,
+    },
+    Release {
+        sender: usize,
+        job: u64,
+    },
+    Refund {
+        sender: usize,
+        job: u64,
+    },
+    AutoRelease {
+        sender: usize,
+        job: u64,
+    },
+    EmergencyRefund {
+        sender: usize,
+        job: u64,
+    },
+    SetPaused {
+        sender: usize,
+        paused: bool,
+    },
+    TransferOwnership {
+        sender: usize,
+        to: usize,
+    },
+}
+
+impl Op {
+    fn sender(&self) -> usize {
+        match *self {
+            Op::Deposit { sender, .. }
+            | Op::Release { sender, .. }
+            | Op::Refund { sender, .. }
+            | Op::AutoRelease { sender, .. }
+            | Op::EmergencyRefund { sender, .. }
+            | Op::SetPaused { sender, .. }
+            | Op::TransferOwnership { sender, .. } => sender,
+        }
+    }
+}
+
+/// Deterministic xorshift generator, so a failing seed can be replayed
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+
+    /// A random operation over `actors` accounts and up to `jobs` existing jobs
+    fn op(&mut self, actors: usize, jobs: u64) -> Op {
+        let sender = self.below(actors as u64) as usize;
+        // Job 0 and one past the last job never exist
+        let job = self.below(jobs + 2);
+        match self.below(12) {
+            0..=3 => Op::Deposit {
+                sender,
+                // One past the actors maps to the zero address
+                freelancer: self.below(actors as u64 + 1) as usize,
+                amount: [0, 1, 1_000_000_000_000_000][self.below(3) as usize],
+                duration: [0, SHORT, LONG][self.below(3) as usize],
+            },
+            4 | 5 => Op::Release { sender, job },
+            6 | 7 => Op::Refund { sender, job },
+            8 | 9 => Op::AutoRelease { sender, job },
+            10 => Op::EmergencyRefund { sender, job },
+            _ if self.below(2) == 0 => Op::SetPaused {
+                sender,
+                paused: self.below(2) == 0,
+            },
+            _ => Op::TransferOwnership {
+                sender,
+                to: self.below(actors as u64 + 1) as usize,
+            },
+        }
+    }
+}
+
+/// Successful events, or the revert message
+type Outcome = Result<Vec<EscrowEvent>, String>;
+
+/// Everything observable about an escrow between steps
+#[derive(Debug, PartialEq)]
+struct Snapshot {
+    /// Jobs with their deadlines zeroed, since the two deposits land in
+    /// different blocks; deadlines are checked against the block at deposit
+    jobs: Vec<Job>,
+    active: Vec<U256>,
+    total: U256,
+    paused: bool,
+    sunset: Sunset,
+    stats: ProtocolStats,
+    users: Vec<UserStats>,
+    /// Each actor's locked amount and pending earnings
+    profiles: Vec<(FreelancerProfile, ClientProfile)>,
+    balance: U256,
+    solvent: bool,
+}
+
+struct Harness {
+    node: Node,
+    stylus: EscrowClient<DynProvider>,
+    reference: EscrowClient<DynProvider>,
+    /// Senders, with the development account (the initial admin) first
+    actors: Vec<(Address, DynProvider)>,
+}
+
+impl Harness {
+    async fn new() -> Result<Self> {
+        let node = Node::from_env()?;
+        let stylus = node.deploy().await?;
+        let reference = node.deploy_reference().await?;
+
+        let mut actors = vec![(node.dev_address(), node.dev_provider()?)];
+        for _ in 0..3 {
+            let signer = node.funded_account(parse_ether("1")?).await?;
+            actors.push((signer.address(), node.provider_for(signer)?));
+        }
+        Ok(Self {
+            node,
+            stylus,
+            reference,
+            actors,
+        })
+    }
+
+    /// Account `i`, or the zero address one past the last actor
+    fn address(&self, i: usize) -> Address {
+        self.actors
+            .get(i)
+            .map_or(Address::ZERO, |(address, _)| *address)
+    }
+
+    /// Build `op` as a transaction against `escrow`
+    fn request(&self, op: Op, escrow: Address) -> TransactionRequest {
+        let contract = IEscrow::new(escrow, &self.actors[op.sender()].1);
+        match op {
+            Op::Deposit {
+                freelancer,
+                amount,
+                duration,
+                ..
+            } => contract
+                .deposit(self.address(freelancer), duration, memo(amount))
+                .value(U256::from(amount))
+                .into_transaction_request(),
+            Op::Release { job, .. } => contract
+                .release(U256::from(job), memo(job))
+                .into_transaction_request(),
+            Op::Refund { job, .. } => contract
+                .refund(U256::from(job), memo(job))
+                .into_transaction_request(),
+            Op::AutoRelease { job, .. } => contract
+                .autoRelease(U256::from(job), memo(job))
+                .into_transaction_request(),
+            Op::EmergencyRefund { job, .. } => contract
+                .emergencyRefund(U256::from(job))
+                .into_transaction_request(),
+            Op::SetPaused { paused, .. } => contract.setPaused(paused).into_transaction_request(),
+            Op::TransferOwnership { to, .. } => contract
+                .transferOwnership(self.address(to))
+                .into_transaction_request(),
+        }
+    }
+
+    /// Send `op` to `escrow`, returning its outcome and, on success, the receipt
+    async fn send(&self, op: Op, escrow: Address) -> Result<(Outcome, Option<TransactionReceipt>)> {
+        let provider = &self.actors[op.sender()].1;
+        let pending = match provider.send_transaction(self.request(op, escrow)).await {
+            Ok(pending) => pending,
+            Err(err) => match ClientError::from(alloy::contract::Error::TransportError(err)) {
+                ClientError::Revert(msg) => return Ok((Err(msg), None)),
+                err => return Err(err.into()),
+            },
+        };
+        let receipt = pending.get_receipt().await?;
+        if !receipt.status() {
+            bail!("{op:?} failed on chain after a successful estimate");
+        }
+        let events = receipt
+            .inner
+            .logs()
+            .iter()
+            .map(|log| {
+                EscrowEvent::decode(log.topics(), &log.data().data)
+                    .ok_or_else(|| eyre!("undecodable log {log:?}"))
+            })
+            .collect::<Result<_>>()?;
+        Ok((Ok(events), Some(receipt)))
+    }
+
+    async fn snapshot(&self, escrow: &EscrowClient<DynProvider>) -> Result<Snapshot> {
+        let total = escrow.get_total_jobs().await?;
+        let mut jobs = Vec::new();
+        // Include the first missing ID so empty lookups are compared too
+        for id in 1..=total.to::<u64>() + 1 {
+            let job = escrow.get_job(U256::from(id)).await?;
+            jobs.push(Job { deadline: 0, ..job });
+        }
+        let mut users = Vec::new();
+        let mut profiles = Vec::new();
+        for (address, _) in &self.actors {
+            users.push(escrow.get_user_stats(*address).await?);
+            profiles.push((
+                escrow.get_freelancer_profile(*address).await?,
+                escrow.get_client_profile(*address).await?,
+            ));
+        }
+        Ok(Snapshot {
+            jobs,
+            active: escrow.get_active_jobs().await?,
+            total,
+            paused: escrow.is_paused().await?,
+            sunset: escrow.get_sunset().await?,
+            stats: escrow.get_protocol_stats().await?,
+            users,
+            profiles,
+            balance: self.node.balance(escrow.address()).await?,
+            solvent: escrow.verify_solvency().await?,
+        })
+    }
+
+    /// Check a new job's deadline against the block it was created in
+    async fn deadline(
+        &self,
+        escrow: &EscrowClient<DynProvider>,
+        receipt: &TransactionReceipt,
+        job_id: U256,
+        duration: u64,
+    ) -> Result<u64> {
+        let number = receipt
+            .block_number
+            .ok_or_else(|| eyre!("receipt has no block number"))?;
+        let block = escrow
+            .provider()
+            .get_block_by_number(number.into(), Default::default())
+            .await?
+            .ok_or_else(|| eyre!("block {number} not found"))?;
+        let deadline = escrow.get_job(job_id).await?.deadline;
+        assert_eq!(deadline, block.header.timestamp + duration, "job {job_id}");
+        Ok(deadline)
+    }
+}
+
+#[tokio::test]
+#[ignore = "requires a local Nitro dev node and solc"]
+async fn stylus_matches_solidity_reference() -> Result<()> {
+    let seed = std::env::var("DIFF_SEED").map_or(Ok(0x5eed), |seed| seed.parse())?;
+    let ops = std::env::var("DIFF_OPS").map_or(Ok(60), |ops| ops.parse())?;
+    let harness = Harness::new().await?;
+    let mut rng = Rng(seed);
+    let mut jobs = 0;
+
+    for step in 0..ops {
+        let op = rng.op(harness.actors.len(), jobs);
+        let (stylus, stylus_receipt) = harness.send(op, harness.stylus.address()).await?;
+        let (reference, reference_receipt) = harness.send(op, harness.reference.address()).await?;
+        assert_eq!(stylus, reference, "seed {seed} step {step}: {op:?}");
+
+        // Wait out short jobs so both contracts see them expired
+        if let (Op::Deposit { duration, .. }, Some(a), Some(b)) =
+            (op, &stylus_receipt, &reference_receipt)
+        {
+            jobs += 1;
+            let id = U256::from(jobs);
+            let a = harness.deadline(&harness.stylus, a, id, duration).await?;
+            let b = harness
+                .deadline(&harness.reference, b, id, duration)
+                .await?;
+            if duration == SHORT {
+                harness.node.wait_past(a.max(b)).await?;
+            }
+        }
+
+        assert_eq!(
+            harness.snapshot(&harness.stylus).await?,
+            harness.snapshot(&harness.reference).await?,
+            "seed {seed} step {step}: {op:?}"
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,82 @@
+//! Full job lifecycles against a live Nitro dev node.
+
+use alloy::primitives::utils::parse_ether;
+use alloy::primitives::U256;
+use escrow_client::{ClientError, EscrowClient, JobStatus};
+use escrow_it::{gas_cost, Node};
+
+const DURATION: u64 = 86_400; // 1 day
+
+#[tokio::test]
+#[ignore = "requires a local Nitro dev node"]
+async fn deposit_then_release_pays_freelancer() -> eyre::Result<()> {
+    let node = Node::from_env()?;
+    let escrow = node.deploy().await?;
+    let freelancer = node.funded_account(parse_ether("0.1")?).await?;
+    let amount = parse_ether("1")?;
+
+    // Deposit moves the funds into the contract
+    let job_id = escrow
+        .deposit(freelancer.address(), DURATION, amount)
+        .await?;
+    assert_eq!(node.balance(escrow.address()).await?, amount);
+    assert_eq!(escrow.get_job(job_id).await?.status(), JobStatus::Active);
+
+    // Release pays the freelancer in full
+    let freelancer_before = node.balance(freelancer.address()).await?;
+    escrow.release(job_id).await?;
+    assert_eq!(
+        node.balance(freelancer.address()).await?,
+        freelancer_before + amount
+    );
+    assert_eq!(node.balance(escrow.address()).await?, U256::ZERO);
+    assert_eq!(escrow.get_job(job_id).await?.status(), JobStatus::Released);
+    assert!(escrow.get_active_jobs().await?.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+#[ignore = "requires a local Nitro dev node"]
+async fn deposit_then_refund_returns_funds() -> eyre::Result<()> {
+    let node = Node::from_env()?;
+    let escrow = node.deploy().await?;
+    let freelancer = node.funded_account(parse_ether("0.1")?).await?;
+    let amount = parse_ether("0.5")?;
+
+    let job_id = escrow
+        .deposit(freelancer.address(), DURATION, amount)
+        .await?;
+
+    // Refund returns the deposit to the client, less gas
+    let client_before = node.balance(node.dev_address()).await?;
+    let receipt = escrow.refund(job_id).await?;
+    assert_eq!(
+        node.balance(node.dev_address()).await?,
+        client_before + amount - gas_cost(&receipt)
+    );
+    assert_eq!(node.balance(escrow.address()).await?, U256::ZERO);
+    assert_eq!(escrow.get_job(job_id).await?.status(), JobStatus::Refunded);
+    Ok(())
+}
+
+#[tokio::test]
+#[ignore = "requires a local Nitro dev node"]
+async fn only_client_can_release() -> eyre::Result<()> {
+    let node = Node::from_env()?;
+    let escrow = node.deploy().await?;
+    let freelancer = node.funded_account(parse_ether("0.1")?).await?;
+    let amount = parse_ether("0.5")?;
+
+    let job_id = escrow
+        .deposit(freelancer.address(), DURATION, amount)
+        .await?;
+
+    // The freelancer's release attempt reverts and leaves the funds in escrow
+    let as_freelancer = EscrowClient::new(escrow.address(), node.provider_for(freelancer)?);
+    match as_freelancer.release(job_id).await {
+        Err(ClientError::Revert(msg)) => assert_eq!(msg, "Only client can release"),
+        other => panic!("expected revert, got {other:?}"),
+    }
+    assert_eq!(node.balance(escrow.address()).await?, amount);
+    Ok(())
+}
@@ -0,0 +1,38 @@
+//! Size regression checks for the compiled contract.
+//!
+//! Stylus rejects contracts whose compressed WASM exceeds 24 KB, and every
+//! exported function adds router and ABI-decoding code. New rarely-used
+//! settings belong behind `configure`, and reading them back behind
+//! `get_setting`, instead of a selector of their own; new read-only data
+//! extends an existing view. The budget is fixed, not raised per feature.
+
+use escrow_it::Node;
+use std::path::Path;
+
+/// Compressed size budget in KB, leaving headroom under the 24 KB limit
+const SIZE_BUDGET_KB: f64 = 22.0;
+
+/// Exported functions allowed in `abi.json`
+const SELECTOR_BUDGET: usize = 200;
+
+#[tokio::test]
+#[ignore = "requires a local Nitro dev node"]
+async fn contract_fits_size_budget() -> eyre::Result<()> {
+    let size = Node::from_env()?.contract_size().await?;
+    assert!(
+        size <= SIZE_BUDGET_KB,
+        "contract size {size} KB exceeds the {SIZE_BUDGET_KB} KB budget"
+    );
+    Ok(())
+}
+
+#[test]
+fn abi_fits_selector_budget() {
+    let abi = std::fs::read_to_string(Path::new(env!("CARGO_MANIFEST_DIR")).join("../abi.json"))
+        .expect("abi.json is readable");
+    let functions = abi.matches("\"type\": \"function\"").count();
+    assert!(
+        functions <= SELECTOR_BUDGET,
+        "abi.json exports {functions} functions, over the budget of {SELECTOR_BUDGET}"
+    );
+}
@@ -0,0 +1,5476 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+pragma solidity ^0.8.20;
+
+/// Subset of the Ethereum Attestation Service used by `src/attestation.rs`
+struct Attestation {
+    bytes32 uid;
+    bytes32 schema;
+    uint64 time;
+    uint64 expirationTime;
+    uint64 revocationTime;
+    bytes32 refUID;
+    address recipient;
+    address attester;
+    bool revocable;
+    bytes data;
+}
+
+interface IEAS {
+    function getAttestation(bytes32 uid) external view returns (Attestation memory);
+}
+
+interface IERC20 {
+    function transfer(address to, uint256 amount) external returns (bool);
+    function transferFrom(address from, address to, uint256 amount) external returns (bool);
+}
+
+interface IERC1271 {
+    function isValidSignature(bytes32 hash, bytes calldata signature) external view returns (bytes4);
+}
+
+/// Subset of a Lido stETH-style staking token used by `src/staking.rs`
+interface IStakingToken {
+    function submit(address referral) external payable returns (uint256);
+    function getPooledEthByShares(uint256 shares) external view returns (uint256);
+    function transferShares(address recipient, uint256 shares) external returns (uint256);
+}
+
+interface IVotes {
+    function getPastVotes(address account, uint256 timepoint) external view returns (uint256);
+}
+
+/// @title Escrow (Solidity reference)
+/// @notice Line-for-line port of the Stylus contract in `src/lib.rs`, used only by
+/// the differential tests in `it/tests/differential.rs`. It must expose the same
+/// ABI, run the same checks in the same order and emit the same events, so any
+/// change to the Stylus contract's behaviour belongs here too.
+contract Escrow {
+    uint64 constant MIN_DORMANCY_PERIOD = 2 * 365 days;
+    uint64 constant DORMANCY_NOTICE_PERIOD = 30 days;
+    uint64 constant TREASURY_DELAY = 7 days;
+    uint64 constant PAYOUT_ADDRESS_DELAY = 3 days;
+    uint64 constant RENOUNCE_DELAY = 7 days;
+    uint64 constant CLAIM_WINDOW = 30 days;
+    uint8 constant JOB_FIELD_DEADLINE = 0;
+    uint8 constant JOB_FIELD_AMOUNT = 1;
+    uint8 constant JOB_FIELD_METADATA = 2;
+    uint8 constant JOB_FIELD_PAYOUT_ADDRESS = 3;
+    uint8 constant JOB_FIELD_CLIENT = 4;
+    uint8 constant JOB_FIELD_FREELANCER = 5;
+    uint16 constant BASIS_POINTS = 10_000;
+    uint64 constant SETTLEMENT_HOOK_GAS = 100_000;
+    uint64 constant PAYOUT_CALL_GAS = 50_000;
+    uint256 constant MAX_TRANCHES = 12;
+    uint256 constant MAX_BASKET_TOKENS = 4;
+    uint64 constant MAX_RETAINER_PERIODS = 36;
+    uint64 constant EPOCH_LENGTH = 1 days;
+    uint256 constant MAX_REFUND_BATCH = 50;
+    uint64 constant MAX_JOBS_RANGE = 100;
+    uint16 constant MAX_PROTOCOL_FEE_BPS = 1_000;
+    uint16 constant MAX_PLATFORM_FEE_BPS = 1_000;
+    uint16 constant MAX_DISPUTE_STAKE_BPS = 500;
+    uint8 constant MAX_JURY_SIZE = 15;
+    uint16 constant MAX_APPEAL_BOND_BPS = 2_000;
+    uint64 constant MAX_CLAWBACK_WINDOW = 7 days;
+    uint256 constant MAX_FEE_TIERS = 8;
+    uint8 constant MAX_STARS = 5;
+    uint64 constant REPUTATION_HALF_LIFE = 180 days;
+    uint64 constant DISPUTE_LOSS_WEIGHT = 2;
+    uint256 constant REPUTATION_PRIOR = 1 ether;
+    uint256 constant MAX_EVIDENCE = 32;
+    uint8 constant EMERGENCY_ACTION_REFUND = 0;
+    uint8 constant EMERGENCY_ACTION_SWEEP_SURPLUS = 1;
+    uint8 constant EMERGENCY_ACTION_EXTEND_PAUSE = 2;
+    uint8 constant TIMESHEET_SUBMITTED = 0;
+    uint8 constant TIMESHEET_APPROVED = 1;
+    uint8 constant TIMESHEET_REJECTED = 2;
+    uint8 constant JOB_STATUS_ANY = 0;
+    uint8 constant JOB_STATUS_ACTIVE = 1;
+    uint8 constant JOB_STATUS_RELEASED = 2;
+    uint8 constant JOB_STATUS_REFUNDED = 3;
+    uint8 constant JOB_STATUS_SWEPT = 4;
+    uint256 constant MAX_GUARDIANS = 16;
+    uint64 constant EMERGENCY_PROPOSAL_TTL = 3 days;
+    uint64 constant MAX_ADMIN_PAUSE = 7 days;
+    uint64 constant EMERGENCY_REFUND_MIN_AGE = 14 days;
+    uint64 constant EMERGENCY_REFUND_GRACE = 3 days;
+    uint8 constant GOVERNANCE_ACTION_PROTOCOL_FEE = 0;
+    uint8 constant GOVERNANCE_ACTION_DEFAULT_JOB_CAP = 1;
+    uint8 constant GOVERNANCE_ACTION_ARBITRATOR = 2;
+    uint8 constant GOVERNANCE_ACTION_RULING_PERIOD = 3;
+    uint8 constant GOVERNANCE_ACTION_DISPUTE_TIMEOUT = 4;
+    uint8 constant GOVERNANCE_ACTION_DORMANCY_PERIOD = 5;
+    uint8 constant GOVERNANCE_ACTION_ADD_COUNCIL_MEMBER = 6;
+    uint8 constant GOVERNANCE_ACTION_REMOVE_COUNCIL_MEMBER = 7;
+    uint8 constant GOVERNANCE_ACTION_QUORUM = 8;
+    uint8 constant GOVERNANCE_ACTION_REFUND_ROOT = 9;
+    uint8 constant GOVERNANCE_ACTION_APPEAL = 10;
+    uint8 constant GOVERNANCE_ACTION_REPLACE_ARBITRATOR = 11;
+    uint8 constant GOVERNANCE_ACTION_SETTING = 12;
+    uint8 constant GOVERNANCE_ACTION_TREASURY = 13;
+    uint64 constant GOVERNANCE_VOTING_PERIOD = 3 days;
+    uint64 constant GOVERNANCE_TIMELOCK = 2 days;
+    uint64 constant GOVERNANCE_EXECUTION_WINDOW = 14 days;
+    uint8 constant SETTING_SWAP_ROUTER = 0;
+    uint8 constant SETTING_BASKET_TOKEN = 1;
+    uint8 constant SETTING_COMPLIANCE_HOOK = 2;
+    uint8 constant SETTING_DEFAULT_JOB_CAP = 3;
+    uint8 constant SETTING_FEE_MANAGER = 4;
+    uint8 constant SETTING_PROTOCOL_FEE = 5;
+    uint8 constant SETTING_SETTLEMENT_REBATE = 6;
+    uint8 constant SETTING_FEE_TIERS = 7;
+    uint8 constant SETTING_VOLUME_DISCOUNTS = 8;
+    uint8 constant SETTING_FEE_EXEMPT = 9;
+    uint8 constant SETTING_DEPOSIT_LIMITS = 10;
+    uint8 constant SETTING_DEPOSIT_LIMIT_EXEMPT = 11;
+    uint8 constant SETTING_ATTESTATION_POLICY = 12;
+    uint8 constant SETTING_ARBITRATION_POLICY = 13;
+    uint8 constant SETTING_DISPUTE_TIMEOUT_POLICY = 14;
+    uint8 constant SETTING_DISPUTE_STAKE_POLICY = 15;
+    uint8 constant SETTING_JURY_POLICY = 16;
+    uint8 constant SETTING_APPEAL_POLICY = 17;
+    uint8 constant SETTING_MAX_PAUSE = 18;
+    uint8 constant SETTING_PAUSER = 19;
+    uint8 constant SETTING_DORMANCY_POLICY = 20;
+    uint8 constant SETTING_ARBITRATOR_LISTING = 21;
+    uint8 constant SETTING_STAKING_TOKEN = 22;
+    uint16 constant MAX_UNSTAKE_SLIPPAGE_BPS = 500;
+    uint64 constant MIN_STAKE_DURATION = 90 days;
+    uint64 constant UNSTAKE_WINDOW = 7 days;
+    uint256 constant MAX_LISTED_ARBITRATORS = 32;
+    uint256 constant MAX_COUNCIL = 16;
+    bytes32 constant EIP712_DOMAIN_TYPEHASH =
+        keccak256("EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)");
+    bytes32 constant JOB_OFFER_TYPEHASH = keccak256(
+        "JobOffer(address client,address freelancer,uint256 amount,uint64 duration,bytes32 memo,uint256 nonce,uint64 expiry)"
+    );
+    bytes32 constant SETTLEMENT_TYPEHASH = keccak256(
+        "Settlement(uint256 job_id,address signer,bool release,bytes32 memo,uint256 nonce,uint64 expiry)"
+    );
+    bytes32 constant VERDICT_TYPEHASH = keccak256("Verdict(uint256 job_id,uint16 freelancer_bps,uint256 nonce,uint64 expiry)");
+    bytes4 constant ERC1271_MAGIC_VALUE = 0x1626ba7e;
+    uint256 constant SECP256K1N_HALF = 0x7FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF5D576E7357A4501DDFE92F46681B20A0;
+
+    struct Job {
+        uint256 jobId;
+        address client;
+        address freelancer;
+        uint256 amount;
+        uint64 deadline;
+        bool released;
+        bool refunded;
+        bool swept;
+        bytes32 commitment;
+        bytes32 attestation;
+        uint64 createdAt;
+        uint16 category;
+        bytes32 tag;
+        address arbitrator;
+    }
+
+    struct PayoutToken {
+        address token;
+        uint256 minRate;
+    }
+
+    struct PayoutAddress {
+        address current;
+        address pending;
+        uint64 effectiveAt;
+    }
+
+    struct JobPlatform {
+        address platform;
+        uint16 feeBps;
+    }
+
+    struct Tranche {
+        uint256 amount;
+        uint64 unlockAt;
+        bool settled;
+    }
+
+    struct Retainer {
+        uint64 period;
+        uint64 notice;
+    }
+
+    struct Cancellation {
+        uint64 notice;
+        uint64 effectiveAt;
+    }
+
+    struct Holdback {
+        uint16 bps;
+        uint64 warranty;
+        uint64 warrantyEnds;
+    }
+
+    struct Clawback {
+        uint64 window;
+        uint64 payableAt;
+        bytes32 memo;
+    }
+
+    struct Sla {
+        uint64 deliveryBy;
+        uint16 lateBpsPerDay;
+        uint64 deliveredAt;
+    }
+
+    struct Engagement {
+        uint256 rate;
+        uint256 pending;
+    }
+
+    struct Timesheet {
+        uint32 hours_;
+        bytes32 details;
+        uint8 status;
+    }
+
+    struct BasketToken {
+        address token;
+        uint256 amount;
+    }
+
+    struct Funding {
+        uint256 target;
+        uint256 raised;
+        uint64 duration;
+        uint64 cutoff;
+    }
+
+    struct Invoice {
+        address freelancer;
+        address client;
+        uint256 amount;
+        uint64 duration;
+        bytes32 metadata;
+        uint64 expiresAt;
+        uint256 jobId;
+    }
+
+    struct Withholding {
+        uint16 rateBps;
+        address destination;
+    }
+
+    struct UserStats {
+        uint256 jobsAsClient;
+        uint256 jobsAsFreelancer;
+        uint256 totalDeposited;
+        uint256 totalEarned;
+        uint256 totalRefunded;
+    }
+
+    struct JobRatings {
+        uint8 byClient;
+        uint8 byFreelancer;
+    }
+
+    struct Reputation {
+        uint32 ratings;
+        uint32 starsTotal;
+        uint32 disputesLost;
+        uint256 weightedStars;
+        uint256 weight;
+        uint64 updatedAt;
+    }
+
+    struct FreelancerProfile {
+        uint32 jobsCompleted;
+        uint32 disputes;
+        uint64 settlementTime;
+    }
+
+    struct ClientProfile {
+        uint32 disputes;
+        uint32 disputesLost;
+    }
+
+    struct JobStake {
+        address token;
+        uint16 freelancerYieldBps;
+        uint16 treasuryYieldBps;
+        uint16 maxSlippageBps;
+        address consentedBy;
+        uint256 principal;
+        uint256 shares;
+    }
+
+    struct CategoryStats {
+        uint256 jobs;
+        uint256 deposited;
+    }
+
+    struct DepositWindow {
+        uint64 lastAt;
+        uint64 blockNumber;
+        uint32 count;
+    }
+
+    struct PeriodStats {
+        uint256 jobsCreated;
+        uint256 deposited;
+        uint256 released;
+        uint256 refunded;
+    }
+
+    struct FeeTier {
+        uint256 minAmount;
+        uint16 feeBps;
+    }
+
+    struct VolumeDiscount {
+        uint256 minVolume;
+        uint16 discountBps;
+    }
+
+    struct Dispute {
+        address openedBy;
+        uint64 openedAt;
+        address arbitrator;
+        uint64 ruleBy;
+        uint16 defaultSplitBps;
+        uint64 timeoutAt;
+        bool timeoutSplit;
+        uint16 timeoutSplitBps;
+        bool lifted;
+        uint256 stake;
+    }
+
+    struct Juror {
+        uint256 stake;
+        uint32 index;
+        uint32 panels;
+    }
+
+    struct JuryVote {
+        bool seated;
+        bool voted;
+        uint16 freelancerBps;
+    }
+
+    struct Jury {
+        uint64 voteBy;
+        address[] panel;
+        mapping(address => JuryVote) votes;
+        uint8 votesCast;
+        bool tallied;
+    }
+
+    struct Appeal {
+        address resolver;
+        uint16 freelancerBps;
+        uint64 appealableUntil;
+        address appellant;
+        uint16 appealBps;
+        uint256 bond;
+        uint256 proposalId;
+    }
+
+    struct DualApproval {
+        bool required;
+        bool clientApproved;
+        bool freelancerApproved;
+    }
+
+    struct EmergencyProposal {
+        uint8 action;
+        uint256 target;
+        address proposer;
+        uint64 expiresAt;
+        uint64 guardianEpoch;
+        uint8 confirmations;
+        bool executed;
+    }
+
+    struct GovernanceProposal {
+        uint8 action;
+        address target;
+        uint256 value;
+        address proposer;
+        uint64 snapshot;
+        uint64 councilEpoch;
+        uint64 votingEnds;
+        uint256 votesFor;
+        uint256 votesAgainst;
+        bool executed;
+        bytes data;
+    }
+
+    struct Evidence {
+        bytes32 evidenceHash;
+        address submitter;
+        uint64 submittedAt;
+    }
+
+    address admin;
+    bool paused;
+    uint256 jobCount;
+    mapping(uint256 => Job) jobs;
+    mapping(uint256 => bool) finalized;
+    uint256 totalDeposited;
+    uint256 totalReleased;
+    uint256 totalRefunded;
+    mapping(address => UserStats) userStats;
+    uint64 sunsetAt;
+    bool retired;
+    uint256 totalSwept;
+    uint64 dormancyPeriod;
+    address recoveryAddress;
+    mapping(uint256 => uint64) sweepAfter;
+    mapping(address => mapping(uint256 => uint256)) nonceJobs;
+    address swapRouter;
+    address weth;
+    mapping(address => PayoutToken) payoutTokens;
+    mapping(address => Withholding) withholdings;
+    address complianceHook;
+    address eas;
+    bytes32 attestationSchema;
+    address attester;
+    mapping(uint256 => address) settlementHooks;
+    mapping(uint256 => address) releaseConditions;
+    mapping(uint256 => Tranche[]) tranches;
+    mapping(uint256 => Retainer) retainers;
+    mapping(uint256 => Cancellation) cancellations;
+    mapping(uint256 => Funding) fundings;
+    mapping(uint256 => mapping(address => uint256)) contributions;
+    mapping(address => uint256) credits;
+    uint256 totalCredit;
+    uint32 defaultJobCap;
+    mapping(address => uint32) jobCaps;
+    mapping(address => uint32) activeJobs;
+    mapping(uint64 => PeriodStats) periodStats;
+    uint16 protocolFeeBps;
+    address feeManager;
+    FeeTier[] feeTiers;
+    mapping(uint256 => uint16) jobFeeBps;
+    VolumeDiscount[] volumeDiscounts;
+    mapping(address => uint256) clientVolume;
+    mapping(address => bool) feeExempt;
+    mapping(uint256 => Dispute) disputes;
+    mapping(uint256 => Evidence[]) evidence;
+    address arbitrator;
+    uint64 rulingPeriod;
+    uint16 defaultSplitBps;
+    mapping(uint256 => DualApproval) dualApprovals;
+    uint64 disputeTimeout;
+    bool timeoutSplit;
+    uint16 timeoutSplitBps;
+    uint256 accruedFees;
+    address treasury;
+    address pendingTreasury;
+    uint64 treasuryEffectiveAt;
+    address[] guardians;
+    mapping(address => bool) isGuardian;
+    uint8 guardianThreshold;
+    uint64 guardianEpoch;
+    uint64 pauseUntil;
+    uint256 emergencyProposalCount;
+    mapping(uint256 => EmergencyProposal) emergencyProposals;
+    mapping(uint256 => mapping(address => bool)) emergencyConfirmations;
+    mapping(uint256 => address) pendingClients;
+    mapping(uint256 => bool) acceptedJobs;
+    mapping(address => PayoutAddress) payoutAddresses;
+    address governanceToken;
+    address[] council;
+    mapping(address => bool) isCouncil;
+    uint256 governanceQuorum;
+    uint64 councilEpoch;
+    uint256 governanceProposalCount;
+    mapping(uint256 => GovernanceProposal) governanceProposals;
+    mapping(uint256 => mapping(address => bool)) governanceVotes;
+    address pauser;
+    uint64 maxPause;
+    uint64 renounceEffectiveAt;
+    mapping(uint256 => JobPlatform) jobPlatforms;
+    mapping(address => mapping(uint256 => bool)) cancelledOffers;
+    uint256 invoiceCount;
+    mapping(uint256 => Invoice) invoices;
+    mapping(uint256 => Holdback) holdbacks;
+    mapping(uint256 => Clawback) clawbacks;
+    mapping(uint256 => Sla) slas;
+    mapping(uint256 => Engagement) engagements;
+    mapping(uint256 => Timesheet[]) timesheets;
+    mapping(address => bool) basketTokens;
+    mapping(uint256 => BasketToken[]) baskets;
+    bytes32 refundRoot;
+    mapping(bytes32 => mapping(uint256 => bool)) refundClaims;
+    mapping(address => uint256[]) partyJobs;
+    mapping(address => mapping(uint256 => bool)) partyIndexed;
+    mapping(uint256 => JobRatings) jobRatings;
+    mapping(address => Reputation) reputations;
+    uint256 settlementRebate;
+    uint64 depositCooldown;
+    uint32 depositsPerBlock;
+    mapping(address => DepositWindow) depositWindows;
+    mapping(address => bool) depositLimitExempt;
+    uint16 disputeStakeBps;
+    uint256 minDisputeStake;
+    uint256 totalDisputeStakes;
+    mapping(uint16 => CategoryStats) categoryStats;
+    mapping(address => mapping(uint256 => bool)) usedAuthorizations;
+    uint8 jurySize;
+    uint64 juryVotingPeriod;
+    uint256 minJurorStake;
+    uint16 jurorSlashBps;
+    address[] jurors;
+    mapping(address => Juror) jurorRecords;
+    uint256 totalJurorStakes;
+    mapping(uint256 => Jury) juries;
+    uint64 appealWindow;
+    uint16 appealBondBps;
+    uint256 minAppealBond;
+    uint256 totalAppealBonds;
+    mapping(uint256 => Appeal) appeals;
+    address[] listedArbitrators;
+    mapping(address => bool) isListedArbitrator;
+    mapping(address => uint256) lockedAmounts;
+    mapping(address => uint256) pendingEarnings;
+    mapping(address => FreelancerProfile) freelancerProfiles;
+    mapping(address => ClientProfile) clientProfiles;
+    address stakingToken;
+    uint16 maxUnstakeSlippageBps;
+    uint256 totalStaked;
+    uint256 totalStakingLosses;
+    mapping(uint256 => JobStake) jobStakes;
+    uint256[] activeJobIds;
+    mapping(uint256 => uint256) activeJobPositions;
+    uint256 totalFeesCollected;
+    uint256 totalDisputed;
+
+    event Deposited(uint256 indexed job_id, address indexed client, address indexed freelancer, uint256 amount, bytes32 memo, uint16 category, bytes32 tag);
+    event Released(uint256 indexed job_id, uint256 amount, bytes32 memo);
+    event Refunded(uint256 indexed job_id, uint256 amount, bytes32 memo);
+    event AutoReleased(uint256 indexed job_id, uint256 amount, bytes32 memo);
+    event EmergencyRefunded(uint256 indexed job_id, address indexed admin);
+    event PauseToggled(bool paused);
+    event OwnershipTransferred(address indexed old_admin, address indexed new_admin);
+    event SunsetBegun(uint64 timestamp);
+    event SunsetFinalized(address indexed admin, uint256 swept);
+    event DormancyPolicySet(uint64 period, address indexed recovery);
+    event DormancyAnnounced(uint256 indexed job_id, uint64 sweep_after);
+    event DormantSwept(uint256 indexed job_id, address indexed recovery, uint256 amount);
+    event PrivateDeposited(uint256 indexed job_id, address indexed client, uint256 amount, bytes32 commitment);
+    event JobRevealed(uint256 indexed job_id, address indexed freelancer, uint256 amount, bytes32 terms);
+    event SwapRouterSet(address indexed router, address weth);
+    event PayoutTokenSet(address indexed freelancer, address indexed token, uint256 min_rate);
+    event PayoutSwapped(uint256 indexed job_id, address indexed token, uint256 amount_in, uint256 amount_out);
+    event PayoutSwapFailed(uint256 indexed job_id, address indexed token);
+    event PayoutPushed(uint256 indexed job_id, address indexed recipient, uint256 amount, bool pushed);
+    event WithholdingSet(address indexed freelancer, address indexed destination, uint16 rate_bps);
+    event Withheld(uint256 indexed job_id, address indexed destination, uint256 net, uint256 withheld);
+    event ComplianceHookSet(address indexed hook);
+    event JobAttested(uint256 indexed job_id, bytes32 indexed uid);
+    event AttestationPolicySet(address indexed eas, bytes32 indexed schema, address indexed attester);
+    event SettlementHookSet(uint256 indexed job_id, address indexed hook);
+    event SettlementHookFailed(uint256 indexed job_id, address indexed hook);
+    event ReleaseConditionSet(uint256 indexed job_id, address indexed condition);
+    event TrancheClaimed(uint256 indexed job_id, uint256 index, uint256 amount, uint256 remaining);
+    event TrancheRefunded(uint256 indexed job_id, uint256 index, uint256 amount, uint256 remaining);
+    event RetainerStarted(uint256 indexed job_id, uint64 periods, uint64 period, uint64 notice);
+    event RetainerStopped(uint256 indexed job_id, uint256 refunded);
+    event CancellationNoticeSet(uint256 indexed job_id, uint64 notice);
+    event CancellationRequested(uint256 indexed job_id, uint64 effective_at);
+    event DeliverySubmitted(uint256 indexed job_id, bytes32 delivery);
+    event FundingOpened(uint256 indexed job_id, address indexed client, address indexed freelancer, uint256 target, uint64 cutoff);
+    event JobFunded(uint256 indexed job_id, address indexed contributor, uint256 amount, uint256 raised);
+    event ContributionReclaimed(uint256 indexed job_id, address indexed contributor, uint256 amount);
+    event ExcessCredited(uint256 indexed job_id, address indexed client, uint256 excess);
+    event CreditWithdrawn(address indexed account, uint256 amount);
+    event DefaultJobCapSet(uint32 cap);
+    event JobCapSet(address indexed freelancer, uint32 cap);
+    event DepositLimitsSet(uint64 cooldown, uint32 per_block);
+    event DepositLimitExemptionSet(address indexed account, bool exempt);
+    event ProtocolFeeSet(uint16 fee_bps);
+    event FeeCharged(uint256 indexed job_id, address indexed recipient, uint256 fee);
+    event FeeManagerSet(address indexed manager);
+    event FeeTiersSet(uint256[] min_amounts, uint16[] fee_bps);
+    event VolumeDiscountsSet(uint256[] min_volumes, uint16[] discount_bps);
+    event FeeExemptionSet(address indexed account, bool exempt);
+    event DisputeOpened(uint256 indexed job_id, address indexed opened_by, bytes32 memo);
+    event EvidenceSubmitted(uint256 indexed job_id, address indexed submitter, uint256 index, bytes32 evidence_hash);
+    event DisputeResolved(uint256 indexed job_id, address indexed resolver, uint256 freelancer_amount, uint256 client_amount, bool by_default);
+    event ArbitrationPolicySet(address indexed arbitrator, uint64 ruling_period, uint16 default_split_bps);
+    event Reclaimed(uint256 indexed job_id, address indexed caller, uint256 amount, bytes32 memo);
+    event FundsReturned(uint256 indexed job_id, uint256 amount, bytes32 memo);
+    event DualApprovalRequired(uint256 indexed job_id);
+    event ReleaseApproved(uint256 indexed job_id, address indexed approver);
+    event DisputeTimedOut(uint256 indexed job_id, bool split);
+    event DisputeTimeoutPolicySet(uint64 timeout, bool split, uint16 split_bps);
+    event DisputeStakePolicySet(uint16 stake_bps, uint256 min_stake);
+    event DisputeStakeSettled(uint256 indexed job_id, address indexed recipient, uint256 amount, bool forfeited);
+    event JuryPolicySet(uint8 size, uint64 voting_period, uint256 min_stake, uint16 slash_bps);
+    event JurorStaked(address indexed juror, uint256 amount, uint256 stake);
+    event JurorLeft(address indexed juror, uint256 amount);
+    event JuryDrawn(uint256 indexed job_id, address[] panel, uint64 vote_by);
+    event JuryVoted(uint256 indexed job_id, address indexed juror, uint16 freelancer_bps);
+    event JuryTallied(uint256 indexed job_id, bool decided, uint16 freelancer_bps, uint256 slashed);
+    event AppealPolicySet(uint64 window, uint16 bond_bps, uint256 min_bond);
+    event RulingHeld(uint256 indexed job_id, address indexed resolver, uint16 freelancer_bps, uint64 appealable_until);
+    event RulingAppealed(uint256 indexed job_id, address indexed appellant, uint16 freelancer_bps, uint256 bond, uint256 indexed proposal_id);
+    event AppealBondSettled(uint256 indexed job_id, address indexed recipient, uint256 amount, bool forfeited);
+    event JobArbitratorSet(uint256 indexed job_id, address indexed arbitrator);
+    event ArbitratorListed(address indexed arbitrator, bool listed);
+    event ArbitratorReplaced(uint256 indexed job_id, address indexed old_arbitrator, address indexed new_arbitrator, uint64 rule_by);
+    event JobAmended(uint256 indexed job_id, uint8 indexed field, bytes32 old_value, bytes32 new_value, address indexed actor);
+    event FeesWithdrawn(address indexed to, uint256 amount, uint256 remaining);
+    event TreasuryProposed(address indexed treasury, uint64 effective_at);
+    event TreasurySet(address indexed treasury);
+    event GuardiansSet(address[] guardians, uint8 threshold);
+    event EmergencyProposed(uint256 indexed proposal_id, uint8 action, uint256 target, address indexed proposer, uint64 expires_at);
+    event EmergencyConfirmed(uint256 indexed proposal_id, address indexed guardian, uint8 confirmations);
+    event GovernanceSet(address token, address[] council, uint256 quorum);
+    event GovernanceProposed(uint256 indexed proposal_id, uint8 action, address target, uint256 value, address indexed proposer, uint64 voting_ends);
+    event GovernanceVoted(uint256 indexed proposal_id, address indexed voter, bool support, uint256 weight);
+    event GovernanceExecuted(uint256 indexed proposal_id);
+    event PauserSet(address indexed pauser);
+    event MaxPauseSet(uint64 duration);
+    event RenounceProposed(uint64 effective_at);
+    event PlatformFeeSet(uint256 indexed job_id, address indexed platform, uint16 fee_bps);
+    event CreditDeposited(address indexed account, uint256 amount);
+    event OfferCancelled(address indexed client, uint256 nonce);
+    event AuthorizationCancelled(address indexed signer, uint256 nonce);
+    event InvoiceCreated(uint256 indexed invoice_id, address indexed freelancer, address indexed client, uint256 amount, uint64 duration, bytes32 metadata, uint64 expiry);
+    event InvoiceFunded(uint256 indexed invoice_id, uint256 indexed job_id);
+    event InvoicePruned(uint256 indexed invoice_id);
+    event PartiallyReleased(uint256 indexed job_id, uint256 amount, uint256 remaining);
+    event HoldbackSet(uint256 indexed job_id, uint16 holdback_bps, uint64 warranty);
+    event HoldbackRetained(uint256 indexed job_id, uint256 amount, uint64 release_at);
+    event ClawbackWindowSet(uint256 indexed job_id, uint64 window);
+    event ReleaseQueued(uint256 indexed job_id, uint256 amount, uint64 payable_at);
+    event ReleaseReversed(uint256 indexed job_id, address indexed arbitrator, uint256 amount);
+    event SlaSet(uint256 indexed job_id, uint64 delivery_by, uint16 late_bps_per_day);
+    event LateRefunded(uint256 indexed job_id, uint64 days_late, uint256 amount);
+    event HourlyRateSet(uint256 indexed job_id, uint256 rate);
+    event TimesheetSubmitted(uint256 indexed job_id, uint256 index, uint32 hours_, uint256 amount, bytes32 details);
+    event TimesheetApproved(uint256 indexed job_id, uint256 index, uint256 amount, uint256 remaining);
+    event TimesheetRejected(uint256 indexed job_id, uint256 index);
+    event BasketTokenSet(address indexed token, bool approved);
+    event BasketFunded(uint256 indexed job_id, address indexed token, uint256 amount);
+    event BasketPaid(uint256 indexed job_id, address indexed token, address indexed recipient, uint256 amount);
+    event RefundRootSet(bytes32 indexed root);
+    event EmergencyRefundBatch(address indexed admin, uint256[] job_ids, bool[] refunded);
+    event SettlementRebateSet(uint256 amount);
+    event SettlementRebated(uint256 indexed job_id, address indexed caller, uint256 amount);
+    event JobRated(uint256 indexed job_id, address indexed rater, address indexed ratee, uint8 stars);
+    event EmergencyRefundClaimed(uint256 indexed job_id, address indexed client, uint256 amount, uint256 remaining);
+    event SurplusSwept(address indexed to, uint256 amount);
+    event PauseExtended(uint64 until);
+    event ClientTransferProposed(uint256 indexed job_id, address indexed client, address indexed new_client);
+    event JobAccepted(uint256 indexed job_id, address indexed freelancer);
+    event FreelancerChanged(uint256 indexed job_id, address indexed old_freelancer, address indexed new_freelancer);
+    event PayoutAddressProposed(address indexed freelancer, address indexed payout_address, uint64 effective_at);
+    event PayoutAddressSet(address indexed freelancer, address indexed payout_address);
+    event StakingTokenSet(address indexed token, uint16 max_slippage_bps);
+    event StakeConsented(
+        uint256 indexed job_id, address indexed party, uint16 freelancer_yield_bps, uint16 treasury_yield_bps
+    );
+    event JobStaked(uint256 indexed job_id, address indexed token, uint256 amount, uint256 shares);
+    event JobUnstaked(uint256 indexed job_id, address indexed buyer, uint256 principal, uint256 paid, uint256 client_yield, uint256 freelancer_yield, uint256 treasury_yield);
+    event YieldSplitSet(uint256 indexed job_id, uint16 client_yield_bps, uint16 freelancer_yield_bps, uint16 treasury_yield_bps);
+
+    constructor(address admin_) {
+        require(admin_ != address(0), "Invalid admin address");
+        admin = admin_;
+        paused = false;
+        jobCount = 0;
+    }
+
+    receive() external payable {
+        revert("Direct transfers not accepted");
+    }
+
+    fallback() external {
+        revert("Unknown function");
+    }
+
+    function deposit(address freelancer, uint64 duration, bytes32 memo) external payable returns (uint256) {
+        return openJob(freelancer, duration, memo);
+    }
+
+    function depositCategorized(address freelancer, uint64 duration, bytes32 memo, uint16 category, bytes32 tag)
+        external
+        payable
+        returns (uint256)
+    {
+        return openJobAs(msg.sender, freelancer, duration, memo, msg.value, category, tag);
+    }
+
+    function depositWithArbitrator(address freelancer, uint64 duration, bytes32 memo, address arbitrator_)
+        external
+        payable
+        returns (uint256)
+    {
+        require(arbitrator_ != address(0), "Invalid arbitrator");
+        require(arbitrator_ != msg.sender && arbitrator_ != freelancer, "Arbitrator is a party");
+
+        uint256 jobId = openJob(freelancer, duration, memo);
+        jobs[jobId].arbitrator = arbitrator_;
+        emit JobArbitratorSet(jobId, arbitrator_);
+        return jobId;
+    }
+
+    function depositExact(address freelancer, uint64 duration, uint256 amount) external payable returns (uint256) {
+        require(msg.value >= amount, "Insufficient deposit");
+
+        uint256 jobId = openJobFor(freelancer, duration, bytes32(0), amount);
+        uint256 excess = msg.value - amount;
+        if (excess != 0) {
+            credit(msg.sender, excess);
+            emit ExcessCredited(jobId, msg.sender, excess);
+        }
+        return jobId;
+    }
+
+    function withdrawCredit() external {
+        require(!pausedNow(), "Escrow is paused");
+        uint256 amount = credits[msg.sender];
+        require(amount != 0, "Nothing to withdraw");
+
+        credits[msg.sender] = 0;
+        totalCredit -= amount;
+
+        pay(msg.sender, amount);
+
+        emit CreditWithdrawn(msg.sender, amount);
+    }
+
+    function getCredit(address account) external view returns (uint256) {
+        return credits[account];
+    }
+
+    function depositCredit() external payable {
+        require(!pausedNow(), "Escrow is paused");
+        assertNotSunset();
+        require(msg.value != 0, "Amount must be > 0");
+
+        credit(msg.sender, msg.value);
+
+        emit CreditDeposited(msg.sender, msg.value);
+    }
+
+    function depositWithNonce(address freelancer, uint64 duration, uint256 nonce) external payable returns (uint256) {
+        assertNonceUnused(msg.sender, nonce);
+
+        uint256 jobId = openJob(freelancer, duration, bytes32(0));
+        nonceJobs[msg.sender][nonce] = jobId;
+
+        return jobId;
+    }
+
+    function getJobByNonce(address client, uint256 nonce) external view returns (uint256) {
+        return nonceJobs[client][nonce];
+    }
+
+    function acceptOffer(
+        address client,
+        uint256 amount,
+        uint64 duration,
+        bytes32 memo,
+        uint256 nonce,
+        uint64 expiry,
+        bytes calldata signature
+    ) external returns (uint256) {
+        require(block.timestamp < expiry, "Offer expired");
+        assertNonceUnused(client, nonce);
+
+        bytes32 digest = offerHash(client, msg.sender, amount, duration, memo, nonce, expiry);
+        require(isSignedBy(client, digest, signature), "Invalid signature");
+
+        require(credits[client] >= amount, "Insufficient credit");
+        credits[client] -= amount;
+        totalCredit -= amount;
+
+        uint256 jobId = openJobAs(client, msg.sender, duration, memo, amount, 0, bytes32(0));
+        nonceJobs[client][nonce] = jobId;
+
+        return jobId;
+    }
+
+    function cancelOffer(uint256 nonce) external {
+        assertNonceUnused(msg.sender, nonce);
+        cancelledOffers[msg.sender][nonce] = true;
+
+        emit OfferCancelled(msg.sender, nonce);
+    }
+
+    function isOfferCancelled(address client, uint256 nonce) external view returns (bool) {
+        return cancelledOffers[client][nonce];
+    }
+
+    function releaseWithSignature(uint256 job_id, bytes32 memo, uint256 nonce, uint64 expiry, bytes calldata signature)
+        external
+    {
+        assertJobExists(job_id);
+        address client = jobs[job_id].client;
+        spendAuthorization(job_id, client, true, memo, nonce, expiry, signature);
+        releaseAs(client, job_id, memo);
+    }
+
+    function refundWithSignature(
+        uint256 job_id,
+        address signer,
+        bytes32 memo,
+        uint256 nonce,
+        uint64 expiry,
+        bytes calldata signature
+    ) external {
+        assertJobExists(job_id);
+        Job storage job = jobs[job_id];
+        require(
+            signer == job.client || (signer == job.freelancer && job.freelancer != address(0)), "Not a party"
+        );
+        spendAuthorization(job_id, signer, false, memo, nonce, expiry, signature);
+        if (signer == job.client) {
+            refundAs(signer, job_id, memo);
+        } else {
+            returnFundsAs(signer, job_id, memo);
+        }
+    }
+
+    function getSettlementDigest(
+        uint256 job_id,
+        address signer,
+        bool release,
+        bytes32 memo,
+        uint256 nonce,
+        uint64 expiry
+    ) external view returns (bytes32) {
+        return settlementHash(job_id, signer, release, memo, nonce, expiry);
+    }
+
+    function verifySignature(address signer, bytes32 digest, bytes calldata signature) external view returns (bool) {
+        return isSignedBy(signer, digest, signature);
+    }
+
+    function cancelAuthorization(uint256 nonce) external {
+        assertAuthorizationUnused(msg.sender, nonce);
+        usedAuthorizations[msg.sender][nonce] = true;
+
+        emit AuthorizationCancelled(msg.sender, nonce);
+    }
+
+    function isAuthorizationUsed(address signer, uint256 nonce) external view returns (bool) {
+        return usedAuthorizations[signer][nonce];
+    }
+
+    function depositWithCondition(address freelancer, uint64 duration, address condition) external payable returns (uint256) {
+        require(condition != address(0), "Invalid condition address");
+
+        uint256 jobId = openJob(freelancer, duration, bytes32(0));
+        releaseConditions[jobId] = condition;
+
+        emit ReleaseConditionSet(jobId, condition);
+        return jobId;
+    }
+
+    function getReleaseCondition(uint256 job_id) external view returns (address) {
+        return releaseConditions[job_id];
+    }
+
+    function depositWithPlatform(address freelancer, uint64 duration, bytes32 memo, address platform, uint16 platform_bps)
+        external
+        payable
+        returns (uint256)
+    {
+        require(platform != address(0), "Invalid platform address");
+        require(platform_bps <= MAX_PLATFORM_FEE_BPS, "Invalid platform fee");
+
+        uint256 jobId = openJob(freelancer, duration, memo);
+        jobPlatforms[jobId] = JobPlatform(platform, platform_bps);
+
+        emit PlatformFeeSet(jobId, platform, platform_bps);
+        return jobId;
+    }
+
+    function getPlatformFee(uint256 job_id) external view returns (address, uint16) {
+        return (jobPlatforms[job_id].platform, jobPlatforms[job_id].feeBps);
+    }
+
+    function depositWithDualApproval(address freelancer, uint64 duration) external payable returns (uint256) {
+        uint256 jobId = openJob(freelancer, duration, bytes32(0));
+        dualApprovals[jobId].required = true;
+
+        emit DualApprovalRequired(jobId);
+        return jobId;
+    }
+
+    function approveRelease(uint256 job_id) external {
+        require(!pausedNow(), "Escrow is paused");
+
+        assertSettleable(job_id);
+        assertNotStaked(job_id);
+        DualApproval storage approval = dualApprovals[job_id];
+        require(approval.required, "Dual approval not required");
+        Job storage job = jobs[job_id];
+        bool approved;
+        bool otherApproved;
+        if (msg.sender == job.client) {
+            (approved, otherApproved) = (approval.clientApproved, approval.freelancerApproved);
+        } else if (msg.sender == job.freelancer) {
+            (approved, otherApproved) = (approval.freelancerApproved, approval.clientApproved);
+        } else {
+            revert("Only job parties can approve");
+        }
+        require(!approved, "Release already approved");
+        if (otherApproved) {
+            assertNotDisputed(job_id);
+            assertCompliant(job.client, job.freelancer, job.amount);
+        }
+
+        if (msg.sender == job.client) {
+            approval.clientApproved = true;
+        } else {
+            approval.freelancerApproved = true;
+        }
+
+        emit ReleaseApproved(job_id, msg.sender);
+
+        if (otherApproved) {
+            releaseApproved(job_id);
+        }
+    }
+
+    function consentToStake(uint256 job_id, uint16 freelancer_yield_bps, uint16 treasury_yield_bps) external {
+        require(!pausedNow(), "Escrow is paused");
+
+        consentToStakeAs(job_id, msg.sender, freelancer_yield_bps, treasury_yield_bps);
+    }
+
+    function unstakeJob(uint256 job_id) external payable {
+        assertNotRetired();
+        unwindStake(job_id, msg.sender, msg.value);
+    }
+
+    function getDualApproval(uint256 job_id) external view returns (bool, bool, bool) {
+        DualApproval storage approval = dualApprovals[job_id];
+        return (approval.required, approval.clientApproved, approval.freelancerApproved);
+    }
+
+    function depositWithNotice(address freelancer, uint64 duration, uint64 notice) external payable returns (uint256) {
+        require(notice != 0, "Invalid notice");
+
+        uint256 jobId = openJob(freelancer, duration, bytes32(0));
+        cancellations[jobId].notice = notice;
+
+        emit CancellationNoticeSet(jobId, notice);
+        return jobId;
+    }
+
+    function getCancellation(uint256 job_id) external view returns (uint64, uint64) {
+        Cancellation storage cancellation = cancellations[job_id];
+        return (cancellation.notice, cancellation.effectiveAt);
+    }
+
+    function depositWithHoldback(address freelancer, uint64 duration, uint16 holdback_bps, uint64 warranty)
+        external
+        payable
+        returns (uint256)
+    {
+        require(holdback_bps != 0 && holdback_bps < BASIS_POINTS, "Invalid holdback");
+        require(warranty != 0, "Invalid warranty");
+
+        uint256 jobId = openJob(freelancer, duration, bytes32(0));
+        holdbacks[jobId].bps = holdback_bps;
+        holdbacks[jobId].warranty = warranty;
+
+        emit HoldbackSet(jobId, holdback_bps, warranty);
+        return jobId;
+    }
+
+    function getHoldback(uint256 job_id) external view returns (uint16, uint64, uint64) {
+        Holdback storage holdback = holdbacks[job_id];
+        return (holdback.bps, holdback.warranty, holdback.warrantyEnds);
+    }
+
+    function depositWithClawback(address freelancer, uint64 duration, uint64 window) external payable returns (uint256) {
+        require(window != 0 && window <= MAX_CLAWBACK_WINDOW, "Invalid clawback window");
+        require(arbitrator != address(0), "No arbitrator");
+
+        uint256 jobId = openJob(freelancer, duration, bytes32(0));
+        clawbacks[jobId].window = window;
+
+        emit ClawbackWindowSet(jobId, window);
+        return jobId;
+    }
+
+    function getClawback(uint256 job_id) external view returns (uint64, uint64) {
+        Clawback storage clawback = clawbacks[job_id];
+        return (clawback.window, clawback.payableAt);
+    }
+
+    function depositWithSla(address freelancer, uint64 duration, uint64 delivery_by, uint16 late_bps_per_day)
+        external
+        payable
+        returns (uint256)
+    {
+        require(delivery_by > block.timestamp, "Invalid delivery date");
+        require(late_bps_per_day != 0 && late_bps_per_day <= BASIS_POINTS, "Invalid late refund");
+
+        uint256 jobId = openJob(freelancer, duration, bytes32(0));
+        slas[jobId].deliveryBy = delivery_by;
+        slas[jobId].lateBpsPerDay = late_bps_per_day;
+
+        emit SlaSet(jobId, delivery_by, late_bps_per_day);
+        return jobId;
+    }
+
+    function getSla(uint256 job_id) external view returns (uint64, uint16, uint64) {
+        Sla storage sla = slas[job_id];
+        return (sla.deliveryBy, sla.lateBpsPerDay, sla.deliveredAt);
+    }
+
+    function createJob(address freelancer, uint64 duration, uint256 target, uint64 cutoff) external payable returns (uint256) {
+        require(!pausedNow(), "Escrow is paused");
+        assertNotSunset();
+        require(freelancer != address(0), "Invalid freelancer address");
+        require(duration != 0, "Duration must be > 0");
+        require(target != 0, "Invalid target");
+        require(cutoff > block.timestamp, "Invalid cutoff");
+        require(msg.value <= target, "Funding exceeds target");
+        assertCompliant(msg.sender, freelancer, target);
+        if (msg.value == target) {
+            assertBelowCap(freelancer);
+        }
+        recordDepositRate(msg.sender);
+
+        uint256 jobId = pushJob(msg.sender, freelancer, target);
+        fundings[jobId] = Funding({target: target, raised: 0, duration: duration, cutoff: cutoff});
+
+        recordClientDeposit(msg.sender, 0);
+
+        emit FundingOpened(jobId, msg.sender, freelancer, target, cutoff);
+        if (msg.value != 0) {
+            addContribution(jobId, msg.sender, msg.value);
+        }
+        return jobId;
+    }
+
+    function fund(uint256 job_id) external payable {
+        require(!pausedNow(), "Escrow is paused");
+        assertNotRetired();
+        require(msg.value != 0, "Amount must be > 0");
+        Funding storage funding = fundings[job_id];
+        require(funding.raised < funding.target, "Job not funding");
+        require(block.timestamp < funding.cutoff, "Funding closed");
+        require(funding.raised + msg.value <= funding.target, "Funding exceeds target");
+        address freelancer = jobs[job_id].freelancer;
+        assertCompliant(msg.sender, freelancer, msg.value);
+        if (funding.raised + msg.value == funding.target) {
+            assertBelowCap(freelancer);
+        }
+
+        addContribution(job_id, msg.sender, msg.value);
+    }
+
+    function reclaimContribution(uint256 job_id) external {
+        require(!pausedNow(), "Escrow is paused");
+        Funding storage funding = fundings[job_id];
+        require(funding.raised < funding.target, "Job not funding");
+        require(block.timestamp >= funding.cutoff, "Funding still open");
+        uint256 amount = contributions[job_id][msg.sender];
+        require(amount != 0, "Nothing to reclaim");
+
+        contributions[job_id][msg.sender] = 0;
+        Job storage job = jobs[job_id];
+        job.amount -= amount;
+        if (job.amount == 0) {
+            job.refunded = true;
+            closeJob(job_id);
+        }
+        recordRefund(job_id, msg.sender, amount);
+
+        pay(msg.sender, amount);
+
+        emit ContributionReclaimed(job_id, msg.sender, amount);
+    }
+
+    function getFunding(uint256 job_id) external view returns (uint256, uint256, uint64) {
+        Funding storage funding = fundings[job_id];
+        return (funding.target, funding.raised, funding.cutoff);
+    }
+
+    function getContribution(uint256 job_id, address contributor) external view returns (uint256) {
+        return contributions[job_id][contributor];
+    }
+
+    function createInvoice(address client, uint256 amount, uint64 duration, bytes32 metadata, uint64 expiry)
+        external
+        returns (uint256)
+    {
+        require(!pausedNow(), "Escrow is paused");
+        assertNotSunset();
+        require(client != address(0), "Invalid client address");
+        require(amount != 0, "Amount must be > 0");
+        require(duration != 0, "Duration must be > 0");
+        require(expiry > block.timestamp, "Invalid expiry");
+
+        uint256 invoiceId = ++invoiceCount;
+        invoices[invoiceId] = Invoice(msg.sender, client, amount, duration, metadata, expiry, 0);
+
+        emit InvoiceCreated(invoiceId, msg.sender, client, amount, duration, metadata, expiry);
+        return invoiceId;
+    }
+
+    function fundInvoice(uint256 invoice_id) external payable returns (uint256) {
+        Invoice storage invoice = invoices[invoice_id];
+        require(invoice.freelancer != address(0), "Invoice not found");
+        require(invoice.jobId == 0, "Invoice already funded");
+        require(msg.sender == invoice.client, "Only invoiced client");
+        require(block.timestamp < invoice.expiresAt, "Invoice expired");
+        require(msg.value == invoice.amount, "Incorrect payment");
+
+        uint256 jobId = openJob(invoice.freelancer, invoice.duration, invoice.metadata);
+        invoice.jobId = jobId;
+
+        emit InvoiceFunded(invoice_id, jobId);
+        return jobId;
+    }
+
+    function pruneInvoices(uint256[] calldata invoice_ids) external returns (uint32) {
+        uint32 pruned = 0;
+        for (uint256 i = 0; i < invoice_ids.length; i++) {
+            Invoice storage invoice = invoices[invoice_ids[i]];
+            if (invoice.freelancer == address(0) || invoice.jobId != 0 || block.timestamp < invoice.expiresAt) {
+                continue;
+            }
+
+            delete invoices[invoice_ids[i]];
+            pruned += 1;
+
+            emit InvoicePruned(invoice_ids[i]);
+        }
+        return pruned;
+    }
+
+    function getInvoice(uint256 invoice_id)
+        external
+        view
+        returns (address, address, uint256, uint64, bytes32, uint64, uint256)
+    {
+        Invoice storage invoice = invoices[invoice_id];
+        return (
+            invoice.freelancer,
+            invoice.client,
+            invoice.amount,
+            invoice.duration,
+            invoice.metadata,
+            invoice.expiresAt,
+            invoice.jobId
+        );
+    }
+
+    function depositTranched(address freelancer, uint256[] calldata amounts, uint64[] calldata unlocks) external payable returns (uint256) {
+        uint256 count = amounts.length;
+        require(count != 0 && count <= MAX_TRANCHES && unlocks.length == count, "Invalid tranches");
+        uint256 total;
+        for (uint256 i = 0; i < count; i++) {
+            require(amounts[i] != 0, "Amount must be > 0");
+            require(i == 0 || unlocks[i] > unlocks[i - 1], "Unlocks must be increasing");
+            total += amounts[i];
+        }
+        require(total == msg.value, "Tranches must sum to deposit");
+        uint64 last = unlocks[count - 1];
+        require(last > block.timestamp, "Invalid unlock time");
+
+        uint256 jobId = openJob(freelancer, last - uint64(block.timestamp), bytes32(0));
+        for (uint256 i = 0; i < count; i++) {
+            tranches[jobId].push(Tranche({amount: amounts[i], unlockAt: unlocks[i], settled: false}));
+        }
+
+        return jobId;
+    }
+
+    function claimTranche(uint256 job_id, uint256 index) external {
+        require(!pausedNow(), "Escrow is paused");
+
+        Job storage job = jobs[job_id];
+        require(job.freelancer == msg.sender, "Only freelancer can claim");
+        require(!job.released && !job.refunded, "Job already settled");
+        require(!finalized[job_id], "Job already finalized");
+        require(index < tranches[job_id].length, "Invalid tranche");
+        Tranche storage tranche = tranches[job_id][index];
+        require(!tranche.settled, "Tranche already settled");
+        require(block.timestamp >= tranche.unlockAt, "Tranche locked");
+        assertNotDisputed(job_id);
+
+        payTranche(job_id, index);
+    }
+
+    function refundTranche(uint256 job_id, uint256 index) external {
+        require(!pausedNow(), "Escrow is paused");
+
+        Job storage job = jobs[job_id];
+        require(job.client == msg.sender, "Only client can refund");
+        require(!job.released && !job.refunded, "Job already settled");
+        require(!finalized[job_id], "Job already finalized");
+        require(retainers[job_id].period == 0, "Job is a retainer");
+        require(index < tranches[job_id].length, "Invalid tranche");
+        Tranche storage tranche = tranches[job_id][index];
+        require(!tranche.settled, "Tranche already settled");
+        require(block.timestamp < tranche.unlockAt, "Tranche unlocked");
+
+        returnTranche(job_id, index);
+    }
+
+    function depositRetainer(address freelancer, uint64 periods, uint64 period, uint64 notice) external payable returns (uint256) {
+        require(periods != 0 && periods <= MAX_RETAINER_PERIODS, "Invalid periods");
+        require(period != 0, "Invalid period");
+        require(msg.value % periods == 0, "Deposit must split evenly");
+
+        uint64 start = uint64(block.timestamp);
+        uint256 jobId = openJob(freelancer, periods * period, bytes32(0));
+        uint256 amount = msg.value / periods;
+        for (uint64 i = 1; i <= periods; i++) {
+            tranches[jobId].push(Tranche({amount: amount, unlockAt: start + i * period, settled: false}));
+        }
+        retainers[jobId] = Retainer({period: period, notice: notice});
+
+        emit RetainerStarted(jobId, periods, period, notice);
+
+        return jobId;
+    }
+
+    function claimUnlocked(uint256 job_id) external {
+        require(!pausedNow(), "Escrow is paused");
+
+        Job storage job = jobs[job_id];
+        require(job.freelancer == msg.sender, "Only freelancer can claim");
+        require(!job.released && !job.refunded, "Job already settled");
+        require(!finalized[job_id], "Job already finalized");
+        Tranche[] storage schedule = tranches[job_id];
+        bool disputed = isDisputed(job_id);
+        bool claimed;
+        for (uint256 i = 0; i < schedule.length; i++) {
+            if (!schedule[i].settled && schedule[i].unlockAt <= block.timestamp) {
+                payTranche(job_id, i);
+                claimed = true;
+            }
+        }
+        require(claimed, "Nothing to claim");
+        require(!disputed, "Job is disputed");
+    }
+
+    function stopRetainer(uint256 job_id) external {
+        require(!pausedNow(), "Escrow is paused");
+
+        Job storage job = jobs[job_id];
+        require(job.client == msg.sender, "Only client can stop");
+        require(!job.released && !job.refunded, "Job already settled");
+        require(!finalized[job_id], "Job already finalized");
+        require(retainers[job_id].period != 0, "Job is not a retainer");
+        uint256 cutoff = block.timestamp + retainers[job_id].notice;
+        Tranche[] storage schedule = tranches[job_id];
+        uint256 refunded;
+        for (uint256 i = 0; i < schedule.length; i++) {
+            if (!schedule[i].settled && schedule[i].unlockAt > cutoff) {
+                refunded += schedule[i].amount;
+                returnTranche(job_id, i);
+            }
+        }
+        require(refunded != 0, "Nothing to stop");
+
+        emit RetainerStopped(job_id, refunded);
+    }
+
+    function getRetainer(uint256 job_id) external view returns (uint64, uint64) {
+        Retainer storage retainer = retainers[job_id];
+        return (retainer.period, retainer.notice);
+    }
+
+    function getTranches(uint256 job_id) external view returns (uint256[] memory, uint64[] memory, bool[] memory) {
+        Tranche[] storage schedule = tranches[job_id];
+        uint256[] memory amounts = new uint256[](schedule.length);
+        uint64[] memory unlocks = new uint64[](schedule.length);
+        bool[] memory settled = new bool[](schedule.length);
+        for (uint256 i = 0; i < schedule.length; i++) {
+            amounts[i] = schedule[i].amount;
+            unlocks[i] = schedule[i].unlockAt;
+            settled[i] = schedule[i].settled;
+        }
+        return (amounts, unlocks, settled);
+    }
+
+    function depositHourly(address freelancer, uint64 duration, uint256 rate) external payable returns (uint256) {
+        require(rate != 0 && rate <= msg.value, "Invalid rate");
+
+        uint256 jobId = openJob(freelancer, duration, bytes32(0));
+        engagements[jobId].rate = rate;
+
+        emit HourlyRateSet(jobId, rate);
+        return jobId;
+    }
+
+    function submitTimesheet(uint256 job_id, uint32 hours_, bytes32 details) external returns (uint256) {
+        require(!pausedNow(), "Escrow is paused");
+
+        assertSettleable(job_id);
+        Job storage job = jobs[job_id];
+        require(job.freelancer == msg.sender, "Only freelancer can bill");
+        require(engagements[job_id].rate != 0, "Job is not hourly");
+        require(hours_ != 0, "Invalid hours");
+        assertNotDisputed(job_id);
+        uint256 amount = engagements[job_id].rate * hours_;
+        require(engagements[job_id].pending + amount <= job.amount, "Exceeds budget");
+
+        uint256 index = pushTimesheet(job_id, hours_, details, amount);
+
+        emit TimesheetSubmitted(job_id, index, hours_, amount, details);
+        return index;
+    }
+
+    function approveTimesheet(uint256 job_id, uint256 index) external {
+        require(!pausedNow(), "Escrow is paused");
+
+        assertSettleable(job_id);
+        Job storage job = jobs[job_id];
+        require(job.client == msg.sender, "Only client can approve");
+        uint256 amount = submittedTimesheet(job_id, index);
+        assertNotDisputed(job_id);
+        assertCompliant(job.client, job.freelancer, amount);
+
+        payTimesheet(job_id, index, amount);
+    }
+
+    function rejectTimesheet(uint256 job_id, uint256 index) external {
+        require(!pausedNow(), "Escrow is paused");
+
+        assertSettleable(job_id);
+        require(jobs[job_id].client == msg.sender, "Only client can reject");
+        uint256 amount = submittedTimesheet(job_id, index);
+
+        rejectTimesheetAt(job_id, index, amount);
+    }
+
+    function endEngagement(uint256 job_id) external {
+        require(!pausedNow(), "Escrow is paused");
+
+        assertSettleable(job_id);
+        assertNotStaked(job_id);
+        Job storage job = jobs[job_id];
+        require(job.client == msg.sender, "Only client can end");
+        require(engagements[job_id].rate != 0, "Job is not hourly");
+        require(block.timestamp >= job.deadline, "Engagement running");
+        assertNotDisputed(job_id);
+        require(engagements[job_id].pending == 0, "Timesheets pending");
+
+        job.refunded = true;
+        closeJob(job_id);
+        recordRefund(job_id, job.client, job.amount);
+
+        pay(job.client, job.amount);
+
+        emit Refunded(job_id, job.amount, bytes32(0));
+        callSettlementHook(job_id, job.amount, false);
+    }
+
+    function getEngagement(uint256 job_id) external view returns (uint256, uint256) {
+        Engagement storage engagement = engagements[job_id];
+        return (engagement.rate, engagement.pending);
+    }
+
+    function getTimesheets(uint256 job_id) external view returns (uint32[] memory, bytes32[] memory, uint8[] memory) {
+        Timesheet[] storage sheets = timesheets[job_id];
+        uint32[] memory hours_ = new uint32[](sheets.length);
+        bytes32[] memory details = new bytes32[](sheets.length);
+        uint8[] memory statuses = new uint8[](sheets.length);
+        for (uint256 i = 0; i < sheets.length; i++) {
+            hours_[i] = sheets[i].hours_;
+            details[i] = sheets[i].details;
+            statuses[i] = sheets[i].status;
+        }
+        return (hours_, details, statuses);
+    }
+
+    function depositBasket(address freelancer, uint64 duration, address[] calldata tokens, uint256[] calldata amounts)
+        external
+        payable
+        returns (uint256)
+    {
+        uint256 count = tokens.length;
+        require(count != 0 && count <= MAX_BASKET_TOKENS && amounts.length == count, "Invalid basket");
+        for (uint256 i = 0; i < count; i++) {
+            require(basketTokens[tokens[i]], "Token not approved");
+            for (uint256 j = 0; j < i; j++) {
+                require(tokens[j] != tokens[i], "Duplicate token");
+            }
+            require(amounts[i] != 0, "Amount must be > 0");
+        }
+
+        uint256 jobId = openJob(freelancer, duration, bytes32(0));
+        for (uint256 i = 0; i < count; i++) {
+            fundBasket(jobId, msg.sender, tokens[i], amounts[i]);
+
+            emit BasketFunded(jobId, tokens[i], amounts[i]);
+        }
+        return jobId;
+    }
+
+    function getBasket(uint256 job_id) external view returns (address[] memory, uint256[] memory) {
+        BasketToken[] storage basket = baskets[job_id];
+        address[] memory tokens = new address[](basket.length + 1);
+        uint256[] memory amounts = new uint256[](basket.length + 1);
+        amounts[0] = jobs[job_id].amount;
+        for (uint256 i = 0; i < basket.length; i++) {
+            tokens[i + 1] = basket[i].token;
+            amounts[i + 1] = basket[i].amount;
+        }
+        return (tokens, amounts);
+    }
+
+    function depositAttested(address freelancer, uint64 duration, bytes32 uid) external payable returns (uint256) {
+        assertAttested(freelancer, uid);
+
+        uint256 jobId = openJob(freelancer, duration, bytes32(0));
+        jobs[jobId].attestation = uid;
+
+        emit JobAttested(jobId, uid);
+        return jobId;
+    }
+
+    function getAttestation(uint256 job_id) external view returns (bytes32) {
+        return jobs[job_id].attestation;
+    }
+
+    function openJob(address freelancer, uint64 duration, bytes32 memo) private returns (uint256) {
+        return openJobFor(freelancer, duration, memo, msg.value);
+    }
+
+    function openJobFor(address freelancer, uint64 duration, bytes32 memo, uint256 amount) private returns (uint256) {
+        return openJobAs(msg.sender, freelancer, duration, memo, amount, 0, bytes32(0));
+    }
+
+    function openJobAs(
+        address client,
+        address freelancer,
+        uint64 duration,
+        bytes32 memo,
+        uint256 amount,
+        uint16 category,
+        bytes32 tag
+    ) private returns (uint256) {
+        require(!pausedNow(), "Escrow is paused");
+        assertNotSunset();
+        require(amount != 0, "Amount must be > 0");
+        require(freelancer != address(0), "Invalid freelancer address");
+        require(duration != 0, "Duration must be > 0");
+        assertCompliant(client, freelancer, amount);
+        assertBelowCap(freelancer);
+        recordDepositRate(client);
+
+        uint256 newId = pushJob(client, freelancer, amount);
+        Job storage job = jobs[newId];
+        job.amount = amount;
+        job.deadline = uint64(block.timestamp) + duration;
+
+        finalized[newId] = false;
+        recordDeposit(client, freelancer, amount);
+        lockEscrow(newId, amount);
+        if (category != 0 || tag != bytes32(0)) {
+            categorize(newId, category, tag, amount);
+        }
+
+        emit Deposited(newId, client, freelancer, amount, memo, category, tag);
+        return newId;
+    }
+
+    function depositPrivate(bytes32 commitment, uint64 duration) external payable returns (uint256) {
+        require(!pausedNow(), "Escrow is paused");
+        assertNotSunset();
+        require(msg.value != 0, "Amount must be > 0");
+        require(commitment != bytes32(0), "Invalid commitment");
+        require(duration != 0, "Duration must be > 0");
+        assertCompliant(msg.sender, address(0), msg.value);
+        recordDepositRate(msg.sender);
+
+        uint256 newId = pushJob(msg.sender, address(0), msg.value);
+        Job storage job = jobs[newId];
+        job.amount = msg.value;
+        job.deadline = uint64(block.timestamp) + duration;
+        job.commitment = commitment;
+
+        recordClientDeposit(msg.sender, msg.value);
+        lockEscrow(newId, msg.value);
+
+        emit PrivateDeposited(newId, msg.sender, msg.value, commitment);
+        return newId;
+    }
+
+    function revealJob(uint256 job_id, address freelancer, uint256 amount, bytes32 terms, bytes32 salt) external {
+        require(!pausedNow(), "Escrow is paused");
+
+        Job storage job = jobs[job_id];
+        require(job.commitment != bytes32(0), "Job is not private");
+        require(msg.sender == job.client || msg.sender == freelancer, "Only job parties can reveal");
+        require(!job.released && !job.refunded, "Job already settled");
+        require(!finalized[job_id], "Job already finalized");
+        require(job.freelancer == address(0), "Job already revealed");
+        require(freelancer != address(0), "Invalid freelancer address");
+        require(
+            keccak256(abi.encode(job.client, freelancer, amount, terms, salt)) == job.commitment,
+            "Commitment mismatch"
+        );
+        require(amount != 0, "Amount must be > 0");
+        uint256 deposit = job.amount;
+        require(amount <= deposit, "Amount exceeds deposit");
+        assertBelowCap(freelancer);
+
+        unlockEscrow(job_id, deposit);
+        job.freelancer = freelancer;
+        job.amount = amount;
+        lockEscrow(job_id, deposit);
+        indexParty(job_id, freelancer);
+        snapshotFee(job_id, job.client, amount);
+        recordAssignment(freelancer);
+
+        uint256 excess = deposit - amount;
+        if (excess != 0) {
+            recordRefund(job_id, job.client, excess);
+            pay(job.client, excess);
+        }
+
+        emit JobRevealed(job_id, freelancer, amount, terms);
+    }
+
+    function getCommitment(uint256 job_id) external view returns (bytes32) {
+        return jobs[job_id].commitment;
+    }
+
+    function getDispute(uint256 job_id) external view returns (address, uint64, address, uint64, uint16, bool) {
+        Dispute storage dispute = disputes[job_id];
+        return (
+            dispute.openedBy,
+            dispute.openedAt,
+            dispute.openedAt == 0 ? jobs[job_id].arbitrator : dispute.arbitrator,
+            dispute.ruleBy,
+            dispute.defaultSplitBps,
+            isDisputed(job_id)
+        );
+    }
+
+    function getDisputeTimeout(uint256 job_id) external view returns (uint64, bool, uint16) {
+        Dispute storage dispute = disputes[job_id];
+        return (dispute.timeoutAt, dispute.timeoutSplit, dispute.timeoutSplitBps);
+    }
+
+    function getEvidence(uint256 job_id) external view returns (bytes32[] memory, address[] memory, uint64[] memory) {
+        Evidence[] storage trail = evidence[job_id];
+        bytes32[] memory hashes = new bytes32[](trail.length);
+        address[] memory submitters = new address[](trail.length);
+        uint64[] memory submittedAt = new uint64[](trail.length);
+        for (uint256 i = 0; i < trail.length; i++) {
+            hashes[i] = trail[i].evidenceHash;
+            submitters[i] = trail[i].submitter;
+            submittedAt[i] = trail[i].submittedAt;
+        }
+        return (hashes, submitters, submittedAt);
+    }
+
+    function setSettlementHook(uint256 job_id, address hook) external {
+        Job storage job = jobs[job_id];
+        require(job.client == msg.sender, "Only client can set hook");
+        require(!job.released && !job.refunded, "Job already settled");
+        require(!finalized[job_id], "Job already finalized");
+
+        settlementHooks[job_id] = hook;
+
+        emit SettlementHookSet(job_id, hook);
+    }
+
+    function getSettlementHook(uint256 job_id) external view returns (address) {
+        return settlementHooks[job_id];
+    }
+
+    function release(uint256 job_id, bytes32 memo) external {
+        releaseAs(msg.sender, job_id, memo);
+    }
+
+    /// Mirrors `release_as` in `src/lib.rs`
+    function releaseAs(address sender, uint256 job_id, bytes32 memo) private {
+        require(!pausedNow(), "Escrow is paused");
+
+        Job storage job = jobs[job_id];
+        require(job.client == sender, "Only client can release");
+        require(!job.released && !job.refunded, "Job already settled");
+        require(!finalized[job_id], "Job already finalized");
+        assertFunded(job_id);
+        assertNotStaked(job_id);
+        require(job.freelancer != address(0), "Job not revealed");
+        assertNotDisputed(job_id);
+        require(clawbacks[job_id].payableAt == 0, "Release queued");
+        require(engagements[job_id].rate == 0, "Job is hourly");
+        require(!dualApprovals[job_id].required, "Dual approval required");
+        uint256 held = pendingHoldback(job_id, job.amount);
+        uint256 late = lateRefund(job_id, job.amount);
+        assertCompliant(job.client, job.freelancer, job.amount - held - late);
+        if (held != 0) {
+            retainHoldback(job_id, held);
+            return;
+        }
+        if (clawbacks[job_id].window != 0) {
+            queueRelease(job_id, memo);
+            return;
+        }
+
+        job.released = true;
+        closeJob(job_id);
+        recordRelease(job_id, job.freelancer, job.amount - late);
+
+        refundLate(job_id, late);
+        payRelease(job_id, job.freelancer, job.amount);
+        payBasket(job_id, BASIS_POINTS);
+
+        emit Released(job_id, job.amount, memo);
+        callSettlementHook(job_id, job.amount, true);
+    }
+
+    function releasePartial(uint256 job_id, uint256 amount) external {
+        require(!pausedNow(), "Escrow is paused");
+
+        Job storage job = jobs[job_id];
+        require(job.client == msg.sender, "Only client can release");
+        require(!job.released && !job.refunded, "Job already settled");
+        require(!finalized[job_id], "Job already finalized");
+        assertFunded(job_id);
+        assertNotStaked(job_id);
+        require(job.freelancer != address(0), "Job not revealed");
+        require(tranches[job_id].length == 0, "Job is tranched");
+        require(clawbacks[job_id].window == 0, "Job has clawback window");
+        require(slas[job_id].deliveryBy == 0, "Job has SLA");
+        require(engagements[job_id].rate == 0, "Job is hourly");
+        require(baskets[job_id].length == 0, "Job has basket");
+        require(amount != 0, "Amount must be > 0");
+        require(amount <= job.amount, "Amount exceeds escrow");
+        assertNotDisputed(job_id);
+        require(!dualApprovals[job_id].required, "Dual approval required");
+        assertCompliant(job.client, job.freelancer, amount);
+
+        uint256 remaining = job.amount - amount;
+        job.amount = remaining;
+        if (remaining == 0) {
+            job.released = true;
+            closeJob(job_id);
+        }
+        recordRelease(job_id, job.freelancer, amount);
+
+        payRelease(job_id, job.freelancer, amount);
+
+        emit PartiallyReleased(job_id, amount, remaining);
+        if (remaining == 0) {
+            callSettlementHook(job_id, amount, true);
+        }
+    }
+
+    function previewRelease(uint256 job_id)
+        external
+        view
+        returns (uint256, uint256, uint256, uint256, uint256, uint256)
+    {
+        assertPreviewable(job_id);
+        Job storage job = jobs[job_id];
+        require(job.freelancer != address(0), "Job not revealed");
+        require(engagements[job_id].rate == 0, "Job is hourly");
+
+        uint256 held = pendingHoldback(job_id, job.amount);
+        uint256 late = lateRefund(job_id, job.amount);
+        (uint256 net, uint256 withheld, uint256 fee, uint256 platformFee_) =
+            releaseSplit(job_id, job.freelancer, job.amount - held - late);
+        return (net, withheld, late, fee, platformFee_, held);
+    }
+
+    function previewRefund(uint256 job_id)
+        external
+        view
+        returns (uint256, uint256, uint256, uint256, uint256, uint256)
+    {
+        assertPreviewable(job_id);
+        require(tranches[job_id].length == 0, "Job is tranched");
+        require(holdbacks[job_id].warrantyEnds == 0, "Holdback retained");
+        require(clawbacks[job_id].payableAt == 0, "Release queued");
+        require(engagements[job_id].rate == 0, "Job is hourly");
+
+        return (0, 0, jobs[job_id].amount, 0, 0, 0);
+    }
+
+    function quoteFee(address client, uint256 amount, address token, address platform, uint16 platform_bps)
+        external
+        view
+        returns (uint256, uint256, uint256)
+    {
+        require(token == address(0), "Unsupported token");
+        require(platform != address(0) || platform_bps == 0, "Invalid platform address");
+        require(platform_bps <= MAX_PLATFORM_FEE_BPS, "Invalid platform fee");
+
+        uint256 fee = feeOn(amount, feeRate(client, amount));
+        uint256 platformFee_ = feeOn(amount, platform_bps);
+        return (fee, platformFee_, amount - fee - platformFee_);
+    }
+
+    function refund(uint256 job_id, bytes32 memo) external {
+        refundAs(msg.sender, job_id, memo);
+    }
+
+    /// Mirrors `rule_as` in `src/lib.rs`
+    function ruleAs(address sender, uint256 job_id, uint16 freelancer_bps) private {
+        require(!pausedNow(), "Escrow is paused");
+        require(isDisputed(job_id), "No open dispute");
+        Dispute storage dispute = disputes[job_id];
+        require(dispute.arbitrator != address(0) && sender == dispute.arbitrator, "Only arbitrator");
+        assertNoJury(job_id);
+        assertNoHeldRuling(job_id);
+        require(block.timestamp < dispute.ruleBy, "Ruling period over");
+        require(freelancer_bps <= BASIS_POINTS, "Invalid split");
+
+        settleRuling(sender, job_id, freelancer_bps);
+    }
+
+    /// Mirrors `refund_as` in `src/lib.rs`
+    function refundAs(address sender, uint256 job_id, bytes32 memo) private {
+        require(!pausedNow(), "Escrow is paused");
+
+        Job storage job = jobs[job_id];
+        require(job.client == sender, "Only client can refund");
+        require(!job.released && !job.refunded, "Job already settled");
+        require(!finalized[job_id], "Job already finalized");
+        assertFunded(job_id);
+        assertNotStaked(job_id);
+        require(tranches[job_id].length == 0, "Job is tranched");
+        require(holdbacks[job_id].warrantyEnds == 0, "Holdback retained");
+        require(clawbacks[job_id].payableAt == 0, "Release queued");
+        require(engagements[job_id].rate == 0, "Job is hourly");
+        Cancellation storage cancellation = cancellations[job_id];
+        if (cancellation.notice != 0) {
+            require(cancellation.effectiveAt != 0, "Cancellation not requested");
+            require(block.timestamp >= cancellation.effectiveAt, "Notice period running");
+        }
+        require(block.timestamp < job.deadline, "Deadline passed");
+
+        job.refunded = true;
+        closeJob(job_id);
+        recordRefund(job_id, job.client, job.amount);
+
+        pay(job.client, job.amount);
+        payBasket(job_id, 0);
+
+        emit Refunded(job_id, job.amount, memo);
+        callSettlementHook(job_id, job.amount, false);
+    }
+
+    function returnFunds(uint256 job_id, bytes32 memo) external {
+        returnFundsAs(msg.sender, job_id, memo);
+    }
+
+    /// Mirrors `return_funds_as` in `src/lib.rs`
+    function returnFundsAs(address sender, uint256 job_id, bytes32 memo) private {
+        require(!pausedNow(), "Escrow is paused");
+
+        assertSettleable(job_id);
+        assertNotStaked(job_id);
+        Job storage job = jobs[job_id];
+        require(job.freelancer == sender, "Only freelancer can return funds");
+        require(clawbacks[job_id].payableAt == 0, "Release queued");
+
+        Tranche[] storage schedule = tranches[job_id];
+        for (uint256 i = 0; i < schedule.length; i++) {
+            schedule[i].settled = true;
+        }
+        job.refunded = true;
+        closeJob(job_id);
+        recordRefund(job_id, job.client, job.amount);
+
+        pay(job.client, job.amount);
+        payBasket(job_id, 0);
+
+        emit FundsReturned(job_id, job.amount, memo);
+        callSettlementHook(job_id, job.amount, false);
+    }
+
+    function requestCancellation(uint256 job_id) external {
+        require(!pausedNow(), "Escrow is paused");
+
+        Job storage job = jobs[job_id];
+        require(job.client == msg.sender, "Only client can cancel");
+        require(!job.released && !job.refunded, "Job already settled");
+        require(!finalized[job_id], "Job already finalized");
+        Cancellation storage cancellation = cancellations[job_id];
+        require(cancellation.notice != 0, "Job has no notice period");
+        require(cancellation.effectiveAt == 0, "Cancellation already requested");
+        require(block.timestamp < job.deadline, "Deadline passed");
+
+        uint64 effectiveAt = uint64(block.timestamp) + cancellation.notice;
+        cancellation.effectiveAt = effectiveAt;
+
+        emit CancellationRequested(job_id, effectiveAt);
+    }
+
+    function submitDelivery(uint256 job_id, bytes32 delivery) external {
+        require(!pausedNow(), "Escrow is paused");
+
+        Job storage job = jobs[job_id];
+        require(job.freelancer == msg.sender, "Only freelancer can deliver");
+        require(!job.released && !job.refunded, "Job already settled");
+        require(!finalized[job_id], "Job already finalized");
+        if (slas[job_id].deliveryBy != 0) {
+            require(slas[job_id].deliveredAt == 0, "Already delivered");
+            slas[job_id].deliveredAt = uint64(block.timestamp);
+        } else {
+            Cancellation storage cancellation = cancellations[job_id];
+            require(cancellation.effectiveAt != 0, "No cancellation pending");
+            require(block.timestamp < cancellation.effectiveAt, "Notice period over");
+
+            cancellation.effectiveAt = 0;
+        }
+
+        emit DeliverySubmitted(job_id, delivery);
+    }
+
+    function extendDeadline(uint256 job_id, uint64 new_deadline) external {
+        require(!pausedNow(), "Escrow is paused");
+
+        assertSettleable(job_id);
+        Job storage job = jobs[job_id];
+        require(job.freelancer == msg.sender, "Only freelancer can extend");
+        require(tranches[job_id].length == 0, "Job is tranched");
+        uint64 deadline = job.deadline;
+        require(new_deadline > deadline, "Deadline must be later");
+
+        job.deadline = new_deadline;
+
+        recordAmendment(job_id, JOB_FIELD_DEADLINE, bytes32(uint256(deadline)), bytes32(uint256(new_deadline)));
+    }
+
+    function transferClient(uint256 job_id, address new_client) external {
+        require(!pausedNow(), "Escrow is paused");
+
+        assertSettleable(job_id);
+        Job storage job = jobs[job_id];
+        address client = job.client;
+        require(client == msg.sender, "Only client can transfer");
+        require(new_client != client && new_client != job.freelancer, "Invalid client address");
+
+        pendingClients[job_id] = new_client;
+
+        emit ClientTransferProposed(job_id, client, new_client);
+    }
+
+    function acceptClient(uint256 job_id) external {
+        require(!pausedNow(), "Escrow is paused");
+
+        assertSettleable(job_id);
+        require(pendingClients[job_id] == msg.sender, "Only pending client can accept");
+        address client = jobs[job_id].client;
+        uint256 amount = jobs[job_id].amount;
+
+        pendingClients[job_id] = address(0);
+        unlockEscrow(job_id, amount);
+        jobs[job_id].client = msg.sender;
+        lockEscrow(job_id, amount);
+        indexParty(job_id, msg.sender);
+
+        recordAmendment(job_id, JOB_FIELD_CLIENT, bytes32(uint256(uint160(client))), bytes32(uint256(uint160(msg.sender))));
+    }
+
+    function getPendingClient(uint256 job_id) external view returns (address) {
+        return pendingClients[job_id];
+    }
+
+    function acceptJob(uint256 job_id) external {
+        require(!pausedNow(), "Escrow is paused");
+
+        assertSettleable(job_id);
+        require(jobs[job_id].freelancer == msg.sender, "Only freelancer can accept");
+        require(!acceptedJobs[job_id], "Job already accepted");
+
+        acceptedJobs[job_id] = true;
+
+        emit JobAccepted(job_id, msg.sender);
+    }
+
+    function isJobAccepted(uint256 job_id) external view returns (bool) {
+        return acceptedJobs[job_id];
+    }
+
+    function reassignFreelancer(uint256 job_id, address new_freelancer) external {
+        require(!pausedNow(), "Escrow is paused");
+
+        assertSettleable(job_id);
+        Job storage job = jobs[job_id];
+        address client = job.client;
+        address freelancer = job.freelancer;
+        require(client == msg.sender, "Only client can reassign");
+        require(clawbacks[job_id].payableAt == 0, "Release queued");
+        require(engagements[job_id].pending == 0, "Timesheets pending");
+        require(freelancer != address(0), "Job not revealed");
+        require(!acceptedJobs[job_id], "Job already accepted");
+        require(job.attestation == bytes32(0), "Job is attested");
+        assertNotDisputed(job_id);
+        require(new_freelancer != address(0) && new_freelancer != freelancer && new_freelancer != client, "Invalid freelancer address");
+        assertCompliant(client, new_freelancer, job.amount);
+        assertBelowCap(new_freelancer);
+
+        recordUnassignment(freelancer);
+        recordAssignment(new_freelancer);
+        unlockEscrow(job_id, job.amount);
+        job.freelancer = new_freelancer;
+        lockEscrow(job_id, job.amount);
+        indexParty(job_id, new_freelancer);
+        dualApprovals[job_id].freelancerApproved = false;
+
+        recordAmendment(job_id, JOB_FIELD_FREELANCER, bytes32(uint256(uint160(freelancer))), bytes32(uint256(uint160(new_freelancer))));
+        emit FreelancerChanged(job_id, freelancer, new_freelancer);
+    }
+
+    function openDispute(uint256 job_id, bytes32 memo) external payable {
+        require(!pausedNow(), "Escrow is paused");
+        assertNotRetired();
+        assertSettleable(job_id);
+        assertNotStaked(job_id);
+        require(isJobParty(job_id, msg.sender), "Only job parties can dispute");
+        require(disputes[job_id].openedAt == 0, "Dispute already opened");
+        require(clawbacks[job_id].payableAt == 0, "Release queued");
+        require(msg.value == disputeStakeFor(job_id), "Incorrect stake");
+
+        address arbitrator_ = jobArbitrator(job_id);
+        uint64 ruleBy = arbitrator_ == address(0) ? 0 : uint64(block.timestamp) + rulingPeriod;
+        uint64 timeoutAt = disputeTimeout == 0 ? 0 : uint64(block.timestamp) + disputeTimeout;
+        disputes[job_id] = Dispute({
+            openedBy: msg.sender,
+            openedAt: uint64(block.timestamp),
+            arbitrator: arbitrator_,
+            ruleBy: ruleBy,
+            defaultSplitBps: defaultSplitBps,
+            timeoutAt: timeoutAt,
+            timeoutSplit: timeoutSplit,
+            timeoutSplitBps: timeoutSplitBps,
+            lifted: false,
+            stake: msg.value
+        });
+        totalDisputeStakes += msg.value;
+        freelancerProfiles[jobs[job_id].freelancer].disputes += 1;
+        clientProfiles[jobs[job_id].client].disputes += 1;
+        totalDisputed += jobs[job_id].amount;
+
+        emit DisputeOpened(job_id, msg.sender, memo);
+    }
+
+    function submitEvidence(uint256 job_id, bytes32 evidence_hash) external {
+        require(!pausedNow(), "Escrow is paused");
+        require(isDisputed(job_id), "No open dispute");
+        require(isJobParty(job_id, msg.sender), "Only job parties can submit evidence");
+        require(evidence_hash != bytes32(0), "Invalid evidence");
+        uint256 index = evidence[job_id].length;
+        require(index < MAX_EVIDENCE, "Too much evidence");
+
+        evidence[job_id].push(
+            Evidence({evidenceHash: evidence_hash, submitter: msg.sender, submittedAt: uint64(block.timestamp)})
+        );
+
+        emit EvidenceSubmitted(job_id, msg.sender, index, evidence_hash);
+    }
+
+    function rule(uint256 job_id, uint16 freelancer_bps) external {
+        ruleAs(msg.sender, job_id, freelancer_bps);
+    }
+
+    function ruleWithSignature(
+        uint256 job_id,
+        uint16 freelancer_bps,
+        uint256 nonce,
+        uint64 expiry,
+        bytes calldata signature
+    ) external {
+        address arbitrator = disputes[job_id].arbitrator;
+        spendVerdict(arbitrator, job_id, freelancer_bps, nonce, expiry, signature);
+        ruleAs(arbitrator, job_id, freelancer_bps);
+    }
+
+    function getVerdictDigest(uint256 job_id, uint16 freelancer_bps, uint256 nonce, uint64 expiry)
+        external
+        view
+        returns (bytes32)
+    {
+        return verdictHash(job_id, freelancer_bps, nonce, expiry);
+    }
+
+    function resolveByDefault(uint256 job_id) external {
+        require(!pausedNow(), "Escrow is paused");
+        require(isDisputed(job_id), "No open dispute");
+        require(isJobParty(job_id, msg.sender), "Only job parties can resolve");
+        Dispute storage dispute = disputes[job_id];
+        require(dispute.arbitrator != address(0), "No arbitrator assigned");
+        assertNoJury(job_id);
+        assertNoHeldRuling(job_id);
+        require(block.timestamp >= dispute.ruleBy, "Ruling period not over");
+
+        settleDispute(msg.sender, job_id, dispute.defaultSplitBps, true);
+    }
+
+    function timeOutDispute(uint256 job_id) external {
+        require(!pausedNow(), "Escrow is paused");
+        require(isDisputed(job_id), "No open dispute");
+        Dispute storage dispute = disputes[job_id];
+        require(dispute.timeoutAt != 0, "No dispute timeout");
+        assertNoHeldRuling(job_id);
+        require(block.timestamp >= dispute.timeoutAt, "Dispute timeout not reached");
+
+        emit DisputeTimedOut(job_id, dispute.timeoutSplit);
+        if (dispute.timeoutSplit) {
+            settleDispute(msg.sender, job_id, dispute.timeoutSplitBps, true);
+        } else {
+            dispute.lifted = true;
+            payDisputeStake(job_id, takeDisputeStake(job_id), false);
+        }
+    }
+
+    function escalateToJury(uint256 job_id) external {
+        require(!pausedNow(), "Escrow is paused");
+        require(isDisputed(job_id), "No open dispute");
+        require(isJobParty(job_id, msg.sender), "Only job parties can escalate");
+        require(juries[job_id].voteBy == 0, "Jury already drawn");
+        require(jurySize != 0, "Juries disabled");
+        assertNoHeldRuling(job_id);
+        Dispute storage dispute = disputes[job_id];
+        require(dispute.arbitrator == address(0) || block.timestamp < dispute.ruleBy, "Ruling period over");
+
+        seatJury(job_id, drawJury(job_id));
+    }
+
+    function castJuryVote(uint256 job_id, uint16 freelancer_bps) external {
+        Jury storage jury = juries[job_id];
+        JuryVote storage vote = jury.votes[msg.sender];
+        require(vote.seated, "Not on jury");
+        require(!jury.tallied && block.timestamp < jury.voteBy, "Voting closed");
+        require(!vote.voted, "Already voted");
+        require(freelancer_bps <= BASIS_POINTS, "Invalid split");
+
+        recordJuryVote(job_id, msg.sender, freelancer_bps);
+    }
+
+    function tallyJury(uint256 job_id) external {
+        require(!pausedNow(), "Escrow is paused");
+        Jury storage jury = juries[job_id];
+        require(jury.voteBy != 0, "No jury");
+        require(!jury.tallied, "Jury already tallied");
+        require(jury.votesCast == jury.panel.length || block.timestamp >= jury.voteBy, "Voting still open");
+
+        bool disputed = isDisputed(job_id);
+        (bool decided, uint16 verdict) = disputed ? juryVerdict(job_id) : (false, 0);
+        uint256 slashed = dismissJury(job_id, decided, verdict);
+        uint16 freelancer_bps = decided ? verdict : disputes[job_id].defaultSplitBps;
+        emit JuryTallied(job_id, decided, freelancer_bps, slashed);
+        if (disputed && decided) {
+            settleRuling(msg.sender, job_id, freelancer_bps);
+        } else if (disputed) {
+            settleDispute(msg.sender, job_id, freelancer_bps, true);
+        }
+    }
+
+    function getJury(uint256 job_id) external view returns (address[] memory, uint64, uint8, bool) {
+        Jury storage jury = juries[job_id];
+        return (jury.panel, jury.voteBy, jury.votesCast, jury.tallied);
+    }
+
+    function getJuryVote(uint256 job_id, address juror) external view returns (bool, uint16) {
+        JuryVote storage vote = juries[job_id].votes[juror];
+        return (vote.voted, vote.freelancerBps);
+    }
+
+    function appealRuling(uint256 job_id, uint16 freelancer_bps) external payable returns (uint256) {
+        require(!pausedNow(), "Escrow is paused");
+        assertNotRetired();
+        require(rulingHeld(job_id), "No pending ruling");
+        require(isJobParty(job_id, msg.sender), "Only job parties can appeal");
+        Appeal storage appeal = appeals[job_id];
+        require(appeal.appellant == address(0), "Ruling already appealed");
+        require(block.timestamp < appeal.appealableUntil, "Appeal window over");
+        require(freelancer_bps <= BASIS_POINTS && freelancer_bps != appeal.freelancerBps, "Invalid split");
+        require(msg.value == appealBondFor(job_id), "Incorrect bond");
+
+        return lodgeAppeal(msg.sender, job_id, freelancer_bps, msg.value);
+    }
+
+    function finalizeRuling(uint256 job_id) external {
+        require(!pausedNow(), "Escrow is paused");
+        require(rulingHeld(job_id), "No pending ruling");
+        require(rulingFinal(job_id), "Ruling still appealable");
+
+        finalizeHeldRuling(job_id);
+    }
+
+    function getHeldRuling(uint256 job_id) external view returns (address, uint16, uint64) {
+        Appeal storage appeal = appeals[job_id];
+        return (appeal.resolver, appeal.freelancerBps, appeal.appealableUntil);
+    }
+
+    function getAppeal(uint256 job_id) external view returns (address, uint16, uint256, uint256) {
+        Appeal storage appeal = appeals[job_id];
+        return (appeal.appellant, appeal.appealBps, appeal.bond, appeal.proposalId);
+    }
+
+    function autoRelease(uint256 job_id, bytes32 memo) external {
+        require(!pausedNow(), "Escrow is paused");
+
+        Job storage job = jobs[job_id];
+        require(job.freelancer == msg.sender, "Only freelancer can claim");
+        require(!job.released && !job.refunded, "Job already settled");
+        require(!finalized[job_id], "Job already finalized");
+        assertFunded(job_id);
+        assertNotStaked(job_id);
+        require(block.timestamp >= job.deadline, "Deadline not reached");
+        require(holdbacks[job_id].warrantyEnds == 0, "Holdback retained");
+        assertNotDisputed(job_id);
+        require(clawbacks[job_id].payableAt == 0, "Release queued");
+        require(engagements[job_id].rate == 0, "Job is hourly");
+        require(!dualApprovals[job_id].required, "Dual approval required");
+        assertReleasable(job_id);
+        uint256 held = pendingHoldback(job_id, job.amount);
+        uint256 late = lateRefund(job_id, job.amount);
+        assertCompliant(job.client, job.freelancer, job.amount - held - late);
+        if (held != 0) {
+            retainHoldback(job_id, held);
+            return;
+        }
+        if (clawbacks[job_id].window != 0) {
+            queueRelease(job_id, memo);
+            return;
+        }
+
+        job.released = true;
+        closeJob(job_id);
+        recordRelease(job_id, job.freelancer, job.amount - late);
+
+        refundLate(job_id, late);
+        payRelease(job_id, job.freelancer, job.amount);
+        paySettlementRebate(job_id, job.amount);
+        payBasket(job_id, BASIS_POINTS);
+
+        emit AutoReleased(job_id, job.amount, memo);
+        callSettlementHook(job_id, job.amount, true);
+    }
+
+    function finalizeRelease(uint256 job_id) external {
+        require(!pausedNow(), "Escrow is paused");
+
+        assertSettleable(job_id);
+        assertNotStaked(job_id);
+        Clawback storage clawback = clawbacks[job_id];
+        require(clawback.payableAt != 0, "No release queued");
+        require(block.timestamp >= clawback.payableAt, "Clawback window open");
+
+        Job storage job = jobs[job_id];
+        assertCompliant(job.client, job.freelancer, job.amount);
+
+        job.released = true;
+        closeJob(job_id);
+        recordRelease(job_id, job.freelancer, job.amount);
+
+        payRelease(job_id, job.freelancer, job.amount);
+        paySettlementRebate(job_id, job.amount);
+
+        emit Released(job_id, job.amount, clawback.memo);
+        callSettlementHook(job_id, job.amount, true);
+    }
+
+    function reverseRelease(uint256 job_id) external {
+        require(!pausedNow(), "Escrow is paused");
+
+        assertSettleable(job_id);
+        assertNotStaked(job_id);
+        address arbitrator_ = jobArbitrator(job_id);
+        require(arbitrator_ != address(0) && msg.sender == arbitrator_, "Only arbitrator");
+        uint64 payableAt = clawbacks[job_id].payableAt;
+        require(payableAt != 0, "No release queued");
+        require(block.timestamp < payableAt, "Clawback window closed");
+
+        Job storage job = jobs[job_id];
+        job.refunded = true;
+        closeJob(job_id);
+        recordRefund(job_id, job.client, job.amount);
+
+        pay(job.client, job.amount);
+
+        emit ReleaseReversed(job_id, msg.sender, job.amount);
+        callSettlementHook(job_id, job.amount, false);
+    }
+
+    function releaseHoldback(uint256 job_id) external {
+        require(!pausedNow(), "Escrow is paused");
+
+        assertSettleable(job_id);
+        assertNotStaked(job_id);
+        uint64 releaseAt = holdbacks[job_id].warrantyEnds;
+        require(releaseAt != 0, "No holdback retained");
+        require(block.timestamp >= releaseAt, "Warranty running");
+        assertNotDisputed(job_id);
+
+        Job storage job = jobs[job_id];
+        assertCompliant(job.client, job.freelancer, job.amount);
+
+        job.released = true;
+        closeJob(job_id);
+        recordRelease(job_id, job.freelancer, job.amount);
+
+        payRelease(job_id, job.freelancer, job.amount);
+        paySettlementRebate(job_id, job.amount);
+
+        emit AutoReleased(job_id, job.amount, bytes32(0));
+        callSettlementHook(job_id, job.amount, true);
+    }
+
+    function reclaim(uint256 job_id, bytes32 memo) external {
+        require(!pausedNow(), "Escrow is paused");
+
+        assertSettleable(job_id);
+        assertNotStaked(job_id);
+        require(tranches[job_id].length == 0, "Job is tranched");
+        require(holdbacks[job_id].warrantyEnds == 0, "Holdback retained");
+        require(clawbacks[job_id].payableAt == 0, "Release queued");
+        require(engagements[job_id].pending == 0, "Timesheets pending");
+        assertNotDisputed(job_id);
+        assertNoJury(job_id);
+        assertNoHeldRuling(job_id);
+        Job storage job = jobs[job_id];
+        require(block.timestamp >= uint256(job.deadline) + CLAIM_WINDOW, "Claim window open");
+
+        job.refunded = true;
+        closeJob(job_id);
+        recordRefund(job_id, job.client, job.amount);
+
+        pay(job.client, job.amount);
+        payBasket(job_id, 0);
+
+        emit Reclaimed(job_id, msg.sender, job.amount, memo);
+        callSettlementHook(job_id, job.amount, false);
+    }
+
+    function setPayoutToken(address token, uint256 min_rate) external {
+        require(token == address(0) || min_rate != 0, "Invalid min rate");
+
+        payoutTokens[msg.sender] = PayoutToken({token: token, minRate: min_rate});
+
+        emit PayoutTokenSet(msg.sender, token, min_rate);
+    }
+
+    function getPayoutToken(address freelancer) external view returns (address, uint256) {
+        PayoutToken storage p = payoutTokens[freelancer];
+        return (p.token, p.minRate);
+    }
+
+    function proposePayoutAddress(address payout_address) external {
+        require(payout_address != address(this), "Invalid payout address");
+
+        uint64 effectiveAt = uint64(block.timestamp) + PAYOUT_ADDRESS_DELAY;
+        PayoutAddress storage p = payoutAddresses[msg.sender];
+        p.pending = payout_address;
+        p.effectiveAt = effectiveAt;
+
+        emit PayoutAddressProposed(msg.sender, payout_address, effectiveAt);
+    }
+
+    function applyPayoutAddress(address freelancer) external {
+        PayoutAddress storage p = payoutAddresses[freelancer];
+        require(p.effectiveAt != 0, "No pending payout address");
+        require(block.timestamp >= p.effectiveAt, "Payout address delay not over");
+
+        address payoutAddress = p.pending;
+        p.current = payoutAddress;
+        p.pending = address(0);
+        p.effectiveAt = 0;
+
+        emit PayoutAddressSet(freelancer, payoutAddress);
+    }
+
+    function getPayoutAddress(address freelancer) external view returns (address, address, uint64) {
+        PayoutAddress storage p = payoutAddresses[freelancer];
+        return (p.current, p.pending, p.effectiveAt);
+    }
+
+    function setWithholding(uint16 rate_bps, address destination) external {
+        require(rate_bps <= BASIS_POINTS, "Invalid withholding rate");
+        require(rate_bps == 0 || destination != address(0), "Invalid withholding address");
+
+        withholdings[msg.sender] = Withholding({rateBps: rate_bps, destination: destination});
+
+        emit WithholdingSet(msg.sender, destination, rate_bps);
+    }
+
+    function getWithholding(address freelancer) external view returns (uint16, address) {
+        Withholding storage w = withholdings[freelancer];
+        return (w.rateBps, w.destination);
+    }
+
+    /// Mirrors `set_swap_router` in `src/settings.rs`
+    function setSwapRouter(address router, address weth_) private {
+        require(msg.sender == admin, "Only admin");
+        require(router == address(0) || weth_ != address(0), "Invalid WETH address");
+
+        swapRouter = router;
+        weth = weth_;
+
+        emit SwapRouterSet(router, weth_);
+    }
+
+    /// Mirrors `set_basket_token` in `src/settings.rs`
+    function setBasketToken(address token, bool approved) private {
+        require(msg.sender == admin, "Only admin");
+        require(token != address(0), "Invalid token");
+
+        basketTokens[token] = approved;
+
+        emit BasketTokenSet(token, approved);
+    }
+
+    /// Mirrors `set_fee_manager` in `src/settings.rs`
+    function setFeeManager(address manager) private {
+        require(msg.sender == admin, "Only admin");
+        assertNotGoverned();
+        updateFeeManager(manager);
+    }
+
+    function withdrawFees(address to, uint256 amount) external {
+        assertFeeManager();
+        require(to != address(0), "Invalid recipient");
+        require(to == revenueRecipient(to), "Fees go to treasury");
+        uint256 accrued = accruedFees;
+        require(amount != 0 && amount <= accrued, "Invalid amount");
+        require(amount <= surplus(), "Exceeds surplus");
+
+        uint256 remaining = accrued - amount;
+        accruedFees = remaining;
+
+        pay(to, amount);
+
+        emit FeesWithdrawn(to, amount, remaining);
+    }
+
+    function getAccruedFees() external view returns (uint256) {
+        return accruedFees;
+    }
+
+    /// Mirrors `set_protocol_fee` in `src/settings.rs`
+    function setProtocolFee(uint16 fee_bps) private {
+        assertFeeManager();
+        assertNotGoverned();
+        updateProtocolFee(fee_bps);
+    }
+
+    /// Mirrors `set_settlement_rebate` in `src/settings.rs`
+    function setSettlementRebate(uint256 amount) private {
+        assertFeeManager();
+        assertNotGoverned();
+        updateSettlementRebate(amount);
+    }
+
+    /// Mirrors `set_fee_tiers` in `src/settings.rs`
+    function setFeeTiers(uint256[] memory min_amounts, uint16[] memory fee_bps) private {
+        assertFeeManager();
+        assertNotGoverned();
+        updateFeeTiers(min_amounts, fee_bps);
+    }
+
+    /// Mirrors `update_fee_tiers` in `src/fees.rs`
+    function updateFeeTiers(uint256[] memory min_amounts, uint16[] memory fee_bps) private {
+        require(min_amounts.length == fee_bps.length && min_amounts.length <= MAX_FEE_TIERS, "Invalid tiers");
+        for (uint256 i = 0; i < min_amounts.length; i++) {
+            require(min_amounts[i] != 0 && (i == 0 || min_amounts[i] > min_amounts[i - 1]), "Invalid tiers");
+        }
+        for (uint256 i = 0; i < fee_bps.length; i++) {
+            require(fee_bps[i] <= MAX_PROTOCOL_FEE_BPS, "Invalid fee");
+        }
+
+        delete feeTiers;
+        for (uint256 i = 0; i < min_amounts.length; i++) {
+            feeTiers.push(FeeTier({minAmount: min_amounts[i], feeBps: fee_bps[i]}));
+        }
+
+        emit FeeTiersSet(min_amounts, fee_bps);
+    }
+
+    function getJobFee(uint256 job_id) external view returns (uint16) {
+        return jobFeeBps[job_id];
+    }
+
+    /// Mirrors `set_volume_discounts` in `src/settings.rs`
+    function setVolumeDiscounts(uint256[] memory min_volumes, uint16[] memory discount_bps) private {
+        assertFeeManager();
+        assertNotGoverned();
+        updateVolumeDiscounts(min_volumes, discount_bps);
+    }
+
+    /// Mirrors `update_volume_discounts` in `src/fees.rs`
+    function updateVolumeDiscounts(uint256[] memory min_volumes, uint16[] memory discount_bps) private {
+        require(min_volumes.length == discount_bps.length && min_volumes.length <= MAX_FEE_TIERS, "Invalid tiers");
+        for (uint256 i = 0; i < min_volumes.length; i++) {
+            require(min_volumes[i] != 0 && (i == 0 || min_volumes[i] > min_volumes[i - 1]), "Invalid tiers");
+        }
+        for (uint256 i = 0; i < discount_bps.length; i++) {
+            require(discount_bps[i] <= BASIS_POINTS, "Invalid discount");
+        }
+
+        delete volumeDiscounts;
+        for (uint256 i = 0; i < min_volumes.length; i++) {
+            volumeDiscounts.push(VolumeDiscount({minVolume: min_volumes[i], discountBps: discount_bps[i]}));
+        }
+
+        emit VolumeDiscountsSet(min_volumes, discount_bps);
+    }
+
+    function getClientVolume(address client) external view returns (uint256) {
+        return clientVolume[client];
+    }
+
+    /// Mirrors `set_fee_exempt` in `src/settings.rs`
+    function setFeeExempt(address account, bool exempt) private {
+        require(msg.sender == admin, "Only admin");
+        assertNotGoverned();
+        updateFeeExemption(account, exempt);
+    }
+
+    /// Mirrors `set_default_job_cap` in `src/settings.rs`
+    function setDefaultJobCap(uint32 cap) private {
+        require(msg.sender == admin, "Only admin");
+        assertNotGoverned();
+        updateDefaultJobCap(cap);
+    }
+
+    /// Mirrors `set_deposit_limits` in `src/settings.rs`
+    function setDepositLimits(uint64 cooldown, uint32 per_block) private {
+        require(msg.sender == admin, "Only admin");
+        assertNotGoverned();
+        updateDepositLimits(cooldown, per_block);
+    }
+
+    /// Mirrors `update_deposit_limits` in `src/deposit_limits.rs`
+    function updateDepositLimits(uint64 cooldown, uint32 per_block) private {
+        depositCooldown = cooldown;
+        depositsPerBlock = per_block;
+        emit DepositLimitsSet(cooldown, per_block);
+    }
+
+    /// Mirrors `set_deposit_limit_exempt` in `src/settings.rs`
+    function setDepositLimitExempt(address account, bool exempt) private {
+        require(msg.sender == admin, "Only admin");
+        depositLimitExempt[account] = exempt;
+        emit DepositLimitExemptionSet(account, exempt);
+    }
+
+    function setJobCap(uint32 cap) external {
+        jobCaps[msg.sender] = cap;
+        emit JobCapSet(msg.sender, cap);
+    }
+
+    function getJobCap(address freelancer) external view returns (uint32, uint32) {
+        return (jobCap(freelancer), activeJobs[freelancer]);
+    }
+
+    /// Mirrors `set_compliance_hook` in `src/settings.rs`
+    function setComplianceHook(address hook) private {
+        require(msg.sender == admin, "Only admin");
+        complianceHook = hook;
+        emit ComplianceHookSet(hook);
+    }
+
+    /// Mirrors `set_attestation_policy` in `src/settings.rs`
+    function setAttestationPolicy(address eas_, bytes32 schema, address attester_) private {
+        require(msg.sender == admin, "Only admin");
+        require(eas_ == address(0) || attester_ != address(0), "Invalid attester address");
+
+        eas = eas_;
+        attestationSchema = schema;
+        attester = attester_;
+
+        emit AttestationPolicySet(eas_, schema, attester_);
+    }
+
+    /// Mirrors `set_arbitration_policy` in `src/settings.rs`
+    function setArbitrationPolicy(address arbitrator_, uint64 ruling_period, uint16 default_split_bps) private {
+        require(msg.sender == admin, "Only admin");
+        assertNotGoverned();
+        updateArbitrationPolicy(arbitrator_, ruling_period, default_split_bps);
+    }
+
+    /// Mirrors `set_dispute_timeout_policy` in `src/settings.rs`
+    function setDisputeTimeoutPolicy(uint64 timeout, bool split, uint16 split_bps) private {
+        require(msg.sender == admin, "Only admin");
+        assertNotGoverned();
+        updateDisputeTimeoutPolicy(timeout, split, split_bps);
+    }
+
+    /// Mirrors `set_dispute_stake_policy` in `src/settings.rs`
+    function setDisputeStakePolicy(uint16 stake_bps, uint256 min_stake) private {
+        require(msg.sender == admin, "Only admin");
+        require(stake_bps <= MAX_DISPUTE_STAKE_BPS, "Invalid stake");
+        disputeStakeBps = stake_bps;
+        minDisputeStake = min_stake;
+        emit DisputeStakePolicySet(stake_bps, min_stake);
+    }
+
+    function quoteDisputeStake(uint256 job_id) external view returns (uint256) {
+        return disputeStakeFor(job_id);
+    }
+
+    function getDisputeStake(uint256 job_id) external view returns (uint256) {
+        return disputes[job_id].stake;
+    }
+
+    /// Mirrors `set_jury_policy` in `src/settings.rs`
+    function setJuryPolicy(uint8 size, uint64 voting_period, uint256 min_stake, uint16 slash_bps) private {
+        require(msg.sender == admin, "Only admin");
+        assertNotGoverned();
+        updateJuryPolicy(size, voting_period, min_stake, slash_bps);
+    }
+
+    function joinJury() external payable {
+        assertNotRetired();
+        require(msg.value != 0, "No stake");
+        stakeJuror(msg.sender, msg.value);
+    }
+
+    function leaveJury() external {
+        unstakeJuror(msg.sender);
+    }
+
+    function getJuror(address juror) external view returns (uint256, bool, uint32) {
+        Juror storage record = jurorRecords[juror];
+        return (record.stake, record.index != 0, record.panels);
+    }
+
+    function getJurorCount() external view returns (uint256) {
+        return jurors.length;
+    }
+
+    /// Mirrors `set_appeal_policy` in `src/settings.rs`
+    function setAppealPolicy(uint64 window, uint16 bond_bps, uint256 min_bond) private {
+        require(msg.sender == admin, "Only admin");
+        require(bond_bps <= MAX_APPEAL_BOND_BPS, "Invalid bond");
+        updateAppealPolicy(window, bond_bps, min_bond);
+    }
+
+    function quoteAppealBond(uint256 job_id) external view returns (uint256) {
+        return appealBondFor(job_id);
+    }
+
+    function configure(uint8 setting, bytes calldata data) external {
+        if (setting == SETTING_SWAP_ROUTER) {
+            (address router, address weth_) = abi.decode(data, (address, address));
+            setSwapRouter(router, weth_);
+        } else if (setting == SETTING_BASKET_TOKEN) {
+            (address token, bool approved) = abi.decode(data, (address, bool));
+            setBasketToken(token, approved);
+        } else if (setting == SETTING_COMPLIANCE_HOOK) {
+            address hook = abi.decode(data, (address));
+            setComplianceHook(hook);
+        } else if (setting == SETTING_DEFAULT_JOB_CAP) {
+            uint32 cap = abi.decode(data, (uint32));
+            setDefaultJobCap(cap);
+        } else if (setting == SETTING_FEE_MANAGER) {
+            address manager = abi.decode(data, (address));
+            setFeeManager(manager);
+        } else if (setting == SETTING_PROTOCOL_FEE) {
+            uint16 fee_bps = abi.decode(data, (uint16));
+            setProtocolFee(fee_bps);
+        } else if (setting == SETTING_SETTLEMENT_REBATE) {
+            uint256 amount = abi.decode(data, (uint256));
+            setSettlementRebate(amount);
+        } else if (setting == SETTING_FEE_TIERS) {
+            (uint256[] memory min_amounts, uint16[] memory fee_bps) = abi.decode(data, (uint256[], uint16[]));
+            setFeeTiers(min_amounts, fee_bps);
+        } else if (setting == SETTING_VOLUME_DISCOUNTS) {
+            (uint256[] memory min_volumes, uint16[] memory discount_bps) = abi.decode(data, (uint256[], uint16[]));
+            setVolumeDiscounts(min_volumes, discount_bps);
+        } else if (setting == SETTING_FEE_EXEMPT) {
+            (address account, bool exempt) = abi.decode(data, (address, bool));
+            setFeeExempt(account, exempt);
+        } else if (setting == SETTING_DEPOSIT_LIMITS) {
+            (uint64 cooldown, uint32 per_block) = abi.decode(data, (uint64, uint32));
+            setDepositLimits(cooldown, per_block);
+        } else if (setting == SETTING_DEPOSIT_LIMIT_EXEMPT) {
+            (address account, bool exempt) = abi.decode(data, (address, bool));
+            setDepositLimitExempt(account, exempt);
+        } else if (setting == SETTING_ATTESTATION_POLICY) {
+            (address eas_, bytes32 schema, address attester_) = abi.decode(data, (address, bytes32, address));
+            setAttestationPolicy(eas_, schema, attester_);
+        } else if (setting == SETTING_ARBITRATION_POLICY) {
+            (address arbitrator_, uint64 ruling_period, uint16 default_split_bps) = abi.decode(data, (address, uint64, uint16));
+            setArbitrationPolicy(arbitrator_, ruling_period, default_split_bps);
+        } else if (setting == SETTING_DISPUTE_TIMEOUT_POLICY) {
+            (uint64 timeout, bool split, uint16 split_bps) = abi.decode(data, (uint64, bool, uint16));
+            setDisputeTimeoutPolicy(timeout, split, split_bps);
+        } else if (setting == SETTING_DISPUTE_STAKE_POLICY) {
+            (uint16 stake_bps, uint256 min_stake) = abi.decode(data, (uint16, uint256));
+            setDisputeStakePolicy(stake_bps, min_stake);
+        } else if (setting == SETTING_JURY_POLICY) {
+            (uint8 size, uint64 voting_period, uint256 min_stake, uint16 slash_bps) = abi.decode(data, (uint8, uint64, uint256, uint16));
+            setJuryPolicy(size, voting_period, min_stake, slash_bps);
+        } else if (setting == SETTING_APPEAL_POLICY) {
+            (uint64 window, uint16 bond_bps, uint256 min_bond) = abi.decode(data, (uint64, uint16, uint256));
+            setAppealPolicy(window, bond_bps, min_bond);
+        } else if (setting == SETTING_MAX_PAUSE) {
+            uint64 duration = abi.decode(data, (uint64));
+            setMaxPause(duration);
+        } else if (setting == SETTING_PAUSER) {
+            address pauser_ = abi.decode(data, (address));
+            setPauser(pauser_);
+        } else if (setting == SETTING_DORMANCY_POLICY) {
+            (uint64 period, address recovery) = abi.decode(data, (uint64, address));
+            setDormancyPolicy(period, recovery);
+        } else if (setting == SETTING_ARBITRATOR_LISTING) {
+            (address arbitrator_, bool listed) = abi.decode(data, (address, bool));
+            setArbitratorListing(arbitrator_, listed);
+        } else if (setting == SETTING_STAKING_TOKEN) {
+            (address token, uint16 maxSlippageBps) = abi.decode(data, (address, uint16));
+            setStakingToken(token, maxSlippageBps);
+        } else {
+            revert("Invalid setting");
+        }
+    }
+
+    /// Mirrors `is_governed_setting` in `src/settings.rs`
+    function isGovernedSetting(uint8 setting) private pure returns (bool) {
+        return setting == SETTING_FEE_MANAGER || setting == SETTING_SETTLEMENT_REBATE || setting == SETTING_FEE_TIERS
+            || setting == SETTING_VOLUME_DISCOUNTS || setting == SETTING_FEE_EXEMPT || setting == SETTING_DEPOSIT_LIMITS
+            || setting == SETTING_JURY_POLICY || setting == SETTING_ARBITRATOR_LISTING;
+    }
+
+    /// Mirrors `apply_governed_setting` in `src/settings.rs`
+    function applyGovernedSetting(uint8 setting, bytes memory data) private {
+        if (setting == SETTING_FEE_MANAGER) {
+            updateFeeManager(abi.decode(data, (address)));
+        } else if (setting == SETTING_SETTLEMENT_REBATE) {
+            updateSettlementRebate(abi.decode(data, (uint256)));
+        } else if (setting == SETTING_FEE_TIERS) {
+            (uint256[] memory min_amounts, uint16[] memory fee_bps) = abi.decode(data, (uint256[], uint16[]));
+            updateFeeTiers(min_amounts, fee_bps);
+        } else if (setting == SETTING_VOLUME_DISCOUNTS) {
+            (uint256[] memory min_volumes, uint16[] memory discount_bps) = abi.decode(data, (uint256[], uint16[]));
+            updateVolumeDiscounts(min_volumes, discount_bps);
+        } else if (setting == SETTING_FEE_EXEMPT) {
+            (address account, bool exempt) = abi.decode(data, (address, bool));
+            updateFeeExemption(account, exempt);
+        } else if (setting == SETTING_DEPOSIT_LIMITS) {
+            (uint64 cooldown, uint32 per_block) = abi.decode(data, (uint64, uint32));
+            updateDepositLimits(cooldown, per_block);
+        } else if (setting == SETTING_JURY_POLICY) {
+            (uint8 size, uint64 voting_period, uint256 min_stake, uint16 slash_bps) = abi.decode(data, (uint8, uint64, uint256, uint16));
+            updateJuryPolicy(size, voting_period, min_stake, slash_bps);
+        } else if (setting == SETTING_ARBITRATOR_LISTING) {
+            (address arbitrator_, bool listed) = abi.decode(data, (address, bool));
+            listArbitrator(arbitrator_, listed);
+        } else {
+            revert("Invalid setting");
+        }
+    }
+
+    /// Mirrors `setting_value` in `src/settings.rs`
+    function getSetting(uint8 setting, address key) external view returns (bytes memory) {
+        if (setting == SETTING_SWAP_ROUTER) {
+            return abi.encode(swapRouter, weth);
+        } else if (setting == SETTING_BASKET_TOKEN) {
+            return abi.encode(key, basketTokens[key]);
+        } else if (setting == SETTING_COMPLIANCE_HOOK) {
+            return abi.encode(complianceHook);
+        } else if (setting == SETTING_DEFAULT_JOB_CAP) {
+            return abi.encode(defaultJobCap);
+        } else if (setting == SETTING_FEE_MANAGER) {
+            return abi.encode(currentFeeManager());
+        } else if (setting == SETTING_PROTOCOL_FEE) {
+            return abi.encode(protocolFeeBps);
+        } else if (setting == SETTING_SETTLEMENT_REBATE) {
+            return abi.encode(settlementRebate);
+        } else if (setting == SETTING_FEE_TIERS) {
+            uint256[] memory minAmounts = new uint256[](feeTiers.length);
+            uint16[] memory feeBps = new uint16[](feeTiers.length);
+            for (uint256 i = 0; i < feeTiers.length; i++) {
+                minAmounts[i] = feeTiers[i].minAmount;
+                feeBps[i] = feeTiers[i].feeBps;
+            }
+            return abi.encode(minAmounts, feeBps);
+        } else if (setting == SETTING_VOLUME_DISCOUNTS) {
+            uint256[] memory minVolumes = new uint256[](volumeDiscounts.length);
+            uint16[] memory discountBps = new uint16[](volumeDiscounts.length);
+            for (uint256 i = 0; i < volumeDiscounts.length; i++) {
+                minVolumes[i] = volumeDiscounts[i].minVolume;
+                discountBps[i] = volumeDiscounts[i].discountBps;
+            }
+            return abi.encode(minVolumes, discountBps);
+        } else if (setting == SETTING_FEE_EXEMPT) {
+            return abi.encode(key, feeExempt[key]);
+        } else if (setting == SETTING_DEPOSIT_LIMITS) {
+            return abi.encode(depositCooldown, depositsPerBlock);
+        } else if (setting == SETTING_DEPOSIT_LIMIT_EXEMPT) {
+            return abi.encode(key, depositLimitExempt[key]);
+        } else if (setting == SETTING_ATTESTATION_POLICY) {
+            return abi.encode(eas, attestationSchema, attester);
+        } else if (setting == SETTING_ARBITRATION_POLICY) {
+            return abi.encode(arbitrator, rulingPeriod, defaultSplitBps);
+        } else if (setting == SETTING_DISPUTE_TIMEOUT_POLICY) {
+            return abi.encode(disputeTimeout, timeoutSplit, timeoutSplitBps);
+        } else if (setting == SETTING_DISPUTE_STAKE_POLICY) {
+            return abi.encode(disputeStakeBps, minDisputeStake);
+        } else if (setting == SETTING_JURY_POLICY) {
+            return abi.encode(jurySize, juryVotingPeriod, minJurorStake, jurorSlashBps);
+        } else if (setting == SETTING_APPEAL_POLICY) {
+            return abi.encode(appealWindow, appealBondBps, minAppealBond);
+        } else if (setting == SETTING_MAX_PAUSE) {
+            return abi.encode(maxPause);
+        } else if (setting == SETTING_PAUSER) {
+            return abi.encode(pauser);
+        } else if (setting == SETTING_DORMANCY_POLICY) {
+            return abi.encode(dormancyPeriod, recoveryAddress);
+        } else if (setting == SETTING_ARBITRATOR_LISTING) {
+            return abi.encode(key, isListedArbitrator[key]);
+        } else if (setting == SETTING_STAKING_TOKEN) {
+            return abi.encode(stakingToken, maxUnstakeSlippageBps);
+        } else {
+            revert("Invalid setting");
+        }
+    }
+
+    function setPaused(bool state) external {
+        require(msg.sender == admin || (state && msg.sender == pauser), "Only admin");
+        if (!state) {
+            pauseUntil = 0;
+        } else if (!pausedNow()) {
+            uint64 limit = pauseLimit();
+            pauseUntil = limit != 0 ? uint64(block.timestamp) + limit : 0;
+        }
+        paused = state;
+
+        emit PauseToggled(state);
+    }
+
+    /// Mirrors `set_max_pause` in `src/settings.rs`
+    function setMaxPause(uint64 duration) private {
+        require(msg.sender == admin, "Only admin");
+        maxPause = duration;
+        emit MaxPauseSet(duration);
+    }
+
+    function unpauseExpired() external {
+        require(paused && !pausedNow(), "Pause not expired");
+
+        paused = false;
+        pauseUntil = 0;
+
+        emit PauseToggled(false);
+    }
+
+    /// Mirrors `set_pauser` in `src/settings.rs`
+    function setPauser(address pauser_) private {
+        require(msg.sender == admin, "Only admin");
+        pauser = pauser_;
+        emit PauserSet(pauser_);
+    }
+
+    function transferOwnership(address new_admin) external {
+        require(msg.sender == admin, "Only admin");
+        require(new_admin != address(0), "Invalid admin address");
+
+        address oldAdmin = admin;
+        admin = new_admin;
+        renounceEffectiveAt = 0;
+
+        emit OwnershipTransferred(oldAdmin, new_admin);
+    }
+
+    function proposeRenounce() external {
+        require(msg.sender == admin, "Only admin");
+        assertParametersFinal();
+
+        uint64 effectiveAt = uint64(block.timestamp) + RENOUNCE_DELAY;
+        renounceEffectiveAt = effectiveAt;
+
+        emit RenounceProposed(effectiveAt);
+    }
+
+    function renounceOwnership() external {
+        require(msg.sender == admin, "Only admin");
+        require(renounceEffectiveAt != 0, "No pending renounce");
+        require(block.timestamp >= renounceEffectiveAt, "Renounce delay not over");
+        assertParametersFinal();
+
+        address oldAdmin = admin;
+        admin = address(0);
+        renounceEffectiveAt = 0;
+
+        emit OwnershipTransferred(oldAdmin, address(0));
+    }
+
+    function getRenounceAt() external view returns (uint64) {
+        return renounceEffectiveAt;
+    }
+
+    function emergencyRefund(uint256 job_id) external {
+        require(msg.sender == admin, "Only admin");
+        assertNoGuardianQuorum();
+
+        refundEmergency(job_id);
+    }
+
+    function emergencyRefundBatch(uint256[] calldata job_ids) external returns (bool[] memory refunded) {
+        require(msg.sender == admin, "Only admin");
+        assertNoGuardianQuorum();
+        require(job_ids.length != 0 && job_ids.length <= MAX_REFUND_BATCH, "Invalid batch");
+
+        refunded = new bool[](job_ids.length);
+        for (uint256 i = 0; i < job_ids.length; i++) {
+            refunded[i] = tryEmergencyRefund(job_ids[i]);
+        }
+
+        emit EmergencyRefundBatch(msg.sender, job_ids, refunded);
+    }
+
+    function sweepSurplus() external {
+        require(msg.sender == admin, "Only admin");
+        assertNoGuardianQuorum();
+
+        sweepSurplusNow();
+    }
+
+    function setRefundRoot(bytes32 root) external {
+        require(msg.sender == admin, "Only admin");
+        assertNotGoverned();
+
+        updateRefundRoot(root);
+    }
+
+    function getRefundRoot() external view returns (bytes32) {
+        return refundRoot;
+    }
+
+    function isRefundClaimed(uint256 job_id) external view returns (bool) {
+        return refundClaims[refundRoot][job_id];
+    }
+
+    function claimEmergencyRefund(uint256 job_id, uint256 amount, bytes32[] calldata proof) external {
+        assertSettleable(job_id);
+        assertNotStaked(job_id);
+        Job storage job = jobs[job_id];
+        require(job.client == msg.sender, "Only client can claim");
+        bytes32 root = refundRoot;
+        require(root != bytes32(0), "No refund root");
+        require(!refundClaims[root][job_id], "Refund already claimed");
+        require(clawbacks[job_id].payableAt == 0, "Release queued");
+        require(amount != 0 && amount <= job.amount, "Invalid refund amount");
+        require(amount == job.amount || (tranches[job_id].length == 0 && engagements[job_id].rate == 0), "Partial refund not supported");
+        require(verifyProof(proof, root, refundLeaf(job_id, amount)), "Invalid proof");
+
+        payRefundClaim(job_id, amount);
+    }
+
+    function setGuardians(address[] calldata guardians_, uint8 threshold) external {
+        require(msg.sender == admin, "Only admin");
+        require(guardians_.length <= MAX_GUARDIANS, "Too many guardians");
+        require(threshold <= guardians_.length && (threshold == 0) == (guardians_.length == 0), "Invalid threshold");
+        for (uint256 i = 0; i < guardians_.length; i++) {
+            require(guardians_[i] != address(0), "Invalid guardian");
+            for (uint256 j = 0; j < i; j++) {
+                require(guardians_[j] != guardians_[i], "Invalid guardian");
+            }
+        }
+
+        replaceGuardians(guardians_, threshold);
+
+        emit GuardiansSet(guardians_, threshold);
+    }
+
+    function getGuardians() external view returns (address[] memory, uint8) {
+        return (guardians, guardianThreshold);
+    }
+
+    function proposeEmergency(uint8 action, uint256 target) external returns (uint256) {
+        assertGuardian();
+        require(action <= EMERGENCY_ACTION_EXTEND_PAUSE, "Invalid action");
+
+        uint256 proposalId = ++emergencyProposalCount;
+        uint64 expiresAt = uint64(block.timestamp) + EMERGENCY_PROPOSAL_TTL;
+        EmergencyProposal storage proposal = emergencyProposals[proposalId];
+        proposal.action = action;
+        proposal.target = target;
+        proposal.proposer = msg.sender;
+        proposal.expiresAt = expiresAt;
+        proposal.guardianEpoch = guardianEpoch;
+
+        emit EmergencyProposed(proposalId, action, target, msg.sender, expiresAt);
+
+        confirmEmergency(proposalId);
+
+        return proposalId;
+    }
+
+    function confirmEmergency(uint256 proposal_id) public {
+        assertGuardian();
+        EmergencyProposal storage proposal = emergencyProposals[proposal_id];
+        require(proposal.proposer != address(0), "Proposal does not exist");
+        require(!proposal.executed, "Proposal already executed");
+        require(proposal.guardianEpoch == guardianEpoch, "Proposal stale");
+        require(block.timestamp < proposal.expiresAt, "Proposal expired");
+        require(!emergencyConfirmations[proposal_id][msg.sender], "Already confirmed");
+
+        uint8 confirmations = proposal.confirmations + 1;
+        emergencyConfirmations[proposal_id][msg.sender] = true;
+        proposal.confirmations = confirmations;
+        if (confirmations >= guardianThreshold) {
+            proposal.executed = true;
+        }
+
+        emit EmergencyConfirmed(proposal_id, msg.sender, confirmations);
+
+        if (confirmations >= guardianThreshold) {
+            executeEmergency(proposal.action, proposal.target);
+        }
+    }
+
+    function getEmergencyProposal(uint256 proposal_id) external view returns (uint8, uint256, address, uint64, uint8, bool) {
+        EmergencyProposal storage proposal = emergencyProposals[proposal_id];
+        require(proposal.proposer != address(0), "Proposal does not exist");
+        return (proposal.action, proposal.target, proposal.proposer, proposal.expiresAt, proposal.confirmations, proposal.executed);
+    }
+
+    function getPauseUntil() external view returns (uint64) {
+        return pauseUntil;
+    }
+
+    function setGovernance(address token, address[] calldata council_, uint256 quorum) external {
+        require(msg.sender == admin, "Only admin");
+        require(!isGoverned(), "Governance already set");
+        require((token == address(0)) != (council_.length == 0), "Invalid governance");
+        require(council_.length <= MAX_COUNCIL, "Council too large");
+        for (uint256 i = 0; i < council_.length; i++) {
+            require(council_[i] != address(0), "Invalid council member");
+            for (uint256 j = 0; j < i; j++) {
+                require(council_[j] != council_[i], "Invalid council member");
+            }
+        }
+        require(quorum != 0 && (token != address(0) || quorum <= council_.length), "Invalid quorum");
+
+        governanceToken = token;
+        replaceCouncil(council_);
+        governanceQuorum = quorum;
+
+        emit GovernanceSet(governanceToken, council, governanceQuorum);
+    }
+
+    function getGovernance() external view returns (address, address[] memory, uint256) {
+        return (governanceToken, council, governanceQuorum);
+    }
+
+    function proposeGovernance(uint8 action, address target, uint256 value, bytes calldata data) external returns (uint256) {
+        require(isGoverned(), "Governance not set");
+        bool councilOnly = action == GOVERNANCE_ACTION_ADD_COUNCIL_MEMBER || action == GOVERNANCE_ACTION_REMOVE_COUNCIL_MEMBER;
+        require(
+            action <= GOVERNANCE_ACTION_TREASURY && action != GOVERNANCE_ACTION_APPEAL
+                && !(councilOnly && governanceToken != address(0)),
+            "Invalid action"
+        );
+        if (action == GOVERNANCE_ACTION_REPLACE_ARBITRATOR) {
+            assertArbitratorReplaceable(value, target);
+        }
+        if (action == GOVERNANCE_ACTION_SETTING) {
+            require(value <= type(uint8).max && isGovernedSetting(uint8(value)), "Invalid setting");
+        }
+        uint64 snapshot = block.number == 0 ? 0 : uint64(block.number - 1);
+        require(votingWeight(msg.sender, snapshot) != 0, "No voting power");
+
+        return openGovernanceProposal(msg.sender, action, target, value, data);
+    }
+
+    function voteGovernance(uint256 proposal_id, bool support) external {
+        GovernanceProposal storage proposal = governanceProposals[proposal_id];
+        require(proposal.proposer != address(0), "Proposal does not exist");
+        require(proposal.councilEpoch == councilEpoch, "Proposal stale");
+        require(block.timestamp < proposal.votingEnds, "Voting closed");
+        require(!governanceVotes[proposal_id][msg.sender], "Already voted");
+        uint256 weight = votingWeight(msg.sender, proposal.snapshot);
+        require(weight != 0, "No voting power");
+
+        governanceVotes[proposal_id][msg.sender] = true;
+        if (support) {
+            proposal.votesFor += weight;
+        } else {
+            proposal.votesAgainst += weight;
+        }
+
+        emit GovernanceVoted(proposal_id, msg.sender, support, weight);
+    }
+
+    function executeGovernance(uint256 proposal_id) external {
+        GovernanceProposal storage proposal = governanceProposals[proposal_id];
+        require(proposal.proposer != address(0), "Proposal does not exist");
+        require(!proposal.executed, "Proposal already executed");
+        require(proposal.councilEpoch == councilEpoch, "Proposal stale");
+        require(proposal.votesFor >= governanceQuorum && proposal.votesFor > proposal.votesAgainst, "Proposal not passed");
+        uint64 executableAt = proposal.votingEnds + GOVERNANCE_TIMELOCK;
+        require(block.timestamp >= executableAt, "Timelock not over");
+        require(block.timestamp < executableAt + GOVERNANCE_EXECUTION_WINDOW, "Proposal expired");
+
+        proposal.executed = true;
+
+        emit GovernanceExecuted(proposal_id);
+
+        executeGovernanceAction(proposal.action, proposal.target, proposal.value, proposal.data);
+    }
+
+    function getGovernanceProposal(uint256 proposal_id) external view returns (uint8, address, uint256, address, uint64, uint256, uint256, bool, bytes memory) {
+        GovernanceProposal storage proposal = governanceProposals[proposal_id];
+        require(proposal.proposer != address(0), "Proposal does not exist");
+        return (proposal.action, proposal.target, proposal.value, proposal.proposer, proposal.votingEnds, proposal.votesFor, proposal.votesAgainst, proposal.executed, proposal.data);
+    }
+
+    function beginSunset(uint64 timestamp) external {
+        require(msg.sender == admin, "Only admin");
+        require(sunsetAt == 0, "Sunset already started");
+        require(timestamp != 0 && timestamp >= block.timestamp, "Invalid sunset time");
+
+        sunsetAt = timestamp;
+
+        emit SunsetBegun(timestamp);
+    }
+
+    function finalizeSunset() external {
+        require(msg.sender == admin, "Only admin");
+        require(!retired, "Escrow is retired");
+        require(isSunset(), "Sunset not reached");
+        require(
+            totalDeposited + totalDisputeStakes + totalAppealBonds - totalReleased - totalRefunded - totalSwept
+                    - totalStakingLosses == 0,
+            "Jobs still open"
+        );
+        require(totalCredit == 0, "Credit outstanding");
+        require(accruedFees == 0, "Fees outstanding");
+        require(totalJurorStakes == 0, "Juror stakes outstanding");
+
+        retired = true;
+
+        address recipient = revenueRecipient(admin);
+        uint256 swept = address(this).balance;
+        if (swept != 0) {
+            pay(recipient, swept);
+        }
+
+        emit SunsetFinalized(recipient, swept);
+    }
+
+    function getSunset() external view returns (uint64, bool) {
+        return (sunsetAt, retired);
+    }
+
+    /// Mirrors `set_dormancy_policy` in `src/settings.rs`
+    function setDormancyPolicy(uint64 period, address recovery) private {
+        require(msg.sender == admin, "Only admin");
+        assertNotGoverned();
+        updateDormancyPolicy(period, recovery);
+    }
+
+    /// Mirrors `set_arbitrator_listing` in `src/settings.rs`
+    function setArbitratorListing(address arbitrator_, bool listed) private {
+        require(msg.sender == admin, "Only admin");
+        assertNotGoverned();
+        listArbitrator(arbitrator_, listed);
+    }
+
+    function setStakingToken(address token, uint16 maxSlippageBps) private {
+        require(msg.sender == admin, "Only admin");
+        require(maxSlippageBps <= MAX_UNSTAKE_SLIPPAGE_BPS, "Invalid slippage");
+
+        stakingToken = token;
+        maxUnstakeSlippageBps = maxSlippageBps;
+
+        emit StakingTokenSet(token, maxSlippageBps);
+    }
+
+    function announceDormant(uint256 job_id) external {
+        require(msg.sender == admin, "Only admin");
+        require(recoveryAddress != address(0), "Dormancy policy not set");
+
+        assertJobExists(job_id);
+        Job storage job = jobs[job_id];
+        require(!job.released && !job.refunded, "Job already settled");
+        require(!finalized[job_id], "Job already finalized");
+        require(sweepAfter[job_id] == 0, "Dormancy already announced");
+        require(block.timestamp >= uint256(job.deadline) + dormancyPeriod, "Job not dormant");
+
+        uint64 sweepAt = uint64(block.timestamp) + DORMANCY_NOTICE_PERIOD;
+        sweepAfter[job_id] = sweepAt;
+
+        emit DormancyAnnounced(job_id, sweepAt);
+    }
+
+    function sweepDormant(uint256 job_id) external {
+        require(msg.sender == admin, "Only admin");
+        require(sweepAfter[job_id] != 0, "Dormancy not announced");
+
+        Job storage job = jobs[job_id];
+        require(!job.released && !job.refunded, "Job already settled");
+        require(!finalized[job_id], "Job already finalized");
+        require(block.timestamp >= sweepAfter[job_id], "Notice period not over");
+        assertNotStaked(job_id);
+
+        unlockEscrow(job_id, job.amount);
+        job.swept = true;
+        closeJob(job_id);
+        totalSwept += job.amount;
+
+        address recovery = revenueRecipient(recoveryAddress);
+        pay(recovery, job.amount);
+        sweepBasket(job_id, recovery);
+
+        emit DormantSwept(job_id, recovery, job.amount);
+    }
+
+    function proposeTreasury(address treasury_) external {
+        require(msg.sender == admin, "Only admin");
+        assertNotGoverned();
+        updatePendingTreasury(treasury_);
+    }
+
+    /// Mirrors `update_pending_treasury` in `src/treasury.rs`
+    function updatePendingTreasury(address treasury_) private {
+        require(treasury_ != address(0), "Invalid treasury address");
+
+        uint64 effectiveAt = uint64(block.timestamp) + TREASURY_DELAY;
+        pendingTreasury = treasury_;
+        treasuryEffectiveAt = effectiveAt;
+
+        emit TreasuryProposed(treasury_, effectiveAt);
+    }
+
+    function applyTreasury() external {
+        address treasury_ = pendingTreasury;
+        require(treasury_ != address(0), "No pending treasury");
+        require(block.timestamp >= treasuryEffectiveAt, "Treasury delay not over");
+
+        treasury = treasury_;
+        pendingTreasury = address(0);
+        treasuryEffectiveAt = 0;
+
+        emit TreasurySet(treasury_);
+    }
+
+    function getTreasury() external view returns (address, address, uint64) {
+        return (treasury, pendingTreasury, treasuryEffectiveAt);
+    }
+
+    function getDormancy(uint256 job_id) external view returns (uint64, bool) {
+        return (sweepAfter[job_id], jobs[job_id].swept);
+    }
+
+    function getJob(uint256 job_id)
+        external
+        view
+        returns (uint256, address, address, uint256, uint64, bool, bool)
+    {
+        assertJobExists(job_id);
+        Job storage j = jobs[job_id];
+        return (j.jobId, j.client, j.freelancer, j.amount, j.deadline, j.released, j.refunded);
+    }
+
+    function getJobsRange(uint256 start_id, uint64 count)
+        external
+        view
+        returns (address[] memory, address[] memory, uint256[] memory, uint64[] memory, bool[] memory, bool[] memory)
+    {
+        require(start_id != 0, "Invalid start");
+        require(count <= MAX_JOBS_RANGE, "Range too large");
+
+        uint256 end = start_id + count;
+        if (end > jobCount + 1) end = jobCount + 1;
+        uint256 n = end > start_id ? end - start_id : 0;
+        address[] memory clients = new address[](n);
+        address[] memory freelancers = new address[](n);
+        uint256[] memory amounts = new uint256[](n);
+        uint64[] memory deadlines = new uint64[](n);
+        bool[] memory released = new bool[](n);
+        bool[] memory refunded = new bool[](n);
+        for (uint256 k = 0; k < n; k++) {
+            Job storage j = jobs[start_id + k];
+            clients[k] = j.client;
+            freelancers[k] = j.freelancer;
+            amounts[k] = j.amount;
+            deadlines[k] = j.deadline;
+            released[k] = j.released;
+            refunded[k] = j.refunded;
+        }
+        return (clients, freelancers, amounts, deadlines, released, refunded);
+    }
+
+    function queryJobs(uint8 status, address party, uint256 cursor, uint64 limit)
+        external
+        view
+        returns (
+            uint256[] memory ids,
+            address[] memory clients,
+            address[] memory freelancers,
+            uint256[] memory amounts,
+            uint64[] memory deadlines,
+            uint8[] memory statuses,
+            uint256 next
+        )
+    {
+        require(status <= JOB_STATUS_SWEPT, "Invalid status");
+        require(limit != 0 && limit <= MAX_JOBS_RANGE, "Invalid limit");
+
+        (ids, next) = scanJobs(status, party, cursor, limit);
+        (clients, freelancers, amounts, deadlines, statuses) = jobColumns(ids);
+    }
+
+    function queryJobsByCategory(uint16 category, bytes32 tag, uint8 status, uint256 cursor, uint64 limit)
+        external
+        view
+        returns (
+            uint256[] memory ids,
+            address[] memory clients,
+            address[] memory freelancers,
+            uint256[] memory amounts,
+            uint64[] memory deadlines,
+            uint8[] memory statuses,
+            uint256 next
+        )
+    {
+        require(status <= JOB_STATUS_SWEPT, "Invalid status");
+        require(limit != 0 && limit <= MAX_JOBS_RANGE, "Invalid limit");
+
+        ids = new uint256[](limit);
+        uint256 n = 0;
+        uint256 start = cursor == 0 ? 1 : cursor;
+        (uint256 end, uint256 resume) = jobScanWindow(start);
+        next = resume;
+        for (uint256 i = start; i < end; i++) {
+            if (n == limit) {
+                next = i;
+                break;
+            }
+            if ((status == JOB_STATUS_ANY || jobStatus(i) == status) && inCategory(i, category, tag)) {
+                ids[n++] = i;
+            }
+        }
+        assembly {
+            mstore(ids, n)
+        }
+        (clients, freelancers, amounts, deadlines, statuses) = jobColumns(ids);
+    }
+
+    function getJobCategory(uint256 job_id) external view returns (uint16, bytes32) {
+        assertJobExists(job_id);
+        return (jobs[job_id].category, jobs[job_id].tag);
+    }
+
+    function getCategoryStats(uint16 category) external view returns (uint256, uint256) {
+        CategoryStats storage s = categoryStats[category];
+        return (s.jobs, s.deposited);
+    }
+
+    /// Columns of the page `queryJobs` returns for `ids`
+    function jobColumns(uint256[] memory ids)
+        private
+        view
+        returns (
+            address[] memory clients,
+            address[] memory freelancers,
+            uint256[] memory amounts,
+            uint64[] memory deadlines,
+            uint8[] memory statuses
+        )
+    {
+        clients = new address[](ids.length);
+        freelancers = new address[](ids.length);
+        amounts = new uint256[](ids.length);
+        deadlines = new uint64[](ids.length);
+        statuses = new uint8[](ids.length);
+        for (uint256 k = 0; k < ids.length; k++) {
+            Job storage j = jobs[ids[k]];
+            clients[k] = j.client;
+            freelancers[k] = j.freelancer;
+            amounts[k] = j.amount;
+            deadlines[k] = j.deadline;
+            statuses[k] = jobStatus(ids[k]);
+        }
+    }
+
+    function getJobsExpiringBefore(uint64 timestamp, uint256 cursor, uint64 limit)
+        external
+        view
+        returns (uint256[] memory, uint256)
+    {
+        require(limit != 0 && limit <= MAX_JOBS_RANGE, "Invalid limit");
+
+        uint256[] memory ids = new uint256[](limit);
+        uint256 n = 0;
+        uint256 next = 0;
+        for (uint256 i = cursor; i < activeJobIds.length; i++) {
+            if (n == limit) {
+                next = i;
+                break;
+            }
+            uint256 jobId = activeJobIds[i];
+            if (fundings[jobId].raised >= fundings[jobId].target && jobs[jobId].deadline < timestamp) {
+                ids[n++] = jobId;
+            }
+        }
+        assembly {
+            mstore(ids, n)
+        }
+        return (ids, next);
+    }
+
+    function resolver(uint256 cursor) external view returns (bool canExec, bytes memory execPayload) {
+        if (pausedNow()) return (false, bytes("Escrow is paused"));
+        uint256 end = activeJobIds.length;
+        if (cursor < end && end - cursor > MAX_JOBS_RANGE) end = cursor + MAX_JOBS_RANGE;
+        for (uint256 i = cursor; i < end; i++) {
+            execPayload = dueSettlement(activeJobIds[i]);
+            if (execPayload.length != 0) return (true, execPayload);
+        }
+        return (false, bytes("No settlement due"));
+    }
+
+    function getActiveJobs() external view returns (uint256[] memory) {
+        return activeJobIds;
+    }
+
+    function getTotalJobs() external view returns (uint256) {
+        return jobCount;
+    }
+
+    function isPaused() external view returns (bool) {
+        return pausedNow();
+    }
+
+    function getProtocolStats() external view returns (uint256, uint256, uint256, uint256, uint256, uint256) {
+        return (totalDeposited, totalReleased, totalRefunded, totalSwept, totalFeesCollected, totalDisputed);
+    }
+
+    function verifySolvency() external view returns (bool) {
+        uint256 owed = 0;
+        for (uint256 i = 1; i <= jobCount; i++) {
+            if (!jobs[i].released && !jobs[i].refunded && !jobs[i].swept) {
+                owed += jobs[i].amount;
+            }
+        }
+        owed += totalCredit + accruedFees + totalDisputeStakes + totalJurorStakes + totalAppealBonds;
+        return owed
+            == totalDeposited + totalCredit + accruedFees + totalDisputeStakes + totalJurorStakes + totalAppealBonds
+                - totalReleased - totalRefunded - totalSwept - totalStakingLosses
+            && address(this).balance + totalStaked >= owed;
+    }
+
+    function getUserStats(address user) external view returns (uint256, uint256, uint256, uint256, uint256, uint256, uint256) {
+        UserStats storage s = userStats[user];
+        return (
+            s.jobsAsClient,
+            s.jobsAsFreelancer,
+            s.totalDeposited,
+            s.totalEarned,
+            s.totalRefunded,
+            lockedAmounts[user],
+            pendingEarnings[user]
+        );
+    }
+
+    function getJobStake(uint256 job_id)
+        external
+        view
+        returns (address, uint256, uint256, uint16, uint16, address, uint16)
+    {
+        JobStake storage stake = jobStakes[job_id];
+        return (
+            stake.token,
+            stake.principal,
+            stake.shares,
+            stake.freelancerYieldBps,
+            stake.treasuryYieldBps,
+            stake.consentedBy,
+            stake.maxSlippageBps
+        );
+    }
+
+    function rateJob(uint256 job_id, uint8 stars) external {
+        require(!pausedNow(), "Escrow is paused");
+        Job storage job = jobs[job_id];
+        require(finalized[job_id] && !job.swept, "Job not settled");
+        require(isJobParty(job_id, msg.sender) && job.freelancer != address(0), "Only job parties can rate");
+        require(stars != 0 && stars <= MAX_STARS, "Invalid rating");
+        uint8 given = msg.sender == job.client ? jobRatings[job_id].byClient : jobRatings[job_id].byFreelancer;
+        require(given == 0, "Already rated");
+
+        rate(job_id, msg.sender, stars);
+    }
+
+    function getJobRatings(uint256 job_id) external view returns (uint8, uint8) {
+        return (jobRatings[job_id].byClient, jobRatings[job_id].byFreelancer);
+    }
+
+    function getReputation(address account)
+        external
+        view
+        returns (uint32, uint32, uint32, uint256, uint256, uint32, uint64, uint32, uint16, uint32, uint16)
+    {
+        Reputation storage r = reputations[account];
+        (uint256 weightedStars, uint256 weight) = weightedReputation(account);
+        FreelancerProfile storage profile = freelancerProfiles[account];
+        uint64 average = profile.jobsCompleted == 0 ? 0 : profile.settlementTime / profile.jobsCompleted;
+        (uint16 refundBps, uint32 clientDisputes, uint16 lossBps) = clientStanding(account);
+        return (
+            r.ratings,
+            r.starsTotal,
+            r.disputesLost,
+            weightedStars,
+            weight,
+            profile.jobsCompleted,
+            average,
+            profile.disputes,
+            refundBps,
+            clientDisputes,
+            lossBps
+        );
+    }
+
+    /// Mirrors `client_profile` in `src/profiles.rs`
+    function clientStanding(address client) private view returns (uint16, uint32, uint16) {
+        UserStats storage stats = userStats[client];
+        ClientProfile storage profile = clientProfiles[client];
+        uint16 refundBps = stats.totalDeposited == 0 ? 0 : uint16(stats.totalRefunded * BASIS_POINTS / stats.totalDeposited);
+        uint16 lossBps = profile.disputes == 0 ? 0 : uint16(uint256(profile.disputesLost) * BASIS_POINTS / profile.disputes);
+        return (refundBps, profile.disputes, lossBps);
+    }
+
+    function getScore(address account) external view returns (uint16) {
+        return reputationScore(account);
+    }
+
+    function getPeriodStats(uint64 epoch) external view returns (uint256, uint256, uint256, uint256) {
+        PeriodStats storage s = periodStats[epoch];
+        return (s.jobsCreated, s.deposited, s.released, s.refunded);
+    }
+
+    function isSunset() private view returns (bool) {
+        return sunsetAt != 0 && block.timestamp >= sunsetAt;
+    }
+
+    /// Mirrors `assert_not_sunset` in `src/lib.rs`
+    function assertNotSunset() private view {
+        assertNotRetired();
+        require(!isSunset(), "Escrow is sunset");
+    }
+
+    /// Mirrors `assert_not_retired` in `src/lib.rs`
+    function assertNotRetired() private view {
+        require(!retired, "Escrow is retired");
+    }
+
+    function recordDeposit(address client, address freelancer, uint256 amount) private {
+        recordClientDeposit(client, amount);
+        recordAssignment(freelancer);
+    }
+
+    function recordClientDeposit(address client, uint256 amount) private {
+        totalDeposited += amount;
+        userStats[client].jobsAsClient += 1;
+        userStats[client].totalDeposited += amount;
+        recordPeriodDeposit(true, amount);
+    }
+
+    function recordAssignment(address freelancer) private {
+        userStats[freelancer].jobsAsFreelancer += 1;
+        takeJobSlot(freelancer);
+    }
+
+    function recordUnassignment(address freelancer) private {
+        if (userStats[freelancer].jobsAsFreelancer != 0) userStats[freelancer].jobsAsFreelancer -= 1;
+        freeJobSlot(freelancer);
+    }
+
+    function recordRelease(uint256 job_id, address freelancer, uint256 amount) private {
+        unlockEscrow(job_id, amount);
+        totalReleased += amount;
+        userStats[freelancer].totalEarned += amount;
+        periodStats[currentEpoch()].released += amount;
+    }
+
+    function recordRefund(uint256 job_id, address client, uint256 amount) private {
+        unlockEscrow(job_id, amount);
+        totalRefunded += amount;
+        userStats[client].totalRefunded += amount;
+        periodStats[currentEpoch()].refunded += amount;
+    }
+
+    /// Mirrors `lock_escrow` in `src/escrowed.rs`
+    function lockEscrow(uint256 job_id, uint256 amount) private {
+        Job storage job = jobs[job_id];
+        lockedAmounts[job.client] += amount;
+        if (job.freelancer != address(0)) pendingEarnings[job.freelancer] += amount;
+    }
+
+    /// Mirrors `unlock_escrow` in `src/escrowed.rs`
+    function unlockEscrow(uint256 job_id, uint256 amount) private {
+        Job storage job = jobs[job_id];
+        address client = job.client;
+        address freelancer = job.freelancer;
+        lockedAmounts[client] = lockedAmounts[client] > amount ? lockedAmounts[client] - amount : 0;
+        if (freelancer != address(0)) {
+            pendingEarnings[freelancer] = pendingEarnings[freelancer] > amount ? pendingEarnings[freelancer] - amount : 0;
+        }
+    }
+
+    function payRelease(uint256 job_id, address freelancer, uint256 amount) private {
+        Withholding storage w = withholdings[freelancer];
+        (uint256 net, uint256 withheld, uint256 fee, uint256 platformFee_) = releaseSplit(job_id, freelancer, amount);
+        recordClientVolume(job_id, amount);
+        chargeFee(job_id, fee);
+        chargePlatformFee(job_id, platformFee_);
+
+        if (withheld != 0) {
+            pushPayout(job_id, w.destination, withheld);
+            emit Withheld(job_id, w.destination, net, withheld);
+        }
+        if (net != 0) {
+            payFreelancer(job_id, freelancer, net);
+        }
+    }
+
+    /// Mirrors `release_split` in `src/preview.rs`
+    function releaseSplit(uint256 job_id, address freelancer, uint256 amount)
+        private
+        view
+        returns (uint256, uint256, uint256, uint256)
+    {
+        uint256 fee = protocolFee(job_id, amount);
+        uint256 platformFee_ = platformFee(job_id, amount);
+        uint256 payout = amount - fee - platformFee_;
+        uint256 withheld = payout * withholdings[freelancer].rateBps / BASIS_POINTS;
+        return (payout - withheld, withheld, fee, platformFee_);
+    }
+
+    /// Mirrors `fee_on` in `src/fees.rs`
+    function feeOn(uint256 amount, uint16 fee_bps) private pure returns (uint256) {
+        return amount * fee_bps / BASIS_POINTS;
+    }
+
+    /// Mirrors `revenue_recipient` in `src/treasury.rs`
+    function revenueRecipient(address fallback_) private view returns (address) {
+        return treasury == address(0) ? fallback_ : treasury;
+    }
+
+    /// Mirrors `payout_recipient` in `src/payouts.rs`
+    function payoutRecipient(address freelancer) private view returns (address) {
+        address payoutAddress = payoutAddresses[freelancer].current;
+        return payoutAddress == address(0) ? freelancer : payoutAddress;
+    }
+
+    /// Mirrors `assert_no_guardian_quorum` in `src/guardians.rs`
+    function assertNoGuardianQuorum() private view {
+        require(guardianThreshold == 0, "Guardian quorum required");
+    }
+
+    /// Mirrors `assert_guardian` in `src/guardians.rs`
+    function assertGuardian() private view {
+        require(isGuardian[msg.sender], "Only guardian");
+    }
+
+    /// Mirrors `assert_parameters_final` in `src/ownership.rs`
+    function assertParametersFinal() private view {
+        require(
+            !paused && pendingTreasury == address(0) && feeManager == address(0) && pauser == address(0),
+            "Parameters not final"
+        );
+    }
+
+    /// Mirrors `pause_limit` in `src/guardians.rs`
+    function pauseLimit() private view returns (uint64) {
+        if (guardianThreshold != 0 && (maxPause == 0 || maxPause > MAX_ADMIN_PAUSE)) {
+            return MAX_ADMIN_PAUSE;
+        }
+        return maxPause;
+    }
+
+    /// Mirrors `paused_now` in `src/guardians.rs`
+    function pausedNow() private view returns (bool) {
+        return paused && (pauseUntil == 0 || block.timestamp < pauseUntil);
+    }
+
+    /// Mirrors `replace_guardians` in `src/guardians.rs`
+    function replaceGuardians(address[] calldata guardians_, uint8 threshold) private {
+        for (uint256 i = 0; i < guardians.length; i++) {
+            isGuardian[guardians[i]] = false;
+        }
+        delete guardians;
+        for (uint256 i = 0; i < guardians_.length; i++) {
+            guardians.push(guardians_[i]);
+            isGuardian[guardians_[i]] = true;
+        }
+        guardianThreshold = threshold;
+        guardianEpoch++;
+    }
+
+    /// Mirrors `execute_emergency` in `src/guardians.rs`
+    function executeEmergency(uint8 action, uint256 target) private {
+        if (action == EMERGENCY_ACTION_REFUND) {
+            refundEmergency(target);
+        } else if (action == EMERGENCY_ACTION_SWEEP_SURPLUS) {
+            sweepSurplusNow();
+        } else {
+            require(target <= type(uint64).max, "Invalid pause end");
+            extendPause(uint64(target));
+        }
+    }
+
+    /// Mirrors `refund_emergency` in `src/guardians.rs`
+    function refundEmergency(uint256 job_id) private {
+        assertEmergencyRefundable(job_id);
+        payEmergencyRefund(job_id);
+    }
+
+    /// Mirrors `assert_emergency_refundable` in `src/guardians.rs`
+    function assertEmergencyRefundable(uint256 job_id) private view {
+        assertJobExists(job_id);
+        Job storage job = jobs[job_id];
+        require(!job.released && !job.refunded, "Job already settled");
+        require(!finalized[job_id], "Job already finalized");
+        assertFunded(job_id);
+        assertNotStaked(job_id);
+        require(block.timestamp >= job.createdAt + EMERGENCY_REFUND_MIN_AGE || block.timestamp >= job.deadline + EMERGENCY_REFUND_GRACE, "Job too recent");
+    }
+
+    /// Whether `assertEmergencyRefundable` would pass for `job_id`
+    function isEmergencyRefundable(uint256 job_id) private view returns (bool) {
+        Job storage job = jobs[job_id];
+        return job_id != 0 && job_id <= jobCount
+            && !job.released && !job.refunded
+            && !finalized[job_id]
+            && fundings[job_id].raised >= fundings[job_id].target
+            && (block.timestamp >= job.createdAt + EMERGENCY_REFUND_MIN_AGE || block.timestamp >= job.deadline + EMERGENCY_REFUND_GRACE);
+    }
+
+    /// Mirrors `pay_emergency_refund` in `src/guardians.rs`
+    function payEmergencyRefund(uint256 job_id) private {
+        Job storage job = jobs[job_id];
+        settleEmergencyRefund(job_id);
+
+        pay(job.client, job.amount);
+        payBasket(job_id, 0);
+
+        logEmergencyRefund(job_id, job.amount);
+    }
+
+    /// Mirrors `try_emergency_refund` in `src/guardians.rs`
+    function tryEmergencyRefund(uint256 job_id) private returns (bool) {
+        if (!isEmergencyRefundable(job_id) || baskets[job_id].length != 0) {
+            return false;
+        }
+        Job storage job = jobs[job_id];
+        settleEmergencyRefund(job_id);
+
+        (bool ok,) = job.client.call{value: job.amount}("");
+        if (!ok) {
+            credit(job.client, job.amount);
+        }
+
+        logEmergencyRefund(job_id, job.amount);
+        return true;
+    }
+
+    /// Mirrors `settle_emergency_refund` in `src/guardians.rs`
+    function settleEmergencyRefund(uint256 job_id) private {
+        Job storage job = jobs[job_id];
+        job.refunded = true;
+        closeJob(job_id);
+        recordRefund(job_id, job.client, job.amount);
+    }
+
+    /// Mirrors `log_emergency_refund` in `src/guardians.rs`
+    function logEmergencyRefund(uint256 job_id, uint256 amount) private {
+        emit EmergencyRefunded(job_id, msg.sender);
+        callSettlementHook(job_id, amount, false);
+    }
+
+    /// Mirrors `sweep_surplus_now` in `src/guardians.rs`
+    function sweepSurplusNow() private {
+        uint256 available = surplus();
+        uint256 amount = available > accruedFees ? available - accruedFees : 0;
+        require(amount != 0, "No surplus");
+        address to = revenueRecipient(admin);
+
+        pay(to, amount);
+
+        emit SurplusSwept(to, amount);
+    }
+
+    /// Mirrors `extend_pause` in `src/guardians.rs`
+    function extendPause(uint64 until) private {
+        require(pausedNow(), "Escrow is not paused");
+        require(pauseUntil != 0 && until > pauseUntil, "Invalid pause end");
+
+        pauseUntil = until;
+
+        emit PauseExtended(until);
+    }
+
+    /// Mirrors `is_governed` in `src/governance.rs`
+    function isGoverned() private view returns (bool) {
+        return governanceQuorum != 0;
+    }
+
+    /// Mirrors `assert_not_governed` in `src/governance.rs`
+    function assertNotGoverned() private view {
+        require(!isGoverned(), "Governed parameter");
+    }
+
+    /// Mirrors `replace_council` in `src/governance.rs`
+    function replaceCouncil(address[] memory council_) private {
+        for (uint256 i = 0; i < council.length; i++) {
+            isCouncil[council[i]] = false;
+        }
+        delete council;
+        for (uint256 i = 0; i < council_.length; i++) {
+            council.push(council_[i]);
+            isCouncil[council_[i]] = true;
+        }
+        councilEpoch++;
+    }
+
+    /// Mirrors `voting_weight` in `src/governance.rs`
+    function votingWeight(address voter, uint64 snapshot) private view returns (uint256) {
+        if (governanceToken == address(0)) {
+            return isCouncil[voter] ? 1 : 0;
+        }
+        try IVotes(governanceToken).getPastVotes(voter, snapshot) returns (uint256 votes) {
+            return votes;
+        } catch {
+            revert("Votes unavailable");
+        }
+    }
+
+    /// Mirrors `open_governance_proposal` in `src/governance.rs`
+    function openGovernanceProposal(address proposer, uint8 action, address target, uint256 value, bytes memory data)
+        private
+        returns (uint256 proposalId)
+    {
+        proposalId = ++governanceProposalCount;
+        uint64 snapshot = block.number == 0 ? 0 : uint64(block.number - 1);
+        uint64 votingEnds = uint64(block.timestamp) + GOVERNANCE_VOTING_PERIOD;
+        GovernanceProposal storage proposal = governanceProposals[proposalId];
+        proposal.action = action;
+        proposal.target = target;
+        proposal.value = value;
+        proposal.proposer = proposer;
+        proposal.snapshot = snapshot;
+        proposal.councilEpoch = councilEpoch;
+        proposal.votingEnds = votingEnds;
+        proposal.data = data;
+
+        emit GovernanceProposed(proposalId, action, target, value, proposer, votingEnds);
+    }
+
+    /// Mirrors `proposal_lapsed` in `src/governance.rs`
+    function proposalLapsed(uint256 proposal_id) private view returns (bool) {
+        GovernanceProposal storage proposal = governanceProposals[proposal_id];
+        if (proposal.executed) return false;
+        if (proposal.councilEpoch != councilEpoch) return true;
+        if (block.timestamp < proposal.votingEnds) return false;
+        bool passed = proposal.votesFor >= governanceQuorum && proposal.votesFor > proposal.votesAgainst;
+        uint256 expiresAt = uint256(proposal.votingEnds) + GOVERNANCE_TIMELOCK + GOVERNANCE_EXECUTION_WINDOW;
+        return !passed || block.timestamp >= expiresAt;
+    }
+
+    /// Mirrors `execute_governance_action` in `src/governance.rs`
+    function executeGovernanceAction(uint8 action, address target, uint256 value, bytes memory data) private {
+        if (action == GOVERNANCE_ACTION_PROTOCOL_FEE) {
+            require(value <= type(uint16).max, "Invalid fee");
+            updateProtocolFee(uint16(value));
+        } else if (action == GOVERNANCE_ACTION_DEFAULT_JOB_CAP) {
+            require(value <= type(uint32).max, "Invalid cap");
+            updateDefaultJobCap(uint32(value));
+        } else if (action == GOVERNANCE_ACTION_ARBITRATOR) {
+            updateArbitrationPolicy(target, rulingPeriod, defaultSplitBps);
+        } else if (action == GOVERNANCE_ACTION_RULING_PERIOD) {
+            require(value <= type(uint64).max, "Invalid ruling period");
+            updateArbitrationPolicy(arbitrator, uint64(value), defaultSplitBps);
+        } else if (action == GOVERNANCE_ACTION_DISPUTE_TIMEOUT) {
+            require(value <= type(uint64).max, "Invalid timeout");
+            updateDisputeTimeoutPolicy(uint64(value), timeoutSplit, timeoutSplitBps);
+        } else if (action == GOVERNANCE_ACTION_DORMANCY_PERIOD) {
+            require(value <= type(uint64).max, "Dormancy period too short");
+            updateDormancyPolicy(uint64(value), recoveryAddress);
+        } else if (action == GOVERNANCE_ACTION_ADD_COUNCIL_MEMBER) {
+            require(target != address(0) && !isCouncil[target], "Invalid council member");
+            require(council.length < MAX_COUNCIL, "Council too large");
+            address[] memory council_ = new address[](council.length + 1);
+            for (uint256 i = 0; i < council.length; i++) {
+                council_[i] = council[i];
+            }
+            council_[council.length] = target;
+            replaceCouncil(council_);
+            emit GovernanceSet(governanceToken, council, governanceQuorum);
+        } else if (action == GOVERNANCE_ACTION_REMOVE_COUNCIL_MEMBER) {
+            require(isCouncil[target], "Invalid council member");
+            require(governanceQuorum <= council.length - 1, "Invalid quorum");
+            address[] memory council_ = new address[](council.length - 1);
+            uint256 n = 0;
+            for (uint256 i = 0; i < council.length; i++) {
+                if (council[i] != target) {
+                    council_[n++] = council[i];
+                }
+            }
+            replaceCouncil(council_);
+            emit GovernanceSet(governanceToken, council, governanceQuorum);
+        } else if (action == GOVERNANCE_ACTION_QUORUM) {
+            require(value != 0 && (governanceToken != address(0) || value <= council.length), "Invalid quorum");
+            governanceQuorum = value;
+            emit GovernanceSet(governanceToken, council, governanceQuorum);
+        } else if (action == GOVERNANCE_ACTION_REFUND_ROOT) {
+            updateRefundRoot(bytes32(value));
+        } else if (action == GOVERNANCE_ACTION_APPEAL) {
+            upholdAppeal(value);
+        } else if (action == GOVERNANCE_ACTION_REPLACE_ARBITRATOR) {
+            replaceArbitrator(value, target);
+        } else if (action == GOVERNANCE_ACTION_SETTING) {
+            applyGovernedSetting(uint8(value), data);
+        } else {
+            updatePendingTreasury(target);
+        }
+    }
+
+    /// Mirrors `update_protocol_fee` in `src/governance.rs`
+    function updateProtocolFee(uint16 fee_bps) private {
+        require(fee_bps <= MAX_PROTOCOL_FEE_BPS, "Invalid fee");
+        protocolFeeBps = fee_bps;
+        emit ProtocolFeeSet(fee_bps);
+    }
+
+    /// Mirrors `update_default_job_cap` in `src/governance.rs`
+    function updateDefaultJobCap(uint32 cap) private {
+        defaultJobCap = cap;
+        emit DefaultJobCapSet(cap);
+    }
+
+    /// Mirrors `update_arbitration_policy` in `src/governance.rs`
+    function updateArbitrationPolicy(address arbitrator_, uint64 ruling_period, uint16 default_split_bps) private {
+        require(arbitrator_ == address(0) || ruling_period != 0, "Invalid ruling period");
+        require(default_split_bps <= BASIS_POINTS, "Invalid split");
+
+        arbitrator = arbitrator_;
+        rulingPeriod = ruling_period;
+        defaultSplitBps = default_split_bps;
+
+        emit ArbitrationPolicySet(arbitrator_, ruling_period, default_split_bps);
+    }
+
+    /// Mirrors `update_dispute_timeout_policy` in `src/governance.rs`
+    function updateDisputeTimeoutPolicy(uint64 timeout, bool split, uint16 split_bps) private {
+        require(split_bps <= BASIS_POINTS, "Invalid split");
+
+        disputeTimeout = timeout;
+        timeoutSplit = split;
+        timeoutSplitBps = split_bps;
+
+        emit DisputeTimeoutPolicySet(timeout, split, split_bps);
+    }
+
+    /// Mirrors `update_dormancy_policy` in `src/governance.rs`
+    function updateDormancyPolicy(uint64 period, address recovery) private {
+        require(period >= MIN_DORMANCY_PERIOD, "Dormancy period too short");
+        require(recovery != address(0), "Invalid recovery address");
+
+        dormancyPeriod = period;
+        recoveryAddress = recovery;
+
+        emit DormancyPolicySet(period, recovery);
+    }
+
+    /// Mirrors `current_fee_manager` in `src/fees.rs`
+    function currentFeeManager() private view returns (address) {
+        return feeManager == address(0) ? admin : feeManager;
+    }
+
+    /// Mirrors `assert_fee_manager` in `src/fees.rs`
+    function assertFeeManager() private view {
+        require(msg.sender == currentFeeManager(), "Only fee manager");
+    }
+
+    /// Mirrors `update_fee_manager` in `src/fees.rs`
+    function updateFeeManager(address manager) private {
+        feeManager = manager;
+        emit FeeManagerSet(manager);
+    }
+
+    /// Mirrors `update_settlement_rebate` in `src/fees.rs`
+    function updateSettlementRebate(uint256 amount) private {
+        settlementRebate = amount;
+        emit SettlementRebateSet(amount);
+    }
+
+    /// Mirrors `update_fee_exemption` in `src/fees.rs`
+    function updateFeeExemption(address account, bool exempt) private {
+        feeExempt[account] = exempt;
+        emit FeeExemptionSet(account, exempt);
+    }
+
+    /// Mirrors `fee_rate` in `src/fees.rs`
+    function feeRate(address client, uint256 amount) private view returns (uint16) {
+        if (feeExempt[client]) return 0;
+        uint16 feeBps = protocolFeeBps;
+        for (uint256 i = 0; i < feeTiers.length && feeTiers[i].minAmount <= amount; i++) {
+            feeBps = feeTiers[i].feeBps;
+        }
+        return uint16(uint32(feeBps) * (BASIS_POINTS - volumeDiscount(client)) / BASIS_POINTS);
+    }
+
+    /// Mirrors `volume_discount` in `src/fees.rs`
+    function volumeDiscount(address client) private view returns (uint16) {
+        uint256 volume = clientVolume[client];
+        uint16 discountBps = 0;
+        for (uint256 i = 0; i < volumeDiscounts.length && volumeDiscounts[i].minVolume <= volume; i++) {
+            discountBps = volumeDiscounts[i].discountBps;
+        }
+        return discountBps;
+    }
+
+    /// Mirrors `record_client_volume` in `src/fees.rs`
+    function recordClientVolume(uint256 job_id, uint256 amount) private {
+        clientVolume[jobs[job_id].client] += amount;
+    }
+
+    /// Mirrors `snapshot_fee` in `src/fees.rs`
+    function snapshotFee(uint256 job_id, address client, uint256 amount) private {
+        jobFeeBps[job_id] = feeRate(client, amount);
+    }
+
+    /// Mirrors `protocol_fee` in `src/fees.rs`
+    function protocolFee(uint256 job_id, uint256 amount) private view returns (uint256) {
+        return feeOn(amount, jobFeeBps[job_id]);
+    }
+
+    /// Mirrors `surplus` in `src/solvency.rs`
+    function surplus() private view returns (uint256) {
+        uint256 principal = totalCredit + totalDisputeStakes + totalJurorStakes + totalAppealBonds;
+        for (uint256 i = 0; i < activeJobIds.length; i++) {
+            principal += jobs[activeJobIds[i]].amount;
+        }
+        uint256 assets = address(this).balance + totalStaked;
+        return assets > principal ? assets - principal : 0;
+    }
+
+    /// Mirrors `charge_fee` in `src/fees.rs`
+    function chargeFee(uint256 job_id, uint256 fee) private {
+        if (fee == 0) return;
+        accruedFees += fee;
+        totalFeesCollected += fee;
+        emit FeeCharged(job_id, address(this), fee);
+    }
+
+    /// Mirrors `pay_settlement_rebate` in `src/rebates.rs`
+    function paySettlementRebate(uint256 job_id, uint256 amount) private {
+        uint256 fee = protocolFee(job_id, amount);
+        uint256 rebate = settlementRebate < fee ? settlementRebate : fee;
+        if (rebate == 0) return;
+        accruedFees -= rebate;
+
+        pay(msg.sender, rebate);
+
+        emit SettlementRebated(job_id, msg.sender, rebate);
+    }
+
+    /// Mirrors `platform_fee` in `src/platform.rs`
+    function platformFee(uint256 job_id, uint256 amount) private view returns (uint256) {
+        return feeOn(amount, jobPlatforms[job_id].feeBps);
+    }
+
+    /// Mirrors `pending_holdback` in `src/holdbacks.rs`
+    function pendingHoldback(uint256 job_id, uint256 amount) private view returns (uint256) {
+        Holdback storage holdback = holdbacks[job_id];
+        if (holdback.warrantyEnds != 0) return 0;
+        return feeOn(amount, holdback.bps);
+    }
+
+    /// Mirrors `retain_holdback` in `src/holdbacks.rs`
+    function retainHoldback(uint256 job_id, uint256 held) private {
+        Job storage job = jobs[job_id];
+        uint256 amount = job.amount - held;
+        uint64 releaseAt = uint64(block.timestamp) + holdbacks[job_id].warranty;
+
+        job.amount = held;
+        holdbacks[job_id].warrantyEnds = releaseAt;
+        recordRelease(job_id, job.freelancer, amount);
+
+        payRelease(job_id, job.freelancer, amount);
+
+        emit PartiallyReleased(job_id, amount, held);
+        emit HoldbackRetained(job_id, held, releaseAt);
+    }
+
+    /// Mirrors `days_late` in `src/sla.rs`
+    function daysLate(uint256 job_id) private view returns (uint64) {
+        Sla storage sla = slas[job_id];
+        uint64 deliveredAt = sla.deliveredAt == 0 ? uint64(block.timestamp) : sla.deliveredAt;
+        if (deliveredAt <= sla.deliveryBy) return 0;
+        return (deliveredAt - sla.deliveryBy) / 1 days;
+    }
+
+    /// Mirrors `late_refund` in `src/sla.rs`
+    function lateRefund(uint256 job_id, uint256 amount) private view returns (uint256) {
+        if (slas[job_id].deliveryBy == 0) return 0;
+        uint256 bps = uint256(daysLate(job_id)) * slas[job_id].lateBpsPerDay;
+        if (bps > BASIS_POINTS) bps = BASIS_POINTS;
+        return feeOn(amount, uint16(bps));
+    }
+
+    /// Mirrors `refund_late` in `src/sla.rs`
+    function refundLate(uint256 job_id, uint256 late) private {
+        if (late == 0) return;
+        Job storage job = jobs[job_id];
+        job.amount -= late;
+        recordRefund(job_id, job.client, late);
+
+        pay(job.client, late);
+
+        emit LateRefunded(job_id, daysLate(job_id), late);
+    }
+
+    /// Mirrors `submitted_timesheet` in `src/timesheets.rs`
+    function submittedTimesheet(uint256 job_id, uint256 index) private view returns (uint256) {
+        require(index < timesheets[job_id].length, "Invalid timesheet");
+        Timesheet storage sheet = timesheets[job_id][index];
+        require(sheet.status == TIMESHEET_SUBMITTED, "Timesheet already settled");
+        return engagements[job_id].rate * sheet.hours_;
+    }
+
+    /// Mirrors `pay_timesheet` in `src/timesheets.rs`
+    function payTimesheet(uint256 job_id, uint256 index, uint256 amount) private {
+        decideTimesheet(job_id, index, amount, TIMESHEET_APPROVED);
+
+        Job storage job = jobs[job_id];
+        uint256 remaining = job.amount - amount;
+        job.amount = remaining;
+        if (remaining == 0) {
+            job.released = true;
+            closeJob(job_id);
+        }
+        recordRelease(job_id, job.freelancer, amount);
+
+        payRelease(job_id, job.freelancer, amount);
+
+        emit TimesheetApproved(job_id, index, amount, remaining);
+        if (remaining == 0) {
+            callSettlementHook(job_id, amount, true);
+        }
+    }
+
+    /// Mirrors `reject_timesheet_at` in `src/timesheets.rs`
+    function rejectTimesheetAt(uint256 job_id, uint256 index, uint256 amount) private {
+        decideTimesheet(job_id, index, amount, TIMESHEET_REJECTED);
+
+        emit TimesheetRejected(job_id, index);
+    }
+
+    /// Mirrors `decide_timesheet` in `src/timesheets.rs`
+    function decideTimesheet(uint256 job_id, uint256 index, uint256 amount, uint8 status) private {
+        timesheets[job_id][index].status = status;
+        engagements[job_id].pending -= amount;
+    }
+
+    /// Mirrors `push_timesheet` in `src/timesheets.rs`
+    function pushTimesheet(uint256 job_id, uint32 hours_, bytes32 details, uint256 amount) private returns (uint256) {
+        timesheets[job_id].push(Timesheet({hours_: hours_, details: details, status: TIMESHEET_SUBMITTED}));
+        engagements[job_id].pending += amount;
+        return timesheets[job_id].length - 1;
+    }
+
+    /// Mirrors `fund_basket` in `src/baskets.rs`
+    function fundBasket(uint256 job_id, address client, address token, uint256 amount) private {
+        baskets[job_id].push(BasketToken({token: token, amount: amount}));
+
+        callToken(token, abi.encodeCall(IERC20.transferFrom, (client, address(this), amount)));
+    }
+
+    /// Mirrors `pay_basket` in `src/baskets.rs`
+    function payBasket(uint256 job_id, uint16 freelancer_bps) private {
+        splitBasket(job_id, freelancer_bps, jobs[job_id].client);
+    }
+
+    /// Mirrors `sweep_basket` in `src/baskets.rs`
+    function sweepBasket(uint256 job_id, address recovery) private {
+        splitBasket(job_id, 0, recovery);
+    }
+
+    /// Mirrors `split_basket` in `src/baskets.rs`
+    function splitBasket(uint256 job_id, uint16 freelancer_bps, address rest_to) private {
+        address payoutTo = payoutRecipient(jobs[job_id].freelancer);
+        BasketToken[] storage basket = baskets[job_id];
+        for (uint256 i = 0; i < basket.length; i++) {
+            uint256 toFreelancer = basket[i].amount * freelancer_bps / BASIS_POINTS;
+            payToken(job_id, basket[i].token, payoutTo, toFreelancer);
+            payToken(job_id, basket[i].token, rest_to, basket[i].amount - toFreelancer);
+        }
+    }
+
+    /// Mirrors `pay_token` in `src/baskets.rs`
+    function payToken(uint256 job_id, address token, address to, uint256 amount) private {
+        if (amount == 0) return;
+        callToken(token, abi.encodeCall(IERC20.transfer, (to, amount)));
+
+        emit BasketPaid(job_id, token, to, amount);
+    }
+
+    /// Mirrors `call_token` in `src/baskets.rs`: accepts tokens that return
+    /// nothing as well as those returning `true`
+    function callToken(address token, bytes memory data) private {
+        (bool ok, bytes memory ret) = token.call(data);
+        require(ok && (ret.length == 0 || (ret.length == 32 && abi.decode(ret, (bool)))), "Token transfer failed");
+    }
+
+    /// Mirrors `refund_leaf` in `crates/escrow-types/src/messages.rs`
+    function refundLeaf(uint256 job_id, uint256 amount) private pure returns (bytes32) {
+        return keccak256(bytes.concat(keccak256(abi.encode(job_id, amount))));
+    }
+
+    /// Mirrors `verify_proof` in `src/refund_roots.rs`
+    function verifyProof(bytes32[] calldata proof, bytes32 root, bytes32 leaf) private pure returns (bool) {
+        bytes32 node = leaf;
+        for (uint256 i = 0; i < proof.length; i++) {
+            node = node <= proof[i]
+                ? keccak256(abi.encodePacked(node, proof[i]))
+                : keccak256(abi.encodePacked(proof[i], node));
+        }
+        return node == root;
+    }
+
+    /// Mirrors `update_refund_root` in `src/refund_roots.rs`
+    function updateRefundRoot(bytes32 root) private {
+        refundRoot = root;
+        emit RefundRootSet(root);
+    }
+
+    /// Mirrors `pay_refund_claim` in `src/refund_roots.rs`
+    function payRefundClaim(uint256 job_id, uint256 amount) private {
+        refundClaims[refundRoot][job_id] = true;
+
+        Job storage job = jobs[job_id];
+        uint256 remaining = job.amount - amount;
+        if (remaining == 0) {
+            job.refunded = true;
+            closeJob(job_id);
+        } else {
+            job.amount = remaining;
+        }
+        recordRefund(job_id, job.client, amount);
+
+        pay(job.client, amount);
+        if (remaining == 0) {
+            payBasket(job_id, 0);
+        }
+
+        emit EmergencyRefundClaimed(job_id, job.client, amount, remaining);
+        if (remaining == 0) {
+            callSettlementHook(job_id, amount, false);
+        }
+    }
+
+    /// Mirrors `decay` in `src/reputation.rs`
+    function decay(uint256 value, uint64 elapsed) private pure returns (uint256) {
+        uint64 halvings = elapsed / REPUTATION_HALF_LIFE;
+        if (halvings >= 256) return 0;
+        uint256 halved = value >> halvings;
+        uint64 into = elapsed % REPUTATION_HALF_LIFE;
+        return halved - halved * into / (2 * uint256(REPUTATION_HALF_LIFE));
+    }
+
+    /// Mirrors `rate` in `src/reputation.rs`
+    function rate(uint256 job_id, address rater, uint8 stars) private {
+        Job storage job = jobs[job_id];
+        address ratee;
+        if (rater == job.client) {
+            jobRatings[job_id].byClient = stars;
+            ratee = job.freelancer;
+        } else {
+            jobRatings[job_id].byFreelancer = stars;
+            ratee = job.client;
+        }
+
+        reputations[ratee].ratings += 1;
+        reputations[ratee].starsTotal += stars;
+        accrue(ratee, stars, job.amount);
+
+        emit JobRated(job_id, rater, ratee, stars);
+    }
+
+    /// Mirrors `record_ruling` in `src/reputation.rs`
+    function recordRuling(uint256 job_id, uint16 freelancer_bps) private {
+        Job storage job = jobs[job_id];
+        if (job.freelancer == address(0)) return;
+        uint16 half = BASIS_POINTS / 2;
+        address loser;
+        if (freelancer_bps < half) {
+            loser = job.freelancer;
+        } else if (freelancer_bps > half) {
+            loser = job.client;
+        } else {
+            return;
+        }
+
+        reputations[loser].disputesLost += 1;
+        accrue(loser, 0, job.amount * DISPUTE_LOSS_WEIGHT);
+        if (loser != job.freelancer) clientProfiles[loser].disputesLost += 1;
+    }
+
+    /// Mirrors `accrue` in `src/reputation.rs`
+    function accrue(address account, uint8 stars, uint256 weight) private {
+        (uint256 weightedStars, uint256 totalWeight) = weightedReputation(account);
+        Reputation storage r = reputations[account];
+        r.weightedStars = weightedStars + weight * stars;
+        r.weight = totalWeight + weight;
+        r.updatedAt = uint64(block.timestamp);
+    }
+
+    /// Mirrors `weighted_reputation` in `src/reputation.rs`
+    function weightedReputation(address account) private view returns (uint256, uint256) {
+        Reputation storage r = reputations[account];
+        uint64 elapsed = uint64(block.timestamp) > r.updatedAt ? uint64(block.timestamp) - r.updatedAt : 0;
+        return (decay(r.weightedStars, elapsed), decay(r.weight, elapsed));
+    }
+
+    /// Mirrors `reputation_score` in `src/reputation.rs`
+    function reputationScore(address account) private view returns (uint16) {
+        (uint256 weightedStars, uint256 weight) = weightedReputation(account);
+        return uint16(weightedStars * BASIS_POINTS / ((weight + REPUTATION_PRIOR) * MAX_STARS));
+    }
+
+    /// Mirrors `queue_release` in `src/clawbacks.rs`
+    function queueRelease(uint256 job_id, bytes32 memo) private {
+        uint64 payableAt = uint64(block.timestamp) + clawbacks[job_id].window;
+        clawbacks[job_id].payableAt = payableAt;
+        clawbacks[job_id].memo = memo;
+
+        emit ReleaseQueued(job_id, jobs[job_id].amount, payableAt);
+    }
+
+    /// Mirrors `charge_platform_fee` in `src/platform.rs`
+    function chargePlatformFee(uint256 job_id, uint256 fee) private {
+        if (fee == 0) return;
+        address recipient = jobPlatforms[job_id].platform;
+        credit(recipient, fee);
+        emit FeeCharged(job_id, recipient, fee);
+    }
+
+    /// Mirrors `record_amendment` in `src/amendments.rs`
+    function recordAmendment(uint256 job_id, uint8 field, bytes32 old_value, bytes32 new_value) private {
+        emit JobAmended(job_id, field, old_value, new_value, msg.sender);
+    }
+
+    /// Mirrors `assert_settleable` in `src/preview.rs`
+
+    function assertSettleable(uint256 job_id) private view {
+        assertJobExists(job_id);
+        require(!jobs[job_id].released && !jobs[job_id].refunded, "Job already settled");
+        require(!finalized[job_id], "Job already finalized");
+        assertFunded(job_id);
+    }
+
+    /// Mirrors `assert_previewable` in `src/preview.rs`
+    function assertPreviewable(uint256 job_id) private view {
+        assertSettleable(job_id);
+        require(baskets[job_id].length == 0, "Job has basket");
+        assertNotStaked(job_id);
+    }
+
+    /// Mirrors `push_job` in `src/registry.rs`
+    function pushJob(address client, address freelancer, uint256 size) private returns (uint256) {
+        uint256 jobId = jobCount + 1;
+        Job storage job = jobs[jobId];
+        job.jobId = jobId;
+        job.client = client;
+        job.freelancer = freelancer;
+        job.createdAt = uint64(block.timestamp);
+        jobCount = jobId;
+        indexParty(jobId, client);
+        indexParty(jobId, freelancer);
+        snapshotFee(jobId, client, size);
+        activeJobIds.push(jobId);
+        activeJobPositions[jobId] = activeJobIds.length;
+        return jobId;
+    }
+
+    /// Mirrors `deactivate_job` in `src/registry.rs`
+    function deactivateJob(uint256 job_id) private {
+        uint256 position = activeJobPositions[job_id];
+        if (position == 0) return;
+        uint256 last = activeJobIds.length - 1;
+        if (position - 1 != last) {
+            uint256 moved = activeJobIds[last];
+            activeJobIds[position - 1] = moved;
+            activeJobPositions[moved] = position;
+        }
+        activeJobIds.pop();
+        delete activeJobPositions[job_id];
+    }
+
+    /// Mirrors `index_party` in `src/registry.rs`
+    function indexParty(uint256 job_id, address party) private {
+        if (party == address(0) || partyIndexed[party][job_id]) return;
+        partyIndexed[party][job_id] = true;
+        partyJobs[party].push(job_id);
+    }
+
+    /// Mirrors `is_party` in `src/registry.rs`
+    function isParty(uint256 job_id, address party) private view returns (bool) {
+        return jobs[job_id].client == party || jobs[job_id].freelancer == party;
+    }
+
+    /// Mirrors `job_status` in `src/registry.rs`
+    function jobStatus(uint256 job_id) private view returns (uint8) {
+        Job storage job = jobs[job_id];
+        if (job.released) return JOB_STATUS_RELEASED;
+        if (job.refunded) return JOB_STATUS_REFUNDED;
+        if (job.swept) return JOB_STATUS_SWEPT;
+        return JOB_STATUS_ACTIVE;
+    }
+
+    /// Whether `job_id` passes `queryJobs`'s filter
+    function matchesQuery(uint256 job_id, uint8 status, address party) private view returns (bool) {
+        return (status == JOB_STATUS_ANY || jobStatus(job_id) == status)
+            && (party == address(0) || isParty(job_id, party));
+    }
+
+    /// IDs `queryJobs` returns and the cursor to resume from
+    function scanJobs(uint8 status, address party, uint256 cursor, uint64 limit)
+        private
+        view
+        returns (uint256[] memory ids, uint256 next)
+    {
+        ids = new uint256[](limit);
+        uint256 n = 0;
+        if (party != address(0)) {
+            uint256[] storage index = partyJobs[party];
+            for (uint256 i = cursor; i < index.length; i++) {
+                if (n == limit) {
+                    next = i;
+                    break;
+                }
+                if (matchesQuery(index[i], status, party)) {
+                    ids[n++] = index[i];
+                }
+            }
+        } else {
+            uint256 start = cursor == 0 ? 1 : cursor;
+            (uint256 end, uint256 resume) = jobScanWindow(start);
+            next = resume;
+            for (uint256 i = start; i < end; i++) {
+                if (n == limit) {
+                    next = i;
+                    break;
+                }
+                if (matchesQuery(i, status, party)) {
+                    ids[n++] = i;
+                }
+            }
+        }
+        assembly {
+            mstore(ids, n)
+        }
+    }
+
+    /// The end of the `MAX_JOBS_RANGE` job IDs scanned from `start`, and the
+    /// ID to resume from after them, or 0 when they reach the newest job
+    function jobScanWindow(uint256 start) private view returns (uint256 end, uint256 resume) {
+        if (start > jobCount || jobCount - start < MAX_JOBS_RANGE) return (jobCount + 1, 0);
+        end = start + MAX_JOBS_RANGE;
+        return (end, end);
+    }
+
+    /// Mirrors `assert_job_exists` in `src/registry.rs`
+    function assertJobExists(uint256 job_id) private view {
+        require(job_id != 0 && job_id <= jobCount, "Job does not exist");
+    }
+
+    /// Mirrors `is_disputed` in `src/disputes.rs`
+    function isDisputed(uint256 job_id) private view returns (bool) {
+        Dispute storage dispute = disputes[job_id];
+        return dispute.openedAt != 0 && !dispute.lifted && isActive(job_id);
+    }
+
+    /// Mirrors `assert_not_disputed` in `src/disputes.rs`
+    function assertNotDisputed(uint256 job_id) private view {
+        require(!isDisputed(job_id), "Job is disputed");
+    }
+
+    /// Mirrors `is_job_party` in `src/disputes.rs`
+    function isJobParty(uint256 job_id, address account) private view returns (bool) {
+        return account != address(0) && (account == jobs[job_id].client || account == jobs[job_id].freelancer);
+    }
+
+    /// Mirrors `settle_dispute` in `src/disputes.rs`
+    function settleDispute(address resolver, uint256 job_id, uint16 freelancer_bps, bool by_default) private {
+        Job storage job = jobs[job_id];
+        address client = job.client;
+        address freelancer = job.freelancer;
+        uint256 amount = job.amount;
+        uint256 toFreelancer = freelancer == address(0) ? 0 : amount * freelancer_bps / BASIS_POINTS;
+        uint256 toClient = amount - toFreelancer;
+        if (toFreelancer != 0) {
+            assertCompliant(client, freelancer, toFreelancer);
+        }
+
+        Tranche[] storage schedule = tranches[job_id];
+        for (uint256 i = 0; i < schedule.length; i++) {
+            schedule[i].settled = true;
+        }
+        if (toFreelancer == 0) {
+            job.refunded = true;
+        } else {
+            job.released = true;
+        }
+        uint256 stake = takeDisputeStake(job_id);
+        bool stakeLost = openerLost(job_id, freelancer_bps);
+        closeJob(job_id);
+        if (!by_default) {
+            recordRuling(job_id, freelancer_bps);
+        }
+
+        if (toFreelancer != 0) {
+            recordRelease(job_id, freelancer, toFreelancer);
+            payRelease(job_id, freelancer, toFreelancer);
+        }
+        if (toClient != 0) {
+            recordRefund(job_id, client, toClient);
+            pay(client, toClient);
+        }
+        payBasket(job_id, freelancer_bps);
+        payDisputeStake(job_id, stake, stakeLost);
+
+        emit DisputeResolved(job_id, resolver, toFreelancer, toClient, by_default);
+        if (toFreelancer != 0) {
+            callSettlementHook(job_id, toFreelancer, true);
+        }
+        if (toClient != 0) {
+            callSettlementHook(job_id, toClient, false);
+        }
+    }
+
+    /// Mirrors `is_active` in `src/registry.rs`
+    function isActive(uint256 job_id) private view returns (bool) {
+        return !jobs[job_id].released && !jobs[job_id].refunded && !jobs[job_id].swept;
+    }
+
+    /// Mirrors `current_epoch` in `src/analytics.rs`
+    function currentEpoch() private view returns (uint64) {
+        return uint64(block.timestamp / EPOCH_LENGTH);
+    }
+
+    /// Mirrors `record_period_deposit` in `src/analytics.rs`
+    function recordPeriodDeposit(bool newJob, uint256 amount) private {
+        PeriodStats storage s = periodStats[currentEpoch()];
+        if (newJob) {
+            s.jobsCreated += 1;
+        }
+        s.deposited += amount;
+    }
+
+    /// Mirrors `assert_compliant` in `src/compliance.rs`: fails closed
+    function assertCompliant(address client, address freelancer, uint256 amount) private view {
+        if (complianceHook == address(0)) return;
+        (bool ok, bytes memory ret) = complianceHook.staticcall(
+            abi.encodeWithSignature("check(address,address,uint256)", client, freelancer, amount)
+        );
+        require(ok && ret.length == 32 && abi.decode(ret, (bool)), "Compliance check failed");
+    }
+
+    /// Mirrors `due_settlement` in `src/automation.rs`; empty if none is due
+    function dueSettlement(uint256 job_id) private view returns (bytes memory) {
+        if (isDisputed(job_id)) {
+            if (jurySitting(job_id) && block.timestamp >= juries[job_id].voteBy) {
+                return abi.encodeCall(this.tallyJury, (job_id));
+            }
+            if (rulingHeld(job_id)) {
+                return rulingFinal(job_id) ? abi.encodeCall(this.finalizeRuling, (job_id)) : bytes("");
+            }
+            uint64 timeoutAt = disputes[job_id].timeoutAt;
+            if (timeoutAt != 0 && block.timestamp >= timeoutAt) {
+                return abi.encodeCall(this.timeOutDispute, (job_id));
+            }
+            return "";
+        }
+        Job storage job = jobs[job_id];
+        if (
+            job.released || job.refunded || finalized[job_id]
+                || fundings[job_id].raised < fundings[job_id].target || isStaked(job_id)
+        ) {
+            return "";
+        }
+
+        uint64 payableAt = clawbacks[job_id].payableAt;
+        if (payableAt != 0) {
+            if (block.timestamp >= payableAt && isCompliant(job.client, job.freelancer, job.amount)) {
+                return abi.encodeCall(this.finalizeRelease, (job_id));
+            }
+            return "";
+        }
+        uint64 warrantyEnds = holdbacks[job_id].warrantyEnds;
+        if (warrantyEnds != 0) {
+            if (block.timestamp >= warrantyEnds && isCompliant(job.client, job.freelancer, job.amount)) {
+                return abi.encodeCall(this.releaseHoldback, (job_id));
+            }
+            return "";
+        }
+
+        if (
+            tranches[job_id].length == 0 && engagements[job_id].pending == 0
+                && block.timestamp >= uint256(job.deadline) + CLAIM_WINDOW
+        ) {
+            return abi.encodeCall(this.reclaim, (job_id, bytes32(0)));
+        }
+        return "";
+    }
+
+    /// Whether `assertCompliant` passes
+    function isCompliant(address client, address freelancer, uint256 amount) private view returns (bool) {
+        if (complianceHook == address(0)) return true;
+        (bool ok, bytes memory ret) = complianceHook.staticcall(
+            abi.encodeWithSignature("check(address,address,uint256)", client, freelancer, amount)
+        );
+        return ok && ret.length == 32 && abi.decode(ret, (bool));
+    }
+
+    /// Mirrors `categorize` in `src/categories.rs`
+    function categorize(uint256 job_id, uint16 category, bytes32 tag, uint256 amount) private {
+        Job storage job = jobs[job_id];
+        job.category = category;
+        job.tag = tag;
+        if (category == 0) return;
+
+        CategoryStats storage s = categoryStats[category];
+        s.jobs += 1;
+        s.deposited += amount;
+    }
+
+    /// Mirrors `in_category` in `src/categories.rs`
+    function inCategory(uint256 job_id, uint16 category, bytes32 tag) private view returns (bool) {
+        return jobs[job_id].category == category && (tag == bytes32(0) || jobs[job_id].tag == tag);
+    }
+
+    /// Mirrors `record_deposit_rate` in `src/deposit_limits.rs`
+    function recordDepositRate(address client) private {
+        if (depositLimitExempt[client]) return;
+        DepositWindow storage window = depositWindows[client];
+        require(
+            depositCooldown == 0 || window.lastAt == 0 || block.timestamp >= window.lastAt + depositCooldown,
+            "Deposit cooldown active"
+        );
+        uint32 inBlock = window.blockNumber == block.number ? window.count : 0;
+        require(depositsPerBlock == 0 || inBlock < depositsPerBlock, "Deposit limit reached");
+
+        window.lastAt = uint64(block.timestamp);
+        window.blockNumber = uint64(block.number);
+        window.count = inBlock + 1;
+    }
+
+    /// Mirrors `assert_attested` in `src/attestation.rs`
+    function assertAttested(address freelancer, bytes32 uid) private view {
+        require(eas != address(0), "Attestations not configured");
+
+        Attestation memory a;
+        try IEAS(eas).getAttestation(uid) returns (Attestation memory found) {
+            a = found;
+        } catch {
+            revert("Invalid attestation");
+        }
+        require(
+            a.uid == uid && a.schema == attestationSchema && a.attester == attester && a.recipient == freelancer,
+            "Invalid attestation"
+        );
+        require(a.revocationTime == 0, "Attestation revoked");
+        require(a.expirationTime == 0 || a.expirationTime > block.timestamp, "Attestation expired");
+    }
+
+    /// Mirrors `job_cap` in `src/caps.rs`
+    function jobCap(address freelancer) private view returns (uint32) {
+        uint32 own = jobCaps[freelancer];
+        if (defaultJobCap == 0) return own;
+        if (own == 0) return defaultJobCap;
+        return own < defaultJobCap ? own : defaultJobCap;
+    }
+
+    /// Mirrors `assert_below_cap` in `src/caps.rs`
+    function assertBelowCap(address freelancer) private view {
+        uint32 cap = jobCap(freelancer);
+        require(cap == 0 || activeJobs[freelancer] < cap, "Job cap reached");
+    }
+
+    /// Mirrors `take_job_slot` in `src/caps.rs`
+    function takeJobSlot(address freelancer) private {
+        activeJobs[freelancer] += 1;
+    }
+
+    /// Mirrors `free_job_slot` in `src/caps.rs`
+    function freeJobSlot(address freelancer) private {
+        if (activeJobs[freelancer] != 0) activeJobs[freelancer] -= 1;
+    }
+
+    /// Mirrors `close_job` in `src/registry.rs`
+    function closeJob(uint256 job_id) private {
+        finalized[job_id] = true;
+        deactivateJob(job_id);
+        creditDisputeStake(job_id);
+        creditAppealBond(job_id);
+
+        // Unrevealed and never-funded jobs were not counted
+        address freelancer = jobs[job_id].freelancer;
+        if (freelancer == address(0) || fundings[job_id].raised < fundings[job_id].target) return;
+        freeJobSlot(freelancer);
+        if (jobs[job_id].released) {
+            FreelancerProfile storage profile = freelancerProfiles[freelancer];
+            profile.jobsCompleted += 1;
+            profile.settlementTime += uint64(block.timestamp) - jobs[job_id].createdAt;
+        }
+    }
+
+    /// Mirrors `dispute_stake_for` in `src/dispute_stakes.rs`
+    function disputeStakeFor(uint256 job_id) private view returns (uint256) {
+        uint256 proportional = jobs[job_id].amount * disputeStakeBps / BASIS_POINTS;
+        return proportional > minDisputeStake ? proportional : minDisputeStake;
+    }
+
+    /// Mirrors `take_dispute_stake` in `src/dispute_stakes.rs`
+    function takeDisputeStake(uint256 job_id) private returns (uint256 stake) {
+        stake = disputes[job_id].stake;
+        if (stake != 0) {
+            disputes[job_id].stake = 0;
+            totalDisputeStakes -= stake;
+        }
+    }
+
+    /// Mirrors `opener_lost` in `src/dispute_stakes.rs`
+    function openerLost(uint256 job_id, uint16 freelancer_bps) private view returns (bool) {
+        address freelancer = jobs[job_id].freelancer;
+        if (freelancer == address(0)) return false;
+        if (disputes[job_id].openedBy == freelancer) return freelancer_bps < BASIS_POINTS / 2;
+        return freelancer_bps > BASIS_POINTS / 2;
+    }
+
+    /// Mirrors `pay_dispute_stake` in `src/dispute_stakes.rs`
+    function payDisputeStake(uint256 job_id, uint256 stake, bool lost) private {
+        if (stake == 0) return;
+        address openedBy = disputes[job_id].openedBy;
+        address recipient = openedBy;
+        if (lost) {
+            recipient = openedBy == jobs[job_id].freelancer ? jobs[job_id].client : jobs[job_id].freelancer;
+        }
+        pay(recipient, stake);
+        emit DisputeStakeSettled(job_id, recipient, stake, lost);
+    }
+
+    /// Mirrors `credit_dispute_stake` in `src/dispute_stakes.rs`
+    function creditDisputeStake(uint256 job_id) private {
+        uint256 stake = takeDisputeStake(job_id);
+        if (stake == 0) return;
+        address recipient = disputes[job_id].openedBy;
+        credit(recipient, stake);
+        emit DisputeStakeSettled(job_id, recipient, stake, false);
+    }
+
+    /// Mirrors `update_jury_policy` in `src/juries.rs`
+    function updateJuryPolicy(uint8 size, uint64 voting_period, uint256 min_stake, uint16 slash_bps) private {
+        require(size <= MAX_JURY_SIZE && (size == 0 || size % 2 == 1), "Invalid jury size");
+        require(size == 0 || voting_period != 0, "Invalid voting period");
+        require(slash_bps <= BASIS_POINTS, "Invalid slash");
+        jurySize = size;
+        juryVotingPeriod = voting_period;
+        minJurorStake = min_stake;
+        jurorSlashBps = slash_bps;
+
+        emit JuryPolicySet(size, voting_period, min_stake, slash_bps);
+    }
+
+    /// Mirrors `stake_juror` in `src/juries.rs`
+    function stakeJuror(address juror, uint256 amount) private {
+        Juror storage record = jurorRecords[juror];
+        uint256 stake = record.stake + amount;
+        require(stake >= minJurorStake, "Stake below minimum");
+
+        record.stake = stake;
+        if (record.index == 0) {
+            jurors.push(juror);
+            record.index = uint32(jurors.length);
+        }
+        totalJurorStakes += amount;
+
+        emit JurorStaked(juror, amount, stake);
+    }
+
+    /// Mirrors `unstake_juror` in `src/juries.rs`
+    function unstakeJuror(address juror) private {
+        Juror storage record = jurorRecords[juror];
+        uint256 stake = record.stake;
+        require(record.index != 0, "Not a juror");
+        require(record.panels == 0, "Juror serving");
+
+        // Move the last juror into the leaver's slot
+        uint256 index = record.index;
+        uint256 last = jurors.length;
+        if (index != last) {
+            address moved = jurors[last - 1];
+            jurors[index - 1] = moved;
+            jurorRecords[moved].index = uint32(index);
+        }
+        jurors.pop();
+        record.index = 0;
+        record.stake = 0;
+        totalJurorStakes -= stake;
+
+        pay(juror, stake);
+
+        emit JurorLeft(juror, stake);
+    }
+
+    /// Mirrors `draw_jury` in `src/juries.rs`
+    function drawJury(uint256 job_id) private view returns (address[] memory panel) {
+        uint256 pool = jurors.length;
+        require(pool != 0, "Not enough jurors");
+        address client = jobs[job_id].client;
+        address freelancer = jobs[job_id].freelancer;
+        address arbitrator_ = disputes[job_id].arbitrator;
+        bytes32 seed = keccak256(abi.encode(job_id, block.number, block.timestamp));
+
+        panel = new address[](jurySize);
+        for (uint256 seat = 0; seat < jurySize; seat++) {
+            uint256 start = uint256(keccak256(abi.encode(seed, seat))) % pool;
+            bool found = false;
+            for (uint256 k = 0; k < pool && !found; k++) {
+                address juror = jurors[(start + k) % pool];
+                if (
+                    juror == client || juror == freelancer || juror == arbitrator_
+                        || jurorRecords[juror].stake < minJurorStake || onPanel(panel, seat, juror)
+                ) {
+                    continue;
+                }
+                panel[seat] = juror;
+                found = true;
+            }
+            require(found, "Not enough jurors");
+        }
+    }
+
+    /// Whether `juror` is among the first `seats` of `panel`
+    function onPanel(address[] memory panel, uint256 seats, address juror) private pure returns (bool) {
+        for (uint256 i = 0; i < seats; i++) {
+            if (panel[i] == juror) return true;
+        }
+        return false;
+    }
+
+    /// Mirrors `seat_jury` in `src/juries.rs`
+    function seatJury(uint256 job_id, address[] memory panel) private {
+        uint64 voteBy = uint64(block.timestamp) + juryVotingPeriod;
+        Jury storage jury = juries[job_id];
+        jury.voteBy = voteBy;
+        for (uint256 i = 0; i < panel.length; i++) {
+            jury.panel.push(panel[i]);
+            jury.votes[panel[i]].seated = true;
+            jurorRecords[panel[i]].panels += 1;
+        }
+
+        emit JuryDrawn(job_id, panel, voteBy);
+    }
+
+    /// Mirrors `jury_sitting` in `src/juries.rs`
+    function jurySitting(uint256 job_id) private view returns (bool) {
+        return juries[job_id].voteBy != 0 && !juries[job_id].tallied;
+    }
+
+    /// Mirrors `assert_no_jury` in `src/juries.rs`
+    function assertNoJury(uint256 job_id) private view {
+        require(!jurySitting(job_id), "Dispute before jury");
+    }
+
+    /// Mirrors `record_jury_vote` in `src/juries.rs`
+    function recordJuryVote(uint256 job_id, address juror, uint16 freelancer_bps) private {
+        Jury storage jury = juries[job_id];
+        JuryVote storage vote = jury.votes[juror];
+        vote.voted = true;
+        vote.freelancerBps = freelancer_bps;
+        jury.votesCast += 1;
+
+        emit JuryVoted(job_id, juror, freelancer_bps);
+    }
+
+    /// Mirrors `jury_verdict` in `src/juries.rs`
+    function juryVerdict(uint256 job_id) private view returns (bool decided, uint16 verdict) {
+        Jury storage jury = juries[job_id];
+        uint256 cast = jury.votesCast;
+        for (uint256 i = 0; i < jury.panel.length; i++) {
+            JuryVote storage vote = jury.votes[jury.panel[i]];
+            if (!vote.voted) continue;
+            uint256 agreeing = 0;
+            for (uint256 j = 0; j < jury.panel.length; j++) {
+                JuryVote storage other = jury.votes[jury.panel[j]];
+                if (other.voted && other.freelancerBps == vote.freelancerBps) agreeing++;
+            }
+            if (agreeing * 2 > cast) return (true, vote.freelancerBps);
+        }
+        return (false, 0);
+    }
+
+    /// Mirrors `dismiss_jury` in `src/juries.rs`
+    function dismissJury(uint256 job_id, bool decided, uint16 verdict) private returns (uint256 slashed) {
+        Jury storage jury = juries[job_id];
+        address[] memory majority = new address[](jury.panel.length);
+        uint256 winners = 0;
+        for (uint256 i = 0; i < jury.panel.length; i++) {
+            address juror = jury.panel[i];
+            JuryVote storage vote = jury.votes[juror];
+            Juror storage record = jurorRecords[juror];
+            record.panels -= 1;
+            if (!decided) continue;
+            if (vote.voted && vote.freelancerBps == verdict) {
+                majority[winners++] = juror;
+            } else {
+                uint256 penalty = record.stake * jurorSlashBps / BASIS_POINTS;
+                record.stake -= penalty;
+                slashed += penalty;
+            }
+        }
+
+        // A verdict has at least one voter; rounding goes to the first
+        if (winners != 0) {
+            uint256 share = slashed / winners;
+            jurorRecords[majority[0]].stake += slashed - share * winners;
+            for (uint256 i = 0; i < winners; i++) {
+                jurorRecords[majority[i]].stake += share;
+            }
+        }
+        jury.tallied = true;
+    }
+
+    /// Mirrors `update_appeal_policy` in `src/appeals.rs`
+    function updateAppealPolicy(uint64 window, uint16 bond_bps, uint256 min_bond) private {
+        appealWindow = window;
+        appealBondBps = bond_bps;
+        minAppealBond = min_bond;
+
+        emit AppealPolicySet(window, bond_bps, min_bond);
+    }
+
+    /// Mirrors `appeals_enabled` in `src/appeals.rs`
+    function appealsEnabled() private view returns (bool) {
+        return appealWindow != 0 && isGoverned();
+    }
+
+    /// Mirrors `appeal_bond_for` in `src/appeals.rs`
+    function appealBondFor(uint256 job_id) private view returns (uint256) {
+        uint256 proportional = jobs[job_id].amount * appealBondBps / BASIS_POINTS;
+        return proportional > minAppealBond ? proportional : minAppealBond;
+    }
+
+    /// Mirrors `settle_ruling` in `src/appeals.rs`
+    function settleRuling(address resolver, uint256 job_id, uint16 freelancer_bps) private {
+        if (!appealsEnabled()) {
+            settleDispute(resolver, job_id, freelancer_bps, false);
+            return;
+        }
+
+        uint64 appealableUntil = uint64(block.timestamp) + appealWindow;
+        Appeal storage appeal = appeals[job_id];
+        appeal.resolver = resolver;
+        appeal.freelancerBps = freelancer_bps;
+        appeal.appealableUntil = appealableUntil;
+
+        emit RulingHeld(job_id, resolver, freelancer_bps, appealableUntil);
+    }
+
+    /// Mirrors `ruling_held` in `src/appeals.rs`
+    function rulingHeld(uint256 job_id) private view returns (bool) {
+        return appeals[job_id].appealableUntil != 0 && isDisputed(job_id);
+    }
+
+    /// Mirrors `assert_no_held_ruling` in `src/appeals.rs`
+    function assertNoHeldRuling(uint256 job_id) private view {
+        require(!rulingHeld(job_id), "Ruling pending");
+    }
+
+    /// Mirrors `lodge_appeal` in `src/appeals.rs`
+    function lodgeAppeal(address appellant, uint256 job_id, uint16 freelancer_bps, uint256 bond)
+        private
+        returns (uint256 proposalId)
+    {
+        proposalId = openGovernanceProposal(appellant, GOVERNANCE_ACTION_APPEAL, address(0), job_id, "");
+        Appeal storage appeal = appeals[job_id];
+        appeal.appellant = appellant;
+        appeal.appealBps = freelancer_bps;
+        appeal.bond = bond;
+        appeal.proposalId = proposalId;
+        totalAppealBonds += bond;
+
+        emit RulingAppealed(job_id, appellant, freelancer_bps, bond, proposalId);
+    }
+
+    /// Mirrors `ruling_final` in `src/appeals.rs`
+    function rulingFinal(uint256 job_id) private view returns (bool) {
+        Appeal storage appeal = appeals[job_id];
+        if (appeal.appellant == address(0)) {
+            return block.timestamp >= appeal.appealableUntil;
+        }
+        return proposalLapsed(appeal.proposalId);
+    }
+
+    /// Mirrors `finalize_held_ruling` in `src/appeals.rs`
+    function finalizeHeldRuling(uint256 job_id) private {
+        Appeal storage appeal = appeals[job_id];
+        uint256 bond = takeAppealBond(job_id);
+
+        settleDispute(appeal.resolver, job_id, appeal.freelancerBps, false);
+        payAppealBond(job_id, bond, true);
+    }
+
+    /// Mirrors `uphold_appeal` in `src/appeals.rs`
+    function upholdAppeal(uint256 job_id) private {
+        require(rulingHeld(job_id) && appeals[job_id].appellant != address(0), "No pending appeal");
+        uint256 bond = takeAppealBond(job_id);
+
+        settleDispute(msg.sender, job_id, appeals[job_id].appealBps, false);
+        payAppealBond(job_id, bond, false);
+    }
+
+    /// Mirrors `take_appeal_bond` in `src/appeals.rs`
+    function takeAppealBond(uint256 job_id) private returns (uint256 bond) {
+        bond = appeals[job_id].bond;
+        if (bond != 0) {
+            appeals[job_id].bond = 0;
+            totalAppealBonds -= bond;
+        }
+    }
+
+    /// Mirrors `pay_appeal_bond` in `src/appeals.rs`
+    function payAppealBond(uint256 job_id, uint256 bond, bool lost) private {
+        if (bond == 0) return;
+        address appellant = appeals[job_id].appellant;
+        address other = appellant == jobs[job_id].freelancer ? jobs[job_id].client : jobs[job_id].freelancer;
+        // An unrevealed job has nobody to forfeit the bond to
+        address recipient = lost && other != address(0) ? other : appellant;
+        pay(recipient, bond);
+        emit AppealBondSettled(job_id, recipient, bond, recipient != appellant);
+    }
+
+    /// Mirrors `credit_appeal_bond` in `src/appeals.rs`
+    function creditAppealBond(uint256 job_id) private {
+        uint256 bond = takeAppealBond(job_id);
+        if (bond == 0) return;
+        address recipient = appeals[job_id].appellant;
+        credit(recipient, bond);
+        emit AppealBondSettled(job_id, recipient, bond, false);
+    }
+
+    /// Mirrors `credit` in `src/credits.rs`
+    function credit(address account, uint256 amount) private {
+        credits[account] += amount;
+        totalCredit += amount;
+    }
+
+    /// Mirrors `assert_nonce_unused` in `src/offers.rs`
+    function assertNonceUnused(address client, uint256 nonce) private view {
+        require(nonceJobs[client][nonce] == 0 && !cancelledOffers[client][nonce], "Nonce already used");
+    }
+
+    /// Mirrors `offer_hash` in `crates/escrow-types/src/messages.rs`
+    function offerHash(
+        address client,
+        address freelancer,
+        uint256 amount,
+        uint64 duration,
+        bytes32 memo,
+        uint256 nonce,
+        uint64 expiry
+    ) private view returns (bytes32) {
+        bytes32 domainSeparator = keccak256(
+            abi.encode(
+                EIP712_DOMAIN_TYPEHASH,
+                keccak256("FreelancePaymentEscrow"),
+                keccak256("1"),
+                block.chainid,
+                address(this)
+            )
+        );
+        bytes32 structHash =
+            keccak256(abi.encode(JOB_OFFER_TYPEHASH, client, freelancer, amount, duration, memo, nonce, expiry));
+        return keccak256(abi.encodePacked("\x19\x01", domainSeparator, structHash));
+    }
+
+    /// Mirrors `settlement_hash` in `crates/escrow-types/src/messages.rs`
+    function settlementHash(
+        uint256 job_id,
+        address signer,
+        bool release,
+        bytes32 memo,
+        uint256 nonce,
+        uint64 expiry
+    ) private view returns (bytes32) {
+        bytes32 domainSeparator = keccak256(
+            abi.encode(
+                EIP712_DOMAIN_TYPEHASH,
+                keccak256("FreelancePaymentEscrow"),
+                keccak256("1"),
+                block.chainid,
+                address(this)
+            )
+        );
+        bytes32 structHash =
+            keccak256(abi.encode(SETTLEMENT_TYPEHASH, job_id, signer, release, memo, nonce, expiry));
+        return keccak256(abi.encodePacked("\x19\x01", domainSeparator, structHash));
+    }
+
+    /// Mirrors `verdict_hash` in `crates/escrow-types/src/messages.rs`
+    function verdictHash(uint256 job_id, uint16 freelancer_bps, uint256 nonce, uint64 expiry)
+        private
+        view
+        returns (bytes32)
+    {
+        bytes32 domainSeparator = keccak256(
+            abi.encode(
+                EIP712_DOMAIN_TYPEHASH,
+                keccak256("FreelancePaymentEscrow"),
+                keccak256("1"),
+                block.chainid,
+                address(this)
+            )
+        );
+        bytes32 structHash = keccak256(abi.encode(VERDICT_TYPEHASH, job_id, freelancer_bps, nonce, expiry));
+        return keccak256(abi.encodePacked("\x19\x01", domainSeparator, structHash));
+    }
+
+    /// Mirrors `is_signed_by` in `src/authorizations.rs`, recovering EOA
+    /// signatures as `offer_signer` in `src/offers.rs` does
+    function isSignedBy(address signer, bytes32 digest, bytes calldata signature) private view returns (bool) {
+        if (signer.code.length == 0) {
+            if (signature.length != 65) return false;
+            bytes32 r = bytes32(signature[0:32]);
+            bytes32 s = bytes32(signature[32:64]);
+            uint8 v = uint8(signature[64]);
+            if (v < 27) v += 27;
+            if ((v != 27 && v != 28) || uint256(s) > SECP256K1N_HALF) return false;
+            address recovered = ecrecover(digest, v, r, s);
+            return recovered != address(0) && recovered == signer;
+        }
+        (bool ok, bytes memory ret) =
+            signer.staticcall(abi.encodeCall(IERC1271.isValidSignature, (digest, signature)));
+        return ok && ret.length == 32 && abi.decode(ret, (bytes4)) == ERC1271_MAGIC_VALUE;
+    }
+
+    /// Mirrors `spend_authorization` in `src/authorizations.rs`
+    function spendAuthorization(
+        uint256 job_id,
+        address signer,
+        bool release,
+        bytes32 memo,
+        uint256 nonce,
+        uint64 expiry,
+        bytes calldata signature
+    ) private {
+        require(block.timestamp < expiry, "Authorization expired");
+        spendSigned(signer, nonce, settlementHash(job_id, signer, release, memo, nonce, expiry), signature);
+    }
+
+    /// Mirrors `spend_verdict` in `src/authorizations.rs`
+    function spendVerdict(
+        address arbitrator,
+        uint256 job_id,
+        uint16 freelancer_bps,
+        uint256 nonce,
+        uint64 expiry,
+        bytes calldata signature
+    ) private {
+        require(block.timestamp < expiry, "Verdict expired");
+        spendSigned(arbitrator, nonce, verdictHash(job_id, freelancer_bps, nonce, expiry), signature);
+    }
+
+    /// Mirrors `spend_signed` in `src/authorizations.rs`
+    function spendSigned(address signer, uint256 nonce, bytes32 digest, bytes calldata signature) private {
+        assertAuthorizationUnused(signer, nonce);
+        require(signer != address(0) && isSignedBy(signer, digest, signature), "Invalid signature");
+
+        usedAuthorizations[signer][nonce] = true;
+    }
+
+    /// Mirrors `assert_authorization_unused` in `src/authorizations.rs`
+    function assertAuthorizationUnused(address signer, uint256 nonce) private view {
+        require(!usedAuthorizations[signer][nonce], "Nonce already used");
+    }
+
+    /// Mirrors `assert_funded` in `src/funding.rs`
+    function assertFunded(uint256 job_id) private view {
+        require(fundings[job_id].raised >= fundings[job_id].target, "Job not funded");
+    }
+
+    /// Mirrors `add_contribution` in `src/funding.rs`
+    function addContribution(uint256 job_id, address contributor, uint256 amount) private {
+        contributions[job_id][contributor] += amount;
+        Funding storage funding = fundings[job_id];
+        funding.raised += amount;
+        Job storage job = jobs[job_id];
+        job.amount += amount;
+        lockEscrow(job_id, amount);
+        totalDeposited += amount;
+        userStats[contributor].totalDeposited += amount;
+        recordPeriodDeposit(false, amount);
+
+        emit JobFunded(job_id, contributor, amount, funding.raised);
+
+        if (funding.raised == funding.target) {
+            job.deadline = uint64(block.timestamp) + funding.duration;
+            recordAssignment(job.freelancer);
+            emit Deposited(job_id, job.client, job.freelancer, funding.target, bytes32(0), job.category, job.tag);
+        }
+    }
+
+    /// Mirrors `pay_tranche` in `src/tranches.rs`
+    function payTranche(uint256 job_id, uint256 index) private {
+        Job storage job = jobs[job_id];
+        uint256 amount = tranches[job_id][index].amount;
+        assertCompliant(job.client, job.freelancer, amount);
+
+        uint256 remaining = settleTranche(job_id, index, true);
+        recordRelease(job_id, job.freelancer, amount);
+
+        payRelease(job_id, job.freelancer, amount);
+
+        emit TrancheClaimed(job_id, index, amount, remaining);
+    }
+
+    /// Mirrors `return_tranche` in `src/tranches.rs`
+    function returnTranche(uint256 job_id, uint256 index) private {
+        Job storage job = jobs[job_id];
+        uint256 amount = tranches[job_id][index].amount;
+
+        uint256 remaining = settleTranche(job_id, index, false);
+        recordRefund(job_id, job.client, amount);
+
+        pay(job.client, amount);
+
+        emit TrancheRefunded(job_id, index, amount, remaining);
+    }
+
+    /// Mirrors `settle_tranche` in `src/tranches.rs`
+    function settleTranche(uint256 job_id, uint256 index, bool claimed) private returns (uint256) {
+        Tranche storage tranche = tranches[job_id][index];
+        tranche.settled = true;
+
+        Job storage job = jobs[job_id];
+        uint256 remaining = job.amount - tranche.amount;
+        job.amount = remaining;
+        if (remaining == 0) {
+            if (claimed) {
+                job.released = true;
+            } else {
+                job.refunded = true;
+            }
+            closeJob(job_id);
+        }
+        return remaining;
+    }
+
+    /// Mirrors `assert_releasable` in `src/conditions.rs`: fails closed
+    function assertReleasable(uint256 job_id) private view {
+        address condition = releaseConditions[job_id];
+        if (condition == address(0)) return;
+        (bool ok, bytes memory ret) =
+            condition.staticcall(abi.encodeWithSignature("canRelease(uint256)", job_id));
+        require(ok && ret.length == 32 && abi.decode(ret, (bool)), "Release condition not met");
+    }
+
+    /// Mirrors `call_settlement_hook` in `src/hooks.rs`: failures are logged, not bubbled up
+    function callSettlementHook(uint256 job_id, uint256 amount, bool released) private {
+        address hook = settlementHooks[job_id];
+        if (hook == address(0)) return;
+
+        bytes memory data = released
+            ? abi.encodeWithSignature("onReleased(uint256,uint256)", job_id, amount)
+            : abi.encodeWithSignature("onRefunded(uint256,uint256)", job_id, amount);
+        (bool ok,) = hook.call{gas: SETTLEMENT_HOOK_GAS}(data);
+        if (!ok) {
+            emit SettlementHookFailed(job_id, hook);
+        }
+    }
+
+    /// Mirrors `pay_freelancer` in `src/swap.rs`
+    function payFreelancer(uint256 job_id, address freelancer, uint256 amount) private {
+        address recipient = payoutRecipient(freelancer);
+        address token = payoutTokens[freelancer].token;
+        if (token != address(0) && token == weth) {
+            payWrapped(job_id, recipient, amount);
+            return;
+        }
+        if (swapRouter == address(0) || token == address(0)) {
+            pushPayout(job_id, recipient, amount);
+            return;
+        }
+
+        address[] memory path = new address[](2);
+        path[0] = weth;
+        path[1] = token;
+        uint256 amountOutMin = amount * payoutTokens[freelancer].minRate / 1 ether;
+        (bool ok, bytes memory data) = swapRouter.call{value: amount}(
+            abi.encodeWithSignature(
+                "swapExactETHForTokens(uint256,address[],address,uint256)",
+                amountOutMin,
+                path,
+                recipient,
+                block.timestamp
+            )
+        );
+        if (ok) {
+            uint256[] memory amounts = abi.decode(data, (uint256[]));
+            uint256 amountOut = amounts.length == 0 ? 0 : amounts[amounts.length - 1];
+            emit PayoutSwapped(job_id, token, amount, amountOut);
+        } else {
+            pushPayout(job_id, recipient, amount);
+            emit PayoutSwapFailed(job_id, token);
+        }
+    }
+
+    /// Mirrors `pay_wrapped` in `src/swap.rs`
+    function payWrapped(uint256 job_id, address recipient, uint256 amount) private {
+        (bool ok,) = weth.call{value: amount}(abi.encodeWithSignature("depositTo(address)", recipient));
+        if (!ok) {
+            pushPayout(job_id, recipient, amount);
+            emit PayoutSwapFailed(job_id, weth);
+            return;
+        }
+        emit PayoutSwapped(job_id, weth, amount, amount);
+    }
+
+    /// Mirrors `is_staked` in `src/staking.rs`
+    function isStaked(uint256 job_id) private view returns (bool) {
+        return jobStakes[job_id].principal != 0;
+    }
+
+    /// Mirrors `assert_not_staked` in `src/staking.rs`
+    function assertNotStaked(uint256 job_id) private view {
+        require(!isStaked(job_id), "Job is staked");
+    }
+
+    /// Mirrors `consent_to_stake_as` in `src/staking.rs`
+    function consentToStakeAs(uint256 job_id, address party, uint16 freelancerYieldBps, uint16 treasuryYieldBps)
+        private
+    {
+        require(stakingToken != address(0), "Staking not enabled");
+        assertSettleable(job_id);
+        Job storage job = jobs[job_id];
+        require(job.freelancer != address(0), "Job not revealed");
+        address other;
+        if (party == job.client) {
+            other = job.freelancer;
+        } else if (party == job.freelancer) {
+            other = job.client;
+        } else {
+            revert("Only job parties can approve");
+        }
+        assertValidYieldSplit(freelancerYieldBps, treasuryYieldBps);
+        assertNotStaked(job_id);
+        assertNotDisputed(job_id);
+        require(clawbacks[job_id].payableAt == 0, "Release queued");
+        require(engagements[job_id].rate == 0, "Job is hourly");
+        require(baskets[job_id].length == 0, "Job has basket");
+        require(tranches[job_id].length == 0, "Job is tranched");
+        require(job.deadline >= block.timestamp + MIN_STAKE_DURATION, "Stake duration too short");
+
+        JobStake storage stake = jobStakes[job_id];
+        bool agreed = stake.consentedBy == other && stake.freelancerYieldBps == freelancerYieldBps
+            && stake.treasuryYieldBps == treasuryYieldBps;
+        stake.freelancerYieldBps = freelancerYieldBps;
+        stake.treasuryYieldBps = treasuryYieldBps;
+        stake.consentedBy = party;
+
+        emit StakeConsented(job_id, party, freelancerYieldBps, treasuryYieldBps);
+
+        if (agreed) {
+            stakeJob(job_id);
+        }
+    }
+
+    /// Mirrors `stake_job` in `src/staking.rs`
+    function stakeJob(uint256 job_id) private {
+        address token = stakingToken;
+        uint256 amount = jobs[job_id].amount;
+        (bool ok, bytes memory data) = token.call{value: amount}(abi.encodeCall(IStakingToken.submit, (address(0))));
+        require(ok && data.length == 32, "Stake failed");
+        uint256 shares = abi.decode(data, (uint256));
+        require(shares != 0, "Stake failed");
+
+        JobStake storage stake = jobStakes[job_id];
+        stake.token = token;
+        stake.maxSlippageBps = maxUnstakeSlippageBps;
+        stake.principal = amount;
+        stake.shares = shares;
+        stake.consentedBy = address(0);
+        totalStaked += amount;
+
+        emit YieldSplitSet(
+            job_id,
+            BASIS_POINTS - stake.freelancerYieldBps - stake.treasuryYieldBps,
+            stake.freelancerYieldBps,
+            stake.treasuryYieldBps
+        );
+
+        emit JobStaked(job_id, token, amount, shares);
+    }
+
+    /// Mirrors `stake_value` in `src/staking.rs`
+    function stakeValue(address token, uint256 shares) private view returns (uint256) {
+        (bool ok, bytes memory data) =
+            token.staticcall(abi.encodeCall(IStakingToken.getPooledEthByShares, (shares)));
+        require(ok && data.length == 32, "Stake failed");
+        return abi.decode(data, (uint256));
+    }
+
+    /// Mirrors `unwind_stake` in `src/staking.rs`
+    function unwindStake(uint256 job_id, address buyer, uint256 paid) private {
+        require(isStaked(job_id), "Job not staked");
+        Job storage job = jobs[job_id];
+        bool isParty = buyer == job.client || buyer == job.freelancer;
+        require(
+            isParty || job.deadline < UNSTAKE_WINDOW || block.timestamp >= job.deadline - UNSTAKE_WINDOW,
+            "Unstake not open"
+        );
+
+        JobStake storage stake = jobStakes[job_id];
+        address token = stake.token;
+        uint256 principal = stake.principal;
+        uint256 shares = stake.shares;
+        uint256 value = stakeValue(token, shares);
+        uint256 floor = value * (BASIS_POINTS - stake.maxSlippageBps) / BASIS_POINTS;
+        uint256 covered = value < principal ? value : principal;
+        if (floor < covered) floor = covered;
+        require(paid >= floor, "Unstake price too low");
+
+        (bool ok,) = token.call(abi.encodeCall(IStakingToken.transferShares, (buyer, shares)));
+        require(ok, "Stake failed");
+
+        stake.token = address(0);
+        stake.maxSlippageBps = 0;
+        stake.principal = 0;
+        stake.shares = 0;
+        totalStaked -= principal;
+
+        uint256 clientYield = 0;
+        uint256 freelancerYield = 0;
+        uint256 treasuryYield = 0;
+        if (paid >= principal) {
+            (clientYield, freelancerYield, treasuryYield) = splitYield(job_id, paid - principal);
+        } else {
+            uint256 loss = principal - paid;
+            unlockEscrow(job_id, loss);
+            job.amount -= loss;
+            totalStakingLosses += loss;
+        }
+        if (clientYield != 0) credit(job.client, clientYield);
+        if (freelancerYield != 0) credit(job.freelancer, freelancerYield);
+        accruedFees += treasuryYield;
+
+        emit JobUnstaked(job_id, buyer, principal, paid, clientYield, freelancerYield, treasuryYield);
+    }
+
+    /// Mirrors `assert_valid_yield_split` in `src/yield_splits.rs`
+    function assertValidYieldSplit(uint16 freelancerYieldBps, uint16 treasuryYieldBps) private pure {
+        require(uint256(freelancerYieldBps) + treasuryYieldBps <= BASIS_POINTS, "Invalid yield split");
+    }
+
+    /// Mirrors `split_yield` in `src/yield_splits.rs`
+    function splitYield(uint256 job_id, uint256 earned) private view returns (uint256, uint256, uint256) {
+        JobStake storage stake = jobStakes[job_id];
+        uint256 freelancerYield = feeOn(earned, stake.freelancerYieldBps);
+        uint256 treasuryYield = feeOn(earned, stake.treasuryYieldBps);
+        return (earned - freelancerYield - treasuryYield, freelancerYield, treasuryYield);
+    }
+
+    /// Mirrors `push_payout` in `src/payouts.rs`
+    function pushPayout(uint256 job_id, address recipient, uint256 amount) private {
+        if (recipient.code.length == 0) {
+            pay(recipient, amount);
+            return;
+        }
+
+        (bool pushed,) = recipient.call{value: amount, gas: PAYOUT_CALL_GAS}("");
+        if (!pushed) credit(recipient, amount);
+
+        emit PayoutPushed(job_id, recipient, amount, pushed);
+    }
+
+    /// Mirrors `release_approved` in `src/approvals.rs`
+    function releaseApproved(uint256 job_id) private {
+        Job storage job = jobs[job_id];
+        job.released = true;
+        closeJob(job_id);
+        recordRelease(job_id, job.freelancer, job.amount);
+
+        payRelease(job_id, job.freelancer, job.amount);
+        payBasket(job_id, BASIS_POINTS);
+
+        emit Released(job_id, job.amount, bytes32(0));
+        callSettlementHook(job_id, job.amount, true);
+    }
+
+    /// Mirrors `transfer_eth`, which forwards all gas and bubbles up failures
+    function pay(address to, uint256 amount) private {
+        (bool ok, bytes memory data) = to.call{value: amount}("");
+        if (!ok) {
+            assembly {
+                revert(add(data, 32), mload(data))
+            }
+        }
+    }
+
+    /// Mirrors `job_arbitrator` in `src/arbitrators.rs`
+    function jobArbitrator(uint256 job_id) private view returns (address) {
+        address arbitrator_ = jobs[job_id].arbitrator;
+        return arbitrator_ == address(0) ? arbitrator : arbitrator_;
+    }
+
+    /// Mirrors `list_arbitrator` in `src/arbitrators.rs`
+    function listArbitrator(address arbitrator_, bool listed) private {
+        require(arbitrator_ != address(0), "Invalid arbitrator");
+        if (listed && !isListedArbitrator[arbitrator_]) {
+            require(listedArbitrators.length < MAX_LISTED_ARBITRATORS, "Too many arbitrators");
+            listedArbitrators.push(arbitrator_);
+        } else if (!listed && isListedArbitrator[arbitrator_]) {
+            // Move the last listed arbitrator into the unlisted one's slot
+            uint256 last = listedArbitrators.length - 1;
+            uint256 index = last;
+            for (uint256 i = 0; i < last; i++) {
+                if (listedArbitrators[i] == arbitrator_) {
+                    index = i;
+                    break;
+                }
+            }
+            listedArbitrators[index] = listedArbitrators[last];
+            listedArbitrators.pop();
+        }
+        isListedArbitrator[arbitrator_] = listed;
+
+        emit ArbitratorListed(arbitrator_, listed);
+    }
+
+    /// Mirrors `assert_arbitrator_replaceable` in `src/arbitrators.rs`
+    function assertArbitratorReplaceable(uint256 job_id, address arbitrator_) private view {
+        require(isDisputed(job_id), "No open dispute");
+        Dispute storage dispute = disputes[job_id];
+        require(dispute.arbitrator != address(0), "No arbitrator assigned");
+        assertNoJury(job_id);
+        assertNoHeldRuling(job_id);
+        require(block.timestamp >= dispute.ruleBy, "Ruling period not over");
+        require(arbitrator_ != address(0) && arbitrator_ != dispute.arbitrator, "Invalid arbitrator");
+        require(arbitrator_ != jobs[job_id].client && arbitrator_ != jobs[job_id].freelancer, "Arbitrator is a party");
+    }
+
+    /// Mirrors `replace_arbitrator` in `src/arbitrators.rs`
+    function replaceArbitrator(uint256 job_id, address arbitrator_) private {
+        assertArbitratorReplaceable(job_id, arbitrator_);
+
+        Dispute storage dispute = disputes[job_id];
+        address oldArbitrator = dispute.arbitrator;
+        uint64 ruleBy = uint64(block.timestamp) + rulingPeriod;
+        dispute.arbitrator = arbitrator_;
+        dispute.ruleBy = ruleBy;
+        jobs[job_id].arbitrator = arbitrator_;
+
+        emit ArbitratorReplaced(job_id, oldArbitrator, arbitrator_, ruleBy);
+    }
+}
@@ -0,0 +1,33 @@
+//! Amendment log.
+//!
+//! Every change to a live job's terms after it is opened, whoever makes it,
+//! is logged as one `JobAmended` event naming the field, its old and new
+//! values and the caller. Values are ABI words: amounts and times as
+//! integers, addresses left-padded, so auditors can rebuild a job's full
+//! history from its events alone.
+
+use crate::{Escrow, JobAmended};
+use alloy_primitives::{B256, U256};
+use stylus_sdk::prelude::*;
+
+impl Escrow {
+    /// Log that `field` of `job_id` changed from `old_value` to `new_value`
+    pub(crate) fn record_amendment(
+        &self,
+        job_id: U256,
+        field: u8,
+        old_value: B256,
+        new_value: B256,
+    ) {
+        log(
+            self.vm(),
+            JobAmended {
+                job_id,
+                field,
+                old_value,
+                new_value,
+                actor: self.vm().msg_sender(),
+            },
+        );
+    }
+}
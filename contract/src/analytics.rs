@@ -0,0 +1,45 @@
+//! Time-bucketed activity counters.
+//!
+//! Alongside the lifetime totals, every deposit, release and refund is also
+//! added to the stats of the epoch it happened in, `EPOCH_LENGTH` seconds
+//! wide and numbered from the Unix epoch. Dashboards can chart activity by
+//! reading one slot per epoch instead of replaying the event log.
+
+use crate::{Escrow, EPOCH_LENGTH};
+use alloy_primitives::{Uint, U256};
+use stylus_sdk::prelude::*;
+
+impl Escrow {
+    /// Epoch the current block falls in
+    pub(crate) fn current_epoch(&self) -> Uint<64, 1> {
+        Uint::<64, 1>::from(self.vm().block_timestamp() / EPOCH_LENGTH)
+    }
+
+    /// Count a deposit of `amount`, opening a new job if `new_job`
+    pub(crate) fn record_period_deposit(&mut self, new_job: bool, amount: U256) {
+        let epoch = self.current_epoch();
+        let mut stats = self.period_stats.setter(epoch);
+        if new_job {
+            let jobs = stats.jobs_created.get();
+            stats.jobs_created.set(jobs + U256::from(1));
+        }
+        let deposited = stats.deposited.get();
+        stats.deposited.set(deposited + amount);
+    }
+
+    /// Count `amount` paid out to a freelancer
+    pub(crate) fn record_period_release(&mut self, amount: U256) {
+        let epoch = self.current_epoch();
+        let mut stats = self.period_stats.setter(epoch);
+        let released = stats.released.get();
+        stats.released.set(released + amount);
+    }
+
+    /// Count `amount` returned to a client or contributor
+    pub(crate) fn record_period_refund(&mut self, amount: U256) {
+        let epoch = self.current_epoch();
+        let mut stats = self.period_stats.setter(epoch);
+        let refunded = stats.refunded.get();
+        stats.refunded.set(refunded + amount);
+    }
+}
@@ -0,0 +1,253 @@
+//! Appeals to governance.
+//!
+//! With an appeal window set and governance configured, a ruling no longer
+//! settles its job at once: the arbitrator's ruling, or a jury's verdict, is
+//! held for `appeal_window` seconds first. Within it either party can appeal
+//! by posting the appeal bond, `appeal_bond_bps` of the job and at least
+//! `min_appeal_bond`, meant to be larger than the dispute stake so only a
+//! party confident of the outcome goes further. The appeal names the split
+//! the appellant wants and becomes a governance proposal to settle the job
+//! at it.
+//!
+//! If governance passes and executes the proposal, the job settles at the
+//! appellant's split and the bond goes back to them. If it lapses, voted
+//! down, short of the quorum or never executed in time, anyone finalizes
+//! the held ruling and the bond goes to the other party. An unappealed
+//! ruling is finalized the same way once the window closes. While a ruling
+//! is held, the dispute can't be ruled on again, settled at its default
+//! split, put to a jury or timed out; a job settled some other way credits
+//! the bond back to the appellant.
+
+use crate::{
+    AppealBondSettled, AppealPolicySet, ErrorCode, Escrow, RulingAppealed, RulingHeld,
+    BASIS_POINTS, GOVERNANCE_ACTION_APPEAL,
+};
+use alloy_primitives::{Address, Uint, U256};
+use stylus_sdk::prelude::*;
+
+impl Escrow {
+    /// Hold rulings for `window` seconds of appeal, taking appeal bonds of
+    /// `bond_bps` of the job, at least `min_bond`
+    pub(crate) fn update_appeal_policy(&mut self, window: u64, bond_bps: u16, min_bond: U256) {
+        self.appeal_window.set(Uint::<64, 1>::from(window));
+        self.appeal_bond_bps.set(Uint::<16, 1>::from(bond_bps));
+        self.min_appeal_bond.set(min_bond);
+
+        log(
+            self.vm(),
+            AppealPolicySet {
+                window,
+                bond_bps,
+                min_bond,
+            },
+        );
+    }
+
+    /// Whether new rulings are held for appeal
+    pub(crate) fn appeals_enabled(&self) -> bool {
+        !self.appeal_window.get().is_zero() && self.is_governed()
+    }
+
+    /// Bond appealing a ruling on `job_id` takes under the current policy
+    pub(crate) fn appeal_bond_for(&self, job_id: U256) -> U256 {
+        let bond_bps: u16 = self.appeal_bond_bps.get().to();
+        let amount = self.jobs.get(job_id).amount.get();
+        let proportional = amount * U256::from(bond_bps) / U256::from(BASIS_POINTS);
+        proportional.max(self.min_appeal_bond.get())
+    }
+
+    /// Settle `job_id`'s dispute on `resolver`'s ruling, or hold the ruling
+    /// for appeal if appeals are on
+    pub(crate) fn settle_ruling(
+        &mut self,
+        resolver: Address,
+        job_id: U256,
+        freelancer_bps: u16,
+    ) -> Result<(), Vec<u8>> {
+        if !self.appeals_enabled() {
+            return self.settle_dispute(resolver, job_id, freelancer_bps, false);
+        }
+
+        let window: u64 = self.appeal_window.get().to();
+        let appealable_until = self.vm().block_timestamp().saturating_add(window);
+        let mut appeal = self.appeals.setter(job_id);
+        appeal.resolver.set(resolver);
+        appeal
+            .freelancer_bps
+            .set(Uint::<16, 1>::from(freelancer_bps));
+        appeal
+            .appealable_until
+            .set(Uint::<64, 1>::from(appealable_until));
+
+        log(
+            self.vm(),
+            RulingHeld {
+                job_id,
+                resolver,
+                freelancer_bps,
+                appealable_until,
+            },
+        );
+        Ok(())
+    }
+
+    /// Whether `job_id`'s dispute has a ruling held for appeal
+    pub(crate) fn ruling_held(&self, job_id: U256) -> bool {
+        !self.appeals.get(job_id).appealable_until.get().is_zero() && self.is_disputed(job_id)
+    }
+
+    /// Revert while `job_id`'s dispute has a ruling held for appeal
+    pub(crate) fn assert_no_held_ruling(&self, job_id: U256) -> Result<(), Vec<u8>> {
+        if self.ruling_held(job_id) {
+            return Err(ErrorCode::RulingPending.into());
+        }
+        Ok(())
+    }
+
+    /// Appeal `job_id`'s held ruling on `appellant`'s behalf with `bond`,
+    /// proposing governance settle it at `freelancer_bps` instead
+    pub(crate) fn lodge_appeal(
+        &mut self,
+        appellant: Address,
+        job_id: U256,
+        freelancer_bps: u16,
+        bond: U256,
+    ) -> U256 {
+        let proposal_id = self.open_governance_proposal(
+            appellant,
+            GOVERNANCE_ACTION_APPEAL,
+            Address::ZERO,
+            job_id,
+            &[],
+        );
+        let mut appeal = self.appeals.setter(job_id);
+        appeal.appellant.set(appellant);
+        appeal.appeal_bps.set(Uint::<16, 1>::from(freelancer_bps));
+        appeal.bond.set(bond);
+        appeal.proposal_id.set(proposal_id);
+        let total = self.total_appeal_bonds.get();
+        self.total_appeal_bonds.set(total + bond);
+
+        log(
+            self.vm(),
+            RulingAppealed {
+                job_id,
+                appellant,
+                freelancer_bps,
+                bond,
+                proposal_id,
+            },
+        );
+        proposal_id
+    }
+
+    /// Whether `job_id`'s held ruling can be finalized: unappealed once its
+    /// window closes, or appealed once the appeal's proposal has lapsed
+    pub(crate) fn ruling_final(&self, job_id: U256) -> bool {
+        let appeal = self.appeals.get(job_id);
+        if appeal.appellant.get() == Address::ZERO {
+            let appealable_until: u64 = appeal.appealable_until.get().to();
+            self.vm().block_timestamp() >= appealable_until
+        } else {
+            self.proposal_lapsed(appeal.proposal_id.get())
+        }
+    }
+
+    /// Settle `job_id` at its held ruling, forfeiting any appeal bond to
+    /// the party that didn't appeal
+    pub(crate) fn finalize_held_ruling(&mut self, job_id: U256) -> Result<(), Vec<u8>> {
+        let appeal = self.appeals.get(job_id);
+        let resolver = appeal.resolver.get();
+        let freelancer_bps: u16 = appeal.freelancer_bps.get().to();
+        let bond = self.take_appeal_bond(job_id);
+
+        self.settle_dispute(resolver, job_id, freelancer_bps, false)?;
+        self.pay_appeal_bond(job_id, bond, true)
+    }
+
+    /// Settle `job_id` at its appellant's split once governance upheld the
+    /// appeal, returning their bond
+    pub(crate) fn uphold_appeal(&mut self, job_id: U256) -> Result<(), Vec<u8>> {
+        if !self.ruling_held(job_id) || self.appeals.get(job_id).appellant.get() == Address::ZERO {
+            return Err(ErrorCode::NoPendingAppeal.into());
+        }
+        let freelancer_bps: u16 = self.appeals.get(job_id).appeal_bps.get().to();
+        let bond = self.take_appeal_bond(job_id);
+
+        let resolver = self.vm().msg_sender();
+        self.settle_dispute(resolver, job_id, freelancer_bps, false)?;
+        self.pay_appeal_bond(job_id, bond, false)
+    }
+
+    /// Stop holding `job_id`'s appeal bond, returning what it was
+    pub(crate) fn take_appeal_bond(&mut self, job_id: U256) -> U256 {
+        let bond = self.appeals.get(job_id).bond.get();
+        if !bond.is_zero() {
+            self.appeals.setter(job_id).bond.set(U256::ZERO);
+            let total = self.total_appeal_bonds.get();
+            self.total_appeal_bonds.set(total - bond);
+        }
+        bond
+    }
+
+    /// Pay `bond` taken from `job_id`'s appeal back to the appellant, or to
+    /// the other party if the appeal was `lost`
+    pub(crate) fn pay_appeal_bond(
+        &mut self,
+        job_id: U256,
+        bond: U256,
+        lost: bool,
+    ) -> Result<(), Vec<u8>> {
+        if bond.is_zero() {
+            return Ok(());
+        }
+        let job = self.jobs.get(job_id);
+        let appellant = self.appeals.get(job_id).appellant.get();
+        let other = if appellant == job.freelancer.get() {
+            job.client.get()
+        } else {
+            job.freelancer.get()
+        };
+        // An unrevealed job has nobody to forfeit the bond to
+        let recipient = if lost && other != Address::ZERO {
+            other
+        } else {
+            appellant
+        };
+        let lost = recipient != appellant;
+
+        self.vm().transfer_eth(recipient, bond)?;
+
+        log(
+            self.vm(),
+            AppealBondSettled {
+                job_id,
+                recipient,
+                amount: bond,
+                forfeited: lost,
+            },
+        );
+        Ok(())
+    }
+
+    /// Credit any bond still held for `job_id`'s appeal back to the
+    /// appellant, for an appealed job settled outside the appeal
+    pub(crate) fn credit_appeal_bond(&mut self, job_id: U256) {
+        let bond = self.take_appeal_bond(job_id);
+        if bond.is_zero() {
+            return;
+        }
+        let recipient = self.appeals.get(job_id).appellant.get();
+        self.credit(recipient, bond);
+
+        log(
+            self.vm(),
+            AppealBondSettled {
+                job_id,
+                recipient,
+                amount: bond,
+                forfeited: false,
+            },
+        );
+    }
+}
@@ -0,0 +1,53 @@
+//! Dual-approval releases.
+//!
+//! A client can open a job that neither party can release alone: the client
+//! and the freelancer each call `approve_release`, and the release executes
+//! with the second approval. Such jobs are meant for high-value engagements,
+//! so the client's `release` and the freelancer's `auto_release` both refuse
+//! them. The client can still refund before the deadline, and unclaimed funds
+//! still go back through `reclaim`.
+
+use crate::{ErrorCode, Escrow, Released, BASIS_POINTS};
+use alloy_primitives::{B256, U256};
+use stylus_sdk::prelude::*;
+
+impl Escrow {
+    /// Whether a job was opened requiring both parties to approve its release
+    pub(crate) fn requires_dual_approval(&self, job_id: U256) -> bool {
+        self.dual_approvals.get(job_id).required.get()
+    }
+
+    /// Revert if the job can only be released with both parties' approval
+    pub(crate) fn assert_unilateral_release(&self, job_id: U256) -> Result<(), Vec<u8>> {
+        if self.requires_dual_approval(job_id) {
+            return Err(ErrorCode::DualApprovalRequired.into());
+        }
+        Ok(())
+    }
+
+    /// Pay out a job both parties have approved; compliance must already
+    /// have been checked
+    pub(crate) fn release_approved(&mut self, job_id: U256) -> Result<(), Vec<u8>> {
+        let job = self.jobs.get(job_id);
+        let amount = job.amount.get();
+        let freelancer = job.freelancer.get();
+
+        self.jobs.setter(job_id).released.set(true);
+        self.close_job(job_id);
+        self.record_release(job_id, freelancer, amount);
+
+        self.pay_release(job_id, freelancer, amount)?;
+        self.pay_basket(job_id, BASIS_POINTS)?;
+
+        log(
+            self.vm(),
+            Released {
+                job_id,
+                amount,
+                memo: B256::ZERO,
+            },
+        );
+        self.call_settlement_hook(job_id, amount, true);
+        Ok(())
+    }
+}
@@ -0,0 +1,148 @@
+//! Per-job arbitrators.
+//!
+//! A client can name the arbitrator of a job when opening it, either any
+//! address both parties trust or one of those the admin lists in the
+//! arbitrator registry. Disputes over that job are assigned to it instead of
+//! the global arbitrator; jobs without one keep falling back to the global
+//! arbitrator.
+//!
+//! An arbitrator who lets a dispute's ruling period pass without ruling can
+//! be replaced through a governance proposal, so a dead address can't hold
+//! the dispute hostage: once executed, the new arbitrator takes over the
+//! dispute and the job with a fresh ruling period.
+
+use crate::{
+    ArbitratorListed, ArbitratorReplaced, ErrorCode, Escrow, JobArbitratorSet,
+    MAX_LISTED_ARBITRATORS,
+};
+use alloy_primitives::{Address, Uint, U256};
+use stylus_sdk::prelude::*;
+
+impl Escrow {
+    /// Arbitrator disputes over `job_id` are assigned to: its own if the
+    /// client named one, else the global arbitrator
+    pub(crate) fn job_arbitrator(&self, job_id: U256) -> Address {
+        let arbitrator = self.jobs.get(job_id).arbitrator.get();
+        if arbitrator == Address::ZERO {
+            self.arbitrator.get()
+        } else {
+            arbitrator
+        }
+    }
+
+    /// Revert unless `arbitrator` can rule on a job between `client` and
+    /// `freelancer`
+    pub(crate) fn assert_valid_arbitrator(
+        &self,
+        arbitrator: Address,
+        client: Address,
+        freelancer: Address,
+    ) -> Result<(), Vec<u8>> {
+        if arbitrator == Address::ZERO {
+            return Err(ErrorCode::InvalidArbitrator.into());
+        }
+        if arbitrator == client || arbitrator == freelancer {
+            return Err(ErrorCode::ArbitratorIsParty.into());
+        }
+        Ok(())
+    }
+
+    /// Name `arbitrator` as the one who rules on `job_id`'s disputes
+    pub(crate) fn assign_arbitrator(&mut self, job_id: U256, arbitrator: Address) {
+        self.jobs.setter(job_id).arbitrator.set(arbitrator);
+
+        log(self.vm(), JobArbitratorSet { job_id, arbitrator });
+    }
+
+    /// Revert unless `job_id`'s dispute is open and its arbitrator let the
+    /// ruling period pass without ruling, and `arbitrator` can take it over
+    pub(crate) fn assert_arbitrator_replaceable(
+        &self,
+        job_id: U256,
+        arbitrator: Address,
+    ) -> Result<(), Vec<u8>> {
+        if !self.is_disputed(job_id) {
+            return Err(ErrorCode::NoOpenDispute.into());
+        }
+        let dispute = self.disputes.get(job_id);
+        let current = dispute.arbitrator.get();
+        if current == Address::ZERO {
+            return Err(ErrorCode::NoArbitratorAssigned.into());
+        }
+        self.assert_no_jury(job_id)?;
+        self.assert_no_held_ruling(job_id)?;
+        if self.vm().block_timestamp() < dispute.rule_by.get().to() {
+            return Err(ErrorCode::RulingPeriodNotOver.into());
+        }
+        if arbitrator == current {
+            return Err(ErrorCode::InvalidArbitrator.into());
+        }
+        let job = self.jobs.get(job_id);
+        self.assert_valid_arbitrator(arbitrator, job.client.get(), job.freelancer.get())
+    }
+
+    /// Hand `job_id`'s dispute, and the job, to `arbitrator` with a fresh
+    /// ruling period
+    pub(crate) fn replace_arbitrator(
+        &mut self,
+        job_id: U256,
+        arbitrator: Address,
+    ) -> Result<(), Vec<u8>> {
+        self.assert_arbitrator_replaceable(job_id, arbitrator)?;
+
+        let old_arbitrator = self.disputes.get(job_id).arbitrator.get();
+        let rule_by = self
+            .vm()
+            .block_timestamp()
+            .saturating_add(self.ruling_period.get().to());
+        let mut dispute = self.disputes.setter(job_id);
+        dispute.arbitrator.set(arbitrator);
+        dispute.rule_by.set(Uint::<64, 1>::from(rule_by));
+        self.jobs.setter(job_id).arbitrator.set(arbitrator);
+
+        log(
+            self.vm(),
+            ArbitratorReplaced {
+                job_id,
+                old_arbitrator,
+                new_arbitrator: arbitrator,
+                rule_by,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Add `arbitrator` to the registry clients pick from, or take it off
+    pub(crate) fn list_arbitrator(
+        &mut self,
+        arbitrator: Address,
+        listed: bool,
+    ) -> Result<(), Vec<u8>> {
+        if arbitrator == Address::ZERO {
+            return Err(ErrorCode::InvalidArbitrator.into());
+        }
+        if listed && !self.is_listed_arbitrator.get(arbitrator) {
+            if self.listed_arbitrators.len() >= MAX_LISTED_ARBITRATORS {
+                return Err(ErrorCode::TooManyArbitrators.into());
+            }
+            self.listed_arbitrators.push(arbitrator);
+        } else if !listed && self.is_listed_arbitrator.get(arbitrator) {
+            // Move the last listed arbitrator into the unlisted one's slot
+            let last = self.listed_arbitrators.len() - 1;
+            let index = (0..last)
+                .find(|&i| self.listed_arbitrators.get(i) == Some(arbitrator))
+                .unwrap_or(last);
+            let moved = self.listed_arbitrators.get(last).unwrap_or_default();
+            if let Some(mut slot) = self.listed_arbitrators.setter(index) {
+                slot.set(moved);
+            }
+            self.listed_arbitrators.pop();
+        }
+        self.is_listed_arbitrator.setter(arbitrator).set(listed);
+
+        log(self.vm(), ArbitratorListed { arbitrator, listed });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+//! Verified-freelancer gating through the Ethereum Attestation Service.
+//!
+//! The admin names the EAS contract, the schema that counts as "verified" and
+//! the attester trusted to issue it. A client can then open a job that only
+//! succeeds if the freelancer is the recipient of a live attestation from
+//! that attester under that schema.
+
+use crate::{ErrorCode, Escrow};
+use alloy_primitives::{Address, B256};
+use alloy_sol_types::{sol, SolCall};
+use stylus_sdk::prelude::*;
+use stylus_sdk::stylus_core::calls::context::Call;
+
+sol! {
+    struct Attestation {
+        bytes32 uid;
+        bytes32 schema;
+        uint64 time;
+        uint64 expirationTime;
+        uint64 revocationTime;
+        bytes32 refUID;
+        address recipient;
+        address attester;
+        bool revocable;
+        bytes data;
+    }
+
+    function getAttestation(bytes32 uid) external view returns (Attestation);
+}
+
+impl Escrow {
+    /// Revert unless `uid` is a live attestation of `freelancer` under the
+    /// configured schema and attester
+    pub(crate) fn assert_attested(&self, freelancer: Address, uid: B256) -> Result<(), Vec<u8>> {
+        let eas = self.eas.get();
+        if eas == Address::ZERO {
+            return Err(ErrorCode::AttestationsNotConfigured.into());
+        }
+
+        let calldata = getAttestationCall { uid }.abi_encode();
+        let attestation = self
+            .vm()
+            .static_call(&Call::new(), eas, &calldata)
+            .ok()
+            .and_then(|output| getAttestationCall::abi_decode_returns(&output, true).ok())
+            .map(|ret| ret._0)
+            .ok_or(ErrorCode::InvalidAttestation)?;
+
+        if attestation.uid != uid
+            || attestation.schema != self.attestation_schema.get()
+            || attestation.attester != self.attester.get()
+            || attestation.recipient != freelancer
+        {
+            return Err(ErrorCode::InvalidAttestation.into());
+        }
+        if attestation.revocationTime != 0 {
+            return Err(ErrorCode::AttestationRevoked.into());
+        }
+        let expires = attestation.expirationTime;
+        if expires != 0 && expires <= self.vm().block_timestamp() {
+            return Err(ErrorCode::AttestationExpired.into());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,141 @@
+//! Signed settlements and ERC-1271 signatures.
+//!
+//! A party can authorize a release or refund by signing an EIP-712
+//! `Settlement` off-chain, which anyone, such as an ERC-4337 bundler or the
+//! other party, then submits. The client signs releases and refunds, and
+//! the freelancer signs to return the funds. Each authorization names its
+//! signer, expires, and is spent by its nonce, so it settles at most once;
+//! the signer can withdraw it with `cancel_authorization` first.
+//!
+//! An arbitrator can likewise sign a `Verdict` for anyone to submit with
+//! `rule_with_signature`, which settles the dispute as their own `rule`
+//! would, so a panel can agree on a ruling off-chain and leave the gas to a
+//! party. Verdicts are spent from the same nonces as the arbitrator's
+//! settlements, so `cancel_authorization` withdraws one the panel
+//! reconsiders before it is submitted.
+//!
+//! Smart-account wallets and multisigs such as Safe can't produce an EOA
+//! signature, so a signer with code is asked through ERC-1271's
+//! `isValidSignature` instead, wherever the escrow accepts a signature:
+//! these authorizations, verdicts and job offers alike.
+
+use crate::{
+    settlement_hash, verdict_hash, AuthorizationCancelled, ErrorCode, Escrow, Settlement, Verdict,
+};
+use alloy_primitives::{fixed_bytes, Address, FixedBytes, B256, U256};
+use alloy_sol_types::{sol, SolCall};
+use stylus_sdk::prelude::*;
+use stylus_sdk::stylus_core::calls::context::Call;
+
+sol! {
+    function isValidSignature(bytes32 hash, bytes signature) external view returns (bytes4);
+}
+
+/// What ERC-1271's `isValidSignature` returns for a valid signature
+const ERC1271_MAGIC_VALUE: FixedBytes<4> = fixed_bytes!("1626ba7e");
+
+impl Escrow {
+    /// Whether `signature` is `signer`'s over `digest`: checked by the
+    /// signer itself under ERC-1271 if it is a contract, and otherwise
+    /// recovered as an ECDSA signature. A revert or malformed answer counts
+    /// as invalid.
+    pub(crate) fn is_signed_by(&self, signer: Address, digest: B256, signature: &[u8]) -> bool {
+        if self.vm().code_size(signer) == 0 {
+            return self
+                .offer_signer(digest, signature)
+                .is_ok_and(|s| s == signer);
+        }
+
+        let calldata = isValidSignatureCall {
+            hash: digest,
+            signature: signature.to_vec().into(),
+        }
+        .abi_encode();
+        self.vm()
+            .static_call(&Call::new(), signer, &calldata)
+            .ok()
+            .and_then(|output| isValidSignatureCall::abi_decode_returns(&output, true).ok())
+            .is_some_and(|ret| ret._0 == ERC1271_MAGIC_VALUE)
+    }
+
+    /// Check `settlement` is live and signed by its signer, then spend its
+    /// nonce
+    pub(crate) fn spend_authorization(
+        &mut self,
+        settlement: &Settlement,
+        signature: &[u8],
+    ) -> Result<(), Vec<u8>> {
+        if self.vm().block_timestamp() >= settlement.expiry {
+            return Err(ErrorCode::AuthorizationExpired.into());
+        }
+        let digest = settlement_hash(
+            settlement,
+            self.vm().chain_id(),
+            self.vm().contract_address(),
+        );
+        self.spend_signed(settlement.signer, settlement.nonce, digest, signature)
+    }
+
+    /// Check `verdict` is live and signed by `arbitrator`, then spend its
+    /// nonce
+    pub(crate) fn spend_verdict(
+        &mut self,
+        arbitrator: Address,
+        verdict: &Verdict,
+        signature: &[u8],
+    ) -> Result<(), Vec<u8>> {
+        if self.vm().block_timestamp() >= verdict.expiry {
+            return Err(ErrorCode::VerdictExpired.into());
+        }
+        let digest = verdict_hash(verdict, self.vm().chain_id(), self.vm().contract_address());
+        self.spend_signed(arbitrator, verdict.nonce, digest, signature)
+    }
+
+    /// Spend `signer`'s `nonce` on `signature` over `digest`, if it is theirs
+    fn spend_signed(
+        &mut self,
+        signer: Address,
+        nonce: U256,
+        digest: B256,
+        signature: &[u8],
+    ) -> Result<(), Vec<u8>> {
+        self.assert_authorization_unused(signer, nonce)?;
+        if signer == Address::ZERO || !self.is_signed_by(signer, digest, signature) {
+            return Err(ErrorCode::InvalidSignature.into());
+        }
+
+        self.used_authorizations
+            .setter(signer)
+            .setter(nonce)
+            .set(true);
+        Ok(())
+    }
+
+    /// Revert if `signer` already spent or cancelled `nonce`
+    pub(crate) fn assert_authorization_unused(
+        &self,
+        signer: Address,
+        nonce: U256,
+    ) -> Result<(), Vec<u8>> {
+        if self.used_authorizations.get(signer).get(nonce) {
+            return Err(ErrorCode::NonceAlreadyUsed.into());
+        }
+        Ok(())
+    }
+
+    /// Withdraw `signer`'s authorization signed with `nonce`
+    pub(crate) fn revoke_authorization(
+        &mut self,
+        signer: Address,
+        nonce: U256,
+    ) -> Result<(), Vec<u8>> {
+        self.assert_authorization_unused(signer, nonce)?;
+        self.used_authorizations
+            .setter(signer)
+            .setter(nonce)
+            .set(true);
+
+        log(self.vm(), AuthorizationCancelled { signer, nonce });
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+//! Gelato Automate resolver.
+//!
+//! Several settlements fall due with time and can be sent by anyone: a jury
+//! whose voting has closed, a ruling no longer appealable, a dispute past
+//! its timeout, a release at the end of its clawback window, a holdback at
+//! the end of its warranty and a job left unclaimed past its claim window.
+//! `resolver` finds the oldest job with one due and returns the call that
+//! settles it, in the `(canExec, execPayload)` shape Gelato's automation
+//! network polls, so a Gelato task pointed at the escrow itself processes
+//! deadlines and review windows with no keeper to run. It checks the same
+//! conditions the call does, so a payload it returns does not revert until
+//! something changes on chain.
+
+use crate::{Escrow, CLAIM_WINDOW};
+use alloy_primitives::{B256, U256};
+use alloy_sol_types::{sol, SolCall};
+use stylus_sdk::prelude::*;
+
+sol! {
+    function tallyJury(uint256 job_id) external;
+    function finalizeRuling(uint256 job_id) external;
+    function timeOutDispute(uint256 job_id) external;
+    function finalizeRelease(uint256 job_id) external;
+    function releaseHoldback(uint256 job_id) external;
+    function reclaim(uint256 job_id, bytes32 memo) external;
+}
+
+impl Escrow {
+    /// Calldata for the settlement of `job_id` anyone can send now, if any
+    pub(crate) fn due_settlement(&self, job_id: U256) -> Option<Vec<u8>> {
+        let now = self.vm().block_timestamp();
+        if self.is_disputed(job_id) {
+            let vote_by: u64 = self.juries.get(job_id).vote_by.get().to();
+            if self.jury_sitting(job_id) && now >= vote_by {
+                return Some(tallyJuryCall { job_id }.abi_encode());
+            }
+            if self.ruling_held(job_id) {
+                return self
+                    .ruling_final(job_id)
+                    .then(|| finalizeRulingCall { job_id }.abi_encode());
+            }
+            let timeout_at: u64 = self.disputes.get(job_id).timeout_at.get().to();
+            return (timeout_at != 0 && now >= timeout_at)
+                .then(|| timeOutDisputeCall { job_id }.abi_encode());
+        }
+        self.assert_settleable(job_id).ok()?;
+        self.assert_not_staked(job_id).ok()?;
+
+        let job = self.jobs.get(job_id);
+        let compliant = || {
+            self.assert_compliant(job.client.get(), job.freelancer.get(), job.amount.get())
+                .is_ok()
+        };
+        let payable_at: u64 = self.clawbacks.get(job_id).payable_at.get().to();
+        if payable_at != 0 {
+            return (now >= payable_at && compliant())
+                .then(|| finalizeReleaseCall { job_id }.abi_encode());
+        }
+        let warranty_ends: u64 = self.holdbacks.get(job_id).warranty_ends.get().to();
+        if warranty_ends != 0 {
+            return (now >= warranty_ends && compliant())
+                .then(|| releaseHoldbackCall { job_id }.abi_encode());
+        }
+
+        let deadline: u64 = job.deadline.get().to();
+        let reclaimable = self.tranches.get(job_id).is_empty()
+            && self.assert_no_pending_timesheets(job_id).is_ok()
+            && now >= deadline.saturating_add(CLAIM_WINDOW);
+        reclaimable.then(|| reclaimCall { job_id, memo: B256::ZERO }.abi_encode())
+    }
+}
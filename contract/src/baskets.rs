@@ -0,0 +1,143 @@
+//! Jobs funded with a basket of ETH and ERC-20s.
+//!
+//! The admin approves the ERC-20s a job can be funded with. A client can then
+//! open a job with a basket: the ETH sent is the job's amount as usual, and
+//! each token in the basket is pulled from the client with `transferFrom` and
+//! escrowed alongside it. Every settlement pays each token the same way as the
+//! ETH: a release sends it to the freelancer's payout address, a refund back
+//! to the client, a dispute ruling splits it in the ruled shares and a
+//! dormancy sweep sends it to the recovery address. Fees, withholding and
+//! payout swaps only apply to the ETH. A basket job can't be released in
+//! parts.
+
+use crate::{BasketPaid, ErrorCode, Escrow, BASIS_POINTS};
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::{sol, sol_data, SolCall, SolType};
+use stylus_sdk::prelude::*;
+use stylus_sdk::stylus_core::calls::context::Call;
+
+sol! {
+    function transfer(address to, uint256 amount) external returns (bool);
+    function transferFrom(address from, address to, uint256 amount) external returns (bool);
+}
+
+impl Escrow {
+    /// Whether `job_id` holds any ERC-20s besides its ETH
+    pub(crate) fn has_basket(&self, job_id: U256) -> bool {
+        !self.baskets.get(job_id).is_empty()
+    }
+
+    /// Revert if `job_id` holds ERC-20s, which only settle with the whole job
+    pub(crate) fn assert_no_basket(&self, job_id: U256) -> Result<(), Vec<u8>> {
+        if self.has_basket(job_id) {
+            return Err(ErrorCode::JobHasBasket.into());
+        }
+        Ok(())
+    }
+
+    /// Record `amount` of `token` in `job_id`'s basket and pull it from
+    /// `client`
+    pub(crate) fn fund_basket(
+        &mut self,
+        job_id: U256,
+        client: Address,
+        token: Address,
+        amount: U256,
+    ) -> Result<(), Vec<u8>> {
+        let mut basket = self.baskets.setter(job_id);
+        let mut item = basket.grow();
+        item.token.set(token);
+        item.amount.set(amount);
+
+        let calldata = transferFromCall {
+            from: client,
+            to: self.vm().contract_address(),
+            amount,
+        }
+        .abi_encode();
+        self.call_token(token, &calldata)?;
+        Ok(())
+    }
+
+    /// Pay out each ERC-20 in `job_id`'s basket, `freelancer_bps` of it to
+    /// the freelancer's payout address and the rest back to the client; the
+    /// job must already be settled
+    pub(crate) fn pay_basket(&mut self, job_id: U256, freelancer_bps: u16) -> Result<(), Vec<u8>> {
+        let client = self.jobs.get(job_id).client.get();
+        self.split_basket(job_id, freelancer_bps, client)
+    }
+
+    /// Send every ERC-20 in `job_id`'s basket to `recovery`
+    pub(crate) fn sweep_basket(&mut self, job_id: U256, recovery: Address) -> Result<(), Vec<u8>> {
+        self.split_basket(job_id, 0, recovery)
+    }
+
+    /// Pay `freelancer_bps` of each ERC-20 in `job_id`'s basket to the
+    /// freelancer's payout address and the rest to `rest_to`
+    fn split_basket(
+        &mut self,
+        job_id: U256,
+        freelancer_bps: u16,
+        rest_to: Address,
+    ) -> Result<(), Vec<u8>> {
+        let freelancer = self.jobs.get(job_id).freelancer.get();
+        let payout_to = self.payout_recipient(freelancer);
+        let basket = self.basket_tokens_of(job_id);
+        for (token, amount) in basket {
+            let to_freelancer = amount * U256::from(freelancer_bps) / U256::from(BASIS_POINTS);
+            self.pay_token(job_id, token, payout_to, to_freelancer)?;
+            self.pay_token(job_id, token, rest_to, amount - to_freelancer)?;
+        }
+        Ok(())
+    }
+
+    /// ERC-20s in `job_id`'s basket as (token, amount)
+    pub(crate) fn basket_tokens_of(&self, job_id: U256) -> Vec<(Address, U256)> {
+        let basket = self.baskets.get(job_id);
+        (0..basket.len())
+            .filter_map(|i| basket.getter(i))
+            .map(|item| (item.token.get(), item.amount.get()))
+            .collect()
+    }
+
+    /// Send `amount` of `token` held for `job_id` to `to`
+    fn pay_token(
+        &mut self,
+        job_id: U256,
+        token: Address,
+        to: Address,
+        amount: U256,
+    ) -> Result<(), Vec<u8>> {
+        if amount.is_zero() {
+            return Ok(());
+        }
+        let calldata = transferCall { to, amount }.abi_encode();
+        self.call_token(token, &calldata)?;
+
+        log(
+            self.vm(),
+            BasketPaid {
+                job_id,
+                token,
+                recipient: to,
+                amount,
+            },
+        );
+        Ok(())
+    }
+
+    /// Call `token` with `calldata`, accepting tokens that return nothing
+    /// as well as those returning `true`
+    pub(crate) fn call_token(&mut self, token: Address, calldata: &[u8]) -> Result<(), Vec<u8>> {
+        let succeeded = match self.vm().call(&Call::new(), token, calldata) {
+            Ok(output) => {
+                output.is_empty() || sol_data::Bool::abi_decode(&output, true).unwrap_or(false)
+            }
+            Err(_) => false,
+        };
+        if !succeeded {
+            return Err(ErrorCode::TokenTransferFailed.into());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+//! Concurrent job caps.
+//!
+//! The admin can set a default limit on how many active jobs a freelancer may
+//! hold at once, and a freelancer can set a lower one for themselves; the
+//! stricter of the two applies and zero means no limit. A deposit that would
+//! take the freelancer past their cap is rejected. A job takes a slot when
+//! the freelancer is assigned to it and frees it when the job closes or the
+//! freelancer is replaced.
+
+use crate::{ErrorCode, Escrow};
+use alloy_primitives::{Address, Uint};
+
+impl Escrow {
+    /// Most active jobs `freelancer` may hold; 0 if unlimited
+    pub(crate) fn job_cap(&self, freelancer: Address) -> u32 {
+        let default: u32 = self.default_job_cap.get().to();
+        let own: u32 = self.job_caps.get(freelancer).to();
+        match (default, own) {
+            (0, cap) | (cap, 0) => cap,
+            (default, own) => default.min(own),
+        }
+    }
+
+    /// Revert if `freelancer` cannot take on another job
+    pub(crate) fn assert_below_cap(&self, freelancer: Address) -> Result<(), Vec<u8>> {
+        let cap = self.job_cap(freelancer);
+        let active: u32 = self.active_jobs.get(freelancer).to();
+        if cap != 0 && active >= cap {
+            return Err(ErrorCode::JobCapReached.into());
+        }
+        Ok(())
+    }
+
+    /// Count a job `freelancer` has taken on against their cap
+    pub(crate) fn take_job_slot(&mut self, freelancer: Address) {
+        let mut active = self.active_jobs.setter(freelancer);
+        let count = active.get();
+        active.set(count + Uint::<32, 1>::from(1));
+    }
+
+    /// Free the place a job of `freelancer`'s held under their cap
+    pub(crate) fn free_job_slot(&mut self, freelancer: Address) {
+        let mut active = self.active_jobs.setter(freelancer);
+        let count = active.get();
+        active.set(count.saturating_sub(Uint::<32, 1>::from(1)));
+    }
+}
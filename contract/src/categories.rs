@@ -0,0 +1,36 @@
+//! Job categories and tags.
+//!
+//! A client can file a job under a `category`, a number the marketplace
+//! assigns to a kind of work such as design, development or writing, and a
+//! free-form `tag` narrowing it down; both are echoed in `Deposited` and
+//! zero means none. Each category counts its jobs and the ETH deposited in
+//! them, so marketplaces mixing kinds of work can segment their volume on
+//! chain, and `query_jobs_by_category` pages through a category's jobs.
+
+use crate::Escrow;
+use alloy_primitives::{Uint, B256, U256};
+
+impl Escrow {
+    /// File `job_id`, escrowing `amount`, under `category` and `tag`
+    pub(crate) fn categorize(&mut self, job_id: U256, category: u16, tag: B256, amount: U256) {
+        let mut job = self.jobs.setter(job_id);
+        job.category.set(Uint::<16, 1>::from(category));
+        job.tag.set(tag);
+        if category == 0 {
+            return;
+        }
+
+        let mut stats = self.category_stats.setter(Uint::<16, 1>::from(category));
+        let jobs = stats.jobs.get();
+        stats.jobs.set(jobs + U256::from(1));
+        let deposited = stats.deposited.get();
+        stats.deposited.set(deposited + amount);
+    }
+
+    /// Whether `job_id` is filed under `category` and, unless `tag` is
+    /// zero, `tag`
+    pub(crate) fn in_category(&self, job_id: U256, category: u16, tag: B256) -> bool {
+        let job = self.jobs.get(job_id);
+        job.category.get().to::<u16>() == category && (tag == B256::ZERO || job.tag.get() == tag)
+    }
+}
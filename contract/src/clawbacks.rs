@@ -0,0 +1,47 @@
+//! Post-release clawback windows.
+//!
+//! A client can open a large job with a clawback window, trading finality
+//! for safety. Releasing such a job, whether by the client's `release` or
+//! the freelancer's `auto_release`, only queues the payout: the funds stay
+//! escrowed for the window, and the arbitrator can reverse a release made
+//! under fraud or duress by refunding the client. Once the window is over,
+//! anyone can finalize the release and pay the freelancer. While a release
+//! is queued, neither party can settle or redirect the job any other way.
+
+use crate::{ErrorCode, Escrow, ReleaseQueued};
+use alloy_primitives::{Uint, B256, U256};
+use stylus_sdk::prelude::*;
+
+impl Escrow {
+    /// Whether releasing `job_id` queues the payout behind a clawback window
+    pub(crate) fn has_clawback(&self, job_id: U256) -> bool {
+        self.clawbacks.get(job_id).window.get() != Uint::<64, 1>::ZERO
+    }
+
+    /// Revert while a release of `job_id` is waiting out its clawback window
+    pub(crate) fn assert_no_queued_release(&self, job_id: U256) -> Result<(), Vec<u8>> {
+        if self.clawbacks.get(job_id).payable_at.get() != Uint::<64, 1>::ZERO {
+            return Err(ErrorCode::ReleaseQueued.into());
+        }
+        Ok(())
+    }
+
+    /// Start `job_id`'s clawback window instead of paying it out, keeping
+    /// `memo` for the release; compliance must already have been checked
+    pub(crate) fn queue_release(&mut self, job_id: U256, memo: B256) {
+        let window: u64 = self.clawbacks.get(job_id).window.get().to();
+        let payable_at = self.vm().block_timestamp().saturating_add(window);
+        let mut clawback = self.clawbacks.setter(job_id);
+        clawback.payable_at.set(Uint::<64, 1>::from(payable_at));
+        clawback.memo.set(memo);
+
+        log(
+            self.vm(),
+            ReleaseQueued {
+                job_id,
+                amount: self.jobs.get(job_id).amount.get(),
+                payable_at,
+            },
+        );
+    }
+}
@@ -0,0 +1,48 @@
+//! Optional compliance screening.
+//!
+//! The admin can point the escrow at a contract implementing
+//! `check(client, freelancer, amount) -> bool`, e.g. a KYC or sanctions
+//! screen. It is consulted before a deposit is accepted and before a payout;
+//! a `false`, a revert or a malformed answer all block the action.
+
+use crate::{ErrorCode, Escrow};
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::{sol, SolCall};
+use stylus_sdk::prelude::*;
+use stylus_sdk::stylus_core::calls::context::Call;
+
+sol! {
+    function check(address client, address freelancer, uint256 amount) external view returns (bool);
+}
+
+impl Escrow {
+    /// Revert unless the compliance hook, if any, approves the action
+    pub(crate) fn assert_compliant(
+        &self,
+        client: Address,
+        freelancer: Address,
+        amount: U256,
+    ) -> Result<(), Vec<u8>> {
+        let hook = self.compliance_hook.get();
+        if hook == Address::ZERO {
+            return Ok(());
+        }
+
+        let calldata = checkCall {
+            client,
+            freelancer,
+            amount,
+        }
+        .abi_encode();
+        let approved = self
+            .vm()
+            .static_call(&Call::new(), hook, &calldata)
+            .ok()
+            .and_then(|output| checkCall::abi_decode_returns(&output, true).ok())
+            .is_some_and(|ret| ret._0);
+        if !approved {
+            return Err(ErrorCode::ComplianceCheckFailed.into());
+        }
+        Ok(())
+    }
+}
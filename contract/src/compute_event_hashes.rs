@@ -1,24 +1,242 @@
-use alloy_primitives::{keccak256, B256};
+//! Exports the Escrow interface for frontends and Solidity integrators.
+//!
+//! Functions come from the `#[public]` impl and events and errors from the `sol!` blocks,
+//! so the output always matches the compiled contract:
+//!
+//! ```bash
+//! cargo run --features export-abi --bin compute_event_hashes -- [topics|selectors|interface|abi]
+//! ```
+
+use alloy_json_abi::{Constructor, Event, Function, JsonAbi};
+use alloy_sol_types::JsonAbiExt;
+use freelance_payment_escrow::*;
+use std::fmt;
+use stylus_sdk::abi::export::GenerateAbi;
+
+/// Formats the `#[public]` methods the way `cargo stylus export-abi` does
+struct StylusAbi;
+
+impl fmt::Display for StylusAbi {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Escrow::fmt_abi(f)
+    }
+}
+
+/// Formats the `#[constructor]` signature the way `cargo stylus export-abi` does
+struct StylusConstructor;
+
+impl fmt::Display for StylusConstructor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Escrow::fmt_constructor_signature(f)
+    }
+}
+
+/// Every event the contract can emit
+fn events() -> Vec<Event> {
+    vec![
+        Deposited::abi(),
+        Released::abi(),
+        Refunded::abi(),
+        AutoReleased::abi(),
+        EmergencyRefunded::abi(),
+        PauseToggled::abi(),
+        OwnershipTransferred::abi(),
+        SunsetBegun::abi(),
+        SunsetFinalized::abi(),
+        DormancyPolicySet::abi(),
+        DormancyAnnounced::abi(),
+        DormantSwept::abi(),
+        PrivateDeposited::abi(),
+        JobRevealed::abi(),
+        SwapRouterSet::abi(),
+        PayoutTokenSet::abi(),
+        PayoutSwapped::abi(),
+        PayoutSwapFailed::abi(),
+        WithholdingSet::abi(),
+        Withheld::abi(),
+        ComplianceHookSet::abi(),
+        JobAttested::abi(),
+        AttestationPolicySet::abi(),
+        SettlementHookSet::abi(),
+        SettlementHookFailed::abi(),
+        ReleaseConditionSet::abi(),
+        TrancheClaimed::abi(),
+        TrancheRefunded::abi(),
+        RetainerStarted::abi(),
+        RetainerStopped::abi(),
+        CancellationNoticeSet::abi(),
+        CancellationRequested::abi(),
+        DeliverySubmitted::abi(),
+        FundingOpened::abi(),
+        JobFunded::abi(),
+        ContributionReclaimed::abi(),
+        ExcessCredited::abi(),
+        CreditWithdrawn::abi(),
+        DefaultJobCapSet::abi(),
+        JobCapSet::abi(),
+        ProtocolFeeSet::abi(),
+        FeeCharged::abi(),
+        FeeManagerSet::abi(),
+        FeeTiersSet::abi(),
+        VolumeDiscountsSet::abi(),
+        FeeExemptionSet::abi(),
+        DisputeOpened::abi(),
+        EvidenceSubmitted::abi(),
+        DisputeResolved::abi(),
+        ArbitrationPolicySet::abi(),
+        Reclaimed::abi(),
+        FundsReturned::abi(),
+        DualApprovalRequired::abi(),
+        ReleaseApproved::abi(),
+        DisputeTimedOut::abi(),
+        DisputeTimeoutPolicySet::abi(),
+        JobAmended::abi(),
+        FeesWithdrawn::abi(),
+        TreasuryProposed::abi(),
+        TreasurySet::abi(),
+        GuardiansSet::abi(),
+        EmergencyProposed::abi(),
+        EmergencyConfirmed::abi(),
+        SurplusSwept::abi(),
+        PauseExtended::abi(),
+        ClientTransferProposed::abi(),
+        JobAccepted::abi(),
+        FreelancerChanged::abi(),
+        PayoutAddressProposed::abi(),
+        PayoutAddressSet::abi(),
+        GovernanceSet::abi(),
+        GovernanceProposed::abi(),
+        GovernanceVoted::abi(),
+        GovernanceExecuted::abi(),
+        PauserSet::abi(),
+        MaxPauseSet::abi(),
+        RenounceProposed::abi(),
+        PlatformFeeSet::abi(),
+        CreditDeposited::abi(),
+        OfferCancelled::abi(),
+        InvoiceCreated::abi(),
+        InvoiceFunded::abi(),
+        InvoicePruned::abi(),
+        HoldbackSet::abi(),
+        PartiallyReleased::abi(),
+        ClawbackWindowSet::abi(),
+        HoldbackRetained::abi(),
+        ReleaseReversed::abi(),
+        ReleaseQueued::abi(),
+        LateRefunded::abi(),
+        SlaSet::abi(),
+        TimesheetSubmitted::abi(),
+        HourlyRateSet::abi(),
+        TimesheetRejected::abi(),
+        TimesheetApproved::abi(),
+        BasketFunded::abi(),
+        BasketTokenSet::abi(),
+        RefundRootSet::abi(),
+        BasketPaid::abi(),
+        EmergencyRefundBatch::abi(),
+        EmergencyRefundClaimed::abi(),
+        SettlementRebateSet::abi(),
+        JobRated::abi(),
+        PayoutPushed::abi(),
+        SettlementRebated::abi(),
+        DepositLimitExemptionSet::abi(),
+        DepositLimitsSet::abi(),
+        DisputeStakeSettled::abi(),
+        DisputeStakePolicySet::abi(),
+        JuryPolicySet::abi(),
+        AuthorizationCancelled::abi(),
+        JurorLeft::abi(),
+        JurorStaked::abi(),
+        JuryVoted::abi(),
+        JuryDrawn::abi(),
+        AppealPolicySet::abi(),
+        JuryTallied::abi(),
+        RulingAppealed::abi(),
+        RulingHeld::abi(),
+        JobArbitratorSet::abi(),
+        AppealBondSettled::abi(),
+        ArbitratorReplaced::abi(),
+        ArbitratorListed::abi(),
+        StakeConsented::abi(),
+        StakingTokenSet::abi(),
+        JobUnstaked::abi(),
+        JobStaked::abi(),
+        YieldSplitSet::abi(),
+    ]
+}
+
+/// Every external function, parsed from the generated Solidity interface
+fn functions() -> Vec<Function> {
+    StylusAbi
+        .to_string()
+        .lines()
+        .map(str::trim)
+        .filter(|line| line.starts_with("function "))
+        .map(|line| {
+            let line = line.trim_end_matches(';');
+            Function::parse(line).unwrap_or_else(|e| panic!("unparseable `{line}`: {e}"))
+        })
+        .collect()
+}
+
+/// The deployment constructor
+fn constructor() -> Constructor {
+    let line = StylusConstructor.to_string();
+    Constructor::parse(line.trim()).unwrap_or_else(|e| panic!("unparseable `{line}`: {e}"))
+}
+
+fn contract_abi() -> JsonAbi {
+    let mut abi = JsonAbi::new();
+    abi.constructor = Some(constructor());
+    for function in functions() {
+        abi.functions
+            .entry(function.name.clone())
+            .or_default()
+            .push(function);
+    }
+    for event in events() {
+        abi.events
+            .entry(event.name.clone())
+            .or_default()
+            .push(event);
+    }
+    let error = EscrowError::abi();
+    abi.errors.entry(error.name.clone()).or_default().push(error);
+    abi
+}
 
 fn main() {
-    // List of event signatures from the Escrow contract
-    let event_signatures = vec![
-        "Deposited(uint256,address,address,uint256)",
-        "Released(uint256,uint256)",
-        "Refunded(uint256,uint256)",
-        "AutoReleased(uint256,uint256)",
-        "EmergencyRefunded(uint256,address)",
-        "PauseToggled(bool)",
-        "OwnershipTransferred(address,address)",
-    ];
-
-    // Compute and print Keccak-256 hashes for each event
-    for signature in event_signatures {
-        // Compute Keccak-256 hash of the event signature
-        let hash: B256 = keccak256(signature.as_bytes());
-        // Format as hex string with 0x prefix
-        println!("Event: {}", signature);
-        println!("Keccak-256 Hash: 0x{}", hex::encode(hash));
-        println!();
+    let command = std::env::args().nth(1).unwrap_or_else(|| "topics".into());
+    match command.as_str() {
+        "topics" => {
+            for event in events() {
+                println!("{} {}", event.selector(), event.signature());
+            }
+        }
+        "selectors" => {
+            for function in functions() {
+                println!("{} {}", function.selector(), function.signature());
+            }
+        }
+        "interface" => {
+            println!("// SPDX-License-Identifier: MIT-OR-APACHE-2.0");
+            println!("pragma solidity ^0.8.23;");
+            println!();
+            println!(
+                "{}",
+                contract_abi().to_sol(&format!("I{}", Escrow::NAME), None)
+            );
+        }
+        "abi" => {
+            let abi = contract_abi();
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&abi).expect("serializable ABI")
+            );
+        }
+        other => {
+            eprintln!("unknown command `{other}`, expected topics|selectors|interface|abi");
+            std::process::exit(1);
+        }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,40 @@
+//! Custom release conditions.
+//!
+//! A client can open a job whose `auto_release` additionally requires an
+//! external contract's `canRelease(job_id)` to return true: an oracle proof,
+//! a DAO vote, a CI pipeline attestation. The condition is fixed when the job
+//! is opened, so the freelancer knows the terms before starting. It only
+//! gates the freelancer's claim; the client can always release by hand.
+
+use crate::{ErrorCode, Escrow};
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::{sol, SolCall};
+use stylus_sdk::prelude::*;
+use stylus_sdk::stylus_core::calls::context::Call;
+
+sol! {
+    function canRelease(uint256 job_id) external view returns (bool);
+}
+
+impl Escrow {
+    /// Revert unless the job's release condition, if any, is met. A revert
+    /// or malformed answer counts as not met.
+    pub(crate) fn assert_releasable(&self, job_id: U256) -> Result<(), Vec<u8>> {
+        let condition = self.release_conditions.get(job_id);
+        if condition == Address::ZERO {
+            return Ok(());
+        }
+
+        let calldata = canReleaseCall { job_id }.abi_encode();
+        let met = self
+            .vm()
+            .static_call(&Call::new(), condition, &calldata)
+            .ok()
+            .and_then(|output| canReleaseCall::abi_decode_returns(&output, true).ok())
+            .is_some_and(|ret| ret._0);
+        if !met {
+            return Err(ErrorCode::ReleaseConditionNotMet.into());
+        }
+        Ok(())
+    }
+}
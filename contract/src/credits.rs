@@ -0,0 +1,20 @@
+//! Withdrawable balances.
+//!
+//! ETH the escrow owes an address outside of any job, such as the surplus of
+//! an overpaid fixed-amount deposit or a payout a contract refused, is
+//! credited here rather than pushed in the same call. It stays a liability
+//! until its owner withdraws it.
+
+use crate::Escrow;
+use alloy_primitives::{Address, U256};
+
+impl Escrow {
+    /// Add `amount` to what `account` can withdraw
+    pub(crate) fn credit(&mut self, account: Address, amount: U256) {
+        let mut balance = self.credits.setter(account);
+        let credited = balance.get();
+        balance.set(credited + amount);
+        let total = self.total_credit.get();
+        self.total_credit.set(total + amount);
+    }
+}
@@ -0,0 +1,68 @@
+//! Deposit rate limits.
+//!
+//! Every job stays in the registry and in both parties' job indexes for
+//! good, so spam deposits cost everyone who lists or scans them. The admin
+//! can make each client wait a cooldown between the jobs it opens and cap
+//! how many it opens in a single block; zero turns either limit off. Known
+//! marketplaces, which open jobs for many clients from one address, can be
+//! exempted from both.
+
+use crate::{DepositLimitExemptionSet, DepositLimitsSet, ErrorCode, Escrow};
+use alloy_primitives::{Address, Uint};
+use stylus_sdk::prelude::*;
+
+impl Escrow {
+    /// Set the cooldown between a client's deposits and the most it may
+    /// open in one block
+    pub(crate) fn update_deposit_limits(&mut self, cooldown: u64, per_block: u32) {
+        self.deposit_cooldown.set(Uint::<64, 1>::from(cooldown));
+        self.deposits_per_block.set(Uint::<32, 1>::from(per_block));
+
+        log(
+            self.vm(),
+            DepositLimitsSet {
+                cooldown,
+                per_block,
+            },
+        );
+    }
+
+    /// Exempt `account` from the deposit limits, or end the exemption
+    pub(crate) fn update_deposit_limit_exemption(&mut self, account: Address, exempt: bool) {
+        self.deposit_limit_exempt.setter(account).set(exempt);
+
+        log(self.vm(), DepositLimitExemptionSet { account, exempt });
+    }
+
+    /// Revert if `client` may not open another job yet, and otherwise count
+    /// the one it is opening
+    pub(crate) fn record_deposit_rate(&mut self, client: Address) -> Result<(), Vec<u8>> {
+        if self.deposit_limit_exempt.get(client) {
+            return Ok(());
+        }
+        let cooldown: u64 = self.deposit_cooldown.get().to();
+        let per_block: u32 = self.deposits_per_block.get().to();
+        let now = self.vm().block_timestamp();
+        let block = self.vm().block_number();
+
+        let window = self.deposit_windows.get(client);
+        let last_at: u64 = window.last_at.get().to();
+        if cooldown != 0 && last_at != 0 && now < last_at + cooldown {
+            return Err(ErrorCode::DepositCooldownActive.into());
+        }
+        let in_block: u32 = if window.block.get().to::<u64>() == block {
+            window.count.get().to()
+        } else {
+            0
+        };
+        if per_block != 0 && in_block >= per_block {
+            return Err(ErrorCode::DepositLimitReached.into());
+        }
+
+        let mut window = self.deposit_windows.setter(client);
+        window.last_at.set(Uint::<64, 1>::from(now));
+        window.block.set(Uint::<64, 1>::from(block));
+        window.count.set(Uint::<32, 1>::from(in_block + 1));
+        Ok(())
+    }
+}
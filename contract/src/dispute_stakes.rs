@@ -0,0 +1,130 @@
+//! Dispute stakes.
+//!
+//! So a party can't open a dispute just to freeze a job's payouts, the admin
+//! can require a stake with every new dispute: `dispute_stake_bps` of the
+//! job's amount, and at least `min_dispute_stake`. Whoever opens the dispute
+//! sends exactly that stake, which stays locked while the dispute is open.
+//!
+//! When the dispute is settled, the stake goes back to the opener if they
+//! prevailed and to the other party if they lost, by the same test a ruling
+//! uses against reputations: the freelancer loses a split giving them less
+//! than half of the job, the client one giving them less than half back, and
+//! an even split has no loser. A dispute lifted by its timeout returns the
+//! stake, as does a disputed job settled some other way, such as an
+//! emergency refund, which credits it to the opener to withdraw.
+
+use crate::{DisputeStakePolicySet, DisputeStakeSettled, Escrow, BASIS_POINTS};
+use alloy_primitives::{Address, Uint, U256};
+use stylus_sdk::prelude::*;
+
+impl Escrow {
+    /// Set the stake new disputes take: `stake_bps` of the job, at least
+    /// `min_stake`
+    pub(crate) fn update_dispute_stake_policy(&mut self, stake_bps: u16, min_stake: U256) {
+        self.dispute_stake_bps.set(Uint::<16, 1>::from(stake_bps));
+        self.min_dispute_stake.set(min_stake);
+
+        log(
+            self.vm(),
+            DisputeStakePolicySet {
+                stake_bps,
+                min_stake,
+            },
+        );
+    }
+
+    /// Stake opening a dispute over `job_id` takes under the current policy
+    pub(crate) fn dispute_stake_for(&self, job_id: U256) -> U256 {
+        let stake_bps: u16 = self.dispute_stake_bps.get().to();
+        let amount = self.jobs.get(job_id).amount.get();
+        let proportional = amount * U256::from(stake_bps) / U256::from(BASIS_POINTS);
+        proportional.max(self.min_dispute_stake.get())
+    }
+
+    /// Hold `stake` for `job_id`'s dispute until it is settled
+    pub(crate) fn lock_dispute_stake(&mut self, job_id: U256, stake: U256) {
+        self.disputes.setter(job_id).stake.set(stake);
+        let total = self.total_dispute_stakes.get();
+        self.total_dispute_stakes.set(total + stake);
+    }
+
+    /// Stop holding `job_id`'s dispute stake, returning what it was
+    pub(crate) fn take_dispute_stake(&mut self, job_id: U256) -> U256 {
+        let stake = self.disputes.get(job_id).stake.get();
+        if !stake.is_zero() {
+            self.disputes.setter(job_id).stake.set(U256::ZERO);
+            let total = self.total_dispute_stakes.get();
+            self.total_dispute_stakes.set(total - stake);
+        }
+        stake
+    }
+
+    /// Whether the split giving the freelancer `freelancer_bps` of `job_id`
+    /// goes against whoever opened its dispute
+    pub(crate) fn opener_lost(&self, job_id: U256, freelancer_bps: u16) -> bool {
+        let freelancer = self.jobs.get(job_id).freelancer.get();
+        let half = BASIS_POINTS / 2;
+        if freelancer == Address::ZERO {
+            // An unrevealed job always goes back to its client in full
+            false
+        } else if self.disputes.get(job_id).opened_by.get() == freelancer {
+            freelancer_bps < half
+        } else {
+            freelancer_bps > half
+        }
+    }
+
+    /// Pay `stake` taken from `job_id`'s dispute back to its opener, or to
+    /// the other party if the opener `lost`
+    pub(crate) fn pay_dispute_stake(
+        &mut self,
+        job_id: U256,
+        stake: U256,
+        lost: bool,
+    ) -> Result<(), Vec<u8>> {
+        if stake.is_zero() {
+            return Ok(());
+        }
+        let job = self.jobs.get(job_id);
+        let opened_by = self.disputes.get(job_id).opened_by.get();
+        let recipient = match (lost, opened_by == job.freelancer.get()) {
+            (false, _) => opened_by,
+            (true, true) => job.client.get(),
+            (true, false) => job.freelancer.get(),
+        };
+
+        self.vm().transfer_eth(recipient, stake)?;
+
+        log(
+            self.vm(),
+            DisputeStakeSettled {
+                job_id,
+                recipient,
+                amount: stake,
+                forfeited: lost,
+            },
+        );
+        Ok(())
+    }
+
+    /// Credit any stake still held for `job_id`'s dispute back to its
+    /// opener, for a disputed job settled outside the dispute
+    pub(crate) fn credit_dispute_stake(&mut self, job_id: U256) {
+        let stake = self.take_dispute_stake(job_id);
+        if stake.is_zero() {
+            return;
+        }
+        let recipient = self.disputes.get(job_id).opened_by.get();
+        self.credit(recipient, stake);
+
+        log(
+            self.vm(),
+            DisputeStakeSettled {
+                job_id,
+                recipient,
+                amount: stake,
+                forfeited: false,
+            },
+        );
+    }
+}
@@ -0,0 +1,129 @@
+//! Disputes and their evidence trail.
+//!
+//! Either party to a funded, unsettled job can open a dispute over it, once.
+//! While the dispute is open both parties can submit evidence: hashes of
+//! documents kept off chain, appended with who submitted them and when, so
+//! arbitrators and auditors share one canonical record. A dispute is over
+//! once its job settles; the evidence stays readable after that.
+//!
+//! A dispute opened while the admin has appointed an arbitrator is assigned
+//! to them, along with the policy's ruling period and default split. The
+//! arbitrator settles the job by ruling how much of it the freelancer gets.
+//! If they have not ruled by the end of the period, either party can settle
+//! it at the default split instead, so an absent arbitrator can't freeze the
+//! funds. A ruling also counts against the reputation of whichever party
+//! lost it.
+//!
+//! The admin can also cap how long any dispute stays open. Each dispute
+//! takes the timeout rule in force when it was opened; once it runs out,
+//! anyone can apply it, either splitting the job at the configured share or
+//! lifting the dispute so the job settles by its deadline as if it had never
+//! been disputed. A lifted dispute cannot be reopened.
+//!
+//! Opening a dispute can take a stake, which the ruling returns or forfeits;
+//! see `dispute_stakes`.
+//!
+//! An open dispute freezes every payout to the freelancer, whether the
+//! client's `release`, the freelancer's `auto_release` or tranche claims, so
+//! nobody can settle around the dispute once the deadline passes.
+
+use crate::{DisputeResolved, ErrorCode, Escrow, BASIS_POINTS};
+use alloy_primitives::{Address, U256};
+use stylus_sdk::prelude::*;
+
+impl Escrow {
+    /// Whether `job_id` has a dispute open: opened, not lifted by its
+    /// timeout and not yet settled
+    pub(crate) fn is_disputed(&self, job_id: U256) -> bool {
+        let dispute = self.disputes.get(job_id);
+        !dispute.opened_at.get().is_zero() && !dispute.lifted.get() && self.is_active(job_id)
+    }
+
+    /// Revert while `job_id` has a dispute open
+    pub(crate) fn assert_not_disputed(&self, job_id: U256) -> Result<(), Vec<u8>> {
+        if self.is_disputed(job_id) {
+            return Err(ErrorCode::JobIsDisputed.into());
+        }
+        Ok(())
+    }
+
+    /// Whether `account` is `job_id`'s client or its known freelancer
+    pub(crate) fn is_job_party(&self, job_id: U256, account: Address) -> bool {
+        let job = self.jobs.get(job_id);
+        account != Address::ZERO && (account == job.client.get() || account == job.freelancer.get())
+    }
+
+    /// Settle a disputed job, paying `freelancer_bps` of what it still holds
+    /// to the freelancer and the rest back to the client. Open tranches are
+    /// settled with it; an unrevealed private job goes back to its client in
+    /// full. The job counts as released if the freelancer gets anything.
+    /// `resolver` is whoever settles it, reported in `DisputeResolved`.
+    pub(crate) fn settle_dispute(
+        &mut self,
+        resolver: Address,
+        job_id: U256,
+        freelancer_bps: u16,
+        by_default: bool,
+    ) -> Result<(), Vec<u8>> {
+        let job = self.jobs.get(job_id);
+        let client = job.client.get();
+        let freelancer = job.freelancer.get();
+        let amount = job.amount.get();
+        let to_freelancer = if freelancer == Address::ZERO {
+            U256::ZERO
+        } else {
+            amount * U256::from(freelancer_bps) / U256::from(BASIS_POINTS)
+        };
+        let to_client = amount - to_freelancer;
+        if !to_freelancer.is_zero() {
+            self.assert_compliant(client, freelancer, to_freelancer)?;
+        }
+
+        for (index, _, _) in self.open_tranches(job_id) {
+            if let Some(mut tranche) = self.tranches.setter(job_id).setter(index) {
+                tranche.settled.set(true);
+            }
+        }
+        let mut job = self.jobs.setter(job_id);
+        if to_freelancer.is_zero() {
+            job.refunded.set(true);
+        } else {
+            job.released.set(true);
+        }
+        let stake = self.take_dispute_stake(job_id);
+        let opener_lost = self.opener_lost(job_id, freelancer_bps);
+        self.close_job(job_id);
+        if !by_default {
+            self.record_ruling(job_id, freelancer_bps);
+        }
+
+        if !to_freelancer.is_zero() {
+            self.record_release(job_id, freelancer, to_freelancer);
+            self.pay_release(job_id, freelancer, to_freelancer)?;
+        }
+        if !to_client.is_zero() {
+            self.record_refund(job_id, client, to_client);
+            self.vm().transfer_eth(client, to_client)?;
+        }
+        self.pay_basket(job_id, freelancer_bps)?;
+        self.pay_dispute_stake(job_id, stake, opener_lost)?;
+
+        log(
+            self.vm(),
+            DisputeResolved {
+                job_id,
+                resolver,
+                freelancer_amount: to_freelancer,
+                client_amount: to_client,
+                by_default,
+            },
+        );
+        if !to_freelancer.is_zero() {
+            self.call_settlement_hook(job_id, to_freelancer, true);
+        }
+        if !to_client.is_zero() {
+            self.call_settlement_hook(job_id, to_client, false);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+//! Escrowed amounts per party.
+//!
+//! Every address keeps a running total of what its unsettled jobs hold, once
+//! as client (funds it has locked) and once as freelancer (earnings it has
+//! pending), so wallets can show "funds in escrow" without walking the jobs.
+//! The totals move with each job's amount: deposits and contributions lock,
+//! every release, refund and sweep unlocks, and a change of party moves the
+//! job's whole amount to the new address.
+
+use crate::Escrow;
+use alloy_primitives::{Address, U256};
+
+impl Escrow {
+    /// Count `amount` more of `job_id` against its client and freelancer
+    pub(crate) fn lock_escrow(&mut self, job_id: U256, amount: U256) {
+        let job = self.jobs.get(job_id);
+        let client = job.client.get();
+        let freelancer = job.freelancer.get();
+
+        let mut locked = self.locked_amounts.setter(client);
+        let total = locked.get();
+        locked.set(total + amount);
+        // A private job's freelancer stays hidden until the reveal
+        if freelancer != Address::ZERO {
+            let mut pending = self.pending_earnings.setter(freelancer);
+            let total = pending.get();
+            pending.set(total + amount);
+        }
+    }
+
+    /// Stop counting `amount` of `job_id` against its client and freelancer,
+    /// as it leaves the job
+    pub(crate) fn unlock_escrow(&mut self, job_id: U256, amount: U256) {
+        let job = self.jobs.get(job_id);
+        let client = job.client.get();
+        let freelancer = job.freelancer.get();
+
+        let mut locked = self.locked_amounts.setter(client);
+        let total = locked.get();
+        locked.set(total.saturating_sub(amount));
+        if freelancer != Address::ZERO {
+            let mut pending = self.pending_earnings.setter(freelancer);
+            let total = pending.get();
+            pending.set(total.saturating_sub(amount));
+        }
+    }
+}
@@ -0,0 +1,216 @@
+//! Protocol fee.
+//!
+//! Every payout to a freelancer pays the protocol fee, a share of the gross
+//! amount in basis points, before any withholding. The rate depends on the
+//! job's size: the fee manager can set tiers that charge larger jobs a
+//! different rate than the base fee. Clients earn volume discounts off that
+//! rate as their jobs release funds, and partners on the admin's allowlist
+//! pay nothing. A job's rate is fixed when it is opened, so changing the fee,
+//! tiers, discounts or exemptions never reprices an escrow already in
+//! flight. Fees accrue in a pool of their own, apart from job principal and
+//! credit balances, until the fee manager withdraws them. Refunds are never
+//! charged.
+
+use crate::{
+    ErrorCode, Escrow, FeeCharged, FeeExemptionSet, FeeManagerSet, FeeTiersSet,
+    SettlementRebateSet, VolumeDiscountsSet, BASIS_POINTS, MAX_FEE_TIERS, MAX_PROTOCOL_FEE_BPS,
+};
+use alloy_primitives::{Address, Uint, U256};
+use stylus_sdk::prelude::*;
+
+/// Fee at `fee_bps` on `amount`
+pub(crate) fn fee_on(amount: U256, fee_bps: u16) -> U256 {
+    amount * U256::from(fee_bps) / U256::from(BASIS_POINTS)
+}
+
+impl Escrow {
+    /// Account that configures fees; the admin unless a fee manager is set
+    pub(crate) fn current_fee_manager(&self) -> Address {
+        let manager = self.fee_manager.get();
+        if manager == Address::ZERO {
+            self.admin.get()
+        } else {
+            manager
+        }
+    }
+
+    /// Revert unless the caller configures fees
+    pub(crate) fn assert_fee_manager(&self) -> Result<(), Vec<u8>> {
+        if self.vm().msg_sender() != self.current_fee_manager() {
+            return Err(ErrorCode::OnlyFeeManager.into());
+        }
+        Ok(())
+    }
+
+    /// Hand fee configuration to `manager`; the zero address gives it back
+    /// to the admin
+    pub(crate) fn update_fee_manager(&mut self, manager: Address) {
+        self.fee_manager.set(manager);
+
+        log(self.vm(), FeeManagerSet { manager });
+    }
+
+    /// Rebate up to `amount` wei of each keeper-style settlement's fee
+    pub(crate) fn update_settlement_rebate(&mut self, amount: U256) {
+        self.settlement_rebate.set(amount);
+
+        log(self.vm(), SettlementRebateSet { amount });
+    }
+
+    /// Replace the size tiers; minimums must be strictly increasing
+    pub(crate) fn update_fee_tiers(
+        &mut self,
+        min_amounts: Vec<U256>,
+        fee_bps: Vec<u16>,
+    ) -> Result<(), Vec<u8>> {
+        if min_amounts.len() != fee_bps.len() || min_amounts.len() > MAX_FEE_TIERS {
+            return Err(ErrorCode::InvalidTiers.into());
+        }
+        for (i, min_amount) in min_amounts.iter().enumerate() {
+            if min_amount.is_zero() || (i > 0 && *min_amount <= min_amounts[i - 1]) {
+                return Err(ErrorCode::InvalidTiers.into());
+            }
+        }
+        if fee_bps.iter().any(|bps| *bps > MAX_PROTOCOL_FEE_BPS) {
+            return Err(ErrorCode::InvalidFee.into());
+        }
+
+        self.fee_tiers.truncate(0);
+        for (min_amount, bps) in min_amounts.iter().zip(&fee_bps) {
+            let mut tier = self.fee_tiers.grow();
+            tier.min_amount.set(*min_amount);
+            tier.fee_bps.set(Uint::<16, 1>::from(*bps));
+        }
+
+        log(
+            self.vm(),
+            FeeTiersSet {
+                min_amounts,
+                fee_bps,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Replace the volume discounts; minimums must be strictly increasing
+    pub(crate) fn update_volume_discounts(
+        &mut self,
+        min_volumes: Vec<U256>,
+        discount_bps: Vec<u16>,
+    ) -> Result<(), Vec<u8>> {
+        if min_volumes.len() != discount_bps.len() || min_volumes.len() > MAX_FEE_TIERS {
+            return Err(ErrorCode::InvalidTiers.into());
+        }
+        for (i, min_volume) in min_volumes.iter().enumerate() {
+            if min_volume.is_zero() || (i > 0 && *min_volume <= min_volumes[i - 1]) {
+                return Err(ErrorCode::InvalidTiers.into());
+            }
+        }
+        if discount_bps.iter().any(|bps| *bps > BASIS_POINTS) {
+            return Err(ErrorCode::InvalidDiscount.into());
+        }
+
+        self.volume_discounts.truncate(0);
+        for (min_volume, bps) in min_volumes.iter().zip(&discount_bps) {
+            let mut discount = self.volume_discounts.grow();
+            discount.min_volume.set(*min_volume);
+            discount.discount_bps.set(Uint::<16, 1>::from(*bps));
+        }
+
+        log(
+            self.vm(),
+            VolumeDiscountsSet {
+                min_volumes,
+                discount_bps,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Exempt `account`'s jobs from the protocol fee, or end the exemption
+    pub(crate) fn update_fee_exemption(&mut self, account: Address, exempt: bool) {
+        self.fee_exempt.setter(account).set(exempt);
+
+        log(self.vm(), FeeExemptionSet { account, exempt });
+    }
+
+    /// Rate a job of `amount` opened by `client` would pay now: the highest
+    /// size tier it reaches, or the base protocol fee below every tier, less
+    /// the client's volume discount; zero for exempt clients
+    pub(crate) fn fee_rate(&self, client: Address, amount: U256) -> u16 {
+        if self.fee_exempt.get(client) {
+            return 0;
+        }
+        let mut fee_bps: u16 = self.protocol_fee_bps.get().to();
+        for i in 0..self.fee_tiers.len() {
+            match self.fee_tiers.get(i) {
+                Some(tier) if tier.min_amount.get() <= amount => fee_bps = tier.fee_bps.get().to(),
+                _ => break,
+            }
+        }
+        let discount_bps = self.volume_discount(client);
+        let discounted =
+            u32::from(fee_bps) * u32::from(BASIS_POINTS - discount_bps) / u32::from(BASIS_POINTS);
+        discounted as u16
+    }
+
+    /// Share of the fee, in basis points, waived for `client`'s volume: the
+    /// highest discount their lifetime released volume reaches
+    fn volume_discount(&self, client: Address) -> u16 {
+        let volume = self.client_volume.get(client);
+        let mut discount_bps = 0;
+        for i in 0..self.volume_discounts.len() {
+            match self.volume_discounts.get(i) {
+                Some(discount) if discount.min_volume.get() <= volume => {
+                    discount_bps = discount.discount_bps.get().to()
+                }
+                _ => break,
+            }
+        }
+        discount_bps
+    }
+
+    /// Add a payout of `amount` from `job_id` to its client's volume
+    pub(crate) fn record_client_volume(&mut self, job_id: U256, amount: U256) {
+        let client = self.jobs.get(job_id).client.get();
+        let mut volume = self.client_volume.setter(client);
+        let total = volume.get();
+        volume.set(total + amount);
+    }
+
+    /// Fix `job_id`'s fee rate at what `client` pays on a job of `amount` now
+    pub(crate) fn snapshot_fee(&mut self, job_id: U256, client: Address, amount: U256) {
+        let fee_bps = self.fee_rate(client, amount);
+        self.job_fee_bps
+            .setter(job_id)
+            .set(Uint::<16, 1>::from(fee_bps));
+    }
+
+    /// Protocol fee on a payout of `amount` from `job_id`
+    pub(crate) fn protocol_fee(&self, job_id: U256, amount: U256) -> U256 {
+        fee_on(amount, self.job_fee_bps.get(job_id).to())
+    }
+
+    /// Add `fee` from `job_id`'s payout to the accrued fees; the event names
+    /// the escrow itself as recipient until the fee is withdrawn
+    pub(crate) fn charge_fee(&mut self, job_id: U256, fee: U256) {
+        if fee.is_zero() {
+            return;
+        }
+        let accrued = self.accrued_fees.get();
+        self.accrued_fees.set(accrued + fee);
+        let collected = self.total_fees_collected.get();
+        self.total_fees_collected.set(collected + fee);
+        let recipient = self.vm().contract_address();
+        log(
+            self.vm(),
+            FeeCharged {
+                job_id,
+                recipient,
+                fee,
+            },
+        );
+    }
+}
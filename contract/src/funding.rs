@@ -0,0 +1,94 @@
+//! Installment funding: jobs that become active once their target is raised.
+//!
+//! A client can open a job with less than its full amount and collect the
+//! rest through any number of `fund` payments, from themselves or others. The
+//! job only becomes active, with its deadline starting, when the target is
+//! met; that is when `Deposited` is logged. If the target is not met by the
+//! cutoff, every contributor can take back exactly what they paid in.
+
+use crate::{Deposited, ErrorCode, Escrow, JobFunded};
+use alloy_primitives::{Address, Uint, B256, U256};
+use stylus_sdk::prelude::*;
+
+impl Escrow {
+    /// Whether a job has its full amount; always true for jobs funded in one
+    /// deposit
+    pub(crate) fn is_funded(&self, job_id: U256) -> bool {
+        let funding = self.fundings.get(job_id);
+        funding.raised.get() >= funding.target.get()
+    }
+
+    /// Revert while a job is still raising its target
+    pub(crate) fn assert_funded(&self, job_id: U256) -> Result<(), Vec<u8>> {
+        if !self.is_funded(job_id) {
+            return Err(ErrorCode::JobNotFunded.into());
+        }
+        Ok(())
+    }
+
+    /// Credit `amount` from `contributor` to a funding job, activating it
+    /// once the target is met
+    pub(crate) fn add_contribution(&mut self, job_id: U256, contributor: Address, amount: U256) {
+        let mut contributions = self.contributions.setter(job_id);
+        let mut contribution = contributions.setter(contributor);
+        let paid = contribution.get();
+        contribution.set(paid + amount);
+
+        let mut funding = self.fundings.setter(job_id);
+        let raised = funding.raised.get() + amount;
+        funding.raised.set(raised);
+        let target = funding.target.get();
+        let duration: u64 = funding.duration.get().to();
+
+        let mut job = self.jobs.setter(job_id);
+        let escrowed = job.amount.get();
+        job.amount.set(escrowed + amount);
+        self.lock_escrow(job_id, amount);
+
+        let total = self.total_deposited.get();
+        self.total_deposited.set(total + amount);
+        let mut stats = self.user_stats.setter(contributor);
+        let deposited = stats.total_deposited.get();
+        stats.total_deposited.set(deposited + amount);
+        self.record_period_deposit(false, amount);
+
+        log(
+            self.vm(),
+            JobFunded {
+                job_id,
+                contributor,
+                amount,
+                raised,
+            },
+        );
+
+        if raised == target {
+            self.activate_job(job_id, target, duration);
+        }
+    }
+
+    /// Start a fully funded job's deadline and announce it like any deposit
+    fn activate_job(&mut self, job_id: U256, amount: U256, duration: u64) {
+        let deadline = self.vm().block_timestamp().saturating_add(duration);
+        let mut job = self.jobs.setter(job_id);
+        job.deadline.set(Uint::<64, 1>::from(deadline));
+        let client = job.client.get();
+        let freelancer = job.freelancer.get();
+        let category = job.category.get().to();
+        let tag = job.tag.get();
+        self.record_assignment(freelancer);
+
+        log(
+            self.vm(),
+            Deposited {
+                job_id,
+                client,
+                freelancer,
+                amount,
+                memo: B256::ZERO,
+                category,
+                tag,
+            },
+        );
+    }
+}
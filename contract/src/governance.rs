@@ -0,0 +1,378 @@
+//! Governance of protocol parameters.
+//!
+//! Until the admin configures governance, protocol parameters are set by the
+//! admin (and the fee manager) directly. Once a council or a voting token is
+//! configured, the protocol fee, default job cap, arbitrator, ruling period,
+//! dispute timeout, dormancy period, emergency refund root and treasury can
+//! only change through proposals, as can the fee, deposit-limit, jury and
+//! arbitrator-registry settings (`GOVERNANCE_ACTION_SETTING`, with the
+//! setting's arguments as the proposal's data): voters back or oppose a proposal for
+//! `GOVERNANCE_VOTING_PERIOD`, and one that reached the quorum with more
+//! support than opposition can be executed by anyone `GOVERNANCE_TIMELOCK`
+//! after voting ends, for `GOVERNANCE_EXECUTION_WINDOW`.
+//!
+//! A council member has one vote. With a token, voting power is the voter's
+//! delegated votes (`getPastVotes`) as of the block before the proposal, so
+//! tokens moved after it was made can't vote twice. Council proposals die
+//! with the council they were made under.
+//!
+//! Governance is also the last instance for disputes: an appealed ruling
+//! becomes a proposal to settle the job at the appellant's split instead,
+//! voted on and executed like any other (see `appeals`). It can likewise
+//! replace an arbitrator who let a dispute's ruling period pass without
+//! ruling (see `arbitrators`).
+
+use crate::{
+    ArbitrationPolicySet, DefaultJobCapSet, DisputeTimeoutPolicySet, DormancyPolicySet, ErrorCode,
+    Escrow, GovernanceProposed, GovernanceSet, ProtocolFeeSet, BASIS_POINTS,
+    GOVERNANCE_ACTION_ADD_COUNCIL_MEMBER, GOVERNANCE_ACTION_APPEAL, GOVERNANCE_ACTION_ARBITRATOR,
+    GOVERNANCE_ACTION_DEFAULT_JOB_CAP, GOVERNANCE_ACTION_DISPUTE_TIMEOUT,
+    GOVERNANCE_ACTION_DORMANCY_PERIOD, GOVERNANCE_ACTION_PROTOCOL_FEE, GOVERNANCE_ACTION_QUORUM,
+    GOVERNANCE_ACTION_REFUND_ROOT, GOVERNANCE_ACTION_REMOVE_COUNCIL_MEMBER,
+    GOVERNANCE_ACTION_REPLACE_ARBITRATOR, GOVERNANCE_ACTION_RULING_PERIOD,
+    GOVERNANCE_ACTION_SETTING, GOVERNANCE_ACTION_TREASURY, GOVERNANCE_EXECUTION_WINDOW,
+    GOVERNANCE_TIMELOCK, GOVERNANCE_VOTING_PERIOD, MAX_COUNCIL, MAX_PROTOCOL_FEE_BPS,
+    MIN_DORMANCY_PERIOD,
+};
+use alloy_primitives::{Address, Uint, B256, U256};
+use alloy_sol_types::{sol, SolCall};
+use stylus_sdk::prelude::*;
+use stylus_sdk::stylus_core::calls::context::Call;
+
+sol! {
+    function getPastVotes(address account, uint256 timepoint) external view returns (uint256);
+}
+
+impl Escrow {
+    /// Whether protocol parameters are governed by proposals
+    pub(crate) fn is_governed(&self) -> bool {
+        !self.governance_quorum.get().is_zero()
+    }
+
+    /// Revert if governance must change this parameter instead of the admin
+    pub(crate) fn assert_not_governed(&self) -> Result<(), Vec<u8>> {
+        if self.is_governed() {
+            return Err(ErrorCode::GovernedParameter.into());
+        }
+        Ok(())
+    }
+
+    /// Current council members
+    pub(crate) fn council_members(&self) -> Vec<Address> {
+        let mut council = Vec::with_capacity(self.council.len());
+        for i in 0..self.council.len() {
+            if let Some(member) = self.council.get(i) {
+                council.push(member);
+            }
+        }
+        council
+    }
+
+    /// Swap the council, invalidating every proposal made under the old one
+    pub(crate) fn replace_council(&mut self, council: &[Address]) {
+        for member in self.council_members() {
+            self.is_council.setter(member).set(false);
+        }
+        self.council.truncate(0);
+        for member in council {
+            self.council.push(*member);
+            self.is_council.setter(*member).set(true);
+        }
+        let epoch = self.council_epoch.get();
+        self.council_epoch.set(epoch + Uint::<64, 1>::from(1));
+    }
+
+    /// Votes `voter` holds on a proposal made at `snapshot`: one per council
+    /// member, or their token votes as of that block
+    pub(crate) fn voting_weight(&self, voter: Address, snapshot: u64) -> Result<U256, Vec<u8>> {
+        let token = self.governance_token.get();
+        if token == Address::ZERO {
+            return Ok(if self.is_council.get(voter) { U256::from(1) } else { U256::ZERO });
+        }
+
+        let calldata = getPastVotesCall {
+            account: voter,
+            timepoint: U256::from(snapshot),
+        }
+        .abi_encode();
+        self.vm()
+            .static_call(&Call::new(), token, &calldata)
+            .ok()
+            .and_then(|output| getPastVotesCall::abi_decode_returns(&output, true).ok())
+            .map(|ret| ret._0)
+            .ok_or_else(|| ErrorCode::VotesUnavailable.into())
+    }
+
+    /// Record `proposer`'s proposal and open it for votes for
+    /// `GOVERNANCE_VOTING_PERIOD`, returning its ID
+    pub(crate) fn open_governance_proposal(
+        &mut self,
+        proposer: Address,
+        action: u8,
+        target: Address,
+        value: U256,
+        data: &[u8],
+    ) -> U256 {
+        let proposal_id = self.governance_proposal_count.get() + U256::from(1);
+        self.governance_proposal_count.set(proposal_id);
+        let snapshot = self.vm().block_number().saturating_sub(1);
+        let voting_ends = self
+            .vm()
+            .block_timestamp()
+            .saturating_add(GOVERNANCE_VOTING_PERIOD);
+        let epoch = self.council_epoch.get();
+        let mut proposal = self.governance_proposals.setter(proposal_id);
+        proposal.action.set(Uint::<8, 1>::from(action));
+        proposal.target.set(target);
+        proposal.value.set(value);
+        proposal.proposer.set(proposer);
+        proposal.snapshot.set(Uint::<64, 1>::from(snapshot));
+        proposal.council_epoch.set(epoch);
+        proposal.voting_ends.set(Uint::<64, 1>::from(voting_ends));
+        proposal.data.set_bytes(data);
+
+        log(
+            self.vm(),
+            GovernanceProposed {
+                proposal_id,
+                action,
+                target,
+                value,
+                proposer,
+                voting_ends,
+            },
+        );
+        proposal_id
+    }
+
+    /// Whether a proposal can no longer be executed: made under an earlier
+    /// council, voted down or short of the quorum, or past its execution
+    /// window
+    pub(crate) fn proposal_lapsed(&self, proposal_id: U256) -> bool {
+        let proposal = self.governance_proposals.get(proposal_id);
+        if proposal.executed.get() {
+            return false;
+        }
+        if proposal.council_epoch.get() != self.council_epoch.get() {
+            return true;
+        }
+        let voting_ends: u64 = proposal.voting_ends.get().to();
+        let now = self.vm().block_timestamp();
+        if now < voting_ends {
+            return false;
+        }
+        let votes_for = proposal.votes_for.get();
+        let passed =
+            votes_for >= self.governance_quorum.get() && votes_for > proposal.votes_against.get();
+        let expires_at = voting_ends
+            .saturating_add(GOVERNANCE_TIMELOCK)
+            .saturating_add(GOVERNANCE_EXECUTION_WINDOW);
+        !passed || now >= expires_at
+    }
+
+    /// Carry out a passed governance proposal
+    pub(crate) fn execute_governance_action(
+        &mut self,
+        action: u8,
+        target: Address,
+        value: U256,
+        data: &[u8],
+    ) -> Result<(), Vec<u8>> {
+        match action {
+            GOVERNANCE_ACTION_PROTOCOL_FEE => {
+                let fee_bps = u16::try_from(value).map_err(|_| ErrorCode::InvalidFee)?;
+                self.update_protocol_fee(fee_bps)
+            }
+            GOVERNANCE_ACTION_DEFAULT_JOB_CAP => {
+                let cap = u32::try_from(value).map_err(|_| ErrorCode::InvalidCap)?;
+                self.update_default_job_cap(cap);
+                Ok(())
+            }
+            GOVERNANCE_ACTION_ARBITRATOR => self.update_arbitration_policy(
+                target,
+                self.ruling_period.get().to(),
+                self.default_split_bps.get().to(),
+            ),
+            GOVERNANCE_ACTION_RULING_PERIOD => {
+                let ruling_period = u64::try_from(value)
+                    .map_err(|_| ErrorCode::InvalidRulingPeriod)?;
+                self.update_arbitration_policy(
+                    self.arbitrator.get(),
+                    ruling_period,
+                    self.default_split_bps.get().to(),
+                )
+            }
+            GOVERNANCE_ACTION_DISPUTE_TIMEOUT => {
+                let timeout =
+                    u64::try_from(value).map_err(|_| ErrorCode::InvalidTimeout)?;
+                self.update_dispute_timeout_policy(
+                    timeout,
+                    self.timeout_split.get(),
+                    self.timeout_split_bps.get().to(),
+                )
+            }
+            GOVERNANCE_ACTION_DORMANCY_PERIOD => {
+                let period = u64::try_from(value)
+                    .map_err(|_| ErrorCode::DormancyPeriodTooShort)?;
+                self.update_dormancy_policy(period, self.recovery_address.get())
+            }
+            GOVERNANCE_ACTION_ADD_COUNCIL_MEMBER => {
+                let mut council = self.council_members();
+                if target == Address::ZERO || council.contains(&target) {
+                    return Err(ErrorCode::InvalidCouncilMember.into());
+                }
+                if council.len() >= MAX_COUNCIL {
+                    return Err(ErrorCode::CouncilTooLarge.into());
+                }
+                council.push(target);
+                self.replace_council(&council);
+                self.log_governance();
+                Ok(())
+            }
+            GOVERNANCE_ACTION_REMOVE_COUNCIL_MEMBER => {
+                let mut council = self.council_members();
+                if !council.contains(&target) {
+                    return Err(ErrorCode::InvalidCouncilMember.into());
+                }
+                council.retain(|member| *member != target);
+                if self.governance_quorum.get() > U256::from(council.len()) {
+                    return Err(ErrorCode::InvalidQuorum.into());
+                }
+                self.replace_council(&council);
+                self.log_governance();
+                Ok(())
+            }
+            GOVERNANCE_ACTION_QUORUM => {
+                let council_mode = self.governance_token.get() == Address::ZERO;
+                if value.is_zero() || (council_mode && value > U256::from(self.council.len())) {
+                    return Err(ErrorCode::InvalidQuorum.into());
+                }
+                self.governance_quorum.set(value);
+                self.log_governance();
+                Ok(())
+            }
+            GOVERNANCE_ACTION_REFUND_ROOT => {
+                self.update_refund_root(B256::from(value));
+                Ok(())
+            }
+            GOVERNANCE_ACTION_APPEAL => self.uphold_appeal(value),
+            GOVERNANCE_ACTION_REPLACE_ARBITRATOR => self.replace_arbitrator(value, target),
+            GOVERNANCE_ACTION_SETTING => {
+                let setting = u8::try_from(value).map_err(|_| ErrorCode::InvalidSetting)?;
+                self.apply_governed_setting(setting, data)
+            }
+            GOVERNANCE_ACTION_TREASURY => self.update_pending_treasury(target),
+            _ => Err(ErrorCode::InvalidAction.into()),
+        }
+    }
+
+    /// Emit the current governance configuration
+    pub(crate) fn log_governance(&self) {
+        log(
+            self.vm(),
+            GovernanceSet {
+                token: self.governance_token.get(),
+                council: self.council_members(),
+                quorum: self.governance_quorum.get(),
+            },
+        );
+    }
+
+    /// Set the protocol fee charged on payouts, in basis points
+    pub(crate) fn update_protocol_fee(&mut self, fee_bps: u16) -> Result<(), Vec<u8>> {
+        if fee_bps > MAX_PROTOCOL_FEE_BPS {
+            return Err(ErrorCode::InvalidFee.into());
+        }
+        self.protocol_fee_bps.set(Uint::<16, 1>::from(fee_bps));
+
+        log(self.vm(), ProtocolFeeSet { fee_bps });
+
+        Ok(())
+    }
+
+    /// Set how many active jobs a freelancer may hold at once; 0 for no limit
+    pub(crate) fn update_default_job_cap(&mut self, cap: u32) {
+        self.default_job_cap.set(Uint::<32, 1>::from(cap));
+
+        log(self.vm(), DefaultJobCapSet { cap });
+    }
+
+    /// Set the arbitrator, ruling period and default split for new disputes
+    pub(crate) fn update_arbitration_policy(
+        &mut self,
+        arbitrator: Address,
+        ruling_period: u64,
+        default_split_bps: u16,
+    ) -> Result<(), Vec<u8>> {
+        if arbitrator != Address::ZERO && ruling_period == 0 {
+            return Err(ErrorCode::InvalidRulingPeriod.into());
+        }
+        if default_split_bps > BASIS_POINTS {
+            return Err(ErrorCode::InvalidSplit.into());
+        }
+
+        self.arbitrator.set(arbitrator);
+        self.ruling_period.set(Uint::<64, 1>::from(ruling_period));
+        self.default_split_bps.set(Uint::<16, 1>::from(default_split_bps));
+
+        log(
+            self.vm(),
+            ArbitrationPolicySet {
+                arbitrator,
+                ruling_period,
+                default_split_bps,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Set how long new disputes may stay unresolved and what happens after
+    pub(crate) fn update_dispute_timeout_policy(
+        &mut self,
+        timeout: u64,
+        split: bool,
+        split_bps: u16,
+    ) -> Result<(), Vec<u8>> {
+        if split_bps > BASIS_POINTS {
+            return Err(ErrorCode::InvalidSplit.into());
+        }
+
+        self.dispute_timeout.set(Uint::<64, 1>::from(timeout));
+        self.timeout_split.set(split);
+        self.timeout_split_bps.set(Uint::<16, 1>::from(split_bps));
+
+        log(
+            self.vm(),
+            DisputeTimeoutPolicySet {
+                timeout,
+                split,
+                split_bps,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Set how long past their deadline jobs may sit before being swept to
+    /// `recovery`
+    pub(crate) fn update_dormancy_policy(
+        &mut self,
+        period: u64,
+        recovery: Address,
+    ) -> Result<(), Vec<u8>> {
+        if period < MIN_DORMANCY_PERIOD {
+            return Err(ErrorCode::DormancyPeriodTooShort.into());
+        }
+        if recovery == Address::ZERO {
+            return Err(ErrorCode::InvalidRecoveryAddress.into());
+        }
+
+        self.dormancy_period.set(Uint::<64, 1>::from(period));
+        self.recovery_address.set(recovery);
+
+        log(self.vm(), DormancyPolicySet { period, recovery });
+
+        Ok(())
+    }
+}
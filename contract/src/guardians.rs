@@ -0,0 +1,216 @@
+//! Guardian quorum for emergency actions.
+//!
+//! Without guardians the admin handles emergencies alone, as before. Once the
+//! admin appoints a guardian set with a threshold, emergency refunds and
+//! surplus sweeps need `threshold` guardians to confirm a proposal, and an
+//! admin pause lapses after `MAX_ADMIN_PAUSE` unless the guardians vote to
+//! extend it. A proposal executes on the confirmation that reaches the
+//! threshold; it expires `EMERGENCY_PROPOSAL_TTL` after it was made and dies
+//! with the guardian set it was made under.
+//!
+//! However it is approved, an emergency refund waits until the job is
+//! `EMERGENCY_REFUND_MIN_AGE` old or `EMERGENCY_REFUND_GRACE` past its
+//! deadline, so it can't preempt a freelancer about to deliver.
+
+use crate::{
+    EmergencyRefunded, ErrorCode, Escrow, PauseExtended, SurplusSwept,
+    EMERGENCY_ACTION_EXTEND_PAUSE, EMERGENCY_ACTION_REFUND, EMERGENCY_ACTION_SWEEP_SURPLUS,
+    EMERGENCY_REFUND_GRACE, EMERGENCY_REFUND_MIN_AGE, MAX_ADMIN_PAUSE,
+};
+use alloy_primitives::{Address, Uint, U256};
+use stylus_sdk::prelude::*;
+
+impl Escrow {
+    /// Whether emergency actions need a guardian quorum
+    pub(crate) fn has_guardian_quorum(&self) -> bool {
+        self.guardian_threshold.get() != Uint::<8, 1>::ZERO
+    }
+
+    /// Revert if a guardian quorum must approve this action instead of the admin
+    pub(crate) fn assert_no_guardian_quorum(&self) -> Result<(), Vec<u8>> {
+        if self.has_guardian_quorum() {
+            return Err(ErrorCode::GuardianQuorumRequired.into());
+        }
+        Ok(())
+    }
+
+    /// Revert unless the caller is a current guardian
+    pub(crate) fn assert_guardian(&self) -> Result<(), Vec<u8>> {
+        if !self.is_guardian.get(self.vm().msg_sender()) {
+            return Err(ErrorCode::OnlyGuardian.into());
+        }
+        Ok(())
+    }
+
+    /// Longest a new pause may last, 0 for no limit: the configured maximum,
+    /// capped at `MAX_ADMIN_PAUSE` once guardians are appointed
+    pub(crate) fn pause_limit(&self) -> u64 {
+        let max_pause: u64 = self.max_pause.get().to();
+        if self.has_guardian_quorum() && (max_pause == 0 || max_pause > MAX_ADMIN_PAUSE) {
+            MAX_ADMIN_PAUSE
+        } else {
+            max_pause
+        }
+    }
+
+    /// Whether the escrow is paused right now; a pause bounded by the guardian
+    /// rules or the maximum pause ends on its own at `pause_until`
+    pub(crate) fn paused_now(&self) -> bool {
+        let until: u64 = self.pause_until.get().to();
+        self.paused.get() && (until == 0 || self.vm().block_timestamp() < until)
+    }
+
+    /// Swap the guardian set, invalidating every proposal made under the old one
+    pub(crate) fn replace_guardians(&mut self, guardians: &[Address], threshold: u8) {
+        for i in 0..self.guardians.len() {
+            if let Some(guardian) = self.guardians.get(i) {
+                self.is_guardian.setter(guardian).set(false);
+            }
+        }
+        self.guardians.truncate(0);
+        for guardian in guardians {
+            self.guardians.push(*guardian);
+            self.is_guardian.setter(*guardian).set(true);
+        }
+        self.guardian_threshold.set(Uint::<8, 1>::from(threshold));
+        let epoch = self.guardian_epoch.get();
+        self.guardian_epoch.set(epoch + Uint::<64, 1>::from(1));
+    }
+
+    /// Carry out an approved emergency action
+    pub(crate) fn execute_emergency(&mut self, action: u8, target: U256) -> Result<(), Vec<u8>> {
+        match action {
+            EMERGENCY_ACTION_REFUND => self.refund_emergency(target),
+            EMERGENCY_ACTION_SWEEP_SURPLUS => self.sweep_surplus_now(),
+            EMERGENCY_ACTION_EXTEND_PAUSE => {
+                let until = u64::try_from(target).map_err(|_| ErrorCode::InvalidPauseEnd)?;
+                self.extend_pause(until)
+            }
+            _ => Err(ErrorCode::InvalidAction.into()),
+        }
+    }
+
+    /// Refund an unsettled, funded job to its client outside the normal rules
+    pub(crate) fn refund_emergency(&mut self, job_id: U256) -> Result<(), Vec<u8>> {
+        self.assert_emergency_refundable(job_id)?;
+        self.pay_emergency_refund(job_id)?;
+        #[cfg(feature = "solvency-check")]
+        self.assert_solvent()?;
+
+        Ok(())
+    }
+
+    /// Revert unless `job_id` is unsettled, funded and old enough to be
+    /// emergency refunded
+    pub(crate) fn assert_emergency_refundable(&self, job_id: U256) -> Result<(), Vec<u8>> {
+        self.assert_job_exists(job_id)?;
+        let job = self.jobs.get(job_id);
+        if job.released.get() || job.refunded.get() {
+            return Err(ErrorCode::JobAlreadySettled.into());
+        }
+        if self.finalized.get(job_id) {
+            return Err(ErrorCode::JobAlreadyFinalized.into());
+        }
+        self.assert_funded(job_id)?;
+        self.assert_not_staked(job_id)?;
+        let created_at: u64 = job.created_at.get().to();
+        let deadline: u64 = job.deadline.get().to();
+        let now = self.vm().block_timestamp();
+        if now < created_at.saturating_add(EMERGENCY_REFUND_MIN_AGE)
+            && now < deadline.saturating_add(EMERGENCY_REFUND_GRACE)
+        {
+            return Err(ErrorCode::JobTooRecent.into());
+        }
+        Ok(())
+    }
+
+    /// Refund `job_id` to its client in full; it must already have been
+    /// checked with `assert_emergency_refundable`
+    pub(crate) fn pay_emergency_refund(&mut self, job_id: U256) -> Result<(), Vec<u8>> {
+        let (client, amount) = self.settle_emergency_refund(job_id);
+
+        self.vm().transfer_eth(client, amount)?;
+        self.pay_basket(job_id, 0)?;
+
+        self.log_emergency_refund(job_id, amount);
+        Ok(())
+    }
+
+    /// Refund `job_id` as one item of a batch without reverting it: false if
+    /// the job can't be refunded, or holds a token basket whose transfers
+    /// could fail; a client that refuses the ETH is credited it instead
+    pub(crate) fn try_emergency_refund(&mut self, job_id: U256) -> bool {
+        if self.assert_emergency_refundable(job_id).is_err() || !self.baskets.get(job_id).is_empty()
+        {
+            return false;
+        }
+        let (client, amount) = self.settle_emergency_refund(job_id);
+
+        if self.vm().transfer_eth(client, amount).is_err() {
+            self.credit(client, amount);
+        }
+
+        self.log_emergency_refund(job_id, amount);
+        true
+    }
+
+    /// Mark `job_id` refunded in full and close it, returning its client and
+    /// the amount they are owed
+    fn settle_emergency_refund(&mut self, job_id: U256) -> (Address, U256) {
+        let job = self.jobs.get(job_id);
+        let amount = job.amount.get();
+        let client = job.client.get();
+
+        self.jobs.setter(job_id).refunded.set(true);
+        self.close_job(job_id);
+        self.record_refund(job_id, client, amount);
+        (client, amount)
+    }
+
+    /// Emit `EmergencyRefunded` for `job_id` and notify its settlement hook
+    fn log_emergency_refund(&mut self, job_id: U256, amount: U256) {
+        log(
+            self.vm(),
+            EmergencyRefunded {
+                job_id,
+                admin: self.vm().msg_sender(),
+            },
+        );
+        self.call_settlement_hook(job_id, amount, false);
+    }
+
+    /// Send whatever the balance holds beyond open jobs, credit and accrued
+    /// fees to the treasury (the admin if none is set)
+    pub(crate) fn sweep_surplus_now(&mut self) -> Result<(), Vec<u8>> {
+        let amount = self.surplus().saturating_sub(self.accrued_fees.get());
+        if amount == U256::ZERO {
+            return Err(ErrorCode::NoSurplus.into());
+        }
+        let to = self.revenue_recipient(self.admin.get());
+
+        self.vm().transfer_eth(to, amount)?;
+
+        log(self.vm(), SurplusSwept { to, amount });
+        #[cfg(feature = "solvency-check")]
+        self.assert_solvent()?;
+
+        Ok(())
+    }
+
+    /// Keep a running pause in force until `until`
+    fn extend_pause(&mut self, until: u64) -> Result<(), Vec<u8>> {
+        if !self.paused_now() {
+            return Err(ErrorCode::EscrowIsNotPaused.into());
+        }
+        let current: u64 = self.pause_until.get().to();
+        if current == 0 || until <= current {
+            return Err(ErrorCode::InvalidPauseEnd.into());
+        }
+
+        self.pause_until.set(Uint::<64, 1>::from(until));
+
+        log(self.vm(), PauseExtended { until });
+
+        Ok(())
+    }
+}
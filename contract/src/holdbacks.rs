@@ -0,0 +1,78 @@
+//! Retainage: part of a job held back through a warranty window.
+//!
+//! A client can open a job with a holdback, a share of it in basis points
+//! that stays escrowed for `warranty` seconds after the job is released.
+//! Releasing the job, or the freelancer's `auto_release`, pays out the rest
+//! and starts the window; the job stays open with only the holdback left.
+//! During the window the client can raise a defect with `open_dispute`,
+//! which freezes the holdback until the dispute settles it. Once the window
+//! ends undisputed, anyone can release the holdback to the freelancer. The
+//! client can't refund or reclaim a holdback that has been retained.
+
+use crate::fees::fee_on;
+use crate::{ErrorCode, Escrow, HoldbackRetained, PartiallyReleased};
+use alloy_primitives::{Uint, U256};
+use stylus_sdk::prelude::*;
+
+impl Escrow {
+    /// Share of `amount` to hold back if `job_id` were released now; zero
+    /// once its warranty has started or if it has no holdback
+    pub(crate) fn pending_holdback(&self, job_id: U256, amount: U256) -> U256 {
+        let holdback = self.holdbacks.get(job_id);
+        if holdback.warranty_ends.get() != Uint::<64, 1>::ZERO {
+            return U256::ZERO;
+        }
+        fee_on(amount, holdback.bps.get().to())
+    }
+
+    /// Whether `job_id` has been released down to its holdback
+    pub(crate) fn is_retained(&self, job_id: U256) -> bool {
+        self.holdbacks.get(job_id).warranty_ends.get() != Uint::<64, 1>::ZERO
+    }
+
+    /// Revert once `job_id` holds only its holdback
+    pub(crate) fn assert_not_retained(&self, job_id: U256) -> Result<(), Vec<u8>> {
+        if self.is_retained(job_id) {
+            return Err(ErrorCode::HoldbackRetained.into());
+        }
+        Ok(())
+    }
+
+    /// Pay all but `held` of `job_id` to its freelancer and start the
+    /// warranty window on what is left; compliance must already have been
+    /// checked
+    pub(crate) fn retain_holdback(&mut self, job_id: U256, held: U256) -> Result<(), Vec<u8>> {
+        let job = self.jobs.get(job_id);
+        let freelancer = job.freelancer.get();
+        let amount = job.amount.get() - held;
+        let warranty: u64 = self.holdbacks.get(job_id).warranty.get().to();
+        let release_at = self.vm().block_timestamp().saturating_add(warranty);
+
+        self.jobs.setter(job_id).amount.set(held);
+        self.holdbacks
+            .setter(job_id)
+            .warranty_ends
+            .set(Uint::<64, 1>::from(release_at));
+        self.record_release(job_id, freelancer, amount);
+
+        self.pay_release(job_id, freelancer, amount)?;
+
+        log(
+            self.vm(),
+            PartiallyReleased {
+                job_id,
+                amount,
+                remaining: held,
+            },
+        );
+        log(
+            self.vm(),
+            HoldbackRetained {
+                job_id,
+                amount: held,
+                release_at,
+            },
+        );
+        Ok(())
+    }
+}
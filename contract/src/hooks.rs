@@ -0,0 +1,39 @@
+//! Settlement callbacks for integrators.
+//!
+//! A job's client can attach a hook contract that is told when the job
+//! settles, so a marketplace can run its own bookkeeping in the same
+//! transaction. The hook runs after the escrow's own state and payouts are
+//! final, with a fixed gas budget; if it reverts or runs out of gas the
+//! settlement still goes through and `SettlementHookFailed` is logged.
+
+use crate::{Escrow, SettlementHookFailed, SETTLEMENT_HOOK_GAS};
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::{sol, SolCall};
+use stylus_sdk::prelude::*;
+use stylus_sdk::stylus_core::calls::context::Call;
+
+sol! {
+    function onReleased(uint256 job_id, uint256 amount) external;
+    function onRefunded(uint256 job_id, uint256 amount) external;
+}
+
+impl Escrow {
+    /// Tell the job's settlement hook, if any, that `amount` was released to
+    /// the freelancer (`released`) or refunded to the client
+    pub(crate) fn call_settlement_hook(&mut self, job_id: U256, amount: U256, released: bool) {
+        let hook = self.settlement_hooks.get(job_id);
+        if hook == Address::ZERO {
+            return;
+        }
+
+        let calldata = if released {
+            onReleasedCall { job_id, amount }.abi_encode()
+        } else {
+            onRefundedCall { job_id, amount }.abi_encode()
+        };
+        let context = Call::new().gas(SETTLEMENT_HOOK_GAS);
+        if self.vm().call(&context, hook, &calldata).is_err() {
+            log(self.vm(), SettlementHookFailed { job_id, hook });
+        }
+    }
+}
@@ -0,0 +1,317 @@
+//! Juror panels.
+//!
+//! For disputes too contentious for a single arbitrator, either party can
+//! put the dispute to a jury instead. Anyone can join the juror registry by
+//! staking at least `min_juror_stake`; escalating a dispute draws the
+//! policy's panel from it, skipping the job's parties, its arbitrator and
+//! jurors whose stake has fallen below the minimum. While the jury sits, the
+//! arbitrator can no longer rule and the default split can't be applied;
+//! the dispute's timeout still can.
+//!
+//! Each juror votes the share of the job the freelancer should get before
+//! the voting window closes. Once it has, or every juror has voted, anyone
+//! tallies the jury: a split a strict majority of the votes cast agrees on
+//! settles the dispute as a ruling would, and every juror who voted
+//! otherwise or not at all loses `juror_slash_bps` of their stake to the
+//! majority. Without a majority the dispute settles at its default split
+//! and nobody is slashed. A juror can leave with their stake once they sit
+//! on no untallied jury.
+//!
+//! Panels are drawn from block data, which the sequencer can influence, so
+//! the draw deters a party from picking its jurors rather than ruling it
+//! out.
+
+use crate::{
+    ErrorCode, Escrow, JurorLeft, JurorStaked, JuryDrawn, JuryPolicySet, JuryVoted, BASIS_POINTS,
+    MAX_JURY_SIZE,
+};
+use alloy_primitives::{keccak256, Address, Uint, U256};
+use alloy_sol_types::SolValue;
+use stylus_sdk::prelude::*;
+
+impl Escrow {
+    /// Set the jury new escalations draw: `size` jurors (odd, at most
+    /// `MAX_JURY_SIZE`; 0 turns juries off), voting for `voting_period`
+    /// seconds, each staked at least `min_stake` and losing `slash_bps` of
+    /// it for voting against the majority
+    pub(crate) fn update_jury_policy(
+        &mut self,
+        size: u8,
+        voting_period: u64,
+        min_stake: U256,
+        slash_bps: u16,
+    ) -> Result<(), Vec<u8>> {
+        if size > MAX_JURY_SIZE || (size != 0 && size % 2 == 0) {
+            return Err(ErrorCode::InvalidJurySize.into());
+        }
+        if size != 0 && voting_period == 0 {
+            return Err(ErrorCode::InvalidVotingPeriod.into());
+        }
+        if slash_bps > BASIS_POINTS {
+            return Err(ErrorCode::InvalidSlash.into());
+        }
+
+        self.jury_size.set(Uint::<8, 1>::from(size));
+        self.jury_voting_period
+            .set(Uint::<64, 1>::from(voting_period));
+        self.min_juror_stake.set(min_stake);
+        self.juror_slash_bps.set(Uint::<16, 1>::from(slash_bps));
+
+        log(
+            self.vm(),
+            JuryPolicySet {
+                size,
+                voting_period,
+                min_stake,
+                slash_bps,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Add `amount` to `juror`'s stake, entering them in the registry
+    pub(crate) fn stake_juror(&mut self, juror: Address, amount: U256) -> Result<(), Vec<u8>> {
+        let stake = self.juror_records.get(juror).stake.get() + amount;
+        if stake < self.min_juror_stake.get() {
+            return Err(ErrorCode::StakeBelowMinimum.into());
+        }
+
+        self.juror_records.setter(juror).stake.set(stake);
+        if self.juror_records.get(juror).index.get().is_zero() {
+            self.jurors.push(juror);
+            let index = self.jurors.len() as u32;
+            self.juror_records
+                .setter(juror)
+                .index
+                .set(Uint::<32, 1>::from(index));
+        }
+        let total = self.total_juror_stakes.get();
+        self.total_juror_stakes.set(total + amount);
+
+        log(
+            self.vm(),
+            JurorStaked {
+                juror,
+                amount,
+                stake,
+            },
+        );
+        Ok(())
+    }
+
+    /// Take `juror` out of the registry and pay them their stake
+    pub(crate) fn unstake_juror(&mut self, juror: Address) -> Result<(), Vec<u8>> {
+        let record = self.juror_records.get(juror);
+        let stake = record.stake.get();
+        if record.index.get().is_zero() {
+            return Err(ErrorCode::NotAJuror.into());
+        }
+        if !record.panels.get().is_zero() {
+            return Err(ErrorCode::JurorServing.into());
+        }
+
+        // Move the last juror into the leaver's slot
+        let index: usize = record.index.get().to::<u32>() as usize;
+        let last = self.jurors.len();
+        if index != last {
+            let moved = self.jurors.get(last - 1).unwrap_or_default();
+            if let Some(mut slot) = self.jurors.setter(index - 1) {
+                slot.set(moved);
+            }
+            self.juror_records
+                .setter(moved)
+                .index
+                .set(Uint::<32, 1>::from(index as u32));
+        }
+        self.jurors.pop();
+        let mut record = self.juror_records.setter(juror);
+        record.index.set(Uint::<32, 1>::ZERO);
+        record.stake.set(U256::ZERO);
+        let total = self.total_juror_stakes.get();
+        self.total_juror_stakes.set(total - stake);
+
+        self.vm().transfer_eth(juror, stake)?;
+
+        log(
+            self.vm(),
+            JurorLeft {
+                juror,
+                amount: stake,
+            },
+        );
+        Ok(())
+    }
+
+    /// Draw the policy's panel for `job_id`'s dispute: each seat starts at a
+    /// pseudo-random place in the registry and takes the first eligible
+    /// juror from there
+    pub(crate) fn draw_jury(&self, job_id: U256) -> Result<Vec<Address>, Vec<u8>> {
+        let size = self.jury_size.get().to::<u8>() as usize;
+        let pool = self.jurors.len();
+        if pool == 0 {
+            return Err(ErrorCode::NotEnoughJurors.into());
+        }
+        let job = self.jobs.get(job_id);
+        let excluded = [
+            job.client.get(),
+            job.freelancer.get(),
+            self.disputes.get(job_id).arbitrator.get(),
+        ];
+        let min_stake = self.min_juror_stake.get();
+        let seed = keccak256(
+            (
+                job_id,
+                U256::from(self.vm().block_number()),
+                U256::from(self.vm().block_timestamp()),
+            )
+                .abi_encode(),
+        );
+
+        let mut panel: Vec<Address> = Vec::with_capacity(size);
+        for seat in 0..size {
+            let draw = U256::from_be_bytes(keccak256((seed, U256::from(seat)).abi_encode()).0);
+            let start: usize = (draw % U256::from(pool)).to();
+            let juror = (0..pool)
+                .filter_map(|k| self.jurors.get((start + k) % pool))
+                .find(|juror| {
+                    !excluded.contains(juror)
+                        && !panel.contains(juror)
+                        && self.juror_records.get(*juror).stake.get() >= min_stake
+                })
+                .ok_or(ErrorCode::NotEnoughJurors)?;
+            panel.push(juror);
+        }
+        Ok(panel)
+    }
+
+    /// Seat `panel` as `job_id`'s jury, voting for the policy's period
+    pub(crate) fn seat_jury(&mut self, job_id: U256, panel: &[Address]) {
+        let period: u64 = self.jury_voting_period.get().to();
+        let vote_by = self.vm().block_timestamp().saturating_add(period);
+        let mut jury = self.juries.setter(job_id);
+        jury.vote_by.set(Uint::<64, 1>::from(vote_by));
+        for juror in panel {
+            jury.panel.push(*juror);
+            jury.votes.setter(*juror).seated.set(true);
+        }
+        for juror in panel {
+            let mut record = self.juror_records.setter(*juror);
+            let panels = record.panels.get();
+            record.panels.set(panels + Uint::<32, 1>::from(1));
+        }
+
+        log(
+            self.vm(),
+            JuryDrawn {
+                job_id,
+                panel: panel.to_vec(),
+                vote_by,
+            },
+        );
+    }
+
+    /// Whether `job_id` has a jury that has not been tallied yet
+    pub(crate) fn jury_sitting(&self, job_id: U256) -> bool {
+        let jury = self.juries.get(job_id);
+        !jury.vote_by.get().is_zero() && !jury.tallied.get()
+    }
+
+    /// Revert while `job_id`'s dispute is before a jury
+    pub(crate) fn assert_no_jury(&self, job_id: U256) -> Result<(), Vec<u8>> {
+        if self.jury_sitting(job_id) {
+            return Err(ErrorCode::DisputeBeforeJury.into());
+        }
+        Ok(())
+    }
+
+    /// Record `juror`'s vote to give the freelancer `freelancer_bps` of
+    /// `job_id`
+    pub(crate) fn record_jury_vote(&mut self, job_id: U256, juror: Address, freelancer_bps: u16) {
+        let mut jury = self.juries.setter(job_id);
+        let mut vote = jury.votes.setter(juror);
+        vote.voted.set(true);
+        vote.freelancer_bps.set(Uint::<16, 1>::from(freelancer_bps));
+        let cast = jury.votes_cast.get();
+        jury.votes_cast.set(cast + Uint::<8, 1>::from(1));
+
+        log(
+            self.vm(),
+            JuryVoted {
+                job_id,
+                juror,
+                freelancer_bps,
+            },
+        );
+    }
+
+    /// Jurors seated on `job_id`'s jury
+    pub(crate) fn jury_panel(&self, job_id: U256) -> Vec<Address> {
+        let jury = self.juries.get(job_id);
+        (0..jury.panel.len())
+            .filter_map(|i| jury.panel.get(i))
+            .collect()
+    }
+
+    /// The split a strict majority of `job_id`'s votes cast agrees on, if
+    /// any
+    pub(crate) fn jury_verdict(&self, job_id: U256) -> Option<u16> {
+        let jury = self.juries.get(job_id);
+        let votes: Vec<u16> = self
+            .jury_panel(job_id)
+            .into_iter()
+            .map(|juror| jury.votes.get(juror))
+            .filter(|vote| vote.voted.get())
+            .map(|vote| vote.freelancer_bps.get().to())
+            .collect();
+        votes
+            .iter()
+            .copied()
+            .find(|bps| votes.iter().filter(|vote| *vote == bps).count() * 2 > votes.len())
+    }
+
+    /// Dismiss `job_id`'s jury. Given a `verdict`, every juror who didn't
+    /// vote for it forfeits `juror_slash_bps` of their stake, shared evenly
+    /// among those who did; returns the total forfeited.
+    pub(crate) fn dismiss_jury(&mut self, job_id: U256, verdict: Option<u16>) -> U256 {
+        let slash_bps: u16 = self.juror_slash_bps.get().to();
+        let mut majority = Vec::new();
+        let mut slashed = U256::ZERO;
+        for juror in self.jury_panel(job_id) {
+            let jury = self.juries.get(job_id);
+            let vote = jury.votes.get(juror);
+            let voted_for = vote
+                .voted
+                .get()
+                .then(|| vote.freelancer_bps.get().to::<u16>());
+            let mut record = self.juror_records.setter(juror);
+            let panels = record.panels.get();
+            record.panels.set(panels - Uint::<32, 1>::from(1));
+            if verdict.is_none() {
+                continue;
+            }
+            if voted_for == verdict {
+                majority.push(juror);
+            } else {
+                let stake = record.stake.get();
+                let penalty = stake * U256::from(slash_bps) / U256::from(BASIS_POINTS);
+                record.stake.set(stake - penalty);
+                slashed += penalty;
+            }
+        }
+
+        // A verdict has at least one voter; rounding goes to the first
+        if !majority.is_empty() {
+            let share = slashed / U256::from(majority.len());
+            let remainder = slashed - share * U256::from(majority.len());
+            for (i, juror) in majority.into_iter().enumerate() {
+                let reward = if i == 0 { share + remainder } else { share };
+                let mut record = self.juror_records.setter(juror);
+                let stake = record.stake.get();
+                record.stake.set(stake + reward);
+            }
+        }
+        self.juries.setter(job_id).tallied.set(true);
+        slashed
+    }
+}
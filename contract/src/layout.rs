@@ -0,0 +1,44 @@
+//! Prints the storage slots of the Escrow's fields, or of given entries.
+//!
+//! Without arguments it lists every struct in the `sol_storage!` block with
+//! the word and byte each field starts at, relative to the struct. Each
+//! argument is a path to one value, such as a mapping entry or vector
+//! element, whose absolute slot is printed instead:
+//!
+//! ```bash
+//! cargo run --features export-abi --bin layout -- 'jobs[7].client' 'fee_tiers[0].fee_bps'
+//! ```
+
+use freelance_payment_escrow::storage_layout::Layout;
+
+fn main() {
+    let layout = Layout::contract();
+    let paths: Vec<String> = std::env::args().skip(1).collect();
+    if paths.is_empty() {
+        for (i, s) in layout.structs.iter().enumerate() {
+            if i > 0 {
+                println!();
+            }
+            println!("{} ({} slots)", s.name, s.slots);
+            for field in &s.fields {
+                println!(
+                    "  {:>4} {:>2} {:>2}  {} {}",
+                    field.slot, field.offset, field.bytes, field.ty, field.name
+                );
+            }
+        }
+        return;
+    }
+    for path in paths {
+        match layout.locate(&path) {
+            Ok(location) => println!(
+                "{path}: slot {:#066x} offset {} bytes {} ({})",
+                location.slot, location.offset, location.bytes, location.ty
+            ),
+            Err(error) => {
+                eprintln!("{path}: {error}");
+                std::process::exit(1);
+            }
+        }
+    }
+}
@@ -1,8 +1,124 @@
+// The `#[public]` router chains an iterator per exported function for the ABI export
+#![recursion_limit = "512"]
+
 extern crate alloc;
 
+use stylus_sdk::abi::Bytes;
 use stylus_sdk::prelude::*;
 use alloy_primitives::{U256, Address, Uint, B256};
-use alloy_sol_types::sol;
+use fees::fee_on;
+
+mod amendments;
+mod analytics;
+mod appeals;
+mod approvals;
+mod arbitrators;
+mod attestation;
+mod authorizations;
+mod automation;
+mod baskets;
+mod caps;
+mod categories;
+mod clawbacks;
+mod compliance;
+mod conditions;
+mod credits;
+mod deposit_limits;
+mod dispute_stakes;
+mod disputes;
+mod escrowed;
+mod fees;
+mod funding;
+mod governance;
+mod guardians;
+mod holdbacks;
+mod hooks;
+mod juries;
+mod offers;
+mod ownership;
+mod payouts;
+mod platform;
+mod preview;
+mod profiles;
+mod rebates;
+mod refund_roots;
+mod registry;
+mod reputation;
+mod settings;
+mod sla;
+mod solvency;
+mod staking;
+#[cfg(any(test, feature = "export-abi"))]
+pub mod storage_layout;
+mod swap;
+mod timesheets;
+mod tranches;
+mod treasury;
+mod yield_splits;
+pub use escrow_types::*;
+
+/// A job as returned by `get_job`:
+/// (id, client, freelancer, amount, deadline, released, refunded)
+pub type JobView = (U256, Address, Address, U256, u64, bool, bool);
+
+/// A page of jobs as returned by `query_jobs`: (IDs, clients, freelancers,
+/// amounts, deadlines, `JOB_STATUS_*` statuses, cursor to resume from)
+pub type JobPage = (
+    Vec<U256>,
+    Vec<Address>,
+    Vec<Address>,
+    Vec<U256>,
+    Vec<u64>,
+    Vec<u8>,
+    U256,
+);
+
+/// Cumulative protocol volume as returned by `get_protocol_stats`:
+/// (deposited, released, refunded, swept, protocol fees charged, escrowed
+/// in jobs when a dispute was opened over them)
+pub type ProtocolStatsView = (U256, U256, U256, U256, U256, U256);
+
+/// An address's stats as returned by `get_user_stats`: (jobs as client,
+/// jobs as freelancer, deposited, earned, refunded, locked as client,
+/// pending as freelancer)
+pub type UserStatsView = (U256, U256, U256, U256, U256, U256, U256);
+
+/// An address's reputation as returned by `get_reputation`:
+/// (ratings, star total, disputes lost, weighted stars, weight, then as
+/// freelancer: jobs completed, average settlement time, disputes, then as
+/// client: refund rate, disputes, dispute-loss rate), rates in basis points
+pub type ReputationView = (u32, u32, u32, U256, U256, u32, u64, u32, u16, u32, u16);
+
+/// A job's stake as returned by `get_job_stake`: (token, principal, shares,
+/// freelancer's and treasury's yield shares, party whose consent is
+/// pending, slippage bound it was staked under)
+pub type JobStakeView = (Address, U256, U256, u16, u16, Address, u16);
+
+/// What settling a job now would pay, as returned by `preview_release` and
+/// `preview_refund`: (freelancer, withheld, client, protocol fee, platform
+/// fee, holdback left in escrow)
+pub type SettlementPreviewView = (U256, U256, U256, U256, U256, U256);
+
+/// A dispute as returned by `get_dispute`:
+/// (opened by, opened at, arbitrator, rule by, default split, open)
+pub type DisputeView = (Address, u64, Address, u64, u16, bool);
+
+/// An emergency proposal as returned by `get_emergency_proposal`:
+/// (action, target, proposer, expires at, confirmations, executed)
+pub type EmergencyProposalView = (u8, U256, Address, u64, u8, bool);
+
+/// A governance proposal as returned by `get_governance_proposal`:
+/// (action, target, value, proposer, voting ends, votes for, votes against,
+/// executed)
+pub type GovernanceProposalView = (u8, Address, U256, Address, u64, U256, U256, bool, Bytes);
+
+/// An invoice as returned by `get_invoice`:
+/// (freelancer, client, amount, duration, metadata, expiry, job ID once funded)
+pub type InvoiceView = (Address, Address, U256, u64, B256, u64, U256);
+
+/// A window of jobs as returned by `get_jobs_range`, one entry per ID:
+/// (clients, freelancers, amounts, deadlines, released, refunded)
+pub type JobsRange = (Vec<Address>, Vec<Address>, Vec<U256>, Vec<u64>, Vec<bool>, Vec<bool>);
 
 sol_storage! {
     #[entrypoint]
@@ -12,6 +128,135 @@ sol_storage! {
         uint256 job_count;
         mapping(uint256 => Job) jobs;
         mapping(uint256 => bool) finalized;
+        uint256 total_deposited;
+        uint256 total_released;
+        uint256 total_refunded;
+        mapping(address => UserStats) user_stats;
+        uint64 sunset_at;
+        bool retired;
+        uint256 total_swept;
+        uint64 dormancy_period;
+        address recovery_address;
+        mapping(uint256 => uint64) sweep_after;
+        mapping(address => mapping(uint256 => uint256)) nonce_jobs;
+        address swap_router;
+        address weth;
+        mapping(address => PayoutToken) payout_tokens;
+        mapping(address => Withholding) withholdings;
+        address compliance_hook;
+        address eas;
+        bytes32 attestation_schema;
+        address attester;
+        mapping(uint256 => address) settlement_hooks;
+        mapping(uint256 => address) release_conditions;
+        mapping(uint256 => Tranche[]) tranches;
+        mapping(uint256 => Retainer) retainers;
+        mapping(uint256 => Cancellation) cancellations;
+        mapping(uint256 => Funding) fundings;
+        mapping(uint256 => mapping(address => uint256)) contributions;
+        mapping(address => uint256) credits;
+        uint256 total_credit;
+        uint32 default_job_cap;
+        mapping(address => uint32) job_caps;
+        mapping(address => uint32) active_jobs;
+        mapping(uint64 => PeriodStats) period_stats;
+        uint16 protocol_fee_bps;
+        address fee_manager;
+        FeeTier[] fee_tiers;
+        mapping(uint256 => uint16) job_fee_bps;
+        VolumeDiscount[] volume_discounts;
+        mapping(address => uint256) client_volume;
+        mapping(address => bool) fee_exempt;
+        mapping(uint256 => Dispute) disputes;
+        mapping(uint256 => Evidence[]) evidence;
+        address arbitrator;
+        uint64 ruling_period;
+        uint16 default_split_bps;
+        mapping(uint256 => DualApproval) dual_approvals;
+        uint64 dispute_timeout;
+        bool timeout_split;
+        uint16 timeout_split_bps;
+        uint256 accrued_fees;
+        address treasury;
+        address pending_treasury;
+        uint64 treasury_effective_at;
+        address[] guardians;
+        mapping(address => bool) is_guardian;
+        uint8 guardian_threshold;
+        uint64 guardian_epoch;
+        uint64 pause_until;
+        uint256 emergency_proposal_count;
+        mapping(uint256 => EmergencyProposal) emergency_proposals;
+        mapping(uint256 => mapping(address => bool)) emergency_confirmations;
+        mapping(uint256 => address) pending_clients;
+        mapping(uint256 => bool) accepted_jobs;
+        mapping(address => PayoutAddress) payout_addresses;
+        address governance_token;
+        address[] council;
+        mapping(address => bool) is_council;
+        uint256 governance_quorum;
+        uint64 council_epoch;
+        uint256 governance_proposal_count;
+        mapping(uint256 => GovernanceProposal) governance_proposals;
+        mapping(uint256 => mapping(address => bool)) governance_votes;
+        address pauser;
+        uint64 max_pause;
+        uint64 renounce_effective_at;
+        mapping(uint256 => JobPlatform) job_platforms;
+        mapping(address => mapping(uint256 => bool)) cancelled_offers;
+        uint256 invoice_count;
+        mapping(uint256 => Invoice) invoices;
+        mapping(uint256 => Holdback) holdbacks;
+        mapping(uint256 => Clawback) clawbacks;
+        mapping(uint256 => Sla) slas;
+        mapping(uint256 => Engagement) engagements;
+        mapping(uint256 => Timesheet[]) timesheets;
+        mapping(address => bool) basket_tokens;
+        mapping(uint256 => BasketToken[]) baskets;
+        bytes32 refund_root;
+        mapping(bytes32 => mapping(uint256 => bool)) refund_claims;
+        mapping(address => uint256[]) party_jobs;
+        mapping(address => mapping(uint256 => bool)) party_indexed;
+        mapping(uint256 => JobRatings) job_ratings;
+        mapping(address => Reputation) reputations;
+        uint256 settlement_rebate;
+        uint64 deposit_cooldown;
+        uint32 deposits_per_block;
+        mapping(address => DepositWindow) deposit_windows;
+        mapping(address => bool) deposit_limit_exempt;
+        uint16 dispute_stake_bps;
+        uint256 min_dispute_stake;
+        uint256 total_dispute_stakes;
+        mapping(uint16 => CategoryStats) category_stats;
+        mapping(address => mapping(uint256 => bool)) used_authorizations;
+        uint8 jury_size;
+        uint64 jury_voting_period;
+        uint256 min_juror_stake;
+        uint16 juror_slash_bps;
+        address[] jurors;
+        mapping(address => Juror) juror_records;
+        uint256 total_juror_stakes;
+        mapping(uint256 => Jury) juries;
+        uint64 appeal_window;
+        uint16 appeal_bond_bps;
+        uint256 min_appeal_bond;
+        uint256 total_appeal_bonds;
+        mapping(uint256 => Appeal) appeals;
+        address[] listed_arbitrators;
+        mapping(address => bool) is_listed_arbitrator;
+        mapping(address => uint256) locked_amounts;
+        mapping(address => uint256) pending_earnings;
+        mapping(address => FreelancerProfile) freelancer_profiles;
+        mapping(address => ClientProfile) client_profiles;
+        address staking_token;
+        uint16 max_unstake_slippage_bps;
+        uint256 total_staked;
+        uint256 total_staking_losses;
+        mapping(uint256 => JobStake) job_stakes;
+        uint256[] active_job_ids;
+        mapping(uint256 => uint256) active_job_positions;
+        uint256 total_fees_collected;
+        uint256 total_disputed;
     }
 
     pub struct Job {
@@ -22,687 +267,11133 @@ sol_storage! {
         uint64 deadline;
         bool released;
         bool refunded;
+        bool swept;
+        bytes32 commitment;
+        bytes32 attestation;
+        uint64 created_at;
+        uint16 category;
+        bytes32 tag;
+        address arbitrator;
+    }
+
+    pub struct PayoutToken {
+        address token;
+        uint256 min_rate;
+    }
+
+    pub struct PayoutAddress {
+        address current;
+        address pending;
+        uint64 effective_at;
+    }
+
+    pub struct JobPlatform {
+        address platform;
+        uint16 fee_bps;
+    }
+
+    pub struct Tranche {
+        uint256 amount;
+        uint64 unlock_at;
+        bool settled;
+    }
+
+    pub struct Retainer {
+        uint64 period;
+        uint64 notice;
+    }
+
+    pub struct Cancellation {
+        uint64 notice;
+        uint64 effective_at;
+    }
+
+    pub struct Funding {
+        uint256 target;
+        uint256 raised;
+        uint64 duration;
+        uint64 cutoff;
+    }
+
+    pub struct Invoice {
+        address freelancer;
+        address client;
+        uint256 amount;
+        uint64 duration;
+        bytes32 metadata;
+        uint64 expires_at;
+        uint256 job_id;
+    }
+
+    pub struct Holdback {
+        uint16 bps;
+        uint64 warranty;
+        uint64 warranty_ends;
+    }
+
+    pub struct Clawback {
+        uint64 window;
+        uint64 payable_at;
+        bytes32 memo;
+    }
+
+    pub struct Sla {
+        uint64 delivery_by;
+        uint16 late_bps_per_day;
+        uint64 delivered_at;
+    }
+
+    pub struct Engagement {
+        uint256 rate;
+        uint256 pending;
+    }
+
+    pub struct Timesheet {
+        uint32 hours;
+        bytes32 details;
+        uint8 status;
+    }
+
+    pub struct BasketToken {
+        address token;
+        uint256 amount;
+    }
+
+    pub struct Withholding {
+        uint16 rate_bps;
+        address destination;
+    }
+
+    pub struct UserStats {
+        uint256 jobs_as_client;
+        uint256 jobs_as_freelancer;
+        uint256 total_deposited;
+        uint256 total_earned;
+        uint256 total_refunded;
+    }
+
+    pub struct JobRatings {
+        uint8 by_client;
+        uint8 by_freelancer;
+    }
+
+    pub struct Reputation {
+        uint32 ratings;
+        uint32 stars_total;
+        uint32 disputes_lost;
+        uint256 weighted_stars;
+        uint256 weight;
+        uint64 updated_at;
+    }
+
+    pub struct FreelancerProfile {
+        uint32 jobs_completed;
+        uint32 disputes;
+        uint64 settlement_time;
+    }
+
+    pub struct ClientProfile {
+        uint32 disputes;
+        uint32 disputes_lost;
+    }
+
+    pub struct JobStake {
+        address token;
+        uint16 freelancer_yield_bps;
+        uint16 treasury_yield_bps;
+        uint16 max_slippage_bps;
+        address consented_by;
+        uint256 principal;
+        uint256 shares;
+    }
+
+    pub struct CategoryStats {
+        uint256 jobs;
+        uint256 deposited;
+    }
+
+    pub struct Juror {
+        uint256 stake;
+        uint32 index;
+        uint32 panels;
+    }
+
+    pub struct JuryVote {
+        bool seated;
+        bool voted;
+        uint16 freelancer_bps;
+    }
+
+    pub struct Jury {
+        uint64 vote_by;
+        address[] panel;
+        mapping(address => JuryVote) votes;
+        uint8 votes_cast;
+        bool tallied;
+    }
+
+    pub struct Appeal {
+        address resolver;
+        uint16 freelancer_bps;
+        uint64 appealable_until;
+        address appellant;
+        uint16 appeal_bps;
+        uint256 bond;
+        uint256 proposal_id;
+    }
+
+    pub struct DepositWindow {
+        uint64 last_at;
+        uint64 block;
+        uint32 count;
+    }
+
+    pub struct PeriodStats {
+        uint256 jobs_created;
+        uint256 deposited;
+        uint256 released;
+        uint256 refunded;
+    }
+
+    pub struct FeeTier {
+        uint256 min_amount;
+        uint16 fee_bps;
+    }
+
+    pub struct VolumeDiscount {
+        uint256 min_volume;
+        uint16 discount_bps;
+    }
+
+    pub struct Dispute {
+        address opened_by;
+        uint64 opened_at;
+        address arbitrator;
+        uint64 rule_by;
+        uint16 default_split_bps;
+        uint64 timeout_at;
+        bool timeout_split;
+        uint16 timeout_split_bps;
+        bool lifted;
+        uint256 stake;
+    }
+
+    pub struct DualApproval {
+        bool required;
+        bool client_approved;
+        bool freelancer_approved;
+    }
+
+    pub struct EmergencyProposal {
+        uint8 action;
+        uint256 target;
+        address proposer;
+        uint64 expires_at;
+        uint64 guardian_epoch;
+        uint8 confirmations;
+        bool executed;
+    }
+
+    pub struct GovernanceProposal {
+        uint8 action;
+        address target;
+        uint256 value;
+        address proposer;
+        uint64 snapshot;
+        uint64 council_epoch;
+        uint64 voting_ends;
+        uint256 votes_for;
+        uint256 votes_against;
+        bool executed;
+        bytes data;
+    }
+
+    pub struct Evidence {
+        bytes32 evidence_hash;
+        address submitter;
+        uint64 submitted_at;
     }
 }
 
 #[public]
 impl Escrow {
-    /// Initialize escrow contract
-    pub fn initialize(&mut self) -> Result<(), Vec<u8>> {
-        self.admin.set(self.vm().msg_sender());
+    /// Set up the escrow with `admin`; runs once, as part of deployment
+    #[constructor]
+    pub fn constructor(&mut self, admin: Address) -> Result<(), Vec<u8>> {
+        if admin == Address::ZERO {
+            return Err(ErrorCode::InvalidAdminAddress.into());
+        }
+        self.admin.set(admin);
         self.paused.set(false);
         self.job_count.set(U256::from(0));
         Ok(())
     }
 
-    /// Client deposits ETH for a job
+    /// Plain ETH transfers are refused so nothing unattributed mixes with
+    /// escrowed funds; money only enters through a job
+    #[receive]
     #[payable]
-    pub fn deposit(&mut self, freelancer: Address, duration: u64) -> Result<U256, Vec<u8>> {
-        if self.paused.get() {
-            return Err("Escrow is paused".as_bytes().to_vec());
-        }
-        if self.vm().msg_value() == U256::from(0) {
-            return Err("Amount must be > 0".as_bytes().to_vec());
-        }
-        if freelancer == Address::ZERO {
-            return Err("Invalid freelancer address".as_bytes().to_vec());
-        }
-        if duration == 0 {
-            return Err("Duration must be > 0".as_bytes().to_vec());
-        }
+    pub fn receive(&mut self) -> Result<(), Vec<u8>> {
+        Err(ErrorCode::DirectTransfersNotAccepted.into())
+    }
 
-        let new_id = self.job_count.get() + U256::from(1);
-        let client = self.vm().msg_sender();
-        let amount = self.vm().msg_value();
-        let timestamp = self.vm().block_timestamp();
-        let deadline = timestamp + duration;
-        let deadline_uint = Uint::<64, 1>::from(deadline);
+    /// Calldata matching no function reverts with a reason instead of empty data
+    #[fallback]
+    pub fn fallback(&mut self, _calldata: &[u8]) -> Result<Vec<u8>, Vec<u8>> {
+        Err(ErrorCode::UnknownFunction.into())
+    }
 
-        let mut job = self.jobs.setter(new_id);
-        job.job_id.set(new_id);
-        job.client.set(client);
-        job.freelancer.set(freelancer);
-        job.amount.set(amount);
-        job.deadline.set(deadline_uint);
-        job.released.set(false);
-        job.refunded.set(false);
+    /// Client deposits ETH for a job, with an optional `memo` (e.g. an invoice
+    /// or PO reference; zero for none) echoed in the event
+    #[payable]
+    pub fn deposit(
+        &mut self,
+        freelancer: Address,
+        duration: u64,
+        memo: B256,
+    ) -> Result<U256, Vec<u8>> {
+        self.open_job(freelancer, duration, memo)
+    }
 
-        self.job_count.set(new_id);
-        self.finalized.setter(new_id).set(false);
+    /// Client deposits ETH for a job filed under `category` and `tag`, both
+    /// echoed in the event alongside `memo`; zero means none
+    #[payable]
+    pub fn deposit_categorized(
+        &mut self,
+        freelancer: Address,
+        duration: u64,
+        memo: B256,
+        category: u16,
+        tag: B256,
+    ) -> Result<U256, Vec<u8>> {
+        let client = self.vm().msg_sender();
+        let amount = self.vm().msg_value();
+        self.open_job_as(client, freelancer, duration, memo, amount, category, tag)
+    }
 
-        log(self.vm(), Deposited {
-            job_id: new_id,
-            client,
-            freelancer,
-            amount,
-        });
+    /// Client deposits for a job whose disputes `arbitrator` rules on instead
+    /// of the global arbitrator, e.g. one the admin listed
+    #[payable]
+    pub fn deposit_with_arbitrator(
+        &mut self,
+        freelancer: Address,
+        duration: u64,
+        memo: B256,
+        arbitrator: Address,
+    ) -> Result<U256, Vec<u8>> {
+        let client = self.vm().msg_sender();
+        self.assert_valid_arbitrator(arbitrator, client, freelancer)?;
 
-        Ok(new_id)
+        let job_id = self.open_job(freelancer, duration, memo)?;
+        self.assign_arbitrator(job_id, arbitrator);
+        Ok(job_id)
     }
 
-    /// Client releases funds to freelancer
-    pub fn release(&mut self, job_id: U256) -> Result<(), Vec<u8>> {
-        if self.paused.get() {
-            return Err("Escrow is paused".as_bytes().to_vec());
+    /// Client deposits for a job at a fixed `amount`; anything paid above it
+    /// is credited back to the client's withdrawable balance
+    #[payable]
+    pub fn deposit_exact(
+        &mut self,
+        freelancer: Address,
+        duration: u64,
+        amount: U256,
+    ) -> Result<U256, Vec<u8>> {
+        let paid = self.vm().msg_value();
+        if paid < amount {
+            return Err(ErrorCode::InsufficientDeposit.into());
         }
 
-        let job = self.jobs.get(job_id);
-        if job.client.get() != self.vm().msg_sender() {
-            return Err("Only client can release".as_bytes().to_vec());
+        let job_id = self.open_job_for(freelancer, duration, B256::ZERO, amount)?;
+        let excess = paid - amount;
+        if excess != U256::ZERO {
+            let client = self.vm().msg_sender();
+            self.credit(client, excess);
+            log(self.vm(), ExcessCredited {
+                job_id,
+                client,
+                excess,
+            });
         }
-        if job.released.get() || job.refunded.get() {
-            return Err("Job already settled".as_bytes().to_vec());
+        #[cfg(feature = "solvency-check")]
+        self.assert_solvent()?;
+
+        Ok(job_id)
+    }
+
+    /// Withdraw everything credited to the caller
+    pub fn withdraw_credit(&mut self) -> Result<(), Vec<u8>> {
+        if self.paused_now() {
+            return Err(ErrorCode::EscrowIsPaused.into());
         }
-        if self.finalized.get(job_id) {
-            return Err("Job already finalized".as_bytes().to_vec());
+        let account = self.vm().msg_sender();
+        let amount = self.credits.get(account);
+        if amount == U256::ZERO {
+            return Err(ErrorCode::NothingToWithdraw.into());
         }
 
-        let amount = job.amount.get();
-        let freelancer = job.freelancer.get();
-
-        self.jobs.setter(job_id).released.set(true);
-        self.finalized.setter(job_id).set(true);
+        self.credits.setter(account).set(U256::ZERO);
+        let total = self.total_credit.get();
+        self.total_credit.set(total - amount);
 
-        self.vm().transfer_eth(freelancer, amount)?;
+        self.vm().transfer_eth(account, amount)?;
 
-        log(self.vm(), Released {
-            job_id,
+        log(self.vm(), CreditWithdrawn {
+            account,
             amount,
         });
+        #[cfg(feature = "solvency-check")]
+        self.assert_solvent()?;
 
         Ok(())
     }
 
-    /// Client refunds funds before deadline
-    pub fn refund(&mut self, job_id: U256) -> Result<(), Vec<u8>> {
-        if self.paused.get() {
-            return Err("Escrow is paused".as_bytes().to_vec());
-        }
+    /// ETH credited to `account` and not yet withdrawn
+    pub fn get_credit(&self, account: Address) -> U256 {
+        self.credits.get(account)
+    }
 
-        let job = self.jobs.get(job_id);
-        if job.client.get() != self.vm().msg_sender() {
-            return Err("Only client can refund".as_bytes().to_vec());
-        }
-        if job.released.get() || job.refunded.get() {
-            return Err("Job already settled".as_bytes().to_vec());
-        }
-        if self.finalized.get(job_id) {
-            return Err("Job already finalized".as_bytes().to_vec());
+    /// Add the ETH sent to the caller's withdrawable balance, e.g. to fund
+    /// job offers they sign
+    #[payable]
+    pub fn deposit_credit(&mut self) -> Result<(), Vec<u8>> {
+        if self.paused_now() {
+            return Err(ErrorCode::EscrowIsPaused.into());
         }
-        if self.vm().block_timestamp() >= job.deadline.get().to() {
-            return Err("Deadline passed".as_bytes().to_vec());
+        self.assert_not_sunset()?;
+        let amount = self.vm().msg_value();
+        if amount == U256::ZERO {
+            return Err(ErrorCode::AmountMustBePositive.into());
         }
 
-        let amount = job.amount.get();
-        let client = job.client.get();
-
-        self.jobs.setter(job_id).refunded.set(true);
-        self.finalized.setter(job_id).set(true);
+        let account = self.vm().msg_sender();
+        self.credit(account, amount);
 
-        self.vm().transfer_eth(client, amount)?;
-
-        log(self.vm(), Refunded {
-            job_id,
+        log(self.vm(), CreditDeposited {
+            account,
             amount,
         });
+        #[cfg(feature = "solvency-check")]
+        self.assert_solvent()?;
 
         Ok(())
     }
 
-    /// Freelancer claims funds after deadline
-    pub fn auto_release(&mut self, job_id: U256) -> Result<(), Vec<u8>> {
-        if self.paused.get() {
-            return Err("Escrow is paused".as_bytes().to_vec());
-        }
+    /// Deposit that can only succeed once per (client, `nonce`), so a retried
+    /// transaction cannot fund the same job twice
+    #[payable]
+    pub fn deposit_with_nonce(
+        &mut self,
+        freelancer: Address,
+        duration: u64,
+        nonce: U256,
+    ) -> Result<U256, Vec<u8>> {
+        let client = self.vm().msg_sender();
+        self.assert_nonce_unused(client, nonce)?;
 
-        let job = self.jobs.get(job_id);
-        if job.freelancer.get() != self.vm().msg_sender() {
-            return Err("Only freelancer can claim".as_bytes().to_vec());
-        }
-        if job.released.get() || job.refunded.get() {
-            return Err("Job already settled".as_bytes().to_vec());
-        }
-        if self.finalized.get(job_id) {
-            return Err("Job already finalized".as_bytes().to_vec());
-        }
-        if self.vm().block_timestamp() < job.deadline.get().to() {
-            return Err("Deadline not reached".as_bytes().to_vec());
-        }
+        let job_id = self.open_job(freelancer, duration, B256::ZERO)?;
+        self.nonce_jobs.setter(client).setter(nonce).set(job_id);
 
-        let amount = job.amount.get();
-        let freelancer = job.freelancer.get();
+        Ok(job_id)
+    }
 
-        self.jobs.setter(job_id).released.set(true);
-        self.finalized.setter(job_id).set(true);
+    /// Deposit that only succeeds if `uid` is a live EAS attestation of the
+    /// freelancer under the admin's schema and attester; the UID is recorded
+    /// on the job
+    #[payable]
+    pub fn deposit_attested(
+        &mut self,
+        freelancer: Address,
+        duration: u64,
+        uid: B256,
+    ) -> Result<U256, Vec<u8>> {
+        self.assert_attested(freelancer, uid)?;
 
-        self.vm().transfer_eth(freelancer, amount)?;
+        let job_id = self.open_job(freelancer, duration, B256::ZERO)?;
+        self.jobs.setter(job_id).attestation.set(uid);
 
-        log(self.vm(), AutoReleased {
+        log(self.vm(), JobAttested {
             job_id,
-            amount,
+            uid,
         });
 
-        Ok(())
+        Ok(job_id)
     }
 
-    /// ADMIN: pause/unpause escrow
-    pub fn set_paused(&mut self, state: bool) -> Result<(), Vec<u8>> {
-        if self.vm().msg_sender() != self.admin.get() {
-            return Err("Only admin".as_bytes().to_vec());
+    /// Attestation UID a job was opened with; zero if none
+    pub fn get_attestation(&self, job_id: U256) -> B256 {
+        self.jobs.get(job_id).attestation.get()
+    }
+
+    /// Deposit whose `auto_release` also requires `condition`'s
+    /// `canRelease(job_id)` to return true; the condition cannot be changed
+    #[payable]
+    pub fn deposit_with_condition(
+        &mut self,
+        freelancer: Address,
+        duration: u64,
+        condition: Address,
+    ) -> Result<U256, Vec<u8>> {
+        if condition == Address::ZERO {
+            return Err(ErrorCode::InvalidConditionAddress.into());
         }
-        self.paused.set(state);
 
-        log(self.vm(), PauseToggled {
-            paused: state,
+        let job_id = self.open_job(freelancer, duration, B256::ZERO)?;
+        self.release_conditions.setter(job_id).set(condition);
+
+        log(self.vm(), ReleaseConditionSet {
+            job_id,
+            condition,
         });
 
-        Ok(())
+        Ok(job_id)
     }
 
-    /// ADMIN: transfer ownership
-    pub fn transfer_ownership(&mut self, new_admin: Address) -> Result<(), Vec<u8>> {
-        if self.vm().msg_sender() != self.admin.get() {
-            return Err("Only admin".as_bytes().to_vec());
+    /// Release condition a job was opened with; zero if none
+    pub fn get_release_condition(&self, job_id: U256) -> Address {
+        self.release_conditions.get(job_id)
+    }
+
+    /// Deposit opened through a marketplace `platform`, which takes
+    /// `platform_bps` of every payout to the freelancer on top of the
+    /// protocol fee
+    #[payable]
+    pub fn deposit_with_platform(
+        &mut self,
+        freelancer: Address,
+        duration: u64,
+        memo: B256,
+        platform: Address,
+        platform_bps: u16,
+    ) -> Result<U256, Vec<u8>> {
+        if platform == Address::ZERO {
+            return Err(ErrorCode::InvalidPlatformAddress.into());
         }
-        if new_admin == Address::ZERO {
-            return Err("Invalid admin address".as_bytes().to_vec());
+        if platform_bps > MAX_PLATFORM_FEE_BPS {
+            return Err(ErrorCode::InvalidPlatformFee.into());
         }
 
-        let old_admin = self.admin.get();
-        self.admin.set(new_admin);
+        let job_id = self.open_job(freelancer, duration, memo)?;
+        let mut job_platform = self.job_platforms.setter(job_id);
+        job_platform.platform.set(platform);
+        job_platform.fee_bps.set(Uint::<16, 1>::from(platform_bps));
 
-        log(self.vm(), OwnershipTransferred {
-            old_admin,
-            new_admin,
+        log(self.vm(), PlatformFeeSet {
+            job_id,
+            platform,
+            fee_bps: platform_bps,
         });
 
-        Ok(())
+        Ok(job_id)
     }
 
-    /// ADMIN: emergency refund
-    pub fn emergency_refund(&mut self, job_id: U256) -> Result<(), Vec<u8>> {
-        if self.vm().msg_sender() != self.admin.get() {
-            return Err("Only admin".as_bytes().to_vec());
+    /// Platform a job was opened through and its fee in basis points; zero
+    /// if none
+    pub fn get_platform_fee(&self, job_id: U256) -> (Address, u16) {
+        let job_platform = self.job_platforms.get(job_id);
+        (job_platform.platform.get(), job_platform.fee_bps.get().to())
+    }
+
+    /// Deposit that is only released once both the client and the freelancer
+    /// have called `approve_release`
+    #[payable]
+    pub fn deposit_with_dual_approval(
+        &mut self,
+        freelancer: Address,
+        duration: u64,
+    ) -> Result<U256, Vec<u8>> {
+        let job_id = self.open_job(freelancer, duration, B256::ZERO)?;
+        self.dual_approvals.setter(job_id).required.set(true);
+
+        log(self.vm(), DualApprovalRequired {
+            job_id,
+        });
+
+        Ok(job_id)
+    }
+
+    /// Either party approves releasing a dual-approval job; the second
+    /// approval pays the freelancer
+    pub fn approve_release(&mut self, job_id: U256) -> Result<(), Vec<u8>> {
+        if self.paused_now() {
+            return Err(ErrorCode::EscrowIsPaused.into());
         }
 
+        self.assert_settleable(job_id)?;
+        self.assert_not_staked(job_id)?;
+        if !self.requires_dual_approval(job_id) {
+            return Err(ErrorCode::DualApprovalNotRequired.into());
+        }
+        let approver = self.vm().msg_sender();
         let job = self.jobs.get(job_id);
-        if job.released.get() || job.refunded.get() {
-            return Err("Job already settled".as_bytes().to_vec());
+        let client = job.client.get();
+        let freelancer = job.freelancer.get();
+        let approval = self.dual_approvals.get(job_id);
+        let (approved, other_approved) = if approver == client {
+            (approval.client_approved.get(), approval.freelancer_approved.get())
+        } else if approver == freelancer {
+            (approval.freelancer_approved.get(), approval.client_approved.get())
+        } else {
+            return Err(ErrorCode::OnlyJobPartiesCanApprove.into());
+        };
+        if approved {
+            return Err(ErrorCode::ReleaseAlreadyApproved.into());
         }
-        if self.finalized.get(job_id) {
-            return Err("Job already finalized".as_bytes().to_vec());
+        if other_approved {
+            self.assert_not_disputed(job_id)?;
+            self.assert_compliant(client, freelancer, job.amount.get())?;
         }
 
-        let amount = job.amount.get();
-        let client = job.client.get();
-
-        self.jobs.setter(job_id).refunded.set(true);
-        self.finalized.setter(job_id).set(true);
-
-        self.vm().transfer_eth(client, amount)?;
+        let mut approval = self.dual_approvals.setter(job_id);
+        if approver == client {
+            approval.client_approved.set(true);
+        } else {
+            approval.freelancer_approved.set(true);
+        }
 
-        log(self.vm(), EmergencyRefunded {
+        log(self.vm(), ReleaseApproved {
             job_id,
-            admin: self.vm().msg_sender(),
+            approver,
         });
 
+        if other_approved {
+            self.release_approved(job_id)?;
+            #[cfg(feature = "solvency-check")]
+            self.assert_solvent()?;
+        }
+
         Ok(())
     }
 
-    /// View job details
-    pub fn get_job(&self, job_id: U256) -> (U256, Address, Address, U256, u64, bool, bool) {
-        let j = self.jobs.get(job_id);
+    /// Either party consents to holding a job whose deadline is at least
+    /// `MIN_STAKE_DURATION` away in the staking token, with
+    /// `freelancer_yield_bps` of the yield going to the freelancer,
+    /// `treasury_yield_bps` to the treasury and the rest to the client; once
+    /// the other party has consented to the same split the job is staked
+    pub fn consent_to_stake(
+        &mut self,
+        job_id: U256,
+        freelancer_yield_bps: u16,
+        treasury_yield_bps: u16,
+    ) -> Result<(), Vec<u8>> {
+        if self.paused_now() {
+            return Err(ErrorCode::EscrowIsPaused.into());
+        }
+
+        let party = self.vm().msg_sender();
+        self.consent_to_stake_as(job_id, party, freelancer_yield_bps, treasury_yield_bps)?;
+        #[cfg(feature = "solvency-check")]
+        self.assert_solvent()?;
+
+        Ok(())
+    }
+
+    /// A party, or anyone from `UNSTAKE_WINDOW` before the deadline, buys a
+    /// staked job's shares for the ETH sent, at least their redemption
+    /// value less the slippage bound the job was staked under, so the job
+    /// can settle in ETH again. The discount can only come out of the yield,
+    /// whoever buys. Yield above the principal is credited to the parties in
+    /// their split; a shortfall from the token losing value comes off the
+    /// job. Works while paused
+    #[payable]
+    pub fn unstake_job(&mut self, job_id: U256) -> Result<(), Vec<u8>> {
+        self.assert_not_retired()?;
+        let buyer = self.vm().msg_sender();
+        let paid = self.vm().msg_value();
+        self.unwind_stake(job_id, buyer, paid)?;
+        #[cfg(feature = "solvency-check")]
+        self.assert_solvent()?;
+
+        Ok(())
+    }
+
+    /// Whether a job needs both parties' approval to release, and which of
+    /// (client, freelancer) have approved
+    pub fn get_dual_approval(&self, job_id: U256) -> (bool, bool, bool) {
+        let approval = self.dual_approvals.get(job_id);
         (
-            j.job_id.get(),
-            j.client.get(),
-            j.freelancer.get(),
-            j.amount.get(),
-            j.deadline.get().to(),
-            j.released.get(),
-            j.refunded.get(),
+            approval.required.get(),
+            approval.client_approved.get(),
+            approval.freelancer_approved.get(),
         )
     }
 
-    /// Get active (unsettled) jobs
-    pub fn get_active_jobs(&self) -> Vec<U256> {
-        let mut ids = Vec::new();
-        let total = self.job_count.get();
-        let mut i = U256::from(1);
-        while i <= total {
-            let job = self.jobs.get(i);
-            if !job.released.get() && !job.refunded.get() {
-                ids.push(i);
-            }
-            i = i + U256::from(1);
+    /// Deposit that can only be refunded `notice` seconds after the client
+    /// asks to cancel, giving the freelancer time to deliver
+    #[payable]
+    pub fn deposit_with_notice(
+        &mut self,
+        freelancer: Address,
+        duration: u64,
+        notice: u64,
+    ) -> Result<U256, Vec<u8>> {
+        if notice == 0 {
+            return Err(ErrorCode::InvalidNotice.into());
         }
-        ids
-    }
+
+        let job_id = self.open_job(freelancer, duration, B256::ZERO)?;
+        self.cancellations
+            .setter(job_id)
+            .notice
+            .set(Uint::<64, 1>::from(notice));
+
+        log(self.vm(), CancellationNoticeSet {
+            job_id,
+            notice,
+        });
+
+        Ok(job_id)
+    }
+
+    /// A job's cancellation notice period and when a requested cancellation
+    /// takes effect; zeros if none
+    pub fn get_cancellation(&self, job_id: U256) -> (u64, u64) {
+        let cancellation = self.cancellations.get(job_id);
+        (cancellation.notice.get().to(), cancellation.effective_at.get().to())
+    }
+
+    /// Deposit that holds `holdback_bps` of the job back for `warranty`
+    /// seconds after it is released, so the client can still dispute
+    /// defects found in that time
+    #[payable]
+    pub fn deposit_with_holdback(
+        &mut self,
+        freelancer: Address,
+        duration: u64,
+        holdback_bps: u16,
+        warranty: u64,
+    ) -> Result<U256, Vec<u8>> {
+        if holdback_bps == 0 || holdback_bps >= BASIS_POINTS {
+            return Err(ErrorCode::InvalidHoldback.into());
+        }
+        if warranty == 0 {
+            return Err(ErrorCode::InvalidWarranty.into());
+        }
+
+        let job_id = self.open_job(freelancer, duration, B256::ZERO)?;
+        let mut holdback = self.holdbacks.setter(job_id);
+        holdback.bps.set(Uint::<16, 1>::from(holdback_bps));
+        holdback.warranty.set(Uint::<64, 1>::from(warranty));
+
+        log(self.vm(), HoldbackSet {
+            job_id,
+            holdback_bps,
+            warranty,
+        });
+
+        Ok(job_id)
+    }
+
+    /// A job's holdback in basis points, its warranty window and when the
+    /// window ends; zero until the job is released, all zeros if none
+    pub fn get_holdback(&self, job_id: U256) -> (u16, u64, u64) {
+        let holdback = self.holdbacks.get(job_id);
+        (
+            holdback.bps.get().to(),
+            holdback.warranty.get().to(),
+            holdback.warranty_ends.get().to(),
+        )
+    }
+
+    /// Deposit whose release only pays out after a `window`-second
+    /// clawback window, during which the arbitrator can reverse it
+    #[payable]
+    pub fn deposit_with_clawback(
+        &mut self,
+        freelancer: Address,
+        duration: u64,
+        window: u64,
+    ) -> Result<U256, Vec<u8>> {
+        if window == 0 || window > MAX_CLAWBACK_WINDOW {
+            return Err(ErrorCode::InvalidClawbackWindow.into());
+        }
+        if self.arbitrator.get() == Address::ZERO {
+            return Err(ErrorCode::NoArbitrator.into());
+        }
+
+        let job_id = self.open_job(freelancer, duration, B256::ZERO)?;
+        self.clawbacks
+            .setter(job_id)
+            .window
+            .set(Uint::<64, 1>::from(window));
+
+        log(self.vm(), ClawbackWindowSet {
+            job_id,
+            window,
+        });
+
+        Ok(job_id)
+    }
+
+    /// A job's clawback window and when its queued release can be paid;
+    /// zero if no release is queued, both zero if the job has no window
+    pub fn get_clawback(&self, job_id: U256) -> (u64, u64) {
+        let clawback = self.clawbacks.get(job_id);
+        (clawback.window.get().to(), clawback.payable_at.get().to())
+    }
+
+    /// Deposit for a job due by `delivery_by`; releasing it refunds the
+    /// client `late_bps_per_day` of it for every full day it is delivered
+    /// late, up to all of it
+    #[payable]
+    pub fn deposit_with_sla(
+        &mut self,
+        freelancer: Address,
+        duration: u64,
+        delivery_by: u64,
+        late_bps_per_day: u16,
+    ) -> Result<U256, Vec<u8>> {
+        if delivery_by <= self.vm().block_timestamp() {
+            return Err(ErrorCode::InvalidDeliveryDate.into());
+        }
+        if late_bps_per_day == 0 || late_bps_per_day > BASIS_POINTS {
+            return Err(ErrorCode::InvalidLateRefund.into());
+        }
+
+        let job_id = self.open_job(freelancer, duration, B256::ZERO)?;
+        let mut sla = self.slas.setter(job_id);
+        sla.delivery_by.set(Uint::<64, 1>::from(delivery_by));
+        sla.late_bps_per_day.set(Uint::<16, 1>::from(late_bps_per_day));
+
+        log(self.vm(), SlaSet {
+            job_id,
+            delivery_by,
+            late_bps_per_day,
+        });
+
+        Ok(job_id)
+    }
+
+    /// A job's agreed delivery date, the share refunded per day late and
+    /// when it was delivered; zero until delivered, all zeros if none
+    pub fn get_sla(&self, job_id: U256) -> (u64, u16, u64) {
+        let sla = self.slas.get(job_id);
+        (
+            sla.delivery_by.get().to(),
+            sla.late_bps_per_day.get().to(),
+            sla.delivered_at.get().to(),
+        )
+    }
+
+    /// Client opens a job for `target` wei, funding any part of it now. The
+    /// rest comes in through `fund`; the job and its `duration` start once
+    /// the target is met, and contributions can be reclaimed if it is not
+    /// met by `cutoff`.
+    #[payable]
+    pub fn create_job(
+        &mut self,
+        freelancer: Address,
+        duration: u64,
+        target: U256,
+        cutoff: u64,
+    ) -> Result<U256, Vec<u8>> {
+        if self.paused_now() {
+            return Err(ErrorCode::EscrowIsPaused.into());
+        }
+        self.assert_not_sunset()?;
+        if freelancer == Address::ZERO {
+            return Err(ErrorCode::InvalidFreelancerAddress.into());
+        }
+        if duration == 0 {
+            return Err(ErrorCode::DurationMustBePositive.into());
+        }
+        if target == U256::ZERO {
+            return Err(ErrorCode::InvalidTarget.into());
+        }
+        if cutoff <= self.vm().block_timestamp() {
+            return Err(ErrorCode::InvalidCutoff.into());
+        }
+        let client = self.vm().msg_sender();
+        let amount = self.vm().msg_value();
+        if amount > target {
+            return Err(ErrorCode::FundingExceedsTarget.into());
+        }
+        self.assert_compliant(client, freelancer, target)?;
+        if amount == target {
+            self.assert_below_cap(freelancer)?;
+        }
+        self.record_deposit_rate(client)?;
+
+        let new_id = self.push_job(client, freelancer, target);
+        let mut funding = self.fundings.setter(new_id);
+        funding.target.set(target);
+        funding.duration.set(Uint::<64, 1>::from(duration));
+        funding.cutoff.set(Uint::<64, 1>::from(cutoff));
+
+        self.record_client_deposit(client, U256::ZERO);
+
+        log(self.vm(), FundingOpened {
+            job_id: new_id,
+            client,
+            freelancer,
+            target,
+            cutoff,
+        });
+        if amount != U256::ZERO {
+            self.add_contribution(new_id, client, amount);
+        }
+        #[cfg(feature = "solvency-check")]
+        self.assert_solvent()?;
+
+        Ok(new_id)
+    }
+
+    /// Pay towards a job's funding target; anyone can contribute
+    #[payable]
+    pub fn fund(&mut self, job_id: U256) -> Result<(), Vec<u8>> {
+        if self.paused_now() {
+            return Err(ErrorCode::EscrowIsPaused.into());
+        }
+        self.assert_not_retired()?;
+        let amount = self.vm().msg_value();
+        if amount == U256::ZERO {
+            return Err(ErrorCode::AmountMustBePositive.into());
+        }
+        let funding = self.fundings.get(job_id);
+        let target = funding.target.get();
+        let raised = funding.raised.get();
+        if raised >= target {
+            return Err(ErrorCode::JobNotFunding.into());
+        }
+        if self.vm().block_timestamp() >= funding.cutoff.get().to() {
+            return Err(ErrorCode::FundingClosed.into());
+        }
+        if raised + amount > target {
+            return Err(ErrorCode::FundingExceedsTarget.into());
+        }
+        let contributor = self.vm().msg_sender();
+        let freelancer = self.jobs.get(job_id).freelancer.get();
+        self.assert_compliant(contributor, freelancer, amount)?;
+        if raised + amount == target {
+            self.assert_below_cap(freelancer)?;
+        }
+
+        self.add_contribution(job_id, contributor, amount);
+        #[cfg(feature = "solvency-check")]
+        self.assert_solvent()?;
+
+        Ok(())
+    }
+
+    /// Contributor takes back what they paid towards a job that missed its
+    /// funding cutoff; the job is closed once everything is reclaimed
+    pub fn reclaim_contribution(&mut self, job_id: U256) -> Result<(), Vec<u8>> {
+        if self.paused_now() {
+            return Err(ErrorCode::EscrowIsPaused.into());
+        }
+        let funding = self.fundings.get(job_id);
+        if funding.raised.get() >= funding.target.get() {
+            return Err(ErrorCode::JobNotFunding.into());
+        }
+        if self.vm().block_timestamp() < funding.cutoff.get().to() {
+            return Err(ErrorCode::FundingStillOpen.into());
+        }
+        let contributor = self.vm().msg_sender();
+        let amount = self.contributions.get(job_id).get(contributor);
+        if amount == U256::ZERO {
+            return Err(ErrorCode::NothingToReclaim.into());
+        }
+
+        self.contributions.setter(job_id).setter(contributor).set(U256::ZERO);
+        let mut job = self.jobs.setter(job_id);
+        let remaining = job.amount.get() - amount;
+        job.amount.set(remaining);
+        if remaining.is_zero() {
+            job.refunded.set(true);
+            self.close_job(job_id);
+        }
+        self.record_refund(job_id, contributor, amount);
+
+        self.vm().transfer_eth(contributor, amount)?;
+
+        log(self.vm(), ContributionReclaimed {
+            job_id,
+            contributor,
+            amount,
+        });
+        #[cfg(feature = "solvency-check")]
+        self.assert_solvent()?;
+
+        Ok(())
+    }
+
+    /// A job's funding target, amount raised so far and cutoff; zeros for
+    /// jobs funded in one deposit
+    pub fn get_funding(&self, job_id: U256) -> (U256, U256, u64) {
+        let funding = self.fundings.get(job_id);
+        (funding.target.get(), funding.raised.get(), funding.cutoff.get().to())
+    }
+
+    /// What `contributor` has paid towards a job's funding and not reclaimed
+    pub fn get_contribution(&self, job_id: U256, contributor: Address) -> U256 {
+        self.contributions.get(job_id).get(contributor)
+    }
+
+    /// Freelancer bills `client` for a job; paying it with `fund_invoice`
+    /// before `expiry` opens a job lasting `duration` with `metadata` as its
+    /// memo
+    pub fn create_invoice(
+        &mut self,
+        client: Address,
+        amount: U256,
+        duration: u64,
+        metadata: B256,
+        expiry: u64,
+    ) -> Result<U256, Vec<u8>> {
+        if self.paused_now() {
+            return Err(ErrorCode::EscrowIsPaused.into());
+        }
+        self.assert_not_sunset()?;
+        if client == Address::ZERO {
+            return Err(ErrorCode::InvalidClientAddress.into());
+        }
+        if amount == U256::ZERO {
+            return Err(ErrorCode::AmountMustBePositive.into());
+        }
+        if duration == 0 {
+            return Err(ErrorCode::DurationMustBePositive.into());
+        }
+        if expiry <= self.vm().block_timestamp() {
+            return Err(ErrorCode::InvalidExpiry.into());
+        }
+
+        let freelancer = self.vm().msg_sender();
+        let invoice_id = self.invoice_count.get() + U256::from(1);
+        self.invoice_count.set(invoice_id);
+        let mut invoice = self.invoices.setter(invoice_id);
+        invoice.freelancer.set(freelancer);
+        invoice.client.set(client);
+        invoice.amount.set(amount);
+        invoice.duration.set(Uint::<64, 1>::from(duration));
+        invoice.metadata.set(metadata);
+        invoice.expires_at.set(Uint::<64, 1>::from(expiry));
+
+        log(self.vm(), InvoiceCreated {
+            invoice_id,
+            freelancer,
+            client,
+            amount,
+            duration,
+            metadata,
+            expiry,
+        });
+
+        Ok(invoice_id)
+    }
+
+    /// The invoiced client pays an invoice in full, opening its job
+    #[payable]
+    pub fn fund_invoice(&mut self, invoice_id: U256) -> Result<U256, Vec<u8>> {
+        let invoice = self.invoices.get(invoice_id);
+        let freelancer = invoice.freelancer.get();
+        if freelancer == Address::ZERO {
+            return Err(ErrorCode::InvoiceNotFound.into());
+        }
+        if invoice.job_id.get() != U256::ZERO {
+            return Err(ErrorCode::InvoiceAlreadyFunded.into());
+        }
+        if self.vm().msg_sender() != invoice.client.get() {
+            return Err(ErrorCode::OnlyInvoicedClient.into());
+        }
+        if self.vm().block_timestamp() >= invoice.expires_at.get().to::<u64>() {
+            return Err(ErrorCode::InvoiceExpired.into());
+        }
+        if self.vm().msg_value() != invoice.amount.get() {
+            return Err(ErrorCode::IncorrectPayment.into());
+        }
+        let duration: u64 = invoice.duration.get().to();
+        let metadata = invoice.metadata.get();
+
+        let job_id = self.open_job(freelancer, duration, metadata)?;
+        self.invoices.setter(invoice_id).job_id.set(job_id);
+
+        log(self.vm(), InvoiceFunded {
+            invoice_id,
+            job_id,
+        });
+
+        Ok(job_id)
+    }
+
+    /// Anyone clears expired, unpaid invoices from storage to reclaim their
+    /// gas; IDs that are unknown, paid or still open are skipped. Returns how
+    /// many were pruned.
+    pub fn prune_invoices(&mut self, invoice_ids: Vec<U256>) -> u32 {
+        let now = self.vm().block_timestamp();
+        let mut pruned = 0;
+        for invoice_id in invoice_ids {
+            let invoice = self.invoices.get(invoice_id);
+            let expires_at: u64 = invoice.expires_at.get().to();
+            if invoice.freelancer.get() == Address::ZERO
+                || invoice.job_id.get() != U256::ZERO
+                || now < expires_at
+            {
+                continue;
+            }
+
+            let mut invoice = self.invoices.setter(invoice_id);
+            invoice.freelancer.set(Address::ZERO);
+            invoice.client.set(Address::ZERO);
+            invoice.amount.set(U256::ZERO);
+            invoice.duration.set(Uint::<64, 1>::ZERO);
+            invoice.metadata.set(B256::ZERO);
+            invoice.expires_at.set(Uint::<64, 1>::ZERO);
+            pruned += 1;
+
+            log(self.vm(), InvoicePruned {
+                invoice_id,
+            });
+        }
+        pruned
+    }
+
+    /// An invoice's terms and the job it opened, if funded
+    pub fn get_invoice(&self, invoice_id: U256) -> InvoiceView {
+        let invoice = self.invoices.get(invoice_id);
+        (
+            invoice.freelancer.get(),
+            invoice.client.get(),
+            invoice.amount.get(),
+            invoice.duration.get().to(),
+            invoice.metadata.get(),
+            invoice.expires_at.get().to(),
+            invoice.job_id.get(),
+        )
+    }
+
+    /// Client deposits for a job paid in tranches: `amounts[i]` unlocks for
+    /// the freelancer at `unlocks[i]` and can be refunded until then. The
+    /// amounts must add up to the deposit and the unlock times must increase.
+    #[payable]
+    pub fn deposit_tranched(
+        &mut self,
+        freelancer: Address,
+        amounts: Vec<U256>,
+        unlocks: Vec<u64>,
+    ) -> Result<U256, Vec<u8>> {
+        let count = amounts.len();
+        if count == 0 || count > MAX_TRANCHES || unlocks.len() != count {
+            return Err(ErrorCode::InvalidTranches.into());
+        }
+        let mut total = U256::ZERO;
+        for (i, amount) in amounts.iter().enumerate() {
+            if amount.is_zero() {
+                return Err(ErrorCode::AmountMustBePositive.into());
+            }
+            if i > 0 && unlocks[i] <= unlocks[i - 1] {
+                return Err(ErrorCode::UnlocksMustBeIncreasing.into());
+            }
+            total = total.saturating_add(*amount);
+        }
+        if total != self.vm().msg_value() {
+            return Err(ErrorCode::TranchesMustSumToDeposit.into());
+        }
+        let now = self.vm().block_timestamp();
+        let last = unlocks[count - 1];
+        if last <= now {
+            return Err(ErrorCode::InvalidUnlockTime.into());
+        }
+
+        let job_id = self.open_job(freelancer, last - now, B256::ZERO)?;
+        let mut schedule = self.tranches.setter(job_id);
+        for (amount, unlock_at) in amounts.into_iter().zip(unlocks) {
+            let mut tranche = schedule.grow();
+            tranche.amount.set(amount);
+            tranche.unlock_at.set(Uint::<64, 1>::from(unlock_at));
+        }
+
+        Ok(job_id)
+    }
+
+    /// Freelancer claims an unlocked tranche
+    pub fn claim_tranche(&mut self, job_id: U256, index: U256) -> Result<(), Vec<u8>> {
+        if self.paused_now() {
+            return Err(ErrorCode::EscrowIsPaused.into());
+        }
+
+        let job = self.jobs.get(job_id);
+        if job.freelancer.get() != self.vm().msg_sender() {
+            return Err(ErrorCode::OnlyFreelancerCanClaim.into());
+        }
+        if job.released.get() || job.refunded.get() {
+            return Err(ErrorCode::JobAlreadySettled.into());
+        }
+        if self.finalized.get(job_id) {
+            return Err(ErrorCode::JobAlreadyFinalized.into());
+        }
+        let Some((i, amount, unlock_at, settled)) = self.tranche(job_id, index) else {
+            return Err(ErrorCode::InvalidTranche.into());
+        };
+        if settled {
+            return Err(ErrorCode::TrancheAlreadySettled.into());
+        }
+        if self.vm().block_timestamp() < unlock_at {
+            return Err(ErrorCode::TrancheLocked.into());
+        }
+        self.assert_not_disputed(job_id)?;
+
+        self.pay_tranche(job_id, i, amount)?;
+
+        #[cfg(feature = "solvency-check")]
+        self.assert_solvent()?;
+
+        Ok(())
+    }
+
+    /// Client takes back a tranche that has not unlocked yet
+    pub fn refund_tranche(&mut self, job_id: U256, index: U256) -> Result<(), Vec<u8>> {
+        if self.paused_now() {
+            return Err(ErrorCode::EscrowIsPaused.into());
+        }
+
+        let job = self.jobs.get(job_id);
+        if job.client.get() != self.vm().msg_sender() {
+            return Err(ErrorCode::OnlyClientCanRefund.into());
+        }
+        if job.released.get() || job.refunded.get() {
+            return Err(ErrorCode::JobAlreadySettled.into());
+        }
+        if self.finalized.get(job_id) {
+            return Err(ErrorCode::JobAlreadyFinalized.into());
+        }
+        if self.retainers.get(job_id).period.get() != Uint::<64, 1>::ZERO {
+            return Err(ErrorCode::JobIsARetainer.into());
+        }
+        let Some((i, amount, unlock_at, settled)) = self.tranche(job_id, index) else {
+            return Err(ErrorCode::InvalidTranche.into());
+        };
+        if settled {
+            return Err(ErrorCode::TrancheAlreadySettled.into());
+        }
+        if self.vm().block_timestamp() >= unlock_at {
+            return Err(ErrorCode::TrancheUnlocked.into());
+        }
+
+        self.return_tranche(job_id, i, amount)?;
+
+        #[cfg(feature = "solvency-check")]
+        self.assert_solvent()?;
+
+        Ok(())
+    }
+
+    /// Client funds a retainer of `periods` equal payments, one unlocking at
+    /// the end of each `period` seconds. It can be stopped with `notice`
+    /// seconds' notice.
+    #[payable]
+    pub fn deposit_retainer(
+        &mut self,
+        freelancer: Address,
+        periods: u64,
+        period: u64,
+        notice: u64,
+    ) -> Result<U256, Vec<u8>> {
+        if periods == 0 || periods > MAX_RETAINER_PERIODS {
+            return Err(ErrorCode::InvalidPeriods.into());
+        }
+        if period == 0 {
+            return Err(ErrorCode::InvalidPeriod.into());
+        }
+        let deposit = self.vm().msg_value();
+        if deposit % U256::from(periods) != U256::ZERO {
+            return Err(ErrorCode::DepositMustSplitEvenly.into());
+        }
+        let Some(duration) = periods.checked_mul(period) else {
+            return Err(ErrorCode::InvalidPeriod.into());
+        };
+
+        let start = self.vm().block_timestamp();
+        let job_id = self.open_job(freelancer, duration, B256::ZERO)?;
+        let amount = deposit / U256::from(periods);
+        let mut schedule = self.tranches.setter(job_id);
+        for i in 1..=periods {
+            let mut tranche = schedule.grow();
+            tranche.amount.set(amount);
+            tranche.unlock_at.set(Uint::<64, 1>::from(start + i * period));
+        }
+        let mut retainer = self.retainers.setter(job_id);
+        retainer.period.set(Uint::<64, 1>::from(period));
+        retainer.notice.set(Uint::<64, 1>::from(notice));
+
+        log(self.vm(), RetainerStarted {
+            job_id,
+            periods,
+            period,
+            notice,
+        });
+
+        Ok(job_id)
+    }
+
+    /// Freelancer claims every unlocked tranche of a tranched job or
+    /// retainer, logging each one
+    pub fn claim_unlocked(&mut self, job_id: U256) -> Result<(), Vec<u8>> {
+        if self.paused_now() {
+            return Err(ErrorCode::EscrowIsPaused.into());
+        }
+
+        let job = self.jobs.get(job_id);
+        if job.freelancer.get() != self.vm().msg_sender() {
+            return Err(ErrorCode::OnlyFreelancerCanClaim.into());
+        }
+        if job.released.get() || job.refunded.get() {
+            return Err(ErrorCode::JobAlreadySettled.into());
+        }
+        if self.finalized.get(job_id) {
+            return Err(ErrorCode::JobAlreadyFinalized.into());
+        }
+        let now = self.vm().block_timestamp();
+        let unlocked: Vec<_> = self
+            .open_tranches(job_id)
+            .into_iter()
+            .filter(|&(_, _, unlock_at)| unlock_at <= now)
+            .collect();
+        if unlocked.is_empty() {
+            return Err(ErrorCode::NothingToClaim.into());
+        }
+        self.assert_not_disputed(job_id)?;
+
+        for (i, amount, _) in unlocked {
+            self.pay_tranche(job_id, i, amount)?;
+        }
+
+        #[cfg(feature = "solvency-check")]
+        self.assert_solvent()?;
+
+        Ok(())
+    }
+
+    /// Client stops a retainer: periods unlocking after the notice period
+    /// are refunded, the rest stay claimable by the freelancer
+    pub fn stop_retainer(&mut self, job_id: U256) -> Result<(), Vec<u8>> {
+        if self.paused_now() {
+            return Err(ErrorCode::EscrowIsPaused.into());
+        }
+
+        let job = self.jobs.get(job_id);
+        if job.client.get() != self.vm().msg_sender() {
+            return Err(ErrorCode::OnlyClientCanStop.into());
+        }
+        if job.released.get() || job.refunded.get() {
+            return Err(ErrorCode::JobAlreadySettled.into());
+        }
+        if self.finalized.get(job_id) {
+            return Err(ErrorCode::JobAlreadyFinalized.into());
+        }
+        let retainer = self.retainers.get(job_id);
+        if retainer.period.get() == Uint::<64, 1>::ZERO {
+            return Err(ErrorCode::JobIsNotARetainer.into());
+        }
+        let notice: u64 = retainer.notice.get().to();
+        let cutoff = self.vm().block_timestamp().saturating_add(notice);
+        let stopped: Vec<_> = self
+            .open_tranches(job_id)
+            .into_iter()
+            .filter(|&(_, _, unlock_at)| unlock_at > cutoff)
+            .collect();
+        if stopped.is_empty() {
+            return Err(ErrorCode::NothingToStop.into());
+        }
+
+        let mut refunded = U256::ZERO;
+        for (i, amount, _) in stopped {
+            self.return_tranche(job_id, i, amount)?;
+            refunded += amount;
+        }
+
+        log(self.vm(), RetainerStopped {
+            job_id,
+            refunded,
+        });
+        #[cfg(feature = "solvency-check")]
+        self.assert_solvent()?;
+
+        Ok(())
+    }
+
+    /// A retainer's period length and notice period; zeros for other jobs
+    pub fn get_retainer(&self, job_id: U256) -> (u64, u64) {
+        let retainer = self.retainers.get(job_id);
+        (retainer.period.get().to(), retainer.notice.get().to())
+    }
+
+    /// Client opens an hourly engagement, depositing a budget the
+    /// freelancer bills against at `rate` wei per hour through timesheets
+    #[payable]
+    pub fn deposit_hourly(
+        &mut self,
+        freelancer: Address,
+        duration: u64,
+        rate: U256,
+    ) -> Result<U256, Vec<u8>> {
+        if rate.is_zero() || rate > self.vm().msg_value() {
+            return Err(ErrorCode::InvalidRate.into());
+        }
+
+        let job_id = self.open_job(freelancer, duration, B256::ZERO)?;
+        self.engagements.setter(job_id).rate.set(rate);
+
+        log(self.vm(), HourlyRateSet { job_id, rate });
+
+        Ok(job_id)
+    }
+
+    /// Freelancer bills `hours` of work on an hourly engagement, described
+    /// by `details` (e.g. a hash of the timesheet); returns its index
+    pub fn submit_timesheet(
+        &mut self,
+        job_id: U256,
+        hours: u32,
+        details: B256,
+    ) -> Result<U256, Vec<u8>> {
+        if self.paused_now() {
+            return Err(ErrorCode::EscrowIsPaused.into());
+        }
+
+        self.assert_settleable(job_id)?;
+        let job = self.jobs.get(job_id);
+        if job.freelancer.get() != self.vm().msg_sender() {
+            return Err(ErrorCode::OnlyFreelancerCanBill.into());
+        }
+        if !self.is_hourly(job_id) {
+            return Err(ErrorCode::JobIsNotHourly.into());
+        }
+        if hours == 0 {
+            return Err(ErrorCode::InvalidHours.into());
+        }
+        self.assert_not_disputed(job_id)?;
+        let engagement = self.engagements.get(job_id);
+        let amount = engagement.rate.get().saturating_mul(U256::from(hours));
+        if engagement.pending.get().saturating_add(amount) > job.amount.get() {
+            return Err(ErrorCode::ExceedsBudget.into());
+        }
+
+        let index = self.push_timesheet(job_id, hours, details, amount);
+
+        log(self.vm(), TimesheetSubmitted {
+            job_id,
+            index,
+            hours,
+            amount,
+            details,
+        });
+
+        Ok(index)
+    }
+
+    /// Client approves a timesheet, paying its amount out of the budget
+    pub fn approve_timesheet(&mut self, job_id: U256, index: U256) -> Result<(), Vec<u8>> {
+        if self.paused_now() {
+            return Err(ErrorCode::EscrowIsPaused.into());
+        }
+
+        self.assert_settleable(job_id)?;
+        let job = self.jobs.get(job_id);
+        if job.client.get() != self.vm().msg_sender() {
+            return Err(ErrorCode::OnlyClientCanApprove.into());
+        }
+        let (i, amount) = self.submitted_timesheet(job_id, index)?;
+        self.assert_not_disputed(job_id)?;
+        self.assert_compliant(job.client.get(), job.freelancer.get(), amount)?;
+
+        self.pay_timesheet(job_id, i, amount)?;
+
+        #[cfg(feature = "solvency-check")]
+        self.assert_solvent()?;
+
+        Ok(())
+    }
+
+    /// Client rejects a timesheet, freeing its amount for later ones
+    pub fn reject_timesheet(&mut self, job_id: U256, index: U256) -> Result<(), Vec<u8>> {
+        if self.paused_now() {
+            return Err(ErrorCode::EscrowIsPaused.into());
+        }
+
+        self.assert_settleable(job_id)?;
+        if self.jobs.get(job_id).client.get() != self.vm().msg_sender() {
+            return Err(ErrorCode::OnlyClientCanReject.into());
+        }
+        let (i, amount) = self.submitted_timesheet(job_id, index)?;
+
+        self.reject_timesheet_at(job_id, i, amount);
+
+        Ok(())
+    }
+
+    /// Client ends an hourly engagement once its deadline has passed and no
+    /// timesheet is pending, taking back the unspent budget
+    pub fn end_engagement(&mut self, job_id: U256) -> Result<(), Vec<u8>> {
+        if self.paused_now() {
+            return Err(ErrorCode::EscrowIsPaused.into());
+        }
+
+        self.assert_settleable(job_id)?;
+        self.assert_not_staked(job_id)?;
+        let job = self.jobs.get(job_id);
+        let client = job.client.get();
+        if client != self.vm().msg_sender() {
+            return Err(ErrorCode::OnlyClientCanEnd.into());
+        }
+        if !self.is_hourly(job_id) {
+            return Err(ErrorCode::JobIsNotHourly.into());
+        }
+        if self.vm().block_timestamp() < job.deadline.get().to() {
+            return Err(ErrorCode::EngagementRunning.into());
+        }
+        self.assert_not_disputed(job_id)?;
+        self.assert_no_pending_timesheets(job_id)?;
+
+        let amount = job.amount.get();
+
+        self.jobs.setter(job_id).refunded.set(true);
+        self.close_job(job_id);
+        self.record_refund(job_id, client, amount);
+
+        self.vm().transfer_eth(client, amount)?;
+
+        log(self.vm(), Refunded {
+            job_id,
+            amount,
+            memo: B256::ZERO,
+        });
+        self.call_settlement_hook(job_id, amount, false);
+        #[cfg(feature = "solvency-check")]
+        self.assert_solvent()?;
+
+        Ok(())
+    }
+
+    /// An hourly engagement's rate and the amount billed in timesheets
+    /// awaiting approval; zeros for other jobs
+    pub fn get_engagement(&self, job_id: U256) -> (U256, U256) {
+        let engagement = self.engagements.get(job_id);
+        (engagement.rate.get(), engagement.pending.get())
+    }
+
+    /// A job's timesheets as parallel lists of hours, details and status
+    /// (`TIMESHEET_SUBMITTED`, `TIMESHEET_APPROVED` or `TIMESHEET_REJECTED`)
+    pub fn get_timesheets(&self, job_id: U256) -> (Vec<u32>, Vec<B256>, Vec<u8>) {
+        let sheets = self.timesheets.get(job_id);
+        let mut hours = Vec::with_capacity(sheets.len());
+        let mut details = Vec::with_capacity(sheets.len());
+        let mut statuses = Vec::with_capacity(sheets.len());
+        for i in 0..sheets.len() {
+            if let Some(sheet) = sheets.getter(i) {
+                hours.push(sheet.hours.get().to());
+                details.push(sheet.details.get());
+                statuses.push(sheet.status.get().to());
+            }
+        }
+        (hours, details, statuses)
+    }
+
+    /// Client deposits for a job funded with a basket: the ETH sent plus
+    /// `amounts[i]` of each admin-approved ERC-20 `tokens[i]`, pulled from
+    /// the client (who must have approved this contract). Every settlement
+    /// pays each token the same way as the ETH; fees only apply to the ETH.
+    #[payable]
+    pub fn deposit_basket(
+        &mut self,
+        freelancer: Address,
+        duration: u64,
+        tokens: Vec<Address>,
+        amounts: Vec<U256>,
+    ) -> Result<U256, Vec<u8>> {
+        let count = tokens.len();
+        if count == 0 || count > MAX_BASKET_TOKENS || amounts.len() != count {
+            return Err(ErrorCode::InvalidBasket.into());
+        }
+        for (i, (token, amount)) in tokens.iter().zip(&amounts).enumerate() {
+            if !self.basket_tokens.get(*token) {
+                return Err(ErrorCode::TokenNotApproved.into());
+            }
+            if tokens[..i].contains(token) {
+                return Err(ErrorCode::DuplicateToken.into());
+            }
+            if amount.is_zero() {
+                return Err(ErrorCode::AmountMustBePositive.into());
+            }
+        }
+
+        let job_id = self.open_job(freelancer, duration, B256::ZERO)?;
+        let client = self.vm().msg_sender();
+        for (token, amount) in tokens.into_iter().zip(amounts) {
+            self.fund_basket(job_id, client, token, amount)?;
+
+            log(self.vm(), BasketFunded {
+                job_id,
+                token,
+                amount,
+            });
+        }
+
+        Ok(job_id)
+    }
+
+    /// A job's full basket as parallel lists of tokens and amounts, its ETH
+    /// first as the zero address
+    pub fn get_basket(&self, job_id: U256) -> (Vec<Address>, Vec<U256>) {
+        let mut tokens = vec![Address::ZERO];
+        let mut amounts = vec![self.jobs.get(job_id).amount.get()];
+        for (token, amount) in self.basket_tokens_of(job_id) {
+            tokens.push(token);
+            amounts.push(amount);
+        }
+        (tokens, amounts)
+    }
+
+    /// A job's tranches as parallel lists of amounts, unlock times and
+    /// whether each has settled; empty for untranched jobs
+    pub fn get_tranches(&self, job_id: U256) -> (Vec<U256>, Vec<u64>, Vec<bool>) {
+        let schedule = self.tranches.get(job_id);
+        let mut amounts = Vec::with_capacity(schedule.len());
+        let mut unlocks = Vec::with_capacity(schedule.len());
+        let mut settled = Vec::with_capacity(schedule.len());
+        for i in 0..schedule.len() {
+            if let Some(tranche) = schedule.getter(i) {
+                amounts.push(tranche.amount.get());
+                unlocks.push(tranche.unlock_at.get().to());
+                settled.push(tranche.settled.get());
+            }
+        }
+        (amounts, unlocks, settled)
+    }
+
+    /// Job a client opened with `nonce`; zero if the nonce is unused
+    pub fn get_job_by_nonce(&self, client: Address, nonce: U256) -> U256 {
+        self.nonce_jobs.get(client).get(nonce)
+    }
+
+    /// Freelancer opens a job from a `JobOffer` the client signed, funded
+    /// from the client's withdrawable balance; the offer's nonce is then used
+    #[allow(clippy::too_many_arguments)]
+    pub fn accept_offer(
+        &mut self,
+        client: Address,
+        amount: U256,
+        duration: u64,
+        memo: B256,
+        nonce: U256,
+        expiry: u64,
+        signature: Bytes,
+    ) -> Result<U256, Vec<u8>> {
+        if self.vm().block_timestamp() >= expiry {
+            return Err(ErrorCode::OfferExpired.into());
+        }
+        self.assert_nonce_unused(client, nonce)?;
+
+        let freelancer = self.vm().msg_sender();
+        let offer = JobOffer {
+            client,
+            freelancer,
+            amount,
+            duration,
+            memo,
+            nonce,
+            expiry,
+        };
+        let digest = offer_hash(&offer, self.vm().chain_id(), self.vm().contract_address());
+        if !self.is_signed_by(client, digest, &signature) {
+            return Err(ErrorCode::InvalidSignature.into());
+        }
+
+        let credit = self.credits.get(client);
+        if credit < amount {
+            return Err(ErrorCode::InsufficientCredit.into());
+        }
+        self.credits.setter(client).set(credit - amount);
+        let total = self.total_credit.get();
+        self.total_credit.set(total - amount);
+
+        let job_id = self.open_job_as(client, freelancer, duration, memo, amount, 0, B256::ZERO)?;
+        self.nonce_jobs.setter(client).setter(nonce).set(job_id);
+
+        Ok(job_id)
+    }
+
+    /// Client withdraws the offer they signed with `nonce` before it is
+    /// accepted
+    pub fn cancel_offer(&mut self, nonce: U256) -> Result<(), Vec<u8>> {
+        let client = self.vm().msg_sender();
+        self.assert_nonce_unused(client, nonce)?;
+        self.cancelled_offers.setter(client).setter(nonce).set(true);
+
+        log(self.vm(), OfferCancelled {
+            client,
+            nonce,
+        });
+
+        Ok(())
+    }
+
+    /// Whether `client` cancelled the offer made with `nonce`
+    pub fn is_offer_cancelled(&self, client: Address, nonce: U256) -> bool {
+        self.cancelled_offers.get(client).get(nonce)
+    }
+
+    /// Release a job on its client's signed `Settlement`, e.g. from a
+    /// smart-account wallet; anyone can submit it
+    pub fn release_with_signature(
+        &mut self,
+        job_id: U256,
+        memo: B256,
+        nonce: U256,
+        expiry: u64,
+        signature: Bytes,
+    ) -> Result<(), Vec<u8>> {
+        self.assert_job_exists(job_id)?;
+        let client = self.jobs.get(job_id).client.get();
+        let settlement = Settlement {
+            job_id,
+            signer: client,
+            release: true,
+            memo,
+            nonce,
+            expiry,
+        };
+        self.spend_authorization(&settlement, &signature)?;
+        self.release_as(client, job_id, memo)
+    }
+
+    /// Refund a job on a party's signed `Settlement`; anyone can submit it.
+    /// The client's refunds it as `refund` would, and the freelancer's
+    /// returns the funds as `return_funds` would
+    pub fn refund_with_signature(
+        &mut self,
+        job_id: U256,
+        signer: Address,
+        memo: B256,
+        nonce: U256,
+        expiry: u64,
+        signature: Bytes,
+    ) -> Result<(), Vec<u8>> {
+        self.assert_job_exists(job_id)?;
+        let job = self.jobs.get(job_id);
+        let (client, freelancer) = (job.client.get(), job.freelancer.get());
+        if signer != client && (signer != freelancer || freelancer == Address::ZERO) {
+            return Err(ErrorCode::NotAParty.into());
+        }
+        let settlement = Settlement {
+            job_id,
+            signer,
+            release: false,
+            memo,
+            nonce,
+            expiry,
+        };
+        self.spend_authorization(&settlement, &signature)?;
+        if signer == client {
+            self.refund_as(signer, job_id, memo)
+        } else {
+            self.return_funds_as(signer, job_id, memo)
+        }
+    }
+
+    /// Digest `signer` signs to authorize releasing (or, with `release`
+    /// false, refunding) a job, for wallets that can't hash EIP-712 data
+    /// themselves
+    pub fn get_settlement_digest(
+        &self,
+        job_id: U256,
+        signer: Address,
+        release: bool,
+        memo: B256,
+        nonce: U256,
+        expiry: u64,
+    ) -> B256 {
+        let settlement = Settlement {
+            job_id,
+            signer,
+            release,
+            memo,
+            nonce,
+            expiry,
+        };
+        settlement_hash(&settlement, self.vm().chain_id(), self.vm().contract_address())
+    }
+
+    /// Whether the escrow would accept `signature` as `signer`'s over
+    /// `digest`, by ERC-1271 for contract accounts and ECDSA otherwise
+    pub fn verify_signature(&self, signer: Address, digest: B256, signature: Bytes) -> bool {
+        self.is_signed_by(signer, digest, &signature)
+    }
+
+    /// Signer withdraws the settlement they authorized with `nonce` before
+    /// it is submitted
+    pub fn cancel_authorization(&mut self, nonce: U256) -> Result<(), Vec<u8>> {
+        let signer = self.vm().msg_sender();
+        self.revoke_authorization(signer, nonce)
+    }
+
+    /// Whether `signer` spent or cancelled the authorization made with
+    /// `nonce`
+    pub fn is_authorization_used(&self, signer: Address, nonce: U256) -> bool {
+        self.used_authorizations.get(signer).get(nonce)
+    }
+
+    /// Client opens a private job that stores only a commitment to its
+    /// parties, amount and terms (see [`job_commitment`]). The deposit may
+    /// exceed the committed amount to hide it; the excess goes back to the
+    /// client on reveal.
+    #[payable]
+    pub fn deposit_private(&mut self, commitment: B256, duration: u64) -> Result<U256, Vec<u8>> {
+        if self.paused_now() {
+            return Err(ErrorCode::EscrowIsPaused.into());
+        }
+        self.assert_not_sunset()?;
+        if self.vm().msg_value() == U256::from(0) {
+            return Err(ErrorCode::AmountMustBePositive.into());
+        }
+        if commitment == B256::ZERO {
+            return Err(ErrorCode::InvalidCommitment.into());
+        }
+        if duration == 0 {
+            return Err(ErrorCode::DurationMustBePositive.into());
+        }
+        // The freelancer is hidden until the reveal and screened on release
+        let client = self.vm().msg_sender();
+        let amount = self.vm().msg_value();
+        self.assert_compliant(client, Address::ZERO, amount)?;
+        self.record_deposit_rate(client)?;
+
+        let deadline = self.vm().block_timestamp() + duration;
+
+        let new_id = self.push_job(client, Address::ZERO, amount);
+        let mut job = self.jobs.setter(new_id);
+        job.amount.set(amount);
+        job.deadline.set(Uint::<64, 1>::from(deadline));
+        job.commitment.set(commitment);
+
+        self.record_client_deposit(client, amount);
+        self.lock_escrow(new_id, amount);
+
+        log(self.vm(), PrivateDeposited {
+            job_id: new_id,
+            client,
+            amount,
+            commitment,
+        });
+        #[cfg(feature = "solvency-check")]
+        self.assert_solvent()?;
+
+        Ok(new_id)
+    }
+
+    /// Either party reveals a private job's preimage, assigning the
+    /// freelancer and amount so it can be settled as usual
+    pub fn reveal_job(
+        &mut self,
+        job_id: U256,
+        freelancer: Address,
+        amount: U256,
+        terms: B256,
+        salt: B256,
+    ) -> Result<(), Vec<u8>> {
+        if self.paused_now() {
+            return Err(ErrorCode::EscrowIsPaused.into());
+        }
+
+        let job = self.jobs.get(job_id);
+        let commitment = job.commitment.get();
+        if commitment == B256::ZERO {
+            return Err(ErrorCode::JobIsNotPrivate.into());
+        }
+        let client = job.client.get();
+        let sender = self.vm().msg_sender();
+        if sender != client && sender != freelancer {
+            return Err(ErrorCode::OnlyJobPartiesCanReveal.into());
+        }
+        if job.released.get() || job.refunded.get() {
+            return Err(ErrorCode::JobAlreadySettled.into());
+        }
+        if self.finalized.get(job_id) {
+            return Err(ErrorCode::JobAlreadyFinalized.into());
+        }
+        if job.freelancer.get() != Address::ZERO {
+            return Err(ErrorCode::JobAlreadyRevealed.into());
+        }
+        if freelancer == Address::ZERO {
+            return Err(ErrorCode::InvalidFreelancerAddress.into());
+        }
+        if job_commitment(client, freelancer, amount, terms, salt) != commitment {
+            return Err(ErrorCode::CommitmentMismatch.into());
+        }
+        if amount == U256::ZERO {
+            return Err(ErrorCode::AmountMustBePositive.into());
+        }
+        let deposit = job.amount.get();
+        if amount > deposit {
+            return Err(ErrorCode::AmountExceedsDeposit.into());
+        }
+        self.assert_below_cap(freelancer)?;
+
+        // The deposit now counts for the freelancer too, less the excess
+        // refunded below
+        self.unlock_escrow(job_id, deposit);
+        let mut job = self.jobs.setter(job_id);
+        job.freelancer.set(freelancer);
+        job.amount.set(amount);
+        self.lock_escrow(job_id, deposit);
+        self.index_party(job_id, freelancer);
+        self.snapshot_fee(job_id, client, amount);
+        self.record_assignment(freelancer);
+
+        let excess = deposit - amount;
+        if excess != U256::ZERO {
+            self.record_refund(job_id, client, excess);
+            self.vm().transfer_eth(client, excess)?;
+        }
+
+        log(self.vm(), JobRevealed {
+            job_id,
+            freelancer,
+            amount,
+            terms,
+        });
+        #[cfg(feature = "solvency-check")]
+        self.assert_solvent()?;
+
+        Ok(())
+    }
+
+    /// A private job's commitment; zero for public jobs
+    pub fn get_commitment(&self, job_id: U256) -> B256 {
+        self.jobs.get(job_id).commitment.get()
+    }
+
+    /// A job's dispute: (opened by, opened at, arbitrator, rule by, default
+    /// freelancer split in basis points, still open); zeros if it was never
+    /// disputed, except the arbitrator its client named for it, if any.
+    /// Disputes opened with no arbitrator appointed have none.
+    pub fn get_dispute(&self, job_id: U256) -> DisputeView {
+        let dispute = self.disputes.get(job_id);
+        let opened_at: u64 = dispute.opened_at.get().to();
+        let arbitrator = if opened_at == 0 {
+            self.jobs.get(job_id).arbitrator.get()
+        } else {
+            dispute.arbitrator.get()
+        };
+        (
+            dispute.opened_by.get(),
+            opened_at,
+            arbitrator,
+            dispute.rule_by.get().to(),
+            dispute.default_split_bps.get().to(),
+            self.is_disputed(job_id),
+        )
+    }
+
+    /// When a job's dispute times out (0 if never), whether it is then split
+    /// rather than lifted, and the freelancer's split in basis points
+    pub fn get_dispute_timeout(&self, job_id: U256) -> (u64, bool, u16) {
+        let dispute = self.disputes.get(job_id);
+        (
+            dispute.timeout_at.get().to(),
+            dispute.timeout_split.get(),
+            dispute.timeout_split_bps.get().to(),
+        )
+    }
+
+    /// A job's evidence as parallel lists of hashes, submitters and
+    /// submission times, oldest first
+    pub fn get_evidence(&self, job_id: U256) -> (Vec<B256>, Vec<Address>, Vec<u64>) {
+        let trail = self.evidence.get(job_id);
+        let mut hashes = Vec::with_capacity(trail.len());
+        let mut submitters = Vec::with_capacity(trail.len());
+        let mut submitted_at = Vec::with_capacity(trail.len());
+        for i in 0..trail.len() {
+            if let Some(evidence) = trail.getter(i) {
+                hashes.push(evidence.evidence_hash.get());
+                submitters.push(evidence.submitter.get());
+                submitted_at.push(evidence.submitted_at.get().to());
+            }
+        }
+        (hashes, submitters, submitted_at)
+    }
+
+    /// Client attaches a `hook` contract whose `onReleased`/`onRefunded` is
+    /// called when the job settles; the zero address detaches it
+    pub fn set_settlement_hook(&mut self, job_id: U256, hook: Address) -> Result<(), Vec<u8>> {
+        let job = self.jobs.get(job_id);
+        if job.client.get() != self.vm().msg_sender() {
+            return Err(ErrorCode::OnlyClientCanSetHook.into());
+        }
+        if job.released.get() || job.refunded.get() {
+            return Err(ErrorCode::JobAlreadySettled.into());
+        }
+        if self.finalized.get(job_id) {
+            return Err(ErrorCode::JobAlreadyFinalized.into());
+        }
+
+        self.settlement_hooks.setter(job_id).set(hook);
+
+        log(self.vm(), SettlementHookSet {
+            job_id,
+            hook,
+        });
+
+        Ok(())
+    }
+
+    /// Settlement hook attached to a job; zero if none
+    pub fn get_settlement_hook(&self, job_id: U256) -> Address {
+        self.settlement_hooks.get(job_id)
+    }
+
+    /// Client releases funds to freelancer, with an optional `memo`
+    pub fn release(&mut self, job_id: U256, memo: B256) -> Result<(), Vec<u8>> {
+        let sender = self.vm().msg_sender();
+        self.release_as(sender, job_id, memo)
+    }
+
+    /// Client releases `amount` of a job's escrow to the freelancer at any
+    /// time, e.g. as an ad-hoc progress payment. The rest stays escrowed
+    /// under the job's original terms; releasing all of it settles the job.
+    pub fn release_partial(&mut self, job_id: U256, amount: U256) -> Result<(), Vec<u8>> {
+        if self.paused_now() {
+            return Err(ErrorCode::EscrowIsPaused.into());
+        }
+
+        let job = self.jobs.get(job_id);
+        if job.client.get() != self.vm().msg_sender() {
+            return Err(ErrorCode::OnlyClientCanRelease.into());
+        }
+        if job.released.get() || job.refunded.get() {
+            return Err(ErrorCode::JobAlreadySettled.into());
+        }
+        if self.finalized.get(job_id) {
+            return Err(ErrorCode::JobAlreadyFinalized.into());
+        }
+        self.assert_funded(job_id)?;
+        self.assert_not_staked(job_id)?;
+        if job.freelancer.get() == Address::ZERO {
+            return Err(ErrorCode::JobNotRevealed.into());
+        }
+        if !self.tranches.get(job_id).is_empty() {
+            return Err(ErrorCode::JobIsTranched.into());
+        }
+        if self.has_clawback(job_id) {
+            return Err(ErrorCode::JobHasClawbackWindow.into());
+        }
+        if self.has_sla(job_id) {
+            return Err(ErrorCode::JobHasSLA.into());
+        }
+        self.assert_not_hourly(job_id)?;
+        self.assert_no_basket(job_id)?;
+        if amount.is_zero() {
+            return Err(ErrorCode::AmountMustBePositive.into());
+        }
+        let escrowed = job.amount.get();
+        if amount > escrowed {
+            return Err(ErrorCode::AmountExceedsEscrow.into());
+        }
+        self.assert_not_disputed(job_id)?;
+        self.assert_unilateral_release(job_id)?;
+
+        let freelancer = job.freelancer.get();
+        self.assert_compliant(job.client.get(), freelancer, amount)?;
+
+        let remaining = escrowed - amount;
+        let mut job = self.jobs.setter(job_id);
+        job.amount.set(remaining);
+        if remaining.is_zero() {
+            job.released.set(true);
+            self.close_job(job_id);
+        }
+        self.record_release(job_id, freelancer, amount);
+
+        self.pay_release(job_id, freelancer, amount)?;
+
+        log(self.vm(), PartiallyReleased {
+            job_id,
+            amount,
+            remaining,
+        });
+        if remaining.is_zero() {
+            self.call_settlement_hook(job_id, amount, true);
+        }
+        #[cfg(feature = "solvency-check")]
+        self.assert_solvent()?;
+
+        Ok(())
+    }
+
+    /// What releasing a job now would pay, as a `SettlementPreviewView`. The
+    /// withheld share goes to the freelancer's withholding destination, the
+    /// platform fee to the marketplace the job was opened through, and any
+    /// holdback stays escrowed through its warranty.
+    pub fn preview_release(&self, job_id: U256) -> Result<SettlementPreviewView, Vec<u8>> {
+        self.assert_previewable(job_id)?;
+        let job = self.jobs.get(job_id);
+        let freelancer = job.freelancer.get();
+        if freelancer == Address::ZERO {
+            return Err(ErrorCode::JobNotRevealed.into());
+        }
+        self.assert_not_hourly(job_id)?;
+
+        let amount = job.amount.get();
+        let held = self.pending_holdback(job_id, amount);
+        let late = self.late_refund(job_id, amount);
+        let (net, withheld, fee, platform_fee) =
+            self.release_split(job_id, freelancer, amount - held - late);
+        Ok((net, withheld, late, fee, platform_fee, held))
+    }
+
+    /// What refunding a job now would pay, as a `SettlementPreviewView`;
+    /// refunds charge neither fee
+    pub fn preview_refund(&self, job_id: U256) -> Result<SettlementPreviewView, Vec<u8>> {
+        self.assert_previewable(job_id)?;
+        if !self.tranches.get(job_id).is_empty() {
+            return Err(ErrorCode::JobIsTranched.into());
+        }
+        self.assert_not_retained(job_id)?;
+        self.assert_no_queued_release(job_id)?;
+        self.assert_not_hourly(job_id)?;
+
+        let amount = self.jobs.get(job_id).amount.get();
+        Ok((U256::ZERO, U256::ZERO, amount, U256::ZERO, U256::ZERO, U256::ZERO))
+    }
+
+    /// Fees on a hypothetical job `client` would open paying `amount` of
+    /// `token` (zero for ETH) through `platform` at `platform_bps`, as
+    /// `deposit_with_platform` takes them (both zero for no platform), after
+    /// the client's volume discount or exemption: (protocol fee, platform
+    /// fee, net payout before the freelancer's own withholding)
+    pub fn quote_fee(
+        &self,
+        client: Address,
+        amount: U256,
+        token: Address,
+        platform: Address,
+        platform_bps: u16,
+    ) -> Result<(U256, U256, U256), Vec<u8>> {
+        if token != Address::ZERO {
+            return Err(ErrorCode::UnsupportedToken.into());
+        }
+        if platform == Address::ZERO && platform_bps != 0 {
+            return Err(ErrorCode::InvalidPlatformAddress.into());
+        }
+        if platform_bps > MAX_PLATFORM_FEE_BPS {
+            return Err(ErrorCode::InvalidPlatformFee.into());
+        }
+
+        let protocol_fee = fee_on(amount, self.fee_rate(client, amount));
+        let platform_fee = fee_on(amount, platform_bps);
+        Ok((protocol_fee, platform_fee, amount - protocol_fee - platform_fee))
+    }
+
+    /// Client refunds funds before deadline, with an optional `memo`
+    pub fn refund(&mut self, job_id: U256, memo: B256) -> Result<(), Vec<u8>> {
+        let sender = self.vm().msg_sender();
+        self.refund_as(sender, job_id, memo)
+    }
+
+    /// Freelancer gives up a job, refunding everything still escrowed to the
+    /// client, with an optional `memo`; allowed at any time before settlement
+    pub fn return_funds(&mut self, job_id: U256, memo: B256) -> Result<(), Vec<u8>> {
+        let sender = self.vm().msg_sender();
+        self.return_funds_as(sender, job_id, memo)
+    }
+
+    /// Client asks to cancel a job opened with a notice period; it can be
+    /// refunded once the notice is over unless the freelancer delivers first
+    pub fn request_cancellation(&mut self, job_id: U256) -> Result<(), Vec<u8>> {
+        if self.paused_now() {
+            return Err(ErrorCode::EscrowIsPaused.into());
+        }
+
+        let job = self.jobs.get(job_id);
+        if job.client.get() != self.vm().msg_sender() {
+            return Err(ErrorCode::OnlyClientCanCancel.into());
+        }
+        if job.released.get() || job.refunded.get() {
+            return Err(ErrorCode::JobAlreadySettled.into());
+        }
+        if self.finalized.get(job_id) {
+            return Err(ErrorCode::JobAlreadyFinalized.into());
+        }
+        let cancellation = self.cancellations.get(job_id);
+        let notice: u64 = cancellation.notice.get().to();
+        if notice == 0 {
+            return Err(ErrorCode::JobHasNoNoticePeriod.into());
+        }
+        if cancellation.effective_at.get() != Uint::<64, 1>::ZERO {
+            return Err(ErrorCode::CancellationAlreadyRequested.into());
+        }
+        let now = self.vm().block_timestamp();
+        if now >= job.deadline.get().to() {
+            return Err(ErrorCode::DeadlinePassed.into());
+        }
+
+        let effective_at = now.saturating_add(notice);
+        self.cancellations
+            .setter(job_id)
+            .effective_at
+            .set(Uint::<64, 1>::from(effective_at));
+
+        log(self.vm(), CancellationRequested {
+            job_id,
+            effective_at,
+        });
+
+        Ok(())
+    }
+
+    /// Freelancer submits a `delivery` (e.g. a content hash): during the
+    /// notice period, withdrawing the client's pending cancellation, or on
+    /// a job with an SLA, stopping the clock on late refunds
+    pub fn submit_delivery(&mut self, job_id: U256, delivery: B256) -> Result<(), Vec<u8>> {
+        if self.paused_now() {
+            return Err(ErrorCode::EscrowIsPaused.into());
+        }
+
+        let job = self.jobs.get(job_id);
+        if job.freelancer.get() != self.vm().msg_sender() {
+            return Err(ErrorCode::OnlyFreelancerCanDeliver.into());
+        }
+        if job.released.get() || job.refunded.get() {
+            return Err(ErrorCode::JobAlreadySettled.into());
+        }
+        if self.finalized.get(job_id) {
+            return Err(ErrorCode::JobAlreadyFinalized.into());
+        }
+        if self.has_sla(job_id) {
+            self.stamp_delivery(job_id)?;
+        } else {
+            let effective_at: u64 = self.cancellations.get(job_id).effective_at.get().to();
+            if effective_at == 0 {
+                return Err(ErrorCode::NoCancellationPending.into());
+            }
+            if self.vm().block_timestamp() >= effective_at {
+                return Err(ErrorCode::NoticePeriodOver.into());
+            }
+
+            self.cancellations
+                .setter(job_id)
+                .effective_at
+                .set(Uint::<64, 1>::ZERO);
+        }
+
+        log(self.vm(), DeliverySubmitted {
+            job_id,
+            delivery,
+        });
+
+        Ok(())
+    }
+
+    /// Freelancer pushes a job's deadline back to `new_deadline`, giving
+    /// themselves more time; the client can refund until then
+    pub fn extend_deadline(&mut self, job_id: U256, new_deadline: u64) -> Result<(), Vec<u8>> {
+        if self.paused_now() {
+            return Err(ErrorCode::EscrowIsPaused.into());
+        }
+
+        self.assert_settleable(job_id)?;
+        let job = self.jobs.get(job_id);
+        if job.freelancer.get() != self.vm().msg_sender() {
+            return Err(ErrorCode::OnlyFreelancerCanExtend.into());
+        }
+        if !self.tranches.get(job_id).is_empty() {
+            return Err(ErrorCode::JobIsTranched.into());
+        }
+        let deadline: u64 = job.deadline.get().to();
+        if new_deadline <= deadline {
+            return Err(ErrorCode::DeadlineMustBeLater.into());
+        }
+
+        self.jobs.setter(job_id).deadline.set(Uint::<64, 1>::from(new_deadline));
+
+        self.record_amendment(
+            job_id,
+            JOB_FIELD_DEADLINE,
+            U256::from(deadline).into(),
+            U256::from(new_deadline).into(),
+        );
+
+        Ok(())
+    }
+
+    /// Client offers control of a live job to `new_client`, who takes over
+    /// once they accept; the zero address withdraws the offer
+    pub fn transfer_client(&mut self, job_id: U256, new_client: Address) -> Result<(), Vec<u8>> {
+        if self.paused_now() {
+            return Err(ErrorCode::EscrowIsPaused.into());
+        }
+
+        self.assert_settleable(job_id)?;
+        let job = self.jobs.get(job_id);
+        let client = job.client.get();
+        if client != self.vm().msg_sender() {
+            return Err(ErrorCode::OnlyClientCanTransfer.into());
+        }
+        if new_client == client || new_client == job.freelancer.get() {
+            return Err(ErrorCode::InvalidClientAddress.into());
+        }
+
+        self.pending_clients.setter(job_id).set(new_client);
+
+        log(self.vm(), ClientTransferProposed {
+            job_id,
+            client,
+            new_client,
+        });
+
+        Ok(())
+    }
+
+    /// The address a job was offered to becomes its client, taking over
+    /// refunds, releases and everything else the client controls
+    pub fn accept_client(&mut self, job_id: U256) -> Result<(), Vec<u8>> {
+        if self.paused_now() {
+            return Err(ErrorCode::EscrowIsPaused.into());
+        }
+
+        self.assert_settleable(job_id)?;
+        let new_client = self.vm().msg_sender();
+        if self.pending_clients.get(job_id) != new_client {
+            return Err(ErrorCode::OnlyPendingClientCanAccept.into());
+        }
+        let job = self.jobs.get(job_id);
+        let client = job.client.get();
+        let amount = job.amount.get();
+
+        self.pending_clients.setter(job_id).set(Address::ZERO);
+        self.unlock_escrow(job_id, amount);
+        self.jobs.setter(job_id).client.set(new_client);
+        self.lock_escrow(job_id, amount);
+        self.index_party(job_id, new_client);
+
+        self.record_amendment(
+            job_id,
+            JOB_FIELD_CLIENT,
+            client.into_word(),
+            new_client.into_word(),
+        );
+
+        Ok(())
+    }
+
+    /// Address a job's client role was offered to; zero if none
+    pub fn get_pending_client(&self, job_id: U256) -> Address {
+        self.pending_clients.get(job_id)
+    }
+
+    /// Freelancer commits to a funded job, after which the client can no
+    /// longer hand it to someone else
+    pub fn accept_job(&mut self, job_id: U256) -> Result<(), Vec<u8>> {
+        if self.paused_now() {
+            return Err(ErrorCode::EscrowIsPaused.into());
+        }
+
+        self.assert_settleable(job_id)?;
+        let freelancer = self.vm().msg_sender();
+        if self.jobs.get(job_id).freelancer.get() != freelancer {
+            return Err(ErrorCode::OnlyFreelancerCanAccept.into());
+        }
+        if self.accepted_jobs.get(job_id) {
+            return Err(ErrorCode::JobAlreadyAccepted.into());
+        }
+
+        self.accepted_jobs.setter(job_id).set(true);
+
+        log(self.vm(), JobAccepted {
+            job_id,
+            freelancer,
+        });
+
+        Ok(())
+    }
+
+    /// Whether the freelancer has accepted a job
+    pub fn is_job_accepted(&self, job_id: U256) -> bool {
+        self.accepted_jobs.get(job_id)
+    }
+
+    /// Client moves a job nobody has accepted yet to `new_freelancer`, e.g.
+    /// after a wrong address or a freelancer bowing out; the job's place
+    /// under the caps moves with it and any freelancer approval is dropped
+    pub fn reassign_freelancer(
+        &mut self,
+        job_id: U256,
+        new_freelancer: Address,
+    ) -> Result<(), Vec<u8>> {
+        if self.paused_now() {
+            return Err(ErrorCode::EscrowIsPaused.into());
+        }
+
+        self.assert_settleable(job_id)?;
+        let job = self.jobs.get(job_id);
+        let client = job.client.get();
+        let freelancer = job.freelancer.get();
+        let amount = job.amount.get();
+        if client != self.vm().msg_sender() {
+            return Err(ErrorCode::OnlyClientCanReassign.into());
+        }
+        self.assert_no_queued_release(job_id)?;
+        self.assert_no_pending_timesheets(job_id)?;
+        if freelancer == Address::ZERO {
+            return Err(ErrorCode::JobNotRevealed.into());
+        }
+        if self.accepted_jobs.get(job_id) {
+            return Err(ErrorCode::JobAlreadyAccepted.into());
+        }
+        if job.attestation.get() != B256::ZERO {
+            return Err(ErrorCode::JobIsAttested.into());
+        }
+        self.assert_not_disputed(job_id)?;
+        if new_freelancer == Address::ZERO
+            || new_freelancer == freelancer
+            || new_freelancer == client
+        {
+            return Err(ErrorCode::InvalidFreelancerAddress.into());
+        }
+        self.assert_compliant(client, new_freelancer, amount)?;
+        self.assert_below_cap(new_freelancer)?;
+
+        self.record_unassignment(freelancer);
+        self.record_assignment(new_freelancer);
+        self.unlock_escrow(job_id, amount);
+        self.jobs.setter(job_id).freelancer.set(new_freelancer);
+        self.lock_escrow(job_id, amount);
+        self.index_party(job_id, new_freelancer);
+        self.dual_approvals
+            .setter(job_id)
+            .freelancer_approved
+            .set(false);
+
+        self.record_amendment(
+            job_id,
+            JOB_FIELD_FREELANCER,
+            freelancer.into_word(),
+            new_freelancer.into_word(),
+        );
+        log(self.vm(), FreelancerChanged {
+            job_id,
+            old_freelancer: freelancer,
+            new_freelancer,
+        });
+
+        Ok(())
+    }
+
+    /// Either party opens a dispute over a funded, unsettled job, sending
+    /// the stake `quote_dispute_stake` asks for, with an optional `memo`
+    /// (e.g. a ticket reference); a job can only be disputed once
+    #[payable]
+    pub fn open_dispute(&mut self, job_id: U256, memo: B256) -> Result<(), Vec<u8>> {
+        if self.paused_now() {
+            return Err(ErrorCode::EscrowIsPaused.into());
+        }
+        self.assert_not_retired()?;
+        self.assert_settleable(job_id)?;
+        self.assert_not_staked(job_id)?;
+        let opened_by = self.vm().msg_sender();
+        if !self.is_job_party(job_id, opened_by) {
+            return Err(ErrorCode::OnlyJobPartiesCanDispute.into());
+        }
+        if !self.disputes.get(job_id).opened_at.get().is_zero() {
+            return Err(ErrorCode::DisputeAlreadyOpened.into());
+        }
+        self.assert_no_queued_release(job_id)?;
+        let stake = self.vm().msg_value();
+        if stake != self.dispute_stake_for(job_id) {
+            return Err(ErrorCode::IncorrectStake.into());
+        }
+
+        let now = self.vm().block_timestamp();
+        let arbitrator = self.job_arbitrator(job_id);
+        let rule_by = if arbitrator == Address::ZERO {
+            0
+        } else {
+            now.saturating_add(self.ruling_period.get().to())
+        };
+        let default_split_bps = self.default_split_bps.get();
+        let timeout: u64 = self.dispute_timeout.get().to();
+        let timeout_at = if timeout == 0 { 0 } else { now.saturating_add(timeout) };
+        let timeout_split = self.timeout_split.get();
+        let timeout_split_bps = self.timeout_split_bps.get();
+        let mut dispute = self.disputes.setter(job_id);
+        dispute.opened_by.set(opened_by);
+        dispute.opened_at.set(Uint::<64, 1>::from(now));
+        dispute.arbitrator.set(arbitrator);
+        dispute.rule_by.set(Uint::<64, 1>::from(rule_by));
+        dispute.default_split_bps.set(default_split_bps);
+        dispute.timeout_at.set(Uint::<64, 1>::from(timeout_at));
+        dispute.timeout_split.set(timeout_split);
+        dispute.timeout_split_bps.set(timeout_split_bps);
+        self.lock_dispute_stake(job_id, stake);
+        self.record_dispute(job_id);
+        let disputed = self.total_disputed.get() + self.jobs.get(job_id).amount.get();
+        self.total_disputed.set(disputed);
+
+        log(self.vm(), DisputeOpened {
+            job_id,
+            opened_by,
+            memo,
+        });
+
+        Ok(())
+    }
+
+    /// Either party adds the hash of a piece of evidence to an open dispute
+    pub fn submit_evidence(&mut self, job_id: U256, evidence_hash: B256) -> Result<(), Vec<u8>> {
+        if self.paused_now() {
+            return Err(ErrorCode::EscrowIsPaused.into());
+        }
+        if !self.is_disputed(job_id) {
+            return Err(ErrorCode::NoOpenDispute.into());
+        }
+        let submitter = self.vm().msg_sender();
+        if !self.is_job_party(job_id, submitter) {
+            return Err(ErrorCode::OnlyJobPartiesCanSubmitEvidence.into());
+        }
+        if evidence_hash == B256::ZERO {
+            return Err(ErrorCode::InvalidEvidence.into());
+        }
+        let index = self.evidence.get(job_id).len();
+        if index >= MAX_EVIDENCE {
+            return Err(ErrorCode::TooMuchEvidence.into());
+        }
+
+        let now = self.vm().block_timestamp();
+        let mut trail = self.evidence.setter(job_id);
+        let mut evidence = trail.grow();
+        evidence.evidence_hash.set(evidence_hash);
+        evidence.submitter.set(submitter);
+        evidence.submitted_at.set(Uint::<64, 1>::from(now));
+
+        log(self.vm(), EvidenceSubmitted {
+            job_id,
+            submitter,
+            index: U256::from(index),
+            evidence_hash,
+        });
+
+        Ok(())
+    }
+
+    /// ARBITRATOR: settle a disputed job before the ruling period ends,
+    /// paying `freelancer_bps` of it to the freelancer and the rest back to
+    /// the client; with appeals on, the ruling is held for appeal first
+    pub fn rule(&mut self, job_id: U256, freelancer_bps: u16) -> Result<(), Vec<u8>> {
+        let sender = self.vm().msg_sender();
+        self.rule_as(sender, job_id, freelancer_bps)
+    }
+
+    /// Settle a disputed job on its arbitrator's signed `Verdict`, e.g. a
+    /// panel's multisig; anyone can submit it
+    pub fn rule_with_signature(
+        &mut self,
+        job_id: U256,
+        freelancer_bps: u16,
+        nonce: U256,
+        expiry: u64,
+        signature: Bytes,
+    ) -> Result<(), Vec<u8>> {
+        let arbitrator = self.disputes.get(job_id).arbitrator.get();
+        let verdict = Verdict {
+            job_id,
+            freelancer_bps,
+            nonce,
+            expiry,
+        };
+        self.spend_verdict(arbitrator, &verdict, &signature)?;
+        self.rule_as(arbitrator, job_id, freelancer_bps)
+    }
+
+    /// Digest a job's arbitrator signs to rule `freelancer_bps` of it to the
+    /// freelancer, for wallets that can't hash EIP-712 data themselves
+    pub fn get_verdict_digest(
+        &self,
+        job_id: U256,
+        freelancer_bps: u16,
+        nonce: U256,
+        expiry: u64,
+    ) -> B256 {
+        let verdict = Verdict {
+            job_id,
+            freelancer_bps,
+            nonce,
+            expiry,
+        };
+        verdict_hash(&verdict, self.vm().chain_id(), self.vm().contract_address())
+    }
+
+    /// Either party settles a dispute at its default split once the
+    /// arbitrator has let the ruling period pass
+    pub fn resolve_by_default(&mut self, job_id: U256) -> Result<(), Vec<u8>> {
+        if self.paused_now() {
+            return Err(ErrorCode::EscrowIsPaused.into());
+        }
+        if !self.is_disputed(job_id) {
+            return Err(ErrorCode::NoOpenDispute.into());
+        }
+        if !self.is_job_party(job_id, self.vm().msg_sender()) {
+            return Err(ErrorCode::OnlyJobPartiesCanResolve.into());
+        }
+        let dispute = self.disputes.get(job_id);
+        if dispute.arbitrator.get() == Address::ZERO {
+            return Err(ErrorCode::NoArbitratorAssigned.into());
+        }
+        self.assert_no_jury(job_id)?;
+        self.assert_no_held_ruling(job_id)?;
+        if self.vm().block_timestamp() < dispute.rule_by.get().to() {
+            return Err(ErrorCode::RulingPeriodNotOver.into());
+        }
+        let default_split_bps = dispute.default_split_bps.get().to();
+
+        let sender = self.vm().msg_sender();
+        self.settle_dispute(sender, job_id, default_split_bps, true)?;
+        #[cfg(feature = "solvency-check")]
+        self.assert_solvent()?;
+
+        Ok(())
+    }
+
+    /// Apply the timeout rule to a dispute left unresolved past its maximum
+    /// duration: split the job as configured, or lift the dispute so the job
+    /// settles by its deadline again. Anyone can call it.
+    pub fn time_out_dispute(&mut self, job_id: U256) -> Result<(), Vec<u8>> {
+        if self.paused_now() {
+            return Err(ErrorCode::EscrowIsPaused.into());
+        }
+        if !self.is_disputed(job_id) {
+            return Err(ErrorCode::NoOpenDispute.into());
+        }
+        let dispute = self.disputes.get(job_id);
+        let timeout_at: u64 = dispute.timeout_at.get().to();
+        if timeout_at == 0 {
+            return Err(ErrorCode::NoDisputeTimeout.into());
+        }
+        self.assert_no_held_ruling(job_id)?;
+        if self.vm().block_timestamp() < timeout_at {
+            return Err(ErrorCode::DisputeTimeoutNotReached.into());
+        }
+        let split = dispute.timeout_split.get();
+        let split_bps = dispute.timeout_split_bps.get().to();
+
+        log(self.vm(), DisputeTimedOut {
+            job_id,
+            split,
+        });
+        if split {
+            let sender = self.vm().msg_sender();
+            self.settle_dispute(sender, job_id, split_bps, true)?;
+        } else {
+            self.disputes.setter(job_id).lifted.set(true);
+            let stake = self.take_dispute_stake(job_id);
+            self.pay_dispute_stake(job_id, stake, false)?;
+        }
+        #[cfg(feature = "solvency-check")]
+        self.assert_solvent()?;
+
+        Ok(())
+    }
+
+    /// Either party puts an open dispute to a panel of jurors drawn from the
+    /// registry, taking it out of the arbitrator's hands; only once, and
+    /// before the ruling period ends
+    pub fn escalate_to_jury(&mut self, job_id: U256) -> Result<(), Vec<u8>> {
+        if self.paused_now() {
+            return Err(ErrorCode::EscrowIsPaused.into());
+        }
+        if !self.is_disputed(job_id) {
+            return Err(ErrorCode::NoOpenDispute.into());
+        }
+        if !self.is_job_party(job_id, self.vm().msg_sender()) {
+            return Err(ErrorCode::OnlyJobPartiesCanEscalate.into());
+        }
+        if !self.juries.get(job_id).vote_by.get().is_zero() {
+            return Err(ErrorCode::JuryAlreadyDrawn.into());
+        }
+        if self.jury_size.get().is_zero() {
+            return Err(ErrorCode::JuriesDisabled.into());
+        }
+        self.assert_no_held_ruling(job_id)?;
+        let dispute = self.disputes.get(job_id);
+        if dispute.arbitrator.get() != Address::ZERO
+            && self.vm().block_timestamp() >= dispute.rule_by.get().to()
+        {
+            return Err(ErrorCode::RulingPeriodOver.into());
+        }
+
+        let panel = self.draw_jury(job_id)?;
+        self.seat_jury(job_id, &panel);
+
+        Ok(())
+    }
+
+    /// A juror on a job's panel votes the share of it, in basis points, the
+    /// freelancer should get; once, before voting closes
+    pub fn cast_jury_vote(&mut self, job_id: U256, freelancer_bps: u16) -> Result<(), Vec<u8>> {
+        let juror = self.vm().msg_sender();
+        let jury = self.juries.get(job_id);
+        let vote = jury.votes.get(juror);
+        if !vote.seated.get() {
+            return Err(ErrorCode::NotOnJury.into());
+        }
+        if jury.tallied.get() || self.vm().block_timestamp() >= jury.vote_by.get().to() {
+            return Err(ErrorCode::VotingClosed.into());
+        }
+        if vote.voted.get() {
+            return Err(ErrorCode::AlreadyVoted.into());
+        }
+        if freelancer_bps > BASIS_POINTS {
+            return Err(ErrorCode::InvalidSplit.into());
+        }
+
+        self.record_jury_vote(job_id, juror, freelancer_bps);
+
+        Ok(())
+    }
+
+    /// Count a jury's votes once voting closes or every juror has voted,
+    /// settling the dispute at the majority's split, or its default split
+    /// without one, and slashing jurors outside the majority. Anyone can
+    /// call it; a dispute settled meanwhile just releases the jurors. The
+    /// majority's split is a ruling, held for appeal if appeals are on.
+    pub fn tally_jury(&mut self, job_id: U256) -> Result<(), Vec<u8>> {
+        if self.paused_now() {
+            return Err(ErrorCode::EscrowIsPaused.into());
+        }
+        let jury = self.juries.get(job_id);
+        if jury.vote_by.get().is_zero() {
+            return Err(ErrorCode::NoJury.into());
+        }
+        if jury.tallied.get() {
+            return Err(ErrorCode::JuryAlreadyTallied.into());
+        }
+        let all_voted = jury.votes_cast.get().to::<usize>() == jury.panel.len();
+        if !all_voted && self.vm().block_timestamp() < jury.vote_by.get().to() {
+            return Err(ErrorCode::VotingStillOpen.into());
+        }
+
+        let disputed = self.is_disputed(job_id);
+        let verdict = if disputed { self.jury_verdict(job_id) } else { None };
+        let slashed = self.dismiss_jury(job_id, verdict);
+        let freelancer_bps = verdict.unwrap_or(self.disputes.get(job_id).default_split_bps.get().to());
+        log(self.vm(), JuryTallied {
+            job_id,
+            decided: verdict.is_some(),
+            freelancer_bps,
+            slashed,
+        });
+        let sender = self.vm().msg_sender();
+        if disputed && verdict.is_some() {
+            self.settle_ruling(sender, job_id, freelancer_bps)?;
+        } else if disputed {
+            self.settle_dispute(sender, job_id, freelancer_bps, true)?;
+        }
+        #[cfg(feature = "solvency-check")]
+        self.assert_solvent()?;
+
+        Ok(())
+    }
+
+    /// A job's jury: (panel, when voting closes, votes cast, whether it was
+    /// tallied); an empty panel if the dispute never went to one
+    pub fn get_jury(&self, job_id: U256) -> (Vec<Address>, u64, u8, bool) {
+        let jury = self.juries.get(job_id);
+        (
+            self.jury_panel(job_id),
+            jury.vote_by.get().to(),
+            jury.votes_cast.get().to(),
+            jury.tallied.get(),
+        )
+    }
+
+    /// A juror's vote on a job: (whether they voted, freelancer's share in
+    /// basis points)
+    pub fn get_jury_vote(&self, job_id: U256, juror: Address) -> (bool, u16) {
+        let jury = self.juries.get(job_id);
+        let vote = jury.votes.get(juror);
+        (vote.voted.get(), vote.freelancer_bps.get().to())
+    }
+
+    /// Either party appeals a held ruling within its window, sending the
+    /// bond `quote_appeal_bond` asks for and naming the split they want;
+    /// returns the governance proposal that decides it
+    #[payable]
+    pub fn appeal_ruling(&mut self, job_id: U256, freelancer_bps: u16) -> Result<U256, Vec<u8>> {
+        if self.paused_now() {
+            return Err(ErrorCode::EscrowIsPaused.into());
+        }
+        self.assert_not_retired()?;
+        if !self.ruling_held(job_id) {
+            return Err(ErrorCode::NoPendingRuling.into());
+        }
+        let appellant = self.vm().msg_sender();
+        if !self.is_job_party(job_id, appellant) {
+            return Err(ErrorCode::OnlyJobPartiesCanAppeal.into());
+        }
+        let appeal = self.appeals.get(job_id);
+        if appeal.appellant.get() != Address::ZERO {
+            return Err(ErrorCode::RulingAlreadyAppealed.into());
+        }
+        if self.vm().block_timestamp() >= appeal.appealable_until.get().to() {
+            return Err(ErrorCode::AppealWindowOver.into());
+        }
+        if freelancer_bps > BASIS_POINTS || freelancer_bps == appeal.freelancer_bps.get().to::<u16>() {
+            return Err(ErrorCode::InvalidSplit.into());
+        }
+        let bond = self.vm().msg_value();
+        if bond != self.appeal_bond_for(job_id) {
+            return Err(ErrorCode::IncorrectBond.into());
+        }
+
+        let proposal_id = self.lodge_appeal(appellant, job_id, freelancer_bps, bond);
+        #[cfg(feature = "solvency-check")]
+        self.assert_solvent()?;
+
+        Ok(proposal_id)
+    }
+
+    /// Settle a job at its held ruling once nobody appealed it in time, or
+    /// its appeal lapsed without governance upholding it, in which case the
+    /// appeal bond goes to the other party. Anyone can call it.
+    pub fn finalize_ruling(&mut self, job_id: U256) -> Result<(), Vec<u8>> {
+        if self.paused_now() {
+            return Err(ErrorCode::EscrowIsPaused.into());
+        }
+        if !self.ruling_held(job_id) {
+            return Err(ErrorCode::NoPendingRuling.into());
+        }
+        if !self.ruling_final(job_id) {
+            return Err(ErrorCode::RulingStillAppealable.into());
+        }
+
+        self.finalize_held_ruling(job_id)?;
+        #[cfg(feature = "solvency-check")]
+        self.assert_solvent()?;
+
+        Ok(())
+    }
+
+    /// A job's held ruling: (who ruled, freelancer's share in basis points,
+    /// when the appeal window closes); all zero if none was held
+    pub fn get_held_ruling(&self, job_id: U256) -> (Address, u16, u64) {
+        let appeal = self.appeals.get(job_id);
+        (
+            appeal.resolver.get(),
+            appeal.freelancer_bps.get().to(),
+            appeal.appealable_until.get().to(),
+        )
+    }
+
+    /// A job's appeal: (appellant, the split they asked for, bond still
+    /// held, deciding governance proposal); a zero appellant if none
+    pub fn get_appeal(&self, job_id: U256) -> (Address, u16, U256, U256) {
+        let appeal = self.appeals.get(job_id);
+        (
+            appeal.appellant.get(),
+            appeal.appeal_bps.get().to(),
+            appeal.bond.get(),
+            appeal.proposal_id.get(),
+        )
+    }
+
+    /// Freelancer claims funds after deadline, with an optional `memo`
+    pub fn auto_release(&mut self, job_id: U256, memo: B256) -> Result<(), Vec<u8>> {
+        if self.paused_now() {
+            return Err(ErrorCode::EscrowIsPaused.into());
+        }
+
+        let job = self.jobs.get(job_id);
+        if job.freelancer.get() != self.vm().msg_sender() {
+            return Err(ErrorCode::OnlyFreelancerCanClaim.into());
+        }
+        if job.released.get() || job.refunded.get() {
+            return Err(ErrorCode::JobAlreadySettled.into());
+        }
+        if self.finalized.get(job_id) {
+            return Err(ErrorCode::JobAlreadyFinalized.into());
+        }
+        self.assert_funded(job_id)?;
+        self.assert_not_staked(job_id)?;
+        if self.vm().block_timestamp() < job.deadline.get().to() {
+            return Err(ErrorCode::DeadlineNotReached.into());
+        }
+        self.assert_not_retained(job_id)?;
+        self.assert_not_disputed(job_id)?;
+        self.assert_no_queued_release(job_id)?;
+        self.assert_not_hourly(job_id)?;
+        self.assert_unilateral_release(job_id)?;
+        self.assert_releasable(job_id)?;
+
+        let amount = job.amount.get();
+        let freelancer = job.freelancer.get();
+        let held = self.pending_holdback(job_id, amount);
+        let late = self.late_refund(job_id, amount);
+        self.assert_compliant(job.client.get(), freelancer, amount - held - late)?;
+        if !held.is_zero() {
+            self.retain_holdback(job_id, held)?;
+            #[cfg(feature = "solvency-check")]
+            self.assert_solvent()?;
+            return Ok(());
+        }
+        if self.has_clawback(job_id) {
+            self.queue_release(job_id, memo);
+            return Ok(());
+        }
+
+        self.jobs.setter(job_id).released.set(true);
+        self.close_job(job_id);
+        let amount = amount - late;
+        self.record_release(job_id, freelancer, amount);
+
+        self.refund_late(job_id, late)?;
+        self.pay_release(job_id, freelancer, amount)?;
+        self.pay_settlement_rebate(job_id, amount)?;
+        self.pay_basket(job_id, BASIS_POINTS)?;
+
+        log(self.vm(), AutoReleased {
+            job_id,
+            amount,
+            memo,
+        });
+        self.call_settlement_hook(job_id, amount, true);
+        #[cfg(feature = "solvency-check")]
+        self.assert_solvent()?;
+
+        Ok(())
+    }
+
+    /// Pay out a job's queued release once its clawback window is over;
+    /// anyone can call it
+    pub fn finalize_release(&mut self, job_id: U256) -> Result<(), Vec<u8>> {
+        if self.paused_now() {
+            return Err(ErrorCode::EscrowIsPaused.into());
+        }
+
+        self.assert_settleable(job_id)?;
+        self.assert_not_staked(job_id)?;
+        let clawback = self.clawbacks.get(job_id);
+        let payable_at: u64 = clawback.payable_at.get().to();
+        let memo = clawback.memo.get();
+        if payable_at == 0 {
+            return Err(ErrorCode::NoReleaseQueued.into());
+        }
+        if self.vm().block_timestamp() < payable_at {
+            return Err(ErrorCode::ClawbackWindowOpen.into());
+        }
+
+        let job = self.jobs.get(job_id);
+        let amount = job.amount.get();
+        let freelancer = job.freelancer.get();
+        self.assert_compliant(job.client.get(), freelancer, amount)?;
+
+        self.jobs.setter(job_id).released.set(true);
+        self.close_job(job_id);
+        self.record_release(job_id, freelancer, amount);
+
+        self.pay_release(job_id, freelancer, amount)?;
+        self.pay_settlement_rebate(job_id, amount)?;
+
+        log(self.vm(), Released {
+            job_id,
+            amount,
+            memo,
+        });
+        self.call_settlement_hook(job_id, amount, true);
+        #[cfg(feature = "solvency-check")]
+        self.assert_solvent()?;
+
+        Ok(())
+    }
+
+    /// Arbitrator reverses a queued release made under fraud or duress
+    /// during its clawback window, refunding the client
+    pub fn reverse_release(&mut self, job_id: U256) -> Result<(), Vec<u8>> {
+        if self.paused_now() {
+            return Err(ErrorCode::EscrowIsPaused.into());
+        }
+
+        self.assert_settleable(job_id)?;
+        self.assert_not_staked(job_id)?;
+        let arbitrator = self.job_arbitrator(job_id);
+        if arbitrator == Address::ZERO || self.vm().msg_sender() != arbitrator {
+            return Err(ErrorCode::OnlyArbitrator.into());
+        }
+        let payable_at: u64 = self.clawbacks.get(job_id).payable_at.get().to();
+        if payable_at == 0 {
+            return Err(ErrorCode::NoReleaseQueued.into());
+        }
+        if self.vm().block_timestamp() >= payable_at {
+            return Err(ErrorCode::ClawbackWindowClosed.into());
+        }
+
+        let job = self.jobs.get(job_id);
+        let amount = job.amount.get();
+        let client = job.client.get();
+
+        self.jobs.setter(job_id).refunded.set(true);
+        self.close_job(job_id);
+        self.record_refund(job_id, client, amount);
+
+        self.vm().transfer_eth(client, amount)?;
+
+        log(self.vm(), ReleaseReversed {
+            job_id,
+            arbitrator,
+            amount,
+        });
+        self.call_settlement_hook(job_id, amount, false);
+        #[cfg(feature = "solvency-check")]
+        self.assert_solvent()?;
+
+        Ok(())
+    }
+
+    /// Pay a job's holdback to the freelancer once its warranty window has
+    /// ended without a dispute; anyone can call it
+    pub fn release_holdback(&mut self, job_id: U256) -> Result<(), Vec<u8>> {
+        if self.paused_now() {
+            return Err(ErrorCode::EscrowIsPaused.into());
+        }
+
+        self.assert_settleable(job_id)?;
+        self.assert_not_staked(job_id)?;
+        let release_at: u64 = self.holdbacks.get(job_id).warranty_ends.get().to();
+        if release_at == 0 {
+            return Err(ErrorCode::NoHoldbackRetained.into());
+        }
+        if self.vm().block_timestamp() < release_at {
+            return Err(ErrorCode::WarrantyRunning.into());
+        }
+        self.assert_not_disputed(job_id)?;
+
+        let job = self.jobs.get(job_id);
+        let amount = job.amount.get();
+        let freelancer = job.freelancer.get();
+        self.assert_compliant(job.client.get(), freelancer, amount)?;
+
+        self.jobs.setter(job_id).released.set(true);
+        self.close_job(job_id);
+        self.record_release(job_id, freelancer, amount);
+
+        self.pay_release(job_id, freelancer, amount)?;
+        self.pay_settlement_rebate(job_id, amount)?;
+
+        log(self.vm(), AutoReleased {
+            job_id,
+            amount,
+            memo: B256::ZERO,
+        });
+        self.call_settlement_hook(job_id, amount, true);
+        #[cfg(feature = "solvency-check")]
+        self.assert_solvent()?;
+
+        Ok(())
+    }
+
+    /// Return a job's funds to its client once the freelancer has left them
+    /// unclaimed for the claim window after the deadline, with an optional
+    /// `memo`; anyone can call it, but not while a dispute, jury or held
+    /// ruling is deciding the job
+    pub fn reclaim(&mut self, job_id: U256, memo: B256) -> Result<(), Vec<u8>> {
+        if self.paused_now() {
+            return Err(ErrorCode::EscrowIsPaused.into());
+        }
+
+        self.assert_settleable(job_id)?;
+        self.assert_not_staked(job_id)?;
+        if !self.tranches.get(job_id).is_empty() {
+            return Err(ErrorCode::JobIsTranched.into());
+        }
+        self.assert_not_retained(job_id)?;
+        self.assert_no_queued_release(job_id)?;
+        self.assert_no_pending_timesheets(job_id)?;
+        self.assert_not_disputed(job_id)?;
+        self.assert_no_jury(job_id)?;
+        self.assert_no_held_ruling(job_id)?;
+        let job = self.jobs.get(job_id);
+        let deadline: u64 = job.deadline.get().to();
+        if self.vm().block_timestamp() < deadline.saturating_add(CLAIM_WINDOW) {
+            return Err(ErrorCode::ClaimWindowOpen.into());
+        }
+
+        let amount = job.amount.get();
+        let client = job.client.get();
+
+        self.jobs.setter(job_id).refunded.set(true);
+        self.close_job(job_id);
+        self.record_refund(job_id, client, amount);
+
+        self.vm().transfer_eth(client, amount)?;
+        self.pay_basket(job_id, 0)?;
+
+        log(self.vm(), Reclaimed {
+            job_id,
+            caller: self.vm().msg_sender(),
+            amount,
+            memo,
+        });
+        self.call_settlement_hook(job_id, amount, false);
+        #[cfg(feature = "solvency-check")]
+        self.assert_solvent()?;
+
+        Ok(())
+    }
+
+    /// Freelancer asks to be paid in `token` instead of ETH, receiving at
+    /// least `min_rate` token units per ETH; the zero address reverts to ETH
+    /// and WETH wraps the payout 1:1 without a swap
+    pub fn set_payout_token(&mut self, token: Address, min_rate: U256) -> Result<(), Vec<u8>> {
+        if token != Address::ZERO && min_rate == U256::ZERO {
+            return Err(ErrorCode::InvalidMinRate.into());
+        }
+
+        let freelancer = self.vm().msg_sender();
+        let mut preference = self.payout_tokens.setter(freelancer);
+        preference.token.set(token);
+        preference.min_rate.set(min_rate);
+
+        log(self.vm(), PayoutTokenSet {
+            freelancer,
+            token,
+            min_rate,
+        });
+
+        Ok(())
+    }
+
+    /// A freelancer's payout token (zero for ETH) and minimum rate per ETH
+    pub fn get_payout_token(&self, freelancer: Address) -> (Address, U256) {
+        let preference = self.payout_tokens.get(freelancer);
+        (preference.token.get(), preference.min_rate.get())
+    }
+
+    /// Freelancer proposes `payout_address` to receive their payouts; it can
+    /// be applied after `PAYOUT_ADDRESS_DELAY`, the zero address goes back to
+    /// paying the freelancer directly, and a new proposal replaces a pending one
+    pub fn propose_payout_address(&mut self, payout_address: Address) -> Result<(), Vec<u8>> {
+        if payout_address == self.vm().contract_address() {
+            return Err(ErrorCode::InvalidPayoutAddress.into());
+        }
+
+        let freelancer = self.vm().msg_sender();
+        let effective_at = self
+            .vm()
+            .block_timestamp()
+            .saturating_add(PAYOUT_ADDRESS_DELAY);
+        let mut payout = self.payout_addresses.setter(freelancer);
+        payout.pending.set(payout_address);
+        payout.effective_at.set(Uint::<64, 1>::from(effective_at));
+
+        log(self.vm(), PayoutAddressProposed {
+            freelancer,
+            payout_address,
+            effective_at,
+        });
+
+        Ok(())
+    }
+
+    /// Make a freelancer's proposed payout address current once its delay
+    /// has passed (anyone)
+    pub fn apply_payout_address(&mut self, freelancer: Address) -> Result<(), Vec<u8>> {
+        let effective_at: u64 = self.payout_addresses.get(freelancer).effective_at.get().to();
+        if effective_at == 0 {
+            return Err(ErrorCode::NoPendingPayoutAddress.into());
+        }
+        if self.vm().block_timestamp() < effective_at {
+            return Err(ErrorCode::PayoutAddressDelayNotOver.into());
+        }
+
+        let mut payout = self.payout_addresses.setter(freelancer);
+        let payout_address = payout.pending.get();
+        payout.current.set(payout_address);
+        payout.pending.set(Address::ZERO);
+        payout.effective_at.set(Uint::<64, 1>::ZERO);
+
+        log(self.vm(), PayoutAddressSet {
+            freelancer,
+            payout_address,
+        });
+
+        Ok(())
+    }
+
+    /// A freelancer's payout address: (current, zero if paid directly;
+    /// pending; when the pending one can be applied, 0 if none)
+    pub fn get_payout_address(&self, freelancer: Address) -> (Address, Address, u64) {
+        let payout = self.payout_addresses.get(freelancer);
+        (
+            payout.current.get(),
+            payout.pending.get(),
+            payout.effective_at.get().to(),
+        )
+    }
+
+    /// Freelancer has `rate_bps` of every payout withheld and sent to
+    /// `destination` for tax; a zero rate turns withholding off
+    pub fn set_withholding(&mut self, rate_bps: u16, destination: Address) -> Result<(), Vec<u8>> {
+        if rate_bps > BASIS_POINTS {
+            return Err(ErrorCode::InvalidWithholdingRate.into());
+        }
+        if rate_bps != 0 && destination == Address::ZERO {
+            return Err(ErrorCode::InvalidWithholdingAddress.into());
+        }
+
+        let freelancer = self.vm().msg_sender();
+        let mut withholding = self.withholdings.setter(freelancer);
+        withholding.rate_bps.set(Uint::<16, 1>::from(rate_bps));
+        withholding.destination.set(destination);
+
+        log(self.vm(), WithholdingSet {
+            freelancer,
+            destination,
+            rate_bps,
+        });
+
+        Ok(())
+    }
+
+    /// A freelancer's withholding: (rate in basis points, destination)
+    pub fn get_withholding(&self, freelancer: Address) -> (u16, Address) {
+        let withholding = self.withholdings.get(freelancer);
+        (withholding.rate_bps.get().to(), withholding.destination.get())
+    }
+
+    /// FEE MANAGER: send `amount` of the accrued protocol fees to `to`, which
+    /// must be the treasury once one is set; never more than the balance
+    /// holds beyond open jobs and credit
+    pub fn withdraw_fees(&mut self, to: Address, amount: U256) -> Result<(), Vec<u8>> {
+        self.assert_fee_manager()?;
+        if to == Address::ZERO {
+            return Err(ErrorCode::InvalidRecipient.into());
+        }
+        if to != self.revenue_recipient(to) {
+            return Err(ErrorCode::FeesGoToTreasury.into());
+        }
+        let accrued = self.accrued_fees.get();
+        if amount == U256::ZERO || amount > accrued {
+            return Err(ErrorCode::InvalidAmount.into());
+        }
+        if amount > self.surplus() {
+            return Err(ErrorCode::ExceedsSurplus.into());
+        }
+
+        let remaining = accrued - amount;
+        self.accrued_fees.set(remaining);
+
+        self.vm().transfer_eth(to, amount)?;
+
+        log(self.vm(), FeesWithdrawn {
+            to,
+            amount,
+            remaining,
+        });
+        #[cfg(feature = "solvency-check")]
+        self.assert_solvent()?;
+
+        Ok(())
+    }
+
+    /// Protocol fees charged and not yet withdrawn
+    pub fn get_accrued_fees(&self) -> U256 {
+        self.accrued_fees.get()
+    }
+
+    /// Fee rate a job was opened with, in basis points
+    pub fn get_job_fee(&self, job_id: U256) -> u16 {
+        self.job_fee_bps.get(job_id).to()
+    }
+
+    /// Total a client's jobs have released to freelancers
+    pub fn get_client_volume(&self, client: Address) -> U256 {
+        self.client_volume.get(client)
+    }
+
+    /// Freelancer limits how many active jobs they take on at once, below
+    /// the admin default if one is set; 0 removes their own limit
+    pub fn set_job_cap(&mut self, cap: u32) -> Result<(), Vec<u8>> {
+        let freelancer = self.vm().msg_sender();
+        self.job_caps.setter(freelancer).set(Uint::<32, 1>::from(cap));
+
+        log(self.vm(), JobCapSet {
+            freelancer,
+            cap,
+        });
+
+        Ok(())
+    }
+
+    /// A freelancer's effective job cap (0 if unlimited) and how many
+    /// active jobs they hold
+    pub fn get_job_cap(&self, freelancer: Address) -> (u32, u32) {
+        (self.job_cap(freelancer), self.active_jobs.get(freelancer).to())
+    }
+
+    /// Stake opening a dispute over a job would take now
+    pub fn quote_dispute_stake(&self, job_id: U256) -> U256 {
+        self.dispute_stake_for(job_id)
+    }
+
+    /// Stake still locked in a job's dispute; zero once it is settled
+    pub fn get_dispute_stake(&self, job_id: U256) -> U256 {
+        self.disputes.get(job_id).stake.get()
+    }
+
+    /// Join the juror registry, or add to your stake, with the ETH sent;
+    /// the stake must reach the policy's minimum. Jurors can still join
+    /// during a sunset, so disputes still open can be empanelled
+    #[payable]
+    pub fn join_jury(&mut self) -> Result<(), Vec<u8>> {
+        self.assert_not_retired()?;
+        let amount = self.vm().msg_value();
+        if amount.is_zero() {
+            return Err(ErrorCode::NoStake.into());
+        }
+        let juror = self.vm().msg_sender();
+        self.stake_juror(juror, amount)?;
+        #[cfg(feature = "solvency-check")]
+        self.assert_solvent()?;
+
+        Ok(())
+    }
+
+    /// Leave the juror registry, withdrawing your whole stake; not while
+    /// you sit on a jury that hasn't been tallied
+    pub fn leave_jury(&mut self) -> Result<(), Vec<u8>> {
+        let juror = self.vm().msg_sender();
+        self.unstake_juror(juror)?;
+        #[cfg(feature = "solvency-check")]
+        self.assert_solvent()?;
+
+        Ok(())
+    }
+
+    /// A juror: (stake, whether they are in the registry, juries they sit
+    /// on)
+    pub fn get_juror(&self, juror: Address) -> (U256, bool, u32) {
+        let record = self.juror_records.get(juror);
+        (
+            record.stake.get(),
+            !record.index.get().is_zero(),
+            record.panels.get().to(),
+        )
+    }
+
+    /// How many jurors are in the registry
+    pub fn get_juror_count(&self) -> U256 {
+        U256::from(self.jurors.len())
+    }
+
+    /// Bond appealing a ruling on a job takes under the current policy
+    pub fn quote_appeal_bond(&self, job_id: U256) -> U256 {
+        self.appeal_bond_for(job_id)
+    }
+
+    /// ADMIN or FEE MANAGER: change a rarely-used setting, one of the
+    /// `SETTING_*` constants, passing its arguments ABI-encoded as a tuple
+    /// in `data`; each setting checks its own caller
+    pub fn configure(&mut self, setting: u8, data: Bytes) -> Result<(), Vec<u8>> {
+        self.apply_setting(setting, &data)
+    }
+
+    /// A rarely-used setting's current value, ABI-encoded as the tuple
+    /// `configure` takes for it; `key` picks the account for per-address
+    /// settings (basket tokens, exemptions, arbitrator listing)
+    pub fn get_setting(&self, setting: u8, key: Address) -> Result<Bytes, Vec<u8>> {
+        Ok(self.setting_value(setting, key)?.into())
+    }
+
+    /// ADMIN: pause/unpause escrow; the pauser may only pause. A new pause
+    /// ends by itself after the configured maximum, or `MAX_ADMIN_PAUSE`
+    /// with guardians appointed unless they extend it.
+    pub fn set_paused(&mut self, state: bool) -> Result<(), Vec<u8>> {
+        let sender = self.vm().msg_sender();
+        let pausing_as_pauser = state && sender == self.pauser.get();
+        if sender != self.admin.get() && !pausing_as_pauser {
+            return Err(ErrorCode::OnlyAdmin.into());
+        }
+        if !state {
+            self.pause_until.set(Uint::<64, 1>::ZERO);
+        } else if !self.paused_now() {
+            let until = match self.pause_limit() {
+                0 => 0,
+                limit => self.vm().block_timestamp().saturating_add(limit),
+            };
+            self.pause_until.set(Uint::<64, 1>::from(until));
+        }
+        self.paused.set(state);
+
+        log(self.vm(), PauseToggled {
+            paused: state,
+        });
+
+        Ok(())
+    }
+
+    /// Clear a pause that has outlived its end (anyone)
+    pub fn unpause_expired(&mut self) -> Result<(), Vec<u8>> {
+        if !self.paused.get() || self.paused_now() {
+            return Err(ErrorCode::PauseNotExpired.into());
+        }
+
+        self.paused.set(false);
+        self.pause_until.set(Uint::<64, 1>::ZERO);
+
+        log(self.vm(), PauseToggled {
+            paused: false,
+        });
+
+        Ok(())
+    }
+
+    /// ADMIN: transfer ownership
+    pub fn transfer_ownership(&mut self, new_admin: Address) -> Result<(), Vec<u8>> {
+        if self.vm().msg_sender() != self.admin.get() {
+            return Err(ErrorCode::OnlyAdmin.into());
+        }
+        if new_admin == Address::ZERO {
+            return Err(ErrorCode::InvalidAdminAddress.into());
+        }
+
+        let old_admin = self.admin.get();
+        self.admin.set(new_admin);
+        self.renounce_effective_at.set(Uint::<64, 1>::ZERO);
+
+        log(self.vm(), OwnershipTransferred {
+            old_admin,
+            new_admin,
+        });
+
+        Ok(())
+    }
+
+    /// ADMIN: start the `RENOUNCE_DELAY` countdown to giving up the admin
+    /// role; the parameters must already be final
+    pub fn propose_renounce(&mut self) -> Result<(), Vec<u8>> {
+        if self.vm().msg_sender() != self.admin.get() {
+            return Err(ErrorCode::OnlyAdmin.into());
+        }
+        self.assert_parameters_final()?;
+
+        let effective_at = self.vm().block_timestamp().saturating_add(RENOUNCE_DELAY);
+        self.renounce_effective_at.set(Uint::<64, 1>::from(effective_at));
+
+        log(self.vm(), RenounceProposed {
+            effective_at,
+        });
+
+        Ok(())
+    }
+
+    /// ADMIN: permanently remove the admin once the renounce delay is over;
+    /// every admin-only function is closed from then on
+    pub fn renounce_ownership(&mut self) -> Result<(), Vec<u8>> {
+        if self.vm().msg_sender() != self.admin.get() {
+            return Err(ErrorCode::OnlyAdmin.into());
+        }
+        let effective_at: u64 = self.renounce_effective_at.get().to();
+        if effective_at == 0 {
+            return Err(ErrorCode::NoPendingRenounce.into());
+        }
+        if self.vm().block_timestamp() < effective_at {
+            return Err(ErrorCode::RenounceDelayNotOver.into());
+        }
+        self.assert_parameters_final()?;
+
+        let old_admin = self.admin.get();
+        self.admin.set(Address::ZERO);
+        self.renounce_effective_at.set(Uint::<64, 1>::ZERO);
+
+        log(self.vm(), OwnershipTransferred {
+            old_admin,
+            new_admin: Address::ZERO,
+        });
+
+        Ok(())
+    }
+
+    /// When the admin may renounce; 0 if no renounce is pending
+    pub fn get_renounce_at(&self) -> u64 {
+        self.renounce_effective_at.get().to()
+    }
+
+    /// ADMIN: emergency refund; needs a guardian proposal instead once
+    /// guardians are appointed
+    pub fn emergency_refund(&mut self, job_id: U256) -> Result<(), Vec<u8>> {
+        if self.vm().msg_sender() != self.admin.get() {
+            return Err(ErrorCode::OnlyAdmin.into());
+        }
+        self.assert_no_guardian_quorum()?;
+
+        self.refund_emergency(job_id)
+    }
+
+    /// ADMIN: emergency refund many jobs at once. Jobs that can't be
+    /// refunded, or hold a token basket, are skipped rather than failing the
+    /// batch, and a client that refuses the ETH is credited it to withdraw;
+    /// returns which were refunded, also reported in one
+    /// `EmergencyRefundBatch` event. Needs guardian proposals instead once
+    /// guardians are appointed
+    pub fn emergency_refund_batch(&mut self, job_ids: Vec<U256>) -> Result<Vec<bool>, Vec<u8>> {
+        if self.vm().msg_sender() != self.admin.get() {
+            return Err(ErrorCode::OnlyAdmin.into());
+        }
+        self.assert_no_guardian_quorum()?;
+        if job_ids.is_empty() || job_ids.len() > MAX_REFUND_BATCH {
+            return Err(ErrorCode::InvalidBatch.into());
+        }
+
+        let refunded: Vec<bool> = job_ids
+            .iter()
+            .map(|&job_id| self.try_emergency_refund(job_id))
+            .collect();
+
+        log(self.vm(), EmergencyRefundBatch {
+            admin: self.vm().msg_sender(),
+            job_ids,
+            refunded: refunded.clone(),
+        });
+        #[cfg(feature = "solvency-check")]
+        self.assert_solvent()?;
+
+        Ok(refunded)
+    }
+
+    /// ADMIN: send the balance beyond open jobs, credit and accrued fees to
+    /// the treasury; needs a guardian proposal instead once guardians are
+    /// appointed
+    pub fn sweep_surplus(&mut self) -> Result<(), Vec<u8>> {
+        if self.vm().msg_sender() != self.admin.get() {
+            return Err(ErrorCode::OnlyAdmin.into());
+        }
+        self.assert_no_guardian_quorum()?;
+
+        self.sweep_surplus_now()
+    }
+
+    /// ADMIN: publish the Merkle root emergency refunds are claimed against
+    /// (see `refund_leaf`), replacing any earlier one; zero stops claims.
+    /// Needs a governance proposal instead once governance is configured
+    pub fn set_refund_root(&mut self, root: B256) -> Result<(), Vec<u8>> {
+        if self.vm().msg_sender() != self.admin.get() {
+            return Err(ErrorCode::OnlyAdmin.into());
+        }
+        self.assert_not_governed()?;
+
+        self.update_refund_root(root);
+        Ok(())
+    }
+
+    /// Merkle root emergency refunds are claimed against, zero if none
+    pub fn get_refund_root(&self) -> B256 {
+        self.refund_root.get()
+    }
+
+    /// Whether a job already claimed its emergency refund under the current
+    /// root
+    pub fn is_refund_claimed(&self, job_id: U256) -> bool {
+        self.refund_claims.get(self.refund_root.get()).get(job_id)
+    }
+
+    /// Client claims the emergency refund of `amount` listed for a job in
+    /// the published Merkle root, proving it with `proof`. Refunding the
+    /// whole amount settles the job; a smaller refund leaves the rest
+    /// escrowed. Works while paused
+    pub fn claim_emergency_refund(
+        &mut self,
+        job_id: U256,
+        amount: U256,
+        proof: Vec<B256>,
+    ) -> Result<(), Vec<u8>> {
+        self.assert_settleable(job_id)?;
+        self.assert_not_staked(job_id)?;
+        let job = self.jobs.get(job_id);
+        if job.client.get() != self.vm().msg_sender() {
+            return Err(ErrorCode::OnlyClientCanClaim.into());
+        }
+        let root = self.refund_root.get();
+        if root == B256::ZERO {
+            return Err(ErrorCode::NoRefundRoot.into());
+        }
+        if self.refund_claims.get(root).get(job_id) {
+            return Err(ErrorCode::RefundAlreadyClaimed.into());
+        }
+        self.assert_no_queued_release(job_id)?;
+        let held = job.amount.get();
+        if amount.is_zero() || amount > held {
+            return Err(ErrorCode::InvalidRefundAmount.into());
+        }
+        if amount < held && (!self.tranches.get(job_id).is_empty() || self.is_hourly(job_id)) {
+            return Err(ErrorCode::PartialRefundNotSupported.into());
+        }
+        if !refund_roots::verify_proof(&proof, root, refund_leaf(job_id, amount)) {
+            return Err(ErrorCode::InvalidProof.into());
+        }
+
+        self.pay_refund_claim(job_id, amount)?;
+        #[cfg(feature = "solvency-check")]
+        self.assert_solvent()?;
+
+        Ok(())
+    }
+
+    /// ADMIN: appoint `guardians`, `threshold` of whom must confirm emergency
+    /// actions; an empty set with threshold 0 hands emergencies back to the
+    /// admin. Pending proposals lapse.
+    pub fn set_guardians(&mut self, guardians: Vec<Address>, threshold: u8) -> Result<(), Vec<u8>> {
+        if self.vm().msg_sender() != self.admin.get() {
+            return Err(ErrorCode::OnlyAdmin.into());
+        }
+        if guardians.len() > MAX_GUARDIANS {
+            return Err(ErrorCode::TooManyGuardians.into());
+        }
+        if usize::from(threshold) > guardians.len() || (threshold == 0) != guardians.is_empty() {
+            return Err(ErrorCode::InvalidThreshold.into());
+        }
+        for (i, guardian) in guardians.iter().enumerate() {
+            if *guardian == Address::ZERO || guardians[..i].contains(guardian) {
+                return Err(ErrorCode::InvalidGuardian.into());
+            }
+        }
+
+        self.replace_guardians(&guardians, threshold);
+
+        log(self.vm(), GuardiansSet {
+            guardians,
+            threshold,
+        });
+
+        Ok(())
+    }
+
+    /// Guardian set: (guardians, confirmations an emergency action needs)
+    pub fn get_guardians(&self) -> (Vec<Address>, u8) {
+        let mut guardians = Vec::with_capacity(self.guardians.len());
+        for i in 0..self.guardians.len() {
+            if let Some(guardian) = self.guardians.get(i) {
+                guardians.push(guardian);
+            }
+        }
+        (guardians, self.guardian_threshold.get().to())
+    }
+
+    /// GUARDIAN: propose an `EMERGENCY_ACTION_*` on `target` (a job ID, or a
+    /// timestamp to pause until) and confirm it; it runs once enough
+    /// guardians confirm
+    pub fn propose_emergency(&mut self, action: u8, target: U256) -> Result<U256, Vec<u8>> {
+        self.assert_guardian()?;
+        if action > EMERGENCY_ACTION_EXTEND_PAUSE {
+            return Err(ErrorCode::InvalidAction.into());
+        }
+
+        let proposal_id = self.emergency_proposal_count.get() + U256::from(1);
+        self.emergency_proposal_count.set(proposal_id);
+        let proposer = self.vm().msg_sender();
+        let expires_at = self.vm().block_timestamp().saturating_add(EMERGENCY_PROPOSAL_TTL);
+        let epoch = self.guardian_epoch.get();
+        let mut proposal = self.emergency_proposals.setter(proposal_id);
+        proposal.action.set(Uint::<8, 1>::from(action));
+        proposal.target.set(target);
+        proposal.proposer.set(proposer);
+        proposal.expires_at.set(Uint::<64, 1>::from(expires_at));
+        proposal.guardian_epoch.set(epoch);
+
+        log(self.vm(), EmergencyProposed {
+            proposal_id,
+            action,
+            target,
+            proposer,
+            expires_at,
+        });
+
+        self.confirm_emergency(proposal_id)?;
+
+        Ok(proposal_id)
+    }
+
+    /// GUARDIAN: confirm an emergency proposal, running it if this
+    /// confirmation reaches the threshold
+    pub fn confirm_emergency(&mut self, proposal_id: U256) -> Result<(), Vec<u8>> {
+        self.assert_guardian()?;
+        let proposal = self.emergency_proposals.get(proposal_id);
+        if proposal.proposer.get() == Address::ZERO {
+            return Err(ErrorCode::ProposalDoesNotExist.into());
+        }
+        if proposal.executed.get() {
+            return Err(ErrorCode::ProposalAlreadyExecuted.into());
+        }
+        if proposal.guardian_epoch.get() != self.guardian_epoch.get() {
+            return Err(ErrorCode::ProposalStale.into());
+        }
+        let expires_at: u64 = proposal.expires_at.get().to();
+        if self.vm().block_timestamp() >= expires_at {
+            return Err(ErrorCode::ProposalExpired.into());
+        }
+        let guardian = self.vm().msg_sender();
+        if self.emergency_confirmations.get(proposal_id).get(guardian) {
+            return Err(ErrorCode::AlreadyConfirmed.into());
+        }
+
+        let action: u8 = proposal.action.get().to();
+        let target = proposal.target.get();
+        let confirmations: u8 = proposal.confirmations.get().to::<u8>() + 1;
+        let threshold: u8 = self.guardian_threshold.get().to();
+        self.emergency_confirmations
+            .setter(proposal_id)
+            .setter(guardian)
+            .set(true);
+        let mut proposal = self.emergency_proposals.setter(proposal_id);
+        proposal.confirmations.set(Uint::<8, 1>::from(confirmations));
+        if confirmations >= threshold {
+            proposal.executed.set(true);
+        }
+
+        log(self.vm(), EmergencyConfirmed {
+            proposal_id,
+            guardian,
+            confirmations,
+        });
+
+        if confirmations >= threshold {
+            self.execute_emergency(action, target)?;
+        }
+
+        Ok(())
+    }
+
+    /// View an emergency proposal; reverts for IDs that were never proposed
+    pub fn get_emergency_proposal(&self, proposal_id: U256) -> Result<EmergencyProposalView, Vec<u8>> {
+        let proposal = self.emergency_proposals.get(proposal_id);
+        if proposal.proposer.get() == Address::ZERO {
+            return Err(ErrorCode::ProposalDoesNotExist.into());
+        }
+        Ok((
+            proposal.action.get().to(),
+            proposal.target.get(),
+            proposal.proposer.get(),
+            proposal.expires_at.get().to(),
+            proposal.confirmations.get().to(),
+            proposal.executed.get(),
+        ))
+    }
+
+    /// When the running pause ends by itself; 0 if it doesn't
+    pub fn get_pause_until(&self) -> u64 {
+        self.pause_until.get().to()
+    }
+
+    /// ADMIN: hand the governed parameters to proposals voted on by
+    /// `council`, one vote each, or by holders of the votes `token` (exactly
+    /// one of the two), passing with `quorum` votes. This is done once; after
+    /// it governance changes itself.
+    pub fn set_governance(
+        &mut self,
+        token: Address,
+        council: Vec<Address>,
+        quorum: U256,
+    ) -> Result<(), Vec<u8>> {
+        if self.vm().msg_sender() != self.admin.get() {
+            return Err(ErrorCode::OnlyAdmin.into());
+        }
+        if self.is_governed() {
+            return Err(ErrorCode::GovernanceAlreadySet.into());
+        }
+        if (token == Address::ZERO) == council.is_empty() {
+            return Err(ErrorCode::InvalidGovernance.into());
+        }
+        if council.len() > MAX_COUNCIL {
+            return Err(ErrorCode::CouncilTooLarge.into());
+        }
+        for (i, member) in council.iter().enumerate() {
+            if *member == Address::ZERO || council[..i].contains(member) {
+                return Err(ErrorCode::InvalidCouncilMember.into());
+            }
+        }
+        if quorum.is_zero() || (token == Address::ZERO && quorum > U256::from(council.len())) {
+            return Err(ErrorCode::InvalidQuorum.into());
+        }
+
+        self.governance_token.set(token);
+        self.replace_council(&council);
+        self.governance_quorum.set(quorum);
+
+        self.log_governance();
+
+        Ok(())
+    }
+
+    /// Governance: (voting token, zero for a council; council members;
+    /// votes a proposal needs, 0 if not governed)
+    pub fn get_governance(&self) -> (Address, Vec<Address>, U256) {
+        (
+            self.governance_token.get(),
+            self.council_members(),
+            self.governance_quorum.get(),
+        )
+    }
+
+    /// Propose a `GOVERNANCE_ACTION_*` other than an appeal with its
+    /// `target` address or `value`, and for a governed setting its encoded
+    /// arguments in `data`; anyone with votes can propose, and voting runs
+    /// for `GOVERNANCE_VOTING_PERIOD`. Replacing a dispute's arbitrator can
+    /// only be proposed once they let its ruling period pass.
+    pub fn propose_governance(
+        &mut self,
+        action: u8,
+        target: Address,
+        value: U256,
+        data: Bytes,
+    ) -> Result<U256, Vec<u8>> {
+        if !self.is_governed() {
+            return Err(ErrorCode::GovernanceNotSet.into());
+        }
+        let council_only = action == GOVERNANCE_ACTION_ADD_COUNCIL_MEMBER
+            || action == GOVERNANCE_ACTION_REMOVE_COUNCIL_MEMBER;
+        if action > GOVERNANCE_ACTION_TREASURY
+            || action == GOVERNANCE_ACTION_APPEAL
+            || (council_only && self.governance_token.get() != Address::ZERO)
+        {
+            return Err(ErrorCode::InvalidAction.into());
+        }
+        if action == GOVERNANCE_ACTION_REPLACE_ARBITRATOR {
+            self.assert_arbitrator_replaceable(value, target)?;
+        }
+        if action == GOVERNANCE_ACTION_SETTING
+            && !u8::try_from(value).is_ok_and(Self::is_governed_setting)
+        {
+            return Err(ErrorCode::InvalidSetting.into());
+        }
+        let proposer = self.vm().msg_sender();
+        let snapshot = self.vm().block_number().saturating_sub(1);
+        if self.voting_weight(proposer, snapshot)?.is_zero() {
+            return Err(ErrorCode::NoVotingPower.into());
+        }
+
+        let proposal_id = self.open_governance_proposal(proposer, action, target, value, &data);
+
+        Ok(proposal_id)
+    }
+
+    /// Back or oppose a governance proposal with all of your votes while
+    /// voting is open
+    pub fn vote_governance(&mut self, proposal_id: U256, support: bool) -> Result<(), Vec<u8>> {
+        let proposal = self.governance_proposals.get(proposal_id);
+        if proposal.proposer.get() == Address::ZERO {
+            return Err(ErrorCode::ProposalDoesNotExist.into());
+        }
+        if proposal.council_epoch.get() != self.council_epoch.get() {
+            return Err(ErrorCode::ProposalStale.into());
+        }
+        let voting_ends: u64 = proposal.voting_ends.get().to();
+        if self.vm().block_timestamp() >= voting_ends {
+            return Err(ErrorCode::VotingClosed.into());
+        }
+        let voter = self.vm().msg_sender();
+        if self.governance_votes.get(proposal_id).get(voter) {
+            return Err(ErrorCode::AlreadyVoted.into());
+        }
+        let weight = self.voting_weight(voter, proposal.snapshot.get().to())?;
+        if weight.is_zero() {
+            return Err(ErrorCode::NoVotingPower.into());
+        }
+
+        let votes_for = proposal.votes_for.get();
+        let votes_against = proposal.votes_against.get();
+        self.governance_votes
+            .setter(proposal_id)
+            .setter(voter)
+            .set(true);
+        let mut proposal = self.governance_proposals.setter(proposal_id);
+        if support {
+            proposal.votes_for.set(votes_for + weight);
+        } else {
+            proposal.votes_against.set(votes_against + weight);
+        }
+
+        log(self.vm(), GovernanceVoted {
+            proposal_id,
+            voter,
+            support,
+            weight,
+        });
+
+        Ok(())
+    }
+
+    /// Carry out a proposal that reached the quorum with more votes for than
+    /// against, once `GOVERNANCE_TIMELOCK` has passed since voting ended
+    /// (anyone)
+    pub fn execute_governance(&mut self, proposal_id: U256) -> Result<(), Vec<u8>> {
+        let proposal = self.governance_proposals.get(proposal_id);
+        if proposal.proposer.get() == Address::ZERO {
+            return Err(ErrorCode::ProposalDoesNotExist.into());
+        }
+        if proposal.executed.get() {
+            return Err(ErrorCode::ProposalAlreadyExecuted.into());
+        }
+        if proposal.council_epoch.get() != self.council_epoch.get() {
+            return Err(ErrorCode::ProposalStale.into());
+        }
+        let votes_for = proposal.votes_for.get();
+        if votes_for < self.governance_quorum.get() || votes_for <= proposal.votes_against.get() {
+            return Err(ErrorCode::ProposalNotPassed.into());
+        }
+        let voting_ends: u64 = proposal.voting_ends.get().to();
+        let executable_at = voting_ends.saturating_add(GOVERNANCE_TIMELOCK);
+        let now = self.vm().block_timestamp();
+        if now < executable_at {
+            return Err(ErrorCode::TimelockNotOver.into());
+        }
+        if now >= executable_at.saturating_add(GOVERNANCE_EXECUTION_WINDOW) {
+            return Err(ErrorCode::ProposalExpired.into());
+        }
+
+        let action: u8 = proposal.action.get().to();
+        let target = proposal.target.get();
+        let value = proposal.value.get();
+        let data = proposal.data.get_bytes();
+        self.governance_proposals
+            .setter(proposal_id)
+            .executed
+            .set(true);
+
+        log(self.vm(), GovernanceExecuted {
+            proposal_id,
+        });
+
+        self.execute_governance_action(action, target, value, &data)
+    }
+
+    /// View a governance proposal; reverts for IDs that were never proposed
+    pub fn get_governance_proposal(&self, proposal_id: U256) -> Result<GovernanceProposalView, Vec<u8>> {
+        let proposal = self.governance_proposals.get(proposal_id);
+        if proposal.proposer.get() == Address::ZERO {
+            return Err(ErrorCode::ProposalDoesNotExist.into());
+        }
+        Ok((
+            proposal.action.get().to(),
+            proposal.target.get(),
+            proposal.value.get(),
+            proposal.proposer.get(),
+            proposal.voting_ends.get().to(),
+            proposal.votes_for.get(),
+            proposal.votes_against.get(),
+            proposal.executed.get(),
+            Bytes(proposal.data.get_bytes()),
+        ))
+    }
+
+    /// ADMIN: stop accepting deposits from `timestamp` on, for good.
+    /// Existing jobs keep settling as usual.
+    pub fn begin_sunset(&mut self, timestamp: u64) -> Result<(), Vec<u8>> {
+        if self.vm().msg_sender() != self.admin.get() {
+            return Err(ErrorCode::OnlyAdmin.into());
+        }
+        if self.sunset_at.get() != Uint::<64, 1>::ZERO {
+            return Err(ErrorCode::SunsetAlreadyStarted.into());
+        }
+        if timestamp == 0 || timestamp < self.vm().block_timestamp() {
+            return Err(ErrorCode::InvalidSunsetTime.into());
+        }
+
+        self.sunset_at.set(Uint::<64, 1>::from(timestamp));
+
+        log(self.vm(), SunsetBegun {
+            timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// ADMIN: retire the deployment once the sunset is reached, every job
+    /// is settled and no credit, accrued fees or juror stakes are left,
+    /// sweeping any stray balance to the treasury (the admin if none is
+    /// set). Each kind of leftover has its own error so the admin knows
+    /// who still has to withdraw.
+    pub fn finalize_sunset(&mut self) -> Result<(), Vec<u8>> {
+        if self.vm().msg_sender() != self.admin.get() {
+            return Err(ErrorCode::OnlyAdmin.into());
+        }
+        if self.retired.get() {
+            return Err(ErrorCode::EscrowIsRetired.into());
+        }
+        if !self.is_sunset() {
+            return Err(ErrorCode::SunsetNotReached.into());
+        }
+        let credit = self.total_credit.get();
+        let fees = self.accrued_fees.get();
+        let juror_stakes = self.total_juror_stakes.get();
+        if self.liabilities() - credit - fees - juror_stakes != U256::ZERO {
+            return Err(ErrorCode::JobsStillOpen.into());
+        }
+        if credit != U256::ZERO {
+            return Err(ErrorCode::CreditOutstanding.into());
+        }
+        if fees != U256::ZERO {
+            return Err(ErrorCode::FeesOutstanding.into());
+        }
+        if juror_stakes != U256::ZERO {
+            return Err(ErrorCode::JurorStakesOutstanding.into());
+        }
+
+        self.retired.set(true);
+
+        let admin = self.revenue_recipient(self.admin.get());
+        let swept = self.vm().balance(self.vm().contract_address());
+        if swept != U256::ZERO {
+            self.vm().transfer_eth(admin, swept)?;
+        }
+
+        log(self.vm(), SunsetFinalized {
+            admin,
+            swept,
+        });
+
+        Ok(())
+    }
+
+    /// Sunset state: (deposits end at, 0 if not scheduled; retired)
+    pub fn get_sunset(&self) -> (u64, bool) {
+        (self.sunset_at.get().to(), self.retired.get())
+    }
+
+    /// ADMIN: announce that a dormant job will be swept after the notice
+    /// period; the parties can still settle it until then
+    pub fn announce_dormant(&mut self, job_id: U256) -> Result<(), Vec<u8>> {
+        if self.vm().msg_sender() != self.admin.get() {
+            return Err(ErrorCode::OnlyAdmin.into());
+        }
+        if self.recovery_address.get() == Address::ZERO {
+            return Err(ErrorCode::DormancyPolicyNotSet.into());
+        }
+
+        self.assert_job_exists(job_id)?;
+        let job = self.jobs.get(job_id);
+        if job.released.get() || job.refunded.get() {
+            return Err(ErrorCode::JobAlreadySettled.into());
+        }
+        if self.finalized.get(job_id) {
+            return Err(ErrorCode::JobAlreadyFinalized.into());
+        }
+        if self.sweep_after.get(job_id) != Uint::<64, 1>::ZERO {
+            return Err(ErrorCode::DormancyAlreadyAnnounced.into());
+        }
+        let deadline: u64 = job.deadline.get().to();
+        let period: u64 = self.dormancy_period.get().to();
+        let now = self.vm().block_timestamp();
+        if now < deadline.saturating_add(period) {
+            return Err(ErrorCode::JobNotDormant.into());
+        }
+
+        let sweep_after = now + DORMANCY_NOTICE_PERIOD;
+        self.sweep_after.setter(job_id).set(Uint::<64, 1>::from(sweep_after));
+
+        log(self.vm(), DormancyAnnounced {
+            job_id,
+            sweep_after,
+        });
+
+        Ok(())
+    }
+
+    /// ADMIN: send an announced dormant job's funds to the treasury (the
+    /// recovery address if none is set) once the notice period is over
+    pub fn sweep_dormant(&mut self, job_id: U256) -> Result<(), Vec<u8>> {
+        if self.vm().msg_sender() != self.admin.get() {
+            return Err(ErrorCode::OnlyAdmin.into());
+        }
+
+        let sweep_after: u64 = self.sweep_after.get(job_id).to();
+        if sweep_after == 0 {
+            return Err(ErrorCode::DormancyNotAnnounced.into());
+        }
+        let job = self.jobs.get(job_id);
+        if job.released.get() || job.refunded.get() {
+            return Err(ErrorCode::JobAlreadySettled.into());
+        }
+        if self.finalized.get(job_id) {
+            return Err(ErrorCode::JobAlreadyFinalized.into());
+        }
+        if self.vm().block_timestamp() < sweep_after {
+            return Err(ErrorCode::NoticePeriodNotOver.into());
+        }
+        self.assert_not_staked(job_id)?;
+
+        let amount = job.amount.get();
+        let recovery = self.revenue_recipient(self.recovery_address.get());
+
+        self.unlock_escrow(job_id, amount);
+        self.jobs.setter(job_id).swept.set(true);
+        self.close_job(job_id);
+        self.total_swept.set(self.total_swept.get() + amount);
+
+        self.vm().transfer_eth(recovery, amount)?;
+        self.sweep_basket(job_id, recovery)?;
+
+        log(self.vm(), DormantSwept {
+            job_id,
+            recovery,
+            amount,
+        });
+        #[cfg(feature = "solvency-check")]
+        self.assert_solvent()?;
+
+        Ok(())
+    }
+
+    /// ADMIN: propose `treasury` as the recipient of fees, dormant sweeps and
+    /// retirement leftovers; it can be applied after `TREASURY_DELAY`, and a
+    /// new proposal replaces a pending one. Once governance is configured
+    /// only `GOVERNANCE_ACTION_TREASURY` proposes a treasury.
+    pub fn propose_treasury(&mut self, treasury: Address) -> Result<(), Vec<u8>> {
+        if self.vm().msg_sender() != self.admin.get() {
+            return Err(ErrorCode::OnlyAdmin.into());
+        }
+        self.assert_not_governed()?;
+
+        self.update_pending_treasury(treasury)
+    }
+
+    /// Make the proposed treasury current once its delay has passed (anyone)
+    pub fn apply_treasury(&mut self) -> Result<(), Vec<u8>> {
+        let treasury = self.pending_treasury.get();
+        if treasury == Address::ZERO {
+            return Err(ErrorCode::NoPendingTreasury.into());
+        }
+        let effective_at: u64 = self.treasury_effective_at.get().to();
+        if self.vm().block_timestamp() < effective_at {
+            return Err(ErrorCode::TreasuryDelayNotOver.into());
+        }
+
+        self.treasury.set(treasury);
+        self.pending_treasury.set(Address::ZERO);
+        self.treasury_effective_at.set(Uint::<64, 1>::ZERO);
+
+        log(self.vm(), TreasurySet {
+            treasury,
+        });
+
+        Ok(())
+    }
+
+    /// Treasury: (current, zero if none; pending, zero if none; when the
+    /// pending one can be applied)
+    pub fn get_treasury(&self) -> (Address, Address, u64) {
+        (
+            self.treasury.get(),
+            self.pending_treasury.get(),
+            self.treasury_effective_at.get().to(),
+        )
+    }
+
+    /// A job's dormancy state: (sweepable from, 0 if not announced; swept)
+    pub fn get_dormancy(&self, job_id: U256) -> (u64, bool) {
+        (self.sweep_after.get(job_id).to(), self.jobs.get(job_id).swept.get())
+    }
+
+    /// View job details; reverts for IDs that were never created
+    pub fn get_job(&self, job_id: U256) -> Result<JobView, Vec<u8>> {
+        self.assert_job_exists(job_id)?;
+        let j = self.jobs.get(job_id);
+        Ok((
+            j.job_id.get(),
+            j.client.get(),
+            j.freelancer.get(),
+            j.amount.get(),
+            j.deadline.get().to(),
+            j.released.get(),
+            j.refunded.get(),
+        ))
+    }
+
+    /// Summaries of up to `count` consecutive jobs starting at `start_id`,
+    /// cut short at the newest job; an empty result means the end was reached
+    pub fn get_jobs_range(&self, start_id: U256, count: u64) -> Result<JobsRange, Vec<u8>> {
+        if start_id.is_zero() {
+            return Err(ErrorCode::InvalidStart.into());
+        }
+        if count > MAX_JOBS_RANGE {
+            return Err(ErrorCode::RangeTooLarge.into());
+        }
+
+        let mut range = JobsRange::default();
+        for i in self.job_window(start_id, count) {
+            let job = self.jobs.get(i);
+            range.0.push(job.client.get());
+            range.1.push(job.freelancer.get());
+            range.2.push(job.amount.get());
+            range.3.push(job.deadline.get().to());
+            range.4.push(job.released.get());
+            range.5.push(job.refunded.get());
+        }
+        Ok(range)
+    }
+
+    /// Gelato Automate checker: whether one of the `MAX_JOBS_RANGE` active
+    /// jobs from position `cursor` has a settlement anyone can send now, and
+    /// the calldata to send it with; a reason instead of calldata when there
+    /// is none. Run one task per `MAX_JOBS_RANGE` positions to cover them all
+    pub fn resolver(&self, cursor: U256) -> (bool, Bytes) {
+        if self.paused_now() {
+            return (false, Bytes(b"Escrow is paused".to_vec()));
+        }
+        let mut window =
+            self.active_jobs_from(cursor.saturating_to()).take(MAX_JOBS_RANGE as usize);
+        match window.find_map(|(_, i)| self.due_settlement(i)) {
+            Some(payload) => (true, Bytes(payload)),
+            None => (false, Bytes(b"No settlement due".to_vec())),
+        }
+    }
+
+    /// Get active (unsettled) jobs, in active-job set order: a job settling
+    /// moves the last active job into its place
+    pub fn get_active_jobs(&self) -> Vec<U256> {
+        self.active_jobs_from(0).map(|(_, job_id)| job_id).collect()
+    }
+
+    /// Up to `limit` funded active jobs whose deadline falls before
+    /// `timestamp`, scanning the active-job set from position `cursor` (0 to
+    /// start) and returning where to resume, or 0 once it was all scanned.
+    /// A job settling between pages moves the last active job into its
+    /// place, which the next pass from 0 picks up
+    pub fn get_jobs_expiring_before(
+        &self,
+        timestamp: u64,
+        cursor: U256,
+        limit: u64,
+    ) -> Result<(Vec<U256>, U256), Vec<u8>> {
+        if limit == 0 || limit > MAX_JOBS_RANGE {
+            return Err(ErrorCode::InvalidLimit.into());
+        }
+
+        let mut ids = Vec::new();
+        for (position, job_id) in self.active_jobs_from(cursor.saturating_to()) {
+            if ids.len() as u64 == limit {
+                return Ok((ids, U256::from(position)));
+            }
+            let deadline: u64 = self.jobs.get(job_id).deadline.get().to();
+            if self.is_funded(job_id) && deadline < timestamp {
+                ids.push(job_id);
+            }
+        }
+        Ok((ids, U256::ZERO))
+    }
+
+    /// Up to `limit` jobs matching a filter: `status` is one of the
+    /// `JOB_STATUS_*` constants (`JOB_STATUS_ANY` for all), and a nonzero
+    /// `party` keeps only jobs it is currently the client or freelancer of.
+    /// Jobs come oldest first, or for a party in the order it became party
+    /// to them. Pass 0 as `cursor` to start and the returned cursor to
+    /// continue; it is 0 once every job was scanned. Without a party a call
+    /// reads at most `MAX_JOBS_RANGE` job IDs, so keep paging until the
+    /// cursor is 0 even when a page comes back short
+    pub fn query_jobs(
+        &self,
+        status: u8,
+        party: Address,
+        cursor: U256,
+        limit: u64,
+    ) -> Result<JobPage, Vec<u8>> {
+        if status > JOB_STATUS_SWEPT {
+            return Err(ErrorCode::InvalidStatus.into());
+        }
+        if limit == 0 || limit > MAX_JOBS_RANGE {
+            return Err(ErrorCode::InvalidLimit.into());
+        }
+
+        Ok(self.scan_jobs(party, cursor, limit, |job_id| {
+            (status == JOB_STATUS_ANY || self.job_status(job_id) == status)
+                && (party == Address::ZERO || self.is_party(job_id, party))
+        }))
+    }
+
+    /// Up to `limit` jobs filed under `category` and, unless `tag` is zero,
+    /// `tag`, with the given `JOB_STATUS_*` status (`JOB_STATUS_ANY` for
+    /// all), oldest first. Paged like `query_jobs`
+    pub fn query_jobs_by_category(
+        &self,
+        category: u16,
+        tag: B256,
+        status: u8,
+        cursor: U256,
+        limit: u64,
+    ) -> Result<JobPage, Vec<u8>> {
+        if status > JOB_STATUS_SWEPT {
+            return Err(ErrorCode::InvalidStatus.into());
+        }
+        if limit == 0 || limit > MAX_JOBS_RANGE {
+            return Err(ErrorCode::InvalidLimit.into());
+        }
+
+        Ok(self.scan_jobs(Address::ZERO, cursor, limit, |job_id| {
+            (status == JOB_STATUS_ANY || self.job_status(job_id) == status)
+                && self.in_category(job_id, category, tag)
+        }))
+    }
+
+    /// A job's category and tag; zero if it was filed under none
+    pub fn get_job_category(&self, job_id: U256) -> Result<(u16, B256), Vec<u8>> {
+        self.assert_job_exists(job_id)?;
+        let job = self.jobs.get(job_id);
+        Ok((job.category.get().to(), job.tag.get()))
+    }
+
+    /// Jobs filed under a category and the ETH deposited in them
+    pub fn get_category_stats(&self, category: u16) -> (U256, U256) {
+        let stats = self.category_stats.get(Uint::<16, 1>::from(category));
+        (stats.jobs.get(), stats.deposited.get())
+    }
 
     /// Get total jobs created
     pub fn get_total_jobs(&self) -> U256 {
         self.job_count.get()
     }
 
-    /// Check if paused
-    pub fn is_paused(&self) -> bool {
-        self.paused.get()
+    /// Check if paused
+    pub fn is_paused(&self) -> bool {
+        self.paused_now()
+    }
+
+    /// Cumulative protocol volume, as a `ProtocolStatsView`; every total
+    /// only ever grows
+    pub fn get_protocol_stats(&self) -> ProtocolStatsView {
+        (
+            self.total_deposited.get(),
+            self.total_released.get(),
+            self.total_refunded.get(),
+            self.total_swept.get(),
+            self.total_fees_collected.get(),
+            self.total_disputed.get(),
+        )
+    }
+
+    /// Whether the contract's balance covers every unsettled job
+    pub fn verify_solvency(&self) -> bool {
+        self.is_solvent()
+    }
+
+    /// Lifetime stats for an address: (jobs as client, jobs as freelancer,
+    /// deposited, earned, refunded), then the ETH it has in escrow across
+    /// its unsettled jobs as client and held for it across those as
+    /// freelancer
+    pub fn get_user_stats(&self, user: Address) -> UserStatsView {
+        let stats = self.user_stats.get(user);
+        (
+            stats.jobs_as_client.get(),
+            stats.jobs_as_freelancer.get(),
+            stats.total_deposited.get(),
+            stats.total_earned.get(),
+            stats.total_refunded.get(),
+            self.locked_amounts.get(user),
+            self.pending_earnings.get(user),
+        )
+    }
+
+    /// `job_id`'s stake: the token and shares it is held in with the ETH
+    /// staked (all zero unless staked), the freelancer's and treasury's
+    /// shares of the yield in basis points as last consented to, the party
+    /// whose consent awaits the other's, and the slippage bound its shares
+    /// can be bought back under
+    pub fn get_job_stake(&self, job_id: U256) -> JobStakeView {
+        let stake = self.job_stakes.get(job_id);
+        (
+            stake.token.get(),
+            stake.principal.get(),
+            stake.shares.get(),
+            stake.freelancer_yield_bps.get().to(),
+            stake.treasury_yield_bps.get().to(),
+            stake.consented_by.get(),
+            stake.max_slippage_bps.get().to(),
+        )
+    }
+
+    /// Party rates the other party to a settled job from 1 to `MAX_STARS`
+    /// stars, once
+    pub fn rate_job(&mut self, job_id: U256, stars: u8) -> Result<(), Vec<u8>> {
+        if self.paused_now() {
+            return Err(ErrorCode::EscrowIsPaused.into());
+        }
+        let job = self.jobs.get(job_id);
+        if !self.finalized.get(job_id) || job.swept.get() {
+            return Err(ErrorCode::JobNotSettled.into());
+        }
+        let rater = self.vm().msg_sender();
+        if !self.is_job_party(job_id, rater) || job.freelancer.get() == Address::ZERO {
+            return Err(ErrorCode::OnlyJobPartiesCanRate.into());
+        }
+        if stars == 0 || stars > MAX_STARS {
+            return Err(ErrorCode::InvalidRating.into());
+        }
+        let ratings = self.job_ratings.get(job_id);
+        let given = if rater == job.client.get() {
+            ratings.by_client.get()
+        } else {
+            ratings.by_freelancer.get()
+        };
+        if !given.is_zero() {
+            return Err(ErrorCode::AlreadyRated.into());
+        }
+
+        self.rate(job_id, rater, stars);
+
+        Ok(())
+    }
+
+    /// Stars each party gave for `job_id`: (client's rating of the
+    /// freelancer, freelancer's rating of the client); 0 if not rated
+    pub fn get_job_ratings(&self, job_id: U256) -> (u8, u8) {
+        let ratings = self.job_ratings.get(job_id);
+        (ratings.by_client.get().to(), ratings.by_freelancer.get().to())
+    }
+
+    /// Ratings received by `account`: (ratings, star total, disputes lost,
+    /// weighted stars, weight), the weighted figures decayed to now; then
+    /// its work history as freelancer: (jobs completed, average seconds
+    /// from deposit to settlement of those jobs, disputes over its jobs)
+    /// and its standing as client: (basis points of its deposits refunded,
+    /// disputes over its jobs, basis points of those an arbitrator ruled
+    /// against it)
+    pub fn get_reputation(&self, account: Address) -> ReputationView {
+        let reputation = self.reputations.get(account);
+        let (weighted_stars, weight) = self.weighted_reputation(account);
+        let (jobs_completed, settlement_time, disputes) = self.freelancer_profile(account);
+        let (refund_bps, client_disputes, dispute_loss_bps) = self.client_profile(account);
+        (
+            reputation.ratings.get().to(),
+            reputation.stars_total.get().to(),
+            reputation.disputes_lost.get().to(),
+            weighted_stars,
+            weight,
+            jobs_completed,
+            settlement_time,
+            disputes,
+            refund_bps,
+            client_disputes,
+            dispute_loss_bps,
+        )
+    }
+
+    /// `account`'s reputation normalized to basis points: 10_000 for an
+    /// all-`MAX_STARS` record far outweighing `REPUTATION_PRIOR`, 0 for none
+    pub fn get_score(&self, account: Address) -> u16 {
+        self.reputation_score(account)
+    }
+
+    /// Activity within one epoch of `EPOCH_LENGTH` seconds:
+    /// (jobs created, deposited, released, refunded)
+    pub fn get_period_stats(&self, epoch: u64) -> (U256, U256, U256, U256) {
+        let stats = self.period_stats.get(Uint::<64, 1>::from(epoch));
+        (
+            stats.jobs_created.get(),
+            stats.deposited.get(),
+            stats.released.get(),
+            stats.refunded.get(),
+        )
+    }
+}
+
+impl Escrow {
+    /// Validate and record a new public job funded by `msg.value`
+    fn open_job(
+        &mut self,
+        freelancer: Address,
+        duration: u64,
+        memo: B256,
+    ) -> Result<U256, Vec<u8>> {
+        let amount = self.vm().msg_value();
+        self.open_job_for(freelancer, duration, memo, amount)
+    }
+
+    /// Validate and record a new public job escrowing `amount` of the deposit
+    fn open_job_for(
+        &mut self,
+        freelancer: Address,
+        duration: u64,
+        memo: B256,
+        amount: U256,
+    ) -> Result<U256, Vec<u8>> {
+        let client = self.vm().msg_sender();
+        self.open_job_as(client, freelancer, duration, memo, amount, 0, B256::ZERO)
+    }
+
+    /// Validate and record a new public job for `client` escrowing `amount`,
+    /// filed under `category` and `tag`
+    #[allow(clippy::too_many_arguments)]
+    fn open_job_as(
+        &mut self,
+        client: Address,
+        freelancer: Address,
+        duration: u64,
+        memo: B256,
+        amount: U256,
+        category: u16,
+        tag: B256,
+    ) -> Result<U256, Vec<u8>> {
+        if self.paused_now() {
+            return Err(ErrorCode::EscrowIsPaused.into());
+        }
+        self.assert_not_sunset()?;
+        if amount == U256::ZERO {
+            return Err(ErrorCode::AmountMustBePositive.into());
+        }
+        if freelancer == Address::ZERO {
+            return Err(ErrorCode::InvalidFreelancerAddress.into());
+        }
+        if duration == 0 {
+            return Err(ErrorCode::DurationMustBePositive.into());
+        }
+        self.assert_compliant(client, freelancer, amount)?;
+        self.assert_below_cap(freelancer)?;
+        self.record_deposit_rate(client)?;
+
+        let timestamp = self.vm().block_timestamp();
+        let deadline = timestamp + duration;
+        let deadline_uint = Uint::<64, 1>::from(deadline);
+
+        let new_id = self.push_job(client, freelancer, amount);
+        let mut job = self.jobs.setter(new_id);
+        job.amount.set(amount);
+        job.deadline.set(deadline_uint);
+        job.released.set(false);
+        job.refunded.set(false);
+
+        self.finalized.setter(new_id).set(false);
+        self.record_deposit(client, freelancer, amount);
+        self.lock_escrow(new_id, amount);
+        if category != 0 || tag != B256::ZERO {
+            self.categorize(new_id, category, tag, amount);
+        }
+
+        log(self.vm(), Deposited {
+            job_id: new_id,
+            client,
+            freelancer,
+            amount,
+            memo,
+            category,
+            tag,
+        });
+        #[cfg(feature = "solvency-check")]
+        self.assert_solvent()?;
+
+        Ok(new_id)
+    }
+
+    /// Release a job as `sender`, who must be its client
+    fn release_as(&mut self, sender: Address, job_id: U256, memo: B256) -> Result<(), Vec<u8>> {
+        if self.paused_now() {
+            return Err(ErrorCode::EscrowIsPaused.into());
+        }
+
+        let job = self.jobs.get(job_id);
+        if job.client.get() != sender {
+            return Err(ErrorCode::OnlyClientCanRelease.into());
+        }
+        if job.released.get() || job.refunded.get() {
+            return Err(ErrorCode::JobAlreadySettled.into());
+        }
+        if self.finalized.get(job_id) {
+            return Err(ErrorCode::JobAlreadyFinalized.into());
+        }
+        self.assert_funded(job_id)?;
+        self.assert_not_staked(job_id)?;
+        if job.freelancer.get() == Address::ZERO {
+            return Err(ErrorCode::JobNotRevealed.into());
+        }
+        self.assert_not_disputed(job_id)?;
+        self.assert_no_queued_release(job_id)?;
+        self.assert_not_hourly(job_id)?;
+        self.assert_unilateral_release(job_id)?;
+
+        let amount = job.amount.get();
+        let freelancer = job.freelancer.get();
+        let held = self.pending_holdback(job_id, amount);
+        let late = self.late_refund(job_id, amount);
+        self.assert_compliant(job.client.get(), freelancer, amount - held - late)?;
+        if !held.is_zero() {
+            self.retain_holdback(job_id, held)?;
+            #[cfg(feature = "solvency-check")]
+            self.assert_solvent()?;
+            return Ok(());
+        }
+        if self.has_clawback(job_id) {
+            self.queue_release(job_id, memo);
+            return Ok(());
+        }
+
+        self.jobs.setter(job_id).released.set(true);
+        self.close_job(job_id);
+        let amount = amount - late;
+        self.record_release(job_id, freelancer, amount);
+
+        self.refund_late(job_id, late)?;
+        self.pay_release(job_id, freelancer, amount)?;
+        self.pay_basket(job_id, BASIS_POINTS)?;
+
+        log(self.vm(), Released {
+            job_id,
+            amount,
+            memo,
+        });
+        self.call_settlement_hook(job_id, amount, true);
+        #[cfg(feature = "solvency-check")]
+        self.assert_solvent()?;
+
+        Ok(())
+    }
+
+    /// Refund a job as `sender`, who must be its client
+    fn refund_as(&mut self, sender: Address, job_id: U256, memo: B256) -> Result<(), Vec<u8>> {
+        if self.paused_now() {
+            return Err(ErrorCode::EscrowIsPaused.into());
+        }
+
+        let job = self.jobs.get(job_id);
+        if job.client.get() != sender {
+            return Err(ErrorCode::OnlyClientCanRefund.into());
+        }
+        if job.released.get() || job.refunded.get() {
+            return Err(ErrorCode::JobAlreadySettled.into());
+        }
+        if self.finalized.get(job_id) {
+            return Err(ErrorCode::JobAlreadyFinalized.into());
+        }
+        self.assert_funded(job_id)?;
+        self.assert_not_staked(job_id)?;
+        if !self.tranches.get(job_id).is_empty() {
+            return Err(ErrorCode::JobIsTranched.into());
+        }
+        self.assert_not_retained(job_id)?;
+        self.assert_no_queued_release(job_id)?;
+        self.assert_not_hourly(job_id)?;
+        let cancellation = self.cancellations.get(job_id);
+        if cancellation.notice.get() != Uint::<64, 1>::ZERO {
+            let effective_at: u64 = cancellation.effective_at.get().to();
+            if effective_at == 0 {
+                return Err(ErrorCode::CancellationNotRequested.into());
+            }
+            if self.vm().block_timestamp() < effective_at {
+                return Err(ErrorCode::NoticePeriodRunning.into());
+            }
+        }
+        if self.vm().block_timestamp() >= job.deadline.get().to() {
+            return Err(ErrorCode::DeadlinePassed.into());
+        }
+
+        let amount = job.amount.get();
+        let client = job.client.get();
+
+        self.jobs.setter(job_id).refunded.set(true);
+        self.close_job(job_id);
+        self.record_refund(job_id, client, amount);
+
+        self.vm().transfer_eth(client, amount)?;
+        self.pay_basket(job_id, 0)?;
+
+        log(self.vm(), Refunded {
+            job_id,
+            amount,
+            memo,
+        });
+        self.call_settlement_hook(job_id, amount, false);
+        #[cfg(feature = "solvency-check")]
+        self.assert_solvent()?;
+
+        Ok(())
+    }
+
+    /// Return a job's funds to its client as `sender`, who must be its
+    /// freelancer
+    fn return_funds_as(
+        &mut self,
+        sender: Address,
+        job_id: U256,
+        memo: B256,
+    ) -> Result<(), Vec<u8>> {
+        if self.paused_now() {
+            return Err(ErrorCode::EscrowIsPaused.into());
+        }
+
+        self.assert_settleable(job_id)?;
+        self.assert_not_staked(job_id)?;
+        let job = self.jobs.get(job_id);
+        if job.freelancer.get() != sender {
+            return Err(ErrorCode::OnlyFreelancerCanReturnFunds.into());
+        }
+        self.assert_no_queued_release(job_id)?;
+
+        let amount = job.amount.get();
+        let client = job.client.get();
+
+        for (index, _, _) in self.open_tranches(job_id) {
+            if let Some(mut tranche) = self.tranches.setter(job_id).setter(index) {
+                tranche.settled.set(true);
+            }
+        }
+        self.jobs.setter(job_id).refunded.set(true);
+        self.close_job(job_id);
+        self.record_refund(job_id, client, amount);
+
+        self.vm().transfer_eth(client, amount)?;
+        self.pay_basket(job_id, 0)?;
+
+        log(self.vm(), FundsReturned {
+            job_id,
+            amount,
+            memo,
+        });
+        self.call_settlement_hook(job_id, amount, false);
+        #[cfg(feature = "solvency-check")]
+        self.assert_solvent()?;
+
+        Ok(())
+    }
+
+    /// Rule on a job's dispute as `sender`, who must be its arbitrator
+    fn rule_as(
+        &mut self,
+        sender: Address,
+        job_id: U256,
+        freelancer_bps: u16,
+    ) -> Result<(), Vec<u8>> {
+        if self.paused_now() {
+            return Err(ErrorCode::EscrowIsPaused.into());
+        }
+        if !self.is_disputed(job_id) {
+            return Err(ErrorCode::NoOpenDispute.into());
+        }
+        let dispute = self.disputes.get(job_id);
+        let arbitrator = dispute.arbitrator.get();
+        if arbitrator == Address::ZERO || sender != arbitrator {
+            return Err(ErrorCode::OnlyArbitrator.into());
+        }
+        self.assert_no_jury(job_id)?;
+        self.assert_no_held_ruling(job_id)?;
+        if self.vm().block_timestamp() >= dispute.rule_by.get().to() {
+            return Err(ErrorCode::RulingPeriodOver.into());
+        }
+        if freelancer_bps > BASIS_POINTS {
+            return Err(ErrorCode::InvalidSplit.into());
+        }
+
+        self.settle_ruling(sender, job_id, freelancer_bps)?;
+        #[cfg(feature = "solvency-check")]
+        self.assert_solvent()?;
+
+        Ok(())
+    }
+
+    /// Pay out a released job, withholding the freelancer's tax share first
+    fn pay_release(
+        &mut self,
+        job_id: U256,
+        freelancer: Address,
+        amount: U256,
+    ) -> Result<(), Vec<u8>> {
+        let destination = self.withholdings.get(freelancer).destination.get();
+        let (net, withheld, fee, platform_fee) = self.release_split(job_id, freelancer, amount);
+        self.record_client_volume(job_id, amount);
+        self.charge_fee(job_id, fee);
+        self.charge_platform_fee(job_id, platform_fee);
+
+        if withheld != U256::ZERO {
+            self.push_payout(job_id, destination, withheld)?;
+            log(self.vm(), Withheld {
+                job_id,
+                destination,
+                net,
+                withheld,
+            });
+        }
+        if net != U256::ZERO {
+            self.pay_freelancer(job_id, freelancer, net)?;
+        }
+
+        Ok(())
+    }
+
+    /// Whether a scheduled sunset has started, closing deposits
+    fn is_sunset(&self) -> bool {
+        let sunset_at: u64 = self.sunset_at.get().to();
+        sunset_at != 0 && self.vm().block_timestamp() >= sunset_at
+    }
+
+    /// Refuse new jobs, invoices and credit once a scheduled sunset has
+    /// started, and anything at all once the deployment is retired
+    fn assert_not_sunset(&self) -> Result<(), Vec<u8>> {
+        self.assert_not_retired()?;
+        if self.is_sunset() {
+            return Err(ErrorCode::EscrowIsSunset.into());
+        }
+        Ok(())
+    }
+
+    /// Refuse ETH into a retired deployment, which owes nothing and has
+    /// swept its balance
+    fn assert_not_retired(&self) -> Result<(), Vec<u8>> {
+        if self.retired.get() {
+            return Err(ErrorCode::EscrowIsRetired.into());
+        }
+        Ok(())
+    }
+
+    fn record_deposit(&mut self, client: Address, freelancer: Address, amount: U256) {
+        self.record_client_deposit(client, amount);
+        self.record_assignment(freelancer);
+    }
+
+    fn record_client_deposit(&mut self, client: Address, amount: U256) {
+        let total = self.total_deposited.get();
+        self.total_deposited.set(total + amount);
+
+        let mut client_stats = self.user_stats.setter(client);
+        let jobs = client_stats.jobs_as_client.get();
+        client_stats.jobs_as_client.set(jobs + U256::from(1));
+        let deposited = client_stats.total_deposited.get();
+        client_stats.total_deposited.set(deposited + amount);
+
+        self.record_period_deposit(true, amount);
+    }
+
+    fn record_assignment(&mut self, freelancer: Address) {
+        let mut freelancer_stats = self.user_stats.setter(freelancer);
+        let jobs = freelancer_stats.jobs_as_freelancer.get();
+        freelancer_stats.jobs_as_freelancer.set(jobs + U256::from(1));
+        self.take_job_slot(freelancer);
+    }
+
+    fn record_unassignment(&mut self, freelancer: Address) {
+        let mut freelancer_stats = self.user_stats.setter(freelancer);
+        let jobs = freelancer_stats.jobs_as_freelancer.get();
+        freelancer_stats.jobs_as_freelancer.set(jobs.saturating_sub(U256::from(1)));
+        self.free_job_slot(freelancer);
+    }
+
+    fn record_release(&mut self, job_id: U256, freelancer: Address, amount: U256) {
+        self.unlock_escrow(job_id, amount);
+
+        let total = self.total_released.get();
+        self.total_released.set(total + amount);
+
+        let mut stats = self.user_stats.setter(freelancer);
+        let earned = stats.total_earned.get();
+        stats.total_earned.set(earned + amount);
+
+        self.record_period_release(amount);
+    }
+
+    fn record_refund(&mut self, job_id: U256, client: Address, amount: U256) {
+        self.unlock_escrow(job_id, amount);
+
+        let total = self.total_refunded.get();
+        self.total_refunded.set(total + amount);
+
+        let mut stats = self.user_stats.setter(client);
+        let refunded = stats.total_refunded.get();
+        stats.total_refunded.set(refunded + amount);
+
+        self.record_period_refund(amount);
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
+mod tests {
+    use super::*;
+    use stylus_sdk::testing::*;
+    use alloy_primitives::{keccak256, Address, I256, U256, B256};
+    use alloy_sol_types::{sol_data, SolType, SolValue};
+
+    /// What TestVM reverts with when the contract cannot cover a transfer
+    const TRANSFER_FAILED: &[u8] = b"insufficient funds for transfer";
+
+    /// Revert data the contract returns for `error`
+    fn revert(error: ErrorCode) -> Vec<u8> {
+        error.into()
+    }
+
+    /// Decode `setting`'s value for `key` as `get_setting` returns it
+    fn setting<T>(contract: &Escrow, setting: u8, key: Address) -> T
+    where
+        T: SolValue + From<<T::SolType as SolType>::RustType>,
+        for<'de> <T::SolType as SolType>::Token<'de>: alloy_sol_types::abi::TokenSeq<'de>,
+    {
+        let data = contract.get_setting(setting, key).unwrap();
+        T::abi_decode_params(&data, true).unwrap()
+    }
+
+    /// Set `msg.value` and credit it to the contract, as a real payable call
+    /// would; TestVM only does the former
+    fn send_value(vm: &TestVM, value: U256) {
+        vm.set_value(value);
+        let contract = vm.contract_address();
+        vm.set_balance(contract, vm.balance(contract) + value);
+    }
+
+    /// Empty the contract's balance so its next `transfer_eth` fails,
+    /// returning what was removed so the caller can put it back
+    fn fail_transfers(vm: &TestVM) -> U256 {
+        let contract = vm.contract_address();
+        let balance = vm.balance(contract);
+        vm.set_balance(contract, U256::ZERO);
+        balance
+    }
+
+    /// ETH balances of a set of addresses at one point in a test
+    struct Balances<'a> {
+        vm: &'a TestVM,
+        before: Vec<(Address, U256)>,
+    }
+
+    impl<'a> Balances<'a> {
+        fn track(vm: &'a TestVM, addresses: &[Address]) -> Self {
+            let before = addresses.iter().map(|&address| (address, vm.balance(address))).collect();
+            Self { vm, before }
+        }
+
+        /// Assert each tracked address moved by exactly its listed delta,
+        /// and any address left unlisted did not move at all
+        fn assert_deltas(&self, deltas: &[(Address, i128)]) {
+            for &(address, before) in &self.before {
+                let expected = deltas
+                    .iter()
+                    .find(|(listed, _)| *listed == address)
+                    .map_or(0, |&(_, delta)| delta);
+                let delta = I256::from_raw(self.vm.balance(address)) - I256::from_raw(before);
+                assert_eq!(delta, I256::try_from(expected).unwrap(), "balance delta of {address}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_constructor() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let admin = Address::from([0x01; 20]);
+
+        // The admin must be a real address
+        assert_eq!(
+            contract.constructor(Address::ZERO).unwrap_err(),
+            revert(ErrorCode::InvalidAdminAddress)
+        );
+
+        // The admin need not be the deployer
+        assert!(contract.constructor(admin).is_ok());
+        assert_eq!(contract.admin.get(), admin);
+        assert_eq!(contract.is_paused(), false);
+        assert_eq!(contract.get_total_jobs(), U256::from(0));
+    }
+
+    #[test]
+    fn test_direct_transfers_rejected() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+
+        assert!(contract.constructor(vm.msg_sender()).is_ok());
+        vm.set_value(U256::from(1_000));
+        assert_eq!(contract.receive().unwrap_err(), revert(ErrorCode::DirectTransfersNotAccepted));
+        vm.set_value(U256::ZERO);
+        assert_eq!(
+            contract.fallback(&[0xde, 0xad, 0xbe, 0xef]).unwrap_err(),
+            revert(ErrorCode::UnknownFunction)
+        );
+        assert!(vm.get_emitted_logs().is_empty());
+    }
+
+    #[test]
+    fn test_error_codes() {
+        use alloy_sol_types::SolError;
+
+        // Codes run from 1 without gaps, so a removed error would show up here
+        for (i, error) in ErrorCode::ALL.iter().enumerate() {
+            assert_eq!(error.code() as usize, i + 1);
+            assert_eq!(ErrorCode::from_code(error.code()), Some(*error));
+        }
+        assert_eq!(ErrorCode::from_code(0), None);
+        let mut messages: Vec<&str> = ErrorCode::ALL.iter().map(|error| error.message()).collect();
+        messages.sort_unstable();
+        messages.dedup();
+        assert_eq!(messages.len(), ErrorCode::ALL.len());
+
+        // A selector and one word, whatever the message
+        let data = revert(ErrorCode::OnlyJobPartiesCanSubmitEvidence);
+        assert_eq!(data.len(), 36);
+        assert_eq!(&data[..4], EscrowError::SELECTOR.as_slice());
+        let decoded = EscrowError::abi_decode(&data, true).unwrap();
+        assert_eq!(ErrorCode::from_code(decoded.code), Some(ErrorCode::OnlyJobPartiesCanSubmitEvidence));
+    }
+
+    #[test]
+    fn test_deposit() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let client = vm.msg_sender();
+        let freelancer = Address::from([0x01; 20]);
+        let amount = U256::from(1_000_000_000_000_000_000_u64); // 1 ETH
+        let duration = 86_400_u64; // 1 day in seconds
+        let memo = B256::right_padding_from(b"PO-2024-0117");
+
+        // Initialize contract
+        assert!(contract.constructor(vm.msg_sender()).is_ok());
+
+        // Set msg.value for deposit
+        vm.set_value(amount);
+
+        // Test successful deposit
+        let result = contract.deposit(freelancer, duration, memo);
+        assert!(result.is_ok());
+        let job_id = result.unwrap();
+        assert_eq!(job_id, U256::from(1));
+        assert_eq!(contract.get_total_jobs(), U256::from(1));
+        assert_eq!(contract.get_active_jobs(), vec![U256::from(1)]);
+
+        // Verify job details
+        let (id, job_client, job_freelancer, job_amount, deadline, released, refunded) =
+            contract.get_job(job_id).unwrap();
+        assert_eq!(id, job_id);
+        assert_eq!(job_client, client);
+        assert_eq!(job_freelancer, freelancer);
+        assert_eq!(job_amount, amount);
+        assert_eq!(deadline, vm.block_timestamp() + duration);
+        assert_eq!(released, false);
+        assert_eq!(refunded, false);
+
+        // IDs outside 1..=`job_count` were never created
+        assert_eq!(contract.get_job(U256::ZERO).unwrap_err(), revert(ErrorCode::JobDoesNotExist));
+        assert_eq!(contract.get_job(U256::from(2)).unwrap_err(), revert(ErrorCode::JobDoesNotExist));
+
+        // Verify Deposited event
+        let logs = vm.get_emitted_logs();
+        assert_eq!(logs.len(), 1);
+        assert_eq!(logs[0].0[0], DEPOSITED_TOPIC);
+        assert_eq!(
+            EscrowEvent::decode(&logs[0].0, &logs[0].1),
+            Some(EscrowEvent::Deposited(Deposited {
+                job_id,
+                client,
+                freelancer,
+                amount,
+                memo,
+                category: 0,
+                tag: B256::ZERO,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_deposit_invalid_inputs() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let freelancer = Address::from([0x01; 20]);
+        let duration = 86_400_u64; // 1 day
+
+        // Initialize contract
+        assert!(contract.constructor(vm.msg_sender()).is_ok());
+
+        // Test deposit with zero amount
+        vm.set_value(U256::from(0));
+        assert_eq!(
+            contract.deposit(freelancer, duration, B256::ZERO).unwrap_err(),
+            revert(ErrorCode::AmountMustBePositive)
+        );
+
+        // Test deposit with zero address
+        vm.set_value(U256::from(1_000_000));
+        assert_eq!(
+            contract.deposit(Address::ZERO, duration, B256::ZERO).unwrap_err(),
+            revert(ErrorCode::InvalidFreelancerAddress)
+        );
+
+        // Test deposit with zero duration
+        assert_eq!(
+            contract.deposit(freelancer, 0, B256::ZERO).unwrap_err(),
+            revert(ErrorCode::DurationMustBePositive)
+        );
+
+        // Test deposit when paused
+        assert!(contract.set_paused(true).is_ok());
+        vm.set_value(U256::from(1_000_000));
+        assert_eq!(
+            contract.deposit(freelancer, duration, B256::ZERO).unwrap_err(),
+            revert(ErrorCode::EscrowIsPaused)
+        );
+    }
+
+    #[test]
+    fn test_deposit_with_nonce() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let client = vm.msg_sender();
+        let freelancer = Address::from([0x01; 20]);
+        let amount = U256::from(1_000_000);
+        let nonce = U256::from(42);
+
+        assert!(contract.constructor(vm.msg_sender()).is_ok());
+        vm.set_value(amount);
+
+        // A failed attempt does not consume the nonce
+        assert_eq!(
+            contract.deposit_with_nonce(freelancer, 0, nonce).unwrap_err(),
+            revert(ErrorCode::DurationMustBePositive)
+        );
+        assert_eq!(contract.get_job_by_nonce(client, nonce), U256::ZERO);
+
+        // The first deposit succeeds and is recorded against the nonce
+        let job_id = contract.deposit_with_nonce(freelancer, 86_400, nonce).unwrap();
+        assert_eq!(contract.get_job_by_nonce(client, nonce), job_id);
+
+        // A retry with the same nonce is rejected
+        assert_eq!(
+            contract.deposit_with_nonce(freelancer, 86_400, nonce).unwrap_err(),
+            revert(ErrorCode::NonceAlreadyUsed)
+        );
+        assert_eq!(contract.get_total_jobs(), U256::from(1));
+
+        // Nonces are per client
+        let other = Address::from([0x02; 20]);
+        vm.set_sender(other);
+        let other_job = contract.deposit_with_nonce(freelancer, 86_400, nonce).unwrap();
+        assert_eq!(contract.get_job_by_nonce(other, nonce), other_job);
+        assert_eq!(contract.get_job_by_nonce(client, nonce), job_id);
+    }
+
+    #[test]
+    fn test_accept_offer() {
+        use ethers::signers::{LocalWallet, Signer};
+        use ethers::types::H256;
+
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let wallet: LocalWallet = "4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318"
+            .parse()
+            .unwrap();
+        let client = Address::from(wallet.address().0);
+        let freelancer = Address::from([0x01; 20]);
+        let amount = U256::from(1_000_000);
+        let memo = B256::right_padding_from(b"PO-7");
+        let nonce = U256::from(7);
+        let expiry = 10_000_u64;
+
+        assert!(contract.constructor(vm.msg_sender()).is_ok());
+        vm.set_block_timestamp(1_000);
+
+        // The digest is the standard EIP-712 encoding of the offer
+        let offer = JobOffer {
+            client,
+            freelancer,
+            amount,
+            duration: 86_400,
+            memo,
+            nonce,
+            expiry,
+        };
+        let domain_separator = keccak256(
+            (
+                keccak256("EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)"),
+                keccak256("FreelancePaymentEscrow"),
+                keccak256("1"),
+                U256::from(vm.chain_id()),
+                vm.contract_address(),
+            )
+                .abi_encode(),
+        );
+        let struct_hash = keccak256(
+            (
+                keccak256("JobOffer(address client,address freelancer,uint256 amount,uint64 duration,bytes32 memo,uint256 nonce,uint64 expiry)"),
+                client,
+                freelancer,
+                amount,
+                86_400_u64,
+                memo,
+                nonce,
+                expiry,
+            )
+                .abi_encode(),
+        );
+        let digest = keccak256([&[0x19, 0x01][..], &domain_separator[..], &struct_hash[..]].concat());
+        assert_eq!(offer_hash(&offer, vm.chain_id(), vm.contract_address()), digest);
+
+        // The ecrecover precompile answers as the chain would for the
+        // client's signature
+        let signature = wallet.sign_hash(H256(digest.0)).unwrap();
+        assert_eq!(signature.recover(H256(digest.0)).unwrap(), wallet.address());
+        let signature = signature.to_vec();
+        let mut input = digest.to_vec();
+        input.extend_from_slice(&U256::from(signature[64]).to_be_bytes::<32>());
+        input.extend_from_slice(&signature[..64]);
+        vm.mock_static_call(Address::with_last_byte(1), input, Ok(client.into_word().to_vec()));
+        let accept = |contract: &mut Escrow, amount: U256, signature: &[u8]| {
+            contract.accept_offer(client, amount, 86_400, memo, nonce, expiry, Bytes(signature.to_vec()))
+        };
+
+        // The offer is funded from the client's credit, which is empty
+        vm.set_sender(freelancer);
+        assert_eq!(
+            accept(&mut contract, amount, &signature).unwrap_err(),
+            revert(ErrorCode::InsufficientCredit)
+        );
+
+        vm.set_sender(client);
+        send_value(&vm, amount * U256::from(2));
+        contract.deposit_credit().unwrap();
+        assert_eq!(contract.get_credit(client), amount * U256::from(2));
+        let logs = vm.get_emitted_logs();
+        assert_eq!(logs.last().unwrap().0[0], CREDIT_DEPOSITED_TOPIC);
+        vm.set_value(U256::ZERO);
+
+        // Only the freelancer named in the offer can submit it, and only on
+        // the signed terms
+        vm.set_sender(Address::from([0x02; 20]));
+        assert_eq!(
+            accept(&mut contract, amount, &signature).unwrap_err(),
+            revert(ErrorCode::InvalidSignature)
+        );
+        vm.set_sender(freelancer);
+        assert_eq!(
+            accept(&mut contract, amount * U256::from(2), &signature).unwrap_err(),
+            revert(ErrorCode::InvalidSignature)
+        );
+        assert_eq!(
+            accept(&mut contract, amount, &signature[..64]).unwrap_err(),
+            revert(ErrorCode::InvalidSignature)
+        );
+
+        // The freelancer opens the job for the client out of their credit
+        let job_id = accept(&mut contract, amount, &signature).unwrap();
+        let (_, job_client, job_freelancer, job_amount, deadline, _, _) = contract.get_job(job_id).unwrap();
+        assert_eq!((job_client, job_freelancer, job_amount), (client, freelancer, amount));
+        assert_eq!(deadline, 1_000 + 86_400);
+        assert_eq!(contract.get_credit(client), amount);
+        assert_eq!(contract.get_job_by_nonce(client, nonce), job_id);
+        assert!(contract.verify_solvency());
+
+        // The offer cannot be replayed, nor used once expired
+        assert_eq!(
+            accept(&mut contract, amount, &signature).unwrap_err(),
+            revert(ErrorCode::NonceAlreadyUsed)
+        );
+        vm.set_block_timestamp(expiry);
+        assert_eq!(
+            accept(&mut contract, amount, &signature).unwrap_err(),
+            revert(ErrorCode::OfferExpired)
+        );
+
+        // A cancelled nonce can open no job, signed or not
+        vm.set_sender(client);
+        let cancelled = U256::from(8);
+        contract.cancel_offer(cancelled).unwrap();
+        assert!(contract.is_offer_cancelled(client, cancelled));
+        let logs = vm.get_emitted_logs();
+        assert_eq!(logs.last().unwrap().0[0], OFFER_CANCELLED_TOPIC);
+        assert_eq!(
+            contract.cancel_offer(nonce).unwrap_err(),
+            revert(ErrorCode::NonceAlreadyUsed)
+        );
+        vm.set_value(amount);
+        assert_eq!(
+            contract.deposit_with_nonce(freelancer, 86_400, cancelled).unwrap_err(),
+            revert(ErrorCode::NonceAlreadyUsed)
+        );
+    }
+
+    #[test]
+    fn test_signed_settlements() {
+        use crate::authorizations::isValidSignatureCall;
+        use alloy_sol_types::SolCall;
+        use ethers::signers::{LocalWallet, Signer};
+        use ethers::types::H256;
+
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        // The client is a smart account, the freelancer an EOA
+        let client = Address::from([0xaa; 20]);
+        let wallet: LocalWallet = "4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318"
+            .parse()
+            .unwrap();
+        let freelancer = Address::from(wallet.address().0);
+        let bundler = Address::from([0x0b; 20]);
+        let expiry = 10_000_u64;
+        let (one, two, three) = (U256::from(1), U256::from(2), U256::from(3));
+
+        assert!(contract.constructor(vm.msg_sender()).is_ok());
+        vm.set_code(client, vec![0x60, 0x00]);
+        vm.set_block_timestamp(1_000);
+        vm.set_sender(client);
+        for _ in 0..3 {
+            send_value(&vm, U256::from(1_000));
+            assert!(contract.deposit(freelancer, 86_400, B256::ZERO).is_ok());
+        }
+        vm.set_value(U256::ZERO);
+
+        // The digest is the EIP-712 hash of the settlement
+        let memo = B256::right_padding_from(b"INV-9");
+        let settlement = Settlement {
+            job_id: one,
+            signer: client,
+            release: true,
+            memo,
+            nonce: one,
+            expiry,
+        };
+        let digest = contract.get_settlement_digest(one, client, true, memo, one, expiry);
+        assert_eq!(digest, settlement_hash(&settlement, vm.chain_id(), vm.contract_address()));
+
+        // The smart account vouches for its signature under ERC-1271
+        let approval = b"signed by the account's owners".to_vec();
+        let check = isValidSignatureCall {
+            hash: digest,
+            signature: approval.clone().into(),
+        }
+        .abi_encode();
+        let mut magic = [0u8; 32];
+        magic[..4].copy_from_slice(&[0x16, 0x26, 0xba, 0x7e]);
+        vm.mock_static_call(client, check, Ok(magic.to_vec()));
+        assert!(contract.verify_signature(client, digest, Bytes(approval.clone())));
+        assert!(!contract.verify_signature(client, digest, Bytes(b"forged".to_vec())));
+
+        vm.set_sender(bundler);
+        assert_eq!(
+            contract
+                .release_with_signature(one, memo, one, expiry, Bytes(b"forged".to_vec()))
+                .unwrap_err(),
+            revert(ErrorCode::InvalidSignature)
+        );
+        // A signature over other terms doesn't verify either
+        assert_eq!(
+            contract
+                .release_with_signature(one, B256::ZERO, one, expiry, Bytes(approval.clone()))
+                .unwrap_err(),
+            revert(ErrorCode::InvalidSignature)
+        );
+        let balances = Balances::track(&vm, &[freelancer]);
+        assert!(contract
+            .release_with_signature(one, memo, one, expiry, Bytes(approval.clone()))
+            .is_ok());
+        balances.assert_deltas(&[(freelancer, 1_000)]);
+        let logs = vm.get_emitted_logs();
+        assert_eq!(
+            EscrowEvent::decode(&logs.last().unwrap().0, &logs.last().unwrap().1),
+            Some(EscrowEvent::Released(Released {
+                job_id: one,
+                amount: U256::from(1_000),
+                memo,
+            }))
+        );
+        assert!(contract.is_authorization_used(client, one));
+        assert_eq!(
+            contract
+                .release_with_signature(one, memo, one, expiry, Bytes(approval))
+                .unwrap_err(),
+            revert(ErrorCode::NonceAlreadyUsed)
+        );
+
+        // The freelancer's ECDSA signature returns a job's funds
+        let digest =
+            contract.get_settlement_digest(two, freelancer, false, B256::ZERO, one, expiry);
+        let signature = wallet.sign_hash(H256(digest.0)).unwrap().to_vec();
+        let mut input = digest.to_vec();
+        input.extend_from_slice(&U256::from(signature[64]).to_be_bytes::<32>());
+        input.extend_from_slice(&signature[..64]);
+        vm.mock_static_call(Address::with_last_byte(1), input, Ok(freelancer.into_word().to_vec()));
+        assert_eq!(
+            contract
+                .refund_with_signature(two, bundler, B256::ZERO, one, expiry, Bytes(signature.clone()))
+                .unwrap_err(),
+            revert(ErrorCode::NotAParty)
+        );
+        let balances = Balances::track(&vm, &[client]);
+        assert!(contract
+            .refund_with_signature(two, freelancer, B256::ZERO, one, expiry, Bytes(signature))
+            .is_ok());
+        balances.assert_deltas(&[(client, 1_000)]);
+        let logs = vm.get_emitted_logs();
+        assert_eq!(logs.last().unwrap().0[0], FUNDS_RETURNED_TOPIC);
+
+        // Authorizations expire, and the signer can cancel them first
+        vm.set_block_timestamp(expiry);
+        assert_eq!(
+            contract
+                .refund_with_signature(three, client, B256::ZERO, two, expiry, Bytes(vec![]))
+                .unwrap_err(),
+            revert(ErrorCode::AuthorizationExpired)
+        );
+        vm.set_sender(client);
+        assert!(contract.cancel_authorization(two).is_ok());
+        let logs = vm.get_emitted_logs();
+        assert_eq!(
+            EscrowEvent::decode(&logs.last().unwrap().0, &logs.last().unwrap().1),
+            Some(EscrowEvent::AuthorizationCancelled(AuthorizationCancelled {
+                signer: client,
+                nonce: two,
+            }))
+        );
+        assert_eq!(
+            contract.cancel_authorization(two).unwrap_err(),
+            revert(ErrorCode::NonceAlreadyUsed)
+        );
+    }
+
+    #[test]
+    fn test_release() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let client = vm.msg_sender();
+        let freelancer = Address::from([0x01; 20]);
+        let amount = U256::from(1_000_000_000_000_000_000_u64); // 1 ETH
+        let duration = 86_400_u64; // 1 day
+        let memo = B256::right_padding_from(b"INV-0042");
+        let escrow = vm.contract_address();
+
+        // Initialize and deposit
+        assert!(contract.constructor(vm.msg_sender()).is_ok());
+        send_value(&vm, amount);
+        let job_id = contract.deposit(freelancer, duration, B256::ZERO).unwrap();
+
+        // Test successful release
+        let balances = Balances::track(&vm, &[client, freelancer, escrow]);
+        assert!(contract.release(job_id, memo).is_ok());
+        balances.assert_deltas(&[
+            (freelancer, 1_000_000_000_000_000_000),
+            (escrow, -1_000_000_000_000_000_000),
+        ]);
+        let (id, _, _, _, _, released, refunded) = contract.get_job(job_id).unwrap();
+        assert_eq!(id, job_id);
+        assert_eq!(released, true);
+        assert_eq!(refunded, false);
+        assert_eq!(contract.finalized.get(job_id), true);
+        assert!(contract.get_active_jobs().is_empty());
+
+        // Verify Released event
+        let logs = vm.get_emitted_logs();
+        assert_eq!(logs.len(), 2); // Deposited + Released
+        assert_eq!(
+            EscrowEvent::decode(&logs[1].0, &logs[1].1),
+            Some(EscrowEvent::Released(Released { job_id, amount, memo }))
+        );
+
+        // Test release by non-client
+        send_value(&vm, amount);
+        let job_id2 = contract.deposit(freelancer, duration, B256::ZERO).unwrap();
+        vm.set_sender(Address::from([0x02; 20]));
+        let balances = Balances::track(&vm, &[client, freelancer, escrow]);
+        assert_eq!(
+            contract.release(job_id2, B256::ZERO).unwrap_err(),
+            revert(ErrorCode::OnlyClientCanRelease)
+        );
+        balances.assert_deltas(&[]);
+
+        // Test release on already settled job
+        vm.set_sender(client);
+        assert!(contract.release(job_id, B256::ZERO).is_err());
+    }
+
+    #[test]
+    fn test_release_partial() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let client = vm.msg_sender();
+        let freelancer = Address::from([0x01; 20]);
+        let escrow = vm.contract_address();
+
+        assert!(contract.constructor(client).is_ok());
+        send_value(&vm, U256::from(1_000));
+        let job_id = contract.deposit(freelancer, 86_400, B256::ZERO).unwrap();
+
+        vm.set_sender(freelancer);
+        assert_eq!(
+            contract.release_partial(job_id, U256::from(300)).unwrap_err(),
+            revert(ErrorCode::OnlyClientCanRelease)
+        );
+        vm.set_sender(client);
+        assert_eq!(
+            contract.release_partial(job_id, U256::ZERO).unwrap_err(),
+            revert(ErrorCode::AmountMustBePositive)
+        );
+        assert_eq!(
+            contract.release_partial(job_id, U256::from(1_001)).unwrap_err(),
+            revert(ErrorCode::AmountExceedsEscrow)
+        );
+
+        // A progress payment leaves the rest escrowed under the same terms
+        let balances = Balances::track(&vm, &[client, freelancer, escrow]);
+        assert!(contract.release_partial(job_id, U256::from(300)).is_ok());
+        balances.assert_deltas(&[(freelancer, 300), (escrow, -300)]);
+        let (_, _, _, remaining, deadline, released, refunded) = contract.get_job(job_id).unwrap();
+        assert_eq!(remaining, U256::from(700));
+        assert_eq!(deadline, 86_400);
+        assert_eq!((released, refunded), (false, false));
+        assert_eq!(contract.get_active_jobs(), vec![job_id]);
+        let logs = vm.get_emitted_logs();
+        assert_eq!(
+            EscrowEvent::decode(&logs.last().unwrap().0, &logs.last().unwrap().1),
+            Some(EscrowEvent::PartiallyReleased(PartiallyReleased {
+                job_id,
+                amount: U256::from(300),
+                remaining: U256::from(700),
+            }))
+        );
+        assert!(contract.verify_solvency());
+
+        // The remainder can still be refunded, or released in full
+        assert_eq!(contract.preview_refund(job_id).unwrap().2, U256::from(700));
+        assert!(contract.release_partial(job_id, U256::from(700)).is_ok());
+        assert_eq!(vm.balance(freelancer), U256::from(1_000));
+        let (_, _, _, remaining, _, released, _) = contract.get_job(job_id).unwrap();
+        assert_eq!(remaining, U256::ZERO);
+        assert_eq!(released, true);
+        assert!(contract.get_active_jobs().is_empty());
+        assert_eq!(
+            contract.release_partial(job_id, U256::from(1)).unwrap_err(),
+            revert(ErrorCode::JobAlreadySettled)
+        );
+        assert!(contract.verify_solvency());
+    }
+
+    #[test]
+    fn test_holdback() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let client = vm.msg_sender();
+        let freelancer = Address::from([0x01; 20]);
+        let warranty = 7 * 86_400_u64;
+
+        assert!(contract.constructor(client).is_ok());
+        send_value(&vm, U256::from(1_000));
+        assert_eq!(
+            contract.deposit_with_holdback(freelancer, 86_400, 0, warranty).unwrap_err(),
+            revert(ErrorCode::InvalidHoldback)
+        );
+        assert_eq!(
+            contract.deposit_with_holdback(freelancer, 86_400, BASIS_POINTS, warranty).unwrap_err(),
+            revert(ErrorCode::InvalidHoldback)
+        );
+        assert_eq!(
+            contract.deposit_with_holdback(freelancer, 86_400, 1_000, 0).unwrap_err(),
+            revert(ErrorCode::InvalidWarranty)
+        );
+        let job_id = contract.deposit_with_holdback(freelancer, 86_400, 1_000, warranty).unwrap();
+        assert_eq!(contract.get_holdback(job_id), (1_000, warranty, 0));
+        let preview = contract.preview_release(job_id).unwrap();
+        assert_eq!((preview.0, preview.5), (U256::from(900), U256::from(100)));
+
+        // Releasing pays out all but the holdback and starts the warranty
+        vm.set_block_timestamp(1_000);
+        assert!(contract.release(job_id, B256::ZERO).is_ok());
+        assert_eq!(vm.balance(freelancer), U256::from(900));
+        assert_eq!(contract.get_holdback(job_id), (1_000, warranty, 1_000 + warranty));
+        let (_, _, _, remaining, _, released, _) = contract.get_job(job_id).unwrap();
+        assert_eq!((remaining, released), (U256::from(100), false));
+        let logs = vm.get_emitted_logs();
+        assert_eq!(
+            EscrowEvent::decode(&logs.last().unwrap().0, &logs.last().unwrap().1),
+            Some(EscrowEvent::HoldbackRetained(HoldbackRetained {
+                job_id,
+                amount: U256::from(100),
+                release_at: 1_000 + warranty,
+            }))
+        );
+        assert!(contract.verify_solvency());
+
+        // The client can't take the holdback back, and it waits out the warranty
+        assert_eq!(contract.refund(job_id, B256::ZERO).unwrap_err(), revert(ErrorCode::HoldbackRetained));
+        vm.set_sender(Address::from([0x02; 20]));
+        assert_eq!(contract.release_holdback(job_id).unwrap_err(), revert(ErrorCode::WarrantyRunning));
+
+        // After it, anyone can release the holdback to the freelancer
+        vm.set_block_timestamp(1_000 + warranty);
+        assert!(contract.release_holdback(job_id).is_ok());
+        assert_eq!(vm.balance(freelancer), U256::from(1_000));
+        let (_, _, _, _, _, released, _) = contract.get_job(job_id).unwrap();
+        assert_eq!(released, true);
+        assert!(contract.verify_solvency());
+
+        // A defect dispute raised during the warranty freezes the holdback
+        vm.set_sender(client);
+        send_value(&vm, U256::from(1_000));
+        let job_id = contract.deposit_with_holdback(freelancer, 86_400, 1_000, warranty).unwrap();
+        assert_eq!(
+            contract.release_holdback(job_id).unwrap_err(),
+            revert(ErrorCode::NoHoldbackRetained)
+        );
+        vm.set_value(U256::ZERO);
+        assert!(contract.release(job_id, B256::ZERO).is_ok());
+        assert!(contract.open_dispute(job_id, B256::ZERO).is_ok());
+        vm.set_block_timestamp(1_000 + 2 * warranty);
+        assert_eq!(contract.release_holdback(job_id).unwrap_err(), revert(ErrorCode::JobIsDisputed));
+    }
+
+    #[test]
+    fn test_clawback() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let client = vm.msg_sender();
+        let freelancer = Address::from([0x01; 20]);
+        let arbitrator = Address::from([0x0a; 20]);
+        let window = 2 * 86_400_u64;
+        let memo = B256::right_padding_from(b"INV-0042");
+
+        assert!(contract.constructor(client).is_ok());
+        send_value(&vm, U256::from(1_000));
+        assert_eq!(
+            contract.deposit_with_clawback(freelancer, 86_400, window).unwrap_err(),
+            revert(ErrorCode::NoArbitrator)
+        );
+        assert!(contract.set_arbitration_policy(arbitrator, 86_400, 5_000).is_ok());
+        assert_eq!(
+            contract.deposit_with_clawback(freelancer, 86_400, MAX_CLAWBACK_WINDOW + 1).unwrap_err(),
+            revert(ErrorCode::InvalidClawbackWindow)
+        );
+        let job_id = contract.deposit_with_clawback(freelancer, 86_400, window).unwrap();
+        assert_eq!(contract.get_clawback(job_id), (window, 0));
+
+        // Releasing only queues the payout for the clawback window
+        vm.set_block_timestamp(1_000);
+        assert!(contract.release(job_id, memo).is_ok());
+        assert_eq!(vm.balance(freelancer), U256::ZERO);
+        assert_eq!(contract.get_clawback(job_id), (window, 1_000 + window));
+        let (_, _, _, amount, _, released, _) = contract.get_job(job_id).unwrap();
+        assert_eq!((amount, released), (U256::from(1_000), false));
+        assert_eq!(contract.release(job_id, memo).unwrap_err(), revert(ErrorCode::ReleaseQueued));
+        assert_eq!(contract.refund(job_id, B256::ZERO).unwrap_err(), revert(ErrorCode::ReleaseQueued));
+        assert_eq!(
+            contract.open_dispute(job_id, B256::ZERO).unwrap_err(),
+            revert(ErrorCode::ReleaseQueued)
+        );
+        assert_eq!(
+            contract.finalize_release(job_id).unwrap_err(),
+            revert(ErrorCode::ClawbackWindowOpen)
+        );
+
+        // Once the window is over anyone can pay it out, with the client's memo
+        vm.set_block_timestamp(1_000 + window);
+        vm.set_sender(arbitrator);
+        assert_eq!(
+            contract.reverse_release(job_id).unwrap_err(),
+            revert(ErrorCode::ClawbackWindowClosed)
+        );
+        vm.set_sender(Address::from([0x02; 20]));
+        assert!(contract.finalize_release(job_id).is_ok());
+        assert_eq!(vm.balance(freelancer), U256::from(1_000));
+        let logs = vm.get_emitted_logs();
+        assert_eq!(
+            EscrowEvent::decode(&logs.last().unwrap().0, &logs.last().unwrap().1),
+            Some(EscrowEvent::Released(Released {
+                job_id,
+                amount: U256::from(1_000),
+                memo,
+            }))
+        );
+        assert!(contract.verify_solvency());
+
+        // Within the window the arbitrator can reverse it, refunding the client
+        vm.set_sender(client);
+        send_value(&vm, U256::from(1_000));
+        let job_id = contract.deposit_with_clawback(freelancer, 86_400, window).unwrap();
+        assert!(contract.release(job_id, B256::ZERO).is_ok());
+        assert_eq!(contract.reverse_release(job_id).unwrap_err(), revert(ErrorCode::OnlyArbitrator));
+        vm.set_sender(arbitrator);
+        let balances = Balances::track(&vm, &[client, freelancer]);
+        assert!(contract.reverse_release(job_id).is_ok());
+        balances.assert_deltas(&[(client, 1_000)]);
+        let (_, _, _, _, _, released, refunded) = contract.get_job(job_id).unwrap();
+        assert_eq!((released, refunded), (false, true));
+        assert!(contract.verify_solvency());
+    }
+
+    #[test]
+    fn test_sla() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let client = vm.msg_sender();
+        let freelancer = Address::from([0x01; 20]);
+        let day = 86_400_u64;
+        let delivery_by = 1_000 + 2 * day;
+
+        assert!(contract.constructor(client).is_ok());
+        vm.set_block_timestamp(1_000);
+        send_value(&vm, U256::from(1_000));
+        assert_eq!(
+            contract.deposit_with_sla(freelancer, 7 * day, 1_000, 500).unwrap_err(),
+            revert(ErrorCode::InvalidDeliveryDate)
+        );
+        assert_eq!(
+            contract.deposit_with_sla(freelancer, 7 * day, delivery_by, 0).unwrap_err(),
+            revert(ErrorCode::InvalidLateRefund)
+        );
+        let job_id = contract.deposit_with_sla(freelancer, 7 * day, delivery_by, 500).unwrap();
+        assert_eq!(contract.get_sla(job_id), (delivery_by, 500, 0));
+
+        // Delivering three and a half days late costs three days' worth
+        vm.set_block_timestamp(delivery_by + 3 * day + day / 2);
+        vm.set_sender(freelancer);
+        assert!(contract.submit_delivery(job_id, B256::from([0xaa; 32])).is_ok());
+        assert_eq!(
+            contract.submit_delivery(job_id, B256::ZERO).unwrap_err(),
+            revert(ErrorCode::AlreadyDelivered)
+        );
+        vm.set_sender(client);
+        assert_eq!(
+            contract.release_partial(job_id, U256::from(100)).unwrap_err(),
+            revert(ErrorCode::JobHasSLA)
+        );
+
+        // The clock stops at delivery, however long the client takes to release
+        vm.set_block_timestamp(delivery_by + 30 * day);
+        let (payout, _, late, _, _, _) = contract.preview_release(job_id).unwrap();
+        assert_eq!((payout, late), (U256::from(850), U256::from(150)));
+        let balances = Balances::track(&vm, &[client, freelancer]);
+        assert!(contract.release(job_id, B256::ZERO).is_ok());
+        balances.assert_deltas(&[(client, 150), (freelancer, 850)]);
+        let logs = vm.get_emitted_logs();
+        assert_eq!(
+            EscrowEvent::decode(&logs[logs.len() - 2].0, &logs[logs.len() - 2].1),
+            Some(EscrowEvent::LateRefunded(LateRefunded {
+                job_id,
+                days_late: 3,
+                amount: U256::from(150),
+            }))
+        );
+        assert!(contract.verify_solvency());
+
+        // Undelivered work keeps accruing lateness until it is claimed
+        send_value(&vm, U256::from(1_000));
+        let delivery_by = delivery_by + 31 * day;
+        let job_id = contract.deposit_with_sla(freelancer, 2 * day, delivery_by, 1_000).unwrap();
+        vm.set_block_timestamp(delivery_by + 5 * day);
+        vm.set_sender(freelancer);
+        let balances = Balances::track(&vm, &[client, freelancer]);
+        assert!(contract.auto_release(job_id, B256::ZERO).is_ok());
+        balances.assert_deltas(&[(client, 500), (freelancer, 500)]);
+        assert!(contract.verify_solvency());
+    }
+
+    #[test]
+    fn test_hourly() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let client = vm.msg_sender();
+        let freelancer = Address::from([0x01; 20]);
+        let week = 7 * 86_400_u64;
+        let rate = U256::from(50);
+        let details = B256::from([0xaa; 32]);
+
+        assert!(contract.constructor(client).is_ok());
+        vm.set_block_timestamp(1_000);
+        send_value(&vm, U256::from(1_000));
+        assert_eq!(
+            contract.deposit_hourly(freelancer, 4 * week, U256::ZERO).unwrap_err(),
+            revert(ErrorCode::InvalidRate)
+        );
+        let job_id = contract.deposit_hourly(freelancer, 4 * week, rate).unwrap();
+        assert_eq!(contract.get_engagement(job_id), (rate, U256::ZERO));
+        assert_eq!(contract.release(job_id, B256::ZERO).unwrap_err(), revert(ErrorCode::JobIsHourly));
+        assert_eq!(contract.refund(job_id, B256::ZERO).unwrap_err(), revert(ErrorCode::JobIsHourly));
+
+        // The freelancer bills each week, within the budget
+        vm.set_sender(freelancer);
+        assert_eq!(contract.submit_timesheet(job_id, 8, details).unwrap(), U256::ZERO);
+        assert_eq!(contract.submit_timesheet(job_id, 6, details).unwrap(), U256::from(1));
+        assert_eq!(
+            contract.submit_timesheet(job_id, 7, details).unwrap_err(),
+            revert(ErrorCode::ExceedsBudget)
+        );
+        assert_eq!(contract.get_engagement(job_id), (rate, U256::from(700)));
+
+        // Approving pays the period out of the budget; rejecting frees it
+        vm.set_sender(client);
+        let balances = Balances::track(&vm, &[client, freelancer]);
+        assert!(contract.approve_timesheet(job_id, U256::ZERO).is_ok());
+        balances.assert_deltas(&[(freelancer, 400)]);
+        let logs = vm.get_emitted_logs();
+        assert_eq!(
+            EscrowEvent::decode(&logs.last().unwrap().0, &logs.last().unwrap().1),
+            Some(EscrowEvent::TimesheetApproved(TimesheetApproved {
+                job_id,
+                index: U256::ZERO,
+                amount: U256::from(400),
+                remaining: U256::from(600),
+            }))
+        );
+        assert_eq!(
+            contract.approve_timesheet(job_id, U256::ZERO).unwrap_err(),
+            revert(ErrorCode::TimesheetAlreadySettled)
+        );
+        assert!(contract.reject_timesheet(job_id, U256::from(1)).is_ok());
+        assert_eq!(
+            contract.get_timesheets(job_id),
+            (
+                vec![8, 6],
+                vec![details, details],
+                vec![TIMESHEET_APPROVED, TIMESHEET_REJECTED]
+            )
+        );
+        assert!(contract.verify_solvency());
+
+        // At the end of the engagement the unspent budget goes back
+        vm.set_sender(freelancer);
+        assert!(contract.submit_timesheet(job_id, 2, details).is_ok());
+        vm.set_block_timestamp(1_000 + 4 * week);
+        vm.set_sender(client);
+        assert_eq!(contract.end_engagement(job_id).unwrap_err(), revert(ErrorCode::TimesheetsPending));
+        assert!(contract.approve_timesheet(job_id, U256::from(2)).is_ok());
+        let balances = Balances::track(&vm, &[client, freelancer]);
+        assert!(contract.end_engagement(job_id).is_ok());
+        balances.assert_deltas(&[(client, 500)]);
+        let (_, _, _, _, _, released, refunded) = contract.get_job(job_id).unwrap();
+        assert_eq!((released, refunded), (false, true));
+        assert!(contract.verify_solvency());
+    }
+
+    #[test]
+    fn test_refund() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let client = vm.msg_sender();
+        let freelancer = Address::from([0x01; 20]);
+        let amount = U256::from(1_000_000_000_000_000_000_u64); // 1 ETH
+        let duration = 86_400_u64; // 1 day
+        let escrow = vm.contract_address();
+
+        // Initialize and deposit
+        assert!(contract.constructor(vm.msg_sender()).is_ok());
+        send_value(&vm, amount);
+        let job_id = contract.deposit(freelancer, duration, B256::ZERO).unwrap();
+
+        // Test successful refund
+        let balances = Balances::track(&vm, &[client, freelancer, escrow]);
+        assert!(contract.refund(job_id, B256::ZERO).is_ok());
+        balances.assert_deltas(&[
+            (client, 1_000_000_000_000_000_000),
+            (escrow, -1_000_000_000_000_000_000),
+        ]);
+        let (id, _, _, _, _, released, refunded) = contract.get_job(job_id).unwrap();
+        assert_eq!(id, job_id);
+        assert_eq!(released, false);
+        assert_eq!(refunded, true);
+        assert_eq!(contract.finalized.get(job_id), true);
+        assert!(contract.get_active_jobs().is_empty());
+
+        // Verify Refunded event
+        let logs = vm.get_emitted_logs();
+        assert_eq!(logs.len(), 2); // Deposited + Refunded
+        assert_eq!(
+            EscrowEvent::decode(&logs[1].0, &logs[1].1),
+            Some(EscrowEvent::Refunded(Refunded { job_id, amount, memo: B256::ZERO }))
+        );
+
+        // Test refund by non-client
+        send_value(&vm, amount);
+        let job_id2 = contract.deposit(freelancer, duration, B256::ZERO).unwrap();
+        vm.set_sender(Address::from([0x02; 20]));
+        assert_eq!(
+            contract.refund(job_id2, B256::ZERO).unwrap_err(),
+            revert(ErrorCode::OnlyClientCanRefund)
+        );
+
+        // Test refund after deadline
+        vm.set_sender(client);
+        send_value(&vm, amount);
+        let job_id3 = contract.deposit(freelancer, duration, B256::ZERO).unwrap();
+        vm.set_block_timestamp(vm.block_timestamp() + duration + 1);
+        assert_eq!(
+            contract.refund(job_id3, B256::ZERO).unwrap_err(),
+            revert(ErrorCode::DeadlinePassed)
+        );
+    }
+
+    #[test]
+    fn test_auto_release() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let freelancer = Address::from([0x01; 20]);
+        let amount = U256::from(1_000_000_000_000_000_000_u64); // 1 ETH
+        let duration = 86_400_u64; // 1 day
+
+        // Initialize and deposit
+        assert!(contract.constructor(vm.msg_sender()).is_ok());
+        send_value(&vm, amount);
+        let job_id = contract.deposit(freelancer, duration, B256::ZERO).unwrap();
+
+        // Test auto-release before deadline
+        vm.set_sender(freelancer);
+        assert_eq!(
+            contract.auto_release(job_id, B256::ZERO).unwrap_err(),
+            revert(ErrorCode::DeadlineNotReached)
+        );
+
+        // Test auto-release after deadline, tagged with a memo
+        vm.set_block_timestamp(vm.block_timestamp() + duration + 1);
+        let balances = Balances::track(&vm, &[freelancer, vm.contract_address()]);
+        let memo = B256::right_padding_from(b"INV-0042");
+        assert!(contract.auto_release(job_id, memo).is_ok());
+        balances.assert_deltas(&[
+            (freelancer, 1_000_000_000_000_000_000),
+            (vm.contract_address(), -1_000_000_000_000_000_000),
+        ]);
+        let (id, _, _, _, _, released, refunded) = contract.get_job(job_id).unwrap();
+        assert_eq!(id, job_id);
+        assert_eq!(released, true);
+        assert_eq!(refunded, false);
+        assert_eq!(contract.finalized.get(job_id), true);
+        assert!(contract.get_active_jobs().is_empty());
+
+        // Verify AutoReleased event
+        let logs = vm.get_emitted_logs();
+        assert_eq!(logs.len(), 2); // Deposited + AutoReleased
+        assert_eq!(
+            EscrowEvent::decode(&logs[1].0, &logs[1].1),
+            Some(EscrowEvent::AutoReleased(AutoReleased { job_id, amount, memo }))
+        );
+
+        // Test auto-release by non-freelancer
+        send_value(&vm, amount);
+        let job_id2 = contract.deposit(freelancer, duration, B256::ZERO).unwrap();
+        vm.set_sender(Address::from([0x02; 20]));
+        vm.set_block_timestamp(vm.block_timestamp() + duration + 1);
+        assert_eq!(
+            contract.auto_release(job_id2, B256::ZERO).unwrap_err(),
+            revert(ErrorCode::OnlyFreelancerCanClaim)
+        );
+    }
+
+    #[test]
+    fn test_transfer_failure() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let client = vm.msg_sender();
+        let freelancer = Address::from([0x01; 20]);
+        let escrow = vm.contract_address();
+        let amount = U256::from(1_000);
+
+        assert!(contract.constructor(client).is_ok());
+        send_value(&vm, amount);
+        let first = contract.deposit(freelancer, 86_400, B256::ZERO).unwrap();
+        send_value(&vm, amount);
+        let second = contract.deposit(freelancer, 86_400, B256::ZERO).unwrap();
+        send_value(&vm, amount);
+        let third = contract.deposit(freelancer, 86_400, B256::ZERO).unwrap();
+
+        // A payout the contract cannot make fails the settlement and moves
+        // nothing (TestVM keeps the storage writes a real revert would undo,
+        // so each failure uses its own job)
+        let balances = Balances::track(&vm, &[client, freelancer, escrow]);
+        let held = fail_transfers(&vm);
+        assert_eq!(contract.release(first, B256::ZERO).unwrap_err(), TRANSFER_FAILED.to_vec());
+        assert_eq!(contract.refund(second, B256::ZERO).unwrap_err(), TRANSFER_FAILED.to_vec());
+        vm.set_balance(escrow, held);
+        balances.assert_deltas(&[]);
+
+        // With funds back in place a settlement moves exactly the job amount
+        assert!(contract.release(third, B256::ZERO).is_ok());
+        balances.assert_deltas(&[(freelancer, 1_000), (escrow, -1_000)]);
+    }
+
+    #[test]
+    fn test_resolver() {
+        use crate::automation::{reclaimCall, releaseHoldbackCall};
+        use alloy_sol_types::SolCall;
+
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let client = vm.msg_sender();
+        let freelancer = Address::from([0x01; 20]);
+        let keeper = Address::from([0x0c; 20]);
+        let warranty = 7 * 86_400_u64;
+
+        assert!(contract.constructor(client).is_ok());
+        vm.set_block_timestamp(1_000);
+        send_value(&vm, U256::from(1_000));
+        let held = contract.deposit_with_holdback(freelancer, 86_400, 1_000, warranty).unwrap();
+        send_value(&vm, U256::from(1_000));
+        let unclaimed = contract.deposit(freelancer, 86_400, B256::ZERO).unwrap();
+        vm.set_value(U256::ZERO);
+        assert_eq!(contract.resolver(U256::ZERO), (false, Bytes(b"No settlement due".to_vec())));
+
+        // A holdback falls due once its warranty is over
+        assert!(contract.release(held, B256::ZERO).is_ok());
+        vm.set_block_timestamp(1_000 + warranty - 1);
+        assert!(!contract.resolver(U256::ZERO).0);
+        vm.set_block_timestamp(1_000 + warranty);
+        let payload = releaseHoldbackCall { job_id: held }.abi_encode();
+        assert_eq!(contract.resolver(U256::ZERO), (true, Bytes(payload)));
+
+        // Nothing is due while paused
+        assert!(contract.set_paused(true).is_ok());
+        assert_eq!(contract.resolver(U256::ZERO), (false, Bytes(b"Escrow is paused".to_vec())));
+        assert!(contract.set_paused(false).is_ok());
+
+        // Sending the payload settles the job, moving on to the next one due
+        vm.set_sender(keeper);
+        assert!(contract.release_holdback(held).is_ok());
+        assert!(!contract.resolver(U256::ZERO).0);
+        vm.set_block_timestamp(1_000 + 86_400 + CLAIM_WINDOW);
+        let payload = reclaimCall { job_id: unclaimed, memo: B256::ZERO }.abi_encode();
+        assert_eq!(contract.resolver(U256::ZERO), (true, Bytes(payload)));
+        // Only positions from the cursor on are checked
+        assert!(!contract.resolver(U256::from(1)).0);
+        assert!(contract.reclaim(unclaimed, B256::ZERO).is_ok());
+        assert!(!contract.resolver(U256::ZERO).0);
+    }
+
+    #[test]
+    fn test_reclaim_unclaimed() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let client = vm.msg_sender();
+        let freelancer = Address::from([0x01; 20]);
+        let stranger = Address::from([0x02; 20]);
+        let amount = U256::from(1_000_000_000_000_000_000_u64); // 1 ETH
+        let duration = 86_400_u64; // 1 day
+
+        assert!(contract.constructor(client).is_ok());
+        vm.set_balance(vm.contract_address(), amount * U256::from(2)); // Fund settlements
+        vm.set_value(amount);
+        let job_id = contract.deposit(freelancer, duration, B256::ZERO).unwrap();
+        let deadline = vm.block_timestamp() + duration;
+
+        // The freelancer keeps the claim window after the deadline
+        vm.set_sender(stranger);
+        vm.set_block_timestamp(deadline + CLAIM_WINDOW - 1);
+        assert_eq!(
+            contract.reclaim(job_id, B256::ZERO).unwrap_err(),
+            revert(ErrorCode::ClaimWindowOpen)
+        );
+
+        // After it anyone can send the funds back to the client
+        vm.set_block_timestamp(deadline + CLAIM_WINDOW);
+        let memo = B256::right_padding_from(b"unclaimed");
+        assert!(contract.reclaim(job_id, memo).is_ok());
+        let (_, _, _, _, _, released, refunded) = contract.get_job(job_id).unwrap();
+        assert!(!released);
+        assert!(refunded);
+        assert!(contract.get_active_jobs().is_empty());
+        let (_, _, refunded_total, ..) = contract.get_protocol_stats();
+        assert_eq!(refunded_total, amount);
+
+        let logs = vm.get_emitted_logs();
+        assert_eq!(
+            EscrowEvent::decode(&logs[logs.len() - 1].0, &logs[logs.len() - 1].1),
+            Some(EscrowEvent::Reclaimed(Reclaimed {
+                job_id,
+                caller: stranger,
+                amount,
+                memo,
+            }))
+        );
+
+        // The freelancer can no longer claim, nor can it be reclaimed twice
+        vm.set_sender(freelancer);
+        assert_eq!(
+            contract.auto_release(job_id, B256::ZERO).unwrap_err(),
+            revert(ErrorCode::JobAlreadySettled)
+        );
+        assert_eq!(
+            contract.reclaim(job_id, B256::ZERO).unwrap_err(),
+            revert(ErrorCode::JobAlreadySettled)
+        );
+
+        // A disputed job is left to its dispute, however long it runs
+        vm.set_sender(client);
+        vm.set_value(amount);
+        let disputed = contract.deposit(freelancer, duration, B256::ZERO).unwrap();
+        vm.set_value(U256::ZERO);
+        let memo = B256::right_padding_from(b"TICKET-7");
+        assert!(contract.open_dispute(disputed, memo).is_ok());
+        let logs = vm.get_emitted_logs();
+        assert_eq!(
+            EscrowEvent::decode(&logs[logs.len() - 1].0, &logs[logs.len() - 1].1),
+            Some(EscrowEvent::DisputeOpened(DisputeOpened {
+                job_id: disputed,
+                opened_by: client,
+                memo,
+            }))
+        );
+        let deadline = vm.block_timestamp() + duration;
+        vm.set_sender(stranger);
+        vm.set_block_timestamp(deadline + CLAIM_WINDOW);
+        assert_eq!(
+            contract.reclaim(disputed, B256::ZERO).unwrap_err(),
+            revert(ErrorCode::JobIsDisputed)
+        );
+        assert!(!contract.get_job(disputed).unwrap().6);
+    }
+
+    #[test]
+    fn test_return_funds() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let client = Address::from([0x03; 20]);
+        let freelancer = Address::from([0x01; 20]);
+        let amount = U256::from(1_000);
+
+        assert!(contract.constructor(vm.msg_sender()).is_ok());
+        vm.set_balance(vm.contract_address(), amount * U256::from(2));
+        vm.set_block_timestamp(1_000);
+        vm.set_sender(client);
+        vm.set_value(amount);
+        let job_id = contract.deposit(freelancer, 86_400, B256::ZERO).unwrap();
+
+        // Only the freelancer can give the job up
+        assert_eq!(
+            contract.return_funds(job_id, B256::ZERO).unwrap_err(),
+            revert(ErrorCode::OnlyFreelancerCanReturnFunds)
+        );
+        vm.set_sender(freelancer);
+        let memo = B256::right_padding_from(b"INV-0042");
+        assert!(contract.return_funds(job_id, memo).is_ok());
+        assert_eq!(vm.balance(client), amount);
+        let (_, _, _, _, _, released, refunded) = contract.get_job(job_id).unwrap();
+        assert!(!released);
+        assert!(refunded);
+        let logs = vm.get_emitted_logs();
+        assert_eq!(
+            EscrowEvent::decode(&logs[logs.len() - 1].0, &logs[logs.len() - 1].1),
+            Some(EscrowEvent::FundsReturned(FundsReturned { job_id, amount, memo }))
+        );
+        assert_eq!(
+            contract.return_funds(job_id, B256::ZERO).unwrap_err(),
+            revert(ErrorCode::JobAlreadySettled)
+        );
+
+        // A tranched job returns whatever has not been claimed yet
+        vm.set_sender(client);
+        let amounts = vec![U256::from(300), U256::from(700)];
+        let job_id = contract.deposit_tranched(freelancer, amounts, vec![1_000, 2_000]).unwrap();
+        vm.set_sender(freelancer);
+        assert!(contract.claim_tranche(job_id, U256::ZERO).is_ok());
+        assert!(contract.return_funds(job_id, B256::ZERO).is_ok());
+        assert_eq!(vm.balance(client), amount + U256::from(700));
+        let (_, _, settled) = contract.get_tranches(job_id);
+        assert_eq!(settled, vec![true, true]);
+        assert!(contract.get_active_jobs().is_empty());
+    }
+
+    #[test]
+    fn test_extend_deadline() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let client = Address::from([0x03; 20]);
+        let freelancer = Address::from([0x01; 20]);
+        let amount = U256::from(1_000);
+
+        assert!(contract.constructor(vm.msg_sender()).is_ok());
+        vm.set_balance(vm.contract_address(), amount);
+        vm.set_block_timestamp(1_000);
+        vm.set_sender(client);
+        vm.set_value(amount);
+        let job_id = contract.deposit(freelancer, 86_400, B256::ZERO).unwrap();
+        let deadline = 1_000 + 86_400_u64;
+
+        // Only the freelancer can give themselves more time
+        assert_eq!(
+            contract.extend_deadline(job_id, deadline + 3_600).unwrap_err(),
+            revert(ErrorCode::OnlyFreelancerCanExtend)
+        );
+        vm.set_sender(freelancer);
+        assert_eq!(
+            contract.extend_deadline(job_id, deadline).unwrap_err(),
+            revert(ErrorCode::DeadlineMustBeLater)
+        );
+        assert!(contract.extend_deadline(job_id, deadline + 3_600).is_ok());
+        let logs = vm.get_emitted_logs();
+        assert_eq!(
+            EscrowEvent::decode(&logs[logs.len() - 1].0, &logs[logs.len() - 1].1),
+            Some(EscrowEvent::JobAmended(JobAmended {
+                job_id,
+                field: JOB_FIELD_DEADLINE,
+                old_value: U256::from(deadline).into(),
+                new_value: U256::from(deadline + 3_600).into(),
+                actor: freelancer,
+            }))
+        );
+
+        // The old deadline no longer lets the job auto-release
+        vm.set_block_timestamp(deadline + 1);
+        assert_eq!(
+            contract.auto_release(job_id, B256::ZERO).unwrap_err(),
+            revert(ErrorCode::DeadlineNotReached)
+        );
+        vm.set_block_timestamp(deadline + 3_601);
+        assert!(contract.auto_release(job_id, B256::ZERO).is_ok());
+        assert_eq!(
+            contract.extend_deadline(job_id, deadline + 7_200).unwrap_err(),
+            revert(ErrorCode::JobAlreadySettled)
+        );
+    }
+
+    #[test]
+    fn test_transfer_client() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let client = Address::from([0x03; 20]);
+        let freelancer = Address::from([0x01; 20]);
+        let new_client = Address::from([0x04; 20]);
+        let amount = U256::from(1_000);
+
+        assert!(contract.constructor(vm.msg_sender()).is_ok());
+        vm.set_sender(client);
+        send_value(&vm, amount);
+        let job_id = contract.deposit(freelancer, 86_400, B256::ZERO).unwrap();
+
+        // Only the client offers the role, and not to a party of the job
+        vm.set_sender(new_client);
+        assert_eq!(
+            contract.transfer_client(job_id, new_client).unwrap_err(),
+            revert(ErrorCode::OnlyClientCanTransfer)
+        );
+        vm.set_sender(client);
+        assert_eq!(
+            contract.transfer_client(job_id, freelancer).unwrap_err(),
+            revert(ErrorCode::InvalidClientAddress)
+        );
+        assert!(contract.transfer_client(job_id, new_client).is_ok());
+        assert_eq!(contract.get_pending_client(job_id), new_client);
+        let logs = vm.get_emitted_logs();
+        assert_eq!(
+            EscrowEvent::decode(&logs[logs.len() - 1].0, &logs[logs.len() - 1].1),
+            Some(EscrowEvent::ClientTransferProposed(ClientTransferProposed {
+                job_id,
+                client,
+                new_client,
+            }))
+        );
+
+        // Nothing changes until the offered address accepts
+        vm.set_sender(freelancer);
+        assert_eq!(
+            contract.accept_client(job_id).unwrap_err(),
+            revert(ErrorCode::OnlyPendingClientCanAccept)
+        );
+        vm.set_sender(new_client);
+        assert!(contract.accept_client(job_id).is_ok());
+        assert_eq!(contract.get_pending_client(job_id), Address::ZERO);
+        assert_eq!(contract.get_job(job_id).unwrap().1, new_client);
+        let logs = vm.get_emitted_logs();
+        assert_eq!(
+            EscrowEvent::decode(&logs[logs.len() - 1].0, &logs[logs.len() - 1].1),
+            Some(EscrowEvent::JobAmended(JobAmended {
+                job_id,
+                field: JOB_FIELD_CLIENT,
+                old_value: client.into_word(),
+                new_value: new_client.into_word(),
+                actor: new_client,
+            }))
+        );
+        assert_eq!(
+            contract.accept_client(job_id).unwrap_err(),
+            revert(ErrorCode::OnlyPendingClientCanAccept)
+        );
+
+        // The new client controls the job and receives its refund
+        vm.set_sender(client);
+        assert_eq!(
+            contract.refund(job_id, B256::ZERO).unwrap_err(),
+            revert(ErrorCode::OnlyClientCanRefund)
+        );
+        vm.set_sender(new_client);
+        let balances = Balances::track(&vm, &[client, new_client]);
+        assert!(contract.refund(job_id, B256::ZERO).is_ok());
+        balances.assert_deltas(&[(client, 0), (new_client, 1_000)]);
+        assert_eq!(
+            contract.transfer_client(job_id, client).unwrap_err(),
+            revert(ErrorCode::JobAlreadySettled)
+        );
+    }
+
+    #[test]
+    fn test_reassign_freelancer() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let client = Address::from([0x03; 20]);
+        let freelancer = Address::from([0x01; 20]);
+        let new_freelancer = Address::from([0x04; 20]);
+        let amount = U256::from(1_000);
+
+        assert!(contract.constructor(vm.msg_sender()).is_ok());
+        vm.set_sender(client);
+        send_value(&vm, amount);
+        let job_id = contract.deposit_with_dual_approval(freelancer, 86_400).unwrap();
+        vm.set_sender(freelancer);
+        assert!(contract.approve_release(job_id).is_ok());
+
+        // Only the client reassigns, and only to a new outside address
+        assert_eq!(
+            contract.reassign_freelancer(job_id, new_freelancer).unwrap_err(),
+            revert(ErrorCode::OnlyClientCanReassign)
+        );
+        vm.set_sender(client);
+        assert_eq!(
+            contract.reassign_freelancer(job_id, client).unwrap_err(),
+            revert(ErrorCode::InvalidFreelancerAddress)
+        );
+        assert!(contract.reassign_freelancer(job_id, new_freelancer).is_ok());
+        assert_eq!(contract.get_job(job_id).unwrap().2, new_freelancer);
+        assert_eq!(contract.get_job_cap(freelancer), (0, 0));
+        assert_eq!(contract.get_job_cap(new_freelancer), (0, 1));
+        assert_eq!(contract.get_user_stats(freelancer).1, U256::ZERO);
+        assert_eq!(contract.get_dual_approval(job_id), (true, false, false));
+        let logs = vm.get_emitted_logs();
+        assert_eq!(
+            EscrowEvent::decode(&logs[logs.len() - 2].0, &logs[logs.len() - 2].1),
+            Some(EscrowEvent::JobAmended(JobAmended {
+                job_id,
+                field: JOB_FIELD_FREELANCER,
+                old_value: freelancer.into_word(),
+                new_value: new_freelancer.into_word(),
+                actor: client,
+            }))
+        );
+        assert_eq!(
+            EscrowEvent::decode(&logs[logs.len() - 1].0, &logs[logs.len() - 1].1),
+            Some(EscrowEvent::FreelancerChanged(FreelancerChanged {
+                job_id,
+                old_freelancer: freelancer,
+                new_freelancer,
+            }))
+        );
+
+        // The old freelancer is out; once the new one accepts, the job stays
+        vm.set_sender(freelancer);
+        assert_eq!(
+            contract.accept_job(job_id).unwrap_err(),
+            revert(ErrorCode::OnlyFreelancerCanAccept)
+        );
+        vm.set_sender(new_freelancer);
+        assert!(contract.accept_job(job_id).is_ok());
+        assert!(contract.is_job_accepted(job_id));
+        assert_eq!(
+            contract.accept_job(job_id).unwrap_err(),
+            revert(ErrorCode::JobAlreadyAccepted)
+        );
+        vm.set_sender(client);
+        assert_eq!(
+            contract.reassign_freelancer(job_id, freelancer).unwrap_err(),
+            revert(ErrorCode::JobAlreadyAccepted)
+        );
+
+        // Payment goes to the new freelancer
+        let balances = Balances::track(&vm, &[freelancer, new_freelancer]);
+        assert!(contract.approve_release(job_id).is_ok());
+        vm.set_sender(new_freelancer);
+        assert!(contract.approve_release(job_id).is_ok());
+        balances.assert_deltas(&[(freelancer, 0), (new_freelancer, 1_000)]);
+    }
+
+    #[test]
+    fn test_dual_approval() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let client = vm.msg_sender();
+        let freelancer = Address::from([0x01; 20]);
+        let stranger = Address::from([0x02; 20]);
+        let amount = U256::from(1_000);
+        let duration = 86_400_u64;
+
+        assert!(contract.constructor(client).is_ok());
+        vm.set_balance(vm.contract_address(), amount * U256::from(2));
+        vm.set_value(amount);
+        let plain = contract.deposit(freelancer, duration, B256::ZERO).unwrap();
+        let job_id = contract.deposit_with_dual_approval(freelancer, duration).unwrap();
+        assert_eq!(contract.get_dual_approval(job_id), (true, false, false));
+        assert_eq!(
+            contract.approve_release(plain).unwrap_err(),
+            revert(ErrorCode::DualApprovalNotRequired)
+        );
+
+        // Neither party can release alone, even after the deadline
+        assert_eq!(
+            contract.release(job_id, B256::ZERO).unwrap_err(),
+            revert(ErrorCode::DualApprovalRequired)
+        );
+        vm.set_sender(freelancer);
+        vm.set_block_timestamp(vm.block_timestamp() + duration);
+        assert_eq!(
+            contract.auto_release(job_id, B256::ZERO).unwrap_err(),
+            revert(ErrorCode::DualApprovalRequired)
+        );
+
+        vm.set_sender(stranger);
+        assert_eq!(
+            contract.approve_release(job_id).unwrap_err(),
+            revert(ErrorCode::OnlyJobPartiesCanApprove)
+        );
+
+        // The first approval is recorded, the second releases
+        vm.set_sender(freelancer);
+        assert!(contract.approve_release(job_id).is_ok());
+        assert_eq!(
+            contract.approve_release(job_id).unwrap_err(),
+            revert(ErrorCode::ReleaseAlreadyApproved)
+        );
+        assert_eq!(contract.get_dual_approval(job_id), (true, false, true));
+        assert_eq!(vm.balance(freelancer), U256::ZERO);
+
+        vm.set_sender(client);
+        assert!(contract.approve_release(job_id).is_ok());
+        assert_eq!(vm.balance(freelancer), amount);
+        let (_, _, _, _, _, released, refunded) = contract.get_job(job_id).unwrap();
+        assert!(released);
+        assert!(!refunded);
+
+        let logs = vm.get_emitted_logs();
+        let n = logs.len();
+        assert_eq!(
+            EscrowEvent::decode(&logs[n - 2].0, &logs[n - 2].1),
+            Some(EscrowEvent::ReleaseApproved(ReleaseApproved {
+                job_id,
+                approver: client,
+            }))
+        );
+        assert_eq!(
+            EscrowEvent::decode(&logs[n - 1].0, &logs[n - 1].1),
+            Some(EscrowEvent::Released(Released {
+                job_id,
+                amount,
+                memo: B256::ZERO,
+            }))
+        );
+        assert_eq!(
+            contract.approve_release(job_id).unwrap_err(),
+            revert(ErrorCode::JobAlreadySettled)
+        );
+    }
+
+    #[test]
+    fn test_admin_functions() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let admin = vm.msg_sender();
+        let new_admin = Address::from([0x01; 20]);
+        let client = Address::from([0x02; 20]);
+        let freelancer = Address::from([0x03; 20]);
+        let amount = U256::from(1_000_000_000_000_000_000_u64); // 1 ETH
+        let duration = 86_400_u64; // 1 day
+
+        // Initialize contract
+        assert!(contract.constructor(vm.msg_sender()).is_ok());
+        vm.set_balance(vm.contract_address(), amount * U256::from(3)); // Fund settlements
+
+        // Test set_paused by admin
+        assert!(contract.set_paused(true).is_ok());
+        assert_eq!(contract.is_paused(), true);
+        let logs = vm.get_emitted_logs();
+        assert_eq!(logs.len(), 1);
+        assert_eq!(
+            EscrowEvent::decode(&logs[0].0, &logs[0].1),
+            Some(EscrowEvent::PauseToggled(PauseToggled { paused: true }))
+        );
+
+        // Test set_paused by non-admin
+        vm.set_sender(Address::from([0x04; 20]));
+        assert_eq!(
+            contract.set_paused(false).unwrap_err(),
+            revert(ErrorCode::OnlyAdmin)
+        );
+
+        // Test transfer_ownership by admin
+        vm.set_sender(admin);
+        assert!(contract.transfer_ownership(new_admin).is_ok());
+        assert_eq!(contract.admin.get(), new_admin);
+        let logs = vm.get_emitted_logs();
+        assert_eq!(logs.len(), 2); // PauseToggled + OwnershipTransferred
+        assert_eq!(
+            EscrowEvent::decode(&logs[1].0, &logs[1].1),
+            Some(EscrowEvent::OwnershipTransferred(OwnershipTransferred {
+                old_admin: admin,
+                new_admin,
+            }))
+        );
+
+        // Test transfer_ownership to zero address
+        vm.set_sender(new_admin);
+        assert_eq!(
+            contract.transfer_ownership(Address::ZERO).unwrap_err(),
+            revert(ErrorCode::InvalidAdminAddress)
+        );
+
+        // Test emergency_refund of a job that was never created
+        assert_eq!(
+            contract.emergency_refund(U256::from(99)).unwrap_err(),
+            revert(ErrorCode::JobDoesNotExist)
+        );
+
+        // Test emergency_refund
+        assert!(contract.set_paused(false).is_ok());
+        vm.set_sender(client);
+        vm.set_value(amount);
+        let job_id = contract.deposit(freelancer, duration, B256::ZERO).unwrap();
+        vm.set_sender(new_admin);
+        assert_eq!(contract.emergency_refund(job_id).unwrap_err(), revert(ErrorCode::JobTooRecent));
+        let deadline = vm.block_timestamp() + duration;
+        vm.set_block_timestamp(deadline + EMERGENCY_REFUND_GRACE - 1);
+        assert_eq!(contract.emergency_refund(job_id).unwrap_err(), revert(ErrorCode::JobTooRecent));
+        vm.set_block_timestamp(deadline + EMERGENCY_REFUND_GRACE);
+        assert!(contract.emergency_refund(job_id).is_ok());
+        let (id, _, _, _, _, released, refunded) = contract.get_job(job_id).unwrap();
+        assert_eq!(id, job_id);
+        assert_eq!(released, false);
+        assert_eq!(refunded, true);
+        assert_eq!(contract.finalized.get(job_id), true);
+
+        // Verify EmergencyRefunded event
+        let logs = vm.get_emitted_logs();
+        assert_eq!(logs.len(), 5); // PauseToggled + OwnershipTransferred + PauseToggled + Deposited + EmergencyRefunded
+        assert_eq!(
+            EscrowEvent::decode(&logs[4].0, &logs[4].1),
+            Some(EscrowEvent::EmergencyRefunded(EmergencyRefunded { job_id, admin: new_admin }))
+        );
+    }
+
+    #[test]
+    fn test_configure() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let admin = vm.msg_sender();
+        let pauser = Address::from([0x01; 20]);
+        assert!(contract.constructor(admin).is_ok());
+
+        // Each setting decodes its tuple and runs its own setter
+        let data = (pauser,).abi_encode_params();
+        assert!(contract.configure(SETTING_PAUSER, data.into()).is_ok());
+        assert_eq!(setting::<(Address,)>(&contract, SETTING_PAUSER, Address::ZERO).0, pauser);
+        let data = (500_u16,).abi_encode_params();
+        assert!(contract.configure(SETTING_PROTOCOL_FEE, data.into()).is_ok());
+        assert_eq!(setting::<(u16,)>(&contract, SETTING_PROTOCOL_FEE, Address::ZERO).0, 500);
+
+        // Per-address settings read back the entry for the key asked about
+        let data = (pauser, true).abi_encode_params();
+        assert!(contract.configure(SETTING_FEE_EXEMPT, data.into()).is_ok());
+        assert_eq!(setting::<(Address, bool)>(&contract, SETTING_FEE_EXEMPT, pauser), (pauser, true));
+        assert_eq!(setting::<(Address, bool)>(&contract, SETTING_FEE_EXEMPT, admin), (admin, false));
+        let logs = vm.get_emitted_logs();
+        assert_eq!(
+            EscrowEvent::decode(&logs[0].0, &logs[0].1),
+            Some(EscrowEvent::PauserSet(PauserSet { pauser }))
+        );
+
+        // Unknown settings and malformed arguments are rejected
+        let data = (pauser,).abi_encode_params();
+        assert_eq!(
+            contract.configure(SETTING_STAKING_TOKEN + 1, data.into()).unwrap_err(),
+            revert(ErrorCode::InvalidSetting)
+        );
+        assert_eq!(
+            contract.get_setting(SETTING_STAKING_TOKEN + 1, Address::ZERO).unwrap_err(),
+            revert(ErrorCode::InvalidSetting)
+        );
+        assert_eq!(
+            contract.configure(SETTING_DORMANCY_POLICY, Bytes::from(vec![0; 20])).unwrap_err(),
+            revert(ErrorCode::InvalidSetting)
+        );
+
+        // The setter still checks its caller
+        vm.set_sender(pauser);
+        let data = (60_u64,).abi_encode_params();
+        assert_eq!(
+            contract.configure(SETTING_MAX_PAUSE, data.into()).unwrap_err(),
+            revert(ErrorCode::OnlyAdmin)
+        );
+    }
+
+    #[test]
+    fn test_emergency_refund_batch() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let admin = vm.msg_sender();
+        let client = Address::from([0x03; 20]);
+        let freelancer = Address::from([0x01; 20]);
+        let amount = U256::from(1_000);
+
+        assert!(contract.constructor(admin).is_ok());
+        vm.set_sender(client);
+        let mut jobs = vec![];
+        for _ in 0..3 {
+            send_value(&vm, amount);
+            jobs.push(contract.deposit(freelancer, 86_400, B256::ZERO).unwrap());
+        }
+        assert!(contract.release(jobs[1], B256::ZERO).is_ok());
+        vm.set_block_timestamp(86_400 + EMERGENCY_REFUND_GRACE);
+        send_value(&vm, amount);
+        let recent = contract.deposit(freelancer, 86_400, B256::ZERO).unwrap();
+
+        assert_eq!(
+            contract.emergency_refund_batch(vec![jobs[0]]).unwrap_err(),
+            revert(ErrorCode::OnlyAdmin)
+        );
+        vm.set_sender(admin);
+        assert_eq!(contract.emergency_refund_batch(vec![]).unwrap_err(), revert(ErrorCode::InvalidBatch));
+        assert_eq!(
+            contract
+                .emergency_refund_batch(vec![jobs[0]; MAX_REFUND_BATCH + 1])
+                .unwrap_err(),
+            revert(ErrorCode::InvalidBatch)
+        );
+
+        // Jobs that can't be refunded are skipped and reported
+        let batch = vec![jobs[0], jobs[1], U256::from(99), recent, jobs[2], jobs[0]];
+        let balances = Balances::track(&vm, &[client, freelancer]);
+        assert_eq!(
+            contract.emergency_refund_batch(batch.clone()),
+            Ok(vec![true, false, false, false, true, false])
+        );
+        balances.assert_deltas(&[(client, 2_000)]);
+        assert!(contract.get_job(jobs[0]).unwrap().6);
+        assert!(contract.get_job(jobs[2]).unwrap().6);
+        assert!(!contract.get_job(recent).unwrap().6);
+        let logs = vm.get_emitted_logs();
+        assert_eq!(
+            EscrowEvent::decode(&logs.last().unwrap().0, &logs.last().unwrap().1),
+            Some(EscrowEvent::EmergencyRefundBatch(EmergencyRefundBatch {
+                admin,
+                job_ids: batch,
+                refunded: vec![true, false, false, false, true, false],
+            }))
+        );
+
+        // A client that can't take the ETH is credited it, and the batch
+        // goes on
+        let refuser = Address::from([0x04; 20]);
+        vm.set_sender(refuser);
+        send_value(&vm, amount);
+        let stuck = contract.deposit(freelancer, 86_400, B256::ZERO).unwrap();
+        vm.set_balance(refuser, U256::MAX);
+        vm.set_block_timestamp(vm.block_timestamp() + EMERGENCY_REFUND_MIN_AGE);
+        vm.set_sender(admin);
+        assert_eq!(contract.emergency_refund_batch(vec![stuck, recent]), Ok(vec![true, true]));
+        assert_eq!(vm.balance(refuser), U256::MAX);
+        assert_eq!(contract.get_credit(refuser), amount);
+        assert!(contract.get_job(stuck).unwrap().6);
+        assert!(contract.verify_solvency());
+
+        // Guardians take over batches as well
+        assert!(contract.set_guardians(vec![admin], 1).is_ok());
+        assert_eq!(
+            contract.emergency_refund_batch(vec![recent]).unwrap_err(),
+            revert(ErrorCode::GuardianQuorumRequired)
+        );
+    }
+
+    #[test]
+    fn test_storage_direct_access() {
+        use storage_layout::Layout;
+        use stylus_sdk::storage::StorageType;
+
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let client = vm.msg_sender();
+        let freelancer = Address::from([0x01; 20]);
+        let amount = U256::from(1_000_000_000_000_000_000_u64); // 1 ETH
+        let duration = 86_400_u64; // 1 day
+        let layout = Layout::contract();
+        let read = |path: &str| {
+            let location = layout.locate(path).unwrap();
+            location.read(&vm.storage_load_bytes32(location.slot).0)
+        };
+
+        // Every struct fills as many words as the storage macro gives it
+        macro_rules! required_slots {
+            ($($name:ident),*) => {
+                [$((stringify!($name), <$name as StorageType>::REQUIRED_SLOTS)),*]
+            };
+        }
+        let required = required_slots!(
+            Escrow, Job, PayoutToken, PayoutAddress, JobPlatform, Tranche, Retainer,
+            Cancellation, Funding, Invoice, Holdback, Clawback, Sla, Engagement, Timesheet,
+            BasketToken, Withholding, UserStats, JobRatings, Reputation, FreelancerProfile,
+            ClientProfile, JobStake, CategoryStats, Juror, JuryVote, Jury, Appeal, DepositWindow,
+            PeriodStats, FeeTier, VolumeDiscount, Dispute, DualApproval, EmergencyProposal,
+            GovernanceProposal, Evidence
+        );
+        assert_eq!(layout.structs.len(), required.len());
+        for (name, slots) in required {
+            assert_eq!(layout.get(name).map(|s| s.slots), Some(slots), "{name}");
+        }
+
+        // Initialize contract
+        assert!(contract.constructor(vm.msg_sender()).is_ok());
+        vm.set_block_timestamp(1_000);
+
+        // Fields packed into one word
+        assert_eq!(read("admin"), client.to_vec());
+        assert_eq!(read("paused"), vec![0]);
+        assert!(contract.set_paused(true).is_ok());
+        assert_eq!(read("paused"), vec![1]);
+        assert_eq!(read("admin"), client.to_vec());
+        assert!(contract.set_paused(false).is_ok());
+
+        // Struct fields of a mapping entry
+        vm.set_value(amount);
+        let job_id = contract.deposit(freelancer, duration, B256::ZERO).unwrap();
+        assert_eq!(read(&format!("jobs[{job_id}].job_id")), job_id.to_be_bytes::<32>().to_vec());
+        assert_eq!(read(&format!("jobs[{job_id}].client")), client.to_vec());
+        assert_eq!(read(&format!("jobs[{job_id}].freelancer")), freelancer.to_vec());
+        assert_eq!(read(&format!("jobs[{job_id}].amount")), amount.to_be_bytes::<32>().to_vec());
+        assert_eq!(read(&format!("jobs[{job_id}].deadline")), (1_000 + duration).to_be_bytes().to_vec());
+        assert_eq!(read(&format!("jobs[{job_id}].released")), vec![0]);
+        assert_eq!(read(&format!("party_jobs[{client}][0]")), job_id.to_be_bytes::<32>().to_vec());
+
+        // Nested mappings
+        let job_id = contract.deposit_with_nonce(freelancer, duration, U256::from(7)).unwrap();
+        assert_eq!(read(&format!("nonce_jobs[{client}][7]")), job_id.to_be_bytes::<32>().to_vec());
+
+        // Vector length and elements
+        assert!(contract.set_fee_tiers(vec![U256::from(100), U256::from(1_000)], vec![300, 200]).is_ok());
+        assert_eq!(read("fee_tiers"), U256::from(2).to_be_bytes::<32>().to_vec());
+        assert_eq!(read("fee_tiers[1].min_amount"), U256::from(1_000).to_be_bytes::<32>().to_vec());
+        assert_eq!(read("fee_tiers[1].fee_bps"), 200_u16.to_be_bytes().to_vec());
+
+        // The last fields, past every earlier miscount
+        assert!(contract.set_appeal_policy(3_600, 1_000, U256::from(5)).is_ok());
+        assert_eq!(read("appeal_window"), 3_600_u64.to_be_bytes().to_vec());
+        assert_eq!(read("appeal_bond_bps"), 1_000_u16.to_be_bytes().to_vec());
+        assert_eq!(read("min_appeal_bond"), U256::from(5).to_be_bytes::<32>().to_vec());
+    }
+
+    #[test]
+    fn test_block_dependent_logic() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let client = vm.msg_sender();
+        let freelancer = Address::from([0x01; 20]);
+        let amount = U256::from(1_000_000_000_000_000_000_u64); // 1 ETH
+        let duration = 86_400_u64; // 1 day
+        let initial_timestamp = 1_234_567_890_u64;
+
+        // Initialize contract
+        assert!(contract.constructor(vm.msg_sender()).is_ok());
+        vm.set_balance(vm.contract_address(), amount * U256::from(3)); // Fund settlements
+
+        // Set initial block timestamp
+        vm.set_block_timestamp(initial_timestamp);
+
+        // Deposit a job
+        vm.set_value(amount);
+        let job_id = contract.deposit(freelancer, duration, B256::ZERO).unwrap();
+        let (_, _, _, _, deadline, _, _) = contract.get_job(job_id).unwrap();
+        assert_eq!(deadline, initial_timestamp + duration);
+
+        // Test refund before deadline
+        assert!(contract.refund(job_id, B256::ZERO).is_ok());
+
+        // Create another job
+        vm.set_value(amount);
+        let job_id2 = contract.deposit(freelancer, duration, B256::ZERO).unwrap();
+
+        // Test auto-release before deadline
+        vm.set_sender(freelancer);
+        assert_eq!(
+            contract.auto_release(job_id2, B256::ZERO).unwrap_err(),
+            revert(ErrorCode::DeadlineNotReached)
+        );
+
+        // Advance timestamp past deadline
+        vm.set_block_timestamp(initial_timestamp + duration + 1);
+
+        // Test refund after deadline
+        vm.set_sender(client);
+        assert_eq!(
+            contract.refund(job_id2, B256::ZERO).unwrap_err(),
+            revert(ErrorCode::DeadlinePassed)
+        );
+
+        // Test auto-release after deadline
+        vm.set_sender(freelancer);
+        assert!(contract.auto_release(job_id2, B256::ZERO).is_ok());
+    }
+
+    #[test]
+    fn test_protocol_and_user_stats() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let client = vm.msg_sender();
+        let freelancer = Address::from([0x01; 20]);
+        let amount = U256::from(1_000_000_000_000_000_000_u64); // 1 ETH
+        let duration = 86_400_u64; // 1 day
+
+        // Initialize contract and fund it so settlements can transfer ETH
+        assert!(contract.constructor(vm.msg_sender()).is_ok());
+        vm.set_balance(vm.contract_address(), amount * U256::from(2));
+
+        // Deposit two jobs
+        vm.set_value(amount);
+        let job_id = contract.deposit(freelancer, duration, B256::ZERO).unwrap();
+        let job_id2 = contract.deposit(freelancer, duration, B256::ZERO).unwrap();
+        assert_eq!(
+            contract.get_protocol_stats(),
+            (amount * U256::from(2), U256::ZERO, U256::ZERO, U256::ZERO, U256::ZERO, U256::ZERO)
+        );
+
+        // Release one, refund the other
+        assert!(contract.release(job_id, B256::ZERO).is_ok());
+        assert!(contract.refund(job_id2, B256::ZERO).is_ok());
+        assert_eq!(
+            contract.get_protocol_stats(),
+            (amount * U256::from(2), amount, amount, U256::ZERO, U256::ZERO, U256::ZERO)
+        );
+
+        // Client stats
+        assert_eq!(
+            contract.get_user_stats(client),
+            (U256::from(2), U256::ZERO, amount * U256::from(2), U256::ZERO, amount, U256::ZERO, U256::ZERO)
+        );
+
+        // Freelancer stats
+        assert_eq!(
+            contract.get_user_stats(freelancer),
+            (U256::ZERO, U256::from(2), U256::ZERO, amount, U256::ZERO, U256::ZERO, U256::ZERO)
+        );
+
+        // Unknown address has empty stats
+        assert_eq!(
+            contract.get_user_stats(Address::from([0x09; 20])),
+            (U256::ZERO, U256::ZERO, U256::ZERO, U256::ZERO, U256::ZERO, U256::ZERO, U256::ZERO)
+        );
+
+        // Fees count once charged, and disputes by what the job held when
+        // the dispute opened, however it later settles
+        assert!(contract.set_protocol_fee(500).is_ok());
+        send_value(&vm, amount);
+        let disputed = contract.deposit(freelancer, duration, B256::ZERO).unwrap();
+        send_value(&vm, amount);
+        let paid = contract.deposit(freelancer, duration, B256::ZERO).unwrap();
+        vm.set_value(U256::ZERO);
+        assert!(contract.open_dispute(disputed, B256::ZERO).is_ok());
+        assert!(contract.release(paid, B256::ZERO).is_ok());
+        let stats = contract.get_protocol_stats();
+        assert_eq!((stats.4, stats.5), (amount / U256::from(20), amount));
+    }
+
+    #[test]
+    fn test_escrowed_amounts() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let client = vm.msg_sender();
+        let freelancer = Address::from([0x01; 20]);
+        let other = Address::from([0x02; 20]);
+        let new_client = Address::from([0x04; 20]);
+        let terms = keccak256(b"Copy edits");
+        let salt = B256::repeat_byte(0x5a);
+        let escrowed = |contract: &Escrow, address| {
+            let stats = contract.get_user_stats(address);
+            (stats.5, stats.6)
+        };
+
+        // Each deposit counts for its client and freelancer
+        assert!(contract.constructor(client).is_ok());
+        send_value(&vm, U256::from(1_000));
+        let job_id = contract.deposit(freelancer, 86_400, B256::ZERO).unwrap();
+        send_value(&vm, U256::from(500));
+        let job_id2 = contract.deposit(freelancer, 86_400, B256::ZERO).unwrap();
+        assert_eq!(escrowed(&contract, client), (U256::from(1_500), U256::ZERO));
+        assert_eq!(escrowed(&contract, freelancer), (U256::ZERO, U256::from(1_500)));
+
+        // A private job counts for its freelancer from the reveal, less the
+        // excess refunded
+        send_value(&vm, U256::from(400));
+        let commitment = job_commitment(client, other, U256::from(300), terms, salt);
+        let job_id3 = contract.deposit_private(commitment, 86_400).unwrap();
+        assert_eq!(escrowed(&contract, client), (U256::from(1_900), U256::ZERO));
+        assert_eq!(escrowed(&contract, other), (U256::ZERO, U256::ZERO));
+        assert!(contract
+            .reveal_job(job_id3, other, U256::from(300), terms, salt)
+            .is_ok());
+        assert_eq!(escrowed(&contract, client), (U256::from(1_800), U256::ZERO));
+        assert_eq!(escrowed(&contract, other), (U256::ZERO, U256::from(300)));
+
+        // Payouts leave the totals, partial or whole
+        assert!(contract.release_partial(job_id, U256::from(300)).is_ok());
+        assert!(contract.refund(job_id3, B256::ZERO).is_ok());
+        assert_eq!(escrowed(&contract, client), (U256::from(1_200), U256::ZERO));
+        assert_eq!(escrowed(&contract, freelancer), (U256::ZERO, U256::from(1_200)));
+        assert_eq!(escrowed(&contract, other), (U256::ZERO, U256::ZERO));
+
+        // A new freelancer or client takes the job's whole amount over
+        assert!(contract.reassign_freelancer(job_id2, other).is_ok());
+        assert_eq!(escrowed(&contract, freelancer), (U256::ZERO, U256::from(700)));
+        assert_eq!(escrowed(&contract, other), (U256::ZERO, U256::from(500)));
+        assert!(contract.transfer_client(job_id2, new_client).is_ok());
+        vm.set_sender(new_client);
+        assert!(contract.accept_client(job_id2).is_ok());
+        assert_eq!(escrowed(&contract, client), (U256::from(700), U256::ZERO));
+        assert_eq!(escrowed(&contract, new_client), (U256::from(500), U256::ZERO));
+
+        // Settling everything empties them
+        assert!(contract.release(job_id2, B256::ZERO).is_ok());
+        vm.set_sender(client);
+        assert!(contract.release(job_id, B256::ZERO).is_ok());
+        for address in [client, freelancer, other, new_client] {
+            assert_eq!(escrowed(&contract, address), (U256::ZERO, U256::ZERO));
+        }
+    }
+
+    #[test]
+    fn test_freelancer_profile() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let client = vm.msg_sender();
+        let freelancer = Address::from([0x01; 20]);
+        let arbitrator = Address::from([0x03; 20]);
+
+        assert!(contract.constructor(client).is_ok());
+        assert!(contract.set_arbitration_policy(arbitrator, 86_400, 5_000).is_ok());
+        let profile = |contract: &Escrow| {
+            let reputation = contract.get_reputation(freelancer);
+            let earned = contract.get_user_stats(freelancer).3;
+            (earned, reputation.5, reputation.6, reputation.7)
+        };
+        assert_eq!(
+            profile(&contract),
+            (U256::ZERO, 0, 0, 0)
+        );
+
+        // Released jobs count with the time from deposit to settlement
+        vm.set_block_timestamp(1_000);
+        send_value(&vm, U256::from(1_000));
+        let fast = contract.deposit(freelancer, 86_400, B256::ZERO).unwrap();
+        send_value(&vm, U256::from(500));
+        let slow = contract.deposit(freelancer, 86_400, B256::ZERO).unwrap();
+        send_value(&vm, U256::from(200));
+        let refunded = contract.deposit(freelancer, 86_400, B256::ZERO).unwrap();
+        vm.set_block_timestamp(2_000);
+        assert!(contract.release(fast, B256::ZERO).is_ok());
+        assert!(contract.refund(refunded, B256::ZERO).is_ok());
+        assert_eq!(
+            profile(&contract),
+            (U256::from(1_000), 1, 1_000, 0)
+        );
+
+        // Disputes count when opened; a ruling with any share completes the job
+        vm.set_value(U256::ZERO);
+        assert!(contract.open_dispute(slow, B256::ZERO).is_ok());
+        assert_eq!(profile(&contract).3, 1);
+        vm.set_block_timestamp(4_000);
+        vm.set_sender(arbitrator);
+        assert!(contract.rule(slow, 4_000).is_ok());
+        assert_eq!(
+            profile(&contract),
+            (U256::from(1_200), 2, 2_000, 1)
+        );
+    }
+
+    #[test]
+    fn test_client_profile() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let client = vm.msg_sender();
+        let freelancer = Address::from([0x01; 20]);
+        let arbitrator = Address::from([0x03; 20]);
+
+        assert!(contract.constructor(client).is_ok());
+        assert!(contract.set_arbitration_policy(arbitrator, 86_400, 5_000).is_ok());
+        let profile = |contract: &Escrow, account| {
+            let reputation = contract.get_reputation(account);
+            let funded = contract.get_user_stats(account).2;
+            (funded, reputation.8, reputation.9, reputation.10)
+        };
+        assert_eq!(profile(&contract, client), (U256::ZERO, 0, 0, 0));
+
+        // The refund rate is the share of deposits that came back
+        send_value(&vm, U256::from(1_000));
+        let refunded = contract.deposit(freelancer, 86_400, B256::ZERO).unwrap();
+        send_value(&vm, U256::from(1_000));
+        let lost = contract.deposit(freelancer, 86_400, B256::ZERO).unwrap();
+        send_value(&vm, U256::from(2_000));
+        let open = contract.deposit(freelancer, 86_400, B256::ZERO).unwrap();
+        assert!(contract.refund(refunded, B256::ZERO).is_ok());
+        assert_eq!(profile(&contract, client), (U256::from(4_000), 2_500, 0, 0));
+
+        // Every dispute counts, losses only once an arbitrator rules; the
+        // client's share of a ruling is refunded like any other refund
+        vm.set_value(U256::ZERO);
+        vm.set_block_timestamp(10);
+        assert!(contract.open_dispute(lost, B256::ZERO).is_ok());
+        assert!(contract.open_dispute(open, B256::ZERO).is_ok());
+        vm.set_sender(arbitrator);
+        assert!(contract.rule(lost, 8_000).is_ok());
+        assert_eq!(profile(&contract, client), (U256::from(4_000), 3_000, 2, 5_000));
+        assert_eq!(profile(&contract, freelancer), (U256::ZERO, 0, 0, 0));
+    }
+
+    #[test]
+    fn test_job_ratings_and_score() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let client = Address::from([0x01; 20]);
+        let freelancer = Address::from([0x02; 20]);
+        let arbitrator = Address::from([0x03; 20]);
+        let amount = U256::from(1_000_000_000_000_000_000_u64); // 1 ETH
+
+        assert!(contract.constructor(vm.msg_sender()).is_ok());
+        assert!(contract.set_arbitration_policy(arbitrator, 100, 5_000).is_ok());
+        vm.set_balance(vm.contract_address(), amount * U256::from(2));
+        let ratings = |contract: &Escrow, account| {
+            let reputation = contract.get_reputation(account);
+            (reputation.0, reputation.1, reputation.2, reputation.3, reputation.4)
+        };
+        vm.set_sender(client);
+        vm.set_value(amount);
+        let job_id = contract.deposit(freelancer, 1_000, B256::ZERO).unwrap();
+        let disputed = contract.deposit(freelancer, 1_000, B256::ZERO).unwrap();
+        vm.set_value(U256::ZERO);
+
+        // Only a settled job can be rated
+        assert_eq!(contract.rate_job(job_id, 5), Err(revert(ErrorCode::JobNotSettled)));
+        assert!(contract.release(job_id, B256::ZERO).is_ok());
+        assert_eq!(contract.get_score(freelancer), 0);
+
+        // By each party, once, from 1 to 5 stars
+        vm.set_sender(arbitrator);
+        assert_eq!(
+            contract.rate_job(job_id, 5),
+            Err(revert(ErrorCode::OnlyJobPartiesCanRate))
+        );
+        vm.set_sender(client);
+        assert_eq!(contract.rate_job(job_id, 0), Err(revert(ErrorCode::InvalidRating)));
+        assert_eq!(
+            contract.rate_job(job_id, MAX_STARS + 1),
+            Err(revert(ErrorCode::InvalidRating))
+        );
+        assert!(contract.rate_job(job_id, 5).is_ok());
+        let logs = vm.get_emitted_logs();
+        let last = logs.last().unwrap();
+        assert_eq!(
+            EscrowEvent::decode(&last.0, &last.1),
+            Some(EscrowEvent::JobRated(JobRated {
+                job_id,
+                rater: client,
+                ratee: freelancer,
+                stars: 5,
+            }))
+        );
+        assert_eq!(contract.rate_job(job_id, 4), Err(revert(ErrorCode::AlreadyRated)));
+        vm.set_sender(freelancer);
+        assert!(contract.rate_job(job_id, 4).is_ok());
+        assert_eq!(contract.get_job_ratings(job_id), (5, 4));
+
+        // A perfect rating on 1 ETH scores half against the 1 ETH prior
+        assert_eq!(
+            ratings(&contract, freelancer),
+            (1, 5, 0, amount * U256::from(5), amount)
+        );
+        assert_eq!(contract.get_score(freelancer), 5_000);
+        assert_eq!(contract.get_score(client), 4_000);
+
+        // Weight halves over the half-life, pulling the score toward zero
+        vm.set_block_timestamp(REPUTATION_HALF_LIFE);
+        assert_eq!(
+            ratings(&contract, freelancer),
+            (1, 5, 0, amount * U256::from(5) / U256::from(2), amount / U256::from(2))
+        );
+        assert_eq!(contract.get_score(freelancer), 3_333);
+
+        // Losing a ruling counts as a zero-star rating of twice the job
+        assert!(contract.open_dispute(disputed, B256::ZERO).is_ok());
+        vm.set_sender(arbitrator);
+        assert!(contract.rule(disputed, 2_000).is_ok());
+        assert_eq!(
+            ratings(&contract, freelancer),
+            (
+                1,
+                5,
+                1,
+                amount * U256::from(5) / U256::from(2),
+                amount / U256::from(2) + amount * U256::from(DISPUTE_LOSS_WEIGHT)
+            )
+        );
+        assert_eq!(contract.get_score(freelancer), 1_428);
+        assert_eq!(contract.get_reputation(client).2, 0);
+
+        // Which still leaves the job to rate
+        vm.set_sender(freelancer);
+        assert!(contract.rate_job(disputed, 1).is_ok());
+        assert_eq!(contract.get_job_ratings(disputed), (0, 1));
+        assert_eq!(contract.get_reputation(client).0, 2);
+    }
+
+    #[test]
+    fn test_verify_solvency() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let freelancer = Address::from([0x01; 20]);
+        let amount = U256::from(1_000_000_000_000_000_000_u64); // 1 ETH
+        let duration = 86_400_u64; // 1 day
+
+        // Initialize contract
+        assert!(contract.constructor(vm.msg_sender()).is_ok());
+        assert!(contract.verify_solvency());
+
+        // TestVM does not credit msg.value, so the deposit is not yet covered
+        vm.set_value(amount);
+        let job_id = contract.deposit(freelancer, duration, B256::ZERO).unwrap();
+        assert!(!contract.verify_solvency());
+
+        // Once the contract holds the deposit it is solvent
+        vm.set_balance(vm.contract_address(), amount);
+        assert!(contract.verify_solvency());
+
+        // Settling pays out the balance and the liability together
+        assert!(contract.release(job_id, B256::ZERO).is_ok());
+        assert_eq!(vm.balance(vm.contract_address()), U256::ZERO);
+        assert!(contract.verify_solvency());
+    }
+
+    #[test]
+    fn test_sunset() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let admin = vm.msg_sender();
+        let client = Address::from([0x01; 20]);
+        let freelancer = Address::from([0x02; 20]);
+        let juror = Address::from([0x03; 20]);
+        let treasury = Address::from([0x04; 20]);
+        let amount = U256::from(1_000_000_000_000_000_000_u64); // 1 ETH
+        let duration = 86_400_u64; // 1 day
+
+        // Initialize contract with a protocol fee, and open a job and top up
+        // credit before the sunset
+        assert!(contract.constructor(admin).is_ok());
+        let data = (500_u16,).abi_encode_params();
+        assert!(contract.configure(SETTING_PROTOCOL_FEE, data.into()).is_ok());
+        vm.set_block_timestamp(1_000);
+        vm.set_sender(client);
+        send_value(&vm, amount);
+        let job_id = contract.deposit(freelancer, duration, B256::ZERO).unwrap();
+        send_value(&vm, U256::from(7));
+        assert!(contract.deposit_credit().is_ok());
+        vm.set_value(U256::ZERO);
+
+        // Only the admin can schedule a sunset, and not in the past
+        assert_eq!(
+            contract.begin_sunset(2_000).unwrap_err(),
+            revert(ErrorCode::OnlyAdmin)
+        );
+        vm.set_sender(admin);
+        assert_eq!(
+            contract.begin_sunset(999).unwrap_err(),
+            revert(ErrorCode::InvalidSunsetTime)
+        );
+        assert!(contract.begin_sunset(2_000).is_ok());
+        assert_eq!(contract.get_sunset(), (2_000, false));
+        let logs = vm.get_emitted_logs();
+        let (topics, data) = logs.last().unwrap();
+        assert_eq!(
+            EscrowEvent::decode(topics, data),
+            Some(EscrowEvent::SunsetBegun(SunsetBegun { timestamp: 2_000 }))
+        );
+
+        // The sunset cannot be moved once scheduled
+        assert_eq!(
+            contract.begin_sunset(3_000).unwrap_err(),
+            revert(ErrorCode::SunsetAlreadyStarted)
+        );
+
+        // Deposits, credit top-ups and invoices stay open until the sunset
+        // time, then close for good
+        vm.set_block_timestamp(2_000);
+        vm.set_sender(client);
+        assert_eq!(
+            contract.deposit(freelancer, duration, B256::ZERO).unwrap_err(),
+            revert(ErrorCode::EscrowIsSunset)
+        );
+        assert_eq!(contract.deposit_credit().unwrap_err(), revert(ErrorCode::EscrowIsSunset));
+        vm.set_sender(freelancer);
+        assert_eq!(
+            contract.create_invoice(client, amount, duration, B256::ZERO, 3_000).unwrap_err(),
+            revert(ErrorCode::EscrowIsSunset)
+        );
+
+        // Jurors can still join for the disputes left open
+        vm.set_sender(juror);
+        send_value(&vm, U256::from(100));
+        assert!(contract.join_jury().is_ok());
+        vm.set_value(U256::ZERO);
+
+        // Retirement waits for open jobs
+        vm.set_sender(admin);
+        assert_eq!(
+            contract.finalize_sunset().unwrap_err(),
+            revert(ErrorCode::JobsStillOpen)
+        );
+
+        // Existing jobs still settle normally
+        vm.set_sender(client);
+        assert!(contract.release(job_id, B256::ZERO).is_ok());
+        let fee = amount * U256::from(500) / U256::from(10_000);
+        assert_eq!(vm.balance(freelancer), amount - fee);
+
+        // Then each kind of leftover holds retirement up with its own error
+        // until whoever it belongs to takes it out
+        vm.set_sender(admin);
+        assert_eq!(
+            contract.finalize_sunset().unwrap_err(),
+            revert(ErrorCode::CreditOutstanding)
+        );
+        vm.set_sender(client);
+        assert!(contract.withdraw_credit().is_ok());
+        vm.set_sender(admin);
+        assert_eq!(
+            contract.finalize_sunset().unwrap_err(),
+            revert(ErrorCode::FeesOutstanding)
+        );
+        assert!(contract.withdraw_fees(treasury, fee).is_ok());
+        assert_eq!(
+            contract.finalize_sunset().unwrap_err(),
+            revert(ErrorCode::JurorStakesOutstanding)
+        );
+        vm.set_sender(juror);
+        assert!(contract.leave_jury().is_ok());
+
+        // Stray ETH is swept to the admin on retirement
+        vm.set_balance(vm.contract_address(), U256::from(5));
+        vm.set_sender(admin);
+        assert!(contract.finalize_sunset().is_ok());
+        assert_eq!(contract.get_sunset(), (2_000, true));
+        assert_eq!(vm.balance(admin), U256::from(5));
+        let logs = vm.get_emitted_logs();
+        let (topics, data) = logs.last().unwrap();
+        assert_eq!(
+            EscrowEvent::decode(topics, data),
+            Some(EscrowEvent::SunsetFinalized(SunsetFinalized { admin, swept: U256::from(5) }))
+        );
+        assert_eq!(
+            contract.finalize_sunset().unwrap_err(),
+            revert(ErrorCode::EscrowIsRetired)
+        );
+
+        // A retired escrow takes no more ETH, not even from jurors
+        vm.set_sender(client);
+        send_value(&vm, amount);
+        assert_eq!(contract.deposit_credit().unwrap_err(), revert(ErrorCode::EscrowIsRetired));
+        assert_eq!(
+            contract.deposit(freelancer, duration, B256::ZERO).unwrap_err(),
+            revert(ErrorCode::EscrowIsRetired)
+        );
+        vm.set_sender(juror);
+        assert_eq!(contract.join_jury().unwrap_err(), revert(ErrorCode::EscrowIsRetired));
+    }
+
+    #[test]
+    fn test_dormant_sweep() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let admin = vm.msg_sender();
+        let client = Address::from([0x01; 20]);
+        let freelancer = Address::from([0x02; 20]);
+        let recovery = Address::from([0x03; 20]);
+        let amount = U256::from(1_000_000_000_000_000_000_u64); // 1 ETH
+        let duration = 86_400_u64; // 1 day
+
+        // Initialize contract and open two jobs
+        assert!(contract.constructor(admin).is_ok());
+        vm.set_balance(vm.contract_address(), amount * U256::from(2));
+        vm.set_sender(client);
+        vm.set_value(amount);
+        let job_id = contract.deposit(freelancer, duration, B256::ZERO).unwrap();
+        let job_id2 = contract.deposit(freelancer, duration, B256::ZERO).unwrap();
+        let dormant_at = duration + MIN_DORMANCY_PERIOD;
+
+        // Nothing can be announced before a policy is set
+        vm.set_sender(admin);
+        assert_eq!(
+            contract.announce_dormant(job_id).unwrap_err(),
+            revert(ErrorCode::DormancyPolicyNotSet)
+        );
+
+        // The policy enforces a long minimum period and a real recovery address
+        assert_eq!(
+            contract.set_dormancy_policy(MIN_DORMANCY_PERIOD - 1, recovery).unwrap_err(),
+            revert(ErrorCode::DormancyPeriodTooShort)
+        );
+        assert_eq!(
+            contract.set_dormancy_policy(MIN_DORMANCY_PERIOD, Address::ZERO).unwrap_err(),
+            revert(ErrorCode::InvalidRecoveryAddress)
+        );
+        vm.set_sender(client);
+        assert_eq!(
+            contract.set_dormancy_policy(MIN_DORMANCY_PERIOD, recovery).unwrap_err(),
+            revert(ErrorCode::OnlyAdmin)
+        );
+        vm.set_sender(admin);
+        assert!(contract.set_dormancy_policy(MIN_DORMANCY_PERIOD, recovery).is_ok());
+        assert_eq!(setting::<(u64, Address)>(&contract, SETTING_DORMANCY_POLICY, Address::ZERO), (MIN_DORMANCY_PERIOD, recovery));
+
+        // Jobs only become dormant once the period past the deadline is over
+        vm.set_block_timestamp(dormant_at - 1);
+        assert_eq!(
+            contract.announce_dormant(job_id).unwrap_err(),
+            revert(ErrorCode::JobNotDormant)
+        );
+        assert_eq!(
+            contract.sweep_dormant(job_id).unwrap_err(),
+            revert(ErrorCode::DormancyNotAnnounced)
+        );
+
+        // Announce both jobs; each is announced once
+        vm.set_block_timestamp(dormant_at);
+        assert!(contract.announce_dormant(job_id).is_ok());
+        assert!(contract.announce_dormant(job_id2).is_ok());
+        let sweep_after = dormant_at + DORMANCY_NOTICE_PERIOD;
+        assert_eq!(contract.get_dormancy(job_id), (sweep_after, false));
+        let logs = vm.get_emitted_logs();
+        assert_eq!(
+            EscrowEvent::decode(&logs[3].0, &logs[3].1),
+            Some(EscrowEvent::DormancyAnnounced(DormancyAnnounced { job_id, sweep_after }))
+        );
+        assert_eq!(
+            contract.announce_dormant(job_id).unwrap_err(),
+            revert(ErrorCode::DormancyAlreadyAnnounced)
+        );
+
+        // The sweep waits out the notice period
+        assert_eq!(
+            contract.sweep_dormant(job_id).unwrap_err(),
+            revert(ErrorCode::NoticePeriodNotOver)
+        );
+
+        // The freelancer can still claim during the notice period
+        vm.set_sender(freelancer);
+        assert!(contract.auto_release(job_id2, B256::ZERO).is_ok());
+
+        // After the notice period the remaining job goes to the recovery address
+        vm.set_block_timestamp(sweep_after);
+        vm.set_sender(admin);
+        assert_eq!(
+            contract.sweep_dormant(job_id2).unwrap_err(),
+            revert(ErrorCode::JobAlreadySettled)
+        );
+        assert!(contract.sweep_dormant(job_id).is_ok());
+        assert_eq!(vm.balance(recovery), amount);
+        assert_eq!(contract.get_dormancy(job_id), (sweep_after, true));
+        assert_eq!(contract.get_active_jobs(), Vec::<U256>::new());
+        assert_eq!(
+            contract.get_protocol_stats(),
+            (amount * U256::from(2), amount, U256::ZERO, amount, U256::ZERO, U256::ZERO)
+        );
+        assert!(contract.verify_solvency());
+        let logs = vm.get_emitted_logs();
+        assert_eq!(
+            EscrowEvent::decode(&logs[6].0, &logs[6].1),
+            Some(EscrowEvent::DormantSwept(DormantSwept { job_id, recovery, amount }))
+        );
+
+        // A swept job cannot be settled again
+        vm.set_sender(client);
+        assert_eq!(
+            contract.release(job_id, B256::ZERO).unwrap_err(),
+            revert(ErrorCode::JobAlreadyFinalized)
+        );
+        vm.set_sender(admin);
+        assert_eq!(
+            contract.sweep_dormant(job_id).unwrap_err(),
+            revert(ErrorCode::JobAlreadyFinalized)
+        );
+    }
+
+    #[test]
+    fn test_treasury() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let admin = vm.msg_sender();
+        let client = Address::from([0x01; 20]);
+        let freelancer = Address::from([0x02; 20]);
+        let recovery = Address::from([0x03; 20]);
+        let treasury = Address::from([0x04; 20]);
+        let amount = U256::from(1_000);
+
+        assert!(contract.constructor(admin).is_ok());
+        vm.set_block_timestamp(1_000);
+
+        // Only the admin proposes, and the change waits out the delay
+        vm.set_sender(client);
+        assert_eq!(contract.propose_treasury(treasury).unwrap_err(), revert(ErrorCode::OnlyAdmin));
+        vm.set_sender(admin);
+        assert_eq!(
+            contract.propose_treasury(Address::ZERO).unwrap_err(),
+            revert(ErrorCode::InvalidTreasuryAddress)
+        );
+        assert_eq!(contract.apply_treasury().unwrap_err(), revert(ErrorCode::NoPendingTreasury));
+        assert!(contract.propose_treasury(treasury).is_ok());
+        let effective_at = 1_000 + TREASURY_DELAY;
+        assert_eq!(contract.get_treasury(), (Address::ZERO, treasury, effective_at));
+        vm.set_block_timestamp(effective_at - 1);
+        assert_eq!(contract.apply_treasury().unwrap_err(), revert(ErrorCode::TreasuryDelayNotOver));
+        vm.set_block_timestamp(effective_at);
+        vm.set_sender(client);
+        assert!(contract.apply_treasury().is_ok());
+        assert_eq!(contract.get_treasury(), (treasury, Address::ZERO, 0));
+        let logs = vm.get_emitted_logs();
+        assert_eq!(
+            EscrowEvent::decode(&logs[0].0, &logs[0].1),
+            Some(EscrowEvent::TreasuryProposed(TreasuryProposed { treasury, effective_at }))
+        );
+        assert_eq!(
+            EscrowEvent::decode(&logs[1].0, &logs[1].1),
+            Some(EscrowEvent::TreasurySet(TreasurySet { treasury }))
+        );
+
+        // Fees can only be withdrawn to the treasury
+        vm.set_sender(admin);
+        assert!(contract.set_protocol_fee(500).is_ok());
+        vm.set_sender(client);
+        send_value(&vm, amount);
+        let job_id = contract.deposit(freelancer, 100, B256::ZERO).unwrap();
+        assert!(contract.release(job_id, B256::ZERO).is_ok());
+        vm.set_sender(admin);
+        assert_eq!(
+            contract.withdraw_fees(admin, U256::from(50)).unwrap_err(),
+            revert(ErrorCode::FeesGoToTreasury)
+        );
+        assert!(contract.withdraw_fees(treasury, U256::from(50)).is_ok());
+        assert_eq!(vm.balance(treasury), U256::from(50));
+
+        // A dormant job is swept to the treasury, not the recovery address
+        assert!(contract.set_dormancy_policy(MIN_DORMANCY_PERIOD, recovery).is_ok());
+        vm.set_sender(client);
+        send_value(&vm, amount);
+        let job_id = contract.deposit(freelancer, 100, B256::ZERO).unwrap();
+        vm.set_sender(admin);
+        vm.set_block_timestamp(effective_at + 100 + MIN_DORMANCY_PERIOD);
+        assert!(contract.announce_dormant(job_id).is_ok());
+        vm.set_block_timestamp(vm.block_timestamp() + DORMANCY_NOTICE_PERIOD);
+        assert!(contract.sweep_dormant(job_id).is_ok());
+        assert_eq!(vm.balance(treasury), U256::from(1_050));
+        assert_eq!(vm.balance(recovery), U256::ZERO);
+        assert!(contract.verify_solvency());
+    }
+
+    #[test]
+    fn test_governance() {
+        use alloy_sol_types::SolCall;
+        use governance::getPastVotesCall;
+
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let admin = vm.msg_sender();
+        let council = [
+            Address::from([0x11; 20]),
+            Address::from([0x12; 20]),
+            Address::from([0x13; 20]),
+        ];
+        let outsider = Address::from([0x14; 20]);
+
+        // The admin hands the parameters over once, and loses them
+        assert!(contract.constructor(admin).is_ok());
+        assert_eq!(
+            contract
+                .set_governance(Address::from([0x20; 20]), council.to_vec(), U256::from(2))
+                .unwrap_err(),
+            revert(ErrorCode::InvalidGovernance)
+        );
+        assert_eq!(
+            contract.set_governance(Address::ZERO, council.to_vec(), U256::from(4)).unwrap_err(),
+            revert(ErrorCode::InvalidQuorum)
+        );
+        assert!(contract.set_governance(Address::ZERO, council.to_vec(), U256::from(2)).is_ok());
+        assert_eq!(
+            contract.set_governance(Address::ZERO, council.to_vec(), U256::from(1)).unwrap_err(),
+            revert(ErrorCode::GovernanceAlreadySet)
+        );
+        assert_eq!(contract.set_protocol_fee(100).unwrap_err(), revert(ErrorCode::GovernedParameter));
+        assert_eq!(
+            contract.set_arbitration_policy(outsider, 86_400, 5_000).unwrap_err(),
+            revert(ErrorCode::GovernedParameter)
+        );
+
+        // Council members propose and vote, one vote each
+        vm.set_block_timestamp(1_000);
+        vm.set_sender(outsider);
+        assert_eq!(
+            contract
+                .propose_governance(GOVERNANCE_ACTION_PROTOCOL_FEE, Address::ZERO, U256::from(250), Bytes(vec![]))
+                .unwrap_err(),
+            revert(ErrorCode::NoVotingPower)
+        );
+        vm.set_sender(council[0]);
+        let fee_proposal = contract
+            .propose_governance(GOVERNANCE_ACTION_PROTOCOL_FEE, Address::ZERO, U256::from(250), Bytes(vec![]))
+            .unwrap();
+        let voting_ends = 1_000 + GOVERNANCE_VOTING_PERIOD;
+        assert!(contract.vote_governance(fee_proposal, true).is_ok());
+        assert_eq!(
+            contract.vote_governance(fee_proposal, true).unwrap_err(),
+            revert(ErrorCode::AlreadyVoted)
+        );
+        vm.set_sender(council[2]);
+        assert!(contract.vote_governance(fee_proposal, false).is_ok());
+        assert_eq!(
+            contract.execute_governance(fee_proposal).unwrap_err(),
+            revert(ErrorCode::ProposalNotPassed)
+        );
+        vm.set_sender(council[1]);
+        assert!(contract.vote_governance(fee_proposal, true).is_ok());
+        let logs = vm.get_emitted_logs();
+        assert_eq!(
+            EscrowEvent::decode(&logs[logs.len() - 1].0, &logs[logs.len() - 1].1),
+            Some(EscrowEvent::GovernanceVoted(GovernanceVoted {
+                proposal_id: fee_proposal,
+                voter: council[1],
+                support: true,
+                weight: U256::from(1),
+            }))
+        );
+
+        // It runs only after voting and the timelock
+        assert_eq!(
+            contract.execute_governance(fee_proposal).unwrap_err(),
+            revert(ErrorCode::TimelockNotOver)
+        );
+        vm.set_block_timestamp(voting_ends);
+        assert_eq!(
+            contract.vote_governance(fee_proposal, true).unwrap_err(),
+            revert(ErrorCode::VotingClosed)
+        );
+        vm.set_block_timestamp(voting_ends + GOVERNANCE_TIMELOCK);
+        vm.set_sender(outsider);
+        assert!(contract.execute_governance(fee_proposal).is_ok());
+        assert_eq!(setting::<(u16,)>(&contract, SETTING_PROTOCOL_FEE, Address::ZERO).0, 250);
+        assert_eq!(
+            contract.get_governance_proposal(fee_proposal),
+            Ok((
+                GOVERNANCE_ACTION_PROTOCOL_FEE,
+                Address::ZERO,
+                U256::from(250),
+                council[0],
+                voting_ends,
+                U256::from(2),
+                U256::from(1),
+                true,
+                Bytes(vec![]),
+            ))
+        );
+        assert_eq!(
+            contract.execute_governance(fee_proposal).unwrap_err(),
+            revert(ErrorCode::ProposalAlreadyExecuted)
+        );
+
+        // Changing the council voids proposals made under the old one
+        vm.set_sender(council[0]);
+        let add = contract
+            .propose_governance(GOVERNANCE_ACTION_ADD_COUNCIL_MEMBER, outsider, U256::ZERO, Bytes(vec![]))
+            .unwrap();
+        let cap = contract
+            .propose_governance(GOVERNANCE_ACTION_DEFAULT_JOB_CAP, Address::ZERO, U256::from(3), Bytes(vec![]))
+            .unwrap();
+        assert!(contract.vote_governance(add, true).is_ok());
+        vm.set_sender(council[1]);
+        assert!(contract.vote_governance(add, true).is_ok());
+        vm.set_block_timestamp(voting_ends + GOVERNANCE_TIMELOCK + GOVERNANCE_VOTING_PERIOD * 2);
+        assert!(contract.execute_governance(add).is_ok());
+        assert_eq!(contract.get_governance().1, [council.to_vec(), vec![outsider]].concat());
+        assert_eq!(contract.execute_governance(cap).unwrap_err(), revert(ErrorCode::ProposalStale));
+
+        // With a token, voting power is the voter's delegated votes before
+        // the proposal's block
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let token = Address::from([0x20; 20]);
+        let holder = Address::from([0x21; 20]);
+        assert!(contract.constructor(admin).is_ok());
+        assert!(contract.set_governance(token, Vec::new(), U256::from(1_000)).is_ok());
+        vm.set_block_number(10);
+        let past_votes = |account| {
+            getPastVotesCall {
+                account,
+                timepoint: U256::from(9),
+            }
+            .abi_encode()
+        };
+        let votes = |amount: u64| Ok(getPastVotesCall::abi_encode_returns(&(U256::from(amount),)));
+        vm.mock_static_call(token, past_votes(holder), votes(1_500));
+        vm.set_sender(holder);
+        assert_eq!(
+            contract
+                .propose_governance(GOVERNANCE_ACTION_ADD_COUNCIL_MEMBER, holder, U256::ZERO, Bytes(vec![]))
+                .unwrap_err(),
+            revert(ErrorCode::InvalidAction)
+        );
+        let ruling = contract
+            .propose_governance(GOVERNANCE_ACTION_RULING_PERIOD, Address::ZERO, U256::from(86_400), Bytes(vec![]))
+            .unwrap();
+        vm.set_block_number(20);
+        assert!(contract.vote_governance(ruling, true).is_ok());
+        assert_eq!(contract.get_governance_proposal(ruling).unwrap().5, U256::from(1_500));
+
+        // Fee and registry settings change through proposals too, with their
+        // encoded arguments as the proposal's data
+        let tiers = (vec![U256::from(1_000)], vec![50u16]).abi_encode_params();
+        vm.set_sender(admin);
+        assert_eq!(contract.set_fee_tiers(vec![], vec![]).unwrap_err(), revert(ErrorCode::GovernedParameter));
+        assert_eq!(
+            contract.configure(SETTING_FEE_TIERS, Bytes(tiers.clone())).unwrap_err(),
+            revert(ErrorCode::GovernedParameter)
+        );
+        vm.set_sender(holder);
+        vm.set_block_number(10);
+        vm.set_block_timestamp(1_000);
+        let protocol_fee = U256::from(SETTING_PROTOCOL_FEE);
+        assert_eq!(
+            contract
+                .propose_governance(GOVERNANCE_ACTION_SETTING, Address::ZERO, protocol_fee, Bytes(tiers.clone()))
+                .unwrap_err(),
+            revert(ErrorCode::InvalidSetting)
+        );
+        let fee_tiers = U256::from(SETTING_FEE_TIERS);
+        let tiers_proposal = contract
+            .propose_governance(GOVERNANCE_ACTION_SETTING, Address::ZERO, fee_tiers, Bytes(tiers.clone()))
+            .unwrap();
+        assert!(contract.vote_governance(tiers_proposal, true).is_ok());
+        vm.set_block_timestamp(1_000 + GOVERNANCE_VOTING_PERIOD + GOVERNANCE_TIMELOCK);
+        assert!(contract.execute_governance(tiers_proposal).is_ok());
+        assert_eq!(
+            setting::<(Vec<U256>, Vec<u16>)>(&contract, SETTING_FEE_TIERS, Address::ZERO),
+            (vec![U256::from(1_000)], vec![50])
+        );
+        assert_eq!(contract.get_governance_proposal(tiers_proposal).unwrap().8, Bytes(tiers));
+
+        // So does the treasury, still waiting out its delay once executed
+        let treasury = Address::from([0x22; 20]);
+        vm.set_sender(admin);
+        assert_eq!(contract.propose_treasury(treasury).unwrap_err(), revert(ErrorCode::GovernedParameter));
+        vm.set_sender(holder);
+        let proposed_at = vm.block_timestamp();
+        let treasury_proposal = contract
+            .propose_governance(GOVERNANCE_ACTION_TREASURY, treasury, U256::ZERO, Bytes(vec![]))
+            .unwrap();
+        assert!(contract.vote_governance(treasury_proposal, true).is_ok());
+        let executed_at = proposed_at + GOVERNANCE_VOTING_PERIOD + GOVERNANCE_TIMELOCK;
+        vm.set_block_timestamp(executed_at);
+        assert!(contract.execute_governance(treasury_proposal).is_ok());
+        assert_eq!(contract.get_treasury(), (Address::ZERO, treasury, executed_at + TREASURY_DELAY));
+    }
+
+    #[test]
+    fn test_guardian_quorum() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let admin = vm.msg_sender();
+        let client = Address::from([0x01; 20]);
+        let freelancer = Address::from([0x02; 20]);
+        let guardians = vec![Address::from([0x11; 20]), Address::from([0x12; 20]), Address::from([0x13; 20])];
+        let amount = U256::from(1_000);
+
+        assert!(contract.constructor(admin).is_ok());
+        vm.set_block_timestamp(1_000);
+
+        // Only the admin appoints guardians, and the set must be sound
+        vm.set_sender(client);
+        assert_eq!(contract.set_guardians(guardians.clone(), 2).unwrap_err(), revert(ErrorCode::OnlyAdmin));
+        vm.set_sender(admin);
+        assert_eq!(contract.set_guardians(guardians.clone(), 4).unwrap_err(), revert(ErrorCode::InvalidThreshold));
+        assert_eq!(contract.set_guardians(guardians.clone(), 0).unwrap_err(), revert(ErrorCode::InvalidThreshold));
+        assert_eq!(
+            contract.set_guardians(vec![guardians[0], guardians[0]], 1).unwrap_err(),
+            revert(ErrorCode::InvalidGuardian)
+        );
+        assert!(contract.set_guardians(guardians.clone(), 2).is_ok());
+        assert_eq!(contract.get_guardians(), (guardians.clone(), 2));
+
+        // The admin can no longer act alone
+        vm.set_sender(client);
+        send_value(&vm, amount);
+        let job_id = contract.deposit(freelancer, 30 * 86_400, B256::ZERO).unwrap();
+        vm.set_sender(admin);
+        assert_eq!(contract.emergency_refund(job_id).unwrap_err(), revert(ErrorCode::GuardianQuorumRequired));
+        assert_eq!(contract.sweep_surplus().unwrap_err(), revert(ErrorCode::GuardianQuorumRequired));
+
+        // A refund runs on the second confirmation
+        assert_eq!(
+            contract.propose_emergency(EMERGENCY_ACTION_REFUND, job_id).unwrap_err(),
+            revert(ErrorCode::OnlyGuardian)
+        );
+        vm.set_sender(guardians[0]);
+        assert_eq!(contract.propose_emergency(3, job_id).unwrap_err(), revert(ErrorCode::InvalidAction));
+        let balances = Balances::track(&vm, &[client, vm.contract_address()]);
+        let now = 1_000 + EMERGENCY_REFUND_MIN_AGE;
+        vm.set_block_timestamp(now);
+        let proposal_id = contract.propose_emergency(EMERGENCY_ACTION_REFUND, job_id).unwrap();
+        let expires_at = now + EMERGENCY_PROPOSAL_TTL;
+        assert_eq!(
+            contract.get_emergency_proposal(proposal_id).unwrap(),
+            (EMERGENCY_ACTION_REFUND, job_id, guardians[0], expires_at, 1, false)
+        );
+        assert_eq!(contract.confirm_emergency(proposal_id).unwrap_err(), revert(ErrorCode::AlreadyConfirmed));
+        vm.set_sender(guardians[1]);
+        assert!(contract.confirm_emergency(proposal_id).is_ok());
+        balances.assert_deltas(&[(client, 1_000), (vm.contract_address(), -1_000)]);
+        assert!(contract.get_emergency_proposal(proposal_id).unwrap().5);
+        vm.set_sender(guardians[2]);
+        assert_eq!(
+            contract.confirm_emergency(proposal_id).unwrap_err(),
+            revert(ErrorCode::ProposalAlreadyExecuted)
+        );
+
+        // An admin pause lapses unless the guardians extend it in time
+        vm.set_sender(admin);
+        assert!(contract.set_paused(true).is_ok());
+        let pause_until = now + MAX_ADMIN_PAUSE;
+        assert_eq!(contract.get_pause_until(), pause_until);
+        let extended = U256::from(pause_until + MAX_ADMIN_PAUSE);
+        vm.set_sender(guardians[0]);
+        let proposal_id = contract.propose_emergency(EMERGENCY_ACTION_EXTEND_PAUSE, extended).unwrap();
+        vm.set_block_timestamp(expires_at);
+        vm.set_sender(guardians[1]);
+        assert_eq!(contract.confirm_emergency(proposal_id).unwrap_err(), revert(ErrorCode::ProposalExpired));
+        let proposal_id = contract.propose_emergency(EMERGENCY_ACTION_EXTEND_PAUSE, extended).unwrap();
+        vm.set_sender(guardians[2]);
+        assert!(contract.confirm_emergency(proposal_id).is_ok());
+        vm.set_block_timestamp(pause_until);
+        assert!(contract.is_paused());
+        vm.set_block_timestamp(pause_until + MAX_ADMIN_PAUSE);
+        assert!(!contract.is_paused());
+
+        // Replacing the guardians voids open proposals
+        let proposal_id = contract.propose_emergency(EMERGENCY_ACTION_SWEEP_SURPLUS, U256::ZERO).unwrap();
+        vm.set_sender(admin);
+        assert!(contract.set_guardians(guardians.clone(), 2).is_ok());
+        vm.set_sender(guardians[0]);
+        assert_eq!(contract.confirm_emergency(proposal_id).unwrap_err(), revert(ErrorCode::ProposalStale));
+
+        // Without guardians the admin sweeps surplus alone
+        vm.set_sender(admin);
+        assert!(contract.set_guardians(Vec::new(), 0).is_ok());
+        assert_eq!(contract.sweep_surplus().unwrap_err(), revert(ErrorCode::NoSurplus));
+        let escrow = vm.contract_address();
+        vm.set_balance(escrow, vm.balance(escrow) + U256::from(500));
+        let balances = Balances::track(&vm, &[admin, escrow]);
+        assert!(contract.sweep_surplus().is_ok());
+        balances.assert_deltas(&[(admin, 500), (escrow, -500)]);
+        assert!(contract.verify_solvency());
+    }
+
+    #[test]
+    fn test_pauser() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let admin = vm.msg_sender();
+        let pauser = Address::from([0x0b; 20]);
+        let freelancer = Address::from([0x02; 20]);
+
+        assert!(contract.constructor(admin).is_ok());
+
+        // Only the admin appoints the pauser
+        vm.set_sender(pauser);
+        assert_eq!(contract.set_pauser(pauser).unwrap_err(), revert(ErrorCode::OnlyAdmin));
+        assert_eq!(contract.set_paused(true).unwrap_err(), revert(ErrorCode::OnlyAdmin));
+        vm.set_sender(admin);
+        assert!(contract.set_pauser(pauser).is_ok());
+        assert_eq!(setting::<(Address,)>(&contract, SETTING_PAUSER, Address::ZERO).0, pauser);
+
+        // The pauser halts the escrow but holds no other power
+        vm.set_sender(pauser);
+        assert!(contract.set_paused(true).is_ok());
+        assert!(contract.is_paused());
+        send_value(&vm, U256::from(1_000));
+        assert_eq!(contract.deposit(freelancer, 86_400, B256::ZERO).unwrap_err(), revert(ErrorCode::EscrowIsPaused));
+        assert_eq!(contract.set_paused(false).unwrap_err(), revert(ErrorCode::OnlyAdmin));
+        assert_eq!(contract.transfer_ownership(pauser).unwrap_err(), revert(ErrorCode::OnlyAdmin));
+        assert_eq!(contract.emergency_refund(U256::from(1)).unwrap_err(), revert(ErrorCode::OnlyAdmin));
+
+        // Only the admin lifts the pause, and can remove the pauser
+        vm.set_sender(admin);
+        assert!(contract.set_paused(false).is_ok());
+        assert!(!contract.is_paused());
+        assert!(contract.set_pauser(Address::ZERO).is_ok());
+        vm.set_sender(pauser);
+        assert_eq!(contract.set_paused(true).unwrap_err(), revert(ErrorCode::OnlyAdmin));
+    }
+
+    #[test]
+    fn test_max_pause() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let admin = vm.msg_sender();
+        let stranger = Address::from([0x0c; 20]);
+        let max_pause = 2 * 86_400;
+
+        assert!(contract.constructor(admin).is_ok());
+        vm.set_block_timestamp(1_000);
+
+        // Without a maximum a pause lasts until the admin lifts it
+        assert!(contract.set_paused(true).is_ok());
+        assert_eq!(contract.get_pause_until(), 0);
+        vm.set_sender(stranger);
+        assert_eq!(contract.unpause_expired().unwrap_err(), revert(ErrorCode::PauseNotExpired));
+        assert_eq!(contract.set_max_pause(max_pause).unwrap_err(), revert(ErrorCode::OnlyAdmin));
+        vm.set_sender(admin);
+        assert!(contract.set_paused(false).is_ok());
+
+        // With one, a new pause lapses and anyone can clear it
+        assert!(contract.set_max_pause(max_pause).is_ok());
+        assert_eq!(setting::<(u64,)>(&contract, SETTING_MAX_PAUSE, Address::ZERO).0, max_pause);
+        assert!(contract.set_paused(true).is_ok());
+        assert_eq!(contract.get_pause_until(), 1_000 + max_pause);
+        vm.set_sender(stranger);
+        vm.set_block_timestamp(1_000 + max_pause - 1);
+        assert!(contract.is_paused());
+        assert_eq!(contract.unpause_expired().unwrap_err(), revert(ErrorCode::PauseNotExpired));
+        vm.set_block_timestamp(1_000 + max_pause);
+        assert!(!contract.is_paused());
+        assert!(contract.unpause_expired().is_ok());
+        assert_eq!(contract.get_pause_until(), 0);
+        assert_eq!(contract.unpause_expired().unwrap_err(), revert(ErrorCode::PauseNotExpired));
+
+        // Guardians still cap a longer maximum at `MAX_ADMIN_PAUSE`
+        vm.set_sender(admin);
+        assert!(contract.set_max_pause(30 * 86_400).is_ok());
+        assert!(contract.set_guardians(vec![Address::from([0x11; 20])], 1).is_ok());
+        assert!(contract.set_paused(true).is_ok());
+        assert_eq!(contract.get_pause_until(), 1_000 + max_pause + MAX_ADMIN_PAUSE);
+    }
+
+    #[test]
+    fn test_renounce_ownership() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let admin = vm.msg_sender();
+        let manager = Address::from([0x0d; 20]);
+
+        assert!(contract.constructor(admin).is_ok());
+        vm.set_block_timestamp(1_000);
+
+        // Nothing the admin handed out may outlive them
+        assert!(contract.set_fee_manager(manager).is_ok());
+        assert_eq!(contract.propose_renounce().unwrap_err(), revert(ErrorCode::ParametersNotFinal));
+        assert!(contract.set_fee_manager(Address::ZERO).is_ok());
+        assert_eq!(contract.renounce_ownership().unwrap_err(), revert(ErrorCode::NoPendingRenounce));
+
+        // The role can only be dropped after the delay, with parameters still final
+        assert!(contract.propose_renounce().is_ok());
+        let effective_at = 1_000 + RENOUNCE_DELAY;
+        assert_eq!(contract.get_renounce_at(), effective_at);
+        assert_eq!(contract.renounce_ownership().unwrap_err(), revert(ErrorCode::RenounceDelayNotOver));
+        vm.set_block_timestamp(effective_at);
+        assert!(contract.set_paused(true).is_ok());
+        assert_eq!(contract.renounce_ownership().unwrap_err(), revert(ErrorCode::ParametersNotFinal));
+        assert!(contract.set_paused(false).is_ok());
+        assert!(contract.renounce_ownership().is_ok());
+        assert_eq!(contract.get_renounce_at(), 0);
+
+        // Every admin function is closed for good
+        assert_eq!(contract.set_paused(true).unwrap_err(), revert(ErrorCode::OnlyAdmin));
+        assert_eq!(contract.transfer_ownership(admin).unwrap_err(), revert(ErrorCode::OnlyAdmin));
+        assert_eq!(contract.set_default_job_cap(1).unwrap_err(), revert(ErrorCode::OnlyAdmin));
+    }
+
+    #[test]
+    fn test_private_job() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let client = vm.msg_sender();
+        let freelancer = Address::from([0x01; 20]);
+        let amount = U256::from(700_000_000_000_000_000_u64); // 0.7 ETH
+        let deposit = U256::from(1_000_000_000_000_000_000_u64); // 1 ETH
+        let duration = 86_400_u64; // 1 day
+        let terms = keccak256(b"Logo design, two revisions");
+        let salt = B256::repeat_byte(0x5a);
+        let commitment = job_commitment(client, freelancer, amount, terms, salt);
+
+        // Initialize and open a private job, padding the deposit past the rate
+        assert!(contract.constructor(vm.msg_sender()).is_ok());
+        vm.set_balance(vm.contract_address(), deposit);
+        vm.set_value(deposit);
+        assert_eq!(
+            contract.deposit_private(B256::ZERO, duration).unwrap_err(),
+            revert(ErrorCode::InvalidCommitment)
+        );
+        let job_id = contract.deposit_private(commitment, duration).unwrap();
+
+        // Only the commitment is stored; the freelancer stays hidden
+        let (_, job_client, job_freelancer, job_amount, _, _, _) =
+            contract.get_job(job_id).unwrap();
+        assert_eq!(job_client, client);
+        assert_eq!(job_freelancer, Address::ZERO);
+        assert_eq!(job_amount, deposit);
+        assert_eq!(contract.get_commitment(job_id), commitment);
+        let logs = vm.get_emitted_logs();
+        assert_eq!(
+            EscrowEvent::decode(&logs[0].0, &logs[0].1),
+            Some(EscrowEvent::PrivateDeposited(PrivateDeposited {
+                job_id,
+                client,
+                amount: deposit,
+                commitment,
+            }))
+        );
+
+        // Nothing can be paid out before the reveal
+        assert_eq!(
+            contract.release(job_id, B256::ZERO).unwrap_err(),
+            revert(ErrorCode::JobNotRevealed)
+        );
+
+        // The preimage must match the commitment and come from a party
+        assert_eq!(
+            contract.reveal_job(job_id, freelancer, deposit, terms, salt).unwrap_err(),
+            revert(ErrorCode::CommitmentMismatch)
+        );
+        vm.set_sender(Address::from([0x02; 20]));
+        assert_eq!(
+            contract.reveal_job(job_id, freelancer, amount, terms, salt).unwrap_err(),
+            revert(ErrorCode::OnlyJobPartiesCanReveal)
+        );
+
+        // The freelancer reveals; the padding goes back to the client
+        vm.set_sender(freelancer);
+        assert!(contract.reveal_job(job_id, freelancer, amount, terms, salt).is_ok());
+        assert_eq!(vm.balance(client), deposit - amount);
+        let (_, _, job_freelancer, job_amount, _, _, _) = contract.get_job(job_id).unwrap();
+        assert_eq!(job_freelancer, freelancer);
+        assert_eq!(job_amount, amount);
+        let logs = vm.get_emitted_logs();
+        assert_eq!(
+            EscrowEvent::decode(&logs[1].0, &logs[1].1),
+            Some(EscrowEvent::JobRevealed(JobRevealed { job_id, freelancer, amount, terms }))
+        );
+        assert_eq!(
+            contract.reveal_job(job_id, freelancer, amount, terms, salt).unwrap_err(),
+            revert(ErrorCode::JobAlreadyRevealed)
+        );
+        assert!(contract.verify_solvency());
+
+        // From here on it settles like any other job
+        vm.set_sender(client);
+        assert!(contract.release(job_id, B256::ZERO).is_ok());
+        assert_eq!(vm.balance(freelancer), amount);
+        assert_eq!(
+            contract.get_user_stats(freelancer),
+            (U256::ZERO, U256::from(1), U256::ZERO, amount, U256::ZERO, U256::ZERO, U256::ZERO)
+        );
+        assert_eq!(
+            contract.get_protocol_stats(),
+            (deposit, amount, deposit - amount, U256::ZERO, U256::ZERO, U256::ZERO)
+        );
+
+        // Public jobs have no commitment to reveal
+        vm.set_value(deposit);
+        let public_id = contract.deposit(freelancer, duration, B256::ZERO).unwrap();
+        assert_eq!(
+            contract.reveal_job(public_id, freelancer, amount, terms, salt).unwrap_err(),
+            revert(ErrorCode::JobIsNotPrivate)
+        );
+    }
+
+    #[test]
+    fn test_payout_swap() {
+        use alloy_sol_types::SolCall;
+        use swap::swapExactETHForTokensCall;
+
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let admin = vm.msg_sender();
+        let client = Address::from([0x01; 20]);
+        let freelancer = Address::from([0x02; 20]);
+        let router = Address::from([0x03; 20]);
+        let weth = Address::from([0x04; 20]);
+        let usdc = Address::from([0x05; 20]);
+        let amount = U256::from(1_000_000_000_000_000_000_u64); // 1 ETH
+        let rate = U256::from(2_000_000_000_u64); // 2,000 USDC per ETH
+
+        // Only the admin approves a router, and it needs WETH to route through
+        assert!(contract.constructor(admin).is_ok());
+        assert_eq!(
+            contract.set_swap_router(router, Address::ZERO).unwrap_err(),
+            revert(ErrorCode::InvalidWETHAddress)
+        );
+        vm.set_sender(client);
+        assert_eq!(
+            contract.set_swap_router(router, weth).unwrap_err(),
+            revert(ErrorCode::OnlyAdmin)
+        );
+        vm.set_sender(admin);
+        assert!(contract.set_swap_router(router, weth).is_ok());
+        assert_eq!(setting::<(Address, Address)>(&contract, SETTING_SWAP_ROUTER, Address::ZERO), (router, weth));
+
+        // The freelancer picks a token and must bound the slippage
+        vm.set_sender(freelancer);
+        assert_eq!(
+            contract.set_payout_token(usdc, U256::ZERO).unwrap_err(),
+            revert(ErrorCode::InvalidMinRate)
+        );
+        assert!(contract.set_payout_token(usdc, rate).is_ok());
+        assert_eq!(contract.get_payout_token(freelancer), (usdc, rate));
+
+        // Release swaps the payout through the router straight to the freelancer
+        vm.set_balance(vm.contract_address(), amount * U256::from(2));
+        vm.set_sender(client);
+        vm.set_value(amount);
+        let job_id = contract.deposit(freelancer, 86_400, B256::ZERO).unwrap();
+        let job_id2 = contract.deposit(freelancer, 86_400, B256::ZERO).unwrap();
+        let swap = swapExactETHForTokensCall {
+            amountOutMin: U256::from(2_000_000_000_u64),
+            path: vec![weth, usdc],
+            to: freelancer,
+            deadline: U256::from(vm.block_timestamp()),
+        }
+        .abi_encode();
+        let amount_out = U256::from(2_010_000_000_u64);
+        vm.mock_call(
+            router,
+            swap.clone(),
+            Ok(swapExactETHForTokensCall::abi_encode_returns(&(vec![amount, amount_out],))),
+        );
+        assert!(contract.release(job_id, B256::ZERO).is_ok());
+        assert_eq!(vm.balance(freelancer), U256::ZERO);
+        let logs = vm.get_emitted_logs();
+        let swapped = logs.iter().rev().nth(1).unwrap();
+        assert_eq!(
+            EscrowEvent::decode(&swapped.0, &swapped.1),
+            Some(EscrowEvent::PayoutSwapped(PayoutSwapped {
+                job_id,
+                token: usdc,
+                amount_in: amount,
+                amount_out,
+            }))
+        );
+
+        // A failed swap falls back to paying ETH
+        vm.mock_call(router, swap, Err(b"INSUFFICIENT_OUTPUT_AMOUNT".to_vec()));
+        assert!(contract.release(job_id2, B256::ZERO).is_ok());
+        assert_eq!(vm.balance(freelancer), amount);
+        let logs = vm.get_emitted_logs();
+        let failed = logs.iter().rev().nth(1).unwrap();
+        assert_eq!(
+            EscrowEvent::decode(&failed.0, &failed.1),
+            Some(EscrowEvent::PayoutSwapFailed(PayoutSwapFailed { job_id: job_id2, token: usdc }))
+        );
+    }
+
+    #[test]
+    fn test_wrapped_payout() {
+        use alloy_sol_types::SolCall;
+        use swap::depositToCall;
+
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let admin = vm.msg_sender();
+        let client = Address::from([0x01; 20]);
+        let freelancer = Address::from([0x02; 20]);
+        let weth = Address::from([0x04; 20]);
+        let amount = U256::from(1_000_000_000_000_000_000_u64); // 1 ETH
+
+        // WETH alone, without a router, is enough to pay wrapped
+        assert!(contract.constructor(admin).is_ok());
+        assert!(contract.set_swap_router(Address::ZERO, weth).is_ok());
+        vm.set_sender(freelancer);
+        assert!(contract.set_payout_token(weth, amount).is_ok());
+
+        // Release wraps the payout 1:1 straight into the freelancer's WETH
+        vm.set_balance(vm.contract_address(), amount * U256::from(3));
+        vm.set_sender(client);
+        vm.set_value(amount);
+        let job_id = contract.deposit(freelancer, 86_400, B256::ZERO).unwrap();
+        let job_id2 = contract.deposit(freelancer, 86_400, B256::ZERO).unwrap();
+        let job_id3 = contract.deposit(freelancer, 86_400, B256::ZERO).unwrap();
+        let wrap = depositToCall { account: freelancer }.abi_encode();
+        vm.mock_call(weth, wrap.clone(), Ok(Vec::new()));
+        assert!(contract.release(job_id, B256::ZERO).is_ok());
+        assert_eq!(vm.balance(freelancer), U256::ZERO);
+        let logs = vm.get_emitted_logs();
+        let wrapped = logs.iter().rev().nth(1).unwrap();
+        assert_eq!(
+            EscrowEvent::decode(&wrapped.0, &wrapped.1),
+            Some(EscrowEvent::PayoutSwapped(PayoutSwapped {
+                job_id,
+                token: weth,
+                amount_in: amount,
+                amount_out: amount,
+            }))
+        );
+
+        // If wrapping fails the freelancer is paid in ETH
+        vm.mock_call(weth, wrap, Err(Vec::new()));
+        assert!(contract.release(job_id2, B256::ZERO).is_ok());
+        assert_eq!(vm.balance(freelancer), amount);
+        let logs = vm.get_emitted_logs();
+        let failed = logs.iter().rev().nth(1).unwrap();
+        assert_eq!(
+            EscrowEvent::decode(&failed.0, &failed.1),
+            Some(EscrowEvent::PayoutSwapFailed(PayoutSwapFailed { job_id: job_id2, token: weth }))
+        );
+
+        // and a payout address that refuses both is credited instead of
+        // the release reverting
+        let vault = Address::from([0x05; 20]);
+        vm.set_sender(freelancer);
+        assert!(contract.propose_payout_address(vault).is_ok());
+        vm.set_block_timestamp(vm.block_timestamp() + PAYOUT_ADDRESS_DELAY);
+        assert!(contract.apply_payout_address(freelancer).is_ok());
+        vm.set_sender(client);
+        vm.set_code(vault, vec![0x60, 0x00]);
+        vm.mock_call(vault, Vec::new(), Err(Vec::new()));
+        vm.mock_call(weth, depositToCall { account: vault }.abi_encode(), Err(Vec::new()));
+        assert!(contract.release(job_id3, B256::ZERO).is_ok());
+        assert_eq!(contract.get_credit(vault), amount);
+        assert_eq!(vm.balance(vault), U256::ZERO);
+        let logs = vm.get_emitted_logs();
+        let failed = logs.iter().rev().nth(1).unwrap();
+        assert_eq!(
+            EscrowEvent::decode(&failed.0, &failed.1),
+            Some(EscrowEvent::PayoutSwapFailed(PayoutSwapFailed { job_id: job_id3, token: weth }))
+        );
+    }
+
+    #[test]
+    fn test_job_staking() {
+        use alloy_sol_types::SolCall;
+        use staking::{getPooledEthBySharesCall, submitCall, transferSharesCall};
+
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let admin = vm.msg_sender();
+        let client = Address::from([0x01; 20]);
+        let freelancer = Address::from([0x02; 20]);
+        let keeper = Address::from([0x03; 20]);
+        let lst = Address::from([0x04; 20]);
+        let amount = U256::from(1_000_000_000_000_000_000_u64); // 1 ETH
+        let shares = U256::from(900_000_000_000_000_000_u64);
+        let long = 200 * 86_400;
+
+        assert!(contract.constructor(admin).is_ok());
+        vm.set_block_timestamp(1_000);
+        vm.set_sender(client);
+        send_value(&vm, amount);
+        let job_id = contract.deposit(freelancer, long, B256::ZERO).unwrap();
+        send_value(&vm, amount);
+        let short_job = contract.deposit(freelancer, 86_400, B256::ZERO).unwrap();
+        assert_eq!(
+            contract.consent_to_stake(job_id, 3_000, 0).unwrap_err(),
+            revert(ErrorCode::StakingNotEnabled)
+        );
+
+        vm.set_sender(admin);
+        assert_eq!(
+            contract.set_staking_token(lst, 501).unwrap_err(),
+            revert(ErrorCode::InvalidSlippage)
+        );
+        assert!(contract.set_staking_token(lst, 100).is_ok());
+
+        // Only the parties of a job months from its deadline can consent
+        vm.set_sender(client);
+        assert_eq!(
+            contract.consent_to_stake(short_job, 3_000, 0).unwrap_err(),
+            revert(ErrorCode::StakeDurationTooShort)
+        );
+        assert_eq!(
+            contract.consent_to_stake(job_id, 9_000, 1_001).unwrap_err(),
+            revert(ErrorCode::InvalidYieldSplit)
+        );
+        vm.set_sender(keeper);
+        assert_eq!(
+            contract.consent_to_stake(job_id, 3_000, 0).unwrap_err(),
+            revert(ErrorCode::OnlyJobPartiesCanApprove)
+        );
+
+        // A different split from the other party replaces the first consent
+        vm.set_sender(client);
+        assert!(contract.consent_to_stake(job_id, 2_000, 0).is_ok());
+        vm.set_sender(freelancer);
+        assert!(contract.consent_to_stake(job_id, 3_000, 0).is_ok());
+        assert_eq!(
+            contract.get_job_stake(job_id),
+            (Address::ZERO, U256::ZERO, U256::ZERO, 3_000, 0, freelancer, 0)
+        );
+
+        // Agreeing on the split stakes the job's amount
+        vm.mock_call(
+            lst,
+            submitCall { referral: Address::ZERO }.abi_encode(),
+            Ok(submitCall::abi_encode_returns(&(shares,))),
+        );
+        vm.set_sender(client);
+        assert!(contract.consent_to_stake(job_id, 3_000, 0).is_ok());
+        assert_eq!(
+            contract.get_job_stake(job_id),
+            (lst, amount, shares, 3_000, 0, Address::ZERO, 100)
+        );
+        let escrow = vm.contract_address();
+        vm.set_balance(escrow, vm.balance(escrow) - amount);
+        assert!(contract.verify_solvency());
+
+        // A staked job can't settle or be disputed until it is unwound
+        assert_eq!(
+            contract.release(job_id, B256::ZERO).unwrap_err(),
+            revert(ErrorCode::JobIsStaked)
+        );
+        assert_eq!(
+            contract.open_dispute(job_id, B256::ZERO).unwrap_err(),
+            revert(ErrorCode::JobIsStaked)
+        );
+        assert_eq!(
+            contract.consent_to_stake(job_id, 3_000, 0).unwrap_err(),
+            revert(ErrorCode::JobIsStaked)
+        );
+
+        // Raising the slippage bound afterwards leaves the staked job's alone
+        vm.set_sender(admin);
+        assert!(contract.set_staking_token(lst, 500).is_ok());
+        assert_eq!(contract.get_job_stake(job_id).6, 100);
+
+        // Only the parties can unwind it until the deadline is close
+        let value = U256::from(1_100_000_000_000_000_000_u64);
+        vm.mock_static_call(
+            lst,
+            getPooledEthBySharesCall { shares }.abi_encode(),
+            Ok(getPooledEthBySharesCall::abi_encode_returns(&(value,))),
+        );
+        vm.mock_call(
+            lst,
+            transferSharesCall { recipient: keeper, shares }.abi_encode(),
+            Ok(transferSharesCall::abi_encode_returns(&(value,))),
+        );
+        vm.set_sender(keeper);
+        send_value(&vm, value);
+        assert_eq!(contract.unstake_job(job_id).unwrap_err(), revert(ErrorCode::UnstakeNotOpen));
+        vm.set_balance(escrow, vm.balance(escrow) - value);
+        vm.set_block_timestamp(1_000 + long - UNSTAKE_WINDOW);
+
+        // The shares sell for at least their value less 1%
+        send_value(&vm, U256::from(1_088_000_000_000_000_000_u64));
+        assert_eq!(contract.unstake_job(job_id).unwrap_err(), revert(ErrorCode::UnstakePriceTooLow));
+        let escrow_balance = vm.balance(escrow);
+        vm.set_balance(escrow, escrow_balance - U256::from(1_088_000_000_000_000_000_u64));
+        send_value(&vm, U256::from(1_090_000_000_000_000_000_u64));
+        assert!(contract.unstake_job(job_id).is_ok());
+        assert_eq!(contract.unstake_job(job_id).unwrap_err(), revert(ErrorCode::JobNotStaked));
+        vm.set_value(U256::ZERO);
+
+        // The yield above the principal is credited in the agreed split
+        assert_eq!(contract.get_credit(freelancer), U256::from(27_000_000_000_000_000_u64));
+        assert_eq!(contract.get_credit(client), U256::from(63_000_000_000_000_000_u64));
+        assert_eq!(contract.get_job_stake(job_id).1, U256::ZERO);
+        assert!(contract.verify_solvency());
+        vm.set_sender(client);
+        assert!(contract.release(job_id, B256::ZERO).is_ok());
+
+        // Not even a party can take the discount out of the principal
+        send_value(&vm, amount);
+        let job_id = contract.deposit(freelancer, long, B256::ZERO).unwrap();
+        assert!(contract.consent_to_stake(job_id, 0, 0).is_ok());
+        vm.set_sender(freelancer);
+        assert!(contract.consent_to_stake(job_id, 0, 0).is_ok());
+        assert_eq!(contract.get_job_stake(job_id).6, 500);
+        vm.set_balance(escrow, vm.balance(escrow) - amount);
+        let value = U256::from(1_020_000_000_000_000_000_u64);
+        vm.mock_static_call(
+            lst,
+            getPooledEthBySharesCall { shares }.abi_encode(),
+            Ok(getPooledEthBySharesCall::abi_encode_returns(&(value,))),
+        );
+        let below_principal = U256::from(969_000_000_000_000_000_u64);
+        send_value(&vm, below_principal);
+        assert_eq!(contract.unstake_job(job_id).unwrap_err(), revert(ErrorCode::UnstakePriceTooLow));
+        vm.set_balance(escrow, vm.balance(escrow) - below_principal);
+
+        // A shortfall only comes from the token losing value, and is taken
+        // off the job
+        let value = U256::from(950_000_000_000_000_000_u64);
+        vm.mock_static_call(
+            lst,
+            getPooledEthBySharesCall { shares }.abi_encode(),
+            Ok(getPooledEthBySharesCall::abi_encode_returns(&(value,))),
+        );
+        vm.mock_call(
+            lst,
+            transferSharesCall { recipient: freelancer, shares }.abi_encode(),
+            Ok(transferSharesCall::abi_encode_returns(&(value,))),
+        );
+        let discounted = U256::from(902_500_000_000_000_000_u64);
+        send_value(&vm, discounted);
+        assert_eq!(contract.unstake_job(job_id).unwrap_err(), revert(ErrorCode::UnstakePriceTooLow));
+        vm.set_balance(escrow, vm.balance(escrow) - discounted);
+        vm.set_block_timestamp(vm.block_timestamp() + long - UNSTAKE_WINDOW);
+        vm.set_sender(keeper);
+        send_value(&vm, value - U256::from(1));
+        assert_eq!(contract.unstake_job(job_id).unwrap_err(), revert(ErrorCode::UnstakePriceTooLow));
+        vm.set_balance(escrow, vm.balance(escrow) - value + U256::from(1));
+        let paid = value;
+        vm.set_sender(freelancer);
+        send_value(&vm, paid);
+        assert!(contract.unstake_job(job_id).is_ok());
+        vm.set_value(U256::ZERO);
+        assert_eq!(contract.get_job(job_id).unwrap().3, paid);
+        assert_eq!(contract.get_user_stats(client).5, paid + amount);
+        assert_eq!(contract.get_credit(client), U256::from(63_000_000_000_000_000_u64));
+        assert!(contract.verify_solvency());
+    }
+
+    #[test]
+    fn test_yield_split() {
+        use alloy_sol_types::SolCall;
+        use staking::{getPooledEthBySharesCall, submitCall, transferSharesCall};
+
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let admin = vm.msg_sender();
+        let client = Address::from([0x01; 20]);
+        let freelancer = Address::from([0x02; 20]);
+        let keeper = Address::from([0x03; 20]);
+        let lst = Address::from([0x04; 20]);
+        let amount = U256::from(1_000_000_000_000_000_000_u64); // 1 ETH
+        let shares = U256::from(900_000_000_000_000_000_u64);
+        let long = 200 * 86_400;
+
+        assert!(contract.constructor(admin).is_ok());
+        assert!(contract.set_staking_token(lst, 0).is_ok());
+        vm.set_block_timestamp(1_000);
+
+        // The freelancer's and treasury's shares can't exceed the whole yield
+        vm.set_sender(client);
+        send_value(&vm, amount);
+        let job_id = contract.deposit(freelancer, long, B256::ZERO).unwrap();
+        assert_eq!(
+            contract.consent_to_stake(job_id, 6_000, 4_001).unwrap_err(),
+            revert(ErrorCode::InvalidYieldSplit)
+        );
+
+        // The parties have to agree on the treasury's share too
+        assert!(contract.consent_to_stake(job_id, 3_000, 1_000).is_ok());
+        vm.set_sender(freelancer);
+        assert!(contract.consent_to_stake(job_id, 3_000, 0).is_ok());
+        vm.set_sender(client);
+        assert!(contract.consent_to_stake(job_id, 3_000, 1_000).is_ok());
+        assert_eq!(contract.get_job_stake(job_id).1, U256::ZERO);
+
+        // Agreeing stakes the job under the split, the client taking the rest
+        vm.mock_call(
+            lst,
+            submitCall { referral: Address::ZERO }.abi_encode(),
+            Ok(submitCall::abi_encode_returns(&(shares,))),
+        );
+        vm.set_sender(freelancer);
+        assert!(contract.consent_to_stake(job_id, 3_000, 1_000).is_ok());
+        let logs = vm.get_emitted_logs();
+        let (topics, data) = &logs[logs.len() - 2];
+        assert_eq!(
+            EscrowEvent::decode(topics, data),
+            Some(EscrowEvent::YieldSplitSet(YieldSplitSet {
+                job_id,
+                client_yield_bps: 6_000,
+                freelancer_yield_bps: 3_000,
+                treasury_yield_bps: 1_000,
+            }))
+        );
+        assert_eq!(
+            contract.get_job_stake(job_id),
+            (lst, amount, shares, 3_000, 1_000, Address::ZERO, 0)
+        );
+        let escrow = vm.contract_address();
+        vm.set_balance(escrow, vm.balance(escrow) - amount);
+
+        // Unwinding pays the yield out by the split, the treasury's share
+        // into the accrued fees
+        let value = U256::from(1_100_000_000_000_000_000_u64);
+        vm.mock_static_call(
+            lst,
+            getPooledEthBySharesCall { shares }.abi_encode(),
+            Ok(getPooledEthBySharesCall::abi_encode_returns(&(value,))),
+        );
+        vm.mock_call(
+            lst,
+            transferSharesCall { recipient: keeper, shares }.abi_encode(),
+            Ok(transferSharesCall::abi_encode_returns(&(value,))),
+        );
+        vm.set_block_timestamp(1_000 + long - UNSTAKE_WINDOW);
+        vm.set_sender(keeper);
+        send_value(&vm, value);
+        assert!(contract.unstake_job(job_id).is_ok());
+        vm.set_value(U256::ZERO);
+        let logs = vm.get_emitted_logs();
+        assert_eq!(
+            EscrowEvent::decode(&logs.last().unwrap().0, &logs.last().unwrap().1),
+            Some(EscrowEvent::JobUnstaked(JobUnstaked {
+                job_id,
+                buyer: keeper,
+                principal: amount,
+                paid: value,
+                client_yield: U256::from(60_000_000_000_000_000_u64),
+                freelancer_yield: U256::from(30_000_000_000_000_000_u64),
+                treasury_yield: U256::from(10_000_000_000_000_000_u64),
+            }))
+        );
+        assert_eq!(contract.get_credit(client), U256::from(60_000_000_000_000_000_u64));
+        assert_eq!(contract.get_credit(freelancer), U256::from(30_000_000_000_000_000_u64));
+        assert_eq!(contract.get_accrued_fees(), U256::from(10_000_000_000_000_000_u64));
+        assert!(contract.verify_solvency());
+    }
+
+    #[test]
+    fn test_basket() {
+        use alloy_sol_types::SolCall;
+        use baskets::{transferCall, transferFromCall};
+
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let admin = vm.msg_sender();
+        let client = Address::from([0x03; 20]);
+        let freelancer = Address::from([0x01; 20]);
+        let usdc = Address::from([0x02; 20]);
+        let dai = Address::from([0x04; 20]);
+        let amount = U256::from(1_000);
+        let usdc_amount = U256::from(2_000_000);
+        let ok = Ok(transferCall::abi_encode_returns(&(true,)));
+
+        // Only admin-approved tokens can go in a basket
+        assert!(contract.constructor(admin).is_ok());
+        vm.set_sender(client);
+        assert_eq!(
+            contract.set_basket_token(usdc, true).unwrap_err(),
+            revert(ErrorCode::OnlyAdmin)
+        );
+        vm.set_sender(admin);
+        assert!(contract.set_basket_token(usdc, true).is_ok());
+        assert!(setting::<(Address, bool)>(&contract, SETTING_BASKET_TOKEN, usdc).1);
+        vm.set_sender(client);
+        send_value(&vm, amount);
+        assert_eq!(
+            contract.deposit_basket(freelancer, 86_400, vec![], vec![]).unwrap_err(),
+            revert(ErrorCode::InvalidBasket)
+        );
+        assert_eq!(
+            contract.deposit_basket(freelancer, 86_400, vec![dai], vec![usdc_amount]).unwrap_err(),
+            revert(ErrorCode::TokenNotApproved)
+        );
+        assert_eq!(
+            contract
+                .deposit_basket(freelancer, 86_400, vec![usdc, usdc], vec![usdc_amount; 2])
+                .unwrap_err(),
+            revert(ErrorCode::DuplicateToken)
+        );
+
+        // The tokens are pulled from the client alongside the ETH
+        let pull = transferFromCall {
+            from: client,
+            to: vm.contract_address(),
+            amount: usdc_amount,
+        }
+        .abi_encode();
+        vm.mock_call(usdc, pull, ok.clone());
+        let job_id = contract.deposit_basket(freelancer, 86_400, vec![usdc], vec![usdc_amount]).unwrap();
+        send_value(&vm, amount);
+        let job_id2 = contract.deposit_basket(freelancer, 86_400, vec![usdc], vec![usdc_amount]).unwrap();
+        // A preview covers ETH only, so it refuses a job holding tokens
+        assert_eq!(contract.preview_release(job_id).unwrap_err(), revert(ErrorCode::JobHasBasket));
+        assert_eq!(contract.preview_refund(job_id).unwrap_err(), revert(ErrorCode::JobHasBasket));
+        let logs = vm.get_emitted_logs();
+        assert_eq!(
+            EscrowEvent::decode(&logs.last().unwrap().0, &logs.last().unwrap().1),
+            Some(EscrowEvent::BasketFunded(BasketFunded {
+                job_id: job_id2,
+                token: usdc,
+                amount: usdc_amount,
+            }))
+        );
+        assert_eq!(
+            contract.get_basket(job_id),
+            (vec![Address::ZERO, usdc], vec![amount, usdc_amount])
+        );
+        assert_eq!(
+            contract.release_partial(job_id, U256::from(1)).unwrap_err(),
+            revert(ErrorCode::JobHasBasket)
+        );
+
+        // Release pays each token to the freelancer with the ETH
+        let pay_freelancer = transferCall {
+            to: freelancer,
+            amount: usdc_amount,
+        }
+        .abi_encode();
+        vm.mock_call(usdc, pay_freelancer, ok.clone());
+        let balances = Balances::track(&vm, &[client, freelancer]);
+        assert!(contract.release(job_id, B256::ZERO).is_ok());
+        balances.assert_deltas(&[(freelancer, 1_000)]);
+        let logs = vm.get_emitted_logs();
+        let paid = logs.iter().rev().nth(1).unwrap();
+        assert_eq!(
+            EscrowEvent::decode(&paid.0, &paid.1),
+            Some(EscrowEvent::BasketPaid(BasketPaid {
+                job_id,
+                token: usdc,
+                recipient: freelancer,
+                amount: usdc_amount,
+            }))
+        );
+
+        // Refund returns each token to the client
+        let pay_client = transferCall {
+            to: client,
+            amount: usdc_amount,
+        }
+        .abi_encode();
+        vm.mock_call(usdc, pay_client.clone(), ok);
+        let balances = Balances::track(&vm, &[client, freelancer]);
+        assert!(contract.refund(job_id2, B256::ZERO).is_ok());
+        balances.assert_deltas(&[(client, 1_000)]);
+        let logs = vm.get_emitted_logs();
+        let paid = logs.iter().rev().nth(1).unwrap();
+        assert_eq!(
+            EscrowEvent::decode(&paid.0, &paid.1),
+            Some(EscrowEvent::BasketPaid(BasketPaid {
+                job_id: job_id2,
+                token: usdc,
+                recipient: client,
+                amount: usdc_amount,
+            }))
+        );
+
+        // A token that refuses the transfer blocks the settlement
+        send_value(&vm, amount);
+        let job_id3 = contract.deposit_basket(freelancer, 86_400, vec![usdc], vec![usdc_amount]).unwrap();
+        vm.mock_call(usdc, pay_client, Ok(transferCall::abi_encode_returns(&(false,))));
+        assert_eq!(
+            contract.refund(job_id3, B256::ZERO).unwrap_err(),
+            revert(ErrorCode::TokenTransferFailed)
+        );
+    }
+
+    #[test]
+    fn test_refund_root() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let admin = vm.msg_sender();
+        let client = Address::from([0x03; 20]);
+        let freelancer = Address::from([0x01; 20]);
+        let member = Address::from([0x05; 20]);
+        let amount = U256::from(1_000);
+        let pair = |a: B256, b: B256| {
+            if a <= b {
+                keccak256([a.as_slice(), b.as_slice()].concat())
+            } else {
+                keccak256([b.as_slice(), a.as_slice()].concat())
+            }
+        };
+
+        assert!(contract.constructor(admin).is_ok());
+        vm.set_sender(client);
+        let mut jobs = vec![];
+        for _ in 0..3 {
+            send_value(&vm, amount);
+            jobs.push(contract.deposit(freelancer, 86_400, B256::ZERO).unwrap());
+        }
+
+        // Leaves match OpenZeppelin's StandardMerkleTree over (uint256, uint256)
+        assert_eq!(
+            refund_leaf(jobs[0], amount),
+            keccak256(keccak256([jobs[0].to_be_bytes::<32>(), amount.to_be_bytes::<32>()].concat()))
+        );
+        let leaves = [
+            refund_leaf(jobs[0], amount),
+            refund_leaf(jobs[1], U256::from(400)),
+            refund_leaf(jobs[2], amount),
+        ];
+        let root = pair(pair(leaves[0], leaves[1]), leaves[2]);
+        let proof0 = vec![leaves[1], leaves[2]];
+        let proof1 = vec![leaves[0], leaves[2]];
+
+        assert_eq!(
+            contract.claim_emergency_refund(jobs[0], amount, proof0.clone()).unwrap_err(),
+            revert(ErrorCode::NoRefundRoot)
+        );
+        assert_eq!(contract.set_refund_root(root).unwrap_err(), revert(ErrorCode::OnlyAdmin));
+
+        // Once governed, only a proposal can publish the root
+        vm.set_sender(admin);
+        assert!(contract.set_governance(Address::ZERO, vec![member], U256::from(1)).is_ok());
+        assert_eq!(contract.set_refund_root(root).unwrap_err(), revert(ErrorCode::GovernedParameter));
+        vm.set_block_timestamp(1_000);
+        vm.set_sender(member);
+        let proposal = contract
+            .propose_governance(GOVERNANCE_ACTION_REFUND_ROOT, Address::ZERO, root.into(), Bytes(vec![]))
+            .unwrap();
+        assert!(contract.vote_governance(proposal, true).is_ok());
+        vm.set_block_timestamp(1_000 + GOVERNANCE_VOTING_PERIOD + GOVERNANCE_TIMELOCK);
+        assert!(contract.execute_governance(proposal).is_ok());
+        assert_eq!(contract.get_refund_root(), root);
+        let logs = vm.get_emitted_logs();
+        assert_eq!(
+            EscrowEvent::decode(&logs.last().unwrap().0, &logs.last().unwrap().1),
+            Some(EscrowEvent::RefundRootSet(RefundRootSet { root }))
+        );
+
+        // Only the client can claim, with the listed amount, even while paused
+        vm.set_sender(admin);
+        assert!(contract.set_paused(true).is_ok());
+        vm.set_sender(freelancer);
+        assert_eq!(
+            contract.claim_emergency_refund(jobs[0], amount, proof0.clone()).unwrap_err(),
+            revert(ErrorCode::OnlyClientCanClaim)
+        );
+        vm.set_sender(client);
+        assert_eq!(
+            contract.claim_emergency_refund(jobs[0], U256::from(999), proof0.clone()).unwrap_err(),
+            revert(ErrorCode::InvalidProof)
+        );
+        assert_eq!(
+            contract.claim_emergency_refund(jobs[0], amount + U256::from(1), proof0.clone()).unwrap_err(),
+            revert(ErrorCode::InvalidRefundAmount)
+        );
+
+        // Claiming the whole amount refunds and settles the job
+        let balances = Balances::track(&vm, &[client, freelancer]);
+        assert!(contract.claim_emergency_refund(jobs[0], amount, proof0.clone()).is_ok());
+        balances.assert_deltas(&[(client, 1_000)]);
+        assert!(contract.is_refund_claimed(jobs[0]));
+        assert!(contract.get_job(jobs[0]).unwrap().6);
+        let logs = vm.get_emitted_logs();
+        assert_eq!(
+            EscrowEvent::decode(&logs.last().unwrap().0, &logs.last().unwrap().1),
+            Some(EscrowEvent::EmergencyRefundClaimed(EmergencyRefundClaimed {
+                job_id: jobs[0],
+                client,
+                amount,
+                remaining: U256::ZERO,
+            }))
+        );
+        assert_eq!(
+            contract.claim_emergency_refund(jobs[0], amount, proof0).unwrap_err(),
+            revert(ErrorCode::JobAlreadySettled)
+        );
+
+        // A smaller amount refunds that much and leaves the rest escrowed
+        let balances = Balances::track(&vm, &[client, freelancer]);
+        assert!(contract.claim_emergency_refund(jobs[1], U256::from(400), proof1.clone()).is_ok());
+        balances.assert_deltas(&[(client, 400)]);
+        assert_eq!(contract.get_job(jobs[1]).unwrap().3, U256::from(600));
+        assert_eq!(
+            contract.claim_emergency_refund(jobs[1], U256::from(400), proof1).unwrap_err(),
+            revert(ErrorCode::RefundAlreadyClaimed)
+        );
+        vm.set_sender(admin);
+        assert!(contract.set_paused(false).is_ok());
+        vm.set_sender(client);
+        let balances = Balances::track(&vm, &[client, freelancer]);
+        assert!(contract.release(jobs[1], B256::ZERO).is_ok());
+        balances.assert_deltas(&[(freelancer, 600)]);
+    }
+
+    #[test]
+    fn test_withholding() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let client = vm.msg_sender();
+        let freelancer = Address::from([0x01; 20]);
+        let tax = Address::from([0x02; 20]);
+        let amount = U256::from(1_000_000);
+
+        // The rate is capped and needs somewhere to go
+        assert!(contract.constructor(vm.msg_sender()).is_ok());
+        vm.set_sender(freelancer);
+        assert_eq!(
+            contract.set_withholding(BASIS_POINTS + 1, tax).unwrap_err(),
+            revert(ErrorCode::InvalidWithholdingRate)
+        );
+        assert_eq!(
+            contract.set_withholding(2_400, Address::ZERO).unwrap_err(),
+            revert(ErrorCode::InvalidWithholdingAddress)
+        );
+        assert!(contract.set_withholding(2_400, tax).is_ok());
+        assert_eq!(contract.get_withholding(freelancer), (2_400, tax));
+
+        // Release splits the payment and reports both amounts
+        vm.set_balance(vm.contract_address(), amount);
+        vm.set_sender(client);
+        vm.set_value(amount);
+        let job_id = contract.deposit(freelancer, 86_400, B256::ZERO).unwrap();
+        let withheld = U256::from(240_000);
+        let net = amount - withheld;
+        assert_eq!(contract.preview_release(job_id), Ok((net, withheld, U256::ZERO, U256::ZERO, U256::ZERO, U256::ZERO)));
+        assert_eq!(
+            contract.preview_refund(job_id),
+            Ok((U256::ZERO, U256::ZERO, amount, U256::ZERO, U256::ZERO, U256::ZERO))
+        );
+        assert!(contract.release(job_id, B256::ZERO).is_ok());
+        assert_eq!(contract.preview_release(job_id).unwrap_err(), revert(ErrorCode::JobAlreadySettled));
+        assert_eq!(vm.balance(tax), withheld);
+        assert_eq!(vm.balance(freelancer), net);
+        let logs = vm.get_emitted_logs();
+        assert_eq!(
+            EscrowEvent::decode(&logs[2].0, &logs[2].1),
+            Some(EscrowEvent::Withheld(Withheld { job_id, destination: tax, net, withheld }))
+        );
+
+        // Gross earnings are still credited to the freelancer
+        assert_eq!(contract.get_user_stats(freelancer).3, amount);
+        assert!(contract.verify_solvency());
+
+        // A destination contract that refuses the push is credited the
+        // withheld share, and the release still goes through
+        let vault = Address::from([0x04; 20]);
+        vm.set_code(vault, vec![0x60, 0x00]);
+        vm.mock_call(vault, Vec::new(), Err(b"no receive".to_vec()));
+        vm.set_sender(freelancer);
+        assert!(contract.set_withholding(2_400, vault).is_ok());
+        vm.set_sender(client);
+        send_value(&vm, amount);
+        let job_id = contract.deposit(freelancer, 86_400, B256::ZERO).unwrap();
+        assert!(contract.release(job_id, B256::ZERO).is_ok());
+        assert_eq!(vm.balance(freelancer), net * U256::from(2));
+        assert_eq!(contract.get_credit(vault), withheld);
+        let logs = vm.get_emitted_logs();
+        assert!(logs.iter().any(|(topics, data)| EscrowEvent::decode(topics, data)
+            == Some(EscrowEvent::PayoutPushed(PayoutPushed {
+                job_id,
+                recipient: vault,
+                amount: withheld,
+                pushed: false,
+            }))));
+        assert!(contract.verify_solvency());
+    }
+
+    #[test]
+    fn test_payout_address() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let client = Address::from([0x03; 20]);
+        let freelancer = Address::from([0x01; 20]);
+        let cold_wallet = Address::from([0x02; 20]);
+        let amount = U256::from(1_000);
+
+        assert!(contract.constructor(vm.msg_sender()).is_ok());
+        vm.set_block_timestamp(1_000);
+        vm.set_sender(freelancer);
+        assert_eq!(
+            contract.propose_payout_address(vm.contract_address()).unwrap_err(),
+            revert(ErrorCode::InvalidPayoutAddress)
+        );
+        assert_eq!(
+            contract.apply_payout_address(freelancer).unwrap_err(),
+            revert(ErrorCode::NoPendingPayoutAddress)
+        );
+        assert!(contract.propose_payout_address(cold_wallet).is_ok());
+        let effective_at = 1_000 + PAYOUT_ADDRESS_DELAY;
+        assert_eq!(
+            contract.get_payout_address(freelancer),
+            (Address::ZERO, cold_wallet, effective_at)
+        );
+        let logs = vm.get_emitted_logs();
+        assert_eq!(
+            EscrowEvent::decode(&logs[logs.len() - 1].0, &logs[logs.len() - 1].1),
+            Some(EscrowEvent::PayoutAddressProposed(PayoutAddressProposed {
+                freelancer,
+                payout_address: cold_wallet,
+                effective_at,
+            }))
+        );
+
+        // Until the delay passes, payouts still go to the freelancer
+        vm.set_sender(client);
+        send_value(&vm, amount);
+        let job_id = contract.deposit(freelancer, 86_400, B256::ZERO).unwrap();
+        assert_eq!(
+            contract.apply_payout_address(freelancer).unwrap_err(),
+            revert(ErrorCode::PayoutAddressDelayNotOver)
+        );
+        let balances = Balances::track(&vm, &[freelancer, cold_wallet]);
+        assert!(contract.release(job_id, B256::ZERO).is_ok());
+        balances.assert_deltas(&[(freelancer, 1_000), (cold_wallet, 0)]);
+
+        // Anyone applies it afterwards, and the job's freelancer is unchanged
+        vm.set_block_timestamp(effective_at);
+        assert!(contract.apply_payout_address(freelancer).is_ok());
+        assert_eq!(contract.get_payout_address(freelancer), (cold_wallet, Address::ZERO, 0));
+        send_value(&vm, amount);
+        let job_id = contract.deposit(freelancer, 86_400, B256::ZERO).unwrap();
+        let balances = Balances::track(&vm, &[freelancer, cold_wallet]);
+        assert!(contract.release(job_id, B256::ZERO).is_ok());
+        balances.assert_deltas(&[(freelancer, 0), (cold_wallet, 1_000)]);
+        assert_eq!(contract.get_job(job_id).unwrap().2, freelancer);
+        assert_eq!(contract.get_user_stats(freelancer).3, U256::from(2_000));
+    }
+
+    #[test]
+    fn test_contract_payout_address() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let client = Address::from([0x03; 20]);
+        let freelancer = Address::from([0x01; 20]);
+        let splitter = Address::from([0x02; 20]);
+        let amount = U256::from(1_000);
+
+        assert!(contract.constructor(vm.msg_sender()).is_ok());
+        vm.set_code(splitter, vec![0x60, 0x00]);
+        vm.set_sender(freelancer);
+        assert!(contract.propose_payout_address(splitter).is_ok());
+        vm.set_block_timestamp(PAYOUT_ADDRESS_DELAY);
+        assert!(contract.apply_payout_address(freelancer).is_ok());
+
+        // A contract that takes the push is paid with a gas-capped call
+        vm.set_sender(client);
+        send_value(&vm, amount);
+        let job_id = contract.deposit(freelancer, 86_400, B256::ZERO).unwrap();
+        assert!(contract.release(job_id, B256::ZERO).is_ok());
+        let logs = vm.get_emitted_logs();
+        assert_eq!(
+            EscrowEvent::decode(&logs[logs.len() - 2].0, &logs[logs.len() - 2].1),
+            Some(EscrowEvent::PayoutPushed(PayoutPushed {
+                job_id,
+                recipient: splitter,
+                amount,
+                pushed: true,
+            }))
+        );
+        assert_eq!(contract.get_credit(splitter), U256::ZERO);
+
+        // One that reverts is credited the payout and withdraws it later
+        vm.mock_call(splitter, Vec::new(), Err(b"no receive".to_vec()));
+        send_value(&vm, amount);
+        let job_id = contract.deposit(freelancer, 86_400, B256::ZERO).unwrap();
+        assert!(contract.release(job_id, B256::ZERO).is_ok());
+        assert!(contract.get_job(job_id).unwrap().5);
+        let logs = vm.get_emitted_logs();
+        assert_eq!(
+            EscrowEvent::decode(&logs[logs.len() - 2].0, &logs[logs.len() - 2].1),
+            Some(EscrowEvent::PayoutPushed(PayoutPushed {
+                job_id,
+                recipient: splitter,
+                amount,
+                pushed: false,
+            }))
+        );
+        assert_eq!(contract.get_credit(splitter), amount);
+        vm.set_sender(splitter);
+        let balances = Balances::track(&vm, &[splitter]);
+        assert!(contract.withdraw_credit().is_ok());
+        balances.assert_deltas(&[(splitter, 1_000)]);
+    }
+
+    #[test]
+    fn test_compliance_hook() {
+        use alloy_sol_types::SolCall;
+        use compliance::checkCall;
+
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let admin = vm.msg_sender();
+        let client = Address::from([0x01; 20]);
+        let freelancer = Address::from([0x02; 20]);
+        let sanctioned = Address::from([0x03; 20]);
+        let hook = Address::from([0x04; 20]);
+        let amount = U256::from(1_000_000);
+        let check = |freelancer| checkCall { client, freelancer, amount }.abi_encode();
+        let answer = |ok: bool| Ok(checkCall::abi_encode_returns(&(ok,)));
+
+        // Only the admin sets the hook
+        assert!(contract.constructor(admin).is_ok());
+        vm.set_sender(client);
+        assert_eq!(contract.set_compliance_hook(hook).unwrap_err(), revert(ErrorCode::OnlyAdmin));
+        vm.set_sender(admin);
+        assert!(contract.set_compliance_hook(hook).is_ok());
+        assert_eq!(setting::<(Address,)>(&contract, SETTING_COMPLIANCE_HOOK, Address::ZERO).0, hook);
+
+        // Deposits the hook rejects, or cannot answer for, are refused
+        vm.mock_static_call(hook, check(freelancer), answer(true));
+        vm.mock_static_call(hook, check(sanctioned), answer(false));
+        vm.set_balance(vm.contract_address(), amount);
+        vm.set_sender(client);
+        vm.set_value(amount);
+        assert_eq!(
+            contract.deposit(sanctioned, 86_400, B256::ZERO).unwrap_err(),
+            revert(ErrorCode::ComplianceCheckFailed)
+        );
+        assert_eq!(
+            contract.deposit(Address::from([0x05; 20]), 86_400, B256::ZERO).unwrap_err(),
+            revert(ErrorCode::ComplianceCheckFailed)
+        );
+        let job_id = contract.deposit(freelancer, 86_400, B256::ZERO).unwrap();
+
+        // A freelancer flagged after the deposit cannot be paid
+        vm.mock_static_call(hook, check(freelancer), Err(Vec::new()));
+        assert_eq!(
+            contract.release(job_id, B256::ZERO).unwrap_err(),
+            revert(ErrorCode::ComplianceCheckFailed)
+        );
+
+        // Turning the hook off lets the release through
+        vm.set_sender(admin);
+        assert!(contract.set_compliance_hook(Address::ZERO).is_ok());
+        vm.set_sender(client);
+        assert!(contract.release(job_id, B256::ZERO).is_ok());
+        assert_eq!(vm.balance(freelancer), amount);
+    }
+
+    #[test]
+    fn test_deposit_attested() {
+        use alloy_sol_types::SolCall;
+        use attestation::{getAttestationCall, Attestation};
+
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let admin = vm.msg_sender();
+        let client = Address::from([0x01; 20]);
+        let freelancer = Address::from([0x02; 20]);
+        let eas = Address::from([0x03; 20]);
+        let attester = Address::from([0x04; 20]);
+        let schema = B256::from([0x05; 32]);
+        let amount = U256::from(1_000_000);
+        let attestation = |uid: B256, recipient, revocation_time| Attestation {
+            uid,
+            schema,
+            time: 1,
+            expirationTime: 0,
+            revocationTime: revocation_time,
+            refUID: B256::ZERO,
+            recipient,
+            attester,
+            revocable: true,
+            data: Default::default(),
+        };
+        let mock = |uid: B256, attestation: Attestation| {
+            vm.mock_static_call(
+                eas,
+                getAttestationCall { uid }.abi_encode(),
+                Ok(getAttestationCall::abi_encode_returns(&(attestation,))),
+            )
+        };
+        let valid = B256::from([0x11; 32]);
+        let other = B256::from([0x12; 32]);
+        let revoked = B256::from([0x13; 32]);
+        mock(valid, attestation(valid, freelancer, 0));
+        mock(other, attestation(other, client, 0));
+        mock(revoked, attestation(revoked, freelancer, 1));
+
+        // Nothing can be verified until the admin configures EAS
+        assert!(contract.constructor(admin).is_ok());
+        vm.set_sender(client);
+        vm.set_value(amount);
+        assert_eq!(
+            contract.deposit_attested(freelancer, 86_400, valid).unwrap_err(),
+            revert(ErrorCode::AttestationsNotConfigured)
+        );
+        assert_eq!(
+            contract.set_attestation_policy(eas, schema, attester).unwrap_err(),
+            revert(ErrorCode::OnlyAdmin)
+        );
+        vm.set_sender(admin);
+        assert_eq!(
+            contract.set_attestation_policy(eas, schema, Address::ZERO).unwrap_err(),
+            revert(ErrorCode::InvalidAttesterAddress)
+        );
+        assert!(contract.set_attestation_policy(eas, schema, attester).is_ok());
+        assert_eq!(setting::<(Address, B256, Address)>(&contract, SETTING_ATTESTATION_POLICY, Address::ZERO), (eas, schema, attester));
+
+        // Attestations of someone else, revoked ones and unknown UIDs are refused
+        vm.set_sender(client);
+        assert_eq!(
+            contract.deposit_attested(freelancer, 86_400, other).unwrap_err(),
+            revert(ErrorCode::InvalidAttestation)
+        );
+        assert_eq!(
+            contract.deposit_attested(freelancer, 86_400, revoked).unwrap_err(),
+            revert(ErrorCode::AttestationRevoked)
+        );
+        assert_eq!(
+            contract.deposit_attested(freelancer, 86_400, B256::from([0x14; 32])).unwrap_err(),
+            revert(ErrorCode::InvalidAttestation)
+        );
+        assert_eq!(contract.get_total_jobs(), U256::ZERO);
+
+        // A live attestation opens the job and is recorded on it
+        let job_id = contract.deposit_attested(freelancer, 86_400, valid).unwrap();
+        assert_eq!(contract.get_attestation(job_id), valid);
+        let (_, _, assigned, deposited, _, _, _) = contract.get_job(job_id).unwrap();
+        assert_eq!((assigned, deposited), (freelancer, amount));
+    }
+
+    #[test]
+    fn test_settlement_hook() {
+        use alloy_sol_types::SolCall;
+        use hooks::{onRefundedCall, onReleasedCall};
+
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let client = vm.msg_sender();
+        let freelancer = Address::from([0x01; 20]);
+        let hook = Address::from([0x02; 20]);
+        let amount = U256::from(1_000_000);
+
+        assert!(contract.constructor(client).is_ok());
+        vm.set_balance(vm.contract_address(), amount * U256::from(2));
+        vm.set_value(amount);
+        let released = contract.deposit(freelancer, 86_400, B256::ZERO).unwrap();
+        let refunded = contract.deposit(freelancer, 86_400, B256::ZERO).unwrap();
+
+        // Only the client attaches a hook
+        vm.set_sender(freelancer);
+        assert_eq!(
+            contract.set_settlement_hook(released, hook).unwrap_err(),
+            revert(ErrorCode::OnlyClientCanSetHook)
+        );
+        vm.set_sender(client);
+        assert!(contract.set_settlement_hook(released, hook).is_ok());
+        assert!(contract.set_settlement_hook(refunded, hook).is_ok());
+        assert_eq!(contract.get_settlement_hook(released), hook);
+
+        // A successful callback leaves no trace in the escrow's logs
+        let on_released = onReleasedCall { job_id: released, amount }.abi_encode();
+        vm.mock_call(hook, on_released, Ok(Vec::new()));
+        assert!(contract.release(released, B256::ZERO).is_ok());
+        let logs = vm.get_emitted_logs();
+        let last = logs.last().unwrap();
+        assert!(matches!(EscrowEvent::decode(&last.0, &last.1), Some(EscrowEvent::Released(_))));
+
+        // A failing hook cannot block the refund
+        let on_refunded = onRefundedCall { job_id: refunded, amount }.abi_encode();
+        vm.mock_call(hook, on_refunded, Err(b"out of gas".to_vec()));
+        assert!(contract.refund(refunded, B256::ZERO).is_ok());
+        assert_eq!(vm.balance(client), amount);
+        let logs = vm.get_emitted_logs();
+        let last = logs.last().unwrap();
+        assert_eq!(
+            EscrowEvent::decode(&last.0, &last.1),
+            Some(EscrowEvent::SettlementHookFailed(SettlementHookFailed { job_id: refunded, hook }))
+        );
+
+        // Settled jobs keep their hook
+        assert_eq!(
+            contract.set_settlement_hook(released, Address::ZERO).unwrap_err(),
+            revert(ErrorCode::JobAlreadySettled)
+        );
+    }
+
+    #[test]
+    fn test_release_condition() {
+        use alloy_sol_types::SolCall;
+        use conditions::canReleaseCall;
+
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let client = vm.msg_sender();
+        let freelancer = Address::from([0x01; 20]);
+        let condition = Address::from([0x02; 20]);
+        let amount = U256::from(1_000_000);
+        let duration = 86_400_u64;
+
+        assert!(contract.constructor(client).is_ok());
+        vm.set_balance(vm.contract_address(), amount * U256::from(2));
+        vm.set_value(amount);
+        assert_eq!(
+            contract.deposit_with_condition(freelancer, duration, Address::ZERO).unwrap_err(),
+            revert(ErrorCode::InvalidConditionAddress)
+        );
+        let job_id = contract.deposit_with_condition(freelancer, duration, condition).unwrap();
+        assert_eq!(contract.get_release_condition(job_id), condition);
+
+        // Past the deadline the freelancer still waits on the condition
+        vm.set_block_timestamp(duration + 1);
+        vm.set_sender(freelancer);
+        let can_release = canReleaseCall { job_id }.abi_encode();
+        let answer = |met: bool| Ok(canReleaseCall::abi_encode_returns(&(met,)));
+        vm.mock_static_call(condition, can_release.clone(), answer(false));
+        assert_eq!(
+            contract.auto_release(job_id, B256::ZERO).unwrap_err(),
+            revert(ErrorCode::ReleaseConditionNotMet)
+        );
+        vm.mock_static_call(condition, can_release.clone(), Err(Vec::new()));
+        assert_eq!(
+            contract.auto_release(job_id, B256::ZERO).unwrap_err(),
+            revert(ErrorCode::ReleaseConditionNotMet)
+        );
+
+        vm.mock_static_call(condition, can_release, answer(true));
+        assert!(contract.auto_release(job_id, B256::ZERO).is_ok());
+        assert_eq!(vm.balance(freelancer), amount);
+
+        // The client can always release by hand
+        vm.set_sender(client);
+        let job_id = contract.deposit_with_condition(freelancer, duration, condition).unwrap();
+        assert!(contract.release(job_id, B256::ZERO).is_ok());
+    }
+
+    #[test]
+    fn test_tranches() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let client = vm.msg_sender();
+        let freelancer = Address::from([0x01; 20]);
+        let amounts = vec![U256::from(300), U256::from(400), U256::from(300)];
+        let unlocks = vec![1_000_u64, 2_000, 3_000];
+
+        assert!(contract.constructor(client).is_ok());
+        vm.set_balance(vm.contract_address(), U256::from(1_000));
+        vm.set_block_timestamp(1_000);
+
+        // The schedule must add up to the deposit and unlock in order
+        vm.set_value(U256::from(999));
+        assert_eq!(
+            contract.deposit_tranched(freelancer, amounts.clone(), unlocks.clone()).unwrap_err(),
+            revert(ErrorCode::TranchesMustSumToDeposit)
+        );
+        vm.set_value(U256::from(1_000));
+        let shuffled = vec![1_000, 3_000, 2_000];
+        assert_eq!(
+            contract.deposit_tranched(freelancer, amounts.clone(), shuffled).unwrap_err(),
+            revert(ErrorCode::UnlocksMustBeIncreasing)
+        );
+        let job_id = contract.deposit_tranched(freelancer, amounts, unlocks).unwrap();
+        let (_, _, _, _, deadline, _, _) = contract.get_job(job_id).unwrap();
+        assert_eq!(deadline, 3_000);
+
+        // The up-front tranche is claimable right away, the next one is not
+        vm.set_sender(freelancer);
+        assert!(contract.claim_tranche(job_id, U256::ZERO).is_ok());
+        assert_eq!(vm.balance(freelancer), U256::from(300));
+        assert_eq!(
+            contract.claim_tranche(job_id, U256::ZERO).unwrap_err(),
+            revert(ErrorCode::TrancheAlreadySettled)
+        );
+        assert_eq!(
+            contract.claim_tranche(job_id, U256::from(1)).unwrap_err(),
+            revert(ErrorCode::TrancheLocked)
+        );
+
+        // The client can pull a locked tranche back, but not the whole job
+        vm.set_sender(client);
+        assert_eq!(contract.refund(job_id, B256::ZERO).unwrap_err(), revert(ErrorCode::JobIsTranched));
+        assert!(contract.refund_tranche(job_id, U256::from(1)).is_ok());
+        assert_eq!(vm.balance(client), U256::from(400));
+        assert_eq!(
+            contract.refund_tranche(job_id, U256::from(3)).unwrap_err(),
+            revert(ErrorCode::InvalidTranche)
+        );
+
+        // Once unlocked, the last tranche can only be claimed, and settles the job
+        vm.set_block_timestamp(3_000);
+        assert_eq!(
+            contract.refund_tranche(job_id, U256::from(2)).unwrap_err(),
+            revert(ErrorCode::TrancheUnlocked)
+        );
+        vm.set_sender(freelancer);
+        assert!(contract.claim_tranche(job_id, U256::from(2)).is_ok());
+        assert_eq!(vm.balance(freelancer), U256::from(600));
+        let (_, _, _, remaining, _, released, refunded) = contract.get_job(job_id).unwrap();
+        assert_eq!((remaining, released, refunded), (U256::ZERO, true, false));
+        let (amounts, unlocks, settled) = contract.get_tranches(job_id);
+        assert_eq!(amounts, vec![U256::from(300), U256::from(400), U256::from(300)]);
+        assert_eq!(unlocks, vec![1_000, 2_000, 3_000]);
+        assert_eq!(settled, vec![true; 3]);
+        assert!(contract.verify_solvency());
+    }
+
+    #[test]
+    fn test_retainer() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let client = vm.msg_sender();
+        let freelancer = Address::from([0x01; 20]);
+        let month = 1_000_u64;
+
+        assert!(contract.constructor(client).is_ok());
+        vm.set_balance(vm.contract_address(), U256::from(3_000));
+        vm.set_value(U256::from(3_001));
+        assert_eq!(
+            contract.deposit_retainer(freelancer, 3, month, month).unwrap_err(),
+            revert(ErrorCode::DepositMustSplitEvenly)
+        );
+        vm.set_value(U256::from(3_000));
+        let job_id = contract.deposit_retainer(freelancer, 3, month, month).unwrap();
+        assert_eq!(contract.get_retainer(job_id), (month, month));
+        let (_, unlocks, _) = contract.get_tranches(job_id);
+        assert_eq!(unlocks, vec![1_000, 2_000, 3_000]);
+
+        // Nothing is due until the first month is over
+        vm.set_sender(freelancer);
+        assert_eq!(contract.claim_unlocked(job_id).unwrap_err(), revert(ErrorCode::NothingToClaim));
+        vm.set_block_timestamp(month);
+        assert!(contract.claim_unlocked(job_id).is_ok());
+        assert_eq!(vm.balance(freelancer), U256::from(1_000));
+
+        // Single months cannot be pulled back without notice
+        vm.set_sender(client);
+        assert_eq!(
+            contract.refund_tranche(job_id, U256::from(2)).unwrap_err(),
+            revert(ErrorCode::JobIsARetainer)
+        );
+
+        // Stopping mid-month keeps the month inside the notice period payable
+        vm.set_block_timestamp(1_500);
+        assert!(contract.stop_retainer(job_id).is_ok());
+        assert_eq!(vm.balance(client), U256::from(1_000));
+        let logs = vm.get_emitted_logs();
+        let stopped = logs.last().unwrap();
+        assert_eq!(
+            EscrowEvent::decode(&stopped.0, &stopped.1),
+            Some(EscrowEvent::RetainerStopped(RetainerStopped {
+                job_id,
+                refunded: U256::from(1_000),
+            }))
+        );
+        assert_eq!(contract.stop_retainer(job_id).unwrap_err(), revert(ErrorCode::NothingToStop));
+
+        // The last payable month settles the job
+        vm.set_block_timestamp(2 * month);
+        vm.set_sender(freelancer);
+        assert!(contract.claim_unlocked(job_id).is_ok());
+        assert_eq!(vm.balance(freelancer), U256::from(2_000));
+        let (_, _, _, _, _, released, _) = contract.get_job(job_id).unwrap();
+        assert!(released);
+        assert!(contract.verify_solvency());
+    }
+
+    #[test]
+    fn test_cancellation_notice() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let client = vm.msg_sender();
+        let freelancer = Address::from([0x01; 20]);
+        let week = 7 * 24 * 60 * 60;
+
+        assert!(contract.constructor(client).is_ok());
+        vm.set_balance(vm.contract_address(), U256::from(1_000));
+        vm.set_value(U256::from(1_000));
+        let job_id = contract.deposit_with_notice(freelancer, 4 * week, week).unwrap();
+        assert_eq!(contract.get_cancellation(job_id), (week, 0));
+
+        // Refunds need a cancellation whose notice has run out
+        assert_eq!(
+            contract.refund(job_id, B256::ZERO).unwrap_err(),
+            revert(ErrorCode::CancellationNotRequested)
+        );
+        assert!(contract.request_cancellation(job_id).is_ok());
+        assert_eq!(contract.get_cancellation(job_id), (week, week));
+        assert_eq!(
+            contract.refund(job_id, B256::ZERO).unwrap_err(),
+            revert(ErrorCode::NoticePeriodRunning)
+        );
+
+        // Delivering within the notice withdraws the cancellation
+        vm.set_block_timestamp(week - 1);
+        vm.set_sender(freelancer);
+        assert!(contract.submit_delivery(job_id, B256::from([0xaa; 32])).is_ok());
+        assert_eq!(contract.get_cancellation(job_id), (week, 0));
+        let logs = vm.get_emitted_logs();
+        let delivered = logs.last().unwrap();
+        assert_eq!(
+            EscrowEvent::decode(&delivered.0, &delivered.1),
+            Some(EscrowEvent::DeliverySubmitted(DeliverySubmitted {
+                job_id,
+                delivery: B256::from([0xaa; 32]),
+            }))
+        );
+
+        // Without a delivery the refund goes through once the notice is over
+        vm.set_sender(client);
+        assert!(contract.request_cancellation(job_id).is_ok());
+        vm.set_block_timestamp(2 * week - 2);
+        assert_eq!(
+            contract.refund(job_id, B256::ZERO).unwrap_err(),
+            revert(ErrorCode::NoticePeriodRunning)
+        );
+        vm.set_block_timestamp(2 * week - 1);
+        vm.set_sender(freelancer);
+        assert_eq!(
+            contract.submit_delivery(job_id, B256::ZERO).unwrap_err(),
+            revert(ErrorCode::NoticePeriodOver)
+        );
+        vm.set_sender(client);
+        assert!(contract.refund(job_id, B256::ZERO).is_ok());
+        assert_eq!(vm.balance(client), U256::from(1_000));
+    }
+
+    #[test]
+    fn test_installment_funding() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let client = vm.msg_sender();
+        let backer = Address::from([0x02; 20]);
+        let freelancer = Address::from([0x01; 20]);
+
+        assert!(contract.constructor(client).is_ok());
+        vm.set_balance(vm.contract_address(), U256::from(1_500));
+        vm.set_value(U256::from(400));
+        let job_id = contract.create_job(freelancer, 100, U256::from(1_000), 50).unwrap();
+        assert_eq!(contract.get_funding(job_id), (U256::from(1_000), U256::from(400), 50));
+        vm.set_value(U256::ZERO);
+        assert_eq!(
+            contract.release(job_id, B256::ZERO).unwrap_err(),
+            revert(ErrorCode::JobNotFunded)
+        );
+
+        // The last installment activates the job and starts its deadline
+        vm.set_block_timestamp(10);
+        vm.set_sender(backer);
+        vm.set_value(U256::from(700));
+        assert_eq!(contract.fund(job_id).unwrap_err(), revert(ErrorCode::FundingExceedsTarget));
+        vm.set_value(U256::from(600));
+        assert!(contract.fund(job_id).is_ok());
+        assert_eq!(contract.get_contribution(job_id, backer), U256::from(600));
+        let (_, _, _, amount, deadline, _, _) = contract.get_job(job_id).unwrap();
+        assert_eq!((amount, deadline), (U256::from(1_000), 110));
+        let logs = vm.get_emitted_logs();
+        let deposited = logs.last().unwrap();
+        assert_eq!(
+            EscrowEvent::decode(&deposited.0, &deposited.1),
+            Some(EscrowEvent::Deposited(Deposited {
+                job_id,
+                client,
+                freelancer,
+                amount: U256::from(1_000),
+                memo: B256::ZERO,
+                category: 0,
+                tag: B256::ZERO,
+            }))
+        );
+        assert_eq!(contract.fund(job_id).unwrap_err(), revert(ErrorCode::JobNotFunding));
+        vm.set_sender(client);
+        vm.set_value(U256::ZERO);
+        assert!(contract.release(job_id, B256::ZERO).is_ok());
+        assert_eq!(vm.balance(freelancer), U256::from(1_000));
+
+        // A job that misses its cutoff hands every contribution back
+        vm.set_value(U256::from(300));
+        let job_id = contract.create_job(freelancer, 100, U256::from(1_000), 50).unwrap();
+        vm.set_sender(backer);
+        vm.set_value(U256::from(200));
+        assert!(contract.fund(job_id).is_ok());
+        vm.set_value(U256::ZERO);
+        assert_eq!(
+            contract.reclaim_contribution(job_id).unwrap_err(),
+            revert(ErrorCode::FundingStillOpen)
+        );
+        vm.set_block_timestamp(50);
+        vm.set_value(U256::from(100));
+        assert_eq!(contract.fund(job_id).unwrap_err(), revert(ErrorCode::FundingClosed));
+        vm.set_value(U256::ZERO);
+        assert!(contract.reclaim_contribution(job_id).is_ok());
+        assert_eq!(vm.balance(backer), U256::from(200));
+        assert_eq!(
+            contract.reclaim_contribution(job_id).unwrap_err(),
+            revert(ErrorCode::NothingToReclaim)
+        );
+        vm.set_sender(client);
+        assert!(contract.reclaim_contribution(job_id).is_ok());
+        assert_eq!(vm.balance(client), U256::from(300));
+        let (_, _, _, _, _, _, refunded) = contract.get_job(job_id).unwrap();
+        assert!(refunded);
+        assert!(contract.verify_solvency());
+    }
+
+    #[test]
+    fn test_invoice() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let client = vm.msg_sender();
+        let freelancer = Address::from([0x01; 20]);
+        let metadata = B256::right_padding_from(b"INV-0042");
+        let amount = U256::from(1_000);
+
+        assert!(contract.constructor(client).is_ok());
+        vm.set_block_timestamp(10);
+
+        // The freelancer bills the client; nothing is escrowed yet
+        vm.set_sender(freelancer);
+        assert_eq!(
+            contract.create_invoice(Address::ZERO, amount, 100, metadata, 60).unwrap_err(),
+            revert(ErrorCode::InvalidClientAddress)
+        );
+        assert_eq!(
+            contract.create_invoice(client, amount, 100, metadata, 10).unwrap_err(),
+            revert(ErrorCode::InvalidExpiry)
+        );
+        let invoice_id = contract.create_invoice(client, amount, 100, metadata, 60).unwrap();
+        assert_eq!(
+            contract.get_invoice(invoice_id),
+            (freelancer, client, amount, 100, metadata, 60, U256::ZERO)
+        );
+        assert_eq!(contract.get_total_jobs(), U256::ZERO);
+        let logs = vm.get_emitted_logs();
+        assert_eq!(logs.last().unwrap().0[0], INVOICE_CREATED_TOPIC);
+
+        // Only the invoiced client can pay it, and only in full
+        send_value(&vm, amount);
+        assert_eq!(
+            contract.fund_invoice(invoice_id).unwrap_err(),
+            revert(ErrorCode::OnlyInvoicedClient)
+        );
+        vm.set_sender(client);
+        vm.set_value(amount - U256::from(1));
+        assert_eq!(
+            contract.fund_invoice(invoice_id).unwrap_err(),
+            revert(ErrorCode::IncorrectPayment)
+        );
+        vm.set_value(amount);
+        assert_eq!(
+            contract.fund_invoice(U256::from(9)).unwrap_err(),
+            revert(ErrorCode::InvoiceNotFound)
+        );
+
+        // Paying it opens an ordinary job on the invoiced terms
+        let job_id = contract.fund_invoice(invoice_id).unwrap();
+        let (_, job_client, job_freelancer, job_amount, deadline, _, _) = contract.get_job(job_id).unwrap();
+        assert_eq!((job_client, job_freelancer, job_amount, deadline), (client, freelancer, amount, 110));
+        assert_eq!(contract.get_invoice(invoice_id).6, job_id);
+        let logs = vm.get_emitted_logs();
+        let funded = logs.last().unwrap();
+        assert_eq!(
+            EscrowEvent::decode(&funded.0, &funded.1),
+            Some(EscrowEvent::InvoiceFunded(InvoiceFunded { invoice_id, job_id }))
+        );
+        let deposited = &logs[logs.len() - 2];
+        assert_eq!(
+            EscrowEvent::decode(&deposited.0, &deposited.1),
+            Some(EscrowEvent::Deposited(Deposited {
+                job_id,
+                client,
+                freelancer,
+                amount,
+                memo: metadata,
+                category: 0,
+                tag: B256::ZERO,
+            }))
+        );
+        assert_eq!(
+            contract.fund_invoice(invoice_id).unwrap_err(),
+            revert(ErrorCode::InvoiceAlreadyFunded)
+        );
+
+        vm.set_value(U256::ZERO);
+        assert!(contract.release(job_id, B256::ZERO).is_ok());
+        assert_eq!(vm.balance(freelancer), amount);
+        assert!(contract.verify_solvency());
+
+        // An unpaid invoice can't be paid once expired, and anyone can then
+        // prune it; paid and open invoices are left alone
+        vm.set_sender(freelancer);
+        let expiring = contract.create_invoice(client, amount, 100, metadata, 60).unwrap();
+        let open = contract.create_invoice(client, amount, 100, metadata, 90).unwrap();
+        vm.set_block_timestamp(60);
+        vm.set_sender(client);
+        vm.set_value(amount);
+        assert_eq!(
+            contract.fund_invoice(expiring).unwrap_err(),
+            revert(ErrorCode::InvoiceExpired)
+        );
+        vm.set_value(U256::ZERO);
+        vm.set_sender(Address::from([0x03; 20]));
+        assert_eq!(
+            contract.prune_invoices(vec![invoice_id, expiring, open, U256::from(9)]),
+            1
+        );
+        assert_eq!(
+            contract.get_invoice(expiring),
+            (Address::ZERO, Address::ZERO, U256::ZERO, 0, B256::ZERO, 0, U256::ZERO)
+        );
+        assert_eq!(contract.get_invoice(invoice_id).6, job_id);
+        assert_eq!(contract.get_invoice(open).1, client);
+        let logs = vm.get_emitted_logs();
+        assert_eq!(logs.last().unwrap().0[0], INVOICE_PRUNED_TOPIC);
+        assert_eq!(contract.prune_invoices(vec![expiring]), 0);
+    }
+
+    #[test]
+    fn test_deposit_exact() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let client = vm.msg_sender();
+        let freelancer = Address::from([0x01; 20]);
+
+        assert!(contract.constructor(client).is_ok());
+        vm.set_balance(vm.contract_address(), U256::from(1_200));
+        vm.set_value(U256::from(900));
+        assert_eq!(
+            contract.deposit_exact(freelancer, 100, U256::from(1_000)).unwrap_err(),
+            revert(ErrorCode::InsufficientDeposit)
+        );
+
+        // The surplus is not escrowed but credited back to the client
+        vm.set_value(U256::from(1_200));
+        let job_id = contract.deposit_exact(freelancer, 100, U256::from(1_000)).unwrap();
+        let (_, _, _, amount, _, _, _) = contract.get_job(job_id).unwrap();
+        assert_eq!(amount, U256::from(1_000));
+        assert_eq!(contract.get_credit(client), U256::from(200));
+        let logs = vm.get_emitted_logs();
+        let credited = logs.last().unwrap();
+        assert_eq!(
+            EscrowEvent::decode(&credited.0, &credited.1),
+            Some(EscrowEvent::ExcessCredited(ExcessCredited {
+                job_id,
+                client,
+                excess: U256::from(200),
+            }))
+        );
+        assert!(contract.verify_solvency());
+
+        vm.set_value(U256::ZERO);
+        assert!(contract.withdraw_credit().is_ok());
+        assert_eq!(vm.balance(client), U256::from(200));
+        assert_eq!(contract.get_credit(client), U256::ZERO);
+        assert_eq!(contract.withdraw_credit().unwrap_err(), revert(ErrorCode::NothingToWithdraw));
+        assert!(contract.verify_solvency());
+    }
+
+    #[test]
+    fn test_job_cap() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let admin = vm.msg_sender();
+        let freelancer = Address::from([0x01; 20]);
+
+        assert!(contract.constructor(admin).is_ok());
+        vm.set_balance(vm.contract_address(), U256::from(300));
+        assert!(contract.set_default_job_cap(2).is_ok());
+        vm.set_sender(freelancer);
+        assert_eq!(contract.set_default_job_cap(5).unwrap_err(), revert(ErrorCode::OnlyAdmin));
+
+        // A freelancer can go below the default but not above it
+        assert!(contract.set_job_cap(1).is_ok());
+        assert_eq!(contract.get_job_cap(freelancer), (1, 0));
+        assert!(contract.set_job_cap(3).is_ok());
+        assert_eq!(contract.get_job_cap(freelancer), (2, 0));
+
+        vm.set_sender(admin);
+        vm.set_value(U256::from(100));
+        let first = contract.deposit(freelancer, 100, B256::ZERO).unwrap();
+        assert!(contract.deposit(freelancer, 100, B256::ZERO).is_ok());
+        assert_eq!(contract.get_job_cap(freelancer), (2, 2));
+        assert_eq!(
+            contract.deposit(freelancer, 100, B256::ZERO).unwrap_err(),
+            revert(ErrorCode::JobCapReached)
+        );
+
+        // Settling a job frees its place
+        vm.set_value(U256::ZERO);
+        assert!(contract.release(first, B256::ZERO).is_ok());
+        assert_eq!(contract.get_job_cap(freelancer), (2, 1));
+        vm.set_value(U256::from(100));
+        assert!(contract.deposit(freelancer, 100, B256::ZERO).is_ok());
+    }
+
+    #[test]
+    fn test_deposit_limits() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let admin = vm.msg_sender();
+        let client = Address::from([0x02; 20]);
+        let marketplace = Address::from([0x03; 20]);
+        let freelancer = Address::from([0x01; 20]);
+
+        assert!(contract.constructor(admin).is_ok());
+        vm.set_balance(vm.contract_address(), U256::from(1_000));
+        vm.set_block_timestamp(1_000);
+        vm.set_block_number(10);
+        assert!(contract.set_deposit_limits(0, 2).is_ok());
+        assert_eq!(setting::<(u64, u32)>(&contract, SETTING_DEPOSIT_LIMITS, Address::ZERO), (0, 2));
+        assert!(contract.set_deposit_limit_exempt(marketplace, true).is_ok());
+        assert!(setting::<(Address, bool)>(&contract, SETTING_DEPOSIT_LIMIT_EXEMPT, marketplace).1);
+        vm.set_sender(client);
+        assert_eq!(contract.set_deposit_limits(60, 0).unwrap_err(), revert(ErrorCode::OnlyAdmin));
+
+        // A client opens at most two jobs a block
+        vm.set_value(U256::from(100));
+        assert!(contract.deposit(freelancer, 100, B256::ZERO).is_ok());
+        assert!(contract.deposit(freelancer, 100, B256::ZERO).is_ok());
+        assert_eq!(
+            contract.deposit(freelancer, 100, B256::ZERO).unwrap_err(),
+            revert(ErrorCode::DepositLimitReached)
+        );
+        vm.set_block_number(11);
+        assert!(contract.deposit(freelancer, 100, B256::ZERO).is_ok());
+
+        // The cooldown spaces jobs out across blocks too
+        vm.set_sender(admin);
+        vm.set_value(U256::ZERO);
+        assert!(contract.set_deposit_limits(60, 0).is_ok());
+        vm.set_sender(client);
+        vm.set_value(U256::from(100));
+        vm.set_block_number(12);
+        assert_eq!(
+            contract.deposit(freelancer, 100, B256::ZERO).unwrap_err(),
+            revert(ErrorCode::DepositCooldownActive)
+        );
+        vm.set_block_timestamp(1_060);
+        assert!(contract.deposit(freelancer, 100, B256::ZERO).is_ok());
+
+        // Exempt marketplaces bypass both
+        vm.set_sender(marketplace);
+        for _ in 0..3 {
+            assert!(contract.deposit(freelancer, 100, B256::ZERO).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_period_stats() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let client = vm.msg_sender();
+        let freelancer = Address::from([0x01; 20]);
+
+        assert!(contract.constructor(client).is_ok());
+        vm.set_balance(vm.contract_address(), U256::from(150));
+        let today = vm.block_timestamp() / EPOCH_LENGTH;
+        vm.set_value(U256::from(100));
+        let first = contract.deposit(freelancer, 100, B256::ZERO).unwrap();
+        vm.set_value(U256::from(50));
+        let second = contract.deposit(freelancer, 2 * EPOCH_LENGTH, B256::ZERO).unwrap();
+        vm.set_value(U256::ZERO);
+        assert!(contract.release(first, B256::ZERO).is_ok());
+
+        // Activity lands in the epoch it happened in
+        vm.set_block_timestamp((today + 1) * EPOCH_LENGTH);
+        assert!(contract.refund(second, B256::ZERO).is_ok());
+        assert_eq!(
+            contract.get_period_stats(today),
+            (U256::from(2), U256::from(150), U256::from(100), U256::ZERO)
+        );
+        assert_eq!(
+            contract.get_period_stats(today + 1),
+            (U256::ZERO, U256::ZERO, U256::ZERO, U256::from(50))
+        );
+    }
+
+    #[test]
+    fn test_jobs_range() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let client = vm.msg_sender();
+        let freelancer = Address::from([0x01; 20]);
+
+        assert!(contract.constructor(client).is_ok());
+        for amount in [100, 200, 300] {
+            vm.set_value(U256::from(amount));
+            assert!(contract.deposit(freelancer, 100, B256::ZERO).is_ok());
+        }
+        vm.set_balance(vm.contract_address(), U256::from(600));
+        vm.set_value(U256::ZERO);
+        assert!(contract.release(U256::from(2), B256::ZERO).is_ok());
+
+        // The window is cut short at the newest job
+        let (clients, freelancers, amounts, deadlines, released, refunded) =
+            contract.get_jobs_range(U256::from(2), 5).unwrap();
+        assert_eq!(clients, vec![client; 2]);
+        assert_eq!(freelancers, vec![freelancer; 2]);
+        assert_eq!(amounts, vec![U256::from(200), U256::from(300)]);
+        assert_eq!(deadlines, vec![vm.block_timestamp() + 100; 2]);
+        assert_eq!(released, vec![true, false]);
+        assert_eq!(refunded, vec![false, false]);
+        assert!(contract.get_jobs_range(U256::from(4), 5).unwrap().0.is_empty());
+
+        assert_eq!(contract.get_jobs_range(U256::ZERO, 5).unwrap_err(), revert(ErrorCode::InvalidStart));
+        assert_eq!(
+            contract.get_jobs_range(U256::from(1), MAX_JOBS_RANGE + 1).unwrap_err(),
+            revert(ErrorCode::RangeTooLarge)
+        );
     }
-}
 
-sol! {
-    event Deposited(uint256 indexed job_id, address indexed client, address indexed freelancer, uint256 amount);
-    event Released(uint256 indexed job_id, uint256 amount);
-    event Refunded(uint256 indexed job_id, uint256 amount);
-    event AutoReleased(uint256 indexed job_id, uint256 amount);
-    event EmergencyRefunded(uint256 indexed job_id, address indexed admin);
-    event PauseToggled(bool paused);
-    event OwnershipTransferred(address indexed old_admin, address indexed new_admin);
-}
+    #[test]
+    fn test_query_jobs() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let client = vm.msg_sender();
+        let alice = Address::from([0x01; 20]);
+        let bob = Address::from([0x02; 20]);
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use stylus_sdk::testing::*;
-    use alloy_primitives::{hex, Address, U256, B256};
+        assert!(contract.constructor(client).is_ok());
+        for freelancer in [alice, bob, alice, bob] {
+            send_value(&vm, U256::from(100));
+            assert!(contract.deposit(freelancer, 100, B256::ZERO).is_ok());
+        }
+        vm.set_value(U256::ZERO);
+        assert!(contract.release(U256::from(1), B256::ZERO).is_ok());
+        assert!(contract.refund(U256::from(2), B256::ZERO).is_ok());
+        // A replaced freelancer no longer matches the job
+        assert!(contract.reassign_freelancer(U256::from(3), bob).is_ok());
+
+        // Filtering by status pages through every job
+        let page = contract.query_jobs(JOB_STATUS_ACTIVE, Address::ZERO, U256::ZERO, 1).unwrap();
+        assert_eq!(page.0, vec![U256::from(3)]);
+        assert_eq!(page.2, vec![bob]);
+        assert_eq!(page.5, vec![JOB_STATUS_ACTIVE]);
+        assert_eq!(page.6, U256::from(4));
+        let page = contract.query_jobs(JOB_STATUS_ACTIVE, Address::ZERO, page.6, 5).unwrap();
+        assert_eq!(page.0, vec![U256::from(4)]);
+        assert_eq!(page.6, U256::ZERO);
+        let page = contract.query_jobs(JOB_STATUS_ANY, Address::ZERO, U256::ZERO, 5).unwrap();
+        assert_eq!(
+            page.5,
+            vec![JOB_STATUS_RELEASED, JOB_STATUS_REFUNDED, JOB_STATUS_ACTIVE, JOB_STATUS_ACTIVE]
+        );
+        assert_eq!(page.1, vec![client; 4]);
+        assert_eq!(page.3, vec![U256::from(100); 4]);
+
+        // Filtering by party reads its own index, listing the party's jobs
+        let page = contract.query_jobs(JOB_STATUS_ANY, alice, U256::ZERO, 5).unwrap();
+        assert_eq!(page.0, vec![U256::from(1)]);
+        // in the order it became party to them
+        let page = contract.query_jobs(JOB_STATUS_ACTIVE, bob, U256::ZERO, 1).unwrap();
+        assert_eq!(page.0, vec![U256::from(4)]);
+        assert_eq!(page.6, U256::from(2));
+        let page = contract.query_jobs(JOB_STATUS_ACTIVE, bob, page.6, 5).unwrap();
+        assert_eq!(page.0, vec![U256::from(3)]);
+        assert_eq!(page.6, U256::ZERO);
+        let page = contract.query_jobs(JOB_STATUS_REFUNDED, client, U256::ZERO, 5).unwrap();
+        assert_eq!(page.0, vec![U256::from(2)]);
+
+        // Without a party a call reads at most MAX_JOBS_RANGE IDs, handing
+        // back a cursor even when nothing in them matched
+        for _ in 0..MAX_JOBS_RANGE {
+            send_value(&vm, U256::from(100));
+            assert!(contract.deposit(alice, 100, B256::ZERO).is_ok());
+        }
+        vm.set_value(U256::ZERO);
+        let page = contract.query_jobs(JOB_STATUS_SWEPT, Address::ZERO, U256::ZERO, 5).unwrap();
+        assert!(page.0.is_empty());
+        assert_eq!(page.6, U256::from(MAX_JOBS_RANGE + 1));
+        let page = contract.query_jobs(JOB_STATUS_SWEPT, Address::ZERO, page.6, 5).unwrap();
+        assert!(page.0.is_empty());
+        assert_eq!(page.6, U256::ZERO);
+
+        assert_eq!(
+            contract.query_jobs(JOB_STATUS_SWEPT + 1, Address::ZERO, U256::ZERO, 5).unwrap_err(),
+            revert(ErrorCode::InvalidStatus)
+        );
+        assert_eq!(
+            contract.query_jobs(JOB_STATUS_ANY, Address::ZERO, U256::ZERO, 0).unwrap_err(),
+            revert(ErrorCode::InvalidLimit)
+        );
+    }
 
     #[test]
-    fn test_initialize() {
+    fn test_job_categories() {
         let vm = TestVM::default();
         let mut contract = Escrow::from(&vm);
-        let sender = vm.msg_sender();
+        let client = vm.msg_sender();
+        let alice = Address::from([0x01; 20]);
+        let design = B256::from([0xd5; 32]);
+        let logo = B256::from([0x10; 32]);
 
-        // Initialize the contract
-        assert!(contract.initialize().is_ok());
-        assert_eq!(contract.admin.get(), sender);
-        assert_eq!(contract.is_paused(), false);
-        assert_eq!(contract.get_total_jobs(), U256::from(0));
+        assert!(contract.constructor(client).is_ok());
+        for (category, tag) in [(1, design), (2, B256::ZERO), (1, logo), (0, B256::ZERO)] {
+            send_value(&vm, U256::from(100));
+            assert!(contract.deposit_categorized(alice, 100, B256::ZERO, category, tag).is_ok());
+        }
+        vm.set_value(U256::ZERO);
+
+        // The category and tag are echoed in the event
+        let logs = vm.get_emitted_logs();
+        let (topics, data) = logs.last().unwrap();
+        let Some(EscrowEvent::Deposited(event)) = EscrowEvent::decode(topics, data) else {
+            panic!("Expected Deposited");
+        };
+        assert_eq!((event.category, event.tag), (0, B256::ZERO));
+        let (topics, data) = &logs[logs.len() - 2];
+        let Some(EscrowEvent::Deposited(event)) = EscrowEvent::decode(topics, data) else {
+            panic!("Expected Deposited");
+        };
+        assert_eq!((event.category, event.tag), (1, logo));
+
+        assert_eq!(contract.get_job_category(U256::from(1)).unwrap(), (1, design));
+        assert_eq!(contract.get_job_category(U256::from(4)).unwrap(), (0, B256::ZERO));
+        assert_eq!(contract.get_job_category(U256::from(5)).unwrap_err(), revert(ErrorCode::JobDoesNotExist));
+        assert_eq!(contract.get_category_stats(1), (U256::from(2), U256::from(200)));
+        assert_eq!(contract.get_category_stats(2), (U256::from(1), U256::from(100)));
+        assert_eq!(contract.get_category_stats(3), (U256::ZERO, U256::ZERO));
+
+        // A zero tag matches every job in the category
+        let page = contract.query_jobs_by_category(1, B256::ZERO, JOB_STATUS_ANY, U256::ZERO, 5).unwrap();
+        assert_eq!(page.0, vec![U256::from(1), U256::from(3)]);
+        let page = contract.query_jobs_by_category(1, logo, JOB_STATUS_ANY, U256::ZERO, 5).unwrap();
+        assert_eq!(page.0, vec![U256::from(3)]);
+        assert!(contract.release(U256::from(1), B256::ZERO).is_ok());
+        let page = contract.query_jobs_by_category(1, B256::ZERO, JOB_STATUS_ACTIVE, U256::ZERO, 5).unwrap();
+        assert_eq!(page.0, vec![U256::from(3)]);
+        assert_eq!(
+            contract.query_jobs_by_category(1, B256::ZERO, JOB_STATUS_ANY, U256::ZERO, 0).unwrap_err(),
+            revert(ErrorCode::InvalidLimit)
+        );
     }
 
     #[test]
-    fn test_deposit() {
+    fn test_jobs_expiring_before() {
         let vm = TestVM::default();
         let mut contract = Escrow::from(&vm);
         let client = vm.msg_sender();
         let freelancer = Address::from([0x01; 20]);
-        let amount = U256::from(1_000_000_000_000_000_000_u64); // 1 ETH
-        let duration = 86_400_u64; // 1 day in seconds
 
-        // Initialize contract
-        assert!(contract.initialize().is_ok());
+        assert!(contract.constructor(client).is_ok());
+        for duration in [100, 500, 200, 300] {
+            vm.set_value(U256::from(100));
+            assert!(contract.deposit(freelancer, duration, B256::ZERO).is_ok());
+        }
+        // Settled and still-funding jobs are skipped
+        vm.set_balance(vm.contract_address(), U256::from(400));
+        vm.set_value(U256::ZERO);
+        assert!(contract.release(U256::from(3), B256::ZERO).is_ok());
+        assert!(contract.create_job(freelancer, 100, U256::from(100), 50).is_ok());
 
-        // Set msg.value for deposit
+        let before = vm.block_timestamp() + 400;
+        let (ids, cursor) = contract.get_jobs_expiring_before(before, U256::ZERO, 1).unwrap();
+        assert_eq!(ids, vec![U256::from(1)]);
+        assert_eq!(cursor, U256::from(1));
+        let (ids, cursor) = contract.get_jobs_expiring_before(before, cursor, 5).unwrap();
+        assert_eq!(ids, vec![U256::from(4)]);
+        assert_eq!(cursor, U256::ZERO);
+
+        // A closed job's place goes to the last active one
+        let active = |contract: &Escrow| contract.active_jobs_from(0).map(|(_, job_id)| job_id).collect::<Vec<_>>();
+        assert_eq!(active(&contract), [1, 2, 4, 5].map(U256::from));
+        assert!(contract.release(U256::from(1), B256::ZERO).is_ok());
+        assert_eq!(active(&contract), [5, 2, 4].map(U256::from));
+        assert_eq!(contract.get_jobs_expiring_before(before, U256::ZERO, 5).unwrap().0, vec![U256::from(4)]);
+
+        assert_eq!(
+            contract.get_jobs_expiring_before(before, U256::ZERO, 0).unwrap_err(),
+            revert(ErrorCode::InvalidLimit)
+        );
+    }
+
+    #[test]
+    fn test_protocol_fee() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let admin = vm.msg_sender();
+        let client = Address::from([0x01; 20]);
+        let freelancer = Address::from([0x02; 20]);
+        let amount = U256::from(1_000);
+
+        assert!(contract.constructor(admin).is_ok());
+        assert_eq!(
+            contract.set_protocol_fee(MAX_PROTOCOL_FEE_BPS + 1).unwrap_err(),
+            revert(ErrorCode::InvalidFee)
+        );
+        assert!(contract.set_protocol_fee(500).is_ok());
+        assert_eq!(setting::<(u16,)>(&contract, SETTING_PROTOCOL_FEE, Address::ZERO).0, 500);
+        assert_eq!(
+            contract.quote_fee(client, amount, Address::ZERO, Address::ZERO, 0),
+            Ok((U256::from(50), U256::ZERO, U256::from(950)))
+        );
+        assert_eq!(
+            contract.quote_fee(client, amount, freelancer, Address::ZERO, 0).unwrap_err(),
+            revert(ErrorCode::UnsupportedToken)
+        );
+        vm.set_sender(client);
+        assert_eq!(contract.set_protocol_fee(0).unwrap_err(), revert(ErrorCode::OnlyFeeManager));
+
+        // The fee comes off the payout and accrues apart from any credit
+        send_value(&vm, amount);
+        let job_id = contract.deposit(freelancer, 100, B256::ZERO).unwrap();
+        vm.set_value(U256::ZERO);
+        assert!(contract.release(job_id, B256::ZERO).is_ok());
+        assert_eq!(vm.balance(freelancer), U256::from(950));
+        assert_eq!(contract.get_accrued_fees(), U256::from(50));
+        assert_eq!(contract.get_credit(admin), U256::ZERO);
+        let logs = vm.get_emitted_logs();
+        assert_eq!(
+            EscrowEvent::decode(&logs[2].0, &logs[2].1),
+            Some(EscrowEvent::FeeCharged(FeeCharged {
+                job_id,
+                recipient: vm.contract_address(),
+                fee: U256::from(50),
+            }))
+        );
+        assert!(contract.verify_solvency());
+
+        // Only the fee manager withdraws, and only what has accrued
+        let treasury = Address::from([0x04; 20]);
+        assert_eq!(
+            contract.withdraw_fees(treasury, U256::from(50)).unwrap_err(),
+            revert(ErrorCode::OnlyFeeManager)
+        );
+        vm.set_sender(admin);
+        assert_eq!(
+            contract.withdraw_fees(Address::ZERO, U256::from(50)).unwrap_err(),
+            revert(ErrorCode::InvalidRecipient)
+        );
+        assert_eq!(
+            contract.withdraw_fees(treasury, U256::from(51)).unwrap_err(),
+            revert(ErrorCode::InvalidAmount)
+        );
+
+        // A shortfall in the balance is taken out of the fees, never principal
+        vm.set_sender(client);
+        send_value(&vm, amount);
+        contract.deposit(freelancer, 100, B256::ZERO).unwrap();
+        vm.set_value(U256::ZERO);
+        vm.set_sender(admin);
+        let escrow = vm.contract_address();
+        vm.set_balance(escrow, amount + U256::from(20));
+        assert_eq!(
+            contract.withdraw_fees(treasury, U256::from(50)).unwrap_err(),
+            revert(ErrorCode::ExceedsSurplus)
+        );
+        let balances = Balances::track(&vm, &[treasury, escrow]);
+        assert!(contract.withdraw_fees(treasury, U256::from(20)).is_ok());
+        balances.assert_deltas(&[(treasury, 20), (escrow, -20)]);
+        assert_eq!(contract.get_accrued_fees(), U256::from(30));
+        let logs = vm.get_emitted_logs();
+        assert_eq!(
+            EscrowEvent::decode(&logs[logs.len() - 1].0, &logs[logs.len() - 1].1),
+            Some(EscrowEvent::FeesWithdrawn(FeesWithdrawn {
+                to: treasury,
+                amount: U256::from(20),
+                remaining: U256::from(30),
+            }))
+        );
+    }
+
+    #[test]
+    fn test_settlement_rebate() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let admin = vm.msg_sender();
+        let client = Address::from([0x01; 20]);
+        let freelancer = Address::from([0x02; 20]);
+        let keeper = Address::from([0x03; 20]);
+        let arbitrator = Address::from([0x0a; 20]);
+        let amount = U256::from(1_000);
+        let window = 86_400_u64;
+
+        assert!(contract.constructor(admin).is_ok());
+        assert!(contract.set_protocol_fee(500).is_ok());
+        assert!(contract.set_arbitration_policy(arbitrator, 86_400, 5_000).is_ok());
+        vm.set_sender(client);
+        assert_eq!(
+            contract.set_settlement_rebate(U256::from(20)).unwrap_err(),
+            revert(ErrorCode::OnlyFeeManager)
+        );
+        vm.set_sender(admin);
+        assert!(contract.set_settlement_rebate(U256::from(20)).is_ok());
+        assert_eq!(setting::<(U256,)>(&contract, SETTING_SETTLEMENT_REBATE, Address::ZERO).0, U256::from(20));
+
+        // A keeper finalizing a queued release gets the rebate out of the fee
+        vm.set_sender(client);
+        send_value(&vm, amount);
+        let job_id = contract.deposit_with_clawback(freelancer, 100, window).unwrap();
+        vm.set_value(U256::ZERO);
+        assert!(contract.release(job_id, B256::ZERO).is_ok());
+        vm.set_block_timestamp(window);
+        vm.set_sender(keeper);
+        let balances = Balances::track(&vm, &[freelancer, keeper]);
+        assert!(contract.finalize_release(job_id).is_ok());
+        balances.assert_deltas(&[(freelancer, 950), (keeper, 20)]);
+        assert_eq!(contract.get_accrued_fees(), U256::from(30));
+        let logs = vm.get_emitted_logs();
+        assert_eq!(
+            EscrowEvent::decode(&logs[logs.len() - 2].0, &logs[logs.len() - 2].1),
+            Some(EscrowEvent::SettlementRebated(SettlementRebated {
+                job_id,
+                caller: keeper,
+                amount: U256::from(20),
+            }))
+        );
+        assert!(contract.verify_solvency());
+
+        // The rebate never exceeds the fee the payout was charged
+        vm.set_sender(admin);
+        assert!(contract.set_settlement_rebate(U256::from(100)).is_ok());
+        vm.set_sender(client);
+        send_value(&vm, amount);
+        let job_id = contract.deposit(freelancer, 100, B256::ZERO).unwrap();
+        vm.set_value(U256::ZERO);
+        vm.set_block_timestamp(window + 100);
+        vm.set_sender(freelancer);
+        let balances = Balances::track(&vm, &[freelancer]);
+        assert!(contract.auto_release(job_id, B256::ZERO).is_ok());
+        balances.assert_deltas(&[(freelancer, 1_000)]);
+        assert_eq!(contract.get_accrued_fees(), U256::from(30));
+        assert!(contract.verify_solvency());
+    }
+
+    #[test]
+    fn test_platform_fee() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let admin = vm.msg_sender();
+        let client = Address::from([0x01; 20]);
+        let freelancer = Address::from([0x02; 20]);
+        let platform = Address::from([0x05; 20]);
+        let amount = U256::from(1_000);
+
+        assert!(contract.constructor(admin).is_ok());
+        assert!(contract.set_protocol_fee(500).is_ok());
+
+        // The platform and its capped rate are fixed at deposit
+        vm.set_sender(client);
+        send_value(&vm, amount);
+        assert_eq!(
+            contract.deposit_with_platform(freelancer, 100, B256::ZERO, Address::ZERO, 200).unwrap_err(),
+            revert(ErrorCode::InvalidPlatformAddress)
+        );
+        assert_eq!(
+            contract
+                .deposit_with_platform(freelancer, 100, B256::ZERO, platform, MAX_PLATFORM_FEE_BPS + 1)
+                .unwrap_err(),
+            revert(ErrorCode::InvalidPlatformFee)
+        );
+        let job_id = contract.deposit_with_platform(freelancer, 100, B256::ZERO, platform, 200).unwrap();
+        vm.set_value(U256::ZERO);
+        assert_eq!(contract.get_platform_fee(job_id), (platform, 200));
+
+        // A quote through the platform matches what the job will be charged
+        assert_eq!(
+            contract.quote_fee(client, amount, Address::ZERO, platform, 200),
+            Ok((U256::from(50), U256::from(20), U256::from(930)))
+        );
+        assert_eq!(
+            contract.quote_fee(client, amount, Address::ZERO, Address::ZERO, 200).unwrap_err(),
+            revert(ErrorCode::InvalidPlatformAddress)
+        );
+        assert_eq!(
+            contract
+                .quote_fee(client, amount, Address::ZERO, platform, MAX_PLATFORM_FEE_BPS + 1)
+                .unwrap_err(),
+            revert(ErrorCode::InvalidPlatformFee)
+        );
+        assert_eq!(
+            contract.preview_release(job_id),
+            Ok((U256::from(930), U256::ZERO, U256::ZERO, U256::from(50), U256::from(20), U256::ZERO))
+        );
+
+        // Both fees come off the payout; the platform's is credited to it
+        assert!(contract.release(job_id, B256::ZERO).is_ok());
+        assert_eq!(vm.balance(freelancer), U256::from(930));
+        assert_eq!(contract.get_accrued_fees(), U256::from(50));
+        assert_eq!(contract.get_credit(platform), U256::from(20));
+        let logs = vm.get_emitted_logs();
+        assert!(logs.iter().any(|(topics, data)| EscrowEvent::decode(topics, data)
+            == Some(EscrowEvent::FeeCharged(FeeCharged {
+                job_id,
+                recipient: platform,
+                fee: U256::from(20),
+            }))));
+        assert!(contract.verify_solvency());
+
+        vm.set_sender(platform);
+        let balances = Balances::track(&vm, &[platform]);
+        assert!(contract.withdraw_credit().is_ok());
+        balances.assert_deltas(&[(platform, 20)]);
+
+        // Refunds pay no platform fee
+        vm.set_sender(client);
+        send_value(&vm, amount);
+        let job_id = contract.deposit_with_platform(freelancer, 100, B256::ZERO, platform, 200).unwrap();
+        vm.set_value(U256::ZERO);
+        assert!(contract.refund(job_id, B256::ZERO).is_ok());
+        assert_eq!(contract.get_credit(platform), U256::ZERO);
+    }
+
+    #[test]
+    fn test_fee_tiers() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let admin = vm.msg_sender();
+        let manager = Address::from([0x03; 20]);
+        let client = Address::from([0x01; 20]);
+        let freelancer = Address::from([0x02; 20]);
+
+        assert!(contract.constructor(admin).is_ok());
+        assert!(contract.set_fee_manager(manager).is_ok());
+        assert_eq!(setting::<(Address,)>(&contract, SETTING_FEE_MANAGER, Address::ZERO).0, manager);
+        assert_eq!(
+            contract.set_fee_tiers(vec![], vec![]).unwrap_err(),
+            revert(ErrorCode::OnlyFeeManager)
+        );
+
+        vm.set_sender(manager);
+        assert_eq!(
+            contract.set_fee_tiers(vec![U256::from(1_000), U256::from(1_000)], vec![200, 100]),
+            Err(revert(ErrorCode::InvalidTiers))
+        );
+        assert_eq!(
+            contract.set_fee_tiers(vec![U256::from(1_000)], vec![MAX_PROTOCOL_FEE_BPS + 1]),
+            Err(revert(ErrorCode::InvalidFee))
+        );
+        assert!(contract.set_protocol_fee(500).is_ok());
+        let min_amounts = vec![U256::from(1_000), U256::from(10_000)];
+        assert!(contract.set_fee_tiers(min_amounts.clone(), vec![200, 100]).is_ok());
+        assert_eq!(setting::<(Vec<U256>, Vec<u16>)>(&contract, SETTING_FEE_TIERS, Address::ZERO), (min_amounts, vec![200, 100]));
+        assert_eq!(
+            contract.quote_fee(client, U256::from(999), Address::ZERO, Address::ZERO, 0),
+            Ok((U256::from(49), U256::ZERO, U256::from(950)))
+        );
+        assert_eq!(
+            contract.quote_fee(client, U256::from(20_000), Address::ZERO, Address::ZERO, 0),
+            Ok((U256::from(200), U256::ZERO, U256::from(19_800)))
+        );
+
+        // A job keeps the tier it was opened at when the tiers change
+        vm.set_sender(client);
+        vm.set_balance(vm.contract_address(), U256::from(5_000));
+        vm.set_value(U256::from(5_000));
+        let job_id = contract.deposit(freelancer, 100, B256::ZERO).unwrap();
+        vm.set_value(U256::ZERO);
+        assert_eq!(contract.get_job_fee(job_id), 200);
+        vm.set_sender(manager);
+        assert!(contract.set_fee_tiers(vec![], vec![]).is_ok());
+        assert_eq!(setting::<(Vec<U256>, Vec<u16>)>(&contract, SETTING_FEE_TIERS, Address::ZERO), (vec![], vec![]));
+
+        vm.set_sender(client);
+        assert!(contract.release(job_id, B256::ZERO).is_ok());
+        assert_eq!(vm.balance(freelancer), U256::from(4_900));
+        assert_eq!(contract.get_accrued_fees(), U256::from(100));
+
+        // The fee manager, not the admin, withdraws the fees
+        vm.set_sender(admin);
+        assert_eq!(
+            contract.withdraw_fees(admin, U256::from(100)).unwrap_err(),
+            revert(ErrorCode::OnlyFeeManager)
+        );
+        vm.set_sender(manager);
+        assert!(contract.withdraw_fees(manager, U256::from(100)).is_ok());
+        assert_eq!(vm.balance(manager), U256::from(100));
+        assert_eq!(contract.get_accrued_fees(), U256::ZERO);
+    }
+
+    #[test]
+    fn test_volume_discounts() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let admin = vm.msg_sender();
+        let client = Address::from([0x01; 20]);
+        let freelancer = Address::from([0x02; 20]);
+        let partner = Address::from([0x03; 20]);
+        let amount = U256::from(10_000);
+
+        assert!(contract.constructor(admin).is_ok());
+        assert!(contract.set_protocol_fee(500).is_ok());
+        assert_eq!(
+            contract.set_volume_discounts(vec![amount], vec![BASIS_POINTS + 1]),
+            Err(revert(ErrorCode::InvalidDiscount))
+        );
+        assert!(contract.set_volume_discounts(vec![amount], vec![5_000]).is_ok());
+        assert_eq!(setting::<(Vec<U256>, Vec<u16>)>(&contract, SETTING_VOLUME_DISCOUNTS, Address::ZERO), (vec![amount], vec![5_000]));
+        assert!(contract.set_fee_exempt(partner, true).is_ok());
+        assert!(setting::<(Address, bool)>(&contract, SETTING_FEE_EXEMPT, partner).1);
+        assert_eq!(
+            contract.quote_fee(partner, amount, Address::ZERO, Address::ZERO, 0),
+            Ok((U256::ZERO, U256::ZERO, amount))
+        );
+
+        // The discount applies once released volume reaches it, to new jobs only
+        vm.set_sender(client);
+        assert_eq!(contract.set_fee_exempt(client, true), Err(revert(ErrorCode::OnlyAdmin)));
+        vm.set_balance(vm.contract_address(), amount * U256::from(2));
         vm.set_value(amount);
+        let first = contract.deposit(freelancer, 100, B256::ZERO).unwrap();
+        let second = contract.deposit(freelancer, 100, B256::ZERO).unwrap();
+        vm.set_value(U256::ZERO);
+        assert!(contract.release(first, B256::ZERO).is_ok());
+        assert_eq!(contract.get_client_volume(client), amount);
+        assert_eq!(contract.get_job_fee(second), 500);
+        assert_eq!(
+            contract.quote_fee(client, amount, Address::ZERO, Address::ZERO, 0),
+            Ok((U256::from(250), U256::ZERO, U256::from(9_750)))
+        );
 
-        // Test successful deposit
-        let result = contract.deposit(freelancer, duration);
-        assert!(result.is_ok());
-        let job_id = result.unwrap();
-        assert_eq!(job_id, U256::from(1));
-        assert_eq!(contract.get_total_jobs(), U256::from(1));
-        assert_eq!(contract.get_active_jobs(), vec![U256::from(1)]);
+        vm.set_value(amount);
+        let third = contract.deposit(freelancer, 100, B256::ZERO).unwrap();
+        vm.set_value(U256::ZERO);
+        assert_eq!(contract.get_job_fee(third), 250);
+        assert_eq!(
+            contract.preview_release(third),
+            Ok((U256::from(9_750), U256::ZERO, U256::ZERO, U256::from(250), U256::ZERO, U256::ZERO))
+        );
+    }
 
-        // Verify job details
-        let (id, job_client, job_freelancer, job_amount, deadline, released, refunded) =
-            contract.get_job(job_id);
-        assert_eq!(id, job_id);
-        assert_eq!(job_client, client);
-        assert_eq!(job_freelancer, freelancer);
-        assert_eq!(job_amount, amount);
-        assert_eq!(deadline, vm.block_timestamp() + duration);
-        assert_eq!(released, false);
-        assert_eq!(refunded, false);
+    #[test]
+    fn test_dispute_evidence() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let client = Address::from([0x01; 20]);
+        let freelancer = Address::from([0x02; 20]);
+        let stranger = Address::from([0x03; 20]);
+        let amount = U256::from(1_000);
+        let evidence = B256::from([0xee; 32]);
 
-        // Verify Deposited event
+        assert!(contract.constructor(vm.msg_sender()).is_ok());
+        vm.set_sender(client);
+        vm.set_balance(vm.contract_address(), amount);
+        vm.set_value(amount);
+        let job_id = contract.deposit(freelancer, 100, B256::ZERO).unwrap();
+        vm.set_value(U256::ZERO);
+        assert_eq!(
+            contract.submit_evidence(job_id, evidence),
+            Err(revert(ErrorCode::NoOpenDispute))
+        );
+
+        vm.set_sender(stranger);
+        assert_eq!(
+            contract.open_dispute(job_id, B256::ZERO),
+            Err(revert(ErrorCode::OnlyJobPartiesCanDispute))
+        );
+        vm.set_sender(freelancer);
+        vm.set_block_timestamp(50);
+        assert!(contract.open_dispute(job_id, B256::ZERO).is_ok());
+        assert_eq!(contract.get_dispute(job_id), (freelancer, 50, Address::ZERO, 0, 0, true));
+        assert_eq!(
+            contract.open_dispute(job_id, B256::ZERO),
+            Err(revert(ErrorCode::DisputeAlreadyOpened))
+        );
+
+        // Both parties add to one trail, in order
+        assert!(contract.submit_evidence(job_id, evidence).is_ok());
+        vm.set_sender(client);
+        vm.set_block_timestamp(60);
+        assert!(contract.submit_evidence(job_id, B256::from([0xcc; 32])).is_ok());
+        assert_eq!(
+            contract.submit_evidence(job_id, B256::ZERO),
+            Err(revert(ErrorCode::InvalidEvidence))
+        );
+        vm.set_sender(stranger);
+        assert_eq!(
+            contract.submit_evidence(job_id, evidence),
+            Err(revert(ErrorCode::OnlyJobPartiesCanSubmitEvidence))
+        );
+        assert_eq!(
+            contract.get_evidence(job_id),
+            (vec![evidence, B256::from([0xcc; 32])], vec![freelancer, client], vec![50, 60])
+        );
+        let logs = vm.get_emitted_logs();
+        let last = logs.last().unwrap();
+        assert_eq!(
+            EscrowEvent::decode(&last.0, &last.1),
+            Some(EscrowEvent::EvidenceSubmitted(EvidenceSubmitted {
+                job_id,
+                submitter: client,
+                index: U256::from(1),
+                evidence_hash: B256::from([0xcc; 32]),
+            }))
+        );
+
+        // Settling the job ends the dispute but keeps its evidence
+        vm.set_sender(client);
+        assert!(contract.refund(job_id, B256::ZERO).is_ok());
+        assert_eq!(contract.get_dispute(job_id), (freelancer, 50, Address::ZERO, 0, 0, false));
+        assert_eq!(contract.get_evidence(job_id).0.len(), 2);
+        assert_eq!(
+            contract.submit_evidence(job_id, evidence),
+            Err(revert(ErrorCode::NoOpenDispute))
+        );
+    }
+
+    #[test]
+    fn test_dispute_ruling() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let client = Address::from([0x01; 20]);
+        let freelancer = Address::from([0x02; 20]);
+        let arbitrator = Address::from([0x03; 20]);
+        let amount = U256::from(1_000);
+
+        assert!(contract.constructor(vm.msg_sender()).is_ok());
+        assert_eq!(
+            contract.set_arbitration_policy(arbitrator, 0, 5_000),
+            Err(revert(ErrorCode::InvalidRulingPeriod))
+        );
+        assert!(contract.set_arbitration_policy(arbitrator, 100, 5_000).is_ok());
+        assert_eq!(setting::<(Address, u64, u16)>(&contract, SETTING_ARBITRATION_POLICY, Address::ZERO), (arbitrator, 100, 5_000));
+
+        vm.set_sender(client);
+        vm.set_balance(vm.contract_address(), amount * U256::from(2));
+        vm.set_value(amount);
+        let ruled = contract.deposit(freelancer, 1_000, B256::ZERO).unwrap();
+        let defaulted = contract.deposit(freelancer, 1_000, B256::ZERO).unwrap();
+        vm.set_value(U256::ZERO);
+        vm.set_block_timestamp(10);
+        assert!(contract.open_dispute(ruled, B256::ZERO).is_ok());
+        assert!(contract.open_dispute(defaulted, B256::ZERO).is_ok());
+        assert_eq!(
+            contract.get_dispute(ruled),
+            (client, 10, arbitrator, 110, 5_000, true)
+        );
+
+        // The arbitrator splits the job within the ruling period
+        assert_eq!(contract.rule(ruled, 3_000), Err(revert(ErrorCode::OnlyArbitrator)));
+        vm.set_sender(arbitrator);
+        assert_eq!(contract.rule(ruled, BASIS_POINTS + 1), Err(revert(ErrorCode::InvalidSplit)));
+        assert!(contract.rule(ruled, 3_000).is_ok());
+        assert_eq!(vm.balance(freelancer), U256::from(300));
+        assert_eq!(vm.balance(client), U256::from(700));
+        assert!(contract.get_job(ruled).unwrap().5);
+        assert_eq!(contract.rule(ruled, 3_000), Err(revert(ErrorCode::NoOpenDispute)));
+
+        // Past the period, the arbitrator is out and either party can settle
+        vm.set_sender(freelancer);
+        assert_eq!(
+            contract.resolve_by_default(defaulted),
+            Err(revert(ErrorCode::RulingPeriodNotOver))
+        );
+        vm.set_block_timestamp(110);
+        vm.set_sender(arbitrator);
+        assert_eq!(contract.rule(defaulted, 0), Err(revert(ErrorCode::RulingPeriodOver)));
+        assert_eq!(
+            contract.resolve_by_default(defaulted),
+            Err(revert(ErrorCode::OnlyJobPartiesCanResolve))
+        );
+        vm.set_sender(freelancer);
+        assert!(contract.resolve_by_default(defaulted).is_ok());
+        assert_eq!(vm.balance(freelancer), U256::from(800));
+        assert_eq!(vm.balance(client), U256::from(1_200));
         let logs = vm.get_emitted_logs();
-        assert_eq!(logs.len(), 1);
-        let event_signature: B256 = hex!(
-            "4a6f7a1c4c9c9a6d7e4c6d4b7b1b5e6f2a3b4c5d6e7f8a9b0c1d2e3f4a5b6c7"
-        ).into(); // Precomputed keccak-256 of Deposited event
-        assert_eq!(logs[0].0[0], event_signature);
-        assert_eq!(Address::from_slice(&logs[0].0[1].to_vec()[12..]), client);
-        assert_eq!(Address::from_slice(&logs[0].0[2].to_vec()[12..]), freelancer);
+        let last = logs.last().unwrap();
+        assert_eq!(
+            EscrowEvent::decode(&last.0, &last.1),
+            Some(EscrowEvent::DisputeResolved(DisputeResolved {
+                job_id: defaulted,
+                resolver: freelancer,
+                freelancer_amount: U256::from(500),
+                client_amount: U256::from(500),
+                by_default: true,
+            }))
+        );
+        assert!(contract.verify_solvency());
     }
 
     #[test]
-    fn test_deposit_invalid_inputs() {
+    fn test_job_arbitrator() {
         let vm = TestVM::default();
         let mut contract = Escrow::from(&vm);
-        let freelancer = Address::from([0x01; 20]);
-        let duration = 86_400_u64; // 1 day
+        let admin = vm.msg_sender();
+        let client = Address::from([0x01; 20]);
+        let freelancer = Address::from([0x02; 20]);
+        let global = Address::from([0x03; 20]);
+        let chosen = Address::from([0x04; 20]);
+        let other = Address::from([0x05; 20]);
+        let amount = U256::from(1_000);
 
-        // Initialize contract
-        assert!(contract.initialize().is_ok());
+        assert!(contract.constructor(admin).is_ok());
+        assert!(contract.set_arbitration_policy(global, 100, 5_000).is_ok());
 
-        // Test deposit with zero amount
-        vm.set_value(U256::from(0));
+        // The admin lists arbitrators, and unlisting moves the last one down
+        for arbitrator in [chosen, other] {
+            let data = (arbitrator, true).abi_encode_params();
+            assert!(contract.configure(SETTING_ARBITRATOR_LISTING, data.into()).is_ok());
+        }
+        assert!(setting::<(Address, bool)>(&contract, SETTING_ARBITRATOR_LISTING, chosen).1);
+        assert!(setting::<(Address, bool)>(&contract, SETTING_ARBITRATOR_LISTING, other).1);
+        assert_eq!(contract.listed_arbitrators.len(), 2);
+        let data = (chosen, false).abi_encode_params();
+        assert!(contract.configure(SETTING_ARBITRATOR_LISTING, data.into()).is_ok());
+        assert!(!setting::<(Address, bool)>(&contract, SETTING_ARBITRATOR_LISTING, chosen).1);
+        assert_eq!(contract.listed_arbitrators.get(0), Some(other));
+        assert_eq!(contract.listed_arbitrators.len(), 1);
+        let data = (Address::ZERO, true).abi_encode_params();
         assert_eq!(
-            contract.deposit(freelancer, duration).unwrap_err(),
-            b"Amount must be > 0".to_vec()
+            contract.configure(SETTING_ARBITRATOR_LISTING, data.into()).unwrap_err(),
+            revert(ErrorCode::InvalidArbitrator)
         );
 
-        // Test deposit with zero address
-        vm.set_value(U256::from(1_000_000));
+        // Clients name any arbitrator but the zero address or a party
+        vm.set_sender(client);
+        send_value(&vm, amount);
         assert_eq!(
-            contract.deposit(Address::ZERO, duration).unwrap_err(),
-            b"Invalid freelancer address".to_vec()
+            contract.deposit_with_arbitrator(freelancer, 1_000, B256::ZERO, Address::ZERO),
+            Err(revert(ErrorCode::InvalidArbitrator))
         );
-
-        // Test deposit with zero duration
         assert_eq!(
-            contract.deposit(freelancer, 0).unwrap_err(),
-            b"Duration must be > 0".to_vec()
+            contract.deposit_with_arbitrator(freelancer, 1_000, B256::ZERO, freelancer),
+            Err(revert(ErrorCode::ArbitratorIsParty))
         );
+        let named = contract
+            .deposit_with_arbitrator(freelancer, 1_000, B256::ZERO, chosen)
+            .unwrap();
+        send_value(&vm, amount);
+        let unnamed = contract.deposit(freelancer, 1_000, B256::ZERO).unwrap();
+        vm.set_value(U256::ZERO);
+        assert_eq!(contract.get_dispute(named).2, chosen);
+        assert_eq!(contract.get_dispute(unnamed).2, Address::ZERO);
+        let logs = vm.get_emitted_logs();
+        assert!(logs.iter().any(|(topics, data)| {
+            EscrowEvent::decode(topics, data)
+                == Some(EscrowEvent::JobArbitratorSet(JobArbitratorSet {
+                    job_id: named,
+                    arbitrator: chosen,
+                }))
+        }));
 
-        // Test deposit when paused
-        assert!(contract.set_paused(true).is_ok());
-        vm.set_value(U256::from(1_000_000));
-        assert_eq!(
-            contract.deposit(freelancer, duration).unwrap_err(),
-            b"Escrow is paused".to_vec()
-        );
+        // Only the named arbitrator rules on the job's dispute
+        vm.set_block_timestamp(10);
+        assert!(contract.open_dispute(named, B256::ZERO).is_ok());
+        assert!(contract.open_dispute(unnamed, B256::ZERO).is_ok());
+        assert_eq!(contract.get_dispute(named).2, chosen);
+        assert_eq!(contract.get_dispute(unnamed).2, global);
+        vm.set_sender(global);
+        assert_eq!(contract.rule(named, 3_000), Err(revert(ErrorCode::OnlyArbitrator)));
+        assert!(contract.rule(unnamed, 3_000).is_ok());
+        vm.set_sender(chosen);
+        assert!(contract.rule(named, 3_000).is_ok());
+        assert_eq!(vm.balance(freelancer), U256::from(600));
+        assert!(contract.verify_solvency());
     }
 
     #[test]
-    fn test_release() {
+    fn test_signed_verdict() {
+        use crate::authorizations::isValidSignatureCall;
+        use alloy_sol_types::SolCall;
+
         let vm = TestVM::default();
         let mut contract = Escrow::from(&vm);
-        let client = vm.msg_sender();
-        let freelancer = Address::from([0x01; 20]);
-        let amount = U256::from(1_000_000_000_000_000_000_u64); // 1 ETH
-        let duration = 86_400_u64; // 1 day
+        let client = Address::from([0x01; 20]);
+        let freelancer = Address::from([0x02; 20]);
+        // The arbitrator is a panel's multisig, such as a Safe
+        let arbitrator = Address::from([0x03; 20]);
+        let expiry = 50_u64;
+        let (one, two) = (U256::from(1), U256::from(2));
 
-        // Initialize and deposit
-        assert!(contract.initialize().is_ok());
-        vm.set_value(amount);
-        let job_id = contract.deposit(freelancer, duration).unwrap();
+        assert!(contract.constructor(vm.msg_sender()).is_ok());
+        assert!(contract.set_arbitration_policy(arbitrator, 100, 5_000).is_ok());
+        vm.set_code(arbitrator, vec![0x60, 0x00]);
+        vm.set_sender(client);
+        send_value(&vm, U256::from(1_000));
+        let ruled = contract.deposit(freelancer, 1_000, B256::ZERO).unwrap();
+        send_value(&vm, U256::from(1_000));
+        let reconsidered = contract.deposit(freelancer, 1_000, B256::ZERO).unwrap();
+        vm.set_value(U256::ZERO);
+        vm.set_block_timestamp(10);
+        assert!(contract.open_dispute(ruled, B256::ZERO).is_ok());
+        assert!(contract.open_dispute(reconsidered, B256::ZERO).is_ok());
 
-        // Test successful release
-        assert!(contract.release(job_id).is_ok());
-        let (id, _, _, _, _, released, refunded) = contract.get_job(job_id);
-        assert_eq!(id, job_id);
-        assert_eq!(released, true);
-        assert_eq!(refunded, false);
-        assert_eq!(contract.finalized.get(job_id), true);
-        assert_eq!(contract.get_active_jobs(), vec![]);
+        let verdict = Verdict {
+            job_id: ruled,
+            freelancer_bps: 3_000,
+            nonce: one,
+            expiry,
+        };
+        let digest = contract.get_verdict_digest(ruled, 3_000, one, expiry);
+        assert_eq!(digest, verdict_hash(&verdict, vm.chain_id(), vm.contract_address()));
+        let approval = b"signed by the panel".to_vec();
+        let check = isValidSignatureCall {
+            hash: digest,
+            signature: approval.clone().into(),
+        }
+        .abi_encode();
+        let mut magic = [0u8; 32];
+        magic[..4].copy_from_slice(&[0x16, 0x26, 0xba, 0x7e]);
+        vm.mock_static_call(arbitrator, check, Ok(magic.to_vec()));
 
-        // Verify Released event
+        // A party submits the verdict, but only as signed and before expiry
+        vm.set_sender(freelancer);
+        assert_eq!(
+            contract.rule_with_signature(ruled, 3_000, one, expiry, Bytes(b"forged".to_vec())),
+            Err(revert(ErrorCode::InvalidSignature))
+        );
+        assert_eq!(
+            contract.rule_with_signature(ruled, 9_000, one, expiry, Bytes(approval.clone())),
+            Err(revert(ErrorCode::InvalidSignature))
+        );
+        vm.set_block_timestamp(expiry);
+        assert_eq!(
+            contract.rule_with_signature(ruled, 3_000, one, expiry, Bytes(approval.clone())),
+            Err(revert(ErrorCode::VerdictExpired))
+        );
+        vm.set_block_timestamp(20);
+        assert!(contract
+            .rule_with_signature(ruled, 3_000, one, expiry, Bytes(approval.clone()))
+            .is_ok());
+        assert_eq!(vm.balance(freelancer), U256::from(300));
+        assert_eq!(vm.balance(client), U256::from(700));
         let logs = vm.get_emitted_logs();
-        assert_eq!(logs.len(), 2); // Deposited + Released
-        let event_signature: B256 = hex!(
-            "d6a6a8b9c9d0e1f2a3b4c5d6e7f8a9b0c1d2e3f4a5b6c7d8e9f0a1b2c3d4e5f"
-        ).into(); // Precomputed keccak-256 of Released event
-        assert_eq!(logs[1].0[0], event_signature);
-
-        // Test release by non-client
-        vm.set_value(amount);
-        let job_id2 = contract.deposit(freelancer, duration).unwrap();
-        vm.set_sender(Address::from([0x02; 20]));
+        let last = logs.last().unwrap();
+        assert_eq!(
+            EscrowEvent::decode(&last.0, &last.1),
+            Some(EscrowEvent::DisputeResolved(DisputeResolved {
+                job_id: ruled,
+                resolver: arbitrator,
+                freelancer_amount: U256::from(300),
+                client_amount: U256::from(700),
+                by_default: false,
+            }))
+        );
+        assert!(contract.is_authorization_used(arbitrator, one));
         assert_eq!(
-            contract.release(job_id2).unwrap_err(),
-            b"Only client can release".to_vec()
+            contract.rule_with_signature(ruled, 3_000, one, expiry, Bytes(approval)),
+            Err(revert(ErrorCode::NonceAlreadyUsed))
         );
 
-        // Test release on already settled job
-        vm.set_sender(client);
-        assert!(contract.release(job_id).is_err());
+        // The panel withdraws a verdict it reconsiders before it is submitted
+        let digest = contract.get_verdict_digest(reconsidered, 10_000, two, expiry);
+        let check = isValidSignatureCall {
+            hash: digest,
+            signature: b"signed by the panel".to_vec().into(),
+        }
+        .abi_encode();
+        vm.mock_static_call(arbitrator, check, Ok(magic.to_vec()));
+        vm.set_sender(arbitrator);
+        assert!(contract.cancel_authorization(two).is_ok());
+        vm.set_sender(freelancer);
+        assert_eq!(
+            contract.rule_with_signature(
+                reconsidered,
+                10_000,
+                two,
+                expiry,
+                Bytes(b"signed by the panel".to_vec())
+            ),
+            Err(revert(ErrorCode::NonceAlreadyUsed))
+        );
+        assert!(contract.is_disputed(reconsidered));
     }
 
     #[test]
-    fn test_refund() {
+    fn test_jury_panel() {
         let vm = TestVM::default();
         let mut contract = Escrow::from(&vm);
-        let client = vm.msg_sender();
-        let freelancer = Address::from([0x01; 20]);
-        let amount = U256::from(1_000_000_000_000_000_000_u64); // 1 ETH
-        let duration = 86_400_u64; // 1 day
+        let client = Address::from([0x01; 20]);
+        let freelancer = Address::from([0x02; 20]);
+        let arbitrator = Address::from([0x03; 20]);
+        let jurors: Vec<Address> = (0x10..0x15).map(|b| Address::from([b; 20])).collect();
+        let stake = U256::from(100);
 
-        // Initialize and deposit
-        assert!(contract.initialize().is_ok());
-        vm.set_value(amount);
-        let job_id = contract.deposit(freelancer, duration).unwrap();
-
-        // Test successful refund
-        assert!(contract.refund(job_id).is_ok());
-        let (id, _, _, _, _, released, refunded) = contract.get_job(job_id);
-        assert_eq!(id, job_id);
-        assert_eq!(released, false);
-        assert_eq!(refunded, true);
-        assert_eq!(contract.finalized.get(job_id), true);
-        assert_eq!(contract.get_active_jobs(), vec![]);
+        assert!(contract.constructor(vm.msg_sender()).is_ok());
+        assert_eq!(
+            contract.set_jury_policy(4, 100, stake, 1_000),
+            Err(revert(ErrorCode::InvalidJurySize))
+        );
+        assert_eq!(
+            contract.set_jury_policy(3, 0, stake, 1_000),
+            Err(revert(ErrorCode::InvalidVotingPeriod))
+        );
+        assert!(contract.set_jury_policy(3, 100, stake, 1_000).is_ok());
+        let policy = contract.get_setting(SETTING_JURY_POLICY, Address::ZERO).unwrap();
+        let policy = <(sol_data::Uint<8>, sol_data::Uint<64>, sol_data::Uint<256>, sol_data::Uint<16>)>::abi_decode_params(&policy, true);
+        assert_eq!(policy.unwrap(), (3, 100, stake, 1_000));
+        assert!(contract.set_arbitration_policy(arbitrator, 1_000, 5_000).is_ok());
 
-        // Verify Refunded event
-        let logs = vm.get_emitted_logs();
-        assert_eq!(logs.len(), 2); // Deposited + Refunded
-        let event_signature: B256 = hex!(
-            "e7b7a9c0d0e1f2a3b4c5d6e7f8a9b0c1d2e3f4a5b6c7d8e9f0a1b2c3d4e5f6"
-        ).into(); // Precomputed keccak-256 of Refunded event
-        assert_eq!(logs[1].0[0], event_signature);
+        // The freelancer sits in the registry too, but never on their own jury
+        for juror in jurors.iter().chain([&freelancer]) {
+            vm.set_sender(*juror);
+            send_value(&vm, U256::from(50));
+            assert_eq!(contract.join_jury(), Err(revert(ErrorCode::StakeBelowMinimum)));
+            send_value(&vm, stake);
+            assert!(contract.join_jury().is_ok());
+        }
+        vm.set_value(U256::ZERO);
+        assert_eq!(contract.get_juror_count(), U256::from(6));
+        assert_eq!(contract.get_juror(jurors[0]), (stake, true, 0));
 
-        // Test refund by non-client
-        vm.set_value(amount);
-        let job_id2 = contract.deposit(freelancer, duration).unwrap();
-        vm.set_sender(Address::from([0x02; 20]));
+        vm.set_sender(client);
+        send_value(&vm, U256::from(1_000));
+        let job_id = contract.deposit(freelancer, 1_000, B256::ZERO).unwrap();
+        vm.set_value(U256::ZERO);
+        vm.set_block_timestamp(10);
+        assert!(contract.open_dispute(job_id, B256::ZERO).is_ok());
+        vm.set_sender(jurors[0]);
         assert_eq!(
-            contract.refund(job_id2).unwrap_err(),
-            b"Only client can refund".to_vec()
+            contract.escalate_to_jury(job_id),
+            Err(revert(ErrorCode::OnlyJobPartiesCanEscalate))
         );
-
-        // Test refund after deadline
         vm.set_sender(client);
-        vm.set_value(amount);
-        let job_id3 = contract.deposit(freelancer, duration).unwrap();
-        vm.set_block_timestamp(vm.block_timestamp() + duration + 1);
+        assert!(contract.escalate_to_jury(job_id).is_ok());
+        assert_eq!(contract.escalate_to_jury(job_id), Err(revert(ErrorCode::JuryAlreadyDrawn)));
+        let (panel, vote_by, _, _) = contract.get_jury(job_id);
+        assert_eq!(panel.len(), 3);
+        assert!(panel.iter().all(|juror| jurors.contains(juror)));
+        assert_eq!(vote_by, 110);
+        let logs = vm.get_emitted_logs();
+        let last = logs.last().unwrap();
         assert_eq!(
-            contract.refund(job_id3).unwrap_err(),
-            b"Deadline passed".to_vec()
+            EscrowEvent::decode(&last.0, &last.1),
+            Some(EscrowEvent::JuryDrawn(JuryDrawn {
+                job_id,
+                panel: panel.clone(),
+                vote_by,
+            }))
         );
+
+        // The arbitrator is out while the jury sits, and jurors can't leave
+        vm.set_sender(arbitrator);
+        assert_eq!(contract.rule(job_id, 10_000), Err(revert(ErrorCode::DisputeBeforeJury)));
+        vm.set_sender(panel[0]);
+        assert_eq!(contract.leave_jury(), Err(revert(ErrorCode::JurorServing)));
+        assert_eq!(contract.tally_jury(job_id), Err(revert(ErrorCode::VotingStillOpen)));
+        let outsider = *jurors.iter().find(|juror| !panel.contains(juror)).unwrap();
+        vm.set_sender(outsider);
+        assert_eq!(contract.cast_jury_vote(job_id, 7_000), Err(revert(ErrorCode::NotOnJury)));
+
+        // Two of three jurors agree, so the third is slashed to pay them
+        for (juror, bps) in panel.iter().zip([7_000, 7_000, 2_000]) {
+            vm.set_sender(*juror);
+            assert!(contract.cast_jury_vote(job_id, bps).is_ok());
+        }
+        assert_eq!(contract.cast_jury_vote(job_id, 7_000), Err(revert(ErrorCode::AlreadyVoted)));
+        assert_eq!(contract.get_jury_vote(job_id, panel[2]), (true, 2_000));
+        assert!(contract.tally_jury(job_id).is_ok());
+        assert_eq!(vm.balance(freelancer), U256::from(700));
+        assert_eq!(vm.balance(client), U256::from(300));
+        assert_eq!(contract.get_juror(panel[0]), (U256::from(105), true, 0));
+        assert_eq!(contract.get_juror(panel[1]), (U256::from(105), true, 0));
+        assert_eq!(contract.get_juror(panel[2]), (U256::from(90), true, 0));
+        let logs = vm.get_emitted_logs();
+        assert!(logs.iter().any(|(topics, data)| {
+            EscrowEvent::decode(topics, data)
+                == Some(EscrowEvent::JuryTallied(JuryTallied {
+                    job_id,
+                    decided: true,
+                    freelancer_bps: 7_000,
+                    slashed: U256::from(10),
+                }))
+        }));
+        assert_eq!(contract.tally_jury(job_id), Err(revert(ErrorCode::JuryAlreadyTallied)));
+
+        // A juror leaves with their stake once dismissed
+        vm.set_sender(panel[2]);
+        assert!(contract.leave_jury().is_ok());
+        assert_eq!(vm.balance(panel[2]), U256::from(90));
+        assert_eq!(contract.get_juror(panel[2]), (U256::ZERO, false, 0));
+        assert_eq!(contract.get_juror_count(), U256::from(5));
+        assert_eq!(contract.leave_jury(), Err(revert(ErrorCode::NotAJuror)));
+        assert!(contract.verify_solvency());
     }
 
     #[test]
-    fn test_auto_release() {
+    fn test_replace_arbitrator() {
         let vm = TestVM::default();
         let mut contract = Escrow::from(&vm);
-        let client = vm.msg_sender();
-        let freelancer = Address::from([0x01; 20]);
-        let amount = U256::from(1_000_000_000_000_000_000_u64); // 1 ETH
-        let duration = 86_400_u64; // 1 day
+        let client = Address::from([0x01; 20]);
+        let freelancer = Address::from([0x02; 20]);
+        let unresponsive = Address::from([0x03; 20]);
+        let replacement = Address::from([0x04; 20]);
+        let council = [Address::from([0x05; 20]), Address::from([0x06; 20])];
 
-        // Initialize and deposit
-        assert!(contract.initialize().is_ok());
-        vm.set_value(amount);
-        let job_id = contract.deposit(freelancer, duration).unwrap();
+        assert!(contract.constructor(vm.msg_sender()).is_ok());
+        assert!(contract.set_arbitration_policy(unresponsive, 100, 5_000).is_ok());
+        assert!(contract.set_governance(Address::ZERO, council.to_vec(), U256::from(2)).is_ok());
 
-        // Test auto-release before deadline
-        vm.set_sender(freelancer);
+        vm.set_sender(client);
+        send_value(&vm, U256::from(1_000));
+        let job_id = contract.deposit(freelancer, 1_000, B256::ZERO).unwrap();
+        vm.set_value(U256::ZERO);
+        vm.set_block_timestamp(10);
+        assert!(contract.open_dispute(job_id, B256::ZERO).is_ok());
+
+        // The arbitrator can only be replaced once their ruling period is over
+        let action = GOVERNANCE_ACTION_REPLACE_ARBITRATOR;
+        vm.set_sender(council[0]);
         assert_eq!(
-            contract.auto_release(job_id).unwrap_err(),
-            b"Deadline not reached".to_vec()
+            contract.propose_governance(action, replacement, job_id, Bytes(vec![])),
+            Err(revert(ErrorCode::RulingPeriodNotOver))
         );
+        vm.set_block_timestamp(110);
+        assert_eq!(
+            contract.propose_governance(action, freelancer, job_id, Bytes(vec![])),
+            Err(revert(ErrorCode::ArbitratorIsParty))
+        );
+        assert_eq!(
+            contract.propose_governance(action, unresponsive, job_id, Bytes(vec![])),
+            Err(revert(ErrorCode::InvalidArbitrator))
+        );
+        let replaced = contract.propose_governance(action, replacement, job_id, Bytes(vec![])).unwrap();
+        let duplicate = contract.propose_governance(action, council[1], job_id, Bytes(vec![])).unwrap();
+        for member in council {
+            vm.set_sender(member);
+            assert!(contract.vote_governance(replaced, true).is_ok());
+            assert!(contract.vote_governance(duplicate, true).is_ok());
+        }
 
-        // Test auto-release after deadline
-        vm.set_block_timestamp(vm.block_timestamp() + duration + 1);
-        assert!(contract.auto_release(job_id).is_ok());
-        let (id, _, _, _, _, released, refunded) = contract.get_job(job_id);
-        assert_eq!(id, job_id);
-        assert_eq!(released, true);
-        assert_eq!(refunded, false);
-        assert_eq!(contract.finalized.get(job_id), true);
-        assert_eq!(contract.get_active_jobs(), vec![]);
-
-        // Verify AutoReleased event
+        // After the timelock the replacement takes over with a fresh period
+        let executable_at = 110 + GOVERNANCE_VOTING_PERIOD + GOVERNANCE_TIMELOCK;
+        vm.set_block_timestamp(executable_at);
+        assert!(contract.execute_governance(replaced).is_ok());
+        assert_eq!(
+            contract.get_dispute(job_id),
+            (client, 10, replacement, executable_at + 100, 5_000, true)
+        );
+        assert_eq!(contract.get_dispute(job_id).2, replacement);
         let logs = vm.get_emitted_logs();
-        assert_eq!(logs.len(), 2); // Deposited + AutoReleased
-        let event_signature: B256 = hex!(
-            "f8c8b0d1e1f2a3b4c5d6e7f8a9b0c1d2e3f4a5b6c7d8e9f0a1b2c3d4e5f607"
-        ).into(); // Precomputed keccak-256 of AutoReleased event
-        assert_eq!(logs[1].0[0], event_signature);
-
-        // Test auto-release by non-freelancer
-        vm.set_value(amount);
-        let job_id2 = contract.deposit(freelancer, duration).unwrap();
-        vm.set_sender(Address::from([0x02; 20]));
-        vm.set_block_timestamp(vm.block_timestamp() + duration + 1);
+        let last = logs.last().unwrap();
+        assert_eq!(
+            EscrowEvent::decode(&last.0, &last.1),
+            Some(EscrowEvent::ArbitratorReplaced(ArbitratorReplaced {
+                job_id,
+                old_arbitrator: unresponsive,
+                new_arbitrator: replacement,
+                rule_by: executable_at + 100,
+            }))
+        );
         assert_eq!(
-            contract.auto_release(job_id2).unwrap_err(),
-            b"Only freelancer can claim".as_bytes().to_vec()
+            contract.execute_governance(duplicate),
+            Err(revert(ErrorCode::RulingPeriodNotOver))
         );
+
+        // Only the replacement can rule now
+        vm.set_sender(unresponsive);
+        assert_eq!(contract.rule(job_id, 3_000), Err(revert(ErrorCode::OnlyArbitrator)));
+        vm.set_sender(replacement);
+        assert!(contract.rule(job_id, 3_000).is_ok());
+        assert_eq!(vm.balance(freelancer), U256::from(300));
+        assert!(contract.verify_solvency());
     }
 
     #[test]
-    fn test_admin_functions() {
+    fn test_appeal_ruling() {
         let vm = TestVM::default();
         let mut contract = Escrow::from(&vm);
-        let admin = vm.msg_sender();
-        let new_admin = Address::from([0x01; 20]);
-        let client = Address::from([0x02; 20]);
-        let freelancer = Address::from([0x03; 20]);
-        let amount = U256::from(1_000_000_000_000_000_000_u64); // 1 ETH
-        let duration = 86_400_u64; // 1 day
+        let client = Address::from([0x01; 20]);
+        let freelancer = Address::from([0x02; 20]);
+        let arbitrator = Address::from([0x03; 20]);
+        let council = [Address::from([0x04; 20]), Address::from([0x05; 20])];
 
-        // Initialize contract
-        assert!(contract.initialize().is_ok());
+        assert!(contract.constructor(vm.msg_sender()).is_ok());
+        assert!(contract.set_arbitration_policy(arbitrator, 1_000, 5_000).is_ok());
+        assert_eq!(
+            contract.set_appeal_policy(100, 2_001, U256::ZERO),
+            Err(revert(ErrorCode::InvalidBond))
+        );
+        assert!(contract.set_appeal_policy(100, 1_000, U256::from(50)).is_ok());
+        assert_eq!(setting::<(u64, u16, U256)>(&contract, SETTING_APPEAL_POLICY, Address::ZERO), (100, 1_000, U256::from(50)));
+        assert!(contract.set_governance(Address::ZERO, council.to_vec(), U256::from(2)).is_ok());
 
-        // Test set_paused by admin
-        assert!(contract.set_paused(true).is_ok());
-        assert_eq!(contract.is_paused(), true);
+        vm.set_sender(client);
+        let mut jobs = Vec::new();
+        for _ in 0..3 {
+            send_value(&vm, U256::from(1_000));
+            jobs.push(contract.deposit(freelancer, 1_000, B256::ZERO).unwrap());
+        }
+        vm.set_value(U256::ZERO);
+        let (upheld, rejected, unappealed) = (jobs[0], jobs[1], jobs[2]);
+        vm.set_block_timestamp(10);
+        for job_id in &jobs {
+            assert!(contract.open_dispute(*job_id, B256::ZERO).is_ok());
+        }
+
+        // Rulings are held for the appeal window instead of paying out
+        vm.set_sender(arbitrator);
+        for (job_id, bps) in jobs.iter().zip([2_000, 2_000, 3_000]) {
+            assert!(contract.rule(*job_id, bps).is_ok());
+        }
         let logs = vm.get_emitted_logs();
-        assert_eq!(logs.len(), 1);
-        let event_signature: B256 = hex!(
-            "a9d9c1e2f3a4b5c6d7e8f9a0b1c2d3e4f5a6b7c8d9e0f1a2b3c4d5e6f70809"
-        ).into(); // Precomputed keccak-256 of PauseToggled event
-        assert_eq!(logs[0].0[0], event_signature);
+        let last = logs.last().unwrap();
+        assert_eq!(
+            EscrowEvent::decode(&last.0, &last.1),
+            Some(EscrowEvent::RulingHeld(RulingHeld {
+                job_id: unappealed,
+                resolver: arbitrator,
+                freelancer_bps: 3_000,
+                appealable_until: 110,
+            }))
+        );
+        assert_eq!(vm.balance(freelancer), U256::ZERO);
+        assert_eq!(contract.get_held_ruling(upheld), (arbitrator, 2_000, 110));
+        assert_eq!(contract.rule(upheld, 5_000), Err(revert(ErrorCode::RulingPending)));
 
-        // Test set_paused by non-admin
-        vm.set_sender(Address::from([0x04; 20]));
+        // The freelancer appeals two of them with a bond of 10% of the job
+        vm.set_sender(freelancer);
+        vm.set_block_timestamp(20);
+        assert_eq!(contract.quote_appeal_bond(upheld), U256::from(100));
+        send_value(&vm, U256::from(50));
+        assert_eq!(contract.appeal_ruling(upheld, 8_000), Err(revert(ErrorCode::IncorrectBond)));
+        send_value(&vm, U256::from(100));
+        assert_eq!(contract.appeal_ruling(upheld, 2_000), Err(revert(ErrorCode::InvalidSplit)));
+        let upheld_proposal = contract.appeal_ruling(upheld, 8_000).unwrap();
         assert_eq!(
-            contract.set_paused(false).unwrap_err(),
-            b"Only admin".to_vec()
+            contract.appeal_ruling(upheld, 8_000),
+            Err(revert(ErrorCode::RulingAlreadyAppealed))
+        );
+        send_value(&vm, U256::from(100));
+        let rejected_proposal = contract.appeal_ruling(rejected, 8_000).unwrap();
+        vm.set_value(U256::ZERO);
+        assert_eq!(
+            contract.get_appeal(upheld),
+            (freelancer, 8_000, U256::from(100), upheld_proposal)
+        );
+        assert_eq!(
+            contract.get_governance_proposal(upheld_proposal).unwrap().0,
+            GOVERNANCE_ACTION_APPEAL
+        );
+        assert_eq!(
+            contract.propose_governance(GOVERNANCE_ACTION_APPEAL, Address::ZERO, upheld, Bytes(vec![])),
+            Err(revert(ErrorCode::InvalidAction))
         );
 
-        // Test transfer_ownership by admin
-        vm.set_sender(admin);
-        assert!(contract.transfer_ownership(new_admin).is_ok());
-        assert_eq!(contract.admin.get(), new_admin);
-        let logs = vm.get_emitted_logs();
-        assert_eq!(logs.len(), 2); // PauseToggled + OwnershipTransferred
-        let event_signature: B256 = hex!(
-            "8be0079c531659141344cd1fd0a4f28419497f9722a3daafe3b4186f6b6457e0"
-        ).into(); // Precomputed keccak-256 of OwnershipTransferred event
-        assert_eq!(logs[1].0[0], event_signature);
+        for member in council {
+            vm.set_sender(member);
+            assert!(contract.vote_governance(upheld_proposal, true).is_ok());
+            assert!(contract.vote_governance(rejected_proposal, false).is_ok());
+        }
 
-        // Test transfer_ownership to zero address
+        // The unappealed ruling stands once its window closes
         assert_eq!(
-            contract.transfer_ownership(Address::ZERO).unwrap_err(),
-            b"Invalid admin address".to_vec()
+            contract.finalize_ruling(unappealed),
+            Err(revert(ErrorCode::RulingStillAppealable))
         );
+        vm.set_block_timestamp(110);
+        assert!(contract.finalize_ruling(unappealed).is_ok());
+        assert_eq!(vm.balance(freelancer), U256::from(300));
+        assert_eq!(vm.balance(client), U256::from(700));
 
-        // Test emergency_refund
-        vm.set_sender(client);
-        vm.set_value(amount);
-        let job_id = contract.deposit(freelancer, duration).unwrap();
-        vm.set_sender(new_admin);
-        assert!(contract.emergency_refund(job_id).is_ok());
-        let (id, _, _, _, _, released, refunded) = contract.get_job(job_id);
-        assert_eq!(id, job_id);
-        assert_eq!(released, false);
-        assert_eq!(refunded, true);
-        assert_eq!(contract.finalized.get(job_id), true);
+        // A voted-down appeal finalizes the ruling and forfeits the bond
+        assert_eq!(
+            contract.finalize_ruling(rejected),
+            Err(revert(ErrorCode::RulingStillAppealable))
+        );
+        let voting_ends = 20 + GOVERNANCE_VOTING_PERIOD;
+        vm.set_block_timestamp(voting_ends);
+        assert!(contract.finalize_ruling(rejected).is_ok());
+        assert_eq!(vm.balance(freelancer), U256::from(500));
+        assert_eq!(vm.balance(client), U256::from(1_600));
+        assert_eq!(contract.get_appeal(rejected).2, U256::ZERO);
 
-        // Verify EmergencyRefunded event
+        // An upheld appeal settles at the appellant's split and refunds the bond
+        assert_eq!(contract.finalize_ruling(upheld), Err(revert(ErrorCode::RulingStillAppealable)));
+        vm.set_block_timestamp(voting_ends + GOVERNANCE_TIMELOCK);
+        assert!(contract.execute_governance(upheld_proposal).is_ok());
+        assert_eq!(vm.balance(freelancer), U256::from(1_400));
+        assert_eq!(vm.balance(client), U256::from(1_800));
         let logs = vm.get_emitted_logs();
-        assert_eq!(logs.len(), 4); // PauseToggled + OwnershipTransferred + Deposited + EmergencyRefunded
-        let event_signature: B256 = hex!(
-            "b0e0d2f3a4b5c6d7e8f9a0b1c2d3e4f5a6b7c8d9e0f1a2b3c4d5e6f7080910"
-        ).into(); // Precomputed keccak-256 of EmergencyRefunded event
-        assert_eq!(logs[3].0[0], event_signature);
+        assert!(logs.iter().any(|(topics, data)| {
+            EscrowEvent::decode(topics, data)
+                == Some(EscrowEvent::AppealBondSettled(AppealBondSettled {
+                    job_id: upheld,
+                    recipient: freelancer,
+                    amount: U256::from(100),
+                    forfeited: false,
+                }))
+        }));
+        assert_eq!(contract.finalize_ruling(upheld), Err(revert(ErrorCode::NoPendingRuling)));
+        assert!(contract.verify_solvency());
     }
 
     #[test]
-    fn test_storage_direct_access() {
+    fn test_dispute_blocks_release() {
         let vm = TestVM::default();
         let mut contract = Escrow::from(&vm);
-        let client = vm.msg_sender();
-        let freelancer = Address::from([0x01; 20]);
-        let amount = U256::from(1_000_000_000_000_000_000_u64); // 1 ETH
-        let duration = 86_400_u64; // 1 day
-
-        // Initialize contract
-        assert!(contract.initialize().is_ok());
+        let client = Address::from([0x01; 20]);
+        let freelancer = Address::from([0x02; 20]);
+        let arbitrator = Address::from([0x03; 20]);
+        let amount = U256::from(1_000);
 
-        // Deposit a job
+        assert!(contract.constructor(vm.msg_sender()).is_ok());
+        assert!(contract.set_arbitration_policy(arbitrator, 100, 5_000).is_ok());
+        vm.set_sender(client);
+        vm.set_balance(vm.contract_address(), amount * U256::from(3));
         vm.set_value(amount);
-        let job_id = contract.deposit(freelancer, duration).unwrap();
+        let job_id = contract.deposit(freelancer, 1_000, B256::ZERO).unwrap();
+        let raced = contract.deposit(freelancer, 1_000, B256::ZERO).unwrap();
+        let tranched = contract
+            .deposit_tranched(freelancer, vec![U256::from(400), U256::from(600)], vec![0, 500])
+            .unwrap();
+        vm.set_value(U256::ZERO);
 
-        // Compute storage slot for job_id in jobs mapping
-        // Mapping slot for jobs is 3 (admin: 0, paused: 1, job_count: 2)
-        let base_slot = U256::from(3);
-        let job_slot = keccak256(&[&job_id.to_be_bytes::<32>(), &base_slot.to_be_bytes::<32>()].concat());
+        // The client disputes delivery just before the deadline
+        vm.set_block_timestamp(999);
+        assert!(contract.open_dispute(job_id, B256::ZERO).is_ok());
+        assert!(contract.open_dispute(tranched, B256::ZERO).is_ok());
+
+        // Once it passes, the freelancer can't claim around the dispute
+        vm.set_block_timestamp(1_000);
+        vm.set_sender(freelancer);
+        assert_eq!(
+            contract.auto_release(job_id, B256::ZERO),
+            Err(revert(ErrorCode::JobIsDisputed))
+        );
+        assert_eq!(
+            contract.claim_tranche(tranched, U256::ZERO),
+            Err(revert(ErrorCode::JobIsDisputed))
+        );
+        assert_eq!(contract.claim_unlocked(tranched), Err(revert(ErrorCode::JobIsDisputed)));
+        vm.set_sender(client);
+        assert_eq!(contract.release(job_id, B256::ZERO), Err(revert(ErrorCode::JobIsDisputed)));
+        assert_eq!(vm.balance(freelancer), U256::ZERO);
 
-        // Read job_id field (offset 0 in Job struct)
-        let job_id_slot = job_slot;
-        let stored_job_id = vm.storage_load_bytes32(job_id_slot);
+        // Only the arbitrator's ruling settles it
+        vm.set_sender(arbitrator);
+        assert!(contract.rule(job_id, 2_000).is_ok());
+        assert_eq!(vm.balance(freelancer), U256::from(200));
+        vm.set_sender(freelancer);
         assert_eq!(
-            stored_job_id,
-            B256::from_slice(&job_id.to_be_bytes::<32>())
+            contract.auto_release(job_id, B256::ZERO),
+            Err(revert(ErrorCode::JobAlreadySettled))
         );
 
-        // Read client field (offset 1 in Job struct)
-        let client_slot = job_slot + U256::from(1);
-        let stored_client = vm.storage_load_bytes32(client_slot);
+        // A claim that lands first settles the job, and the dispute comes too late
+        assert!(contract.auto_release(raced, B256::ZERO).is_ok());
+        vm.set_sender(client);
         assert_eq!(
-            Address::from_slice(&stored_client.to_vec()[12..]),
-            client
+            contract.open_dispute(raced, B256::ZERO),
+            Err(revert(ErrorCode::JobAlreadySettled))
         );
+        assert!(contract.verify_solvency());
     }
 
     #[test]
-    fn test_block_dependent_logic() {
+    fn test_dispute_stake() {
         let vm = TestVM::default();
         let mut contract = Escrow::from(&vm);
-        let client = vm.msg_sender();
-        let freelancer = Address::from([0x01; 20]);
-        let amount = U256::from(1_000_000_000_000_000_000_u64); // 1 ETH
-        let duration = 86_400_u64; // 1 day
-        let initial_timestamp = 1_234_567_890_u64;
+        let client = Address::from([0x01; 20]);
+        let freelancer = Address::from([0x02; 20]);
+        let arbitrator = Address::from([0x03; 20]);
+        let admin = vm.msg_sender();
 
-        // Initialize contract
-        assert!(contract.initialize().is_ok());
+        assert!(contract.constructor(admin).is_ok());
+        assert!(contract.set_arbitration_policy(arbitrator, 100, 5_000).is_ok());
+        assert_eq!(
+            contract.set_dispute_stake_policy(MAX_DISPUTE_STAKE_BPS + 1, U256::ZERO),
+            Err(revert(ErrorCode::InvalidStake))
+        );
+        assert!(contract.set_dispute_stake_policy(100, U256::from(50)).is_ok());
+        assert_eq!(setting::<(u16, U256)>(&contract, SETTING_DISPUTE_STAKE_POLICY, Address::ZERO), (100, U256::from(50)));
 
-        // Set initial block timestamp
-        vm.set_block_timestamp(initial_timestamp);
+        vm.set_sender(client);
+        send_value(&vm, U256::from(10_000));
+        let large = contract.deposit(freelancer, 1_000, B256::ZERO).unwrap();
+        send_value(&vm, U256::from(1_000));
+        let small = contract.deposit(freelancer, 1_000, B256::ZERO).unwrap();
+        send_value(&vm, U256::from(1_000));
+        let lifted = contract.deposit(freelancer, 1_000, B256::ZERO).unwrap();
+        vm.set_block_timestamp(10);
 
-        // Deposit a job
-        vm.set_value(amount);
-        let job_id = contract.deposit(freelancer, duration).unwrap();
-        let (_, _, _, _, deadline, _, _) = contract.get_job(job_id);
-        assert_eq!(deadline, initial_timestamp + duration);
+        // The stake is a share of the job, but never below the minimum
+        assert_eq!(contract.quote_dispute_stake(large), U256::from(100));
+        assert_eq!(contract.quote_dispute_stake(small), U256::from(50));
+        vm.set_value(U256::ZERO);
+        assert_eq!(
+            contract.open_dispute(large, B256::ZERO),
+            Err(revert(ErrorCode::IncorrectStake))
+        );
+        send_value(&vm, U256::from(100));
+        assert!(contract.open_dispute(large, B256::ZERO).is_ok());
+        assert_eq!(contract.get_dispute_stake(large), U256::from(100));
+        assert!(contract.verify_solvency());
 
-        // Test refund before deadline
-        assert!(contract.refund(job_id).is_ok());
+        // A client who loses the ruling forfeits their stake to the freelancer
+        let balances = Balances::track(&vm, &[client, freelancer]);
+        vm.set_sender(arbitrator);
+        vm.set_value(U256::ZERO);
+        assert!(contract.rule(large, 8_000).is_ok());
+        balances.assert_deltas(&[(client, 2_000), (freelancer, 8_100)]);
+        assert_eq!(contract.get_dispute_stake(large), U256::ZERO);
+        let logs = vm.get_emitted_logs();
+        let stake_log = &logs[logs.len() - 2];
+        assert_eq!(
+            EscrowEvent::decode(&stake_log.0, &stake_log.1),
+            Some(EscrowEvent::DisputeStakeSettled(DisputeStakeSettled {
+                job_id: large,
+                recipient: freelancer,
+                amount: U256::from(100),
+                forfeited: true,
+            }))
+        );
 
-        // Create another job
+        // A freelancer who prevails gets theirs back
+        vm.set_sender(freelancer);
+        send_value(&vm, U256::from(50));
+        assert!(contract.open_dispute(small, B256::ZERO).is_ok());
+        let balances = Balances::track(&vm, &[client, freelancer]);
+        vm.set_sender(arbitrator);
+        vm.set_value(U256::ZERO);
+        assert!(contract.rule(small, 6_000).is_ok());
+        balances.assert_deltas(&[(client, 400), (freelancer, 650)]);
+
+        // A dispute lifted by its timeout returns the stake too
+        vm.set_sender(admin);
+        assert!(contract.set_dispute_timeout_policy(10, false, 0).is_ok());
+        vm.set_sender(client);
+        send_value(&vm, U256::from(50));
+        assert!(contract.open_dispute(lifted, B256::ZERO).is_ok());
+        vm.set_value(U256::ZERO);
+        vm.set_block_timestamp(20);
+        let balances = Balances::track(&vm, &[client, freelancer]);
+        assert!(contract.time_out_dispute(lifted).is_ok());
+        balances.assert_deltas(&[(client, 50)]);
+        assert!(contract.verify_solvency());
+    }
+
+    #[test]
+    fn test_dispute_timeout() {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        let admin = vm.msg_sender();
+        let client = Address::from([0x01; 20]);
+        let freelancer = Address::from([0x02; 20]);
+        let stranger = Address::from([0x03; 20]);
+        let amount = U256::from(1_000);
+
+        assert!(contract.constructor(admin).is_ok());
+        assert_eq!(
+            contract.set_dispute_timeout_policy(100, true, BASIS_POINTS + 1),
+            Err(revert(ErrorCode::InvalidSplit))
+        );
+        vm.set_sender(client);
+        vm.set_balance(vm.contract_address(), amount * U256::from(3));
         vm.set_value(amount);
-        let job_id2 = contract.deposit(freelancer, duration).unwrap();
+        let unlimited = contract.deposit(freelancer, 1_000, B256::ZERO).unwrap();
+        let split = contract.deposit(freelancer, 1_000, B256::ZERO).unwrap();
+        let lifted = contract.deposit(freelancer, 1_000, B256::ZERO).unwrap();
+        vm.set_value(U256::ZERO);
+        vm.set_block_timestamp(10);
 
-        // Test auto-release before deadline
-        vm.set_sender(freelancer);
+        // Disputes keep the rule in force when they were opened
+        assert!(contract.open_dispute(unlimited, B256::ZERO).is_ok());
+        vm.set_sender(admin);
+        assert!(contract.set_dispute_timeout_policy(100, true, 2_000).is_ok());
+        assert_eq!(setting::<(u64, bool, u16)>(&contract, SETTING_DISPUTE_TIMEOUT_POLICY, Address::ZERO), (100, true, 2_000));
+        vm.set_sender(client);
+        assert!(contract.open_dispute(split, B256::ZERO).is_ok());
+        vm.set_sender(admin);
+        assert!(contract.set_dispute_timeout_policy(100, false, 0).is_ok());
+        vm.set_sender(client);
+        assert!(contract.open_dispute(lifted, B256::ZERO).is_ok());
+        assert_eq!(contract.get_dispute_timeout(unlimited), (0, false, 0));
+        assert_eq!(contract.get_dispute_timeout(split), (110, true, 2_000));
+
+        vm.set_sender(stranger);
+        vm.set_block_timestamp(109);
         assert_eq!(
-            contract.auto_release(job_id2).unwrap_err(),
-            b"Deadline not reached".to_vec()
+            contract.time_out_dispute(split),
+            Err(revert(ErrorCode::DisputeTimeoutNotReached))
         );
+        vm.set_block_timestamp(1_010);
+        assert_eq!(contract.time_out_dispute(unlimited), Err(revert(ErrorCode::NoDisputeTimeout)));
 
-        // Advance timestamp past deadline
-        vm.set_block_timestamp(initial_timestamp + duration + 1);
+        // A split timeout settles the job like a default ruling
+        assert!(contract.time_out_dispute(split).is_ok());
+        assert_eq!(vm.balance(freelancer), U256::from(200));
+        assert_eq!(vm.balance(client), U256::from(800));
+        assert_eq!(contract.time_out_dispute(split), Err(revert(ErrorCode::NoOpenDispute)));
 
-        // Test refund after deadline
+        // Lifting one puts the job back on its deadline
+        assert!(contract.time_out_dispute(lifted).is_ok());
+        let logs = vm.get_emitted_logs();
+        let last = logs.last().unwrap();
+        assert_eq!(
+            EscrowEvent::decode(&last.0, &last.1),
+            Some(EscrowEvent::DisputeTimedOut(DisputeTimedOut {
+                job_id: lifted,
+                split: false,
+            }))
+        );
+        assert!(!contract.get_dispute(lifted).5);
         vm.set_sender(client);
         assert_eq!(
-            contract.refund(job_id2).unwrap_err(),
-            b"Deadline passed".to_vec()
+            contract.open_dispute(lifted, B256::ZERO),
+            Err(revert(ErrorCode::DisputeAlreadyOpened))
         );
-
-        // Test auto-release after deadline
         vm.set_sender(freelancer);
-        assert!(contract.auto_release(job_id2).is_ok());
+        assert!(contract.auto_release(lifted, B256::ZERO).is_ok());
+        assert_eq!(vm.balance(freelancer), U256::from(1_200));
+        assert!(contract.verify_solvency());
+    }
+
+    #[test]
+    fn test_event_topics() {
+        // Topic constants are the keccak256 of the canonical event signatures
+        let topics = [
+            (DEPOSITED_TOPIC, "Deposited(uint256,address,address,uint256,bytes32,uint16,bytes32)"),
+            (RELEASED_TOPIC, "Released(uint256,uint256,bytes32)"),
+            (REFUNDED_TOPIC, "Refunded(uint256,uint256,bytes32)"),
+            (AUTO_RELEASED_TOPIC, "AutoReleased(uint256,uint256,bytes32)"),
+            (EMERGENCY_REFUNDED_TOPIC, "EmergencyRefunded(uint256,address)"),
+            (PAUSE_TOGGLED_TOPIC, "PauseToggled(bool)"),
+            (OWNERSHIP_TRANSFERRED_TOPIC, "OwnershipTransferred(address,address)"),
+            (SUNSET_BEGUN_TOPIC, "SunsetBegun(uint64)"),
+            (SUNSET_FINALIZED_TOPIC, "SunsetFinalized(address,uint256)"),
+            (DORMANCY_POLICY_SET_TOPIC, "DormancyPolicySet(uint64,address)"),
+            (DORMANCY_ANNOUNCED_TOPIC, "DormancyAnnounced(uint256,uint64)"),
+            (DORMANT_SWEPT_TOPIC, "DormantSwept(uint256,address,uint256)"),
+            (PRIVATE_DEPOSITED_TOPIC, "PrivateDeposited(uint256,address,uint256,bytes32)"),
+            (JOB_REVEALED_TOPIC, "JobRevealed(uint256,address,uint256,bytes32)"),
+            (SWAP_ROUTER_SET_TOPIC, "SwapRouterSet(address,address)"),
+            (PAYOUT_TOKEN_SET_TOPIC, "PayoutTokenSet(address,address,uint256)"),
+            (PAYOUT_SWAPPED_TOPIC, "PayoutSwapped(uint256,address,uint256,uint256)"),
+            (PAYOUT_SWAP_FAILED_TOPIC, "PayoutSwapFailed(uint256,address)"),
+            (WITHHOLDING_SET_TOPIC, "WithholdingSet(address,address,uint16)"),
+            (WITHHELD_TOPIC, "Withheld(uint256,address,uint256,uint256)"),
+            (COMPLIANCE_HOOK_SET_TOPIC, "ComplianceHookSet(address)"),
+            (JOB_ATTESTED_TOPIC, "JobAttested(uint256,bytes32)"),
+            (ATTESTATION_POLICY_SET_TOPIC, "AttestationPolicySet(address,bytes32,address)"),
+            (SETTLEMENT_HOOK_SET_TOPIC, "SettlementHookSet(uint256,address)"),
+            (SETTLEMENT_HOOK_FAILED_TOPIC, "SettlementHookFailed(uint256,address)"),
+            (RELEASE_CONDITION_SET_TOPIC, "ReleaseConditionSet(uint256,address)"),
+            (TRANCHE_CLAIMED_TOPIC, "TrancheClaimed(uint256,uint256,uint256,uint256)"),
+            (TRANCHE_REFUNDED_TOPIC, "TrancheRefunded(uint256,uint256,uint256,uint256)"),
+            (RETAINER_STARTED_TOPIC, "RetainerStarted(uint256,uint64,uint64,uint64)"),
+            (RETAINER_STOPPED_TOPIC, "RetainerStopped(uint256,uint256)"),
+            (CANCELLATION_NOTICE_SET_TOPIC, "CancellationNoticeSet(uint256,uint64)"),
+            (CANCELLATION_REQUESTED_TOPIC, "CancellationRequested(uint256,uint64)"),
+            (DELIVERY_SUBMITTED_TOPIC, "DeliverySubmitted(uint256,bytes32)"),
+            (FUNDING_OPENED_TOPIC, "FundingOpened(uint256,address,address,uint256,uint64)"),
+            (JOB_FUNDED_TOPIC, "JobFunded(uint256,address,uint256,uint256)"),
+            (CONTRIBUTION_RECLAIMED_TOPIC, "ContributionReclaimed(uint256,address,uint256)"),
+            (EXCESS_CREDITED_TOPIC, "ExcessCredited(uint256,address,uint256)"),
+            (CREDIT_WITHDRAWN_TOPIC, "CreditWithdrawn(address,uint256)"),
+            (DEFAULT_JOB_CAP_SET_TOPIC, "DefaultJobCapSet(uint32)"),
+            (JOB_CAP_SET_TOPIC, "JobCapSet(address,uint32)"),
+            (PROTOCOL_FEE_SET_TOPIC, "ProtocolFeeSet(uint16)"),
+            (FEE_CHARGED_TOPIC, "FeeCharged(uint256,address,uint256)"),
+            (FEE_MANAGER_SET_TOPIC, "FeeManagerSet(address)"),
+            (FEE_TIERS_SET_TOPIC, "FeeTiersSet(uint256[],uint16[])"),
+            (VOLUME_DISCOUNTS_SET_TOPIC, "VolumeDiscountsSet(uint256[],uint16[])"),
+            (FEE_EXEMPTION_SET_TOPIC, "FeeExemptionSet(address,bool)"),
+            (DISPUTE_OPENED_TOPIC, "DisputeOpened(uint256,address,bytes32)"),
+            (EVIDENCE_SUBMITTED_TOPIC, "EvidenceSubmitted(uint256,address,uint256,bytes32)"),
+            (DISPUTE_RESOLVED_TOPIC, "DisputeResolved(uint256,address,uint256,uint256,bool)"),
+            (ARBITRATION_POLICY_SET_TOPIC, "ArbitrationPolicySet(address,uint64,uint16)"),
+            (RECLAIMED_TOPIC, "Reclaimed(uint256,address,uint256,bytes32)"),
+            (FUNDS_RETURNED_TOPIC, "FundsReturned(uint256,uint256,bytes32)"),
+            (DUAL_APPROVAL_REQUIRED_TOPIC, "DualApprovalRequired(uint256)"),
+            (RELEASE_APPROVED_TOPIC, "ReleaseApproved(uint256,address)"),
+            (DISPUTE_TIMED_OUT_TOPIC, "DisputeTimedOut(uint256,bool)"),
+            (DISPUTE_TIMEOUT_POLICY_SET_TOPIC, "DisputeTimeoutPolicySet(uint64,bool,uint16)"),
+            (JOB_AMENDED_TOPIC, "JobAmended(uint256,uint8,bytes32,bytes32,address)"),
+            (FEES_WITHDRAWN_TOPIC, "FeesWithdrawn(address,uint256,uint256)"),
+            (TREASURY_PROPOSED_TOPIC, "TreasuryProposed(address,uint64)"),
+            (TREASURY_SET_TOPIC, "TreasurySet(address)"),
+            (GUARDIANS_SET_TOPIC, "GuardiansSet(address[],uint8)"),
+            (EMERGENCY_PROPOSED_TOPIC, "EmergencyProposed(uint256,uint8,uint256,address,uint64)"),
+            (EMERGENCY_CONFIRMED_TOPIC, "EmergencyConfirmed(uint256,address,uint8)"),
+            (SURPLUS_SWEPT_TOPIC, "SurplusSwept(address,uint256)"),
+            (PAUSE_EXTENDED_TOPIC, "PauseExtended(uint64)"),
+            (CLIENT_TRANSFER_PROPOSED_TOPIC, "ClientTransferProposed(uint256,address,address)"),
+            (JOB_ACCEPTED_TOPIC, "JobAccepted(uint256,address)"),
+            (FREELANCER_CHANGED_TOPIC, "FreelancerChanged(uint256,address,address)"),
+            (PAYOUT_ADDRESS_PROPOSED_TOPIC, "PayoutAddressProposed(address,address,uint64)"),
+            (PAYOUT_ADDRESS_SET_TOPIC, "PayoutAddressSet(address,address)"),
+            (GOVERNANCE_SET_TOPIC, "GovernanceSet(address,address[],uint256)"),
+            (GOVERNANCE_PROPOSED_TOPIC, "GovernanceProposed(uint256,uint8,address,uint256,address,uint64)"),
+            (GOVERNANCE_VOTED_TOPIC, "GovernanceVoted(uint256,address,bool,uint256)"),
+            (GOVERNANCE_EXECUTED_TOPIC, "GovernanceExecuted(uint256)"),
+            (PAUSER_SET_TOPIC, "PauserSet(address)"),
+            (MAX_PAUSE_SET_TOPIC, "MaxPauseSet(uint64)"),
+            (RENOUNCE_PROPOSED_TOPIC, "RenounceProposed(uint64)"),
+            (PLATFORM_FEE_SET_TOPIC, "PlatformFeeSet(uint256,address,uint16)"),
+            (CREDIT_DEPOSITED_TOPIC, "CreditDeposited(address,uint256)"),
+            (OFFER_CANCELLED_TOPIC, "OfferCancelled(address,uint256)"),
+            (INVOICE_CREATED_TOPIC, "InvoiceCreated(uint256,address,address,uint256,uint64,bytes32,uint64)"),
+            (INVOICE_FUNDED_TOPIC, "InvoiceFunded(uint256,uint256)"),
+            (INVOICE_PRUNED_TOPIC, "InvoicePruned(uint256)"),
+            (PARTIALLY_RELEASED_TOPIC, "PartiallyReleased(uint256,uint256,uint256)"),
+            (HOLDBACK_SET_TOPIC, "HoldbackSet(uint256,uint16,uint64)"),
+            (HOLDBACK_RETAINED_TOPIC, "HoldbackRetained(uint256,uint256,uint64)"),
+            (CLAWBACK_WINDOW_SET_TOPIC, "ClawbackWindowSet(uint256,uint64)"),
+            (RELEASE_QUEUED_TOPIC, "ReleaseQueued(uint256,uint256,uint64)"),
+            (RELEASE_REVERSED_TOPIC, "ReleaseReversed(uint256,address,uint256)"),
+            (SLA_SET_TOPIC, "SlaSet(uint256,uint64,uint16)"),
+            (LATE_REFUNDED_TOPIC, "LateRefunded(uint256,uint64,uint256)"),
+            (HOURLY_RATE_SET_TOPIC, "HourlyRateSet(uint256,uint256)"),
+            (TIMESHEET_SUBMITTED_TOPIC, "TimesheetSubmitted(uint256,uint256,uint32,uint256,bytes32)"),
+            (TIMESHEET_APPROVED_TOPIC, "TimesheetApproved(uint256,uint256,uint256,uint256)"),
+            (TIMESHEET_REJECTED_TOPIC, "TimesheetRejected(uint256,uint256)"),
+            (BASKET_TOKEN_SET_TOPIC, "BasketTokenSet(address,bool)"),
+            (BASKET_FUNDED_TOPIC, "BasketFunded(uint256,address,uint256)"),
+            (BASKET_PAID_TOPIC, "BasketPaid(uint256,address,address,uint256)"),
+            (REFUND_ROOT_SET_TOPIC, "RefundRootSet(bytes32)"),
+            (EMERGENCY_REFUND_CLAIMED_TOPIC, "EmergencyRefundClaimed(uint256,address,uint256,uint256)"),
+            (EMERGENCY_REFUND_BATCH_TOPIC, "EmergencyRefundBatch(address,uint256[],bool[])"),
+            (JOB_RATED_TOPIC, "JobRated(uint256,address,address,uint8)"),
+            (SETTLEMENT_REBATE_SET_TOPIC, "SettlementRebateSet(uint256)"),
+            (SETTLEMENT_REBATED_TOPIC, "SettlementRebated(uint256,address,uint256)"),
+            (PAYOUT_PUSHED_TOPIC, "PayoutPushed(uint256,address,uint256,bool)"),
+            (DEPOSIT_LIMITS_SET_TOPIC, "DepositLimitsSet(uint64,uint32)"),
+            (DEPOSIT_LIMIT_EXEMPTION_SET_TOPIC, "DepositLimitExemptionSet(address,bool)"),
+            (DISPUTE_STAKE_POLICY_SET_TOPIC, "DisputeStakePolicySet(uint16,uint256)"),
+            (DISPUTE_STAKE_SETTLED_TOPIC, "DisputeStakeSettled(uint256,address,uint256,bool)"),
+            (AUTHORIZATION_CANCELLED_TOPIC, "AuthorizationCancelled(address,uint256)"),
+            (JURY_POLICY_SET_TOPIC, "JuryPolicySet(uint8,uint64,uint256,uint16)"),
+            (JUROR_STAKED_TOPIC, "JurorStaked(address,uint256,uint256)"),
+            (JUROR_LEFT_TOPIC, "JurorLeft(address,uint256)"),
+            (JURY_DRAWN_TOPIC, "JuryDrawn(uint256,address[],uint64)"),
+            (JURY_VOTED_TOPIC, "JuryVoted(uint256,address,uint16)"),
+            (JURY_TALLIED_TOPIC, "JuryTallied(uint256,bool,uint16,uint256)"),
+            (APPEAL_POLICY_SET_TOPIC, "AppealPolicySet(uint64,uint16,uint256)"),
+            (RULING_HELD_TOPIC, "RulingHeld(uint256,address,uint16,uint64)"),
+            (RULING_APPEALED_TOPIC, "RulingAppealed(uint256,address,uint16,uint256,uint256)"),
+            (APPEAL_BOND_SETTLED_TOPIC, "AppealBondSettled(uint256,address,uint256,bool)"),
+            (JOB_ARBITRATOR_SET_TOPIC, "JobArbitratorSet(uint256,address)"),
+            (ARBITRATOR_LISTED_TOPIC, "ArbitratorListed(address,bool)"),
+            (ARBITRATOR_REPLACED_TOPIC, "ArbitratorReplaced(uint256,address,address,uint64)"),
+            (STAKING_TOKEN_SET_TOPIC, "StakingTokenSet(address,uint16)"),
+            (STAKE_CONSENTED_TOPIC, "StakeConsented(uint256,address,uint16,uint16)"),
+            (JOB_STAKED_TOPIC, "JobStaked(uint256,address,uint256,uint256)"),
+            (JOB_UNSTAKED_TOPIC, "JobUnstaked(uint256,address,uint256,uint256,uint256,uint256,uint256)"),
+            (YIELD_SPLIT_SET_TOPIC, "YieldSplitSet(uint256,uint16,uint16,uint16)"),
+        ];
+        for (topic, signature) in topics {
+            assert_eq!(topic, keccak256(signature.as_bytes()), "{}", signature);
+            assert!(EVENT_TOPICS.contains(&topic));
+        }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,67 @@
+//! Job offers signed by the client and submitted by the freelancer.
+//!
+//! A client can sign an EIP-712 `JobOffer` off-chain and hand it to the
+//! freelancer, who opens the job with `accept_offer` and pays the gas. The
+//! escrow holds ETH, so there is no token allowance to pull from: an offer
+//! draws on the client's withdrawable balance, which they top up beforehand
+//! with `deposit_credit`. An offer's nonce is the client's
+//! `deposit_with_nonce` nonce, so it opens at most one job, found with
+//! `get_job_by_nonce`, and the client can withdraw it with `cancel_offer`.
+
+use crate::{ErrorCode, Escrow};
+use alloy_primitives::{address, uint, Address, B256, U256};
+use stylus_sdk::prelude::*;
+use stylus_sdk::stylus_core::calls::context::Call;
+
+/// Precompile recovering the signer of a secp256k1 signature
+const ECRECOVER: Address = address!("0000000000000000000000000000000000000001");
+
+/// Half the secp256k1 group order; signatures with a larger `s` are the
+/// malleable twin of another and are rejected
+const SECP256K1N_HALF: U256 =
+    uint!(0x7FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF5D576E7357A4501DDFE92F46681B20A0_U256);
+
+impl Escrow {
+    /// Revert if `client` already opened a job with `nonce` or cancelled the
+    /// offer made with it
+    pub(crate) fn assert_nonce_unused(&self, client: Address, nonce: U256) -> Result<(), Vec<u8>> {
+        if self.nonce_jobs.get(client).get(nonce) != U256::ZERO
+            || self.cancelled_offers.get(client).get(nonce)
+        {
+            return Err(ErrorCode::NonceAlreadyUsed.into());
+        }
+        Ok(())
+    }
+
+    /// Address that produced the 65-byte `(r, s, v)` `signature` over
+    /// `digest`. A malformed or malleable signature is rejected rather than
+    /// recovering some other address.
+    pub(crate) fn offer_signer(&self, digest: B256, signature: &[u8]) -> Result<Address, Vec<u8>> {
+        let invalid = || ErrorCode::InvalidSignature.into();
+        if signature.len() != 65 {
+            return Err(invalid());
+        }
+        let s = U256::from_be_slice(&signature[32..64]);
+        let v = match signature[64] {
+            v @ (27 | 28) => v,
+            v @ (0 | 1) => v + 27,
+            _ => return Err(invalid()),
+        };
+        if s > SECP256K1N_HALF {
+            return Err(invalid());
+        }
+
+        let mut input = Vec::with_capacity(128);
+        input.extend_from_slice(digest.as_slice());
+        input.extend_from_slice(&U256::from(v).to_be_bytes::<32>());
+        input.extend_from_slice(&signature[..64]);
+        let output = self
+            .vm()
+            .static_call(&Call::new(), ECRECOVER, &input)
+            .map_err(|_| invalid())?;
+        if output.len() != 32 {
+            return Err(invalid());
+        }
+        Ok(Address::from_slice(&output[12..]))
+    }
+}
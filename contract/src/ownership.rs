@@ -0,0 +1,25 @@
+//! Renouncing the admin role.
+//!
+//! A deployment can graduate into an escrow nobody controls: the admin
+//! proposes renouncing, and `RENOUNCE_DELAY` later may drop the role for
+//! good. Both steps require the parameters to be final, so nothing the admin
+//! handed out or left half-done outlives them: no pause, no pending treasury
+//! and no fee manager or pauser holding admin powers. Guardians and
+//! governance, if set, keep theirs.
+
+use crate::{ErrorCode, Escrow};
+use alloy_primitives::Address;
+
+impl Escrow {
+    /// Revert unless the escrow can run without an admin
+    pub(crate) fn assert_parameters_final(&self) -> Result<(), Vec<u8>> {
+        if self.paused.get()
+            || self.pending_treasury.get() != Address::ZERO
+            || self.fee_manager.get() != Address::ZERO
+            || self.pauser.get() != Address::ZERO
+        {
+            return Err(ErrorCode::ParametersNotFinal.into());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+//! Payout addresses.
+//!
+//! A freelancer can have their payouts sent to another address, such as a
+//! cold wallet or a splitter contract, while the address that holds their
+//! jobs stays the one they work from. A new payout address only takes
+//! effect `PAYOUT_ADDRESS_DELAY` after it is proposed, so a stolen hot key
+//! cannot quietly redirect payments that are about to be released.
+//!
+//! A payout address that is a contract, such as a multisig or a splitter,
+//! is paid with a call capped at `PAYOUT_CALL_GAS`, and so is a withholding
+//! destination. If it reverts or runs out of gas the payout is credited to
+//! it to withdraw instead, so a wallet that can't take a push never blocks
+//! the settlement, and `PayoutPushed` records which way the payout went.
+
+use crate::{Escrow, PayoutPushed, PAYOUT_CALL_GAS};
+use alloy_primitives::{Address, U256};
+use stylus_sdk::prelude::*;
+use stylus_sdk::stylus_core::calls::context::Call;
+
+impl Escrow {
+    /// Where `freelancer` is paid: their payout address, or themselves
+    /// until one is set
+    pub(crate) fn payout_recipient(&self, freelancer: Address) -> Address {
+        let payout_address = self.payout_addresses.get(freelancer).current.get();
+        if payout_address == Address::ZERO {
+            freelancer
+        } else {
+            payout_address
+        }
+    }
+
+    /// Send `amount` wei paid out for `job_id` to `recipient`; a contract
+    /// that refuses it is credited the amount instead
+    pub(crate) fn push_payout(
+        &mut self,
+        job_id: U256,
+        recipient: Address,
+        amount: U256,
+    ) -> Result<(), Vec<u8>> {
+        if self.vm().code_size(recipient) == 0 {
+            return self.vm().transfer_eth(recipient, amount);
+        }
+
+        let context = Call::new().value(amount).gas(PAYOUT_CALL_GAS);
+        let pushed = self.vm().call(&context, recipient, &[]).is_ok();
+        if !pushed {
+            self.credit(recipient, amount);
+        }
+
+        log(
+            self.vm(),
+            PayoutPushed {
+                job_id,
+                recipient,
+                amount,
+                pushed,
+            },
+        );
+        Ok(())
+    }
+}
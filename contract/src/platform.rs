@@ -0,0 +1,39 @@
+//! Marketplace platform fees.
+//!
+//! A marketplace that opens jobs for its users can name itself and a rate
+//! at deposit. Every payout to the freelancer then pays the platform fee
+//! alongside the protocol fee, both taken off the gross amount before any
+//! withholding, and refunds pay neither. The rate is capped at
+//! `MAX_PLATFORM_FEE_BPS` and fixed for the life of the job. The fee is
+//! credited to the platform's withdrawable balance rather than pushed, so a
+//! platform that can't receive ETH never blocks a payout.
+
+use crate::fees::fee_on;
+use crate::{Escrow, FeeCharged};
+use alloy_primitives::U256;
+use stylus_sdk::prelude::*;
+
+impl Escrow {
+    /// Platform fee on a payout of `amount` from `job_id`
+    pub(crate) fn platform_fee(&self, job_id: U256, amount: U256) -> U256 {
+        fee_on(amount, self.job_platforms.get(job_id).fee_bps.get().to())
+    }
+
+    /// Credit `fee` from `job_id`'s payout to the platform the job was
+    /// opened through
+    pub(crate) fn charge_platform_fee(&mut self, job_id: U256, fee: U256) {
+        if fee.is_zero() {
+            return;
+        }
+        let recipient = self.job_platforms.get(job_id).platform.get();
+        self.credit(recipient, fee);
+        log(
+            self.vm(),
+            FeeCharged {
+                job_id,
+                recipient,
+                fee,
+            },
+        );
+    }
+}
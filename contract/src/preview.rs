@@ -0,0 +1,51 @@
+//! Settlement previews.
+//!
+//! Views that work out where a job's funds would go if it were settled now,
+//! so a UI can show the exact split before anyone signs. Releases go through
+//! the same split `pay_release` uses, so a preview can't drift from what the
+//! settlement actually pays. Jobs whose settlement the ETH amounts can't
+//! describe, those holding a token basket or staked in a vault, get an
+//! error instead of a preview.
+
+use crate::{ErrorCode, Escrow, BASIS_POINTS};
+use alloy_primitives::{Address, U256};
+
+impl Escrow {
+    /// Split `amount` released from `job_id` to `freelancer` into (net, withheld,
+    /// protocol fee, platform fee): both fees come off the gross, withholding
+    /// off what is left
+    pub(crate) fn release_split(
+        &self,
+        job_id: U256,
+        freelancer: Address,
+        amount: U256,
+    ) -> (U256, U256, U256, U256) {
+        let fee = self.protocol_fee(job_id, amount);
+        let platform_fee = self.platform_fee(job_id, amount);
+        let payout = amount - fee - platform_fee;
+        let rate_bps = U256::from(self.withholdings.get(freelancer).rate_bps.get());
+        let withheld = payout * rate_bps / U256::from(BASIS_POINTS);
+        (payout - withheld, withheld, fee, platform_fee)
+    }
+
+    /// Revert unless `job_id` is a funded job that has not been settled
+    pub(crate) fn assert_settleable(&self, job_id: U256) -> Result<(), Vec<u8>> {
+        self.assert_job_exists(job_id)?;
+        let job = self.jobs.get(job_id);
+        if job.released.get() || job.refunded.get() {
+            return Err(ErrorCode::JobAlreadySettled.into());
+        }
+        if self.finalized.get(job_id) {
+            return Err(ErrorCode::JobAlreadyFinalized.into());
+        }
+        self.assert_funded(job_id)
+    }
+
+    /// Revert unless `job_id` is settleable and its settlement would move
+    /// only the ETH a preview reports
+    pub(crate) fn assert_previewable(&self, job_id: U256) -> Result<(), Vec<u8>> {
+        self.assert_settleable(job_id)?;
+        self.assert_no_basket(job_id)?;
+        self.assert_not_staked(job_id)
+    }
+}
@@ -0,0 +1,97 @@
+//! Freelancer work history and client standing.
+//!
+//! Next to the lifetime earnings in `user_stats`, every freelancer keeps how
+//! many of its jobs were paid out, how long they took from deposit to
+//! settlement and how many were disputed, so proof-of-income tools can read
+//! a freelancer's record from `get_reputation` instead of replaying its
+//! jobs.
+//!
+//! A job counts as completed when it settles as released, including a
+//! ruling that gives the freelancer any share; refunded and swept jobs only
+//! show up in the dispute count, if they were disputed.
+//!
+//! Clients get the mirror image for freelancers weighing an offer: how much
+//! they have funded, what share of it they took back, and how many disputes
+//! over their jobs an arbitrator ruled against them. Disputes settled at a
+//! default or timeout split are not rulings, so they count as disputes but
+//! never as losses.
+
+use crate::{Escrow, BASIS_POINTS};
+use alloy_primitives::{Address, Uint, U256};
+use stylus_sdk::prelude::*;
+
+impl Escrow {
+    /// Count `job_id`, just settled as released, towards its freelancer's
+    /// completed jobs and settlement time
+    pub(crate) fn record_completion(&mut self, job_id: U256, freelancer: Address) {
+        let created_at: u64 = self.jobs.get(job_id).created_at.get().to();
+        let took = self.vm().block_timestamp().saturating_sub(created_at);
+
+        let mut profile = self.freelancer_profiles.setter(freelancer);
+        let completed = profile.jobs_completed.get();
+        profile
+            .jobs_completed
+            .set(completed + Uint::<32, 1>::from(1));
+        let total = profile.settlement_time.get();
+        profile
+            .settlement_time
+            .set(total.saturating_add(Uint::<64, 1>::from(took)));
+    }
+
+    /// Count a dispute just opened over `job_id` against its client and
+    /// freelancer
+    pub(crate) fn record_dispute(&mut self, job_id: U256) {
+        let job = self.jobs.get(job_id);
+        let client = job.client.get();
+        let freelancer = job.freelancer.get();
+
+        let mut profile = self.freelancer_profiles.setter(freelancer);
+        let disputes = profile.disputes.get();
+        profile.disputes.set(disputes + Uint::<32, 1>::from(1));
+        let mut profile = self.client_profiles.setter(client);
+        let disputes = profile.disputes.get();
+        profile.disputes.set(disputes + Uint::<32, 1>::from(1));
+    }
+
+    /// Count a ruling `client` lost as a job's client
+    pub(crate) fn record_client_loss(&mut self, client: Address) {
+        let mut profile = self.client_profiles.setter(client);
+        let lost = profile.disputes_lost.get();
+        profile.disputes_lost.set(lost + Uint::<32, 1>::from(1));
+    }
+
+    /// `freelancer`'s completed jobs, their average settlement time and
+    /// disputes
+    pub(crate) fn freelancer_profile(&self, freelancer: Address) -> (u32, u64, u32) {
+        let profile = self.freelancer_profiles.get(freelancer);
+        let completed: u32 = profile.jobs_completed.get().to();
+        let total: u64 = profile.settlement_time.get().to();
+        let average = if completed == 0 {
+            0
+        } else {
+            total / u64::from(completed)
+        };
+        (completed, average, profile.disputes.get().to())
+    }
+
+    /// The share of `client`'s funded volume refunded, the disputes over its
+    /// jobs and the share of those it lost, both shares in basis points
+    pub(crate) fn client_profile(&self, client: Address) -> (u16, u32, u16) {
+        let stats = self.user_stats.get(client);
+        let deposited = stats.total_deposited.get();
+        let refund_bps = if deposited.is_zero() {
+            0
+        } else {
+            (stats.total_refunded.get() * U256::from(BASIS_POINTS) / deposited).to()
+        };
+        let profile = self.client_profiles.get(client);
+        let disputes: u32 = profile.disputes.get().to();
+        let lost: u32 = profile.disputes_lost.get().to();
+        let loss_bps = if disputes == 0 {
+            0
+        } else {
+            (u64::from(lost) * u64::from(BASIS_POINTS) / u64::from(disputes)) as u16
+        };
+        (refund_bps, disputes, loss_bps)
+    }
+}
@@ -0,0 +1,46 @@
+//! Gas rebates for settling jobs.
+//!
+//! The fee manager can set a fixed settlement rebate, in wei. Whoever sends
+//! a keeper-style settlement, `auto_release`, `release_holdback` or
+//! `finalize_release`, then gets up to that much back out of the protocol
+//! fee the payout was just charged, so running a keeper pays for its own
+//! gas. The rebate never exceeds that fee: a fee-exempt or zero-fee job
+//! rebates nothing, and the job's principal is never touched. Zero turns
+//! rebates off.
+
+use crate::{Escrow, SettlementRebated};
+use alloy_primitives::U256;
+use stylus_sdk::prelude::*;
+
+impl Escrow {
+    /// Pay the sender the settlement rebate out of the protocol fee charged
+    /// on releasing `amount` of `job_id`; the fee must already be accrued
+    pub(crate) fn pay_settlement_rebate(
+        &mut self,
+        job_id: U256,
+        amount: U256,
+    ) -> Result<(), Vec<u8>> {
+        let rebate = self
+            .settlement_rebate
+            .get()
+            .min(self.protocol_fee(job_id, amount));
+        if rebate.is_zero() {
+            return Ok(());
+        }
+        let accrued = self.accrued_fees.get();
+        self.accrued_fees.set(accrued - rebate);
+
+        let caller = self.vm().msg_sender();
+        self.vm().transfer_eth(caller, rebate)?;
+
+        log(
+            self.vm(),
+            SettlementRebated {
+                job_id,
+                caller,
+                amount: rebate,
+            },
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+//! Batch emergency refunds claimed against a Merkle root.
+//!
+//! After a mass incident, such as a compromised marketplace, refunding every
+//! affected job with its own `emergency_refund` doesn't scale. Instead the
+//! admin, or governance once it is configured, publishes the root of a Merkle
+//! tree of (job ID, refund amount) entries, and each affected client claims
+//! their own refund with a proof. Leaves are built like OpenZeppelin's
+//! `StandardMerkleTree` over `["uint256", "uint256"]`, and proofs hash sorted
+//! pairs, so trees made with its tooling can be published as they are.
+//!
+//! A claim of the job's whole amount refunds and settles the job; a smaller
+//! one refunds that much and leaves the rest escrowed for the parties to
+//! settle as usual. Each job can claim once per published root.
+
+use crate::{EmergencyRefundClaimed, Escrow, RefundRootSet};
+use alloy_primitives::{keccak256, B256, U256};
+use stylus_sdk::prelude::*;
+
+/// Whether `proof` leads from `leaf` to `root`, hashing each pair in sorted
+/// order
+pub(crate) fn verify_proof(proof: &[B256], root: B256, leaf: B256) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        if node <= *sibling {
+            keccak256([node.as_slice(), sibling.as_slice()].concat())
+        } else {
+            keccak256([sibling.as_slice(), node.as_slice()].concat())
+        }
+    });
+    computed == root
+}
+
+impl Escrow {
+    /// Publish `root` as the tree emergency refunds are claimed against,
+    /// replacing any earlier one; the zero root stops claims
+    pub(crate) fn update_refund_root(&mut self, root: B256) {
+        self.refund_root.set(root);
+
+        log(self.vm(), RefundRootSet { root });
+    }
+
+    /// Refund `amount` of `job_id` to its client, settling the job if that is
+    /// all of it; the claim must already have been checked
+    pub(crate) fn pay_refund_claim(&mut self, job_id: U256, amount: U256) -> Result<(), Vec<u8>> {
+        let root = self.refund_root.get();
+        self.refund_claims.setter(root).setter(job_id).set(true);
+
+        let job = self.jobs.get(job_id);
+        let client = job.client.get();
+        let remaining = job.amount.get() - amount;
+
+        if remaining.is_zero() {
+            self.jobs.setter(job_id).refunded.set(true);
+            self.close_job(job_id);
+        } else {
+            self.jobs.setter(job_id).amount.set(remaining);
+        }
+        self.record_refund(job_id, client, amount);
+
+        self.vm().transfer_eth(client, amount)?;
+        if remaining.is_zero() {
+            self.pay_basket(job_id, 0)?;
+        }
+
+        log(
+            self.vm(),
+            EmergencyRefundClaimed {
+                job_id,
+                client,
+                amount,
+                remaining,
+            },
+        );
+        if remaining.is_zero() {
+            self.call_settlement_hook(job_id, amount, false);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,224 @@
+//! Job bookkeeping.
+//!
+//! Jobs live in a plain mapping from ID to `Job`, with `job_count` as the
+//! number created; there is no separate job store. Every job is created by
+//! `push_job`, which hands out IDs sequentially from 1, so every ID from 1
+//! to `job_count` is a real job and anything outside it was never created,
+//! and views that must tell a missing job from an empty one check
+//! `assert_job_exists`. Every settlement marks the job released, refunded
+//! or swept and then calls `close_job`.
+//!
+//! The mapping stays rather than becoming a `StorageVec<Job>`. A vector
+//! would move every job to a different slot, breaking the layout the
+//! `layout` tool publishes for `cast storage` reads (`jobs[7].client`) and
+//! the reference contract's `jobs` mapping. It would also need ID `n` at
+//! index `n - 1`, because ID 0 means "no job" in every side table keyed by
+//! job. `push_job` as the only writer, `assert_job_exists` and
+//! `job_window` already give what a vector would: no gaps, no phantom jobs
+//! and range reads.
+//!
+//! Each address also keeps an index of the jobs it has been client or
+//! freelancer of, so a party's jobs can be listed without scanning them all.
+//! Jobs that still hold funds are kept in an active set as well, an array
+//! with each job's position beside it, that a job is swap-removed from as it
+//! closes, so keepers walk open jobs only.
+
+use crate::{
+    ErrorCode, Escrow, JobPage, JOB_STATUS_ACTIVE, JOB_STATUS_REFUNDED, JOB_STATUS_RELEASED,
+    JOB_STATUS_SWEPT, MAX_JOBS_RANGE,
+};
+use alloy_primitives::{Address, Uint, U256};
+use stylus_sdk::prelude::*;
+
+impl Escrow {
+    /// Allocate the next job ID, record its parties and creation time and
+    /// fix its fee rate for a job of `size`
+    pub(crate) fn push_job(&mut self, client: Address, freelancer: Address, size: U256) -> U256 {
+        let job_id = self.job_count.get() + U256::from(1);
+        let now = self.vm().block_timestamp();
+        let mut job = self.jobs.setter(job_id);
+        job.job_id.set(job_id);
+        job.client.set(client);
+        job.freelancer.set(freelancer);
+        job.created_at.set(Uint::<64, 1>::from(now));
+        self.job_count.set(job_id);
+        self.index_party(job_id, client);
+        self.index_party(job_id, freelancer);
+        self.snapshot_fee(job_id, client, size);
+        self.active_job_ids.push(job_id);
+        let position = U256::from(self.active_job_ids.len());
+        self.active_job_positions.setter(job_id).set(position);
+        job_id
+    }
+
+    /// Finalize a settled job, taking it out of the active set, freeing its
+    /// place under the freelancer's cap, returning any dispute stake or
+    /// appeal bond it settled around and, if released, adding it to the
+    /// freelancer's history
+    pub(crate) fn close_job(&mut self, job_id: U256) {
+        self.finalized.setter(job_id).set(true);
+        self.deactivate_job(job_id);
+        self.credit_dispute_stake(job_id);
+        self.credit_appeal_bond(job_id);
+
+        // Unrevealed and never-funded jobs were not counted
+        let freelancer = self.jobs.get(job_id).freelancer.get();
+        if freelancer == Address::ZERO || !self.is_funded(job_id) {
+            return;
+        }
+        self.free_job_slot(freelancer);
+        if self.jobs.get(job_id).released.get() {
+            self.record_completion(job_id, freelancer);
+        }
+    }
+
+    /// Take `job_id` out of the active set, moving the last active job into
+    /// its place
+    pub(crate) fn deactivate_job(&mut self, job_id: U256) {
+        let position = self.active_job_positions.get(job_id);
+        if position.is_zero() {
+            return;
+        }
+        let index = position.to::<usize>() - 1;
+        let last = self.active_job_ids.len() - 1;
+        if index != last {
+            let moved = self.active_job_ids.get(last).unwrap_or_default();
+            if let Some(mut slot) = self.active_job_ids.setter(index) {
+                slot.set(moved);
+            }
+            self.active_job_positions.setter(moved).set(position);
+        }
+        self.active_job_ids.erase_last();
+        self.active_job_positions.setter(job_id).set(U256::ZERO);
+    }
+
+    /// Active job IDs with their positions in the set, from `start` on
+    pub(crate) fn active_jobs_from(
+        &self,
+        start: usize,
+    ) -> impl Iterator<Item = (usize, U256)> + '_ {
+        (start..self.active_job_ids.len())
+            .filter_map(|i| self.active_job_ids.get(i).map(|job_id| (i, job_id)))
+    }
+
+    /// Add `job_id` to the jobs `party` is listed under, once; entries are
+    /// never removed, so a party that has since been replaced still lists
+    /// the job and readers check `is_party`
+    pub(crate) fn index_party(&mut self, job_id: U256, party: Address) {
+        if party == Address::ZERO || self.party_indexed.get(party).get(job_id) {
+            return;
+        }
+        self.party_indexed.setter(party).setter(job_id).set(true);
+        self.party_jobs.setter(party).push(job_id);
+    }
+
+    /// Whether `party` is currently `job_id`'s client or freelancer
+    pub(crate) fn is_party(&self, job_id: U256, party: Address) -> bool {
+        let job = self.jobs.get(job_id);
+        job.client.get() == party || job.freelancer.get() == party
+    }
+
+    /// `job_id`'s `JOB_STATUS_*` status
+    pub(crate) fn job_status(&self, job_id: U256) -> u8 {
+        let job = self.jobs.get(job_id);
+        if job.released.get() {
+            JOB_STATUS_RELEASED
+        } else if job.refunded.get() {
+            JOB_STATUS_REFUNDED
+        } else if job.swept.get() {
+            JOB_STATUS_SWEPT
+        } else {
+            JOB_STATUS_ACTIVE
+        }
+    }
+
+    /// Whether `job_id` was ever created
+    pub(crate) fn job_exists(&self, job_id: U256) -> bool {
+        !job_id.is_zero() && job_id <= self.job_count.get()
+    }
+
+    /// Revert for IDs that were never created
+    pub(crate) fn assert_job_exists(&self, job_id: U256) -> Result<(), Vec<u8>> {
+        if !self.job_exists(job_id) {
+            return Err(ErrorCode::JobDoesNotExist.into());
+        }
+        Ok(())
+    }
+
+    /// Whether a job still holds funds: not released, refunded or swept
+    pub(crate) fn is_active(&self, job_id: U256) -> bool {
+        let job = self.jobs.get(job_id);
+        !job.released.get() && !job.refunded.get() && !job.swept.get()
+    }
+
+    /// Up to `count` job IDs from `start_id` on, stopping at the newest job
+    pub(crate) fn job_window(&self, start_id: U256, count: u64) -> impl Iterator<Item = U256> {
+        let start = start_id.saturating_to::<u64>();
+        let end = start
+            .saturating_add(count)
+            .min(self.job_count.get().to::<u64>() + 1);
+        (start..end).map(U256::from)
+    }
+
+    /// Up to `limit` jobs passing `matches`, as a page resuming from
+    /// `cursor`. With a nonzero `party` only its own index is scanned, and
+    /// the cursor is a position in it; otherwise it is a job ID, and at most
+    /// `MAX_JOBS_RANGE` IDs are scanned per call, so a page can come back
+    /// short or empty with a cursor to continue from
+    pub(crate) fn scan_jobs(
+        &self,
+        party: Address,
+        cursor: U256,
+        limit: u64,
+        matches: impl Fn(U256) -> bool,
+    ) -> JobPage {
+        let mut page = JobPage::default();
+        let push = |page: &mut JobPage, job_id: U256| {
+            let job = self.jobs.get(job_id);
+            page.0.push(job_id);
+            page.1.push(job.client.get());
+            page.2.push(job.freelancer.get());
+            page.3.push(job.amount.get());
+            page.4.push(job.deadline.get().to());
+            page.5.push(self.job_status(job_id));
+        };
+
+        // A party's own index holds every job it was ever party to, so only
+        // those are scanned, by position in the index
+        if party != Address::ZERO {
+            let index = self.party_jobs.get(party);
+            let start = cursor.saturating_to::<usize>();
+            for i in start..index.len() {
+                if page.0.len() as u64 == limit {
+                    page.6 = U256::from(i);
+                    return page;
+                }
+                let Some(job_id) = index.get(i) else {
+                    continue;
+                };
+                if matches(job_id) {
+                    push(&mut page, job_id);
+                }
+            }
+            return page;
+        }
+
+        // Without a party every job ID is a candidate, so the scan is
+        // bounded by IDs read rather than jobs matched
+        let start = cursor.max(U256::from(1));
+        for i in self.job_window(start, MAX_JOBS_RANGE) {
+            if page.0.len() as u64 == limit {
+                page.6 = i;
+                return page;
+            }
+            if matches(i) {
+                push(&mut page, i);
+            }
+        }
+        let next = start.saturating_add(U256::from(MAX_JOBS_RANGE));
+        if next <= self.job_count.get() {
+            page.6 = next;
+        }
+        page
+    }
+}
@@ -0,0 +1,133 @@
+//! Ratings and the reputation score built from them.
+//!
+//! Once a job has settled, each party can rate the other once, from 1 to
+//! `MAX_STARS` stars. Besides the raw count and star total, every address
+//! keeps a value-weighted average: each rating weighs as much as the amount
+//! the job settled with, and every weight halves over `REPUTATION_HALF_LIFE`
+//! so recent work counts for more. A job paid out in parts, by tranches or
+//! timesheets, weighs what its last payout left in it.
+//!
+//! Losing an arbitrator's ruling, by getting less than half of the job as
+//! the freelancer or more than half back as the client, counts against the
+//! loser as a zero-star rating weighing `DISPUTE_LOSS_WEIGHT` times the
+//! disputed amount. Settlements at a default or timeout split are not
+//! rulings and leave both parties' reputations alone.
+//!
+//! `get_score` normalizes the weighted average to basis points, measured
+//! against `REPUTATION_PRIOR` of unrated value, so a score only climbs as
+//! well-rated work builds up and drifts back down once it stops. Other
+//! contracts can threshold on it, e.g. to waive a bond for high scores.
+
+use crate::{
+    Escrow, JobRated, BASIS_POINTS, DISPUTE_LOSS_WEIGHT, MAX_STARS, REPUTATION_HALF_LIFE,
+    REPUTATION_PRIOR,
+};
+use alloy_primitives::{Address, Uint, U256};
+use stylus_sdk::prelude::*;
+
+/// `value` after `elapsed` seconds of halving every `REPUTATION_HALF_LIFE`,
+/// interpolated linearly within each half-life
+fn decay(value: U256, elapsed: u64) -> U256 {
+    let halvings = elapsed / REPUTATION_HALF_LIFE;
+    if halvings >= 256 {
+        return U256::ZERO;
+    }
+    let halved = value >> halvings as usize;
+    let into = elapsed % REPUTATION_HALF_LIFE;
+    halved - halved * U256::from(into) / U256::from(2 * REPUTATION_HALF_LIFE)
+}
+
+impl Escrow {
+    /// Record `rater`'s rating of `stars` for the other party to `job_id`;
+    /// the rating must already have been checked
+    pub(crate) fn rate(&mut self, job_id: U256, rater: Address, stars: u8) {
+        let job = self.jobs.get(job_id);
+        let client = job.client.get();
+        let value = job.amount.get();
+        let ratee = if rater == client {
+            self.job_ratings.setter(job_id).by_client.set(Uint::<8, 1>::from(stars));
+            job.freelancer.get()
+        } else {
+            self.job_ratings.setter(job_id).by_freelancer.set(Uint::<8, 1>::from(stars));
+            client
+        };
+
+        let mut reputation = self.reputations.setter(ratee);
+        let ratings = reputation.ratings.get();
+        reputation.ratings.set(ratings + Uint::<32, 1>::from(1));
+        let stars_total = reputation.stars_total.get();
+        reputation.stars_total.set(stars_total + Uint::<32, 1>::from(stars));
+        self.accrue(ratee, stars, value);
+
+        log(
+            self.vm(),
+            JobRated {
+                job_id,
+                rater,
+                ratee,
+                stars,
+            },
+        );
+    }
+
+    /// Count an arbitrator's ruling giving the freelancer `freelancer_bps`
+    /// of `job_id` against whichever party lost it; an even split has no
+    /// loser
+    pub(crate) fn record_ruling(&mut self, job_id: U256, freelancer_bps: u16) {
+        let job = self.jobs.get(job_id);
+        let freelancer = job.freelancer.get();
+        if freelancer == Address::ZERO {
+            return;
+        }
+        let half = BASIS_POINTS / 2;
+        let loser = match freelancer_bps {
+            bps if bps < half => freelancer,
+            bps if bps > half => job.client.get(),
+            _ => return,
+        };
+        let weight = job.amount.get() * U256::from(DISPUTE_LOSS_WEIGHT);
+
+        let mut reputation = self.reputations.setter(loser);
+        let lost = reputation.disputes_lost.get();
+        reputation.disputes_lost.set(lost + Uint::<32, 1>::from(1));
+        self.accrue(loser, 0, weight);
+        if loser != freelancer {
+            self.record_client_loss(loser);
+        }
+    }
+
+    /// Decay `account`'s weighted average to now and add `stars` weighing
+    /// `weight` to it
+    fn accrue(&mut self, account: Address, stars: u8, weight: U256) {
+        let (weighted_stars, total_weight) = self.weighted_reputation(account);
+        let now = self.vm().block_timestamp();
+
+        let mut reputation = self.reputations.setter(account);
+        reputation
+            .weighted_stars
+            .set(weighted_stars + weight * U256::from(stars));
+        reputation.weight.set(total_weight + weight);
+        reputation.updated_at.set(Uint::<64, 1>::from(now));
+    }
+
+    /// `account`'s weighted star total and weight as of now: (weighted
+    /// stars, weight)
+    pub(crate) fn weighted_reputation(&self, account: Address) -> (U256, U256) {
+        let reputation = self.reputations.get(account);
+        let updated_at: u64 = reputation.updated_at.get().to();
+        let elapsed = self.vm().block_timestamp().saturating_sub(updated_at);
+        (
+            decay(reputation.weighted_stars.get(), elapsed),
+            decay(reputation.weight.get(), elapsed),
+        )
+    }
+
+    /// `account`'s weighted average in basis points of `MAX_STARS`, counting
+    /// `REPUTATION_PRIOR` of unrated value
+    pub(crate) fn reputation_score(&self, account: Address) -> u16 {
+        let (weighted_stars, weight) = self.weighted_reputation(account);
+        let full = (weight + REPUTATION_PRIOR) * U256::from(MAX_STARS);
+        let score = weighted_stars * U256::from(BASIS_POINTS) / full;
+        score.to()
+    }
+}
@@ -0,0 +1,718 @@
+//! Rarely-used configuration, routed through `configure`.
+//!
+//! Policies and parameters the admin or fee manager sets once, or a few
+//! times in the contract's life, share the single `configure(setting, data)`
+//! entrypoint instead of exporting a function each: `setting` is one of the
+//! `SETTING_*` constants and `data` its arguments, ABI-encoded as a tuple.
+//! Every exported function costs router, decoding and ABI code in the WASM,
+//! so keeping these behind one selector leaves room under the Stylus size
+//! limit for the calls parties actually make. Each setter keeps its own
+//! permission check and validation. Reading them back goes through the
+//! single `get_setting(setting, key)` view the same way, returning the
+//! tuple `configure` takes; `key` picks the account for per-address
+//! settings and is ignored by the rest.
+//!
+//! Fee, deposit-limit, jury and arbitrator-registry settings are protocol
+//! parameters: once governance is configured the admin and fee manager
+//! can't change them, and a passed `GOVERNANCE_ACTION_SETTING` proposal
+//! applies them with the same encoded arguments instead.
+
+use crate::{
+    AttestationPolicySet, BasketTokenSet, ComplianceHookSet, ErrorCode, Escrow, MaxPauseSet,
+    PauserSet, StakingTokenSet, SwapRouterSet, MAX_APPEAL_BOND_BPS, MAX_DISPUTE_STAKE_BPS,
+    MAX_UNSTAKE_SLIPPAGE_BPS, SETTING_APPEAL_POLICY, SETTING_ARBITRATION_POLICY,
+    SETTING_ARBITRATOR_LISTING, SETTING_ATTESTATION_POLICY, SETTING_BASKET_TOKEN,
+    SETTING_COMPLIANCE_HOOK, SETTING_DEFAULT_JOB_CAP, SETTING_DEPOSIT_LIMITS,
+    SETTING_DEPOSIT_LIMIT_EXEMPT, SETTING_DISPUTE_STAKE_POLICY, SETTING_DISPUTE_TIMEOUT_POLICY,
+    SETTING_DORMANCY_POLICY, SETTING_FEE_EXEMPT, SETTING_FEE_MANAGER, SETTING_FEE_TIERS,
+    SETTING_JURY_POLICY, SETTING_MAX_PAUSE, SETTING_PAUSER, SETTING_PROTOCOL_FEE,
+    SETTING_SETTLEMENT_REBATE, SETTING_STAKING_TOKEN, SETTING_SWAP_ROUTER,
+    SETTING_VOLUME_DISCOUNTS,
+};
+use alloy_primitives::{Address, Uint, B256, U256};
+use alloy_sol_types::{abi::TokenSeq, sol_data, SolType, SolValue};
+use stylus_sdk::prelude::*;
+
+/// Decode `data` as the ABI-encoded tuple `T` of a setting's arguments
+fn decode<T>(data: &[u8]) -> Result<T::RustType, ErrorCode>
+where
+    T: SolType,
+    for<'de> T::Token<'de>: TokenSeq<'de>,
+{
+    T::abi_decode_params(data, true).map_err(|_| ErrorCode::InvalidSetting)
+}
+
+impl Escrow {
+    /// Apply `setting` with the arguments ABI-encoded in `data`
+    pub(crate) fn apply_setting(&mut self, setting: u8, data: &[u8]) -> Result<(), Vec<u8>> {
+        match setting {
+            SETTING_SWAP_ROUTER => {
+                let (router, weth) = decode::<(sol_data::Address, sol_data::Address)>(data)?;
+                self.set_swap_router(router, weth)
+            }
+            SETTING_BASKET_TOKEN => {
+                let (token, approved) = decode::<(sol_data::Address, sol_data::Bool)>(data)?;
+                self.set_basket_token(token, approved)
+            }
+            SETTING_COMPLIANCE_HOOK => {
+                let (hook,) = decode::<(sol_data::Address,)>(data)?;
+                self.set_compliance_hook(hook)
+            }
+            SETTING_DEFAULT_JOB_CAP => {
+                let (cap,) = decode::<(sol_data::Uint<32>,)>(data)?;
+                self.set_default_job_cap(cap)
+            }
+            SETTING_FEE_MANAGER => {
+                let (manager,) = decode::<(sol_data::Address,)>(data)?;
+                self.set_fee_manager(manager)
+            }
+            SETTING_PROTOCOL_FEE => {
+                let (fee_bps,) = decode::<(sol_data::Uint<16>,)>(data)?;
+                self.set_protocol_fee(fee_bps)
+            }
+            SETTING_SETTLEMENT_REBATE => {
+                let (amount,) = decode::<(sol_data::Uint<256>,)>(data)?;
+                self.set_settlement_rebate(amount)
+            }
+            SETTING_FEE_TIERS => {
+                let (min_amounts, fee_bps) = decode::<(
+                    sol_data::Array<sol_data::Uint<256>>,
+                    sol_data::Array<sol_data::Uint<16>>,
+                )>(data)?;
+                self.set_fee_tiers(min_amounts, fee_bps)
+            }
+            SETTING_VOLUME_DISCOUNTS => {
+                let (min_volumes, discount_bps) = decode::<(
+                    sol_data::Array<sol_data::Uint<256>>,
+                    sol_data::Array<sol_data::Uint<16>>,
+                )>(data)?;
+                self.set_volume_discounts(min_volumes, discount_bps)
+            }
+            SETTING_FEE_EXEMPT => {
+                let (account, exempt) = decode::<(sol_data::Address, sol_data::Bool)>(data)?;
+                self.set_fee_exempt(account, exempt)
+            }
+            SETTING_DEPOSIT_LIMITS => {
+                let (cooldown, per_block) =
+                    decode::<(sol_data::Uint<64>, sol_data::Uint<32>)>(data)?;
+                self.set_deposit_limits(cooldown, per_block)
+            }
+            SETTING_DEPOSIT_LIMIT_EXEMPT => {
+                let (account, exempt) = decode::<(sol_data::Address, sol_data::Bool)>(data)?;
+                self.set_deposit_limit_exempt(account, exempt)
+            }
+            SETTING_ATTESTATION_POLICY => {
+                let (eas, schema, attester) = decode::<(
+                    sol_data::Address,
+                    sol_data::FixedBytes<32>,
+                    sol_data::Address,
+                )>(data)?;
+                self.set_attestation_policy(eas, schema, attester)
+            }
+            SETTING_ARBITRATION_POLICY => {
+                let (arbitrator, ruling_period, default_split_bps) =
+                    decode::<(sol_data::Address, sol_data::Uint<64>, sol_data::Uint<16>)>(data)?;
+                self.set_arbitration_policy(arbitrator, ruling_period, default_split_bps)
+            }
+            SETTING_DISPUTE_TIMEOUT_POLICY => {
+                let (timeout, split, split_bps) =
+                    decode::<(sol_data::Uint<64>, sol_data::Bool, sol_data::Uint<16>)>(data)?;
+                self.set_dispute_timeout_policy(timeout, split, split_bps)
+            }
+            SETTING_DISPUTE_STAKE_POLICY => {
+                let (stake_bps, min_stake) =
+                    decode::<(sol_data::Uint<16>, sol_data::Uint<256>)>(data)?;
+                self.set_dispute_stake_policy(stake_bps, min_stake)
+            }
+            SETTING_JURY_POLICY => {
+                let (size, voting_period, min_stake, slash_bps) = decode::<(
+                    sol_data::Uint<8>,
+                    sol_data::Uint<64>,
+                    sol_data::Uint<256>,
+                    sol_data::Uint<16>,
+                )>(data)?;
+                self.set_jury_policy(size, voting_period, min_stake, slash_bps)
+            }
+            SETTING_APPEAL_POLICY => {
+                let (window, bond_bps, min_bond) =
+                    decode::<(sol_data::Uint<64>, sol_data::Uint<16>, sol_data::Uint<256>)>(data)?;
+                self.set_appeal_policy(window, bond_bps, min_bond)
+            }
+            SETTING_MAX_PAUSE => {
+                let (duration,) = decode::<(sol_data::Uint<64>,)>(data)?;
+                self.set_max_pause(duration)
+            }
+            SETTING_PAUSER => {
+                let (pauser,) = decode::<(sol_data::Address,)>(data)?;
+                self.set_pauser(pauser)
+            }
+            SETTING_DORMANCY_POLICY => {
+                let (period, recovery) = decode::<(sol_data::Uint<64>, sol_data::Address)>(data)?;
+                self.set_dormancy_policy(period, recovery)
+            }
+            SETTING_ARBITRATOR_LISTING => {
+                let (arbitrator, listed) = decode::<(sol_data::Address, sol_data::Bool)>(data)?;
+                self.set_arbitrator_listing(arbitrator, listed)
+            }
+            SETTING_STAKING_TOKEN => {
+                let (token, max_slippage_bps) =
+                    decode::<(sol_data::Address, sol_data::Uint<16>)>(data)?;
+                self.set_staking_token(token, max_slippage_bps)
+            }
+            _ => Err(ErrorCode::InvalidSetting.into()),
+        }
+    }
+
+    /// Whether `setting` is a protocol parameter governance changes once set
+    pub(crate) fn is_governed_setting(setting: u8) -> bool {
+        matches!(
+            setting,
+            SETTING_FEE_MANAGER
+                | SETTING_SETTLEMENT_REBATE
+                | SETTING_FEE_TIERS
+                | SETTING_VOLUME_DISCOUNTS
+                | SETTING_FEE_EXEMPT
+                | SETTING_DEPOSIT_LIMITS
+                | SETTING_JURY_POLICY
+                | SETTING_ARBITRATOR_LISTING
+        )
+    }
+
+    /// Apply a governed `setting` from a passed proposal, with its arguments
+    /// ABI-encoded in `data` as for `configure`
+    pub(crate) fn apply_governed_setting(
+        &mut self,
+        setting: u8,
+        data: &[u8],
+    ) -> Result<(), Vec<u8>> {
+        match setting {
+            SETTING_FEE_MANAGER => {
+                let (manager,) = decode::<(sol_data::Address,)>(data)?;
+                self.update_fee_manager(manager);
+                Ok(())
+            }
+            SETTING_SETTLEMENT_REBATE => {
+                let (amount,) = decode::<(sol_data::Uint<256>,)>(data)?;
+                self.update_settlement_rebate(amount);
+                Ok(())
+            }
+            SETTING_FEE_TIERS => {
+                let (min_amounts, fee_bps) = decode::<(
+                    sol_data::Array<sol_data::Uint<256>>,
+                    sol_data::Array<sol_data::Uint<16>>,
+                )>(data)?;
+                self.update_fee_tiers(min_amounts, fee_bps)
+            }
+            SETTING_VOLUME_DISCOUNTS => {
+                let (min_volumes, discount_bps) = decode::<(
+                    sol_data::Array<sol_data::Uint<256>>,
+                    sol_data::Array<sol_data::Uint<16>>,
+                )>(data)?;
+                self.update_volume_discounts(min_volumes, discount_bps)
+            }
+            SETTING_FEE_EXEMPT => {
+                let (account, exempt) = decode::<(sol_data::Address, sol_data::Bool)>(data)?;
+                self.update_fee_exemption(account, exempt);
+                Ok(())
+            }
+            SETTING_DEPOSIT_LIMITS => {
+                let (cooldown, per_block) =
+                    decode::<(sol_data::Uint<64>, sol_data::Uint<32>)>(data)?;
+                self.update_deposit_limits(cooldown, per_block);
+                Ok(())
+            }
+            SETTING_JURY_POLICY => {
+                let (size, voting_period, min_stake, slash_bps) = decode::<(
+                    sol_data::Uint<8>,
+                    sol_data::Uint<64>,
+                    sol_data::Uint<256>,
+                    sol_data::Uint<16>,
+                )>(data)?;
+                self.update_jury_policy(size, voting_period, min_stake, slash_bps)
+            }
+            SETTING_ARBITRATOR_LISTING => {
+                let (arbitrator, listed) = decode::<(sol_data::Address, sol_data::Bool)>(data)?;
+                self.list_arbitrator(arbitrator, listed)
+            }
+            _ => Err(ErrorCode::InvalidSetting.into()),
+        }
+    }
+
+    /// `setting`'s current value, ABI-encoded as the tuple `configure` takes
+    /// for it; per-address settings read `key`'s entry
+    pub(crate) fn setting_value(&self, setting: u8, key: Address) -> Result<Vec<u8>, Vec<u8>> {
+        let value = match setting {
+            SETTING_SWAP_ROUTER => (self.swap_router.get(), self.weth.get()).abi_encode_params(),
+            SETTING_BASKET_TOKEN => (key, self.basket_tokens.get(key)).abi_encode_params(),
+            SETTING_COMPLIANCE_HOOK => (self.compliance_hook.get(),).abi_encode_params(),
+            SETTING_DEFAULT_JOB_CAP => {
+                (self.default_job_cap.get().to::<u32>(),).abi_encode_params()
+            }
+            SETTING_FEE_MANAGER => (self.current_fee_manager(),).abi_encode_params(),
+            SETTING_PROTOCOL_FEE => (self.protocol_fee_bps.get().to::<u16>(),).abi_encode_params(),
+            SETTING_SETTLEMENT_REBATE => (self.settlement_rebate.get(),).abi_encode_params(),
+            SETTING_FEE_TIERS => {
+                let tiers = (0..self.fee_tiers.len()).filter_map(|i| self.fee_tiers.get(i));
+                let (min_amounts, fee_bps): (Vec<U256>, Vec<u16>) = tiers
+                    .map(|tier| (tier.min_amount.get(), tier.fee_bps.get().to::<u16>()))
+                    .unzip();
+                (min_amounts, fee_bps).abi_encode_params()
+            }
+            SETTING_VOLUME_DISCOUNTS => {
+                let discounts =
+                    (0..self.volume_discounts.len()).filter_map(|i| self.volume_discounts.get(i));
+                let (min_volumes, discount_bps): (Vec<U256>, Vec<u16>) = discounts
+                    .map(|discount| {
+                        (
+                            discount.min_volume.get(),
+                            discount.discount_bps.get().to::<u16>(),
+                        )
+                    })
+                    .unzip();
+                (min_volumes, discount_bps).abi_encode_params()
+            }
+            SETTING_FEE_EXEMPT => (key, self.fee_exempt.get(key)).abi_encode_params(),
+            SETTING_DEPOSIT_LIMITS => (
+                self.deposit_cooldown.get().to::<u64>(),
+                self.deposits_per_block.get().to::<u32>(),
+            )
+                .abi_encode_params(),
+            SETTING_DEPOSIT_LIMIT_EXEMPT => {
+                (key, self.deposit_limit_exempt.get(key)).abi_encode_params()
+            }
+            SETTING_ATTESTATION_POLICY => (
+                self.eas.get(),
+                self.attestation_schema.get(),
+                self.attester.get(),
+            )
+                .abi_encode_params(),
+            SETTING_ARBITRATION_POLICY => (
+                self.arbitrator.get(),
+                self.ruling_period.get().to::<u64>(),
+                self.default_split_bps.get().to::<u16>(),
+            )
+                .abi_encode_params(),
+            SETTING_DISPUTE_TIMEOUT_POLICY => (
+                self.dispute_timeout.get().to::<u64>(),
+                self.timeout_split.get(),
+                self.timeout_split_bps.get().to::<u16>(),
+            )
+                .abi_encode_params(),
+            SETTING_DISPUTE_STAKE_POLICY => (
+                self.dispute_stake_bps.get().to::<u16>(),
+                self.min_dispute_stake.get(),
+            )
+                .abi_encode_params(),
+            SETTING_JURY_POLICY => <(
+                sol_data::Uint<8>,
+                sol_data::Uint<64>,
+                sol_data::Uint<256>,
+                sol_data::Uint<16>,
+            )>::abi_encode_params(&(
+                self.jury_size.get().to::<u8>(),
+                self.jury_voting_period.get().to::<u64>(),
+                self.min_juror_stake.get(),
+                self.juror_slash_bps.get().to::<u16>(),
+            )),
+            SETTING_APPEAL_POLICY => (
+                self.appeal_window.get().to::<u64>(),
+                self.appeal_bond_bps.get().to::<u16>(),
+                self.min_appeal_bond.get(),
+            )
+                .abi_encode_params(),
+            SETTING_MAX_PAUSE => (self.max_pause.get().to::<u64>(),).abi_encode_params(),
+            SETTING_PAUSER => (self.pauser.get(),).abi_encode_params(),
+            SETTING_DORMANCY_POLICY => (
+                self.dormancy_period.get().to::<u64>(),
+                self.recovery_address.get(),
+            )
+                .abi_encode_params(),
+            SETTING_ARBITRATOR_LISTING => {
+                (key, self.is_listed_arbitrator.get(key)).abi_encode_params()
+            }
+            SETTING_STAKING_TOKEN => (
+                self.staking_token.get(),
+                self.max_unstake_slippage_bps.get().to::<u16>(),
+            )
+                .abi_encode_params(),
+            _ => return Err(ErrorCode::InvalidSetting.into()),
+        };
+        Ok(value)
+    }
+
+    /// ADMIN: approve the Uniswap V2-style `router` that swaps payouts, with
+    /// the `weth` it routes through; the zero address disables swaps, while
+    /// `weth` alone still lets freelancers take payouts wrapped
+    pub(crate) fn set_swap_router(
+        &mut self,
+        router: Address,
+        weth: Address,
+    ) -> Result<(), Vec<u8>> {
+        if self.vm().msg_sender() != self.admin.get() {
+            return Err(ErrorCode::OnlyAdmin.into());
+        }
+        if router != Address::ZERO && weth == Address::ZERO {
+            return Err(ErrorCode::InvalidWETHAddress.into());
+        }
+
+        self.swap_router.set(router);
+        self.weth.set(weth);
+
+        log(self.vm(), SwapRouterSet { router, weth });
+
+        Ok(())
+    }
+
+    /// ADMIN: allow or stop new jobs being funded with `token` in a basket;
+    /// jobs already holding it are unaffected
+    pub(crate) fn set_basket_token(
+        &mut self,
+        token: Address,
+        approved: bool,
+    ) -> Result<(), Vec<u8>> {
+        if self.vm().msg_sender() != self.admin.get() {
+            return Err(ErrorCode::OnlyAdmin.into());
+        }
+        if token == Address::ZERO {
+            return Err(ErrorCode::InvalidToken.into());
+        }
+
+        self.basket_tokens.setter(token).set(approved);
+
+        log(self.vm(), BasketTokenSet { token, approved });
+
+        Ok(())
+    }
+
+    /// ADMIN: screen deposits and payouts with `hook`'s
+    /// `check(client, freelancer, amount)`; the zero address turns it off
+    pub(crate) fn set_compliance_hook(&mut self, hook: Address) -> Result<(), Vec<u8>> {
+        if self.vm().msg_sender() != self.admin.get() {
+            return Err(ErrorCode::OnlyAdmin.into());
+        }
+        self.compliance_hook.set(hook);
+
+        log(self.vm(), ComplianceHookSet { hook });
+
+        Ok(())
+    }
+
+    /// ADMIN: limit how many active jobs a freelancer may hold at once;
+    /// 0 removes the limit
+    pub(crate) fn set_default_job_cap(&mut self, cap: u32) -> Result<(), Vec<u8>> {
+        if self.vm().msg_sender() != self.admin.get() {
+            return Err(ErrorCode::OnlyAdmin.into());
+        }
+        self.assert_not_governed()?;
+        self.update_default_job_cap(cap);
+
+        Ok(())
+    }
+
+    /// ADMIN: hand fee configuration to `manager`; the zero address gives
+    /// it back to the admin
+    pub(crate) fn set_fee_manager(&mut self, manager: Address) -> Result<(), Vec<u8>> {
+        if self.vm().msg_sender() != self.admin.get() {
+            return Err(ErrorCode::OnlyAdmin.into());
+        }
+        self.assert_not_governed()?;
+        self.update_fee_manager(manager);
+
+        Ok(())
+    }
+
+    /// FEE MANAGER: set the protocol fee charged on payouts, in basis points
+    pub(crate) fn set_protocol_fee(&mut self, fee_bps: u16) -> Result<(), Vec<u8>> {
+        self.assert_fee_manager()?;
+        self.assert_not_governed()?;
+        self.update_protocol_fee(fee_bps)
+    }
+
+    /// FEE MANAGER: rebate up to `amount` wei of each keeper-style
+    /// settlement's protocol fee to whoever sends it; zero turns rebates off
+    pub(crate) fn set_settlement_rebate(&mut self, amount: U256) -> Result<(), Vec<u8>> {
+        self.assert_fee_manager()?;
+        self.assert_not_governed()?;
+        self.update_settlement_rebate(amount);
+
+        Ok(())
+    }
+
+    /// FEE MANAGER: replace the size tiers. A job whose amount reaches a
+    /// tier's `min_amounts` entry pays that tier's `fee_bps` instead of the
+    /// protocol fee; minimums must be strictly increasing. Jobs keep the rate
+    /// they were opened with.
+    pub(crate) fn set_fee_tiers(
+        &mut self,
+        min_amounts: Vec<U256>,
+        fee_bps: Vec<u16>,
+    ) -> Result<(), Vec<u8>> {
+        self.assert_fee_manager()?;
+        self.assert_not_governed()?;
+        self.update_fee_tiers(min_amounts, fee_bps)
+    }
+
+    /// FEE MANAGER: replace the volume discounts. A client whose lifetime
+    /// released volume reaches a discount's `min_volumes` entry has that
+    /// share, in basis points, taken off the fee rate of jobs they open;
+    /// minimums must be strictly increasing.
+    pub(crate) fn set_volume_discounts(
+        &mut self,
+        min_volumes: Vec<U256>,
+        discount_bps: Vec<u16>,
+    ) -> Result<(), Vec<u8>> {
+        self.assert_fee_manager()?;
+        self.assert_not_governed()?;
+        self.update_volume_discounts(min_volumes, discount_bps)
+    }
+
+    /// ADMIN: exempt a partner's jobs from the protocol fee, or end the
+    /// exemption; jobs keep the rate they were opened with
+    pub(crate) fn set_fee_exempt(&mut self, account: Address, exempt: bool) -> Result<(), Vec<u8>> {
+        if self.vm().msg_sender() != self.admin.get() {
+            return Err(ErrorCode::OnlyAdmin.into());
+        }
+        self.assert_not_governed()?;
+        self.update_fee_exemption(account, exempt);
+
+        Ok(())
+    }
+
+    /// ADMIN: make each client wait `cooldown` seconds between the jobs it
+    /// opens and open at most `per_block` in one block; 0 turns either off
+    pub(crate) fn set_deposit_limits(
+        &mut self,
+        cooldown: u64,
+        per_block: u32,
+    ) -> Result<(), Vec<u8>> {
+        if self.vm().msg_sender() != self.admin.get() {
+            return Err(ErrorCode::OnlyAdmin.into());
+        }
+        self.assert_not_governed()?;
+        self.update_deposit_limits(cooldown, per_block);
+
+        Ok(())
+    }
+
+    /// ADMIN: exempt an address, such as a marketplace opening jobs for
+    /// many clients, from the deposit limits, or end the exemption
+    pub(crate) fn set_deposit_limit_exempt(
+        &mut self,
+        account: Address,
+        exempt: bool,
+    ) -> Result<(), Vec<u8>> {
+        if self.vm().msg_sender() != self.admin.get() {
+            return Err(ErrorCode::OnlyAdmin.into());
+        }
+        self.update_deposit_limit_exemption(account, exempt);
+
+        Ok(())
+    }
+
+    /// ADMIN: configure which EAS attestations mark a freelancer as verified;
+    /// the zero `eas` address disables attested deposits
+    pub(crate) fn set_attestation_policy(
+        &mut self,
+        eas: Address,
+        schema: B256,
+        attester: Address,
+    ) -> Result<(), Vec<u8>> {
+        if self.vm().msg_sender() != self.admin.get() {
+            return Err(ErrorCode::OnlyAdmin.into());
+        }
+        if eas != Address::ZERO && attester == Address::ZERO {
+            return Err(ErrorCode::InvalidAttesterAddress.into());
+        }
+
+        self.eas.set(eas);
+        self.attestation_schema.set(schema);
+        self.attester.set(attester);
+
+        log(
+            self.vm(),
+            AttestationPolicySet {
+                eas,
+                schema,
+                attester,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// ADMIN: appoint the `arbitrator` assigned to new disputes, who has
+    /// `ruling_period` seconds to rule before either party can settle at
+    /// `default_split_bps` to the freelancer; the zero address leaves new
+    /// disputes without one. Open disputes keep the policy they opened with.
+    pub(crate) fn set_arbitration_policy(
+        &mut self,
+        arbitrator: Address,
+        ruling_period: u64,
+        default_split_bps: u16,
+    ) -> Result<(), Vec<u8>> {
+        if self.vm().msg_sender() != self.admin.get() {
+            return Err(ErrorCode::OnlyAdmin.into());
+        }
+        self.assert_not_governed()?;
+        self.update_arbitration_policy(arbitrator, ruling_period, default_split_bps)
+    }
+
+    /// ADMIN: set how long new disputes may stay unresolved, 0 for no limit.
+    /// Past it a dispute is either split, giving the freelancer `split_bps`,
+    /// or lifted so the job settles by its deadline (`split` false).
+    pub(crate) fn set_dispute_timeout_policy(
+        &mut self,
+        timeout: u64,
+        split: bool,
+        split_bps: u16,
+    ) -> Result<(), Vec<u8>> {
+        if self.vm().msg_sender() != self.admin.get() {
+            return Err(ErrorCode::OnlyAdmin.into());
+        }
+        self.assert_not_governed()?;
+        self.update_dispute_timeout_policy(timeout, split, split_bps)
+    }
+
+    /// ADMIN: make whoever opens a dispute stake `stake_bps` of the job (at
+    /// most `MAX_DISPUTE_STAKE_BPS`), and at least `min_stake`; open
+    /// disputes keep the stake they were opened with
+    pub(crate) fn set_dispute_stake_policy(
+        &mut self,
+        stake_bps: u16,
+        min_stake: U256,
+    ) -> Result<(), Vec<u8>> {
+        if self.vm().msg_sender() != self.admin.get() {
+            return Err(ErrorCode::OnlyAdmin.into());
+        }
+        if stake_bps > MAX_DISPUTE_STAKE_BPS {
+            return Err(ErrorCode::InvalidStake.into());
+        }
+        self.update_dispute_stake_policy(stake_bps, min_stake);
+
+        Ok(())
+    }
+
+    /// ADMIN: make escalated disputes seat `size` jurors (odd, at most
+    /// `MAX_JURY_SIZE`; 0 turns juries off) voting for `voting_period`
+    /// seconds, each staked at least `min_stake` and losing `slash_bps` of
+    /// it for voting outside the majority; seated juries keep their terms
+    pub(crate) fn set_jury_policy(
+        &mut self,
+        size: u8,
+        voting_period: u64,
+        min_stake: U256,
+        slash_bps: u16,
+    ) -> Result<(), Vec<u8>> {
+        if self.vm().msg_sender() != self.admin.get() {
+            return Err(ErrorCode::OnlyAdmin.into());
+        }
+        self.assert_not_governed()?;
+        self.update_jury_policy(size, voting_period, min_stake, slash_bps)
+    }
+
+    /// ADMIN: hold new rulings for `window` seconds of appeal to governance
+    /// (0 settles them at once), taking appeal bonds of `bond_bps` of the
+    /// job (at most `MAX_APPEAL_BOND_BPS`), at least `min_bond`. Rulings
+    /// are only held once governance is set.
+    pub(crate) fn set_appeal_policy(
+        &mut self,
+        window: u64,
+        bond_bps: u16,
+        min_bond: U256,
+    ) -> Result<(), Vec<u8>> {
+        if self.vm().msg_sender() != self.admin.get() {
+            return Err(ErrorCode::OnlyAdmin.into());
+        }
+        if bond_bps > MAX_APPEAL_BOND_BPS {
+            return Err(ErrorCode::InvalidBond.into());
+        }
+        self.update_appeal_policy(window, bond_bps, min_bond);
+
+        Ok(())
+    }
+
+    /// ADMIN: cap how long a new pause may last, so a lost or rogue admin key
+    /// can't freeze settlements for good; 0 removes the cap
+    pub(crate) fn set_max_pause(&mut self, duration: u64) -> Result<(), Vec<u8>> {
+        if self.vm().msg_sender() != self.admin.get() {
+            return Err(ErrorCode::OnlyAdmin.into());
+        }
+        self.max_pause.set(Uint::<64, 1>::from(duration));
+
+        log(self.vm(), MaxPauseSet { duration });
+
+        Ok(())
+    }
+
+    /// ADMIN: let `pauser`, e.g. an incident-response bot, pause the escrow
+    /// but not unpause it or use any other admin power; zero removes it
+    pub(crate) fn set_pauser(&mut self, pauser: Address) -> Result<(), Vec<u8>> {
+        if self.vm().msg_sender() != self.admin.get() {
+            return Err(ErrorCode::OnlyAdmin.into());
+        }
+        self.pauser.set(pauser);
+
+        log(self.vm(), PauserSet { pauser });
+
+        Ok(())
+    }
+
+    /// ADMIN: sweep jobs left unsettled for `period` seconds past their
+    /// deadline to `recovery`
+    pub(crate) fn set_dormancy_policy(
+        &mut self,
+        period: u64,
+        recovery: Address,
+    ) -> Result<(), Vec<u8>> {
+        if self.vm().msg_sender() != self.admin.get() {
+            return Err(ErrorCode::OnlyAdmin.into());
+        }
+        self.assert_not_governed()?;
+        self.update_dormancy_policy(period, recovery)
+    }
+
+    /// ADMIN: list `arbitrator` in the registry clients pick their jobs'
+    /// arbitrators from, or unlist it; jobs that named it keep it
+    pub(crate) fn set_arbitrator_listing(
+        &mut self,
+        arbitrator: Address,
+        listed: bool,
+    ) -> Result<(), Vec<u8>> {
+        if self.vm().msg_sender() != self.admin.get() {
+            return Err(ErrorCode::OnlyAdmin.into());
+        }
+        self.assert_not_governed()?;
+        self.list_arbitrator(arbitrator, listed)
+    }
+
+    /// ADMIN: set the liquid staking `token` long jobs can be staked in, and
+    /// the discount to their redemption value (at most
+    /// `MAX_UNSTAKE_SLIPPAGE_BPS`) staked shares can be bought back at; the
+    /// zero address stops new stakes, while staked jobs keep their token
+    pub(crate) fn set_staking_token(
+        &mut self,
+        token: Address,
+        max_slippage_bps: u16,
+    ) -> Result<(), Vec<u8>> {
+        if self.vm().msg_sender() != self.admin.get() {
+            return Err(ErrorCode::OnlyAdmin.into());
+        }
+        if max_slippage_bps > MAX_UNSTAKE_SLIPPAGE_BPS {
+            return Err(ErrorCode::InvalidSlippage.into());
+        }
+
+        self.staking_token.set(token);
+        self.max_unstake_slippage_bps
+            .set(Uint::<16, 1>::from(max_slippage_bps));
+
+        log(
+            self.vm(),
+            StakingTokenSet {
+                token,
+                max_slippage_bps,
+            },
+        );
+
+        Ok(())
+    }
+}
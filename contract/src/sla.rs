@@ -0,0 +1,86 @@
+//! Service-level agreements: schedule-based refunds for late delivery.
+//!
+//! A client can open a job with an agreed delivery date and a lateness
+//! curve, `late_bps_per_day` of the job refunded to them for every full day
+//! the work arrives after that date, up to all of it. The freelancer stamps
+//! delivery with `submit_delivery`; a job released before then counts as
+//! delivered when it is released. Releasing the job, whether by the client's
+//! `release` or the freelancer's `auto_release`, refunds the late share to
+//! the client and pays the rest, so a slipped schedule settles without a
+//! dispute.
+
+use crate::fees::fee_on;
+use crate::{ErrorCode, Escrow, LateRefunded, BASIS_POINTS};
+use alloy_primitives::{Uint, U256};
+use stylus_sdk::prelude::*;
+
+const SECONDS_PER_DAY: u64 = 86_400;
+
+impl Escrow {
+    /// Whether `job_id` has an agreed delivery date
+    pub(crate) fn has_sla(&self, job_id: U256) -> bool {
+        self.slas.get(job_id).delivery_by.get() != Uint::<64, 1>::ZERO
+    }
+
+    /// Record that the freelancer delivered `job_id` now
+    pub(crate) fn stamp_delivery(&mut self, job_id: U256) -> Result<(), Vec<u8>> {
+        if self.slas.get(job_id).delivered_at.get() != Uint::<64, 1>::ZERO {
+            return Err(ErrorCode::AlreadyDelivered.into());
+        }
+        let now = self.vm().block_timestamp();
+        self.slas.setter(job_id).delivered_at.set(Uint::<64, 1>::from(now));
+        Ok(())
+    }
+
+    /// Full days `job_id` was delivered after its agreed date, counting an
+    /// undelivered job as delivered now
+    pub(crate) fn days_late(&self, job_id: U256) -> u64 {
+        let sla = self.slas.get(job_id);
+        let delivery_by: u64 = sla.delivery_by.get().to();
+        let delivered_at = match sla.delivered_at.get().to() {
+            0 => self.vm().block_timestamp(),
+            at => at,
+        };
+        delivered_at.saturating_sub(delivery_by) / SECONDS_PER_DAY
+    }
+
+    /// Share of `amount` refunded to the client if `job_id` were released
+    /// now; zero if it has no SLA or was on time
+    pub(crate) fn late_refund(&self, job_id: U256, amount: U256) -> U256 {
+        if !self.has_sla(job_id) {
+            return U256::ZERO;
+        }
+        let per_day: u64 = self.slas.get(job_id).late_bps_per_day.get().to();
+        let bps = self
+            .days_late(job_id)
+            .saturating_mul(per_day)
+            .min(u64::from(BASIS_POINTS));
+        fee_on(amount, bps as u16)
+    }
+
+    /// Refund `late` of a job being released to its client, leaving the
+    /// rest as the job's amount; the job must already be closed
+    pub(crate) fn refund_late(&mut self, job_id: U256, late: U256) -> Result<(), Vec<u8>> {
+        if late.is_zero() {
+            return Ok(());
+        }
+        let job = self.jobs.get(job_id);
+        let client = job.client.get();
+        let amount = job.amount.get();
+
+        self.jobs.setter(job_id).amount.set(amount - late);
+        self.record_refund(job_id, client, late);
+
+        self.vm().transfer_eth(client, late)?;
+
+        log(
+            self.vm(),
+            LateRefunded {
+                job_id,
+                days_late: self.days_late(job_id),
+                amount: late,
+            },
+        );
+        Ok(())
+    }
+}
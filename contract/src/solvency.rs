@@ -0,0 +1,77 @@
+//! Accounting invariant: the contract's ETH balance always covers what it owes.
+//!
+//! Liabilities are the amounts of all unsettled (and unswept) jobs plus unwithdrawn credit,
+//! protocol fees, locked dispute stakes, juror stakes and appeal bonds. Job amounts are tracked two
+//! ways, by summing the jobs themselves and by the cumulative protocol counters, and both must
+//! agree. The ETH staked for jobs is held as the staking token, so it counts towards the balance.
+
+use crate::Escrow;
+use alloy_primitives::U256;
+use stylus_sdk::prelude::*;
+
+impl Escrow {
+    /// ETH owed to unsettled jobs, credit holders, the fee pool, dispute
+    /// stakers, jurors and appellants, derived from the cumulative counters
+    /// (O(1))
+    pub(crate) fn liabilities(&self) -> U256 {
+        self.total_deposited.get()
+            + self.total_credit.get()
+            + self.accrued_fees.get()
+            + self.total_dispute_stakes.get()
+            + self.total_juror_stakes.get()
+            + self.total_appeal_bonds.get()
+            - self.total_released.get()
+            - self.total_refunded.get()
+            - self.total_swept.get()
+            - self.total_staking_losses.get()
+    }
+
+    /// ETH owed to unsettled jobs, summed job by job over the active-job set
+    /// (O(active jobs), not every job ever created)
+    pub(crate) fn sum_active_amounts(&self) -> U256 {
+        let mut sum = U256::ZERO;
+        for (_, job_id) in self.active_jobs_from(0) {
+            sum += self.jobs.get(job_id).amount.get();
+        }
+        sum
+    }
+
+    /// Whether the balance covers every open job and the counters agree with the jobs
+    pub(crate) fn is_solvent(&self) -> bool {
+        let owed = self.sum_active_amounts()
+            + self.total_credit.get()
+            + self.accrued_fees.get()
+            + self.total_dispute_stakes.get()
+            + self.total_juror_stakes.get()
+            + self.total_appeal_bonds.get();
+        owed == self.liabilities() && self.assets() >= owed
+    }
+
+    /// Balance beyond what open jobs, credit holders, dispute stakers,
+    /// jurors and appellants are owed, summed job by job so a counter bug can't let fee
+    /// withdrawals reach principal
+    pub(crate) fn surplus(&self) -> U256 {
+        let principal = self.sum_active_amounts()
+            + self.total_credit.get()
+            + self.total_dispute_stakes.get()
+            + self.total_juror_stakes.get()
+            + self.total_appeal_bonds.get();
+        self.assets().saturating_sub(principal)
+    }
+
+    /// Revert if the balance no longer covers open escrow.
+    ///
+    /// Only the O(1) counter check runs here so it can follow every state change.
+    #[cfg(feature = "solvency-check")]
+    pub(crate) fn assert_solvent(&self) -> Result<(), Vec<u8>> {
+        if self.assets() < self.liabilities() {
+            return Err(crate::ErrorCode::Insolvent.into());
+        }
+        Ok(())
+    }
+
+    /// ETH balance plus the principal of staked jobs
+    fn assets(&self) -> U256 {
+        self.vm().balance(self.vm().contract_address()) + self.total_staked.get()
+    }
+}
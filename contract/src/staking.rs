@@ -0,0 +1,271 @@
+//! Liquid staking of long-running jobs.
+//!
+//! A job whose deadline is months away can be held in the liquid staking
+//! token the admin sets, a Lido stETH-style token, instead of idle ETH. Both
+//! parties have to consent with the same split of the yield between client,
+//! freelancer and treasury; the second consent stakes the job's whole
+//! amount and keeps the shares for the job.
+//!
+//! Stylus denies reentrant calls, so the escrow can't take ETH back from the
+//! token or a DEX in the middle of its own call. Unwinding is a sale
+//! instead: a party, or anyone once the deadline is `UNSTAKE_WINDOW` away,
+//! can buy a staked job's shares with ETH worth at least their redemption
+//! value, less the slippage bound in force when the job was staked that
+//! pays the buyer for doing it. The discount only ever comes out of the
+//! yield: no buyer, party or not, pays less than the principal, or the
+//! redemption value if the token has lost some. The ETH takes the place of
+//! the principal; what it brings above it is yield, paid out by the job's
+//! yield split, and a shortfall the token's own loss leaves comes off the
+//! job. A
+//! staked job can't be settled or disputed until it has been unwound.
+
+use crate::{
+    ErrorCode, Escrow, JobStaked, JobUnstaked, StakeConsented, BASIS_POINTS, MIN_STAKE_DURATION,
+    UNSTAKE_WINDOW,
+};
+use alloy_primitives::{Address, Uint, U256};
+use alloy_sol_types::{sol, SolCall};
+use stylus_sdk::prelude::*;
+use stylus_sdk::stylus_core::calls::context::Call;
+
+sol! {
+    function submit(address referral) external payable returns (uint256);
+    function getPooledEthByShares(uint256 shares) external view returns (uint256);
+    function transferShares(address recipient, uint256 shares) external returns (uint256);
+}
+
+impl Escrow {
+    /// Whether `job_id`'s amount is held in the staking token
+    pub(crate) fn is_staked(&self, job_id: U256) -> bool {
+        !self.job_stakes.get(job_id).principal.get().is_zero()
+    }
+
+    /// Revert if `job_id` is staked, which has to be unwound before it settles
+    pub(crate) fn assert_not_staked(&self, job_id: U256) -> Result<(), Vec<u8>> {
+        if self.is_staked(job_id) {
+            return Err(ErrorCode::JobIsStaked.into());
+        }
+        Ok(())
+    }
+
+    /// Record `party`'s consent to stake `job_id` with `freelancer_yield_bps`
+    /// of the yield going to the freelancer and `treasury_yield_bps` to the
+    /// treasury, staking it once the other party has consented to the same
+    /// split
+    pub(crate) fn consent_to_stake_as(
+        &mut self,
+        job_id: U256,
+        party: Address,
+        freelancer_yield_bps: u16,
+        treasury_yield_bps: u16,
+    ) -> Result<(), Vec<u8>> {
+        if self.staking_token.get() == Address::ZERO {
+            return Err(ErrorCode::StakingNotEnabled.into());
+        }
+        self.assert_settleable(job_id)?;
+        let job = self.jobs.get(job_id);
+        let client = job.client.get();
+        let freelancer = job.freelancer.get();
+        if freelancer == Address::ZERO {
+            return Err(ErrorCode::JobNotRevealed.into());
+        }
+        let other = if party == client {
+            freelancer
+        } else if party == freelancer {
+            client
+        } else {
+            return Err(ErrorCode::OnlyJobPartiesCanApprove.into());
+        };
+        Self::assert_valid_yield_split(freelancer_yield_bps, treasury_yield_bps)?;
+        self.assert_not_staked(job_id)?;
+        self.assert_not_disputed(job_id)?;
+        self.assert_no_queued_release(job_id)?;
+        self.assert_not_hourly(job_id)?;
+        self.assert_no_basket(job_id)?;
+        if !self.tranches.get(job_id).is_empty() {
+            return Err(ErrorCode::JobIsTranched.into());
+        }
+        let deadline: u64 = job.deadline.get().to();
+        if deadline
+            < self
+                .vm()
+                .block_timestamp()
+                .saturating_add(MIN_STAKE_DURATION)
+        {
+            return Err(ErrorCode::StakeDurationTooShort.into());
+        }
+
+        let stake = self.job_stakes.get(job_id);
+        let agreed = stake.consented_by.get() == other
+            && stake.freelancer_yield_bps.get().to::<u16>() == freelancer_yield_bps
+            && stake.treasury_yield_bps.get().to::<u16>() == treasury_yield_bps;
+        let mut stake = self.job_stakes.setter(job_id);
+        stake
+            .freelancer_yield_bps
+            .set(Uint::<16, 1>::from(freelancer_yield_bps));
+        stake
+            .treasury_yield_bps
+            .set(Uint::<16, 1>::from(treasury_yield_bps));
+        stake.consented_by.set(party);
+
+        log(
+            self.vm(),
+            StakeConsented {
+                job_id,
+                party,
+                freelancer_yield_bps,
+                treasury_yield_bps,
+            },
+        );
+
+        if agreed {
+            self.stake_job(job_id)?;
+        }
+        Ok(())
+    }
+
+    /// Stake all of `job_id`'s amount into the staking token under the
+    /// consented split, recording the slippage bound its shares can be
+    /// bought back under
+    fn stake_job(&mut self, job_id: U256) -> Result<(), Vec<u8>> {
+        let token = self.staking_token.get();
+        let amount = self.jobs.get(job_id).amount.get();
+        let calldata = submitCall {
+            referral: Address::ZERO,
+        }
+        .abi_encode();
+        let shares = self
+            .vm()
+            .call(&Call::new().value(amount), token, &calldata)
+            .ok()
+            .and_then(|output| submitCall::abi_decode_returns(&output, true).ok())
+            .map(|ret| ret._0)
+            .filter(|shares| !shares.is_zero())
+            .ok_or(ErrorCode::StakeFailed)?;
+
+        let max_slippage_bps = self.max_unstake_slippage_bps.get();
+        let mut stake = self.job_stakes.setter(job_id);
+        stake.token.set(token);
+        stake.max_slippage_bps.set(max_slippage_bps);
+        stake.principal.set(amount);
+        stake.shares.set(shares);
+        stake.consented_by.set(Address::ZERO);
+        self.total_staked.set(self.total_staked.get() + amount);
+
+        self.log_yield_split(job_id);
+
+        log(
+            self.vm(),
+            JobStaked {
+                job_id,
+                token,
+                amount,
+                shares,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// ETH `shares` of `token` redeem for
+    pub(crate) fn stake_value(&self, token: Address, shares: U256) -> Result<U256, Vec<u8>> {
+        let calldata = getPooledEthBySharesCall { shares }.abi_encode();
+        let value = self
+            .vm()
+            .static_call(&Call::new(), token, &calldata)
+            .ok()
+            .and_then(|output| getPooledEthBySharesCall::abi_decode_returns(&output, true).ok())
+            .map(|ret| ret._0)
+            .ok_or(ErrorCode::StakeFailed)?;
+        Ok(value)
+    }
+
+    /// Sell `job_id`'s shares to `buyer` for the `paid` wei it sent, which
+    /// replaces the staked principal, at a discount the yield covers. A
+    /// buyer other than the parties can only do so from `UNSTAKE_WINDOW`
+    /// before the deadline
+    pub(crate) fn unwind_stake(
+        &mut self,
+        job_id: U256,
+        buyer: Address,
+        paid: U256,
+    ) -> Result<(), Vec<u8>> {
+        if !self.is_staked(job_id) {
+            return Err(ErrorCode::JobNotStaked.into());
+        }
+        let job = self.jobs.get(job_id);
+        let is_party = buyer == job.client.get() || buyer == job.freelancer.get();
+        let deadline: u64 = job.deadline.get().to();
+        if !is_party && self.vm().block_timestamp() < deadline.saturating_sub(UNSTAKE_WINDOW) {
+            return Err(ErrorCode::UnstakeNotOpen.into());
+        }
+
+        let stake = self.job_stakes.get(job_id);
+        let token = stake.token.get();
+        let principal = stake.principal.get();
+        let shares = stake.shares.get();
+        let value = self.stake_value(token, shares)?;
+        let discount_bps = U256::from(stake.max_slippage_bps.get());
+        let basis_points = U256::from(BASIS_POINTS);
+        let discounted = value * (basis_points - discount_bps) / basis_points;
+        if paid < discounted.max(value.min(principal)) {
+            return Err(ErrorCode::UnstakePriceTooLow.into());
+        }
+
+        let calldata = transferSharesCall {
+            recipient: buyer,
+            shares,
+        }
+        .abi_encode();
+        self.vm()
+            .call(&Call::new(), token, &calldata)
+            .map_err(|_| ErrorCode::StakeFailed)?;
+
+        let mut stake = self.job_stakes.setter(job_id);
+        stake.token.set(Address::ZERO);
+        stake.max_slippage_bps.set(Uint::<16, 1>::ZERO);
+        stake.principal.set(U256::ZERO);
+        stake.shares.set(U256::ZERO);
+        self.total_staked.set(self.total_staked.get() - principal);
+
+        let job = self.jobs.get(job_id);
+        let client = job.client.get();
+        let freelancer = job.freelancer.get();
+        let amount = job.amount.get();
+        let (client_yield, freelancer_yield, treasury_yield) = if paid >= principal {
+            self.split_yield(job_id, paid - principal)
+        } else {
+            let loss = principal - paid;
+            self.unlock_escrow(job_id, loss);
+            self.jobs.setter(job_id).amount.set(amount - loss);
+            self.total_staking_losses
+                .set(self.total_staking_losses.get() + loss);
+            (U256::ZERO, U256::ZERO, U256::ZERO)
+        };
+        if !client_yield.is_zero() {
+            self.credit(client, client_yield);
+        }
+        if !freelancer_yield.is_zero() {
+            self.credit(freelancer, freelancer_yield);
+        }
+        if !treasury_yield.is_zero() {
+            self.accrued_fees
+                .set(self.accrued_fees.get() + treasury_yield);
+        }
+
+        log(
+            self.vm(),
+            JobUnstaked {
+                job_id,
+                buyer,
+                principal,
+                paid,
+                client_yield,
+                freelancer_yield,
+                treasury_yield,
+            },
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,390 @@
+//! Storage slots of the escrow's fields, derived from its `sol_storage!` block.
+//!
+//! The block in `lib.rs` is parsed as written and laid out with the same
+//! rules the `#[storage]` macro applies: fields pack into a 32-byte word from
+//! its low-order end until the next one no longer fits, structs start a
+//! fresh word and fill whole words, and mapping entries and vector elements
+//! live at Solidity's hashed locations. The `layout` binary prints the result,
+//! and the tests read the contract's storage through it, so the two can't
+//! drift apart unnoticed.
+
+use alloy_primitives::{keccak256, Address, U256};
+use std::fmt;
+
+/// The source the layout is derived from
+const SOURCE: &str = include_str!("lib.rs");
+
+/// Type of a storage field, as declared in `sol_storage!`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FieldType {
+    /// A value packed into part of a word, e.g. `uint64` or `address`
+    Value {
+        name: String,
+        bytes: usize,
+    },
+    Mapping(Box<FieldType>, Box<FieldType>),
+    Vec(Box<FieldType>),
+    /// `bytes` or `string`: one word holding the length, or short contents
+    Bytes(String),
+    Struct(String),
+}
+
+impl FieldType {
+    /// Parse a declared type such as `mapping(address => Job[])`
+    fn parse(source: &str) -> Result<Self, String> {
+        let source = source.trim();
+        if let Some(inner) = source
+            .strip_prefix("mapping(")
+            .and_then(|rest| rest.strip_suffix(')'))
+        {
+            let (key, value) = inner
+                .split_once("=>")
+                .ok_or_else(|| format!("malformed mapping `{source}`"))?;
+            return Ok(Self::Mapping(
+                Box::new(Self::parse(key)?),
+                Box::new(Self::parse(value)?),
+            ));
+        }
+        if let Some(element) = source.strip_suffix("[]") {
+            return Ok(Self::Vec(Box::new(Self::parse(element)?)));
+        }
+        if source == "bytes" || source == "string" {
+            return Ok(Self::Bytes(source.into()));
+        }
+        let bytes = match source {
+            "bool" => Some(1),
+            "address" => Some(20),
+            _ => ["uint", "int", "bytes"].iter().find_map(|prefix| {
+                let width: usize = source.strip_prefix(prefix)?.parse().ok()?;
+                Some(if *prefix == "bytes" { width } else { width / 8 })
+            }),
+        };
+        Ok(match bytes {
+            Some(bytes) => Self::Value {
+                name: source.into(),
+                bytes,
+            },
+            None => Self::Struct(source.into()),
+        })
+    }
+}
+
+impl fmt::Display for FieldType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Value { name, .. } | Self::Bytes(name) | Self::Struct(name) => {
+                write!(f, "{name}")
+            }
+            Self::Mapping(key, value) => write!(f, "mapping({key} => {value})"),
+            Self::Vec(element) => write!(f, "{element}[]"),
+        }
+    }
+}
+
+/// A field and where it sits relative to the start of its struct
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Field {
+    pub name: String,
+    pub ty: FieldType,
+    /// Word the field starts in, counted from the struct's first
+    pub slot: usize,
+    /// Byte within the word where the field starts
+    pub offset: u8,
+    /// Bytes the field takes in its word; 32 for whole words
+    pub bytes: usize,
+}
+
+/// A struct declared in `sol_storage!`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Struct {
+    pub name: String,
+    pub fields: Vec<Field>,
+    /// Words the struct fills, as its `StorageType::REQUIRED_SLOTS`
+    pub slots: usize,
+}
+
+/// Where a value lives in storage
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Location {
+    pub slot: U256,
+    pub offset: u8,
+    pub bytes: usize,
+    pub ty: FieldType,
+}
+
+impl Location {
+    /// The value's bytes within the word `slot` holds
+    pub fn read(&self, word: &[u8; 32]) -> Vec<u8> {
+        let offset = self.offset as usize;
+        word[offset..offset + self.bytes].to_vec()
+    }
+}
+
+/// Every struct in a `sol_storage!` block, laid out
+#[derive(Clone, Debug)]
+pub struct Layout {
+    /// In declaration order; the first is the `#[entrypoint]` contract
+    pub structs: Vec<Struct>,
+}
+
+impl Layout {
+    /// Layout of the escrow as compiled
+    pub fn contract() -> Self {
+        Self::parse(SOURCE).expect("lib.rs has a well-formed sol_storage! block")
+    }
+
+    /// Lay out the first `sol_storage!` block in `source`
+    pub fn parse(source: &str) -> Result<Self, String> {
+        let start = source
+            .find("sol_storage! {")
+            .ok_or("no sol_storage! block")?;
+        let mut declared: Vec<(String, Vec<(String, FieldType)>)> = Vec::new();
+        let mut current: Option<(String, Vec<(String, FieldType)>)> = None;
+        for line in source[start..].lines().skip(1).map(str::trim) {
+            if line.is_empty() || line.starts_with("//") || line.starts_with("#[") {
+                continue;
+            }
+            if let Some(name) = line
+                .strip_prefix("pub struct ")
+                .and_then(|rest| rest.strip_suffix('{'))
+            {
+                current = Some((name.trim().into(), Vec::new()));
+            } else if line == "}" {
+                match current.take() {
+                    Some(declaration) => declared.push(declaration),
+                    None => break,
+                }
+            } else {
+                let (ty, name) = line
+                    .strip_suffix(';')
+                    .and_then(|field| field.rsplit_once(' '))
+                    .ok_or_else(|| format!("malformed field `{line}`"))?;
+                let fields = &mut current
+                    .as_mut()
+                    .ok_or_else(|| format!("field `{line}` outside a struct"))?
+                    .1;
+                fields.push((name.into(), FieldType::parse(ty)?));
+            }
+        }
+
+        let mut layout = Self {
+            structs: Vec::new(),
+        };
+        for (name, _) in &declared {
+            layout.lay_out(name, &declared, &mut Vec::new())?;
+        }
+        let order = |name: &str| declared.iter().position(|(n, _)| n == name);
+        layout.structs.sort_by_key(|s| order(&s.name));
+        Ok(layout)
+    }
+
+    /// Lay out struct `name` and the structs it contains, unless done already
+    fn lay_out(
+        &mut self,
+        name: &str,
+        declared: &[(String, Vec<(String, FieldType)>)],
+        stack: &mut Vec<String>,
+    ) -> Result<(), String> {
+        if self.get(name).is_some() {
+            return Ok(());
+        }
+        if stack.iter().any(|s| s == name) {
+            return Err(format!("struct `{name}` contains itself"));
+        }
+        let (_, declaration) = declared
+            .iter()
+            .find(|(n, _)| n == name)
+            .ok_or_else(|| format!("unknown struct `{name}`"))?;
+        stack.push(name.into());
+        for (_, ty) in declaration {
+            if let FieldType::Struct(inner) = ty {
+                self.lay_out(inner, declared, stack)?;
+            }
+        }
+        stack.pop();
+
+        // As the `#[storage]` macro places each field
+        let mut fields = Vec::new();
+        let (mut slot, mut space) = (0, 32);
+        for (field, ty) in declaration {
+            let (bytes, words) = self.size(ty)?;
+            if space < bytes {
+                space = 32;
+                slot += 1;
+            }
+            space -= bytes;
+            fields.push(Field {
+                name: field.clone(),
+                ty: ty.clone(),
+                slot,
+                offset: space as u8,
+                bytes,
+            });
+            if words > 0 {
+                slot += words;
+                space = 32;
+            }
+        }
+
+        // As the macro's `required_slots` counts them
+        let (mut slots, mut space) = (0, 32);
+        for field in &fields {
+            let (bytes, words) = self.size(&field.ty)?;
+            if words > 0 {
+                slots += words;
+                space = 32;
+            } else {
+                if space < bytes {
+                    space = 32;
+                    slots += 1;
+                }
+                space -= bytes;
+            }
+        }
+        if space != 32 || slots == 0 {
+            slots += 1;
+        }
+
+        self.structs.push(Struct {
+            name: name.into(),
+            fields,
+            slots,
+        });
+        Ok(())
+    }
+
+    /// A laid out struct
+    pub fn get(&self, name: &str) -> Option<&Struct> {
+        self.structs.iter().find(|s| s.name == name)
+    }
+
+    /// `StorageType::SLOT_BYTES` and `REQUIRED_SLOTS` of `ty`
+    fn size(&self, ty: &FieldType) -> Result<(usize, usize), String> {
+        Ok(match ty {
+            FieldType::Value { bytes, .. } => (*bytes, 0),
+            FieldType::Mapping(..) | FieldType::Vec(_) | FieldType::Bytes(_) => (32, 0),
+            FieldType::Struct(name) => {
+                let inner = self
+                    .get(name)
+                    .ok_or_else(|| format!("unknown struct `{name}`"))?;
+                (32, inner.slots)
+            }
+        })
+    }
+
+    /// Find the value at `path`, such as `admin`, `jobs[7].client`,
+    /// `nonce_jobs[0x…][3]` or `fee_tiers[0].fee_bps`. Mapping keys are
+    /// written as decimal or `0x` hex numbers, addresses or hashes; vector
+    /// indexes as decimals, and a vector's own location holds its length.
+    pub fn locate(&self, path: &str) -> Result<Location, String> {
+        let root = self.structs.first().ok_or("no contract struct")?;
+        let mut location = Location {
+            slot: U256::ZERO,
+            offset: 0,
+            bytes: 32,
+            ty: FieldType::Struct(root.name.clone()),
+        };
+        let mut rest = path.trim();
+        let mut first = true;
+        while !rest.is_empty() {
+            if let Some(indexed) = rest.strip_prefix('[') {
+                let (key, after) = indexed
+                    .split_once(']')
+                    .ok_or_else(|| format!("unclosed `[` in `{path}`"))?;
+                location = self.index(location, key.trim())?;
+                rest = after;
+            } else {
+                let named = if first {
+                    rest
+                } else {
+                    rest.strip_prefix('.')
+                        .ok_or_else(|| format!("expected `.` or `[` at `{rest}`"))?
+                };
+                let end = named.find(['.', '[']).unwrap_or(named.len());
+                location = self.member(location, &named[..end])?;
+                rest = &named[end..];
+            }
+            first = false;
+        }
+        Ok(location)
+    }
+
+    /// Field `name` of the struct at `location`
+    fn member(&self, location: Location, name: &str) -> Result<Location, String> {
+        let FieldType::Struct(struct_name) = &location.ty else {
+            return Err(format!("`{}` has no field `{name}`", location.ty));
+        };
+        let field = self
+            .get(struct_name)
+            .and_then(|s| s.fields.iter().find(|f| f.name == name))
+            .ok_or_else(|| format!("`{struct_name}` has no field `{name}`"))?;
+        Ok(Location {
+            slot: location.slot + U256::from(field.slot),
+            offset: field.offset,
+            bytes: field.bytes,
+            ty: field.ty.clone(),
+        })
+    }
+
+    /// Entry `key` of the mapping or vector at `location`
+    fn index(&self, location: Location, key: &str) -> Result<Location, String> {
+        match location.ty {
+            FieldType::Mapping(key_type, value) => {
+                let (bytes, _) = self.size(&value)?;
+                let word = encode_key(&key_type, key)?;
+                let slot = keccak256([word, location.slot.to_be_bytes::<32>()].concat()).into();
+                Ok(Location {
+                    slot,
+                    offset: (32 - bytes) as u8,
+                    bytes,
+                    ty: *value,
+                })
+            }
+            FieldType::Vec(element) => {
+                let index: usize = key.parse().map_err(|_| format!("invalid index `{key}`"))?;
+                let (bytes, words) = self.size(&element)?;
+                let density = 32 / bytes;
+                let base: U256 = keccak256(location.slot.to_be_bytes::<32>()).into();
+                Ok(Location {
+                    slot: base + U256::from(words.max(1) * index / density),
+                    offset: (32 - bytes * (1 + index % density)) as u8,
+                    bytes,
+                    ty: *element,
+                })
+            }
+            ty => Err(format!("`{ty}` can't be indexed")),
+        }
+    }
+}
+
+/// The word a mapping hashes `key` into, as stylus-sdk's `StorageKey`
+fn encode_key(ty: &FieldType, key: &str) -> Result<[u8; 32], String> {
+    let invalid = || format!("invalid `{ty}` key `{key}`");
+    match ty {
+        FieldType::Value { name, .. } if name == "address" => {
+            let address: Address = key.parse().map_err(|_| invalid())?;
+            Ok(address.into_word().0)
+        }
+        FieldType::Value { name, bytes } if name.starts_with("bytes") => {
+            let data = key.strip_prefix("0x").ok_or_else(invalid)?;
+            if data.len() != bytes * 2 {
+                return Err(invalid());
+            }
+            let mut word = [0; 32];
+            for (i, byte) in word.iter_mut().take(*bytes).enumerate() {
+                *byte = u8::from_str_radix(&data[2 * i..2 * i + 2], 16).map_err(|_| invalid())?;
+            }
+            Ok(word)
+        }
+        FieldType::Value { name, .. } if name == "bool" => match key {
+            "true" => Ok(U256::from(1).to_be_bytes()),
+            "false" => Ok([0; 32]),
+            _ => Err(invalid()),
+        },
+        FieldType::Value { .. } => {
+            let value: U256 = key.parse().map_err(|_| invalid())?;
+            Ok(value.to_be_bytes())
+        }
+        _ => Err(invalid()),
+    }
+}
@@ -0,0 +1,108 @@
+//! Payouts in the freelancer's preferred token.
+//!
+//! A freelancer can ask to be paid in an ERC-20 instead of ETH. Their payouts
+//! are then sold through the admin-approved Uniswap V2-style router, with the
+//! freelancer's minimum rate as the slippage bound. If the swap fails for any
+//! reason the freelancer is paid in ETH, so a release never gets stuck on it.
+//!
+//! Picking WETH itself needs no router: the payout is wrapped 1:1 straight
+//! into the recipient's balance with Arbitrum WETH's `depositTo`, whatever
+//! the minimum rate, and reported like a swap. Wrapping and delivery are one
+//! call, so the escrow never holds WETH it couldn't hand on; if the call
+//! fails the payout goes out in ETH, or is credited to a recipient that
+//! refuses ETH, like any other payout. Jobs are only ever funded in ETH:
+//! unwrapping would send ETH back into the escrow mid-call, and Stylus
+//! denies reentrant calls.
+
+use crate::{ErrorCode, Escrow, PayoutSwapFailed, PayoutSwapped};
+use alloc::vec;
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::{sol, SolCall};
+use stylus_sdk::prelude::*;
+use stylus_sdk::stylus_core::calls::context::Call;
+
+sol! {
+    function swapExactETHForTokens(uint256 amountOutMin, address[] path, address to, uint256 deadline) external payable returns (uint256[] amounts);
+    function depositTo(address account) external payable;
+}
+
+/// Token amounts are quoted per whole ETH
+const WEI_PER_ETH: u64 = 1_000_000_000_000_000_000;
+
+impl Escrow {
+    /// Pay `amount` wei for `job_id` to `freelancer`'s payout address,
+    /// swapped into their preferred token when they have one and a router
+    /// is set
+    pub(crate) fn pay_freelancer(
+        &mut self,
+        job_id: U256,
+        freelancer: Address,
+        amount: U256,
+    ) -> Result<(), Vec<u8>> {
+        let recipient = self.payout_recipient(freelancer);
+        let router = self.swap_router.get();
+        let preference = self.payout_tokens.get(freelancer);
+        let token = preference.token.get();
+        if token != Address::ZERO && token == self.weth.get() {
+            return self.pay_wrapped(job_id, token, recipient, amount);
+        }
+        if router == Address::ZERO || token == Address::ZERO {
+            return self.push_payout(job_id, recipient, amount);
+        }
+
+        let amount_out_min = amount * preference.min_rate.get() / U256::from(WEI_PER_ETH);
+        let calldata = swapExactETHForTokensCall {
+            amountOutMin: amount_out_min,
+            path: vec![self.weth.get(), token],
+            to: recipient,
+            deadline: U256::from(self.vm().block_timestamp()),
+        }
+        .abi_encode();
+
+        match self.vm().call(&Call::new().value(amount), router, &calldata) {
+            Ok(output) => {
+                let amounts = swapExactETHForTokensCall::abi_decode_returns(&output, true)
+                    .map_err(|_| ErrorCode::InvalidSwapOutput)?
+                    .amounts;
+                let amount_out = amounts.last().copied().unwrap_or_default();
+                log(self.vm(), PayoutSwapped {
+                    job_id,
+                    token,
+                    amount_in: amount,
+                    amount_out,
+                });
+            }
+            Err(_) => {
+                self.push_payout(job_id, recipient, amount)?;
+                log(self.vm(), PayoutSwapFailed { job_id, token });
+            }
+        }
+        Ok(())
+    }
+
+    /// Wrap `amount` wei for `job_id` into `weth` held by `recipient`,
+    /// paying ETH through the usual push-or-credit fallback instead if
+    /// the wrap fails
+    fn pay_wrapped(
+        &mut self,
+        job_id: U256,
+        weth: Address,
+        recipient: Address,
+        amount: U256,
+    ) -> Result<(), Vec<u8>> {
+        let calldata = depositToCall { account: recipient }.abi_encode();
+        if self.vm().call(&Call::new().value(amount), weth, &calldata).is_err() {
+            self.push_payout(job_id, recipient, amount)?;
+            log(self.vm(), PayoutSwapFailed { job_id, token: weth });
+            return Ok(());
+        }
+
+        log(self.vm(), PayoutSwapped {
+            job_id,
+            token: weth,
+            amount_in: amount,
+            amount_out: amount,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,146 @@
+//! Hourly engagements billed through approved timesheets.
+//!
+//! A client can open a job as an hourly engagement: the deposit is a budget
+//! and the job carries an hourly rate. The freelancer bills the work done in
+//! each period by submitting a timesheet, whose amount is its hours times
+//! the rate and must fit in the budget not already billed. Approving a
+//! timesheet pays its amount out of the budget; rejecting it frees that
+//! amount for later timesheets, and the freelancer can dispute the job if
+//! they disagree. The job can't be released or refunded whole. Once the
+//! deadline has passed and no timesheet is waiting, the client ends the
+//! engagement and gets the unspent budget back.
+
+use crate::{
+    ErrorCode, Escrow, TimesheetApproved, TimesheetRejected, TIMESHEET_APPROVED,
+    TIMESHEET_REJECTED, TIMESHEET_SUBMITTED,
+};
+use alloy_primitives::{Uint, B256, U256};
+use stylus_sdk::prelude::*;
+
+impl Escrow {
+    /// Whether `job_id` is an hourly engagement
+    pub(crate) fn is_hourly(&self, job_id: U256) -> bool {
+        !self.engagements.get(job_id).rate.get().is_zero()
+    }
+
+    /// Revert if `job_id` is an hourly engagement, which only pays out
+    /// through timesheets
+    pub(crate) fn assert_not_hourly(&self, job_id: U256) -> Result<(), Vec<u8>> {
+        if self.is_hourly(job_id) {
+            return Err(ErrorCode::JobIsHourly.into());
+        }
+        Ok(())
+    }
+
+    /// Revert while any timesheet of `job_id` awaits the client
+    pub(crate) fn assert_no_pending_timesheets(&self, job_id: U256) -> Result<(), Vec<u8>> {
+        if !self.engagements.get(job_id).pending.get().is_zero() {
+            return Err(ErrorCode::TimesheetsPending.into());
+        }
+        Ok(())
+    }
+
+    /// Timesheet `index` of `job_id` awaiting the client as (index, amount);
+    /// reverts if there is none or it was already decided
+    pub(crate) fn submitted_timesheet(
+        &self,
+        job_id: U256,
+        index: U256,
+    ) -> Result<(usize, U256), Vec<u8>> {
+        let sheets = self.timesheets.get(job_id);
+        let Some((i, sheet)) = usize::try_from(index)
+            .ok()
+            .and_then(|i| sheets.getter(i).map(|sheet| (i, sheet)))
+        else {
+            return Err(ErrorCode::InvalidTimesheet.into());
+        };
+        if sheet.status.get().to::<u8>() != TIMESHEET_SUBMITTED {
+            return Err(ErrorCode::TimesheetAlreadySettled.into());
+        }
+        let hours = U256::from(sheet.hours.get().to::<u32>());
+        Ok((i, self.engagements.get(job_id).rate.get() * hours))
+    }
+
+    /// Pay timesheet `index` of `job_id` out of its budget, settling the job
+    /// once the budget is spent
+    pub(crate) fn pay_timesheet(
+        &mut self,
+        job_id: U256,
+        index: usize,
+        amount: U256,
+    ) -> Result<(), Vec<u8>> {
+        self.decide_timesheet(job_id, index, amount, TIMESHEET_APPROVED);
+
+        let mut job = self.jobs.setter(job_id);
+        let freelancer = job.freelancer.get();
+        let remaining = job.amount.get() - amount;
+        job.amount.set(remaining);
+        if remaining.is_zero() {
+            job.released.set(true);
+            self.close_job(job_id);
+        }
+        self.record_release(job_id, freelancer, amount);
+
+        self.pay_release(job_id, freelancer, amount)?;
+
+        log(
+            self.vm(),
+            TimesheetApproved {
+                job_id,
+                index: U256::from(index),
+                amount,
+                remaining,
+            },
+        );
+        if remaining.is_zero() {
+            self.call_settlement_hook(job_id, amount, true);
+        }
+        Ok(())
+    }
+
+    /// Turn down timesheet `index` of `job_id`, freeing its amount for later
+    /// timesheets
+    pub(crate) fn reject_timesheet_at(&mut self, job_id: U256, index: usize, amount: U256) {
+        self.decide_timesheet(job_id, index, amount, TIMESHEET_REJECTED);
+
+        log(
+            self.vm(),
+            TimesheetRejected {
+                job_id,
+                index: U256::from(index),
+            },
+        );
+    }
+
+    /// Record the client's decision on timesheet `index` and take its
+    /// `amount` off what is awaiting approval
+    fn decide_timesheet(&mut self, job_id: U256, index: usize, amount: U256, status: u8) {
+        if let Some(mut sheet) = self.timesheets.setter(job_id).setter(index) {
+            sheet.status.set(Uint::<8, 1>::from(status));
+        }
+        let mut engagement = self.engagements.setter(job_id);
+        let pending = engagement.pending.get();
+        engagement.pending.set(pending - amount);
+    }
+
+    /// Add a timesheet of `hours` described by `details` to `job_id`,
+    /// billing `amount`; returns its index
+    pub(crate) fn push_timesheet(
+        &mut self,
+        job_id: U256,
+        hours: u32,
+        details: B256,
+        amount: U256,
+    ) -> U256 {
+        let mut sheets = self.timesheets.setter(job_id);
+        let index = U256::from(sheets.len());
+        let mut sheet = sheets.grow();
+        sheet.hours.set(Uint::<32, 1>::from(hours));
+        sheet.details.set(details);
+
+        let mut engagement = self.engagements.setter(job_id);
+        let pending = engagement.pending.get();
+        engagement.pending.set(pending + amount);
+        index
+    }
+}
@@ -0,0 +1,115 @@
+//! Tranched jobs: a job's amount split into parts that unlock at set times.
+//!
+//! Each tranche is claimable by the freelancer once its unlock time has
+//! passed and refundable to the client until then. The job's `amount` tracks
+//! what is still escrowed; when the last tranche settles the job is marked
+//! released or refunded according to how that tranche went.
+//!
+//! Retainers are tranched jobs with one equal tranche per period, paid in
+//! arrears. The client cannot refund single periods; stopping the retainer
+//! refunds only the periods that unlock after the agreed notice.
+
+use crate::{Escrow, TrancheClaimed, TrancheRefunded};
+use alloy_primitives::U256;
+use stylus_sdk::prelude::*;
+
+impl Escrow {
+    /// Pay tranche `index` of `job_id` to the job's freelancer
+    pub(crate) fn pay_tranche(
+        &mut self,
+        job_id: U256,
+        index: usize,
+        amount: U256,
+    ) -> Result<(), Vec<u8>> {
+        let job = self.jobs.get(job_id);
+        let freelancer = job.freelancer.get();
+        self.assert_compliant(job.client.get(), freelancer, amount)?;
+
+        let remaining = self.settle_tranche(job_id, index, amount, true);
+        self.record_release(job_id, freelancer, amount);
+
+        self.pay_release(job_id, freelancer, amount)?;
+
+        log(
+            self.vm(),
+            TrancheClaimed {
+                job_id,
+                index: U256::from(index),
+                amount,
+                remaining,
+            },
+        );
+        Ok(())
+    }
+
+    /// Return tranche `index` of `job_id` to the job's client
+    pub(crate) fn return_tranche(
+        &mut self,
+        job_id: U256,
+        index: usize,
+        amount: U256,
+    ) -> Result<(), Vec<u8>> {
+        let client = self.jobs.get(job_id).client.get();
+
+        let remaining = self.settle_tranche(job_id, index, amount, false);
+        self.record_refund(job_id, client, amount);
+
+        self.vm().transfer_eth(client, amount)?;
+
+        log(
+            self.vm(),
+            TrancheRefunded {
+                job_id,
+                index: U256::from(index),
+                amount,
+                remaining,
+            },
+        );
+        Ok(())
+    }
+
+    /// Unsettled tranches of `job_id` as (index, amount, unlock time)
+    pub(crate) fn open_tranches(&self, job_id: U256) -> Vec<(usize, U256, u64)> {
+        let schedule = self.tranches.get(job_id);
+        (0..schedule.len())
+            .filter_map(|i| schedule.getter(i).map(|tranche| (i, tranche)))
+            .filter(|(_, tranche)| !tranche.settled.get())
+            .map(|(i, tranche)| (i, tranche.amount.get(), tranche.unlock_at.get().to()))
+            .collect()
+    }
+
+    /// Mark tranche `index` of `job_id` as settled and take its `amount` off
+    /// the job, finalizing the job once nothing is left. Returns what is
+    /// still escrowed.
+    fn settle_tranche(&mut self, job_id: U256, index: usize, amount: U256, claimed: bool) -> U256 {
+        if let Some(mut tranche) = self.tranches.setter(job_id).setter(index) {
+            tranche.settled.set(true);
+        }
+
+        let mut job = self.jobs.setter(job_id);
+        let remaining = job.amount.get() - amount;
+        job.amount.set(remaining);
+        if remaining.is_zero() {
+            if claimed {
+                job.released.set(true);
+            } else {
+                job.refunded.set(true);
+            }
+            self.close_job(job_id);
+        }
+        remaining
+    }
+
+    /// Tranche `index` of `job_id` as (index, amount, unlock time, settled)
+    pub(crate) fn tranche(&self, job_id: U256, index: U256) -> Option<(usize, U256, u64, bool)> {
+        let index = usize::try_from(index).ok()?;
+        let schedule = self.tranches.get(job_id);
+        let tranche = schedule.getter(index)?;
+        Some((
+            index,
+            tranche.amount.get(),
+            tranche.unlock_at.get().to(),
+            tranche.settled.get(),
+        ))
+    }
+}
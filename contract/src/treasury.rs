@@ -0,0 +1,47 @@
+//! Treasury: the address that receives the escrow's revenue.
+//!
+//! Withdrawn protocol fees, swept dormant jobs and whatever is left when the
+//! escrow retires all go to the treasury, so the keys that operate the escrow
+//! never hold its revenue. Until a treasury is set they go where they always
+//! did. A new treasury only takes effect `TREASURY_DELAY` after the admin
+//! proposes it, leaving time to notice a redirected revenue stream. Once
+//! governance is configured, only a passed proposal can propose one.
+
+use crate::{ErrorCode, Escrow, TreasuryProposed, TREASURY_DELAY};
+use alloy_primitives::{Address, Uint};
+use stylus_sdk::prelude::*;
+
+impl Escrow {
+    /// Where revenue goes: the treasury, or `fallback` until one is set
+    pub(crate) fn revenue_recipient(&self, fallback: Address) -> Address {
+        let treasury = self.treasury.get();
+        if treasury == Address::ZERO {
+            fallback
+        } else {
+            treasury
+        }
+    }
+
+    /// Make `treasury` the pending treasury, applicable after
+    /// `TREASURY_DELAY`
+    pub(crate) fn update_pending_treasury(&mut self, treasury: Address) -> Result<(), Vec<u8>> {
+        if treasury == Address::ZERO {
+            return Err(ErrorCode::InvalidTreasuryAddress.into());
+        }
+
+        let effective_at = self.vm().block_timestamp().saturating_add(TREASURY_DELAY);
+        self.pending_treasury.set(treasury);
+        self.treasury_effective_at
+            .set(Uint::<64, 1>::from(effective_at));
+
+        log(
+            self.vm(),
+            TreasuryProposed {
+                treasury,
+                effective_at,
+            },
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,68 @@
+//! Yield splits: who gets what a job's escrow earns while it is held.
+//!
+//! Both parties consent to staking a job with the same freelancer and
+//! treasury shares of any yield in basis points, the client taking the
+//! rest; the split is kept with the job's stake and stands while it is
+//! staked. Unwinding a stake pays its yield out by it: the parties' shares
+//! as credits, the treasury's into the accrued fees that are withdrawn to
+//! it.
+
+use crate::fees::fee_on;
+use crate::{ErrorCode, Escrow, YieldSplitSet, BASIS_POINTS};
+use alloy_primitives::U256;
+use stylus_sdk::prelude::*;
+
+impl Escrow {
+    /// Revert unless the freelancer's and treasury's shares leave the client
+    /// a share of the yield
+    pub(crate) fn assert_valid_yield_split(
+        freelancer_yield_bps: u16,
+        treasury_yield_bps: u16,
+    ) -> Result<(), Vec<u8>> {
+        let total = u32::from(freelancer_yield_bps) + u32::from(treasury_yield_bps);
+        if total > u32::from(BASIS_POINTS) {
+            return Err(ErrorCode::InvalidYieldSplit.into());
+        }
+        Ok(())
+    }
+
+    /// `job_id`'s (client, freelancer, treasury) yield shares in basis
+    /// points, as last consented to
+    pub(crate) fn yield_split(&self, job_id: U256) -> (u16, u16, u16) {
+        let stake = self.job_stakes.get(job_id);
+        let freelancer_yield_bps: u16 = stake.freelancer_yield_bps.get().to();
+        let treasury_yield_bps: u16 = stake.treasury_yield_bps.get().to();
+        (
+            BASIS_POINTS - freelancer_yield_bps - treasury_yield_bps,
+            freelancer_yield_bps,
+            treasury_yield_bps,
+        )
+    }
+
+    /// Log the split `job_id` was staked with
+    pub(crate) fn log_yield_split(&self, job_id: U256) {
+        let (client_yield_bps, freelancer_yield_bps, treasury_yield_bps) = self.yield_split(job_id);
+        log(
+            self.vm(),
+            YieldSplitSet {
+                job_id,
+                client_yield_bps,
+                freelancer_yield_bps,
+                treasury_yield_bps,
+            },
+        );
+    }
+
+    /// Split `earned` by `job_id`'s yield split into the (client, freelancer,
+    /// treasury) shares, the client taking any rounding dust
+    pub(crate) fn split_yield(&self, job_id: U256, earned: U256) -> (U256, U256, U256) {
+        let (_, freelancer_yield_bps, treasury_yield_bps) = self.yield_split(job_id);
+        let freelancer_yield = fee_on(earned, freelancer_yield_bps);
+        let treasury_yield = fee_on(earned, treasury_yield_bps);
+        (
+            earned - freelancer_yield - treasury_yield,
+            freelancer_yield,
+            treasury_yield,
+        )
+    }
+}
@@ -0,0 +1,302 @@
+//! Property-based state machine tests.
+//!
+//! Random sequences of deposits, settlements, pauses, timestamp jumps and
+//! sender switches are run against both the contract (on `TestVM`) and a small
+//! reference model. After every step the contract must agree with the model and
+//! hold at least as much ETH as it owes to unsettled jobs.
+
+use alloy_primitives::{Address, B256, U256};
+use freelance_payment_escrow::{Escrow, EMERGENCY_REFUND_GRACE, EMERGENCY_REFUND_MIN_AGE};
+use proptest::prelude::*;
+use stylus_sdk::prelude::*;
+use stylus_sdk::testing::*;
+
+/// Accounts that take turns as sender; index 0 is the admin
+const ACTORS: [Address; 4] = [
+    Address::repeat_byte(0xA0),
+    Address::repeat_byte(0xA1),
+    Address::repeat_byte(0xA2),
+    Address::repeat_byte(0xA3),
+];
+
+#[derive(Clone, Debug)]
+enum Op {
+    Deposit {
+        sender: usize,
+        freelancer: usize,
+        amount: u64,
+        duration: u64,
+    },
+    Release {
+        sender: usize,
+        job: u64,
+    },
+    Refund {
+        sender: usize,
+        job: u64,
+    },
+    AutoRelease {
+        sender: usize,
+        job: u64,
+    },
+    EmergencyRefund {
+        sender: usize,
+        job: u64,
+    },
+    SetPaused {
+        sender: usize,
+        paused: bool,
+    },
+    Warp {
+        seconds: u64,
+    },
+}
+
+fn op() -> impl Strategy<Value = Op> {
+    let sender = 0..ACTORS.len();
+    // One past the actors maps to the zero address
+    let freelancer = 0..=ACTORS.len();
+    let amount = prop_oneof![1 => Just(0u64), 9 => 1..10_000_000_000_000_000_000u64];
+    let duration = prop_oneof![1 => Just(0u64), 9 => 1..2_000u64];
+    // Job 0 and IDs past the last job never exist
+    let job = 0..12u64;
+    prop_oneof![
+        4 => (sender.clone(), freelancer, amount, duration).prop_map(
+            |(sender, freelancer, amount, duration)| Op::Deposit { sender, freelancer, amount, duration }
+        ),
+        2 => (sender.clone(), job.clone()).prop_map(|(sender, job)| Op::Release { sender, job }),
+        2 => (sender.clone(), job.clone()).prop_map(|(sender, job)| Op::Refund { sender, job }),
+        2 => (sender.clone(), job.clone()).prop_map(|(sender, job)| Op::AutoRelease { sender, job }),
+        1 => (sender.clone(), job).prop_map(|(sender, job)| Op::EmergencyRefund { sender, job }),
+        1 => (sender, any::<bool>()).prop_map(|(sender, paused)| Op::SetPaused { sender, paused }),
+        2 => (0..3_000u64).prop_map(|seconds| Op::Warp { seconds }),
+        // Long enough for jobs to become emergency refundable
+        1 => Just(Op::Warp { seconds: EMERGENCY_REFUND_GRACE }),
+    ]
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Settled {
+    No,
+    Released,
+    Refunded,
+}
+
+struct ModelJob {
+    client: Address,
+    freelancer: Address,
+    amount: U256,
+    deadline: u64,
+    created_at: u64,
+    settled: Settled,
+}
+
+/// Reference model of the escrow's expected state
+struct Model {
+    admin: Address,
+    paused: bool,
+    now: u64,
+    jobs: Vec<ModelJob>,
+    deposited: U256,
+    released: U256,
+    refunded: U256,
+}
+
+impl Model {
+    fn job(&self, id: u64) -> Option<&ModelJob> {
+        self.jobs.get((id as usize).checked_sub(1)?)
+    }
+
+    /// Total owed to unsettled jobs
+    fn owed(&self) -> U256 {
+        self.jobs
+            .iter()
+            .filter(|job| job.settled == Settled::No)
+            .map(|job| job.amount)
+            .sum()
+    }
+}
+
+/// Apply `op` to the contract and the model, asserting they agree on success
+fn step(
+    vm: &TestVM,
+    contract: &mut Escrow,
+    model: &mut Model,
+    op: &Op,
+) -> Result<(), TestCaseError> {
+    match *op {
+        Op::Deposit {
+            sender,
+            freelancer,
+            amount,
+            duration,
+        } => {
+            let client = ACTORS[sender];
+            let freelancer = ACTORS.get(freelancer).copied().unwrap_or(Address::ZERO);
+            let amount = U256::from(amount);
+            let expected =
+                !model.paused && !amount.is_zero() && !freelancer.is_zero() && duration > 0;
+
+            // msg.value arrives before execution but TestVM does not move it, so
+            // credit the contract by hand and take it back if the call reverts
+            let contract_address = vm.contract_address();
+            let balance = vm.balance(contract_address);
+            vm.set_balance(contract_address, balance + amount);
+
+            vm.set_sender(client);
+            vm.set_value(amount);
+            let result = contract.deposit(freelancer, duration, B256::ZERO);
+            vm.set_value(U256::ZERO);
+            prop_assert_eq!(result.is_ok(), expected, "deposit: {:?}", result);
+
+            if let Ok(job_id) = result {
+                model.jobs.push(ModelJob {
+                    client,
+                    freelancer,
+                    amount,
+                    deadline: model.now + duration,
+                    created_at: model.now,
+                    settled: Settled::No,
+                });
+                model.deposited += amount;
+                prop_assert_eq!(job_id, U256::from(model.jobs.len()));
+            } else {
+                vm.set_balance(contract_address, balance);
+            }
+        }
+        Op::Release { sender, job }
+        | Op::Refund { sender, job }
+        | Op::AutoRelease { sender, job } => {
+            let caller = ACTORS[sender];
+            let open = model.job(job).filter(|j| j.settled == Settled::No);
+            let expected = !model.paused
+                && open.is_some_and(|j| match op {
+                    Op::Release { .. } => j.client == caller,
+                    Op::Refund { .. } => j.client == caller && model.now < j.deadline,
+                    _ => j.freelancer == caller && model.now >= j.deadline,
+                });
+
+            vm.set_sender(caller);
+            let result = match op {
+                Op::Release { .. } => contract.release(U256::from(job), B256::ZERO),
+                Op::Refund { .. } => contract.refund(U256::from(job), B256::ZERO),
+                _ => contract.auto_release(U256::from(job), B256::ZERO),
+            };
+            prop_assert_eq!(result.is_ok(), expected, "{:?}: {:?}", op, result);
+
+            if expected {
+                let j = &mut model.jobs[job as usize - 1];
+                if matches!(op, Op::Refund { .. }) {
+                    j.settled = Settled::Refunded;
+                    model.refunded += j.amount;
+                } else {
+                    j.settled = Settled::Released;
+                    model.released += j.amount;
+                }
+            }
+        }
+        Op::EmergencyRefund { sender, job } => {
+            let caller = ACTORS[sender];
+            let open = model.job(job).is_some_and(|j| {
+                j.settled == Settled::No
+                    && (model.now >= j.created_at + EMERGENCY_REFUND_MIN_AGE
+                        || model.now >= j.deadline + EMERGENCY_REFUND_GRACE)
+            });
+            let expected = caller == model.admin && open;
+
+            vm.set_sender(caller);
+            let result = contract.emergency_refund(U256::from(job));
+            prop_assert_eq!(result.is_ok(), expected, "emergency_refund: {:?}", result);
+
+            if expected {
+                let j = &mut model.jobs[job as usize - 1];
+                j.settled = Settled::Refunded;
+                model.refunded += j.amount;
+            }
+        }
+        Op::SetPaused { sender, paused } => {
+            let caller = ACTORS[sender];
+            vm.set_sender(caller);
+            let result = contract.set_paused(paused);
+            prop_assert_eq!(result.is_ok(), caller == model.admin);
+            if result.is_ok() {
+                model.paused = paused;
+            }
+        }
+        Op::Warp { seconds } => {
+            model.now += seconds;
+            vm.set_block_timestamp(model.now);
+        }
+    }
+    Ok(())
+}
+
+/// Invariants that must hold after every step
+fn check(vm: &TestVM, contract: &Escrow, model: &Model) -> Result<(), TestCaseError> {
+    // The contract never owes more than it holds
+    let balance = vm.balance(vm.contract_address());
+    let owed = model.owed();
+    prop_assert!(balance >= owed, "balance {} < owed {}", balance, owed);
+    prop_assert_eq!(balance, owed);
+    prop_assert!(contract.verify_solvency());
+
+    // Every job matches the model and is settled at most once
+    let mut active = Vec::new();
+    for (i, job) in model.jobs.iter().enumerate() {
+        let id = U256::from(i + 1);
+        let (job_id, client, freelancer, amount, deadline, released, refunded) =
+            contract.get_job(id).unwrap();
+        prop_assert_eq!(job_id, id);
+        prop_assert_eq!(client, job.client);
+        prop_assert_eq!(freelancer, job.freelancer);
+        prop_assert_eq!(amount, job.amount);
+        prop_assert_eq!(deadline, job.deadline);
+        prop_assert!(!(released && refunded), "job {} settled twice", id);
+        prop_assert_eq!(released, job.settled == Settled::Released);
+        prop_assert_eq!(refunded, job.settled == Settled::Refunded);
+        if job.settled == Settled::No {
+            active.push(id);
+        }
+    }
+    let mut listed = contract.get_active_jobs();
+    listed.sort();
+    prop_assert_eq!(listed, active);
+    prop_assert_eq!(contract.get_total_jobs(), U256::from(model.jobs.len()));
+    prop_assert_eq!(contract.is_paused(), model.paused);
+
+    // Cumulative stats account for every wei still held
+    let (deposited, released, refunded, ..) = contract.get_protocol_stats();
+    prop_assert_eq!(
+        (deposited, released, refunded),
+        (model.deposited, model.released, model.refunded)
+    );
+    prop_assert_eq!(deposited - released - refunded, owed);
+    Ok(())
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(2_000))]
+
+    #[test]
+    fn escrow_matches_model(ops in prop::collection::vec(op(), 1..40)) {
+        let vm = TestVM::default();
+        let mut contract = Escrow::from(&vm);
+        vm.set_sender(ACTORS[0]);
+        contract.constructor(ACTORS[0]).unwrap();
+
+        let mut model = Model {
+            admin: ACTORS[0],
+            paused: false,
+            now: vm.block_timestamp(),
+            jobs: Vec::new(),
+            deposited: U256::ZERO,
+            released: U256::ZERO,
+            refunded: U256::ZERO,
+        };
+
+        for op in &ops {
+            step(&vm, &mut contract, &mut model, op)?;
+            check(&vm, &contract, &model)?;
+        }
+    }
+}